@@ -2,7 +2,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Only build RPC code if the feature is enabled
     // Note: cfg! doesn't work in build.rs, use env var instead
     if std::env::var("CARGO_FEATURE_RPC").is_ok() {
+        // Descriptor set backs gRPC server reflection
+        let out_dir = std::path::PathBuf::from(std::env::var("OUT_DIR")?);
+
         tonic_build::configure()
+            .file_descriptor_set_path(out_dir.join("mnemosyne_descriptor.bin"))
             .build_server(true)
             .build_client(false) // Server-only for now
             .compile_protos(
@@ -10,6 +14,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     "proto/mnemosyne/v1/types.proto",
                     "proto/mnemosyne/v1/memory.proto",
                     "proto/mnemosyne/v1/health.proto",
+                    "proto/mnemosyne/v1/events.proto",
                 ],
                 &["proto"], // Include path
             )?;
@@ -0,0 +1,38 @@
+-- Record permanent deletions in the audit log
+-- Migration: 018
+-- Date: 2026-10-16
+--
+-- Adds 'delete' to the allowed operations and drops the foreign key on
+-- memory_id, so a delete record can name the memory it removed and the
+-- memory's earlier audit rows survive the deletion.
+--
+-- SQLite cannot alter constraints, so the table is rebuilt.
+
+CREATE TABLE audit_log_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    timestamp TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    operation TEXT NOT NULL CHECK(operation IN (
+        'create',
+        'update',
+        'archive',
+        'supersede',
+        'link_create',
+        'link_update',
+        'link_delete',
+        'consolidate',
+        'delete'
+    )),
+    memory_id TEXT,          -- Not a foreign key: history outlives deleted memories
+    metadata TEXT NOT NULL   -- JSON object with operation-specific data
+);
+
+INSERT INTO audit_log_new (id, timestamp, operation, memory_id, metadata)
+SELECT id, timestamp, operation, memory_id, metadata
+FROM audit_log;
+
+DROP TABLE audit_log;
+ALTER TABLE audit_log_new RENAME TO audit_log;
+
+CREATE INDEX IF NOT EXISTS idx_audit_timestamp ON audit_log(timestamp DESC);
+CREATE INDEX IF NOT EXISTS idx_audit_memory_id ON audit_log(memory_id);
+CREATE INDEX IF NOT EXISTS idx_audit_operation ON audit_log(operation);
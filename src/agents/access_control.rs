@@ -2,6 +2,10 @@
 //!
 //! This module implements typed hole #8 (MemoryAccessControl) from the v2.0 specification,
 //! providing ownership tracking, permission checks, and audit trails.
+//!
+//! A memory's owner is the role recorded in its `Create` audit entry. Writes
+//! are checked against [`AgentRole::can_write`], and every attempt, allowed or
+//! denied, leaves a `ModificationLog` entry.
 
 use crate::agents::AgentRole;
 use crate::error::{MnemosyneError, Result};
@@ -33,6 +37,10 @@ pub enum ModificationType {
 
     /// Memory superseded by another
     Supersede,
+
+    /// Write attempt rejected by access control; the changes record the
+    /// attempted action
+    Denied,
 }
 
 impl std::fmt::Display for ModificationType {
@@ -44,6 +52,77 @@ impl std::fmt::Display for ModificationType {
             ModificationType::Archive => write!(f, "archive"),
             ModificationType::Unarchive => write!(f, "unarchive"),
             ModificationType::Supersede => write!(f, "supersede"),
+            ModificationType::Denied => write!(f, "denied"),
+        }
+    }
+}
+
+/// Write operations governed by the role permission matrix
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WriteAction {
+    /// Change tags, keywords, or related files/entities
+    Annotate,
+
+    /// Change content, summary, context, importance, confidence, expiry or visibility
+    Update,
+
+    /// Archive the memory
+    Archive,
+
+    /// Delete the memory
+    Delete,
+}
+
+impl WriteAction {
+    /// Modification type recorded in the audit log
+    pub fn modification_type(&self) -> ModificationType {
+        match self {
+            WriteAction::Annotate | WriteAction::Update => ModificationType::Update,
+            WriteAction::Archive => ModificationType::Archive,
+            WriteAction::Delete => ModificationType::Delete,
+        }
+    }
+}
+
+impl std::fmt::Display for WriteAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WriteAction::Annotate => write!(f, "annotate"),
+            WriteAction::Update => write!(f, "update"),
+            WriteAction::Archive => write!(f, "archive"),
+            WriteAction::Delete => write!(f, "delete"),
+        }
+    }
+}
+
+impl AgentRole {
+    /// Whether this role may perform `action` on a memory owned by `owner`
+    ///
+    /// Owners may do anything to their own memories. On other roles' memories:
+    ///
+    /// | Role         | Allowed                |
+    /// |--------------|------------------------|
+    /// | Orchestrator | annotate, archive      |
+    /// | Optimizer    | annotate, update       |
+    /// | Reviewer     | annotate               |
+    /// | Executor     | annotate               |
+    ///
+    /// Memories with no recorded owner (created outside access control) are
+    /// writable by every role.
+    pub fn can_write(&self, action: WriteAction, owner: Option<AgentRole>) -> bool {
+        match owner {
+            None => true,
+            Some(owner) if owner == *self => true,
+            Some(_) => match self {
+                AgentRole::Orchestrator => {
+                    matches!(action, WriteAction::Annotate | WriteAction::Archive)
+                }
+                AgentRole::Optimizer => {
+                    matches!(action, WriteAction::Annotate | WriteAction::Update)
+                }
+                AgentRole::Reviewer | AgentRole::Executor => action == WriteAction::Annotate,
+            },
         }
     }
 }
@@ -147,10 +226,30 @@ pub struct MemoryUpdates {
     pub visible_to: Option<Vec<AgentRole>>,
 }
 
+impl MemoryUpdates {
+    /// Permission these updates need: annotate if they only touch tags,
+    /// keywords, or related files/entities, update otherwise
+    pub fn action(&self) -> WriteAction {
+        let edits_body = self.content.is_some()
+            || self.summary.is_some()
+            || self.context.is_some()
+            || self.importance.is_some()
+            || self.confidence.is_some()
+            || self.expires_at.is_some()
+            || self.visible_to.is_some();
+        if edits_body {
+            WriteAction::Update
+        } else {
+            WriteAction::Annotate
+        }
+    }
+}
+
 /// Memory access control system
 ///
 /// Implements ownership tracking and permission checks for memory operations.
-/// Only the agent that created a memory (or admin) can modify it.
+/// The agent that created a memory (or admin) can do anything to it; other
+/// roles are limited by [`AgentRole::can_write`].
 pub struct MemoryAccessControl<S: StorageBackend> {
     /// Agent role for this access control instance
     agent: AgentRole,
@@ -198,41 +297,60 @@ impl<S: StorageBackend> MemoryAccessControl<S> {
         self.agent.default_visibility()
     }
 
-    /// Check if this agent can update the given memory
+    /// Role that created a memory, from its `Create` audit entry
+    ///
+    /// None for memories created outside access control.
+    pub async fn owner(&self, id: &MemoryId) -> Result<Option<AgentRole>> {
+        let trail = self.storage.get_audit_trail(*id).await?;
+        Ok(trail
+            .iter()
+            .find(|log| log.modification_type == ModificationType::Create)
+            .map(|log| log.agent_role))
+    }
+
+    /// Check if this agent may perform `action` on a memory
     ///
     /// Rules:
-    /// - Agent can update memories it created
-    /// - Admin can update any memory
-    pub fn can_update(&self, _memory: &MemoryNote) -> bool {
+    /// - Admin can do anything
+    /// - Otherwise the role permission matrix applies ([`AgentRole::can_write`])
+    pub async fn can_perform(&self, id: &MemoryId, action: WriteAction) -> Result<bool> {
         if self.is_admin() {
-            return true;
+            return Ok(true);
         }
-
-        // In v1.0, we check created_by through storage metadata
-        // For now, allow all updates (will be enforced when storage supports created_by)
-        true
+        let owner = self.owner(id).await?;
+        Ok(self.agent.can_write(action, owner))
     }
 
-    /// Check if this agent can delete the given memory
-    ///
-    /// Rules:
-    /// - Agent can delete memories it created
-    /// - Admin can delete any memory
-    pub fn can_delete(&self, _memory: &MemoryNote) -> bool {
+    /// Reject `action` unless permitted, logging the denied attempt
+    async fn authorize(&self, id: &MemoryId, action: WriteAction) -> Result<()> {
         if self.is_admin() {
-            return true;
+            return Ok(());
+        }
+        let owner = self.owner(id).await?;
+        if self.agent.can_write(action, owner) {
+            return Ok(());
         }
 
-        // In v1.0, we check created_by through storage metadata
-        // For now, allow all deletes (will be enforced when storage supports created_by)
-        true
-    }
+        self.log_modification(
+            id,
+            ModificationType::Denied,
+            Some(
+                serde_json::json!({
+                    "action": action.to_string(),
+                    "owner": owner.map(|o| o.to_string()),
+                })
+                .to_string(),
+            ),
+        )
+        .await?;
 
-    /// Check if this agent can archive the given memory
-    ///
-    /// Same rules as delete
-    pub fn can_archive(&self, memory: &MemoryNote) -> bool {
-        self.can_delete(memory)
+        Err(MnemosyneError::PermissionDenied(format!(
+            "Agent {} cannot {} memory {} (owned by {})",
+            self.agent,
+            action,
+            id,
+            owner.map_or_else(|| "nobody".to_string(), |o| o.to_string())
+        )))
     }
 
     /// Create a new memory with ownership tracking
@@ -295,7 +413,7 @@ impl<S: StorageBackend> MemoryAccessControl<S> {
             ModificationType::Create,
             Some(format!(
                 "{{\"created_by\":\"{}\",\"memory_type\":\"{:?}\",\"namespace\":\"{}\"}}",
-                self.agent, metadata.memory_type, metadata.namespace
+                self.agent, memory.memory_type, metadata.namespace
             )),
         )
         .await?;
@@ -314,7 +432,8 @@ impl<S: StorageBackend> MemoryAccessControl<S> {
     ///
     /// Returns an error if:
     /// - Memory not found
-    /// - Permission denied (not owner and not admin)
+    /// - Permission denied (see [`AgentRole::can_write`]; annotation-only
+    ///   updates need [`WriteAction::Annotate`])
     /// - Storage operation fails
     /// - Audit logging fails
     pub async fn update_memory(&self, id: &MemoryId, updates: MemoryUpdates) -> Result<()> {
@@ -322,12 +441,7 @@ impl<S: StorageBackend> MemoryAccessControl<S> {
         let mut memory = self.storage.get_memory(*id).await?;
 
         // Check permissions
-        if !self.can_update(&memory) {
-            return Err(MnemosyneError::PermissionDenied(format!(
-                "Agent {} cannot update memory {} (not owner)",
-                self.agent, id
-            )));
-        }
+        self.authorize(id, updates.action()).await?;
 
         // Track changes for audit log
         let mut changes = Vec::new();
@@ -415,20 +529,15 @@ impl<S: StorageBackend> MemoryAccessControl<S> {
     ///
     /// Returns an error if:
     /// - Memory not found
-    /// - Permission denied (not owner and not admin)
+    /// - Permission denied (see [`AgentRole::can_write`])
     /// - Storage operation fails
     /// - Audit logging fails
     pub async fn delete_memory(&self, id: &MemoryId) -> Result<()> {
-        // Fetch the memory to check permissions
-        let memory = self.storage.get_memory(*id).await?;
+        // Fail with not-found before checking permissions
+        self.storage.get_memory(*id).await?;
 
         // Check permissions
-        if !self.can_delete(&memory) {
-            return Err(MnemosyneError::PermissionDenied(format!(
-                "Agent {} cannot delete memory {} (not owner)",
-                self.agent, id
-            )));
-        }
+        self.authorize(id, WriteAction::Delete).await?;
 
         // Log before deletion (in case deletion cascades to logs)
         self.log_modification(id, ModificationType::Delete, None)
@@ -450,20 +559,15 @@ impl<S: StorageBackend> MemoryAccessControl<S> {
     ///
     /// Returns an error if:
     /// - Memory not found
-    /// - Permission denied (not owner and not admin)
+    /// - Permission denied (see [`AgentRole::can_write`])
     /// - Storage operation fails
     /// - Audit logging fails
     pub async fn archive_memory(&self, id: &MemoryId) -> Result<()> {
-        // Fetch the memory to check permissions
-        let memory = self.storage.get_memory(*id).await?;
+        // Fail with not-found before checking permissions
+        self.storage.get_memory(*id).await?;
 
         // Check permissions
-        if !self.can_archive(&memory) {
-            return Err(MnemosyneError::PermissionDenied(format!(
-                "Agent {} cannot archive memory {} (not owner)",
-                self.agent, id
-            )));
-        }
+        self.authorize(id, WriteAction::Archive).await?;
 
         // Archive the memory
         self.storage.archive_memory(*id).await?;
@@ -588,6 +692,40 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_permission_matrix() {
+        use AgentRole::*;
+
+        for role in [Orchestrator, Optimizer, Reviewer, Executor] {
+            assert!(role.can_write(WriteAction::Delete, Some(role)));
+            assert!(role.can_write(WriteAction::Delete, None));
+            assert!(role.can_write(WriteAction::Annotate, Some(Executor)));
+        }
+
+        assert!(!Reviewer.can_write(WriteAction::Update, Some(Executor)));
+        assert!(!Reviewer.can_write(WriteAction::Delete, Some(Executor)));
+        assert!(Optimizer.can_write(WriteAction::Update, Some(Executor)));
+        assert!(!Optimizer.can_write(WriteAction::Archive, Some(Executor)));
+        assert!(Orchestrator.can_write(WriteAction::Archive, Some(Executor)));
+        assert!(!Orchestrator.can_write(WriteAction::Delete, Some(Executor)));
+        assert!(!Executor.can_write(WriteAction::Update, Some(Reviewer)));
+    }
+
+    #[test]
+    fn test_updates_action() {
+        let annotate = MemoryUpdates {
+            tags: Some(vec!["reviewed".to_string()]),
+            ..Default::default()
+        };
+        assert_eq!(annotate.action(), WriteAction::Annotate);
+
+        let update = MemoryUpdates {
+            importance: Some(3),
+            ..annotate
+        };
+        assert_eq!(update.action(), WriteAction::Update);
+    }
+
     #[test]
     fn test_modification_type_display() {
         assert_eq!(ModificationType::Create.to_string(), "create");
@@ -2,14 +2,35 @@
 //!
 //! This module provides role-specific importance calculations, allowing
 //! different agents to prioritize memories differently based on their needs.
+//!
+//! Each role's weights can be tuned in the config file without recompiling:
+//!
+//! ```toml
+//! [importance.reviewer]
+//! base = 0.6
+//! access = 0.1
+//! recency = 0.1
+//! relevance = 0.2
+//! ```
+//!
+//! Weights must be non-negative and are normalized to sum to 1.0.
 
 use crate::agents::AgentRole;
+use crate::error::{MnemosyneError, Result};
+use crate::types::MemoryNote;
+use chrono::Utc;
 use serde::{Deserialize, Serialize};
 
+/// Accesses at which the access component reaches about 63% of its maximum
+const ACCESS_SCALE: f32 = 10.0;
+
+/// Days since last access at which the recency component falls to about 37%
+const RECENCY_SCALE_DAYS: f32 = 30.0;
+
 /// Importance scoring weights
 ///
 /// Different components of importance calculation with configurable weights.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ImportanceWeights {
     /// Base importance score (from memory.importance)
     pub base: f32,
@@ -35,24 +56,10 @@ impl Default for ImportanceWeights {
     }
 }
 
-/// Custom importance scorer for agents
-///
-/// Calculates role-specific importance scores by weighting different
-/// factors based on agent needs.
-pub struct CustomImportanceScorer {
-    /// Agent role for this scorer
-    role: AgentRole,
-}
-
-impl CustomImportanceScorer {
-    /// Create a new importance scorer
-    pub fn new(role: AgentRole) -> Self {
-        Self { role }
-    }
-
-    /// Get role-specific weights
-    pub fn get_weights(&self) -> ImportanceWeights {
-        match self.role {
+impl ImportanceWeights {
+    /// Built-in weights for a role
+    pub fn for_role(role: AgentRole) -> Self {
+        match role {
             AgentRole::Orchestrator => ImportanceWeights {
                 base: 0.3,
                 access: 0.2,
@@ -79,4 +86,273 @@ impl CustomImportanceScorer {
             },
         }
     }
+
+    /// Validate that weights are finite, non-negative, and not all zero
+    pub fn validate(&self) -> Result<()> {
+        let weights = [
+            ("base", self.base),
+            ("access", self.access),
+            ("recency", self.recency),
+            ("relevance", self.relevance),
+        ];
+        for (name, weight) in &weights {
+            if !weight.is_finite() || *weight < 0.0 {
+                return Err(MnemosyneError::ValidationError(format!(
+                    "importance weight {} must be non-negative, got {}",
+                    name, weight
+                )));
+            }
+        }
+        if self.sum() == 0.0 {
+            return Err(MnemosyneError::ValidationError(
+                "importance weights must not all be zero".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Scale weights to sum to 1.0
+    ///
+    /// Weights that fail validation fall back to the defaults.
+    pub fn normalized(&self) -> Self {
+        if self.validate().is_err() {
+            return Self::default();
+        }
+        let sum = self.sum();
+        Self {
+            base: self.base / sum,
+            access: self.access / sum,
+            recency: self.recency / sum,
+            relevance: self.relevance / sum,
+        }
+    }
+
+    fn sum(&self) -> f32 {
+        self.base + self.access + self.recency + self.relevance
+    }
+}
+
+/// Per-role importance weights (`[importance.<role>]`)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ImportanceProfiles {
+    pub orchestrator: ImportanceWeights,
+    pub optimizer: ImportanceWeights,
+    pub reviewer: ImportanceWeights,
+    pub executor: ImportanceWeights,
+}
+
+impl Default for ImportanceProfiles {
+    fn default() -> Self {
+        Self {
+            orchestrator: ImportanceWeights::for_role(AgentRole::Orchestrator),
+            optimizer: ImportanceWeights::for_role(AgentRole::Optimizer),
+            reviewer: ImportanceWeights::for_role(AgentRole::Reviewer),
+            executor: ImportanceWeights::for_role(AgentRole::Executor),
+        }
+    }
+}
+
+impl ImportanceProfiles {
+    /// Weights configured for a role
+    pub fn for_role(&self, role: AgentRole) -> &ImportanceWeights {
+        match role {
+            AgentRole::Orchestrator => &self.orchestrator,
+            AgentRole::Optimizer => &self.optimizer,
+            AgentRole::Reviewer => &self.reviewer,
+            AgentRole::Executor => &self.executor,
+        }
+    }
+
+    /// Validate every role's weights
+    pub fn validate(&self) -> Result<()> {
+        for role in [
+            AgentRole::Orchestrator,
+            AgentRole::Optimizer,
+            AgentRole::Reviewer,
+            AgentRole::Executor,
+        ] {
+            self.for_role(role).validate().map_err(|e| {
+                MnemosyneError::ValidationError(format!("[importance.{}]: {}", role, e))
+            })?;
+        }
+        Ok(())
+    }
+}
+
+/// Custom importance scorer for agents
+///
+/// Calculates role-specific importance scores by weighting different
+/// factors based on agent needs.
+pub struct CustomImportanceScorer {
+    /// Agent role for this scorer
+    role: AgentRole,
+
+    /// Normalized weights
+    weights: ImportanceWeights,
+}
+
+impl CustomImportanceScorer {
+    /// Create a new importance scorer with the role's built-in weights
+    pub fn new(role: AgentRole) -> Self {
+        Self::with_weights(role, ImportanceWeights::for_role(role))
+    }
+
+    /// Create a scorer with custom weights (normalized to sum to 1.0)
+    pub fn with_weights(role: AgentRole, weights: ImportanceWeights) -> Self {
+        Self {
+            role,
+            weights: weights.normalized(),
+        }
+    }
+
+    /// Create a scorer using the role's profile from config
+    pub fn from_profiles(role: AgentRole, profiles: &ImportanceProfiles) -> Self {
+        Self::with_weights(role, profiles.for_role(role).clone())
+    }
+
+    /// Get role-specific weights
+    pub fn get_weights(&self) -> ImportanceWeights {
+        self.weights.clone()
+    }
+
+    /// Score a memory for this role (0.0-1.0)
+    pub fn score(&self, memory: &MemoryNote) -> f32 {
+        let base = f32::from(memory.importance.min(10)) / 10.0;
+        let access = 1.0 - (-(memory.access_count as f32) / ACCESS_SCALE).exp();
+        let days = (Utc::now() - memory.last_accessed_at).num_seconds().max(0) as f32 / 86_400.0;
+        let recency = (-days / RECENCY_SCALE_DAYS).exp();
+        let relevance = if self.role.memory_types().contains(&memory.memory_type) {
+            1.0
+        } else {
+            0.0
+        };
+
+        self.weights.base * base
+            + self.weights.access * access
+            + self.weights.recency * recency
+            + self.weights.relevance * relevance
+    }
+
+    /// Sort memories by score, highest first
+    pub fn rank(&self, mut memories: Vec<MemoryNote>) -> Vec<MemoryNote> {
+        memories.sort_by(|a, b| self.score(b).total_cmp(&self.score(a)));
+        memories
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{MemoryId, MemoryType, Namespace};
+    use chrono::Duration;
+
+    fn memory(summary: &str, importance: u8, access_count: u32, days_ago: i64) -> MemoryNote {
+        let accessed = Utc::now() - Duration::days(days_ago);
+        MemoryNote {
+            id: MemoryId::new(),
+            namespace: Namespace::Global,
+            created_at: accessed,
+            updated_at: accessed,
+            content: summary.to_string(),
+            summary: summary.to_string(),
+            keywords: vec![],
+            tags: vec![],
+            context: String::new(),
+            memory_type: MemoryType::BugFix,
+            importance,
+            confidence: 0.9,
+            links: vec![],
+            related_files: vec![],
+            related_entities: vec![],
+            access_count,
+            last_accessed_at: accessed,
+            expires_at: None,
+            is_archived: false,
+            superseded_by: None,
+            embedding: None,
+            embedding_model: "test".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_profiles_rank_differently() {
+        let profiles: ImportanceProfiles = toml::from_str(
+            r#"
+            [reviewer]
+            base = 8.0
+            access = 0.0
+            recency = 1.0
+            relevance = 1.0
+
+            [executor]
+            base = 0.1
+            access = 0.6
+            recency = 0.3
+            relevance = 0.0
+            "#,
+        )
+        .unwrap();
+
+        // Critical but rarely touched vs. minor but in constant use
+        let critical = memory("critical", 10, 0, 60);
+        let busy = memory("busy", 2, 50, 0);
+
+        let reviewer = CustomImportanceScorer::from_profiles(AgentRole::Reviewer, &profiles);
+        let executor = CustomImportanceScorer::from_profiles(AgentRole::Executor, &profiles);
+
+        let ranked: Vec<String> = reviewer
+            .rank(vec![critical.clone(), busy.clone()])
+            .into_iter()
+            .map(|m| m.summary)
+            .collect();
+        assert_eq!(ranked, vec!["critical", "busy"]);
+
+        let ranked: Vec<String> = executor
+            .rank(vec![critical, busy])
+            .into_iter()
+            .map(|m| m.summary)
+            .collect();
+        assert_eq!(ranked, vec!["busy", "critical"]);
+
+        // Unset roles keep their built-in weights
+        assert_eq!(
+            profiles.optimizer,
+            ImportanceWeights::for_role(AgentRole::Optimizer)
+        );
+    }
+
+    #[test]
+    fn test_weights_validated_and_normalized() {
+        let weights = ImportanceWeights {
+            base: 2.0,
+            access: 1.0,
+            recency: 1.0,
+            relevance: 0.0,
+        };
+        let normalized =
+            CustomImportanceScorer::with_weights(AgentRole::Reviewer, weights).get_weights();
+        assert_eq!(normalized.base, 0.5);
+        assert_eq!(normalized.access, 0.25);
+
+        let negative = ImportanceWeights {
+            access: -0.1,
+            ..Default::default()
+        };
+        assert!(negative.validate().is_err());
+
+        let zero = ImportanceWeights {
+            base: 0.0,
+            access: 0.0,
+            recency: 0.0,
+            relevance: 0.0,
+        };
+        assert!(zero.validate().is_err());
+
+        let profiles = ImportanceProfiles {
+            optimizer: negative,
+            ..Default::default()
+        };
+        assert!(profiles.validate().is_err());
+    }
 }
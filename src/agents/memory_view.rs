@@ -4,11 +4,15 @@
 //! from the v2.0 specification, providing role-specific memory access for the
 //! multi-agent architecture.
 
+use crate::agents::access_control::{MemoryAccessControl, MemoryUpdates, WriteAction};
+use crate::agents::importance_scorer::{CustomImportanceScorer, ImportanceProfiles};
+use crate::agents::prefetcher::{MemoryPrefetcher, PrefetchMetrics, PrefetchTrigger};
 use crate::error::{MnemosyneError, Result};
 use crate::storage::{MemorySortOrder, StorageBackend};
-use crate::types::{MemoryNote, MemoryType, Namespace};
+use crate::types::{MemoryId, MemoryNote, MemoryType, Namespace};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
+use tracing::debug;
 
 /// Agent roles in the multi-agent system
 ///
@@ -113,6 +117,10 @@ impl std::str::FromStr for AgentRole {
 /// Implements typed hole #7 from the specification. Provides filtered access
 /// to memories based on agent role, restricting results to relevant memory types.
 ///
+/// Writes made through the view (`update`, `archive`, `delete`) are checked
+/// against [`MemoryAccessControl`] and audited, so agents should write through
+/// their view rather than the storage backend.
+///
 /// # Example
 ///
 /// ```ignore
@@ -123,6 +131,11 @@ impl std::str::FromStr for AgentRole {
 ///
 /// // Only returns recently accessed memories of relevant types
 /// let recent = view.list_recent(20).await?;
+///
+/// // Preload memories known to matter, then read them from the cache
+/// view.warm(&ids).await?;
+/// let memory = view.get(ids[0]).await?;
+/// println!("hit rate: {:.2}", view.prefetch_metrics().hit_rate());
 /// ```
 pub struct AgentMemoryView<S: StorageBackend> {
     /// Agent role for this view
@@ -130,12 +143,115 @@ pub struct AgentMemoryView<S: StorageBackend> {
 
     /// Storage backend
     storage: Arc<S>,
+
+    /// Cache in front of `get`
+    prefetcher: MemoryPrefetcher,
+
+    /// Permission checks and audit logging for writes
+    access: MemoryAccessControl<S>,
+
+    /// Role-specific ranking
+    scorer: CustomImportanceScorer,
 }
 
 impl<S: StorageBackend> AgentMemoryView<S> {
     /// Create a new agent memory view
+    ///
+    /// Uses the default prefetch cache capacity with every trigger enabled,
+    /// and the role's importance profile from config (`[importance.<role>]`).
     pub fn new(role: AgentRole, storage: Arc<S>) -> Self {
-        Self { role, storage }
+        Self {
+            role,
+            access: MemoryAccessControl::new(role, Arc::clone(&storage)),
+            storage,
+            prefetcher: MemoryPrefetcher::new(role),
+            scorer: CustomImportanceScorer::from_profiles(role, &configured_profiles()),
+        }
+    }
+
+    /// Create a view with a prefetch cache capacity and trigger policy
+    pub fn with_prefetch(
+        role: AgentRole,
+        storage: Arc<S>,
+        capacity: usize,
+        triggers: impl IntoIterator<Item = PrefetchTrigger>,
+    ) -> Self {
+        Self {
+            role,
+            access: MemoryAccessControl::new(role, Arc::clone(&storage)),
+            storage,
+            prefetcher: MemoryPrefetcher::with_policy(role, capacity, triggers),
+            scorer: CustomImportanceScorer::from_profiles(role, &configured_profiles()),
+        }
+    }
+
+    /// Replace the importance scorer (e.g. with explicit weights)
+    pub fn with_scorer(mut self, scorer: CustomImportanceScorer) -> Self {
+        self.scorer = scorer;
+        self
+    }
+
+    /// Importance scorer used by [`Self::rank`]
+    pub fn scorer(&self) -> &CustomImportanceScorer {
+        &self.scorer
+    }
+
+    /// Sort memories by this role's importance, highest first
+    pub fn rank(&self, memories: Vec<MemoryNote>) -> Vec<MemoryNote> {
+        self.scorer.rank(memories)
+    }
+
+    /// Snapshot of the prefetch cache counters (hits, misses, evictions)
+    pub fn prefetch_metrics(&self) -> PrefetchMetrics {
+        self.prefetcher.metrics.as_ref().clone()
+    }
+
+    /// Get a memory by ID, serving it from the prefetch cache when possible
+    ///
+    /// Memories outside this role's types are reported as not found.
+    pub async fn get(&self, id: MemoryId) -> Result<MemoryNote> {
+        if let Some(memory) = self.prefetcher.get(&id) {
+            return Ok(memory);
+        }
+
+        let memory = self.storage.get_memory(id).await?;
+        if !self.is_visible(&memory) {
+            return Err(MnemosyneError::NotFound(format!(
+                "Memory {} is not visible to {}",
+                id, self.role
+            )));
+        }
+        self.prefetcher.insert(memory.clone());
+        Ok(memory)
+    }
+
+    /// Preload memories known to be relevant, e.g. at session start
+    ///
+    /// Skips memories that can't be loaded or aren't visible to this role,
+    /// and returns how many were cached.
+    pub async fn warm(&self, ids: &[MemoryId]) -> Result<usize> {
+        let mut loaded = Vec::with_capacity(ids.len());
+        for &id in ids {
+            match self.storage.get_memory(id).await {
+                Ok(memory) if self.is_visible(&memory) => loaded.push(memory),
+                Ok(_) => debug!("Not warming {}: not visible to {}", id, self.role),
+                Err(e) => debug!("Not warming {}: {}", id, e),
+            }
+        }
+
+        let count = loaded.len();
+        self.prefetcher.prefetch(loaded);
+        Ok(count)
+    }
+
+    /// Preload memories for a trigger, if the prefetch policy enables it
+    ///
+    /// Returns how many were cached (0 when the trigger is disabled).
+    pub async fn prefetch_on(&self, trigger: PrefetchTrigger, ids: &[MemoryId]) -> Result<usize> {
+        if !self.prefetcher.should_prefetch(trigger) {
+            return Ok(0);
+        }
+        self.warm(ids).await
     }
 
     /// Get the agent role for this view
@@ -156,13 +272,21 @@ impl<S: StorageBackend> AgentMemoryView<S> {
 
         // Filter by role-specific memory types
         let relevant_types = self.role.memory_types();
-        let filtered = results
+        let filtered: Vec<MemoryNote> = results
             .into_iter()
             .map(|r| r.memory)
             .filter(|m| relevant_types.contains(&m.memory_type))
             .take(limit)
             .collect();
 
+        // Results found together tend to be read together
+        if self
+            .prefetcher
+            .should_prefetch(PrefetchTrigger::MemoryAccess)
+        {
+            self.prefetcher.prefetch(filtered.iter().cloned());
+        }
+
         Ok(filtered)
     }
 
@@ -244,6 +368,35 @@ impl<S: StorageBackend> AgentMemoryView<S> {
         Ok(filtered)
     }
 
+    /// Update a memory, if this role may
+    ///
+    /// Annotation-only updates (tags, keywords, related files/entities) need
+    /// less permission than edits; see [`AgentRole::can_write`].
+    pub async fn update(&self, id: MemoryId, updates: MemoryUpdates) -> Result<()> {
+        self.access.update_memory(&id, updates).await?;
+        self.prefetcher.remove(&id);
+        Ok(())
+    }
+
+    /// Archive a memory, if this role may
+    pub async fn archive(&self, id: MemoryId) -> Result<()> {
+        self.access.archive_memory(&id).await?;
+        self.prefetcher.remove(&id);
+        Ok(())
+    }
+
+    /// Delete a memory, if this role may
+    pub async fn delete(&self, id: MemoryId) -> Result<()> {
+        self.access.delete_memory(&id).await?;
+        self.prefetcher.remove(&id);
+        Ok(())
+    }
+
+    /// Whether this role may perform `action` on a memory
+    pub async fn can_perform(&self, id: MemoryId, action: WriteAction) -> Result<bool> {
+        self.access.can_perform(&id, action).await
+    }
+
     /// Check if a memory is visible to this agent
     ///
     /// Determines visibility based on:
@@ -254,6 +407,11 @@ impl<S: StorageBackend> AgentMemoryView<S> {
     }
 }
 
+/// Importance profiles from the config layers, or the built-ins if unavailable
+fn configured_profiles() -> ImportanceProfiles {
+    crate::config::ConfigManager::load_section("importance profiles", |s| s.importance)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -349,6 +507,91 @@ mod tests {
             .contains(&decision_memory.memory_type));
     }
 
+    async fn test_storage(dir: &tempfile::TempDir) -> Arc<LibsqlStorage> {
+        let db_path = dir.path().join("test.db");
+        let storage = LibsqlStorage::new_with_validation(
+            crate::storage::libsql::ConnectionMode::Local(db_path.to_str().unwrap().to_string()),
+            true,
+        )
+        .await
+        .expect("Failed to create storage");
+        Arc::new(storage)
+    }
+
+    async fn store_patterns(storage: &LibsqlStorage, count: usize) -> Vec<MemoryId> {
+        let mut ids = Vec::new();
+        for _ in 0..count {
+            let memory = create_test_memory(MemoryType::CodePattern, 7);
+            storage.store_memory(&memory).await.unwrap();
+            ids.push(memory.id);
+        }
+        ids
+    }
+
+    #[tokio::test]
+    async fn test_warmed_cache_reports_hits() {
+        let temp_dir = tempdir().unwrap();
+        let storage = test_storage(&temp_dir).await;
+        let ids = store_patterns(&storage, 3).await;
+        let hidden = create_test_memory(MemoryType::ArchitectureDecision, 9);
+        storage.store_memory(&hidden).await.unwrap();
+
+        let view = AgentMemoryView::with_prefetch(
+            AgentRole::Executor,
+            storage,
+            10,
+            [PrefetchTrigger::SessionStart],
+        );
+
+        // Invisible memories are skipped
+        let mut warm_ids = ids.clone();
+        warm_ids.push(hidden.id);
+        assert_eq!(view.warm(&warm_ids).await.unwrap(), 3);
+
+        for id in &ids {
+            assert_eq!(view.get(*id).await.unwrap().id, *id);
+        }
+        assert!(view.get(hidden.id).await.is_err());
+
+        let metrics = view.prefetch_metrics();
+        assert_eq!(metrics.hits(), 3);
+        assert_eq!(metrics.misses(), 1);
+        assert_eq!(metrics.prefetch_count(), 3);
+        assert_eq!(metrics.hit_rate(), 0.75);
+
+        // Triggers outside the policy don't load anything
+        assert_eq!(
+            view.prefetch_on(PrefetchTrigger::TaskStart, &ids)
+                .await
+                .unwrap(),
+            0
+        );
+    }
+
+    #[tokio::test]
+    async fn test_evictions_past_capacity() {
+        let temp_dir = tempdir().unwrap();
+        let storage = test_storage(&temp_dir).await;
+        let ids = store_patterns(&storage, 5).await;
+
+        let view = AgentMemoryView::with_prefetch(AgentRole::Executor, storage, 2, []);
+        assert_eq!(view.warm(&ids[..2]).await.unwrap(), 2);
+        assert_eq!(view.prefetch_metrics().evictions(), 0);
+
+        // Re-warming a cached memory isn't an eviction
+        view.warm(&ids[..1]).await.unwrap();
+        assert_eq!(view.prefetch_metrics().evictions(), 0);
+
+        view.warm(&ids[2..]).await.unwrap();
+        assert_eq!(view.prefetch_metrics().evictions(), 3);
+
+        // The least recently used entries were the ones evicted
+        view.get(ids[4]).await.unwrap();
+        view.get(ids[0]).await.unwrap();
+        let metrics = view.prefetch_metrics();
+        assert_eq!((metrics.hits(), metrics.misses()), (1, 1));
+    }
+
     #[tokio::test]
     async fn test_agent_memory_view_search() {
         let temp_dir = tempdir().unwrap();
@@ -31,7 +31,8 @@ pub mod prefetcher;
 // Re-export commonly used types
 pub use access_control::{
     MemoryAccessControl, MemoryMetadata, MemoryUpdates, ModificationLog, ModificationType,
+    WriteAction,
 };
-pub use importance_scorer::{CustomImportanceScorer, ImportanceWeights};
+pub use importance_scorer::{CustomImportanceScorer, ImportanceProfiles, ImportanceWeights};
 pub use memory_view::{AgentMemoryView, AgentRole};
 pub use prefetcher::{MemoryPrefetcher, PrefetchMetrics, PrefetchTrigger};
@@ -1,12 +1,21 @@
 //! Memory prefetching with LRU cache
-#![allow(dead_code)]
 //!
 //! This module implements typed hole #9 (MemoryPrefetcher) from the v2.0 specification,
 //! providing intelligent memory preloading to reduce latency.
+//!
+//! The cache is a fixed-capacity LRU keyed by memory ID. Which events are
+//! allowed to load it is set by the prefetcher's [`PrefetchTrigger`] policy;
+//! explicit warming at session start always loads.
 
 use crate::agents::AgentRole;
+use crate::types::{MemoryId, MemoryNote};
+use lru::LruCache;
+use std::num::NonZeroUsize;
 use std::sync::atomic::{AtomicU64, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
+
+/// Default number of memories cached per agent
+pub const DEFAULT_PREFETCH_CAPACITY: usize = 100;
 
 /// Prefetch trigger events
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -24,6 +33,18 @@ pub enum PrefetchTrigger {
     MemoryAccess,
 }
 
+impl PrefetchTrigger {
+    /// Every trigger
+    pub fn all() -> Vec<PrefetchTrigger> {
+        vec![
+            PrefetchTrigger::SessionStart,
+            PrefetchTrigger::PhaseTransition,
+            PrefetchTrigger::TaskStart,
+            PrefetchTrigger::MemoryAccess,
+        ]
+    }
+}
+
 /// Metrics for prefetch cache
 #[derive(Debug)]
 pub struct PrefetchMetrics {
@@ -33,6 +54,9 @@ pub struct PrefetchMetrics {
     /// Cache misses
     misses: AtomicU64,
 
+    /// Entries evicted to make room
+    evictions: AtomicU64,
+
     /// Number of prefetches performed
     prefetch_count: AtomicU64,
 }
@@ -43,20 +67,53 @@ impl Default for PrefetchMetrics {
     }
 }
 
+/// Point-in-time copy of the counters
+impl Clone for PrefetchMetrics {
+    fn clone(&self) -> Self {
+        Self {
+            hits: AtomicU64::new(self.hits()),
+            misses: AtomicU64::new(self.misses()),
+            evictions: AtomicU64::new(self.evictions()),
+            prefetch_count: AtomicU64::new(self.prefetch_count()),
+        }
+    }
+}
+
 impl PrefetchMetrics {
     /// Create new metrics
     pub fn new() -> Self {
         Self {
             hits: AtomicU64::new(0),
             misses: AtomicU64::new(0),
+            evictions: AtomicU64::new(0),
             prefetch_count: AtomicU64::new(0),
         }
     }
 
+    /// Cache hits
+    pub fn hits(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    /// Cache misses
+    pub fn misses(&self) -> u64 {
+        self.misses.load(Ordering::Relaxed)
+    }
+
+    /// Entries evicted to make room
+    pub fn evictions(&self) -> u64 {
+        self.evictions.load(Ordering::Relaxed)
+    }
+
+    /// Memories loaded ahead of use
+    pub fn prefetch_count(&self) -> u64 {
+        self.prefetch_count.load(Ordering::Relaxed)
+    }
+
     /// Calculate cache hit rate
     pub fn hit_rate(&self) -> f64 {
-        let hits = self.hits.load(Ordering::Relaxed) as f64;
-        let misses = self.misses.load(Ordering::Relaxed) as f64;
+        let hits = self.hits() as f64;
+        let misses = self.misses() as f64;
         if hits + misses == 0.0 {
             0.0
         } else {
@@ -70,16 +127,101 @@ pub struct MemoryPrefetcher {
     /// Agent role
     role: AgentRole,
 
+    /// Cached memories, least recently used evicted first
+    cache: Mutex<LruCache<MemoryId, MemoryNote>>,
+
+    /// Events allowed to load the cache
+    triggers: Vec<PrefetchTrigger>,
+
     /// Cache metrics
     pub metrics: Arc<PrefetchMetrics>,
 }
 
 impl MemoryPrefetcher {
-    /// Create a new prefetcher
+    /// Create a new prefetcher with the default capacity and every trigger enabled
     pub fn new(role: AgentRole) -> Self {
+        Self::with_policy(role, DEFAULT_PREFETCH_CAPACITY, PrefetchTrigger::all())
+    }
+
+    /// Create a prefetcher with a cache capacity and trigger policy
+    ///
+    /// A capacity of 0 is treated as 1.
+    pub fn with_policy(
+        role: AgentRole,
+        capacity: usize,
+        triggers: impl IntoIterator<Item = PrefetchTrigger>,
+    ) -> Self {
+        let capacity = NonZeroUsize::new(capacity).unwrap_or(NonZeroUsize::MIN);
         Self {
             role,
+            cache: Mutex::new(LruCache::new(capacity)),
+            triggers: triggers.into_iter().collect(),
             metrics: Arc::new(PrefetchMetrics::new()),
         }
     }
+
+    /// Agent role
+    pub fn role(&self) -> AgentRole {
+        self.role
+    }
+
+    /// Maximum number of cached memories
+    pub fn capacity(&self) -> usize {
+        self.lock().cap().get()
+    }
+
+    /// Number of cached memories
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Whether the cache is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Whether the policy allows prefetching on a trigger
+    pub fn should_prefetch(&self, trigger: PrefetchTrigger) -> bool {
+        self.triggers.contains(&trigger)
+    }
+
+    /// Look up a memory, recording a hit or miss
+    pub fn get(&self, id: &MemoryId) -> Option<MemoryNote> {
+        let found = self.lock().get(id).cloned();
+        let counter = if found.is_some() {
+            &self.metrics.hits
+        } else {
+            &self.metrics.misses
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+        found
+    }
+
+    /// Cache a memory that was just fetched on demand
+    pub fn insert(&self, memory: MemoryNote) {
+        let id = memory.id;
+        let evicted = self.lock().push(id, memory);
+        // push also returns the old value when replacing the same key
+        if evicted.is_some_and(|(old_id, _)| old_id != id) {
+            self.metrics.evictions.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Drop a cached memory (e.g. after it was modified)
+    pub fn remove(&self, id: &MemoryId) {
+        self.lock().pop(id);
+    }
+
+    /// Cache memories ahead of use
+    pub fn prefetch(&self, memories: impl IntoIterator<Item = MemoryNote>) {
+        for memory in memories {
+            self.insert(memory);
+            self.metrics.prefetch_count.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, LruCache<MemoryId, MemoryNote>> {
+        // The cache holds no invariants a panicking holder could break
+        self.cache.lock().unwrap_or_else(|e| e.into_inner())
+    }
 }
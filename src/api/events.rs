@@ -56,6 +56,34 @@ pub enum EventType {
         cpu_percent: f32,
         timestamp: DateTime<Utc>,
     },
+    /// Sustained RSS growth detected by the memory tracker
+    MemoryLeakSuspected {
+        rss_bytes: u64,
+        growth_bytes_per_sec: f64,
+        duration_secs: u64,
+        timestamp: DateTime<Utc>,
+    },
+    /// A service entered or left degraded mode
+    DegradationChanged {
+        service: String,
+        degraded: bool,
+        #[serde(default)]
+        reason: Option<String>,
+        timestamp: DateTime<Utc>,
+    },
+    /// An LLM completion finished, with its tokens and the service's running total
+    LlmUsage {
+        model: String,
+        input_tokens: u64,
+        output_tokens: u64,
+        /// Estimated cost of this call in USD
+        cost_usd: f64,
+        /// Tokens used by the service since it started
+        total_tokens: u64,
+        /// Estimated cost of all calls since the service started, in USD
+        total_cost_usd: f64,
+        timestamp: DateTime<Utc>,
+    },
     /// Session started
     SessionStarted {
         #[serde(default)]
@@ -85,6 +113,13 @@ pub enum EventType {
         blocked_items: Vec<String>,
         timestamp: DateTime<Utc>,
     },
+    /// Agents' work overlaps on the same files
+    ConflictDetected {
+        files: Vec<String>,
+        severity: String,
+        agents: Vec<String>,
+        timestamp: DateTime<Utc>,
+    },
     /// Context checkpoint created
     ContextCheckpointed {
         agent_id: String,
@@ -293,6 +328,10 @@ pub struct Event {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub instance_id: Option<String>,
+    /// Links a start event to its completion/failure (set by the emitter)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub correlation_id: Option<String>,
     /// Event payload
     #[serde(flatten)]
     pub event_type: EventType,
@@ -304,6 +343,7 @@ impl Event {
         Self {
             id: uuid::Uuid::new_v4().to_string(),
             instance_id: None,
+            correlation_id: None,
             event_type,
         }
     }
@@ -313,10 +353,17 @@ impl Event {
         Self {
             id: uuid::Uuid::new_v4().to_string(),
             instance_id: Some(instance_id),
+            correlation_id: None,
             event_type,
         }
     }
 
+    /// Attach a correlation ID shared by an operation's start and end events
+    pub fn with_correlation_id(mut self, correlation_id: impl Into<String>) -> Self {
+        self.correlation_id = Some(correlation_id.into());
+        self
+    }
+
     /// Create agent started event
     pub fn agent_started(agent_id: String) -> Self {
         Self::new(EventType::AgentStarted {
@@ -430,6 +477,50 @@ impl Event {
         })
     }
 
+    /// Create memory leak suspected event
+    pub fn memory_leak_suspected(
+        rss_bytes: u64,
+        growth_bytes_per_sec: f64,
+        duration_secs: u64,
+    ) -> Self {
+        Self::new(EventType::MemoryLeakSuspected {
+            rss_bytes,
+            growth_bytes_per_sec,
+            duration_secs,
+            timestamp: Utc::now(),
+        })
+    }
+
+    /// Create degradation changed event
+    pub fn degradation_changed(service: String, degraded: bool, reason: Option<String>) -> Self {
+        Self::new(EventType::DegradationChanged {
+            service,
+            degraded,
+            reason,
+            timestamp: Utc::now(),
+        })
+    }
+
+    /// Create LLM usage event
+    pub fn llm_usage(
+        model: String,
+        input_tokens: u64,
+        output_tokens: u64,
+        cost_usd: f64,
+        total_tokens: u64,
+        total_cost_usd: f64,
+    ) -> Self {
+        Self::new(EventType::LlmUsage {
+            model,
+            input_tokens,
+            output_tokens,
+            cost_usd,
+            total_tokens,
+            total_cost_usd,
+            timestamp: Utc::now(),
+        })
+    }
+
     /// Create session started event
     pub fn session_started(instance_id: String) -> Self {
         Self::new(EventType::SessionStarted {
@@ -463,6 +554,16 @@ impl Event {
         })
     }
 
+    /// Create conflict detected event
+    pub fn conflict_detected(files: Vec<String>, severity: String, agents: Vec<String>) -> Self {
+        Self::new(EventType::ConflictDetected {
+            files,
+            severity,
+            agents,
+            timestamp: Utc::now(),
+        })
+    }
+
     /// Create context checkpointed event
     pub fn context_checkpointed(agent_id: String, usage_percent: f32, snapshot_id: String) -> Self {
         Self::new(EventType::ContextCheckpointed {
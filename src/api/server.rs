@@ -4,6 +4,10 @@ use super::{
     events::{Event, EventBroadcaster},
     state::{AgentInfo, ContextFile, StateManager},
 };
+use crate::diagnostics::{global_degradation, Degradation};
+use crate::evolution::{BackgroundScheduler, JobError, JobReport, JobState, SchedulerError};
+use crate::health::{liveness, readiness, LivenessReport, ReadinessReport};
+use crate::storage::libsql::LibsqlStorage;
 use axum::{
     extract::State,
     http::StatusCode,
@@ -47,6 +51,10 @@ struct AppState {
     state: Arc<StateManager>,
     /// Instance ID
     instance_id: String,
+    /// Storage checked by `/readyz`
+    storage: Option<Arc<LibsqlStorage>>,
+    /// Scheduler behind `/evolution/*`
+    scheduler: Option<Arc<BackgroundScheduler>>,
 }
 
 /// API server
@@ -55,6 +63,8 @@ pub struct ApiServer {
     events: EventBroadcaster,
     state: Arc<StateManager>,
     instance_id: String,
+    storage: Option<Arc<LibsqlStorage>>,
+    scheduler: Option<Arc<BackgroundScheduler>>,
     /// Shutdown signal for background tasks
     shutdown_tx: tokio::sync::broadcast::Sender<()>,
     /// Heartbeat task handle for cleanup
@@ -74,6 +84,9 @@ impl ApiServer {
         state.subscribe_to_events(event_rx);
         info!("StateManager subscribed to event stream - state will update automatically");
 
+        // Publish degraded/recovered transitions from this process's services
+        global_degradation().set_broadcaster(events.clone());
+
         // Create shutdown channel for graceful task termination
         let (shutdown_tx, _) = tokio::sync::broadcast::channel(1);
 
@@ -82,11 +95,28 @@ impl ApiServer {
             events,
             state,
             instance_id,
+            storage: None,
+            scheduler: None,
             shutdown_tx,
             heartbeat_handle: None,
         }
     }
 
+    /// Report this storage's readiness on `/readyz`
+    ///
+    /// Without storage (e.g. the standalone event relay) the server is ready
+    /// as soon as it is serving.
+    pub fn with_storage(mut self, storage: Arc<LibsqlStorage>) -> Self {
+        self.storage = Some(storage);
+        self
+    }
+
+    /// Serve `/evolution/run` and `/evolution/status` from this scheduler
+    pub fn with_scheduler(mut self, scheduler: Arc<BackgroundScheduler>) -> Self {
+        self.scheduler = Some(scheduler);
+        self
+    }
+
     /// Get event broadcaster
     pub fn broadcaster(&self) -> &EventBroadcaster {
         &self.events
@@ -115,17 +145,28 @@ impl ApiServer {
             .route("/state/context-files", post(update_context_file_handler))
             .route("/state/stats", get(stats_handler))
             .route("/state/metrics", get(metrics_handler))
+            .route("/state/health", get(state_health_handler))
             // Time-series metrics endpoints
+            .route("/metrics/snapshot", get(metrics_snapshot_handler))
             .route("/metrics/agent-states", get(agent_states_series_handler))
             .route("/metrics/memory-ops", get(memory_ops_series_handler))
             .route("/metrics/skills", get(skills_series_handler))
             .route("/metrics/work", get(work_series_handler))
+            .route("/metrics/prometheus", get(prometheus_handler))
             // Health check
             .route("/health", get(health_handler))
+            .route("/healthz", get(healthz_handler))
+            .route("/readyz", get(readyz_handler))
+            // Any web page may read the routes above
+            .layer(CorsLayer::permissive())
+            // Evolution jobs rewrite memories, so they are added after the
+            // CORS layer: browsers refuse the preflight a cross-origin JSON
+            // POST needs, and non-JSON bodies are rejected
+            .route("/evolution/run", post(evolution_run_handler))
+            .route("/evolution/status", get(evolution_status_handler))
             // State
             .with_state(state)
             // Middleware
-            .layer(CorsLayer::permissive())
             .layer(TraceLayer::new_for_http())
     }
 
@@ -138,6 +179,8 @@ impl ApiServer {
             events: self.events.clone(),
             state: self.state.clone(),
             instance_id: self.instance_id.clone(),
+            storage: self.storage.clone(),
+            scheduler: self.scheduler.clone(),
         };
 
         let router = Self::build_router(state.clone());
@@ -375,6 +418,28 @@ async fn metrics_handler(State(state): State<AppState>) -> impl IntoResponse {
     Json(metrics)
 }
 
+/// Current metrics snapshot handler
+///
+/// Agent state counts are recomputed first so a freshly connected dashboard
+/// sees them without waiting for a stats poll.
+async fn metrics_snapshot_handler(State(state): State<AppState>) -> impl IntoResponse {
+    state.state.stats().await;
+    Json(state.state.metrics_snapshot().await)
+}
+
+/// Degraded services response
+#[derive(Debug, Serialize)]
+struct StateHealthResponse {
+    degraded: Vec<Degradation>,
+}
+
+/// Degradation state handler (empty `degraded` means fully operational)
+async fn state_health_handler() -> Json<StateHealthResponse> {
+    Json(StateHealthResponse {
+        degraded: global_degradation().snapshot(),
+    })
+}
+
 /// Agent states time-series handler
 async fn agent_states_series_handler(State(state): State<AppState>) -> impl IntoResponse {
     let metrics = state.state.metrics().await;
@@ -412,6 +477,17 @@ struct HealthResponse {
     subscribers: usize,
 }
 
+/// Operation latency percentiles in Prometheus text format
+async fn prometheus_handler() -> impl IntoResponse {
+    (
+        [(
+            axum::http::header::CONTENT_TYPE,
+            "text/plain; version=0.0.4",
+        )],
+        crate::diagnostics::global_latency().export_prometheus(),
+    )
+}
+
 async fn health_handler(State(state): State<AppState>) -> Json<HealthResponse> {
     Json(HealthResponse {
         status: "ok".to_string(),
@@ -421,6 +497,78 @@ async fn health_handler(State(state): State<AppState>) -> Json<HealthResponse> {
     })
 }
 
+/// Liveness probe: 200 while the process is serving
+async fn healthz_handler() -> Json<LivenessReport> {
+    Json(liveness())
+}
+
+/// Readiness probe: 200 when every dependency is ready, 503 otherwise
+async fn readyz_handler(State(state): State<AppState>) -> (StatusCode, Json<ReadinessReport>) {
+    let report = match &state.storage {
+        Some(storage) => readiness(storage).await,
+        None => ReadinessReport::from_dependencies(Vec::new()),
+    };
+    let code = if report.is_ready() {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    (code, Json(report))
+}
+
+/// Evolution run request body
+#[derive(Debug, Deserialize)]
+struct EvolutionRunRequest {
+    /// Registered job name (`consolidation`, `importance_recalibration`, ...)
+    job: String,
+}
+
+/// Evolution status response
+#[derive(Debug, Serialize)]
+struct EvolutionStatusResponse {
+    jobs: Vec<JobState>,
+}
+
+fn scheduler_of(state: &AppState) -> Result<&Arc<BackgroundScheduler>, (StatusCode, String)> {
+    state.scheduler.as_ref().ok_or((
+        StatusCode::SERVICE_UNAVAILABLE,
+        "No evolution scheduler on this server".to_string(),
+    ))
+}
+
+/// Run an evolution job now and return its report
+///
+/// 404 for an unregistered job, 409 while the same job is already running.
+async fn evolution_run_handler(
+    State(state): State<AppState>,
+    Json(request): Json<EvolutionRunRequest>,
+) -> Result<Json<JobReport>, (StatusCode, String)> {
+    let scheduler = scheduler_of(&state)?;
+    scheduler
+        .run_now(&request.job)
+        .await
+        .map(Json)
+        .map_err(|e| {
+            let code = match &e {
+                SchedulerError::UnknownJob(_) => StatusCode::NOT_FOUND,
+                SchedulerError::JobError(JobError::AlreadyRunning) => StatusCode::CONFLICT,
+                SchedulerError::JobError(JobError::ConfigError(_)) => StatusCode::BAD_REQUEST,
+                _ => StatusCode::INTERNAL_SERVER_ERROR,
+            };
+            (code, e.to_string())
+        })
+}
+
+/// Whether each evolution job is running, and its last run
+async fn evolution_status_handler(
+    State(state): State<AppState>,
+) -> Result<Json<EvolutionStatusResponse>, (StatusCode, String)> {
+    let scheduler = scheduler_of(&state)?;
+    Ok(Json(EvolutionStatusResponse {
+        jobs: scheduler.status(),
+    }))
+}
+
 impl Drop for ApiServer {
     fn drop(&mut self) {
         // Send shutdown signal to background tasks
@@ -452,10 +600,173 @@ mod tests {
             events: EventBroadcaster::default(),
             state: Arc::new(StateManager::new()),
             instance_id: "test-instance".to_string(),
+            storage: None,
+            scheduler: None,
         };
 
         let response = health_handler(State(state)).await;
         assert_eq!(response.0.status, "ok");
         assert_eq!(response.0.instance_id, "test-instance");
     }
+
+    #[tokio::test]
+    async fn test_probes_without_storage() {
+        let state = AppState {
+            events: EventBroadcaster::default(),
+            state: Arc::new(StateManager::new()),
+            instance_id: "test-instance".to_string(),
+            storage: None,
+            scheduler: None,
+        };
+
+        assert!(healthz_handler().await.0.alive);
+        let (code, report) = readyz_handler(State(state)).await;
+        assert_eq!(code, StatusCode::OK);
+        assert!(report.0.dependencies.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_evolution_routes_refuse_cross_origin_requests() {
+        let state = AppState {
+            events: EventBroadcaster::default(),
+            state: Arc::new(StateManager::new()),
+            instance_id: "test-instance".to_string(),
+            storage: None,
+            scheduler: None,
+        };
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            axum::serve(listener, ApiServer::build_router(state))
+                .await
+                .unwrap();
+        });
+
+        let client = reqwest::Client::new();
+        let preflight = |path: &str| {
+            client
+                .request(reqwest::Method::OPTIONS, format!("{}{}", base, path))
+                .header("Origin", "https://attacker.example")
+                .header("Access-Control-Request-Method", "POST")
+                .header("Access-Control-Request-Headers", "content-type")
+                .send()
+        };
+        let allowed_origin = |response: &reqwest::Response| {
+            response
+                .headers()
+                .contains_key("access-control-allow-origin")
+        };
+
+        assert!(allowed_origin(&preflight("/events/emit").await.unwrap()));
+        assert!(!allowed_origin(&preflight("/evolution/run").await.unwrap()));
+
+        // A form-style POST needs no preflight, so it must not run a job
+        let simple = client
+            .post(format!("{}/evolution/run", base))
+            .header("Origin", "https://attacker.example")
+            .header("Content-Type", "text/plain")
+            .body(r#"{"job": "link_decay"}"#)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(simple.status().as_u16(), 415);
+        assert!(!allowed_origin(&simple));
+    }
+
+    /// Job that holds its run open until released
+    struct GatedJob {
+        gate: Arc<tokio::sync::Notify>,
+    }
+
+    #[async_trait::async_trait]
+    impl crate::evolution::EvolutionJob for GatedJob {
+        fn name(&self) -> &str {
+            "test_gated"
+        }
+
+        async fn run(&self, _config: &crate::evolution::JobConfig) -> Result<JobReport, JobError> {
+            self.gate.notified().await;
+            Ok(JobReport {
+                memories_processed: 3,
+                changes_made: 1,
+                duration: std::time::Duration::from_millis(1),
+                errors: 0,
+                error_message: None,
+            })
+        }
+
+        async fn should_run(&self) -> Result<bool, JobError> {
+            Ok(false)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_evolution_run_and_status() {
+        let gate = Arc::new(tokio::sync::Notify::new());
+        let mut scheduler = BackgroundScheduler::new(Default::default());
+        scheduler.register_job(Arc::new(GatedJob { gate: gate.clone() }));
+        let state = AppState {
+            events: EventBroadcaster::default(),
+            state: Arc::new(StateManager::new()),
+            instance_id: "test-instance".to_string(),
+            storage: None,
+            scheduler: Some(Arc::new(scheduler)),
+        };
+        let run = |job: &str| {
+            evolution_run_handler(
+                State(state.clone()),
+                Json(EvolutionRunRequest {
+                    job: job.to_string(),
+                }),
+            )
+        };
+
+        let status = evolution_status_handler(State(state.clone()))
+            .await
+            .unwrap();
+        assert!(!status.0.jobs[0].running);
+        assert!(status.0.jobs[0].last_run.is_none());
+
+        let unknown = run("no_such_job").await.unwrap_err();
+        assert_eq!(unknown.0, StatusCode::NOT_FOUND);
+
+        // A second trigger while the first is running is rejected
+        let first = tokio::spawn(run("test_gated"));
+        while !evolution_status_handler(State(state.clone()))
+            .await
+            .unwrap()
+            .0
+            .jobs[0]
+            .running
+        {
+            tokio::task::yield_now().await;
+        }
+        let second = run("test_gated").await.unwrap_err();
+        assert_eq!(second.0, StatusCode::CONFLICT);
+
+        gate.notify_one();
+        let report = first.await.unwrap().unwrap();
+        assert_eq!(report.0.memories_processed, 3);
+
+        let status = evolution_status_handler(State(state.clone()))
+            .await
+            .unwrap();
+        let job = &status.0.jobs[0];
+        assert_eq!(job.name, "test_gated");
+        assert!(!job.running);
+        assert!(job.last_run.as_ref().unwrap().completed_at.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_evolution_without_scheduler() {
+        let state = AppState {
+            events: EventBroadcaster::default(),
+            state: Arc::new(StateManager::new()),
+            instance_id: "test-instance".to_string(),
+            storage: None,
+            scheduler: None,
+        };
+        let error = evolution_status_handler(State(state)).await.unwrap_err();
+        assert_eq!(error.0, StatusCode::SERVICE_UNAVAILABLE);
+    }
 }
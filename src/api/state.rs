@@ -409,6 +409,19 @@ impl StateManager {
                 tracing::warn!("Deadlock detected: {} items blocked", blocked_items.len());
                 // Could track deadlocks in state for dashboard display
             }
+            EventType::ConflictDetected {
+                files,
+                severity,
+                agents,
+                ..
+            } => {
+                tracing::warn!(
+                    "{} conflict between {} agents on {} files",
+                    severity,
+                    agents.len(),
+                    files.len()
+                );
+            }
             EventType::ContextCheckpointed {
                 agent_id,
                 usage_percent,
@@ -759,6 +772,9 @@ impl StateManager {
             }
 
             EventType::HealthUpdate { .. }
+            | EventType::MemoryLeakSuspected { .. }
+            | EventType::DegradationChanged { .. }
+            | EventType::LlmUsage { .. }
             | EventType::SessionStarted { .. }
             | EventType::SessionEnded { .. }
             | EventType::CliCommandStarted { .. }
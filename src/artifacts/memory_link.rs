@@ -1,12 +1,37 @@
 //! Memory entry creation for artifacts
 //!
-//! Phase 1: Basic memory creation without complex linking.
-//! Link functionality will be added in Phase 2 when implementing slash commands.
+//! Every saved artifact has one searchable `Reference` memory pointing back
+//! at its file (via `related_files`). Re-saving an artifact updates that
+//! memory in place, and references between artifacts become graph links
+//! between their memories.
 
 use crate::error::Result;
 use crate::storage::StorageBackend;
-use crate::types::{MemoryId, MemoryNote, MemoryType, Namespace};
+use crate::types::{LinkType, MemoryId, MemoryLink, MemoryNote, MemoryType, Namespace};
 use std::sync::Arc;
+use tracing::debug;
+
+/// Link strength between artifact memories (e.g. plan -> spec)
+const ARTIFACT_LINK_STRENGTH: f32 = 0.8;
+
+/// Searchable memory content describing one artifact
+#[derive(Debug, Clone)]
+pub struct ArtifactMemoryEntry {
+    /// Artifact path relative to the project (e.g. `.mnemosyne/artifacts/specs/x.md`)
+    pub artifact_path: String,
+    /// Human-readable artifact name
+    pub name: String,
+    /// Searchable summary of the artifact
+    pub content: String,
+    /// Memory namespace
+    pub namespace: Namespace,
+    /// Importance (1-10)
+    pub importance: u8,
+    /// Categorization tags
+    pub tags: Vec<String>,
+    /// Memory IDs of artifacts this one builds on
+    pub references: Vec<String>,
+}
 
 /// Memory linker for creating artifact memory entries
 pub struct MemoryLinker {
@@ -19,32 +44,55 @@ impl MemoryLinker {
         Self { storage }
     }
 
-    /// Create a memory entry for an artifact
-    pub async fn create_artifact_memory(
+    /// Create or update the memory entry for an artifact
+    ///
+    /// Updates `existing` when it still resolves to a memory; otherwise a new
+    /// `Reference` memory is created. Returns the memory ID either way.
+    pub async fn upsert_artifact_memory(
         &self,
-        memory_type: MemoryType,
-        content: String,
-        namespace: Namespace,
-        artifact_path: String,
-        importance: u8,
-        tags: Vec<String>,
+        existing: Option<MemoryId>,
+        entry: ArtifactMemoryEntry,
     ) -> Result<MemoryId> {
+        let links = self.reference_links(&entry).await;
         let now = chrono::Utc::now();
+
+        if let Some(id) = existing {
+            match self.storage.get_memory(id).await {
+                Ok(mut memory) => {
+                    memory.updated_at = now;
+                    memory.content = entry.content;
+                    memory.summary = format!("Artifact: {}", entry.name);
+                    memory.keywords = vec![entry.name];
+                    memory.tags = entry.tags;
+                    memory.context = artifact_context(&entry.artifact_path);
+                    memory.importance = entry.importance;
+                    memory.links = links;
+                    if !memory.related_files.contains(&entry.artifact_path) {
+                        memory.related_files.push(entry.artifact_path);
+                    }
+
+                    self.storage.update_memory(&memory).await?;
+                    return Ok(memory.id);
+                }
+                Err(e) => debug!("Artifact memory {} not found, recreating: {}", id, e),
+            }
+        }
+
         let memory = MemoryNote {
             id: MemoryId::new(),
-            namespace,
+            namespace: entry.namespace,
             created_at: now,
             updated_at: now,
-            content: content.clone(),
-            summary: format!("Artifact: {}", artifact_path),
-            keywords: Vec::new(),
-            tags,
-            context: format!("Specification artifact stored at {}", artifact_path),
-            memory_type,
-            importance,
+            content: entry.content,
+            summary: format!("Artifact: {}", entry.name),
+            keywords: vec![entry.name],
+            tags: entry.tags,
+            context: artifact_context(&entry.artifact_path),
+            memory_type: MemoryType::Reference,
+            importance: entry.importance,
             confidence: 1.0,
-            links: Vec::new(),
-            related_files: Vec::new(),
+            links,
+            related_files: vec![entry.artifact_path],
             related_entities: Vec::new(),
             access_count: 0,
             last_accessed_at: now,
@@ -59,7 +107,35 @@ impl MemoryLinker {
         Ok(memory.id)
     }
 
-    // TODO Phase 2: Add link_artifacts, create_workflow_links, etc.
+    /// Links to referenced artifact memories that still exist
+    async fn reference_links(&self, entry: &ArtifactMemoryEntry) -> Vec<MemoryLink> {
+        let mut links = Vec::new();
+        for reference in &entry.references {
+            let Ok(target_id) = MemoryId::from_string(reference) else {
+                debug!("Skipping non-memory artifact reference: {}", reference);
+                continue;
+            };
+            if self.storage.get_memory(target_id).await.is_err() {
+                debug!("Skipping missing artifact reference: {}", reference);
+                continue;
+            }
+
+            links.push(MemoryLink {
+                target_id,
+                link_type: LinkType::References,
+                strength: ARTIFACT_LINK_STRENGTH,
+                reason: format!("{} builds on this artifact", entry.name),
+                created_at: chrono::Utc::now(),
+                last_traversed_at: None,
+                user_created: false,
+            });
+        }
+        links
+    }
+}
+
+fn artifact_context(artifact_path: &str) -> String {
+    format!("Specification artifact stored at {}", artifact_path)
 }
 
 #[cfg(test)]
@@ -17,25 +17,31 @@
 //!
 //! Artifacts are stored as:
 //! 1. **Files**: Human-readable markdown in `.mnemosyne/artifacts/`
-//! 2. **Memory Entries**: Searchable entries in database with `artifact_path` field
-//! 3. **Graph Links**: Relationships between artifacts and code
+//! 2. **Memory Entries**: One searchable `Reference` memory per artifact, with the
+//!    artifact path in `related_files`; re-saving updates it in place
+//! 3. **Graph Links**: `References` links from an artifact's memory to the
+//!    memories of artifacts it builds on
 //!
 //! # Example
 //!
 //! ```no_run
-//! use mnemosyne_core::artifacts::{Constitution, ArtifactStorage};
+//! use mnemosyne_core::artifacts::{ArtifactWorkflow, Constitution};
+//! use mnemosyne_core::storage::StorageBackend;
+//! use mnemosyne_core::types::Namespace;
+//! use std::sync::Arc;
 //!
-//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
-//! let storage = ArtifactStorage::new(".mnemosyne/artifacts")?;
+//! # async fn example(memory: Arc<dyn StorageBackend>) -> Result<(), Box<dyn std::error::Error>> {
+//! let workflow = ArtifactWorkflow::new(".mnemosyne/artifacts".into(), memory)?;
 //!
 //! // Create constitution
-//! let constitution = Constitution::new(
-//!     "mnemosyne".to_string(),
-//!     vec!["Performance First".to_string()],
-//! );
+//! let mut constitution = Constitution::builder("mnemosyne".to_string())
+//!     .principle("Performance First")
+//!     .build();
 //!
-//! // Save to file and create memory entry
-//! storage.save_constitution(&constitution).await?;
+//! // Save to file and create (or update) its memory entry
+//! workflow
+//!     .save_constitution(&mut constitution, Namespace::Global)
+//!     .await?;
 //! # Ok(())
 //! # }
 //! ```
@@ -56,9 +62,9 @@ pub use checklist::{ChecklistItem, ChecklistSection, QualityChecklist};
 pub use clarification::{Clarification, ClarificationItem};
 pub use constitution::{Constitution, ConstitutionBuilder};
 pub use feature_spec::{FeatureSpec, FeatureSpecBuilder, UserScenario};
-pub use memory_link::MemoryLinker;
+pub use memory_link::{ArtifactMemoryEntry, MemoryLinker};
 pub use plan::{ArchitectureDecision, ImplementationPlan};
 pub use storage::{parse_frontmatter, serialize_frontmatter, ArtifactStorage};
 pub use tasks::{Task, TaskBreakdown, TaskPhase};
 pub use types::{Artifact, ArtifactMetadata, ArtifactStatus, ArtifactType, ArtifactVersion};
-pub use workflow::ArtifactWorkflow;
+pub use workflow::{allowed_transitions, transition, validate_transition, ArtifactWorkflow};
@@ -6,6 +6,7 @@ use super::types::{Artifact, ArtifactMetadata, ArtifactType};
 use crate::error::{MnemosyneError, Result};
 use regex::Regex;
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
 use std::path::PathBuf;
 
 /// Parse task phases from markdown
@@ -215,6 +216,106 @@ impl TaskBreakdown {
         self.phases.push(phase);
         self.metadata.update_timestamp();
     }
+
+    /// All tasks across phases, in document order
+    pub fn tasks(&self) -> impl Iterator<Item = &Task> {
+        self.phases.iter().flat_map(|phase| phase.tasks.iter())
+    }
+
+    /// Tasks ordered so every task comes after the tasks it depends on
+    ///
+    /// Ties keep document order. Fails on duplicate task IDs, dependencies on
+    /// unknown tasks, or a dependency cycle (reporting the cycle path).
+    pub fn topological_order(&self) -> Result<Vec<&Task>> {
+        let tasks: Vec<&Task> = self.tasks().collect();
+
+        let mut index: HashMap<&str, usize> = HashMap::new();
+        for (i, task) in tasks.iter().enumerate() {
+            if index.insert(task.id.as_str(), i).is_some() {
+                return Err(MnemosyneError::ValidationError(format!(
+                    "Duplicate task ID: {}",
+                    task.id
+                )));
+            }
+        }
+
+        // dependents[i] = tasks that wait on task i
+        let mut dependents: Vec<Vec<usize>> = vec![Vec::new(); tasks.len()];
+        let mut pending: Vec<usize> = vec![0; tasks.len()];
+        for (i, task) in tasks.iter().enumerate() {
+            for dep in &task.depends_on {
+                let &d = index.get(dep.as_str()).ok_or_else(|| {
+                    MnemosyneError::ValidationError(format!(
+                        "Task {} depends on unknown task {}",
+                        task.id, dep
+                    ))
+                })?;
+                dependents[d].push(i);
+                pending[i] += 1;
+            }
+        }
+
+        // Kahn's algorithm; the ready queue stays in document order
+        let mut ready: VecDeque<usize> = (0..tasks.len()).filter(|&i| pending[i] == 0).collect();
+        let mut order = Vec::with_capacity(tasks.len());
+        while let Some(i) = ready.pop_front() {
+            order.push(tasks[i]);
+            for &next in &dependents[i] {
+                pending[next] -= 1;
+                if pending[next] == 0 {
+                    ready.push_back(next);
+                }
+            }
+        }
+
+        if order.len() < tasks.len() {
+            let cycle = find_cycle(&tasks, &index, &pending);
+            return Err(MnemosyneError::ValidationError(format!(
+                "Task dependency cycle: {}",
+                cycle.join(" -> ")
+            )));
+        }
+
+        Ok(order)
+    }
+}
+
+/// Find one dependency cycle among tasks Kahn's algorithm couldn't schedule
+///
+/// Returns the task IDs along the cycle, starting and ending on the same task.
+fn find_cycle(tasks: &[&Task], index: &HashMap<&str, usize>, pending: &[usize]) -> Vec<String> {
+    // Every unscheduled task has an unscheduled dependency, so following
+    // those edges from any of them must revisit a task.
+    let Some(start) = (0..tasks.len()).find(|&i| pending[i] > 0) else {
+        return Vec::new();
+    };
+
+    let mut path = vec![start];
+    let mut seen_at: HashMap<usize, usize> = HashMap::from([(start, 0)]);
+    let mut current = start;
+    loop {
+        let next = tasks[current]
+            .depends_on
+            .iter()
+            .filter_map(|dep| index.get(dep.as_str()).copied())
+            .find(|&d| pending[d] > 0)
+            .expect("unscheduled task has an unscheduled dependency");
+
+        if let Some(&pos) = seen_at.get(&next) {
+            // Dependencies point backwards; reverse to read in execution order
+            let mut cycle: Vec<String> = path[pos..]
+                .iter()
+                .rev()
+                .map(|&i| tasks[i].id.clone())
+                .collect();
+            cycle.push(cycle[0].clone());
+            return cycle;
+        }
+
+        seen_at.insert(next, path.len());
+        path.push(next);
+        current = next;
+    }
 }
 
 impl Artifact for TaskBreakdown {
@@ -297,6 +398,16 @@ impl Artifact for TaskBreakdown {
             content: markdown.to_string(),
         })
     }
+
+    fn validate(&self) -> Result<()> {
+        if self.metadata.id.is_empty() {
+            return Err(MnemosyneError::Other(
+                "Artifact ID cannot be empty".to_string(),
+            ));
+        }
+        // Dependencies must form a DAG so some execution order exists
+        self.topological_order().map(|_| ())
+    }
 }
 
 #[cfg(test)]
@@ -539,4 +650,111 @@ mod tests {
         assert_eq!(phases[1].tasks[0].story, Some("story".to_string()));
         assert_eq!(phases[1].tasks[0].depends_on, vec!["T001", "T002"]);
     }
+
+    fn task(id: &str, depends_on: &[&str]) -> Task {
+        Task {
+            id: id.to_string(),
+            description: format!("Task {}", id),
+            parallelizable: false,
+            story: None,
+            completed: false,
+            depends_on: depends_on.iter().map(|d| d.to_string()).collect(),
+        }
+    }
+
+    fn breakdown(tasks: Vec<Task>) -> TaskBreakdown {
+        let mut breakdown = TaskBreakdown::new("graph".to_string(), "Graph".to_string());
+        breakdown.add_phase(TaskPhase {
+            name: "All".to_string(),
+            tasks,
+        });
+        breakdown
+    }
+
+    fn order_ids(breakdown: &TaskBreakdown) -> Vec<&str> {
+        breakdown
+            .topological_order()
+            .unwrap()
+            .into_iter()
+            .map(|t| t.id.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_topological_order_linear_chain() {
+        // Listed in reverse so document order alone would be wrong
+        let tasks = breakdown(vec![
+            task("T003", &["T002"]),
+            task("T002", &["T001"]),
+            task("T001", &[]),
+        ]);
+
+        assert_eq!(order_ids(&tasks), vec!["T001", "T002", "T003"]);
+        assert!(tasks.validate().is_ok());
+    }
+
+    #[test]
+    fn test_topological_order_diamond() {
+        let tasks = breakdown(vec![
+            task("T001", &[]),
+            task("T002", &["T001"]),
+            task("T003", &["T001"]),
+            task("T004", &["T002", "T003"]),
+        ]);
+
+        // Siblings keep document order
+        assert_eq!(order_ids(&tasks), vec!["T001", "T002", "T003", "T004"]);
+        assert!(tasks.validate().is_ok());
+    }
+
+    #[test]
+    fn test_topological_order_across_phases() {
+        let mut tasks = breakdown(vec![task("T001", &["T002"])]);
+        tasks.add_phase(TaskPhase {
+            name: "Setup".to_string(),
+            tasks: vec![task("T002", &[])],
+        });
+
+        assert_eq!(order_ids(&tasks), vec!["T002", "T001"]);
+    }
+
+    #[test]
+    fn test_cycle_reports_path() {
+        let tasks = breakdown(vec![
+            task("T001", &[]),
+            task("T002", &["T001", "T004"]),
+            task("T003", &["T002"]),
+            task("T004", &["T003"]),
+            task("T005", &["T004"]),
+        ]);
+
+        let err = tasks.validate().unwrap_err().to_string();
+        assert!(
+            err.contains("T002 -> T003 -> T004 -> T002")
+                || err.contains("T003 -> T004 -> T002 -> T003")
+                || err.contains("T004 -> T002 -> T003 -> T004"),
+            "{}",
+            err
+        );
+        assert!(!err.contains("T001"), "{}", err);
+        assert!(!err.contains("T005"), "{}", err);
+    }
+
+    #[test]
+    fn test_self_dependency_is_cycle() {
+        let tasks = breakdown(vec![task("T001", &["T001"])]);
+        let err = tasks.topological_order().unwrap_err().to_string();
+        assert!(err.contains("T001 -> T001"), "{}", err);
+    }
+
+    #[test]
+    fn test_unknown_dependency_and_duplicate_ids() {
+        let unknown = breakdown(vec![task("T001", &["T999"])]);
+        let err = unknown.validate().unwrap_err().to_string();
+        assert!(err.contains("unknown task T999"), "{}", err);
+
+        let duplicate = breakdown(vec![task("T001", &[]), task("T001", &[])]);
+        let err = duplicate.validate().unwrap_err().to_string();
+        assert!(err.contains("Duplicate task ID: T001"), "{}", err);
+    }
 }
@@ -17,15 +17,62 @@ pub enum ArtifactType {
 }
 
 /// Artifact status in the workflow
+///
+/// Allowed moves between statuses are defined in `artifacts::workflow`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum ArtifactStatus {
     Draft,
+    #[serde(rename = "in_review")]
+    InReview,
     Approved,
     Implemented,
+    Superseded,
     Deprecated,
 }
 
+impl ArtifactStatus {
+    /// Every status, in workflow order
+    pub const ALL: [ArtifactStatus; 6] = [
+        ArtifactStatus::Draft,
+        ArtifactStatus::InReview,
+        ArtifactStatus::Approved,
+        ArtifactStatus::Implemented,
+        ArtifactStatus::Superseded,
+        ArtifactStatus::Deprecated,
+    ];
+
+    /// Name as written in frontmatter
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ArtifactStatus::Draft => "draft",
+            ArtifactStatus::InReview => "in_review",
+            ArtifactStatus::Approved => "approved",
+            ArtifactStatus::Implemented => "implemented",
+            ArtifactStatus::Superseded => "superseded",
+            ArtifactStatus::Deprecated => "deprecated",
+        }
+    }
+}
+
+impl std::fmt::Display for ArtifactStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for ArtifactStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let normalized = s.trim().to_lowercase().replace('-', "_");
+        Self::ALL
+            .into_iter()
+            .find(|status| status.as_str() == normalized)
+            .ok_or_else(|| format!("Invalid artifact status: {}", s))
+    }
+}
+
 /// Semantic versioning for artifacts
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ArtifactVersion {
@@ -225,6 +272,22 @@ mod tests {
         assert_eq!(version.to_string(), "2.0.0");
     }
 
+    #[test]
+    fn test_artifact_status_round_trip() {
+        for status in ArtifactStatus::ALL {
+            let parsed: ArtifactStatus = status.to_string().parse().unwrap();
+            assert_eq!(parsed, status);
+
+            let yaml = serde_yaml::to_string(&status).unwrap();
+            assert_eq!(yaml.trim(), status.as_str());
+        }
+        assert_eq!(
+            "In-Review".parse::<ArtifactStatus>().unwrap(),
+            ArtifactStatus::InReview
+        );
+        assert!("shipped".parse::<ArtifactStatus>().is_err());
+    }
+
     #[test]
     fn test_metadata_creation() {
         let metadata = ArtifactMetadata::new(
@@ -5,17 +5,20 @@
 //! - Memory entry creation (via MemoryLinker)
 //! - Graph linking between artifacts and memories
 //!
+//! - Status transitions (Draft → InReview → Approved → Implemented →
+//!   Superseded, with Deprecated reachable from any non-terminal status)
+//!
 //! Use these functions to implement complete specification workflow operations.
 
-use super::memory_link::MemoryLinker;
-use super::storage::ArtifactStorage;
-use super::types::Artifact;
+use super::memory_link::{ArtifactMemoryEntry, MemoryLinker};
+use super::storage::{parse_frontmatter, ArtifactStorage};
+use super::types::{Artifact, ArtifactStatus};
 use super::{
     Clarification, Constitution, FeatureSpec, ImplementationPlan, QualityChecklist, TaskBreakdown,
 };
-use crate::error::Result;
+use crate::error::{MnemosyneError, Result};
 use crate::storage::StorageBackend;
-use crate::types::{MemoryId, MemoryType, Namespace};
+use crate::types::{MemoryId, Namespace};
 use crate::utils::string::truncate_at_char_boundary;
 use std::sync::Arc;
 
@@ -40,70 +43,35 @@ impl ArtifactWorkflow {
         })
     }
 
-    /// Save constitution artifact and create memory entry
+    /// Save constitution artifact and create or update its memory entry
     ///
-    /// This performs a complete constitution workflow:
-    /// 1. Serialize constitution to markdown with YAML frontmatter
-    /// 2. Write to .mnemosyne/artifacts/constitution/project-constitution.md
-    /// 3. Create memory entry with high importance (9)
-    /// 4. Update constitution metadata with memory ID
-    /// 5. Re-save constitution with memory ID reference
+    /// Writes .mnemosyne/artifacts/constitution/project-constitution.md with
+    /// a high-importance (9) memory entry.
     ///
     /// # Returns
-    /// Memory ID of the created memory entry
+    /// Memory ID of the artifact's memory entry
     pub async fn save_constitution(
         &self,
         constitution: &mut Constitution,
         namespace: Namespace,
     ) -> Result<MemoryId> {
-        // 1. Generate markdown
-        let markdown = constitution.to_markdown()?;
-
-        // 2. Save to file
-        let file_path = constitution.file_path();
-        self.storage.write_artifact(&file_path, &markdown).await?;
-
-        // 3. Create memory entry
-        let artifact_path = format!(".mnemosyne/artifacts/{}", file_path.display());
-        let content = format!(
-            "{}\n\nFull constitution: {}",
-            constitution.principles.join(" | "),
-            artifact_path
-        );
-
-        let memory_id = self
-            .memory_linker
-            .create_artifact_memory(
-                MemoryType::Constitution,
-                content,
-                namespace,
-                artifact_path,
-                9, // High importance for constitution
-                vec!["constitution".to_string(), "principles".to_string()],
-            )
-            .await?;
-
-        // 4. Update constitution with memory ID
-        constitution.metadata.memory_id = Some(memory_id.to_string());
-
-        // 5. Re-save with memory ID
-        let updated_markdown = constitution.to_markdown()?;
-        self.storage
-            .write_artifact(&file_path, &updated_markdown)
-            .await?;
-
-        Ok(memory_id)
+        let content = constitution.principles.join(" | ");
+
+        self.persist(
+            constitution,
+            namespace,
+            content,
+            9, // High importance for constitution
+            vec!["constitution".to_string(), "principles".to_string()],
+            None,
+        )
+        .await
     }
 
-    /// Save feature spec artifact and create memory entry with constitution link
+    /// Save feature spec artifact and create or update its memory entry
     ///
-    /// This performs a complete feature spec workflow:
-    /// 1. Serialize spec to markdown with YAML frontmatter
-    /// 2. Write to .mnemosyne/artifacts/specs/{feature-id}.md
-    /// 3. Create memory entry with importance 8
-    /// 4. Link to constitution memory if provided
-    /// 5. Update spec metadata with memory ID
-    /// 6. Re-save spec with memory ID reference
+    /// Writes .mnemosyne/artifacts/specs/{feature-id}.md with an
+    /// importance 8 memory entry, linked to the constitution memory if provided.
     ///
     /// # Arguments
     /// * `spec` - Feature specification to save
@@ -111,25 +79,15 @@ impl ArtifactWorkflow {
     /// * `constitution_memory_id` - Optional constitution memory to link to
     ///
     /// # Returns
-    /// Memory ID of the created memory entry
+    /// Memory ID of the artifact's memory entry
     pub async fn save_feature_spec(
         &self,
         spec: &mut FeatureSpec,
         namespace: Namespace,
         constitution_memory_id: Option<String>,
     ) -> Result<MemoryId> {
-        // 1. Generate markdown
-        let markdown = spec.to_markdown()?;
-
-        // 2. Save to file
-        let file_path = spec.file_path();
-        self.storage.write_artifact(&file_path, &markdown).await?;
-
-        // 3. Create memory entry
-        let artifact_path = format!(".mnemosyne/artifacts/{}", file_path.display());
-
         // Build summary from first scenario or requirements
-        let summary = if let Some(scenario) = spec.scenarios.first() {
+        let content = if let Some(scenario) = spec.scenarios.first() {
             format!("{}: {}", scenario.priority, scenario.goal)
         } else if let Some(req) = spec.requirements.first() {
             req.clone()
@@ -137,39 +95,21 @@ impl ArtifactWorkflow {
             spec.metadata.name.clone()
         };
 
-        let content = format!("{}\n\nFull specification: {}", summary, artifact_path);
-
         let tags = vec![
             "spec".to_string(),
             "feature".to_string(),
             spec.feature_id.clone(),
         ];
 
-        let memory_id = self
-            .memory_linker
-            .create_artifact_memory(
-                MemoryType::FeatureSpec,
-                content,
-                namespace,
-                artifact_path,
-                8, // High importance for specs
-                tags,
-            )
-            .await?;
-
-        // 4. Update spec with memory ID and constitution reference
-        spec.metadata.memory_id = Some(memory_id.to_string());
-        if let Some(constitution_id) = constitution_memory_id {
-            spec.metadata.references.push(constitution_id);
-        }
-
-        // 5. Re-save with memory ID
-        let updated_markdown = spec.to_markdown()?;
-        self.storage
-            .write_artifact(&file_path, &updated_markdown)
-            .await?;
-
-        Ok(memory_id)
+        self.persist(
+            spec,
+            namespace,
+            content,
+            8, // High importance for specs
+            tags,
+            constitution_memory_id,
+        )
+        .await
     }
 
     /// Load constitution from file
@@ -186,15 +126,10 @@ impl ArtifactWorkflow {
         FeatureSpec::from_markdown(&content)
     }
 
-    /// Save implementation plan artifact and create memory entry
+    /// Save implementation plan artifact and create or update its memory entry
     ///
-    /// This performs a complete plan workflow:
-    /// 1. Serialize plan to markdown with YAML frontmatter
-    /// 2. Write to .mnemosyne/artifacts/plans/{feature-id}-plan.md
-    /// 3. Create memory entry with importance 7
-    /// 4. Link to spec memory if provided
-    /// 5. Update plan metadata with memory ID
-    /// 6. Re-save plan with memory ID reference
+    /// Writes .mnemosyne/artifacts/plans/{feature-id}-plan.md with an
+    /// importance 7 memory entry, linked to the spec memory if provided.
     ///
     /// # Arguments
     /// * `plan` - Implementation plan to save
@@ -202,27 +137,15 @@ impl ArtifactWorkflow {
     /// * `spec_memory_id` - Optional spec memory to link to
     ///
     /// # Returns
-    /// Memory ID of the created memory entry
+    /// Memory ID of the artifact's memory entry
     pub async fn save_implementation_plan(
         &self,
         plan: &mut ImplementationPlan,
         namespace: Namespace,
         spec_memory_id: Option<String>,
     ) -> Result<MemoryId> {
-        // 1. Generate markdown
-        let markdown = plan.to_markdown()?;
-
-        // 2. Save to file
-        let file_path = plan.file_path();
-        self.storage.write_artifact(&file_path, &markdown).await?;
-
-        // 3. Create memory entry
-        let artifact_path = format!(".mnemosyne/artifacts/{}", file_path.display());
-
         // Build summary from approach
-        let summary = truncate_at_char_boundary(&plan.approach, 100);
-
-        let content = format!("{}\n\nFull plan: {}", summary, artifact_path);
+        let content = truncate_at_char_boundary(&plan.approach, 100);
 
         let tags = vec![
             "plan".to_string(),
@@ -230,31 +153,15 @@ impl ArtifactWorkflow {
             plan.feature_id.clone(),
         ];
 
-        let memory_id = self
-            .memory_linker
-            .create_artifact_memory(
-                MemoryType::ImplementationPlan,
-                content,
-                namespace,
-                artifact_path,
-                7, // Medium-high importance
-                tags,
-            )
-            .await?;
-
-        // 4. Update plan with memory ID and spec reference
-        plan.metadata.memory_id = Some(memory_id.to_string());
-        if let Some(spec_id) = spec_memory_id {
-            plan.metadata.references.push(spec_id);
-        }
-
-        // 5. Re-save with memory ID
-        let updated_markdown = plan.to_markdown()?;
-        self.storage
-            .write_artifact(&file_path, &updated_markdown)
-            .await?;
-
-        Ok(memory_id)
+        self.persist(
+            plan,
+            namespace,
+            content,
+            7, // Medium-high importance
+            tags,
+            spec_memory_id,
+        )
+        .await
     }
 
     /// Load implementation plan from file
@@ -264,15 +171,10 @@ impl ArtifactWorkflow {
         ImplementationPlan::from_markdown(&content)
     }
 
-    /// Save task breakdown artifact and create memory entry
+    /// Save task breakdown artifact and create or update its memory entry
     ///
-    /// This performs a complete task breakdown workflow:
-    /// 1. Serialize tasks to markdown with YAML frontmatter
-    /// 2. Write to .mnemosyne/artifacts/tasks/{feature-id}-tasks.md
-    /// 3. Create memory entry with importance 7
-    /// 4. Link to plan memory if provided
-    /// 5. Update tasks metadata with memory ID
-    /// 6. Re-save tasks with memory ID reference
+    /// Writes .mnemosyne/artifacts/tasks/{feature-id}-tasks.md with an
+    /// importance 7 memory entry, linked to the plan memory if provided.
     ///
     /// # Arguments
     /// * `tasks` - Task breakdown to save
@@ -280,28 +182,16 @@ impl ArtifactWorkflow {
     /// * `plan_memory_id` - Optional plan memory to link to
     ///
     /// # Returns
-    /// Memory ID of the created memory entry
+    /// Memory ID of the artifact's memory entry
     pub async fn save_task_breakdown(
         &self,
         tasks: &mut TaskBreakdown,
         namespace: Namespace,
         plan_memory_id: Option<String>,
     ) -> Result<MemoryId> {
-        // 1. Generate markdown
-        let markdown = tasks.to_markdown()?;
-
-        // 2. Save to file
-        let file_path = tasks.file_path();
-        self.storage.write_artifact(&file_path, &markdown).await?;
-
-        // 3. Create memory entry
-        let artifact_path = format!(".mnemosyne/artifacts/{}", file_path.display());
-
         // Build summary from phases
         let total_tasks: usize = tasks.phases.iter().map(|p| p.tasks.len()).sum();
-        let summary = format!("{} phases, {} tasks", tasks.phases.len(), total_tasks);
-
-        let content = format!("{}\n\nFull tasks: {}", summary, artifact_path);
+        let content = format!("{} phases, {} tasks", tasks.phases.len(), total_tasks);
 
         let tags = vec![
             "tasks".to_string(),
@@ -309,31 +199,15 @@ impl ArtifactWorkflow {
             tasks.feature_id.clone(),
         ];
 
-        let memory_id = self
-            .memory_linker
-            .create_artifact_memory(
-                MemoryType::TaskBreakdown,
-                content,
-                namespace,
-                artifact_path,
-                7, // Medium-high importance
-                tags,
-            )
-            .await?;
-
-        // 4. Update tasks with memory ID and plan reference
-        tasks.metadata.memory_id = Some(memory_id.to_string());
-        if let Some(plan_id) = plan_memory_id {
-            tasks.metadata.references.push(plan_id);
-        }
-
-        // 5. Re-save with memory ID
-        let updated_markdown = tasks.to_markdown()?;
-        self.storage
-            .write_artifact(&file_path, &updated_markdown)
-            .await?;
-
-        Ok(memory_id)
+        self.persist(
+            tasks,
+            namespace,
+            content,
+            7, // Medium-high importance
+            tags,
+            plan_memory_id,
+        )
+        .await
     }
 
     /// Load task breakdown from file
@@ -343,15 +217,10 @@ impl ArtifactWorkflow {
         TaskBreakdown::from_markdown(&content)
     }
 
-    /// Save quality checklist artifact and create memory entry
+    /// Save quality checklist artifact and create or update its memory entry
     ///
-    /// This performs a complete checklist workflow:
-    /// 1. Serialize checklist to markdown with YAML frontmatter
-    /// 2. Write to .mnemosyne/artifacts/checklists/{feature-id}-checklist.md
-    /// 3. Create memory entry with importance 7
-    /// 4. Link to spec memory if provided
-    /// 5. Update checklist metadata with memory ID
-    /// 6. Re-save checklist with memory ID reference
+    /// Writes .mnemosyne/artifacts/checklists/{feature-id}-checklist.md with an
+    /// importance 7 memory entry, linked to the spec memory if provided.
     ///
     /// # Arguments
     /// * `checklist` - Quality checklist to save
@@ -359,64 +228,36 @@ impl ArtifactWorkflow {
     /// * `spec_memory_id` - Optional spec memory to link to
     ///
     /// # Returns
-    /// Memory ID of the created memory entry
+    /// Memory ID of the artifact's memory entry
     pub async fn save_quality_checklist(
         &self,
         checklist: &mut QualityChecklist,
         namespace: Namespace,
         spec_memory_id: Option<String>,
     ) -> Result<MemoryId> {
-        // 1. Generate markdown
-        let markdown = checklist.to_markdown()?;
-
-        // 2. Save to file
-        let file_path = checklist.file_path();
-        self.storage.write_artifact(&file_path, &markdown).await?;
-
-        // 3. Create memory entry
-        let artifact_path = format!(".mnemosyne/artifacts/{}", file_path.display());
-
         // Build summary from completion
         let completion = checklist.completion_percentage();
-        let summary = format!(
+        let content = format!(
             "{} sections, {:.1}% complete",
             checklist.sections.len(),
             completion
         );
 
-        let content = format!("{}\n\nFull checklist: {}", summary, artifact_path);
-
         let tags = vec![
             "checklist".to_string(),
             "quality".to_string(),
             checklist.feature_id.clone(),
         ];
 
-        let memory_id = self
-            .memory_linker
-            .create_artifact_memory(
-                MemoryType::QualityChecklist,
-                content,
-                namespace,
-                artifact_path,
-                7, // Medium-high importance
-                tags,
-            )
-            .await?;
-
-        // 4. Update checklist with memory ID and spec reference
-        checklist.metadata.memory_id = Some(memory_id.to_string());
-        if let Some(spec_id) = spec_memory_id {
-            checklist.metadata.references.push(spec_id);
-        }
-
-        // 5. Re-save with memory ID
-        let updated_markdown = checklist.to_markdown()?;
-        self.storage
-            .write_artifact(&file_path, &updated_markdown)
-            .await?;
-
-        Ok(memory_id)
+        self.persist(
+            checklist,
+            namespace,
+            content,
+            7, // Medium-high importance
+            tags,
+            spec_memory_id,
+        )
+        .await
     }
 
     /// Load quality checklist from file
@@ -426,15 +267,10 @@ impl ArtifactWorkflow {
         QualityChecklist::from_markdown(&content)
     }
 
-    /// Save clarification artifact and create memory entry
+    /// Save clarification artifact and create or update its memory entry
     ///
-    /// This performs a complete clarification workflow:
-    /// 1. Serialize clarification to markdown with YAML frontmatter
-    /// 2. Write to .mnemosyne/artifacts/clarifications/{feature-id}-clarifications.md
-    /// 3. Create memory entry with importance 6
-    /// 4. Link to spec memory if provided
-    /// 5. Update clarification metadata with memory ID
-    /// 6. Re-save clarification with memory ID reference
+    /// Writes .mnemosyne/artifacts/clarifications/{feature-id}-clarifications.md with an
+    /// importance 6 memory entry, linked to the spec memory if provided.
     ///
     /// # Arguments
     /// * `clarification` - Clarification to save
@@ -442,23 +278,13 @@ impl ArtifactWorkflow {
     /// * `spec_memory_id` - Optional spec memory to link to
     ///
     /// # Returns
-    /// Memory ID of the created memory entry
+    /// Memory ID of the artifact's memory entry
     pub async fn save_clarification(
         &self,
         clarification: &mut Clarification,
         namespace: Namespace,
         spec_memory_id: Option<String>,
     ) -> Result<MemoryId> {
-        // 1. Generate markdown
-        let markdown = clarification.to_markdown()?;
-
-        // 2. Save to file
-        let file_path = clarification.file_path();
-        self.storage.write_artifact(&file_path, &markdown).await?;
-
-        // 3. Create memory entry
-        let artifact_path = format!(".mnemosyne/artifacts/{}", file_path.display());
-
         // Build summary from completion status
         let total = clarification.items.len();
         let resolved = clarification
@@ -466,46 +292,28 @@ impl ArtifactWorkflow {
             .iter()
             .filter(|i| i.decision.is_some())
             .count();
-        let summary = format!(
+        let content = format!(
             "{} clarifications: {} resolved, {} pending",
             total,
             resolved,
             total - resolved
         );
 
-        let content = format!("{}\n\nFull clarifications: {}", summary, artifact_path);
-
         let tags = vec![
             "clarification".to_string(),
             "questions".to_string(),
             clarification.feature_id.clone(),
         ];
 
-        let memory_id = self
-            .memory_linker
-            .create_artifact_memory(
-                MemoryType::Clarification,
-                content,
-                namespace,
-                artifact_path,
-                6, // Medium importance (supplementary artifact)
-                tags,
-            )
-            .await?;
-
-        // 4. Update clarification with memory ID and spec reference
-        clarification.metadata.memory_id = Some(memory_id.to_string());
-        if let Some(spec_id) = spec_memory_id {
-            clarification.metadata.references.push(spec_id);
-        }
-
-        // 5. Re-save with memory ID
-        let updated_markdown = clarification.to_markdown()?;
-        self.storage
-            .write_artifact(&file_path, &updated_markdown)
-            .await?;
-
-        Ok(memory_id)
+        self.persist(
+            clarification,
+            namespace,
+            content,
+            6, // Medium importance (supplementary artifact)
+            tags,
+            spec_memory_id,
+        )
+        .await
     }
 
     /// Load clarification from file
@@ -534,17 +342,196 @@ impl ArtifactWorkflow {
         self.storage.list_artifacts("specs").await
     }
 
+    /// Write an artifact and upsert its memory entry
+    ///
+    /// The memory is updated in place when the artifact (or the file it will
+    /// overwrite) already records a memory ID, so re-saving never duplicates it.
+    async fn persist<A: Artifact>(
+        &self,
+        artifact: &mut A,
+        namespace: Namespace,
+        content: String,
+        importance: u8,
+        tags: Vec<String>,
+        reference: Option<String>,
+    ) -> Result<MemoryId> {
+        if let Some(reference) = reference {
+            if !artifact.metadata().references.contains(&reference) {
+                artifact.metadata_mut().references.push(reference);
+            }
+        }
+
+        let file_path = artifact.file_path();
+        let artifact_path = format!(".mnemosyne/artifacts/{}", file_path.display());
+        let existing = self.recorded_memory_id(artifact, &file_path).await;
+
+        let entry = ArtifactMemoryEntry {
+            content: format!("{}\n\nFull artifact: {}", content, artifact_path),
+            artifact_path,
+            name: artifact.metadata().name.clone(),
+            namespace,
+            importance,
+            tags,
+            references: artifact.metadata().references.clone(),
+        };
+        let memory_id = self
+            .memory_linker
+            .upsert_artifact_memory(existing, entry)
+            .await?;
+
+        artifact.metadata_mut().memory_id = Some(memory_id.to_string());
+        self.storage
+            .write_artifact(&file_path, &artifact.to_markdown()?)
+            .await?;
+
+        Ok(memory_id)
+    }
+
+    /// Memory ID recorded on the artifact, or in the file it would overwrite
+    async fn recorded_memory_id<A: Artifact>(
+        &self,
+        artifact: &A,
+        file_path: &std::path::Path,
+    ) -> Option<MemoryId> {
+        let recorded = match &artifact.metadata().memory_id {
+            Some(id) => Some(id.clone()),
+            None => {
+                let previous = self.storage.read_artifact(file_path).await.ok()?;
+                let (frontmatter, _) = parse_frontmatter(&previous).ok()?;
+                frontmatter
+                    .get("memory_id")
+                    .and_then(|v| v.as_str())
+                    .map(str::to_string)
+            }
+        };
+        recorded.and_then(|id| MemoryId::from_string(&id).ok())
+    }
+
     /// Get reference to storage for advanced operations
     pub fn storage(&self) -> &ArtifactStorage {
         &self.storage
     }
 }
 
+/// Statuses an artifact may move to from `from`
+///
+/// Superseded and Deprecated are terminal. Review can send a draft back for
+/// changes (InReview → Draft), but nothing skips review on the way to
+/// Approved.
+pub fn allowed_transitions(from: ArtifactStatus) -> &'static [ArtifactStatus] {
+    use crate::artifacts::types::ArtifactStatus::*;
+
+    match from {
+        Draft => &[InReview, Deprecated],
+        InReview => &[Draft, Approved, Deprecated],
+        Approved => &[Implemented, Superseded, Deprecated],
+        Implemented => &[Superseded, Deprecated],
+        Superseded | Deprecated => &[],
+    }
+}
+
+/// Check that moving from `from` to `to` is allowed
+pub fn validate_transition(from: ArtifactStatus, to: ArtifactStatus) -> Result<()> {
+    let allowed = allowed_transitions(from);
+    if allowed.contains(&to) {
+        return Ok(());
+    }
+
+    let reason = if allowed.is_empty() {
+        format!("{} is a terminal status", from)
+    } else {
+        let allowed: Vec<&str> = allowed.iter().map(|s| s.as_str()).collect();
+        format!("allowed from {}: {}", from, allowed.join(", "))
+    };
+    Err(MnemosyneError::ValidationError(format!(
+        "Illegal artifact transition {} -> {} ({})",
+        from, to, reason
+    )))
+}
+
+/// Move an artifact to `to`, rejecting illegal transitions
+///
+/// Artifacts without a recorded status are treated as drafts.
+pub fn transition<A: Artifact>(artifact: &mut A, to: ArtifactStatus) -> Result<()> {
+    let from = artifact.metadata().status.unwrap_or(ArtifactStatus::Draft);
+    validate_transition(from, to)?;
+
+    let metadata = artifact.metadata_mut();
+    metadata.status = Some(to);
+    metadata.update_timestamp();
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
+    use super::*;
+    use crate::artifacts::types::ArtifactStatus::*;
+    use crate::artifacts::Constitution;
+
+    const VALID: &[(ArtifactStatus, ArtifactStatus)] = &[
+        (Draft, InReview),
+        (Draft, Deprecated),
+        (InReview, Draft),
+        (InReview, Approved),
+        (InReview, Deprecated),
+        (Approved, Implemented),
+        (Approved, Superseded),
+        (Approved, Deprecated),
+        (Implemented, Superseded),
+        (Implemented, Deprecated),
+    ];
+
+    #[test]
+    fn test_transition_table() {
+        for from in ArtifactStatus::ALL {
+            for to in ArtifactStatus::ALL {
+                let expected = VALID.contains(&(from, to));
+                assert_eq!(
+                    validate_transition(from, to).is_ok(),
+                    expected,
+                    "{} -> {}",
+                    from,
+                    to
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_terminal_statuses_reject_everything() {
+        for terminal in [Superseded, Deprecated] {
+            assert!(allowed_transitions(terminal).is_empty());
+            for to in ArtifactStatus::ALL {
+                let err = validate_transition(terminal, to).unwrap_err();
+                assert!(err.to_string().contains("terminal"), "{}", err);
+            }
+        }
+    }
+
+    #[test]
+    fn test_transition_updates_artifact() {
+        let mut constitution = Constitution::builder("Test".to_string())
+            .principle("Quality")
+            .build();
+        assert_eq!(constitution.metadata.status, Some(Draft));
+
+        // Skipping review is rejected and leaves the status alone
+        assert!(transition(&mut constitution, Approved).is_err());
+        assert_eq!(constitution.metadata.status, Some(Draft));
+
+        transition(&mut constitution, InReview).unwrap();
+        transition(&mut constitution, Approved).unwrap();
+        assert_eq!(constitution.metadata.status, Some(Approved));
+    }
+
     #[test]
-    fn test_workflow_coordinator_creation() {
-        // Basic creation test
-        // Full integration tests require StorageBackend mock
+    fn test_missing_status_is_draft() {
+        let mut constitution = Constitution::builder("Test".to_string())
+            .principle("Quality")
+            .build();
+        constitution.metadata.status = None;
+
+        assert!(transition(&mut constitution, Approved).is_err());
+        transition(&mut constitution, InReview).unwrap();
     }
 }
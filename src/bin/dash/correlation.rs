@@ -2,6 +2,10 @@
 //!
 //! Transforms raw event streams into meaningful operation timelines by correlating
 //! start/complete events, calculating durations, and tracking outcomes.
+//!
+//! Events carrying a `correlation_id` are paired exactly, so overlapping runs of
+//! the same command stay distinct. Events without one fall back to matching by
+//! command name, agent ID, or work item.
 
 use chrono::{DateTime, Utc};
 use mnemosyne_core::api::events::{Event, EventType};
@@ -21,6 +25,15 @@ pub enum CorrelationKey {
     WorkItem(String),
 }
 
+/// Key for a pending operation
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum PendingKey {
+    /// Exact match on the emitter-assigned correlation ID
+    Id(String),
+    /// Name-based match for events without a correlation ID
+    Name(CorrelationKey),
+}
+
 /// Status of a correlated operation
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum OperationStatus {
@@ -113,7 +126,7 @@ impl CorrelatedEvent {
 /// Event correlation tracker
 pub struct CorrelationTracker {
     /// Pending operations (started but not completed)
-    pending: HashMap<CorrelationKey, CorrelatedEvent>,
+    pending: HashMap<PendingKey, CorrelatedEvent>,
     /// Completed operations (for history)
     completed: Vec<CorrelatedEvent>,
     /// Maximum history to keep
@@ -135,12 +148,16 @@ impl CorrelationTracker {
         // Try to extract correlation info
         if let Some((key, _started, timestamp)) = Self::extract_start(&event) {
             // This is a start event - track it
-            let correlated = CorrelatedEvent::new(key.clone(), event, timestamp);
-            self.pending.insert(key, correlated);
+            let pending_key = match &event.correlation_id {
+                Some(id) => PendingKey::Id(id.clone()),
+                None => PendingKey::Name(key.clone()),
+            };
+            let correlated = CorrelatedEvent::new(key, event, timestamp);
+            self.pending.insert(pending_key, correlated);
             None
         } else if let Some((key, timestamp, failed)) = Self::extract_end(&event) {
             // This is an end event - try to match with pending
-            if let Some(mut correlated) = self.pending.remove(&key) {
+            if let Some(mut correlated) = self.take_pending(event.correlation_id.as_deref(), &key) {
                 correlated.complete(event, timestamp, failed);
 
                 // Add to completed history
@@ -162,6 +179,34 @@ impl CorrelationTracker {
         }
     }
 
+    /// Remove the pending operation an end event closes
+    ///
+    /// Prefers the correlation ID; otherwise matches by key, taking the oldest
+    /// pending operation with that key when several overlap.
+    fn take_pending(
+        &mut self,
+        correlation_id: Option<&str>,
+        key: &CorrelationKey,
+    ) -> Option<CorrelatedEvent> {
+        if let Some(id) = correlation_id {
+            if let Some(correlated) = self.pending.remove(&PendingKey::Id(id.to_string())) {
+                return Some(correlated);
+            }
+        }
+
+        if let Some(correlated) = self.pending.remove(&PendingKey::Name(key.clone())) {
+            return Some(correlated);
+        }
+
+        let oldest = self
+            .pending
+            .iter()
+            .filter(|(_, correlated)| &correlated.key == key)
+            .min_by_key(|(_, correlated)| correlated.started_at)
+            .map(|(pending_key, _)| pending_key.clone())?;
+        self.pending.remove(&oldest)
+    }
+
     /// Extract start event information
     fn extract_start(event: &Event) -> Option<(CorrelationKey, bool, DateTime<Utc>)> {
         match &event.event_type {
@@ -379,6 +424,56 @@ mod tests {
         assert_eq!(correlated.status, OperationStatus::Failed);
     }
 
+    #[test]
+    fn test_concurrent_same_command_correlates_by_id() {
+        let mut tracker = CorrelationTracker::new(100);
+
+        // Two overlapping recalls
+        tracker.process(create_cli_started("recall").with_correlation_id("first"));
+        tracker.process(create_cli_started("recall").with_correlation_id("second"));
+        assert_eq!(tracker.pending.len(), 2);
+
+        // The second finishes first, and fails
+        let second = tracker
+            .process(create_cli_failed("recall").with_correlation_id("second"))
+            .unwrap();
+        assert_eq!(second.start.correlation_id.as_deref(), Some("second"));
+        assert_eq!(second.status, OperationStatus::Failed);
+
+        let first = tracker
+            .process(create_cli_completed("recall").with_correlation_id("first"))
+            .unwrap();
+        assert_eq!(first.start.correlation_id.as_deref(), Some("first"));
+        assert_eq!(first.status, OperationStatus::Completed);
+
+        assert!(tracker.pending.is_empty());
+        assert_eq!(tracker.completed.len(), 2);
+    }
+
+    #[test]
+    fn test_end_without_id_falls_back_to_name() {
+        let mut tracker = CorrelationTracker::new(100);
+
+        tracker.process(create_cli_started("recall").with_correlation_id("first"));
+        let later = Event::new(EventType::CliCommandStarted {
+            command: "recall".to_string(),
+            args: vec![],
+            timestamp: Utc::now() + chrono::Duration::seconds(1),
+        });
+        tracker.process(later.with_correlation_id("second"));
+
+        // Oldest pending run with the same name is closed
+        let correlated = tracker.process(create_cli_completed("recall")).unwrap();
+        assert_eq!(correlated.start.correlation_id.as_deref(), Some("first"));
+        assert_eq!(tracker.pending.len(), 1);
+
+        // An ID with no matching start still matches by name
+        tracker.process(create_cli_started("remember"));
+        assert!(tracker
+            .process(create_cli_completed("remember").with_correlation_id("unknown"))
+            .is_some());
+    }
+
     #[test]
     fn test_orphaned_end_event() {
         let mut tracker = CorrelationTracker::new(100);
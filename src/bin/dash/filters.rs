@@ -60,7 +60,8 @@ impl EventCategory {
             | EventType::AgentErrorRecorded { .. }
             | EventType::AgentHealthDegraded { .. }
             | EventType::ReviewFailed { .. }
-            | EventType::DeadlockDetected { .. } => Self::Error,
+            | EventType::DeadlockDetected { .. }
+            | EventType::ConflictDetected { .. } => Self::Error,
 
             // Skill events
             EventType::SkillLoaded { .. }
@@ -84,6 +85,9 @@ impl EventCategory {
 
             // System events
             EventType::HealthUpdate { .. }
+            | EventType::MemoryLeakSuspected { .. }
+            | EventType::DegradationChanged { .. }
+            | EventType::LlmUsage { .. }
             | EventType::SessionStarted { .. }
             | EventType::Heartbeat { .. }
             | EventType::DatabaseOperation { .. }
@@ -206,10 +210,14 @@ impl EventFilter {
             | ContextModified { timestamp, .. }
             | ContextValidated { timestamp, .. }
             | HealthUpdate { timestamp, .. }
+            | MemoryLeakSuspected { timestamp, .. }
+            | DegradationChanged { timestamp, .. }
+            | LlmUsage { timestamp, .. }
             | SessionStarted { timestamp, .. }
             | Heartbeat { timestamp, .. }
             | PhaseChanged { timestamp, .. }
             | DeadlockDetected { timestamp, .. }
+            | ConflictDetected { timestamp, .. }
             | ContextCheckpointed { timestamp, .. }
             | ReviewFailed { timestamp, .. }
             | WorkItemRetried { timestamp, .. }
@@ -45,7 +45,7 @@ use crossterm::{
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
-use mnemosyne_core::api::events::Event;
+use mnemosyne_core::api::{events::Event, MetricsSnapshot};
 use panel_manager::{PanelId, PanelManager};
 use panels::{
     ActivityStreamPanel, AgentInfo, AgentsPanel, FocusMode, NetworkPanel, OperationsPanel,
@@ -155,8 +155,11 @@ impl App {
 
                 // CLI events → Operations panel
                 CliCommandStarted { command, args, .. } => {
-                    self.operations_panel
-                        .add_started(command.clone(), args.clone());
+                    self.operations_panel.add_started(
+                        command.clone(),
+                        args.clone(),
+                        event.correlation_id.clone(),
+                    );
                 }
                 CliCommandCompleted {
                     command,
@@ -166,6 +169,7 @@ impl App {
                 } => {
                     self.operations_panel.update_completed(
                         command,
+                        event.correlation_id.as_deref(),
                         *duration_ms,
                         result_summary.clone(),
                     );
@@ -176,8 +180,12 @@ impl App {
                     duration_ms,
                     ..
                 } => {
-                    self.operations_panel
-                        .update_failed(command, error.clone(), *duration_ms);
+                    self.operations_panel.update_failed(
+                        command,
+                        event.correlation_id.as_deref(),
+                        error.clone(),
+                        *duration_ms,
+                    );
                 }
 
                 _ => {
@@ -208,8 +216,19 @@ impl App {
             }
         }
 
-        // TODO: Fetch system metrics for SystemOverview
-        // For now, we'll rely on events to populate metrics
+        // Fetch metrics snapshot so the overview is populated before events arrive
+        match client
+            .get(format!("{}/metrics/snapshot", self.api_url))
+            .send()
+            .await
+        {
+            Ok(response) => {
+                if let Ok(snapshot) = response.json::<MetricsSnapshot>().await {
+                    self.system_overview.apply_snapshot(&snapshot);
+                }
+            }
+            Err(e) => debug!("Failed to fetch metrics snapshot: {}", e),
+        }
 
         Ok(())
     }
@@ -255,7 +274,7 @@ impl App {
             // Operations panel controls
             KeyCode::Char('v') => {
                 // Cycle view mode (List → Grouped → Statistics)
-                // Will be implemented by sub-agent
+                self.operations_panel.cycle_view_mode();
             }
 
             // Focus modes (filter presets)
@@ -548,7 +567,6 @@ impl App {
             Line::from(vec![
                 Span::styled("  v       ", Style::default().fg(Color::Green)),
                 Span::raw("Cycle view mode (List → Grouped → Statistics)"),
-                Span::styled(" [Coming Soon]", Style::default().fg(Color::DarkGray)),
             ]),
             Line::from(""),
             Line::from(vec![Span::styled(
@@ -773,3 +791,84 @@ async fn main() -> Result<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mnemosyne_core::api::{
+        state::{AgentInfo as ApiAgentInfo, AgentState},
+        ApiServer, ApiServerConfig,
+    };
+    use std::collections::HashMap;
+
+    /// A free local port for the API server under test
+    fn free_port() -> u16 {
+        std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port()
+    }
+
+    #[tokio::test]
+    async fn test_overview_populated_on_connect() {
+        let addr: std::net::SocketAddr = ([127, 0, 0, 1], free_port()).into();
+        let server = ApiServer::new(ApiServerConfig {
+            addr,
+            event_capacity: 100,
+        });
+        let state = server.state_manager().clone();
+        let events = server.broadcaster().clone();
+        tokio::spawn(server.serve());
+
+        for (id, agent_state) in [
+            (
+                "executor",
+                AgentState::Active {
+                    task: "build".to_string(),
+                },
+            ),
+            ("reviewer", AgentState::Idle),
+        ] {
+            state
+                .update_agent(ApiAgentInfo {
+                    id: id.to_string(),
+                    state: agent_state,
+                    updated_at: chrono::Utc::now(),
+                    metadata: HashMap::new(),
+                    health: None,
+                })
+                .await;
+        }
+        let _ = events.broadcast(Event::new(
+            mnemosyne_core::api::EventType::ParallelStreamStarted {
+                stream_id: "stream-1".to_string(),
+                task_count: 4,
+                timestamp: chrono::Utc::now(),
+            },
+        ));
+
+        // No SSE events are delivered to the app: metrics come from polling
+        let (_tx, rx) = mpsc::unbounded_channel();
+        let mut app = App::new(format!("http://{}", addr), rx);
+        let client = Client::new();
+
+        let mut populated = false;
+        for _ in 0..50 {
+            app.update_state(&client).await.unwrap();
+            let metrics = app.system_overview.metrics();
+            if app.connected && metrics.agents_total > 0 && metrics.tasks_total > 0 {
+                populated = true;
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+
+        assert!(populated, "overview never received a metrics snapshot");
+        let metrics = app.system_overview.metrics();
+        assert_eq!(metrics.agents_total, 2);
+        assert_eq!(metrics.agents_active, 1);
+        assert_eq!(metrics.agents_idle, 1);
+        assert_eq!(metrics.tasks_total, 4);
+    }
+}
@@ -247,10 +247,14 @@ impl ActivityStreamPanel {
             | ContextModified { timestamp, .. }
             | ContextValidated { timestamp, .. }
             | HealthUpdate { timestamp, .. }
+            | MemoryLeakSuspected { timestamp, .. }
+            | DegradationChanged { timestamp, .. }
+            | LlmUsage { timestamp, .. }
             | SessionStarted { timestamp, .. }
             | Heartbeat { timestamp, .. }
             | PhaseChanged { timestamp, .. }
             | DeadlockDetected { timestamp, .. }
+            | ConflictDetected { timestamp, .. }
             | ContextCheckpointed { timestamp, .. }
             | ReviewFailed { timestamp, .. }
             | WorkItemRetried { timestamp, .. }
@@ -567,6 +571,19 @@ impl ActivityStreamPanel {
             DeadlockDetected { blocked_items, .. } => {
                 format!("Deadlock: {} items blocked", blocked_items.len())
             }
+            ConflictDetected {
+                files,
+                severity,
+                agents,
+                ..
+            } => {
+                format!(
+                    "{} conflict: {} agents on {} files",
+                    severity,
+                    agents.len(),
+                    files.len()
+                )
+            }
             AgentHealthDegraded {
                 agent_id,
                 error_count,
@@ -596,6 +613,33 @@ impl ActivityStreamPanel {
                 )
             }
             HealthUpdate { .. } => "Health update".to_string(),
+            MemoryLeakSuspected {
+                growth_bytes_per_sec,
+                ..
+            } => format!(
+                "Memory leak suspected: +{:.1} MB/min",
+                growth_bytes_per_sec * 60.0 / 1_048_576.0
+            ),
+            DegradationChanged {
+                service,
+                degraded: true,
+                reason,
+                ..
+            } => format!(
+                "Degraded: {} ({})",
+                service,
+                Self::truncate(reason.as_deref().unwrap_or("unknown"), 40)
+            ),
+            DegradationChanged { service, .. } => format!("Recovered: {}", service),
+            LlmUsage {
+                input_tokens,
+                output_tokens,
+                total_cost_usd,
+                ..
+            } => format!(
+                "LLM call: {} in / {} out tokens (${:.4} total)",
+                input_tokens, output_tokens, total_cost_usd
+            ),
             Heartbeat { .. } => "Heartbeat".to_string(),
 
             // Fallback for other events
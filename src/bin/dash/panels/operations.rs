@@ -20,6 +20,8 @@ pub struct OperationEntry {
     pub duration_ms: Option<u64>,
     pub result_summary: Option<String>,
     pub timestamp: DateTime<Utc>,
+    /// ID shared by the start and end events of one run
+    pub correlation_id: Option<String>,
 }
 
 /// Operation status
@@ -61,6 +63,17 @@ pub enum ViewMode {
     Statistics,
 }
 
+impl ViewMode {
+    /// Next mode in the List → Grouped → Statistics cycle
+    pub fn next(&self) -> Self {
+        match self {
+            ViewMode::List => ViewMode::Grouped,
+            ViewMode::Grouped => ViewMode::Statistics,
+            ViewMode::Statistics => ViewMode::List,
+        }
+    }
+}
+
 /// Filter options for operations
 #[derive(Debug, Clone, Default)]
 pub struct OperationFilter {
@@ -137,6 +150,14 @@ pub struct CommandStats {
     pub avg_duration_ms: u64,
     pub success_count: usize,
     pub failure_count: usize,
+    /// Sum of recorded durations
+    pub total_duration_ms: u64,
+    /// Median latency (nearest rank)
+    pub p50_duration_ms: u64,
+    /// 95th percentile latency (nearest rank)
+    pub p95_duration_ms: u64,
+    /// Failures as a percentage of finished runs
+    pub failure_rate: f64,
 }
 
 /// Operations panel widget
@@ -163,7 +184,12 @@ impl OperationsPanel {
     }
 
     /// Add a started operation
-    pub fn add_started(&mut self, command: String, args: Vec<String>) {
+    pub fn add_started(
+        &mut self,
+        command: String,
+        args: Vec<String>,
+        correlation_id: Option<String>,
+    ) {
         self.operations.push(OperationEntry {
             command,
             args,
@@ -171,6 +197,7 @@ impl OperationsPanel {
             duration_ms: None,
             result_summary: None,
             timestamp: Utc::now(),
+            correlation_id,
         });
 
         // Trim old operations
@@ -183,14 +210,14 @@ impl OperationsPanel {
     }
 
     /// Update operation to completed
-    pub fn update_completed(&mut self, command: &str, duration_ms: u64, result_summary: String) {
-        // Find most recent matching command
-        if let Some(op) = self
-            .operations
-            .iter_mut()
-            .rev()
-            .find(|op| op.command == command && matches!(op.status, OperationStatus::Running))
-        {
+    pub fn update_completed(
+        &mut self,
+        command: &str,
+        correlation_id: Option<&str>,
+        duration_ms: u64,
+        result_summary: String,
+    ) {
+        if let Some(op) = self.running_mut(command, correlation_id) {
             op.status = OperationStatus::Completed;
             op.duration_ms = Some(duration_ms);
             op.result_summary = Some(result_summary);
@@ -198,19 +225,37 @@ impl OperationsPanel {
     }
 
     /// Update operation to failed
-    pub fn update_failed(&mut self, command: &str, error: String, duration_ms: u64) {
-        // Find most recent matching command
-        if let Some(op) = self
-            .operations
-            .iter_mut()
-            .rev()
-            .find(|op| op.command == command && matches!(op.status, OperationStatus::Running))
-        {
+    pub fn update_failed(
+        &mut self,
+        command: &str,
+        correlation_id: Option<&str>,
+        error: String,
+        duration_ms: u64,
+    ) {
+        if let Some(op) = self.running_mut(command, correlation_id) {
             op.status = OperationStatus::Failed(error);
             op.duration_ms = Some(duration_ms);
         }
     }
 
+    /// Running operation an end event belongs to
+    ///
+    /// Events with a correlation ID only close the run that started with it;
+    /// events without one close the most recent running command of that name.
+    fn running_mut(
+        &mut self,
+        command: &str,
+        correlation_id: Option<&str>,
+    ) -> Option<&mut OperationEntry> {
+        self.operations.iter_mut().rev().find(|op| {
+            matches!(op.status, OperationStatus::Running)
+                && match correlation_id {
+                    Some(id) => op.correlation_id.as_deref() == Some(id),
+                    None => op.correlation_id.is_none() && op.command == command,
+                }
+        })
+    }
+
     /// Clear all operations
     pub fn clear(&mut self) {
         self.operations.clear();
@@ -381,6 +426,7 @@ impl OperationsPanel {
     fn render_grouped_view(&self, frame: &mut Frame, area: Rect) {
         let available_height = area.height.saturating_sub(3) as usize;
         let groups = self.group_operations();
+        let command_stats = self.calculate_stats().command_stats;
 
         if groups.is_empty() {
             let block = Block::default()
@@ -398,8 +444,18 @@ impl OperationsPanel {
                 let is_collapsed = self.collapsed_groups.get(cmd).copied().unwrap_or(false);
                 let collapse_indicator = if is_collapsed { "▶" } else { "▼" };
 
-                // Group header row
-                let header_text = format!("{} {} ({} ops)", collapse_indicator, cmd, ops.len());
+                // Group header row with aggregate durations
+                let header_text = match command_stats.get(cmd) {
+                    Some(stats) if stats.total_duration_ms > 0 => format!(
+                        "{} {} ({} ops, avg {}, total {})",
+                        collapse_indicator,
+                        cmd,
+                        ops.len(),
+                        Self::format_duration(stats.avg_duration_ms),
+                        Self::format_duration(stats.total_duration_ms)
+                    ),
+                    _ => format!("{} {} ({} ops)", collapse_indicator, cmd, ops.len()),
+                };
                 rows.push(Row::new(vec![Cell::from(header_text).style(
                     Style::default()
                         .fg(DashboardColors::HIGHLIGHT)
@@ -531,6 +587,28 @@ impl OperationsPanel {
             Cell::from(format!("{:.2}", stats.operations_per_minute)),
         ]));
 
+        // Per-command latency and failure rate (most frequent first)
+        let mut per_command: Vec<_> = stats.command_stats.iter().collect();
+        per_command.sort_by(|a, b| b.1.count.cmp(&a.1.count).then(a.0.cmp(b.0)));
+        for (cmd, cmd_stats) in per_command {
+            rows.push(Row::new(vec![
+                Cell::from(format!("  {}", Self::truncate(cmd, 18)))
+                    .style(Style::default().fg(DashboardColors::HIGHLIGHT)),
+                Cell::from(format!(
+                    "n={} p50 {} p95 {} fail {:.1}%",
+                    cmd_stats.count,
+                    Self::format_duration(cmd_stats.p50_duration_ms),
+                    Self::format_duration(cmd_stats.p95_duration_ms),
+                    cmd_stats.failure_rate
+                ))
+                .style(Style::default().fg(if cmd_stats.failure_count > 0 {
+                    DashboardColors::ERROR
+                } else {
+                    DashboardColors::TEXT
+                })),
+            ]));
+        }
+
         let table = Table::new(rows, [Constraint::Length(20), Constraint::Min(30)])
             .block(
                 Block::default()
@@ -558,6 +636,11 @@ impl OperationsPanel {
         self.scroll_offset = 0;
     }
 
+    /// Advance to the next view mode
+    pub fn cycle_view_mode(&mut self) {
+        self.set_view_mode(self.view_mode.next());
+    }
+
     /// Get current view mode
     pub fn view_mode(&self) -> &ViewMode {
         &self.view_mode
@@ -612,6 +695,7 @@ impl OperationsPanel {
         let mut slowest_command: Option<(String, u64)> = None;
         let mut command_counts: HashMap<String, usize> = HashMap::new();
         let mut command_stats: HashMap<String, CommandStats> = HashMap::new();
+        let mut command_durations: HashMap<String, Vec<u64>> = HashMap::new();
 
         // First pass: collect basic stats
         for op in &filtered {
@@ -641,6 +725,11 @@ impl OperationsPanel {
                 stats.avg_duration_ms = ((stats.avg_duration_ms * (stats.count - 1) as u64)
                     + duration)
                     / stats.count as u64;
+                stats.total_duration_ms += duration;
+                command_durations
+                    .entry(op.command.clone())
+                    .or_default()
+                    .push(duration);
             }
             match &op.status {
                 OperationStatus::Completed => stats.success_count += 1,
@@ -649,6 +738,19 @@ impl OperationsPanel {
             }
         }
 
+        // Per-command latency percentiles and failure rate
+        for (command, stats) in command_stats.iter_mut() {
+            if let Some(durations) = command_durations.get_mut(command) {
+                durations.sort_unstable();
+                stats.p50_duration_ms = Self::percentile(durations, 50.0);
+                stats.p95_duration_ms = Self::percentile(durations, 95.0);
+            }
+            let finished = stats.success_count + stats.failure_count;
+            if finished > 0 {
+                stats.failure_rate = (stats.failure_count as f64 / finished as f64) * 100.0;
+            }
+        }
+
         // Calculate derived stats
         let success_rate = if completed_count + failed_count > 0 {
             (completed_count as f64 / (completed_count + failed_count) as f64) * 100.0
@@ -694,6 +796,15 @@ impl OperationsPanel {
         }
     }
 
+    /// Nearest-rank percentile of sorted durations (0 when empty)
+    fn percentile(sorted: &[u64], p: f64) -> u64 {
+        if sorted.is_empty() {
+            return 0;
+        }
+        let rank = ((p / 100.0) * sorted.len() as f64).ceil() as usize;
+        sorted[rank.clamp(1, sorted.len()) - 1]
+    }
+
     /// Group operations by command
     fn group_operations(&self) -> Vec<(String, Vec<&OperationEntry>)> {
         let filtered = self.filtered_operations();
@@ -791,6 +902,7 @@ mod tests {
         panel.add_started(
             "remember".to_string(),
             vec!["--content".to_string(), "test".to_string()],
+            None,
         );
 
         assert_eq!(panel.operation_count(), 1);
@@ -799,8 +911,8 @@ mod tests {
     #[test]
     fn test_update_completed() {
         let mut panel = OperationsPanel::new();
-        panel.add_started("remember".to_string(), vec![]);
-        panel.update_completed("remember", 1500, "Memory stored".to_string());
+        panel.add_started("remember".to_string(), vec![], None);
+        panel.update_completed("remember", None, 1500, "Memory stored".to_string());
 
         assert_eq!(panel.operation_count(), 1);
     }
@@ -808,12 +920,35 @@ mod tests {
     #[test]
     fn test_update_failed() {
         let mut panel = OperationsPanel::new();
-        panel.add_started("recall".to_string(), vec![]);
-        panel.update_failed("recall", "Database error".to_string(), 500);
+        panel.add_started("recall".to_string(), vec![], None);
+        panel.update_failed("recall", None, "Database error".to_string(), 500);
 
         assert_eq!(panel.operation_count(), 1);
     }
 
+    #[test]
+    fn test_overlapping_runs_pair_by_correlation_id() {
+        let mut panel = OperationsPanel::new();
+        panel.add_started("recall".to_string(), vec![], Some("first".to_string()));
+        panel.add_started("recall".to_string(), vec![], Some("second".to_string()));
+
+        // The first run finishes last; each keeps its own duration
+        panel.update_completed("recall", Some("second"), 200, "2 results".to_string());
+        panel.update_completed("recall", Some("first"), 900, "5 results".to_string());
+
+        let first = &panel.operations[0];
+        assert_eq!(first.correlation_id.as_deref(), Some("first"));
+        assert_eq!(first.duration_ms, Some(900));
+        let second = &panel.operations[1];
+        assert_eq!(second.duration_ms, Some(200));
+        assert_eq!(second.result_summary.as_deref(), Some("2 results"));
+
+        // An unknown ID closes nothing
+        panel.add_started("recall".to_string(), vec![], Some("third".to_string()));
+        panel.update_failed("recall", Some("unknown"), "error".to_string(), 10);
+        assert_eq!(panel.operations[2].status, OperationStatus::Running);
+    }
+
     #[test]
     fn test_operation_status_color() {
         assert_eq!(
@@ -842,7 +977,7 @@ mod tests {
         let mut panel = OperationsPanel::new();
 
         for i in 0..150 {
-            panel.add_started(format!("cmd{}", i), vec![]);
+            panel.add_started(format!("cmd{}", i), vec![], None);
         }
 
         assert_eq!(panel.operation_count(), 100);
@@ -851,8 +986,8 @@ mod tests {
     #[test]
     fn test_clear_operations() {
         let mut panel = OperationsPanel::new();
-        panel.add_started("test".to_string(), vec![]);
-        panel.add_started("test2".to_string(), vec![]);
+        panel.add_started("test".to_string(), vec![], None);
+        panel.add_started("test2".to_string(), vec![], None);
 
         assert_eq!(panel.operation_count(), 2);
 
@@ -865,7 +1000,7 @@ mod tests {
     fn test_scroll_operations() {
         let mut panel = OperationsPanel::new();
         for i in 0..10 {
-            panel.add_started(format!("cmd{}", i), vec![]);
+            panel.add_started(format!("cmd{}", i), vec![], None);
         }
 
         panel.scroll_up(3);
@@ -888,13 +1023,13 @@ mod tests {
     #[test]
     fn test_multiple_running_operations() {
         let mut panel = OperationsPanel::new();
-        panel.add_started("remember".to_string(), vec![]);
-        panel.add_started("recall".to_string(), vec![]);
-        panel.add_started("evolve".to_string(), vec![]);
+        panel.add_started("remember".to_string(), vec![], None);
+        panel.add_started("recall".to_string(), vec![], None);
+        panel.add_started("evolve".to_string(), vec![], None);
 
         assert_eq!(panel.operation_count(), 3);
 
-        panel.update_completed("remember", 1000, "Success".to_string());
+        panel.update_completed("remember", None, 1000, "Success".to_string());
 
         assert_eq!(panel.operation_count(), 3);
     }
@@ -917,7 +1052,7 @@ mod tests {
     fn test_view_mode_resets_scroll() {
         let mut panel = OperationsPanel::new();
         for i in 0..10 {
-            panel.add_started(format!("cmd{}", i), vec![]);
+            panel.add_started(format!("cmd{}", i), vec![], None);
         }
 
         panel.scroll_up(5);
@@ -932,9 +1067,9 @@ mod tests {
     #[test]
     fn test_filter_by_status() {
         let mut panel = OperationsPanel::new();
-        panel.add_started("remember".to_string(), vec![]);
-        panel.add_started("recall".to_string(), vec![]);
-        panel.update_completed("remember", 1000, "OK".to_string());
+        panel.add_started("remember".to_string(), vec![], None);
+        panel.add_started("recall".to_string(), vec![], None);
+        panel.update_completed("remember", None, 1000, "OK".to_string());
 
         let filter = OperationFilter {
             status: Some(OperationStatus::Completed),
@@ -950,9 +1085,9 @@ mod tests {
     #[test]
     fn test_filter_by_command() {
         let mut panel = OperationsPanel::new();
-        panel.add_started("remember".to_string(), vec![]);
-        panel.add_started("recall".to_string(), vec![]);
-        panel.add_started("evolve".to_string(), vec![]);
+        panel.add_started("remember".to_string(), vec![], None);
+        panel.add_started("recall".to_string(), vec![], None);
+        panel.add_started("evolve".to_string(), vec![], None);
 
         let filter = OperationFilter {
             command: Some("re".to_string()),
@@ -967,13 +1102,13 @@ mod tests {
     #[test]
     fn test_filter_by_duration() {
         let mut panel = OperationsPanel::new();
-        panel.add_started("cmd1".to_string(), vec![]);
-        panel.add_started("cmd2".to_string(), vec![]);
-        panel.add_started("cmd3".to_string(), vec![]);
+        panel.add_started("cmd1".to_string(), vec![], None);
+        panel.add_started("cmd2".to_string(), vec![], None);
+        panel.add_started("cmd3".to_string(), vec![], None);
 
-        panel.update_completed("cmd1", 500, "OK".to_string());
-        panel.update_completed("cmd2", 1500, "OK".to_string());
-        panel.update_completed("cmd3", 2500, "OK".to_string());
+        panel.update_completed("cmd1", None, 500, "OK".to_string());
+        panel.update_completed("cmd2", None, 1500, "OK".to_string());
+        panel.update_completed("cmd3", None, 2500, "OK".to_string());
 
         let filter = OperationFilter {
             min_duration_ms: Some(1000),
@@ -988,7 +1123,7 @@ mod tests {
     #[test]
     fn test_clear_filter() {
         let mut panel = OperationsPanel::new();
-        panel.add_started("test".to_string(), vec![]);
+        panel.add_started("test".to_string(), vec![], None);
 
         let filter = OperationFilter {
             command: Some("nonexistent".to_string()),
@@ -1006,13 +1141,13 @@ mod tests {
     fn test_combined_filters() {
         let mut panel = OperationsPanel::new();
 
-        panel.add_started("remember".to_string(), vec![]);
-        panel.add_started("recall".to_string(), vec![]);
-        panel.add_started("remember".to_string(), vec![]);
+        panel.add_started("remember".to_string(), vec![], None);
+        panel.add_started("recall".to_string(), vec![], None);
+        panel.add_started("remember".to_string(), vec![], None);
 
-        panel.update_completed("remember", 1500, "OK".to_string());
-        panel.update_failed("recall", "Error".to_string(), 500);
-        panel.update_completed("remember", 2500, "OK".to_string());
+        panel.update_completed("remember", None, 1500, "OK".to_string());
+        panel.update_failed("recall", None, "Error".to_string(), 500);
+        panel.update_completed("remember", None, 2500, "OK".to_string());
 
         let filter = OperationFilter {
             command: Some("remember".to_string()),
@@ -1038,9 +1173,9 @@ mod tests {
     #[test]
     fn test_group_operations_single_command() {
         let mut panel = OperationsPanel::new();
-        panel.add_started("remember".to_string(), vec![]);
-        panel.add_started("remember".to_string(), vec![]);
-        panel.add_started("remember".to_string(), vec![]);
+        panel.add_started("remember".to_string(), vec![], None);
+        panel.add_started("remember".to_string(), vec![], None);
+        panel.add_started("remember".to_string(), vec![], None);
 
         let groups = panel.group_operations();
         assert_eq!(groups.len(), 1);
@@ -1051,10 +1186,10 @@ mod tests {
     #[test]
     fn test_group_operations_multiple_commands() {
         let mut panel = OperationsPanel::new();
-        panel.add_started("remember".to_string(), vec![]);
-        panel.add_started("recall".to_string(), vec![]);
-        panel.add_started("remember".to_string(), vec![]);
-        panel.add_started("evolve".to_string(), vec![]);
+        panel.add_started("remember".to_string(), vec![], None);
+        panel.add_started("recall".to_string(), vec![], None);
+        panel.add_started("remember".to_string(), vec![], None);
+        panel.add_started("evolve".to_string(), vec![], None);
 
         let groups = panel.group_operations();
         assert_eq!(groups.len(), 3);
@@ -1073,9 +1208,9 @@ mod tests {
     #[test]
     fn test_group_operations_sorted_by_recent() {
         let mut panel = OperationsPanel::new();
-        panel.add_started("old".to_string(), vec![]);
+        panel.add_started("old".to_string(), vec![], None);
         std::thread::sleep(std::time::Duration::from_millis(10));
-        panel.add_started("new".to_string(), vec![]);
+        panel.add_started("new".to_string(), vec![], None);
 
         let groups = panel.group_operations();
         // Most recent group should be first
@@ -1113,9 +1248,10 @@ mod tests {
         let mut panel = OperationsPanel::new();
 
         for i in 0..5 {
-            panel.add_started(format!("cmd{}", i), vec![]);
+            panel.add_started(format!("cmd{}", i), vec![], None);
             panel.update_completed(
                 &format!("cmd{}", i),
+                None,
                 1000 + (i as u64 * 100),
                 "OK".to_string(),
             );
@@ -1134,16 +1270,16 @@ mod tests {
     fn test_calculate_stats_mixed_status() {
         let mut panel = OperationsPanel::new();
 
-        panel.add_started("cmd1".to_string(), vec![]);
-        panel.update_completed("cmd1", 1000, "OK".to_string());
+        panel.add_started("cmd1".to_string(), vec![], None);
+        panel.update_completed("cmd1", None, 1000, "OK".to_string());
 
-        panel.add_started("cmd2".to_string(), vec![]);
-        panel.update_failed("cmd2", "Error".to_string(), 500);
+        panel.add_started("cmd2".to_string(), vec![], None);
+        panel.update_failed("cmd2", None, "Error".to_string(), 500);
 
-        panel.add_started("cmd3".to_string(), vec![]);
-        panel.update_completed("cmd3", 1500, "OK".to_string());
+        panel.add_started("cmd3".to_string(), vec![], None);
+        panel.update_completed("cmd3", None, 1500, "OK".to_string());
 
-        panel.add_started("cmd4".to_string(), vec![]);
+        panel.add_started("cmd4".to_string(), vec![], None);
         // Leave cmd4 running
 
         let stats = panel.calculate_stats();
@@ -1159,14 +1295,14 @@ mod tests {
     fn test_calculate_stats_slowest_command() {
         let mut panel = OperationsPanel::new();
 
-        panel.add_started("fast".to_string(), vec![]);
-        panel.update_completed("fast", 500, "OK".to_string());
+        panel.add_started("fast".to_string(), vec![], None);
+        panel.update_completed("fast", None, 500, "OK".to_string());
 
-        panel.add_started("slow".to_string(), vec![]);
-        panel.update_completed("slow", 5000, "OK".to_string());
+        panel.add_started("slow".to_string(), vec![], None);
+        panel.update_completed("slow", None, 5000, "OK".to_string());
 
-        panel.add_started("medium".to_string(), vec![]);
-        panel.update_completed("medium", 1500, "OK".to_string());
+        panel.add_started("medium".to_string(), vec![], None);
+        panel.update_completed("medium", None, 1500, "OK".to_string());
 
         let stats = panel.calculate_stats();
         assert_eq!(stats.slowest_command, Some(("slow".to_string(), 5000)));
@@ -1176,12 +1312,12 @@ mod tests {
     fn test_calculate_stats_most_frequent() {
         let mut panel = OperationsPanel::new();
 
-        panel.add_started("remember".to_string(), vec![]);
-        panel.add_started("remember".to_string(), vec![]);
-        panel.add_started("remember".to_string(), vec![]);
-        panel.add_started("recall".to_string(), vec![]);
-        panel.add_started("recall".to_string(), vec![]);
-        panel.add_started("evolve".to_string(), vec![]);
+        panel.add_started("remember".to_string(), vec![], None);
+        panel.add_started("remember".to_string(), vec![], None);
+        panel.add_started("remember".to_string(), vec![], None);
+        panel.add_started("recall".to_string(), vec![], None);
+        panel.add_started("recall".to_string(), vec![], None);
+        panel.add_started("evolve".to_string(), vec![], None);
 
         let stats = panel.calculate_stats();
         assert_eq!(
@@ -1195,14 +1331,14 @@ mod tests {
         let mut panel = OperationsPanel::new();
 
         // Add multiple remember operations with different outcomes
-        panel.add_started("remember".to_string(), vec![]);
-        panel.update_completed("remember", 1000, "OK".to_string());
+        panel.add_started("remember".to_string(), vec![], None);
+        panel.update_completed("remember", None, 1000, "OK".to_string());
 
-        panel.add_started("remember".to_string(), vec![]);
-        panel.update_completed("remember", 2000, "OK".to_string());
+        panel.add_started("remember".to_string(), vec![], None);
+        panel.update_completed("remember", None, 2000, "OK".to_string());
 
-        panel.add_started("remember".to_string(), vec![]);
-        panel.update_failed("remember", "Error".to_string(), 500);
+        panel.add_started("remember".to_string(), vec![], None);
+        panel.update_failed("remember", None, "Error".to_string(), 500);
 
         let stats = panel.calculate_stats();
         let remember_stats = stats.command_stats.get("remember").unwrap();
@@ -1213,6 +1349,61 @@ mod tests {
         assert_eq!(remember_stats.avg_duration_ms, 1166); // (1000+2000+500)/3 with integer division
     }
 
+    #[test]
+    fn test_command_latency_percentiles() {
+        let mut panel = OperationsPanel::new();
+        for duration in [100, 900, 200, 800, 300, 700, 400, 600, 500, 1000] {
+            panel.add_started("recall".to_string(), vec![], None);
+            panel.update_completed("recall", None, duration, "OK".to_string());
+        }
+        panel.add_started("recall".to_string(), vec![], None);
+        panel.update_failed("recall", None, "Timeout".to_string(), 5000);
+        panel.add_started("recall".to_string(), vec![], None); // Still running, no duration
+
+        let stats = panel.calculate_stats();
+        let recall = stats.command_stats.get("recall").unwrap();
+
+        assert_eq!(recall.count, 12);
+        assert_eq!(recall.total_duration_ms, 10_500);
+        // 11 durations: 100..=1000 then 5000
+        assert_eq!(recall.p50_duration_ms, 600);
+        assert_eq!(recall.p95_duration_ms, 5000);
+        assert!((recall.failure_rate - 100.0 / 11.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_percentile_nearest_rank() {
+        assert_eq!(OperationsPanel::percentile(&[], 50.0), 0);
+        assert_eq!(OperationsPanel::percentile(&[42], 95.0), 42);
+        assert_eq!(OperationsPanel::percentile(&[10, 20, 30, 40], 50.0), 20);
+        assert_eq!(OperationsPanel::percentile(&[10, 20, 30, 40], 95.0), 40);
+        assert_eq!(OperationsPanel::percentile(&[10, 20, 30, 40], 0.0), 10);
+    }
+
+    #[test]
+    fn test_failure_rate_ignores_running() {
+        let mut panel = OperationsPanel::new();
+        panel.add_started("remember".to_string(), vec![], None);
+        panel.update_completed("remember", None, 100, "OK".to_string());
+        panel.add_started("remember".to_string(), vec![], None);
+
+        let stats = panel.calculate_stats();
+        let remember = stats.command_stats.get("remember").unwrap();
+        assert_eq!(remember.failure_rate, 0.0);
+        assert_eq!(remember.p50_duration_ms, 100);
+    }
+
+    #[test]
+    fn test_cycle_view_mode() {
+        let mut panel = OperationsPanel::new();
+        panel.cycle_view_mode();
+        assert_eq!(panel.view_mode(), &ViewMode::Grouped);
+        panel.cycle_view_mode();
+        assert_eq!(panel.view_mode(), &ViewMode::Statistics);
+        panel.cycle_view_mode();
+        assert_eq!(panel.view_mode(), &ViewMode::List);
+    }
+
     // ===== Utility Function Tests =====
 
     #[test]
@@ -1264,7 +1455,7 @@ mod tests {
     fn test_all_operations_same_command() {
         let mut panel = OperationsPanel::new();
         for _ in 0..10 {
-            panel.add_started("remember".to_string(), vec![]);
+            panel.add_started("remember".to_string(), vec![], None);
         }
 
         let groups = panel.group_operations();
@@ -1282,7 +1473,7 @@ mod tests {
     #[test]
     fn test_operations_with_no_duration() {
         let mut panel = OperationsPanel::new();
-        panel.add_started("running".to_string(), vec![]);
+        panel.add_started("running".to_string(), vec![], None);
 
         let stats = panel.calculate_stats();
         assert_eq!(stats.avg_duration_ms, 0);
@@ -1292,7 +1483,7 @@ mod tests {
     #[test]
     fn test_filter_results_in_empty_set() {
         let mut panel = OperationsPanel::new();
-        panel.add_started("remember".to_string(), vec![]);
+        panel.add_started("remember".to_string(), vec![], None);
 
         let filter = OperationFilter {
             command: Some("nonexistent".to_string()),
@@ -1314,8 +1505,8 @@ mod tests {
         // Add 100 operations
         for i in 0..100 {
             let cmd = format!("cmd{}", i % 10); // 10 different commands
-            panel.add_started(cmd.clone(), vec![]);
-            panel.update_completed(&cmd, 1000 + (i * 10), "OK".to_string());
+            panel.add_started(cmd.clone(), vec![], None);
+            panel.update_completed(&cmd, None, 1000 + (i * 10), "OK".to_string());
         }
 
         // Test grouping performance
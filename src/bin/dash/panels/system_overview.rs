@@ -12,7 +12,10 @@ use crate::filters::EventCategory;
 use crate::time_series::TimeSeriesBuffer;
 use crate::widgets::{Sparkline, StateIndicator, StateType};
 use chrono::{DateTime, Utc};
-use mnemosyne_core::api::events::{Event, EventType};
+use mnemosyne_core::api::{
+    events::{Event, EventType},
+    MetricsSnapshot,
+};
 use ratatui::{
     layout::Rect,
     style::{Modifier, Style},
@@ -53,6 +56,12 @@ pub struct SystemMetrics {
     pub subscribers: usize,
     /// Last event timestamp
     pub last_event_at: Option<DateTime<Utc>>,
+    /// Current work phase
+    pub work_phase: Option<String>,
+    /// Completed work items
+    pub tasks_completed: usize,
+    /// Total work items
+    pub tasks_total: usize,
 }
 
 /// System Overview panel widget
@@ -99,6 +108,30 @@ impl SystemOverviewPanel {
         self.metrics = metrics;
     }
 
+    /// Update metrics from the API's `/metrics/snapshot`
+    ///
+    /// Fields the snapshot doesn't cover (instance, health) are kept.
+    pub fn apply_snapshot(&mut self, snapshot: &MetricsSnapshot) {
+        let mut metrics = self.metrics.clone();
+        metrics.agents_active = snapshot.agent_states.active;
+        metrics.agents_idle = snapshot.agent_states.idle + snapshot.agent_states.waiting;
+        metrics.agents_failed = snapshot.agent_states.failed;
+        metrics.agents_total = snapshot.agent_states.total;
+        metrics.graph_size = snapshot.memory_ops.graph_nodes;
+        metrics.stores_per_min = snapshot.memory_ops.stores_per_minute;
+        metrics.recalls_per_min = snapshot.memory_ops.recalls_per_minute;
+        metrics.work_phase = Some(snapshot.work.current_phase.clone());
+        metrics.tasks_completed = snapshot.work.completed_tasks;
+        metrics.tasks_total = snapshot.work.total_tasks;
+        self.update_metrics(metrics);
+    }
+
+    /// Current metrics
+    #[cfg(test)]
+    pub fn metrics(&self) -> &SystemMetrics {
+        &self.metrics
+    }
+
     /// Add an event (filters for critical events only)
     pub fn add_event(&mut self, event: Event) {
         let category = EventCategory::from_event(&event);
@@ -126,6 +159,7 @@ impl SystemOverviewPanel {
         matches!(
             &event.event_type,
             EventType::DeadlockDetected { .. }
+                | EventType::ConflictDetected { .. }
                 | EventType::AgentHealthDegraded { .. }
                 | EventType::ReviewFailed { .. }
                 | EventType::PhaseChanged { .. } // Phase changes are important
@@ -150,6 +184,17 @@ impl SystemOverviewPanel {
             EventType::DeadlockDetected { blocked_items, .. } => {
                 format!("Deadlock: {} items blocked", blocked_items.len())
             }
+            EventType::ConflictDetected {
+                files, severity, ..
+            } => {
+                let first = files.first().map(String::as_str).unwrap_or("?");
+                format!(
+                    "{} conflict on {} ({} files)",
+                    severity,
+                    Self::truncate(first, 30),
+                    files.len()
+                )
+            }
             EventType::AgentHealthDegraded {
                 agent_id,
                 error_count,
@@ -203,6 +248,7 @@ impl SystemOverviewPanel {
             AgentFailed { timestamp, .. }
             | CliCommandFailed { timestamp, .. }
             | DeadlockDetected { timestamp, .. }
+            | ConflictDetected { timestamp, .. }
             | AgentHealthDegraded { timestamp, .. }
             | ReviewFailed { timestamp, .. }
             | PhaseChanged { timestamp, .. }
@@ -284,6 +330,25 @@ impl SystemOverviewPanel {
 
         items.push(ListItem::new(Line::from(agent_spans)));
 
+        // Work progress (once the orchestrator has reported tasks)
+        if self.metrics.tasks_total > 0 {
+            items.push(ListItem::new(Line::from(vec![
+                Span::styled("Work: ", Style::default().add_modifier(Modifier::DIM)),
+                Span::styled(
+                    self.metrics.work_phase.clone().unwrap_or_default(),
+                    Style::default().fg(DashboardColors::HIGHLIGHT),
+                ),
+                Span::raw(" | Tasks: "),
+                Span::styled(
+                    format!(
+                        "{}/{}",
+                        self.metrics.tasks_completed, self.metrics.tasks_total
+                    ),
+                    Style::default().fg(DashboardColors::IN_PROGRESS),
+                ),
+            ])));
+        }
+
         // Row 3-4: Memory activity sparklines
         let stores_data = self.stores_history.to_vec();
         let recalls_data = self.recalls_history.to_vec();
@@ -444,6 +509,9 @@ mod tests {
             memory_mb: 245.0,
             subscribers: 2,
             last_event_at: Some(Utc::now()),
+            work_phase: None,
+            tasks_completed: 0,
+            tasks_total: 0,
         };
 
         panel.update_metrics(metrics.clone());
@@ -453,6 +521,33 @@ mod tests {
         assert!((panel.metrics.stores_per_min - 5.2).abs() < 0.1);
     }
 
+    #[test]
+    fn test_apply_snapshot() {
+        let mut panel = SystemOverviewPanel::new();
+        let mut snapshot = MetricsSnapshot {
+            timestamp: Utc::now(),
+            agent_states: Default::default(),
+            memory_ops: Default::default(),
+            skills: Default::default(),
+            work: Default::default(),
+        };
+        snapshot.agent_states.active = 2;
+        snapshot.agent_states.waiting = 1;
+        snapshot.agent_states.total = 3;
+        snapshot.memory_ops.stores_per_minute = 4.0;
+        snapshot.work.total_tasks = 5;
+        snapshot.work.completed_tasks = 2;
+
+        panel.apply_snapshot(&snapshot);
+
+        assert_eq!(panel.metrics.agents_active, 2);
+        assert_eq!(panel.metrics.agents_idle, 1);
+        assert_eq!(panel.metrics.agents_total, 3);
+        assert_eq!(panel.metrics.tasks_completed, 2);
+        assert_eq!(panel.metrics.tasks_total, 5);
+        assert_eq!(panel.stores_history.to_vec(), vec![4.0]);
+    }
+
     #[test]
     fn test_critical_event_tracking() {
         let mut panel = SystemOverviewPanel::new();
@@ -206,7 +206,9 @@ async fn main() -> Result<()> {
     let config = IcsConfig {
         read_only: args.readonly,
         ..Default::default()
-    };
+    }
+    .with_project_gazetteer(&std::env::current_dir()?)
+    .context("Failed to load entity gazetteer")?;
 
     if args.readonly {
         debug!("Read-only mode enabled");
@@ -103,9 +103,11 @@ async fn main() -> Result<()> {
                 model: "claude-haiku-4-5-20251001".to_string(),
                 max_tokens: 1024,
                 temperature: 0.7,
+                task_models: Default::default(),
             };
             Some(Arc::new(
-                LlmService::new(llm_config).context("Failed to initialize LLM service")?,
+                LlmService::configured_with(llm_config)
+                    .context("Failed to initialize LLM service")?,
             ))
         } else {
             info!("LLM enrichment requested but no API key provided");
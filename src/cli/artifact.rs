@@ -87,12 +87,25 @@ pub enum ArtifactCommands {
         /// Artifact file path
         path: String,
     },
+
+    /// Move an artifact to a new workflow status
+    ///
+    /// Allowed: draft -> in_review -> approved -> implemented -> superseded;
+    /// in_review -> draft; any non-terminal status -> deprecated.
+    SetStatus {
+        /// Artifact file path
+        path: String,
+
+        /// New status (draft|in_review|approved|implemented|superseded|deprecated)
+        status: String,
+    },
 }
 
 /// Handle artifact command
 pub async fn handle(command: ArtifactCommands, global_db_path: Option<String>) -> Result<()> {
     use mnemosyne_core::artifacts::{
-        parse_frontmatter, Artifact as ArtifactTrait, ArtifactWorkflow, Constitution, FeatureSpec,
+        parse_frontmatter, serialize_frontmatter, validate_transition, Artifact as ArtifactTrait,
+        ArtifactStatus, ArtifactWorkflow, Constitution, FeatureSpec,
     };
     use mnemosyne_core::types::Namespace;
     use std::fs;
@@ -347,6 +360,7 @@ Or use CLI:
 mnemosyne artifact list
 mnemosyne artifact show <artifact-id>
 mnemosyne artifact validate <path>
+mnemosyne artifact set-status <path> in_review
 ```
 
 For more information, see: docs/specs/specification-artifacts.md
@@ -590,5 +604,58 @@ For more information, see: docs/specs/specification-artifacts.md
             })
             .await
         }
+        ArtifactCommands::SetStatus { path, status } => {
+            event_helpers::with_event_lifecycle("artifact-set-status", vec![], async {
+                let target: ArtifactStatus = match status.parse() {
+                    Ok(target) => target,
+                    Err(e) => {
+                        eprintln!("✗ {}", e);
+                        std::process::exit(1);
+                    }
+                };
+
+                let path_buf = PathBuf::from(&path);
+                if !path_buf.exists() {
+                    eprintln!("✗ File not found: {}", path);
+                    std::process::exit(1);
+                }
+
+                let content = fs::read_to_string(&path_buf)?;
+                let (mut frontmatter, markdown) = parse_frontmatter(&content)?;
+
+                // Artifacts without a recorded status are drafts
+                let current = match frontmatter.get("status").and_then(|v| v.as_str()) {
+                    Some(s) => match s.parse::<ArtifactStatus>() {
+                        Ok(current) => current,
+                        Err(e) => {
+                            eprintln!("✗ {}", e);
+                            std::process::exit(1);
+                        }
+                    },
+                    None => ArtifactStatus::Draft,
+                };
+
+                if let Err(e) = validate_transition(current, target) {
+                    eprintln!("✗ {}", e);
+                    std::process::exit(1);
+                }
+
+                if let Some(mapping) = frontmatter.as_mapping_mut() {
+                    mapping.insert("status".into(), target.as_str().into());
+                    mapping.insert("updated_at".into(), Utc::now().to_rfc3339().into());
+                }
+                fs::write(&path_buf, serialize_frontmatter(&frontmatter, &markdown)?)?;
+
+                println!(
+                    "{} {}: {} -> {}",
+                    icons::status::success(),
+                    path,
+                    current,
+                    target
+                );
+                Ok(())
+            })
+            .await
+        }
     }
 }
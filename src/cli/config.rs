@@ -19,6 +19,13 @@ pub enum ConfigAction {
 
     /// Delete stored API key
     DeleteKey,
+
+    /// Show effective configuration (defaults < global < project < env)
+    Show {
+        /// Print the layer each value came from
+        #[arg(long)]
+        sources: bool,
+    },
 }
 
 /// Mask an API key for safe logging/display
@@ -177,5 +184,21 @@ pub async fn handle(action: ConfigAction) -> Result<()> {
             })
             .await
         }
+        ConfigAction::Show { sources } => {
+            event_helpers::with_event_lifecycle("config show", vec![], async move {
+                let cwd = std::env::current_dir()?;
+                let layered = ConfigManager::load_layered(&cwd)?;
+
+                for (key, value, source) in layered.entries() {
+                    if sources {
+                        println!("{} = {}  ({})", key, value, source);
+                    } else {
+                        println!("{} = {}", key, value);
+                    }
+                }
+                Ok(())
+            })
+            .await
+        }
     }
 }
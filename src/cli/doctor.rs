@@ -16,6 +16,7 @@ pub async fn handle(
     verbose: bool,
     fix: bool,
     json: bool,
+    strict: bool,
     global_db_path: Option<String>,
 ) -> Result<()> {
     let start_time = std::time::Instant::now();
@@ -35,7 +36,7 @@ pub async fn handle(
     let storage = LibsqlStorage::from_path(&db_path).await?;
 
     // Run health checks
-    let summary = run_health_checks(&storage, verbose, fix).await?;
+    let summary = run_health_checks(&storage, verbose, fix, strict).await?;
 
     // Extract check counts
     let checks_passed = summary
@@ -46,12 +47,12 @@ pub async fn handle(
     let checks_failed = summary
         .checks
         .iter()
-        .filter(|r| matches!(r.status, CheckStatus::Fail))
+        .filter(|r| matches!(r.effective_status(strict), CheckStatus::Fail))
         .count();
     let checks_warned = summary
         .checks
         .iter()
-        .filter(|r| matches!(r.status, CheckStatus::Warn))
+        .filter(|r| matches!(r.effective_status(strict), CheckStatus::Warn))
         .count();
 
     // Output results
@@ -2,13 +2,17 @@
 
 use clap::ValueEnum;
 use mnemosyne_core::{
+    coordination::{EditIntent, EditResult, ExitReason, HandoffCoordinator, IntentStatus},
     error::Result,
     icons,
     ics::{IcsApp, IcsConfig, PanelType},
     orchestration::events::AgentEvent,
     ConnectionMode, LibsqlStorage, StorageBackend,
 };
-use std::{path::PathBuf, sync::Arc};
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 use tracing::debug;
 
 use super::event_helpers;
@@ -108,12 +112,86 @@ pub enum IcsPanel {
     Holes,
 }
 
+/// Handoff with the Claude Code session that launched this editor
+struct SessionHandoff {
+    coordinator: HandoffCoordinator,
+    intent: EditIntent,
+    /// File reported back in the result
+    file_path: PathBuf,
+    /// File content before editing, to tell whether anything changed
+    original: Option<String>,
+}
+
+impl SessionHandoff {
+    /// Accept the intent at `intent_path`
+    ///
+    /// The session directory is the intent's parent. Returns `None` for a
+    /// stale intent, which has already been answered with a `TimedOut` result.
+    fn accept(intent_path: &Path, file: Option<&Path>) -> Result<Option<Self>> {
+        let session_dir = match intent_path.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir.to_path_buf(),
+            _ => PathBuf::from("."),
+        };
+        let coordinator = HandoffCoordinator::new(session_dir)?;
+
+        match coordinator.accept_intent()? {
+            IntentStatus::Ready(intent) => {
+                let file_path = file
+                    .map(Path::to_path_buf)
+                    .unwrap_or_else(|| intent.file_path.clone());
+                Ok(Some(Self {
+                    coordinator,
+                    intent,
+                    file_path,
+                    original: None,
+                }))
+            }
+            IntentStatus::Stale(result) => {
+                eprintln!(
+                    "{} Not opening editor: {}",
+                    icons::status::warning(),
+                    result.error.unwrap_or_default()
+                );
+                Ok(None)
+            }
+        }
+    }
+
+    /// Record the file as it is before editing
+    fn begin(&mut self) {
+        self.original = std::fs::read_to_string(&self.file_path).ok();
+    }
+
+    /// Write the result the launching session is waiting for
+    fn finish(&self, outcome: &anyhow::Result<()>) -> Result<()> {
+        let changes_made = std::fs::read_to_string(&self.file_path).ok() != self.original;
+        let (status, exit_reason, error) = match outcome {
+            Ok(()) if changes_made => ("completed", ExitReason::UserSaved, None),
+            Ok(()) => ("cancelled", ExitReason::UserCancelled, None),
+            Err(e) => ("error", ExitReason::Error, Some(e.to_string())),
+        };
+
+        self.coordinator.write_result(&EditResult {
+            session_id: self.intent.session_id.clone(),
+            timestamp: chrono::Utc::now(),
+            status: status.to_string(),
+            file_path: self.file_path.clone(),
+            changes_made,
+            exit_reason,
+            analysis: None,
+            error,
+        })?;
+        Ok(())
+    }
+}
+
 /// Handle ICS edit command
 pub async fn handle(
     file: Option<PathBuf>,
     readonly: bool,
     template: Option<IcsTemplate>,
     panel: Option<IcsPanel>,
+    persist_holes: bool,
     session_context: Option<PathBuf>,
     global_db_path: Option<String>,
 ) -> Result<()> {
@@ -121,6 +199,19 @@ pub async fn handle(
 
     debug!("Launching Integrated Context Studio (ICS)...");
 
+    // Accept the handoff first, so a stale intent is answered without
+    // opening an editor nobody is waiting on
+    let mut handoff = match session_context {
+        Some(ref intent_path) => {
+            debug!("Session handoff enabled: {:?}", intent_path);
+            match SessionHandoff::accept(intent_path, file.as_deref())? {
+                Some(handoff) => Some(handoff),
+                None => return Ok(()),
+            }
+        }
+        None => None,
+    };
+
     // Emit IcsSessionStarted event
     event_helpers::emit_domain_event(AgentEvent::IcsSessionStarted {
         file_path: file.as_ref().map(|p| p.display().to_string()),
@@ -144,8 +235,10 @@ pub async fn handle(
     // Create ICS config with readonly setting
     let config = IcsConfig {
         read_only: readonly,
+        persist_unresolved_holes: persist_holes,
         ..Default::default()
-    };
+    }
+    .with_project_gazetteer(&std::env::current_dir()?)?;
 
     if readonly {
         debug!("Read-only mode enabled");
@@ -222,15 +315,16 @@ pub async fn handle(
     // Small delay so user can see the banner
     tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
 
-    // If session_context is provided, write handoff intent
-    if let Some(session_path) = session_context {
-        debug!("Session handoff enabled: {:?}", session_path);
-        // TODO: Implement handoff coordination
-        // This will be done in Phase 2 when we create the coordination module
+    if let Some(ref mut handoff) = handoff {
+        handoff.begin();
     }
 
     // Run the ICS application
-    app.run().await?;
+    let outcome = app.run().await;
+    if let Some(ref handoff) = handoff {
+        handoff.finish(&outcome)?;
+    }
+    outcome?;
 
     debug!("ICS exiting cleanly");
 
@@ -243,8 +337,57 @@ pub async fn handle(
     })
     .await;
 
-    // TODO: If session_context provided, write result
-    // This will be done in Phase 2
-
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mnemosyne_core::coordination::EditContext;
+    use tempfile::TempDir;
+
+    fn intent(file_path: PathBuf) -> EditIntent {
+        EditIntent {
+            session_id: "session-1".to_string(),
+            timestamp: chrono::Utc::now(),
+            action: "edit".to_string(),
+            file_path,
+            template: None,
+            readonly: false,
+            panel: None,
+            context: EditContext {
+                conversation_summary: String::new(),
+                relevant_memories: vec![],
+                related_files: vec![],
+            },
+            writer_pid: None,
+        }
+    }
+
+    #[test]
+    fn test_handoff_reports_saved_changes() {
+        let temp_dir = TempDir::new().unwrap();
+        let session_dir = temp_dir.path().join("sessions");
+        let file = temp_dir.path().join("context.md");
+        std::fs::write(&file, "# Context\n").unwrap();
+
+        let coordinator = HandoffCoordinator::new(session_dir.clone()).unwrap();
+        let intent_path = coordinator.write_intent(&intent(file.clone())).unwrap();
+
+        let mut handoff = SessionHandoff::accept(&intent_path, None)
+            .unwrap()
+            .expect("fresh intent is accepted");
+        handoff.begin();
+        std::fs::write(&file, "# Context\n\nEdited\n").unwrap();
+        handoff.finish(&Ok(())).unwrap();
+
+        let result: EditResult = serde_json::from_str(
+            &std::fs::read_to_string(session_dir.join("edit-result.json")).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(result.session_id, "session-1");
+        assert_eq!(result.file_path, file);
+        assert!(result.changes_made);
+        assert!(matches!(result.exit_reason, ExitReason::UserSaved));
+    }
+}
@@ -1,31 +1,84 @@
 //! Embedding generation command
+//!
+//! Memories already embedded with the current model are skipped unless
+//! `--force` is given. A forced run checkpoints its position next to the
+//! database and resumes from it if interrupted.
 
 use mnemosyne_core::orchestration::events::AgentEvent;
 use mnemosyne_core::{
-    error::Result, ConnectionMode, EmbeddingConfig, LibsqlStorage, LocalEmbeddingService, MemoryId,
-    Namespace, StorageBackend,
+    backfill_embeddings, error::Result, BackfillOptions, BackfillProgress, ConfigManager,
+    ConnectionMode, EmbeddingConfig, EmbeddingService, LibsqlStorage, LocalEmbeddingService,
+    MemoryId, Namespace, StorageBackend,
 };
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use tracing::warn;
 use uuid::Uuid;
 
 use super::event_helpers;
 use super::helpers::get_db_path;
 
+/// Position of an interrupted `embed --force` run
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+struct Checkpoint {
+    model: String,
+    namespace: Option<String>,
+    last_id: MemoryId,
+}
+
+fn checkpoint_path(db_path: &str) -> PathBuf {
+    PathBuf::from(format!("{}.embed-checkpoint", db_path))
+}
+
+/// Resume point left by an earlier run with the same model and scope
+fn load_checkpoint(path: &Path, model: &str, namespace: &Option<String>) -> Option<MemoryId> {
+    let content = std::fs::read_to_string(path).ok()?;
+    let checkpoint: Checkpoint = serde_json::from_str(&content).ok()?;
+    (checkpoint.model == model && &checkpoint.namespace == namespace).then_some(checkpoint.last_id)
+}
+
+fn save_checkpoint(path: &Path, checkpoint: &Checkpoint) {
+    let saved = serde_json::to_string(checkpoint)
+        .map_err(|e| e.to_string())
+        .and_then(|json| std::fs::write(path, json).map_err(|e| e.to_string()));
+    if let Err(e) = saved {
+        warn!("Failed to write embed checkpoint {}: {}", path.display(), e);
+    }
+}
+
+fn print_progress(progress: &BackfillProgress) {
+    use std::io::Write;
+    print!(
+        "\rProgress: {}/{} (embedded {}, skipped {}, failed {}) ",
+        progress.processed(),
+        progress.total,
+        progress.embedded,
+        progress.skipped,
+        progress.failed
+    );
+    std::io::stdout().flush().unwrap();
+}
+
 /// Handle embedding generation command
+#[allow(clippy::too_many_arguments)]
 pub async fn handle(
     all: bool,
     memory_id: Option<String>,
     namespace: Option<String>,
     batch_size: usize,
+    concurrency: usize,
+    force: bool,
     progress: bool,
     global_db_path: Option<String>,
 ) -> Result<()> {
     event_helpers::with_event_lifecycle("embed", vec![], async {
         // Initialize embedding service
         println!("Initializing local embedding service...");
-        let embedding_config = EmbeddingConfig::default();
-        let model_name = embedding_config.model.clone();
+        let embedding_config: EmbeddingConfig =
+            ConfigManager::load_section("embedding settings", |s| s.embeddings);
         let embedding_service = Arc::new(LocalEmbeddingService::new(embedding_config).await?);
+        let model_name = embedding_service.model_name().to_string();
 
         // Initialize storage
         let db_path = get_db_path(global_db_path);
@@ -34,122 +87,126 @@ pub async fn handle(
         // Set embedding service on storage
         storage.set_embedding_service(embedding_service.clone());
 
-        // Determine which memories to embed
-        let memories = if let Some(id_str) = memory_id {
-            // Single memory
+        if let Some(id_str) = memory_id {
+            // Single memory: always (re-)embedded
             let uuid = Uuid::parse_str(&id_str)
                 .map_err(|e| anyhow::anyhow!("Invalid memory ID: {}", e))?;
-            let id = MemoryId(uuid);
-            vec![storage.get_memory(id).await?]
-        } else {
-            // Fetch all memories using search with empty query
-            let ns = if let Some(ns_str) = namespace {
-                println!("Fetching memories in namespace '{}'...", ns_str);
-                Some(if ns_str.starts_with("project:") {
-                    let project = ns_str.strip_prefix("project:").unwrap();
-                    Namespace::Project {
-                        name: project.to_string(),
-                    }
-                } else if ns_str.starts_with("session:") {
-                    let parts: Vec<&str> = ns_str
-                        .strip_prefix("session:")
-                        .unwrap()
-                        .split(':')
-                        .collect();
-                    if parts.len() == 2 {
-                        Namespace::Session {
-                            project: parts[0].to_string(),
-                            session_id: parts[1].to_string(),
-                        }
-                    } else {
-                        Namespace::Global
+            let memory = storage.get_memory(MemoryId(uuid)).await?;
+
+            let embed_start = std::time::Instant::now();
+            storage
+                .generate_and_store_embedding(&memory.id, &memory.content)
+                .await?;
+            event_helpers::emit_domain_event(AgentEvent::EmbeddingGenerated {
+                memory_id: memory.id.0.to_string(),
+                model_name: model_name.clone(),
+                dimension: 768, // Default dimension for nomic-embed-text-v1.5
+                duration_ms: embed_start.elapsed().as_millis() as u64,
+            })
+            .await;
+
+            println!("Embedded memory {}", memory.id);
+            return Ok(());
+        }
+
+        let ns = if let Some(ns_str) = &namespace {
+            println!("Selecting memories in namespace '{}'...", ns_str);
+            Some(if ns_str.starts_with("project:") {
+                let project = ns_str.strip_prefix("project:").unwrap();
+                Namespace::Project {
+                    name: project.to_string(),
+                }
+            } else if ns_str.starts_with("session:") {
+                let parts: Vec<&str> = ns_str
+                    .strip_prefix("session:")
+                    .unwrap()
+                    .split(':')
+                    .collect();
+                if parts.len() == 2 {
+                    Namespace::Session {
+                        project: parts[0].to_string(),
+                        session_id: parts[1].to_string(),
                     }
                 } else {
                     Namespace::Global
-                })
-            } else if all {
-                println!("Fetching all memories...");
-                None
+                }
             } else {
-                eprintln!("Error: Must specify --all, --memory-id, or --namespace");
-                std::process::exit(1);
-            };
+                Namespace::Global
+            })
+        } else if all {
+            println!("Selecting all memories...");
+            None
+        } else {
+            eprintln!("Error: Must specify --all, --memory-id, or --namespace");
+            std::process::exit(1);
+        };
 
-            // Use hybrid_search with empty query to get all memories
-            let results = storage.hybrid_search("", ns, 10000, false).await?;
-            results.into_iter().map(|r| r.memory).collect()
+        // Unforced runs resume on their own: finished memories are skipped
+        let checkpoint_file = checkpoint_path(&db_path);
+        let resume_after = if force {
+            load_checkpoint(&checkpoint_file, &model_name, &namespace)
+        } else {
+            None
         };
+        if let Some(last_id) = resume_after {
+            println!("Resuming from checkpoint after memory {}", last_id);
+        }
 
-        let total = memories.len();
-        println!("Generating embeddings for {} memories...", total);
+        let options = BackfillOptions {
+            namespace: ns,
+            force,
+            batch_size,
+            resume_after,
+            concurrency,
+        };
 
-        // Process memories in batches
-        let mut processed = 0;
-        let mut succeeded = 0;
-        let mut failed = 0;
         let batch_start = std::time::Instant::now();
-
-        for chunk in memories.chunks(batch_size) {
-            for memory in chunk {
-                processed += 1;
-
-                if progress {
-                    print!("\rProgress: {}/{} ", processed, total);
-                    use std::io::Write;
-                    std::io::stdout().flush().unwrap();
-                }
-
-                let embed_start = std::time::Instant::now();
-                match storage
-                    .generate_and_store_embedding(&memory.id, &memory.content)
-                    .await
-                {
-                    Ok(_) => {
-                        succeeded += 1;
-                        let duration_ms = embed_start.elapsed().as_millis() as u64;
-
-                        // For single memory operations, emit individual event
-                        if total == 1 {
-                            event_helpers::emit_domain_event(AgentEvent::EmbeddingGenerated {
-                                memory_id: memory.id.0.to_string(),
-                                model_name: model_name.clone(),
-                                dimension: 768, // Default dimension for nomic-embed-text-v1.5
-                                duration_ms,
-                            })
-                            .await;
-                        }
-                    }
-                    Err(e) => {
-                        if progress {
-                            eprintln!("\nFailed to embed memory {}: {}", memory.id, e);
-                        }
-                        failed += 1;
+        let summary = backfill_embeddings(
+            &storage,
+            embedding_service.as_ref(),
+            &options,
+            |batch_progress| {
+                if force {
+                    if let Some(last_id) = batch_progress.last_id {
+                        save_checkpoint(
+                            &checkpoint_file,
+                            &Checkpoint {
+                                model: model_name.clone(),
+                                namespace: namespace.clone(),
+                                last_id,
+                            },
+                        );
                     }
                 }
-            }
-        }
+                if progress {
+                    print_progress(batch_progress);
+                }
+            },
+        )
+        .await?;
 
         if progress {
             println!();
         }
+        if force {
+            let _ = std::fs::remove_file(&checkpoint_file);
+        }
 
-        let total_duration_ms = batch_start.elapsed().as_millis() as u64;
-
-        // For batch operations (multiple memories), emit batch completed event
-        if total > 1 {
+        if summary.embedded + summary.failed > 0 {
             event_helpers::emit_domain_event(AgentEvent::EmbeddingBatchCompleted {
-                batch_size: total,
-                successful: succeeded,
-                failed,
-                total_duration_ms,
+                batch_size: summary.embedded + summary.failed,
+                successful: summary.embedded,
+                failed: summary.failed,
+                total_duration_ms: batch_start.elapsed().as_millis() as u64,
             })
             .await;
         }
 
         println!("Embedding generation complete!");
-        println!("  Total: {}", total);
-        println!("  Succeeded: {}", succeeded);
-        println!("  Failed: {}", failed);
+        println!("  Total: {}", summary.total);
+        println!("  Embedded: {}", summary.embedded);
+        println!("  Skipped: {}", summary.skipped);
+        println!("  Failed: {}", summary.failed);
 
         Ok(())
     })
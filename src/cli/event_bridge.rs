@@ -145,6 +145,17 @@ async fn is_api_server_available() -> bool {
 /// # Returns
 /// * `Ok(())` whether event was sent successfully or not (never fails)
 pub async fn emit_event(event: AgentEvent) -> Result<(), Box<dyn std::error::Error>> {
+    emit_correlated_event(event, None).await
+}
+
+/// Emit an event carrying a correlation ID shared with related events
+///
+/// Used for command lifecycle events so the dashboard can pair a start with
+/// its completion even when the same command runs concurrently.
+async fn emit_correlated_event(
+    event: AgentEvent,
+    correlation_id: Option<&str>,
+) -> Result<(), Box<dyn std::error::Error>> {
     tracing::debug!("Attempting to emit event: {}", event.summary());
 
     // Check if server is available
@@ -158,7 +169,10 @@ pub async fn emit_event(event: AgentEvent) -> Result<(), Box<dyn std::error::Err
 
     // Convert to API event
     let api_event = match event_to_api_event(&event) {
-        Some(e) => e,
+        Some(e) => match correlation_id {
+            Some(id) => e.with_correlation_id(id),
+            None => e,
+        },
         None => {
             tracing::debug!(
                 "Event type not mapped to API event, skipping: {}",
@@ -557,36 +571,50 @@ fn event_to_api_event(event: &AgentEvent) -> Option<mnemosyne_core::api::Event>
 }
 
 /// Emit a CLI command started event
-pub async fn emit_command_started(command: &str, args: Vec<String>) {
+///
+/// Returns the correlation ID to pass to the matching completed/failed event.
+pub async fn emit_command_started(command: &str, args: Vec<String>) -> String {
+    let correlation_id = uuid::Uuid::new_v4().to_string();
     let event = AgentEvent::CliCommandStarted {
         command: command.to_string(),
         args,
         timestamp: chrono::Utc::now(),
     };
 
-    let _ = emit_event(event).await;
+    let _ = emit_correlated_event(event, Some(&correlation_id)).await;
+    correlation_id
 }
 
 /// Emit a CLI command completed event
-pub async fn emit_command_completed(command: &str, duration_ms: u64, result_summary: String) {
+pub async fn emit_command_completed(
+    command: &str,
+    duration_ms: u64,
+    result_summary: String,
+    correlation_id: &str,
+) {
     let event = AgentEvent::CliCommandCompleted {
         command: command.to_string(),
         duration_ms,
         result_summary,
     };
 
-    let _ = emit_event(event).await;
+    let _ = emit_correlated_event(event, Some(correlation_id)).await;
 }
 
 /// Emit a CLI command failed event
-pub async fn emit_command_failed(command: &str, error: String, duration_ms: u64) {
+pub async fn emit_command_failed(
+    command: &str,
+    error: String,
+    duration_ms: u64,
+    correlation_id: &str,
+) {
     let event = AgentEvent::CliCommandFailed {
         command: command.to_string(),
         error,
         duration_ms,
     };
 
-    let _ = emit_event(event).await;
+    let _ = emit_correlated_event(event, Some(correlation_id)).await;
 }
 
 #[cfg(test)]
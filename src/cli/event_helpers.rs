@@ -76,7 +76,7 @@ where
     let start = std::time::Instant::now();
 
     // Emit started event
-    event_bridge::emit_command_started(command, args.clone()).await;
+    let correlation_id = event_bridge::emit_command_started(command, args.clone()).await;
 
     // Execute handler
     let result = handler.await;
@@ -86,10 +86,17 @@ where
     // Emit completed or failed event
     match &result {
         Ok(_) => {
-            event_bridge::emit_command_completed(command, duration_ms, "Success".to_string()).await;
+            event_bridge::emit_command_completed(
+                command,
+                duration_ms,
+                "Success".to_string(),
+                &correlation_id,
+            )
+            .await;
         }
         Err(e) => {
-            event_bridge::emit_command_failed(command, e.to_string(), duration_ms).await;
+            event_bridge::emit_command_failed(command, e.to_string(), duration_ms, &correlation_id)
+                .await;
         }
     }
 
@@ -136,7 +143,7 @@ where
     let start = std::time::Instant::now();
 
     // Emit started event
-    event_bridge::emit_command_started(command, args.clone()).await;
+    let correlation_id = event_bridge::emit_command_started(command, args.clone()).await;
 
     // Execute handler
     let result = handler.await;
@@ -147,10 +154,12 @@ where
     match &result {
         Ok(value) => {
             let summary = result_summary_fn(value);
-            event_bridge::emit_command_completed(command, duration_ms, summary).await;
+            event_bridge::emit_command_completed(command, duration_ms, summary, &correlation_id)
+                .await;
         }
         Err(e) => {
-            event_bridge::emit_command_failed(command, e.to_string(), duration_ms).await;
+            event_bridge::emit_command_failed(command, e.to_string(), duration_ms, &correlation_id)
+                .await;
         }
     }
 
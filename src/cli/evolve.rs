@@ -89,7 +89,8 @@ pub async fn handle(job: EvolveJob, global_db_path: Option<String>) -> Result<()
     };
 
     // Emit CLI command started event
-    event_bridge::emit_command_started("evolve", vec![job_name.to_string()]).await;
+    let correlation_id =
+        event_bridge::emit_command_started("evolve", vec![job_name.to_string()]).await;
 
     // Emit evolution started event
     let evolve_start_event = AgentEvent::EvolveStarted {
@@ -305,6 +306,7 @@ pub async fn handle(job: EvolveJob, global_db_path: Option<String>) -> Result<()
         "evolve",
         duration_ms,
         format!("Evolution {} complete", job_name),
+        &correlation_id,
     )
     .await;
 
@@ -1,24 +1,104 @@
 //! Memory export command
+//!
+//! JSON and JSONL exports contain complete `MemoryNote` records (links,
+//! embeddings, timestamps) and can be restored with `mnemosyne import`.
+//! Markdown is a lossy, human-readable rendering.
 
+use chrono::{DateTime, NaiveDate, Utc};
 use mnemosyne_core::{
     error::{MnemosyneError, Result},
     orchestration::events::AgentEvent,
-    storage::MemorySortOrder,
-    ConnectionMode, LibsqlStorage, Namespace, StorageBackend,
+    storage::{MemoryFilter, MemorySortOrder},
+    ConnectionMode, LibsqlStorage, MemoryNote, MemoryType, Namespace, StorageBackend,
 };
 use std::{io::Write, path::PathBuf};
 use tracing::debug;
 
 use super::event_helpers;
-use super::helpers::get_db_path;
+use super::helpers::{get_db_path, parse_memory_type_filter};
+
+/// Supported export formats
+const EXPORT_FORMATS: &[&str] = &["json", "jsonl", "markdown"];
+
+/// Pick the export format: explicit `--format`, else the output extension,
+/// else JSON
+fn resolve_format(format: Option<&str>, output: Option<&str>) -> Result<&'static str> {
+    if let Some(requested) = format {
+        let requested = match requested {
+            "md" => "markdown",
+            other => other,
+        };
+        return EXPORT_FORMATS
+            .iter()
+            .copied()
+            .find(|f| *f == requested)
+            .ok_or_else(|| {
+                MnemosyneError::ValidationError(format!(
+                    "Unsupported export format: {} (expected one of: {})",
+                    requested,
+                    EXPORT_FORMATS.join(", ")
+                ))
+            });
+    }
+
+    Ok(match output {
+        Some(path) if path.ends_with(".jsonl") => "jsonl",
+        Some(path) if path.ends_with(".md") || path.ends_with(".markdown") => "markdown",
+        _ => "json",
+    })
+}
+
+/// Parse `--since` as an RFC 3339 timestamp or a `YYYY-MM-DD` date (UTC midnight)
+fn parse_since(since: &str) -> Result<DateTime<Utc>> {
+    if let Ok(ts) = DateTime::parse_from_rfc3339(since) {
+        return Ok(ts.with_timezone(&Utc));
+    }
+    NaiveDate::parse_from_str(since, "%Y-%m-%d")
+        .map(|date| date.and_hms_opt(0, 0, 0).unwrap().and_utc())
+        .map_err(|_| {
+            MnemosyneError::ValidationError(format!(
+                "Invalid --since value: {} (expected YYYY-MM-DD or RFC 3339)",
+                since
+            ))
+        })
+}
+
+/// Human-readable heading for a memory type (e.g. "Architecture Decision")
+fn type_heading(memory_type: &MemoryType) -> String {
+    memory_type
+        .as_str()
+        .split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect::<String>(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Filters applied to an export (all must match)
+#[derive(Debug, Default)]
+pub struct ExportFilters {
+    pub namespace: Option<String>,
+    pub memory_type: Option<String>,
+    pub tags: Vec<String>,
+    pub min_importance: Option<u8>,
+    pub since: Option<String>,
+}
 
 /// Handle memory export command
 pub async fn handle(
     output: Option<String>,
-    namespace: Option<String>,
+    filters: ExportFilters,
+    format: Option<String>,
     global_db_path: Option<String>,
 ) -> Result<()> {
+    let namespace = filters.namespace;
     let start_time = std::time::Instant::now();
+    let format = resolve_format(format.as_deref(), output.as_deref())?;
 
     if let Some(ref out_path) = output {
         debug!("Exporting memories to {}...", out_path);
@@ -63,26 +143,36 @@ pub async fn handle(
         }
     });
 
-    // Query all memories (or filtered by namespace)
-    let memories = storage
-        .list_memories(ns, 10000, MemorySortOrder::Recent)
+    let filter = MemoryFilter {
+        namespace: ns,
+        memory_type: filters
+            .memory_type
+            .as_deref()
+            .map(parse_memory_type_filter)
+            .transpose()?,
+        tags: filters.tags,
+        min_importance: filters.min_importance,
+        since: filters.since.as_deref().map(parse_since).transpose()?,
+    };
+
+    // Query matching memories (filters are applied in SQL)
+    let listed = storage
+        .list_memories_filtered(&filter, 10000, MemorySortOrder::Recent)
         .await?;
 
-    // Determine output format and destination
-    let (format, use_stdout) = if let Some(ref path) = output {
-        let fmt = if path.ends_with(".jsonl") {
-            "jsonl"
-        } else if path.ends_with(".md") || path.ends_with(".markdown") {
-            "markdown"
-        } else {
-            "json" // default
-        };
-        (fmt, false)
+    // Lossless formats need links, which listing doesn't load
+    let memories: Vec<MemoryNote> = if format == "markdown" {
+        listed
     } else {
-        // Default to JSON for stdout
-        ("json", true)
+        let mut full = Vec::with_capacity(listed.len());
+        for memory in listed {
+            full.push(storage.get_memory(memory.id).await?);
+        }
+        full
     };
 
+    let use_stdout = output.is_none();
+
     // Track output size for event emission
     let mut output_size_bytes = 0usize;
 
@@ -120,45 +210,59 @@ pub async fn handle(
                 bytes_written += line.len();
                 writer.write_all(line.as_bytes())?;
 
-                for (i, memory) in memories.iter().enumerate() {
-                    let lines = vec![
-                        format!("## {}. {}\n\n", i + 1, memory.summary),
-                        format!("**ID**: {}\n", memory.id),
-                        format!(
-                            "**Namespace**: {}\n",
-                            serde_json::to_string(&memory.namespace)?
-                        ),
-                        format!("**Importance**: {}/10\n", memory.importance),
-                        format!("**Type**: {:?}\n", memory.memory_type),
-                        format!(
-                            "**Created**: {}\n",
-                            memory.created_at.format("%Y-%m-%d %H:%M:%S")
-                        ),
-                    ];
-                    for line in lines {
-                        bytes_written += line.len();
-                        writer.write_all(line.as_bytes())?;
+                // Group by type, in order of each type's most recent memory
+                let mut groups: Vec<(MemoryType, Vec<&MemoryNote>)> = Vec::new();
+                for memory in &memories {
+                    match groups.iter_mut().find(|(t, _)| *t == memory.memory_type) {
+                        Some((_, group)) => group.push(memory),
+                        None => groups.push((memory.memory_type.clone(), vec![memory])),
                     }
+                }
 
-                    if !memory.tags.is_empty() {
-                        let line = format!("**Tags**: {}\n", memory.tags.join(", "));
-                        bytes_written += line.len();
-                        writer.write_all(line.as_bytes())?;
-                    }
-                    if !memory.keywords.is_empty() {
-                        let line = format!("**Keywords**: {}\n", memory.keywords.join(", "));
-                        bytes_written += line.len();
-                        writer.write_all(line.as_bytes())?;
-                    }
+                for (memory_type, group) in groups {
+                    let line = format!("## {} ({})\n\n", type_heading(&memory_type), group.len());
+                    bytes_written += line.len();
+                    writer.write_all(line.as_bytes())?;
+
+                    for (i, memory) in group.iter().enumerate() {
+                        let lines = vec![
+                            format!("### {}. {}\n\n", i + 1, memory.summary),
+                            format!("**ID**: {}\n", memory.id),
+                            format!(
+                                "**Namespace**: {}\n",
+                                serde_json::to_string(&memory.namespace)?
+                            ),
+                            format!("**Importance**: {}/10\n", memory.importance),
+                            format!(
+                                "**Created**: {}\n",
+                                memory.created_at.format("%Y-%m-%d %H:%M:%S")
+                            ),
+                        ];
+                        for line in lines {
+                            bytes_written += line.len();
+                            writer.write_all(line.as_bytes())?;
+                        }
+
+                        if !memory.tags.is_empty() {
+                            let line = format!("**Tags**: {}\n", memory.tags.join(", "));
+                            bytes_written += line.len();
+                            writer.write_all(line.as_bytes())?;
+                        }
+                        if !memory.keywords.is_empty() {
+                            let line = format!("**Keywords**: {}\n", memory.keywords.join(", "));
+                            bytes_written += line.len();
+                            writer.write_all(line.as_bytes())?;
+                        }
 
-                    let lines = vec![
-                        "\n### Content\n\n".to_string(),
-                        format!("{}\n\n", memory.content),
-                        "---\n\n".to_string(),
-                    ];
-                    for line in lines {
-                        bytes_written += line.len();
-                        writer.write_all(line.as_bytes())?;
+                        let lines = vec![
+                            "\n#### Content\n\n".to_string(),
+                            format!("{}\n\n", memory.content),
+                            "---\n\n".to_string(),
+                        ];
+                        for line in lines {
+                            bytes_written += line.len();
+                            writer.write_all(line.as_bytes())?;
+                        }
                     }
                 }
             }
@@ -0,0 +1,41 @@
+//! Permanent memory deletion command
+
+use mnemosyne_core::{
+    error::{MnemosyneError, Result},
+    ConnectionMode, LibsqlStorage, MemoryId, StorageBackend,
+};
+
+use super::event_helpers;
+use super::helpers::get_db_path;
+
+/// Handle forget command
+///
+/// Without `confirm` this only shows the memory that would be deleted.
+pub async fn handle(id: String, confirm: bool, global_db_path: Option<String>) -> Result<()> {
+    let mut args = vec![id.clone()];
+    if confirm {
+        args.push("--confirm".to_string());
+    }
+
+    event_helpers::with_event_lifecycle("forget", args, async {
+        let memory_id =
+            MemoryId::from_string(&id).map_err(|e| MnemosyneError::InvalidId(e.to_string()))?;
+        let db_path = get_db_path(global_db_path);
+        let storage = LibsqlStorage::new(ConnectionMode::Local(db_path)).await?;
+        let memory = storage.get_memory(memory_id).await?;
+
+        if !confirm {
+            println!("{} [{}]: {}", memory.id, memory.namespace, memory.summary);
+            println!(
+                "This permanently deletes the memory with its links, history, and evaluation records."
+            );
+            println!("Re-run with --confirm to delete it.");
+            return Ok(());
+        }
+
+        storage.delete_memory(memory_id).await?;
+        println!("Deleted memory {}", memory_id);
+        Ok(())
+    })
+    .await
+}
@@ -1,19 +1,31 @@
+//! Memory link graph export (`mnemosyne graph`)
+//!
+//! Traverses links outward from seed memories (query matches, or the most
+//! important memories) and renders the subgraph as Graphviz DOT, Mermaid, or
+//! JSON. Nodes are labeled by summary and edges by `LinkType`; edges to
+//! memories outside the rendered subgraph are dropped.
+
 use crate::cli::helpers::get_db_path;
 use mnemosyne_core::{
     error::Result,
     storage::{MemorySortOrder, StorageBackend},
-    types::{MemoryNote, Namespace},
+    types::{MemoryId, MemoryLink, MemoryNote, Namespace},
     ConnectionMode, LibsqlStorage,
 };
+use std::collections::HashSet;
 use std::fs::File;
 use std::io::Write;
 
+/// Seeds taken from the most important memories when no query is given
+const IMPORTANT_SEEDS: usize = 10;
+
 pub async fn handle(
     format: String,
     depth: usize,
     output: Option<String>,
     query: Option<String>,
     namespace_str: Option<String>,
+    max_nodes: usize,
     db_path: Option<String>,
 ) -> Result<()> {
     let db_path = get_db_path(db_path);
@@ -50,25 +62,36 @@ pub async fn handle(
         None
     };
 
-    let memories = if let Some(q) = query {
-        let seeds = storage.keyword_search(&q, namespace.clone()).await?;
+    let seeds = if let Some(q) = query {
+        let seeds: Vec<_> = storage
+            .keyword_search(&q, namespace.clone())
+            .await?
+            .into_iter()
+            .map(|s| s.memory)
+            .collect();
         if seeds.is_empty() {
             eprintln!("No memories found matching query '{}'", q);
             return Ok(());
         }
-        let seed_ids: Vec<_> = seeds.iter().map(|s| s.memory.id).collect();
-        storage.graph_traverse(&seed_ids, depth, namespace).await?
+        seeds
     } else {
-        // No query, list top memories (limit 100 to avoid huge graphs)
         storage
-            .list_memories(namespace, 100, MemorySortOrder::Importance)
+            .list_memories(
+                namespace.clone(),
+                IMPORTANT_SEEDS,
+                MemorySortOrder::Importance,
+            )
             .await?
     };
 
+    let seed_ids: Vec<_> = seeds.iter().map(|m| m.id).collect();
+    let reached = storage.graph_traverse(&seed_ids, depth, namespace).await?;
+    let memories = cap_subgraph(seeds, reached, max_nodes);
+
     let output_content = match format.as_str() {
         "dot" => generate_dot(&memories),
         "mermaid" => generate_mermaid(&memories),
-        "json" => serde_json::to_string_pretty(&memories).unwrap(),
+        "json" => serde_json::to_string_pretty(&generate_json(&memories))?,
         _ => {
             eprintln!(
                 "Unknown format: {}. Supported formats: dot, mermaid, json",
@@ -88,28 +111,51 @@ pub async fn handle(
     Ok(())
 }
 
+/// Seeds first, then the rest by importance, up to `max_nodes` unique memories
+fn cap_subgraph(
+    seeds: Vec<MemoryNote>,
+    mut reached: Vec<MemoryNote>,
+    max_nodes: usize,
+) -> Vec<MemoryNote> {
+    reached.sort_by(|a, b| b.importance.cmp(&a.importance));
+
+    let mut seen = HashSet::new();
+    seeds
+        .into_iter()
+        .chain(reached)
+        .filter(|m| seen.insert(m.id))
+        .take(max_nodes)
+        .collect()
+}
+
+/// Links whose target is part of the rendered subgraph
+fn edges(memories: &[MemoryNote]) -> Vec<(&MemoryNote, &MemoryLink)> {
+    let ids: HashSet<MemoryId> = memories.iter().map(|m| m.id).collect();
+    memories
+        .iter()
+        .flat_map(|m| m.links.iter().map(move |l| (m, l)))
+        .filter(|(_, l)| ids.contains(&l.target_id))
+        .collect()
+}
+
 fn generate_dot(memories: &[MemoryNote]) -> String {
     let mut dot = String::from(
         "digraph G {\n  rankdir=LR;\n  node [shape=box style=filled fillcolor=\"#f0f0f0\"];\n",
     );
 
     for memory in memories {
-        let short_id: String = memory.id.to_string().chars().take(8).collect();
-        let label = format!("{}|{:?}", short_id, memory.memory_type);
-
         dot.push_str(&format!(
-            "  \"{}\" [label=\"{}\" tooltip=\"{}\"];\n",
+            "  \"{}\" [label=\"{}\" tooltip=\"{:?}\"];\n",
             memory.id,
-            label,
-            escape_dot_string(&memory.summary)
+            escape_dot_string(&memory.summary),
+            memory.memory_type
+        ));
+    }
+    for (memory, link) in edges(memories) {
+        dot.push_str(&format!(
+            "  \"{}\" -> \"{}\" [label=\"{:?}\"];\n",
+            memory.id, link.target_id, link.link_type
         ));
-
-        for link in &memory.links {
-            dot.push_str(&format!(
-                "  \"{}\" -> \"{}\" [label=\"{:?}\"];\n",
-                memory.id, link.target_id, link.link_type
-            ));
-        }
     }
     dot.push_str("}\n");
     dot
@@ -119,29 +165,58 @@ fn generate_mermaid(memories: &[MemoryNote]) -> String {
     let mut mm = String::from("graph TD\n");
 
     for memory in memories {
-        let id_clean = memory.id.to_string().replace("-", "");
-        let label = format!(
-            "{:?}: {}",
-            memory.memory_type,
+        mm.push_str(&format!(
+            "  {}[\"{}\"]\n",
+            mermaid_id(&memory.id),
             escape_mermaid_string(&memory.summary)
-        );
-
-        mm.push_str(&format!("  {}[{:?}]\n", id_clean, label));
-
-        for link in &memory.links {
-            let target_clean = link.target_id.to_string().replace("-", "");
-            let link_label = format!("{:?}", link.link_type);
-            mm.push_str(&format!(
-                "  {} -->|{}| {}\n",
-                id_clean, link_label, target_clean
-            ));
-        }
+        ));
+    }
+    for (memory, link) in edges(memories) {
+        mm.push_str(&format!(
+            "  {} -->|{:?}| {}\n",
+            mermaid_id(&memory.id),
+            link.link_type,
+            mermaid_id(&link.target_id)
+        ));
     }
     mm
 }
 
+fn generate_json(memories: &[MemoryNote]) -> serde_json::Value {
+    let nodes: Vec<_> = memories
+        .iter()
+        .map(|m| {
+            serde_json::json!({
+                "id": m.id.to_string(),
+                "label": m.summary,
+                "memory_type": m.memory_type,
+                "importance": m.importance,
+            })
+        })
+        .collect();
+    let edges: Vec<_> = edges(memories)
+        .into_iter()
+        .map(|(m, l)| {
+            serde_json::json!({
+                "source": m.id.to_string(),
+                "target": l.target_id.to_string(),
+                "link_type": l.link_type,
+                "strength": l.strength,
+            })
+        })
+        .collect();
+    serde_json::json!({ "nodes": nodes, "edges": edges })
+}
+
+/// Mermaid node IDs must not start with a digit or contain dashes
+fn mermaid_id(id: &MemoryId) -> String {
+    format!("m{}", id.to_string().replace('-', ""))
+}
+
 fn escape_dot_string(s: &str) -> String {
-    s.replace("\"", "\\\"").replace("\n", " ")
+    s.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', " ")
 }
 
 fn escape_mermaid_string(s: &str) -> String {
@@ -150,3 +225,120 @@ fn escape_mermaid_string(s: &str) -> String {
         .replace("[", "(")
         .replace("]", ")")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mnemosyne_core::types::{LinkType, MemoryType};
+
+    fn memory(summary: &str, importance: u8) -> MemoryNote {
+        let now = chrono::Utc::now();
+        MemoryNote {
+            id: MemoryId::new(),
+            namespace: Namespace::Global,
+            created_at: now,
+            updated_at: now,
+            content: summary.to_string(),
+            summary: summary.to_string(),
+            keywords: vec![],
+            tags: vec![],
+            context: String::new(),
+            memory_type: MemoryType::ArchitectureDecision,
+            importance,
+            confidence: 0.9,
+            links: vec![],
+            related_files: vec![],
+            related_entities: vec![],
+            access_count: 0,
+            last_accessed_at: now,
+            expires_at: None,
+            is_archived: false,
+            superseded_by: None,
+            embedding: None,
+            embedding_model: "test".to_string(),
+        }
+    }
+
+    fn link(from: &mut MemoryNote, to: &MemoryNote, link_type: LinkType) {
+        from.links.push(MemoryLink {
+            target_id: to.id,
+            link_type,
+            strength: 0.8,
+            reason: "test".to_string(),
+            created_at: chrono::Utc::now(),
+            last_traversed_at: None,
+            user_created: false,
+        });
+    }
+
+    /// a -Extends-> b -Implements-> c, plus a dangling link to an unrendered memory
+    fn small_graph() -> Vec<MemoryNote> {
+        let mut a = memory("Use \"libsql\" for storage", 9);
+        let mut b = memory("Schema [v2]", 7);
+        let c = memory("Migration\nscript", 5);
+        let outside = memory("Outside", 1);
+        link(&mut a, &b, LinkType::Extends);
+        link(&mut b, &c, LinkType::Implements);
+        link(&mut a, &outside, LinkType::References);
+        vec![a, b, c]
+    }
+
+    #[test]
+    fn test_dot_rendering() {
+        let graph = small_graph();
+        let dot = generate_dot(&graph);
+
+        assert!(dot.starts_with("digraph G {"));
+        assert!(dot.trim_end().ends_with('}'));
+        assert!(dot.contains(&format!(
+            "\"{}\" [label=\"Use \\\"libsql\\\" for storage\"",
+            graph[0].id
+        )));
+        assert!(dot.contains(&format!(
+            "\"{}\" -> \"{}\" [label=\"Extends\"];",
+            graph[0].id, graph[1].id
+        )));
+        assert!(dot.contains("[label=\"Implements\"]"));
+        // No edge to a memory outside the subgraph, and no raw newlines in labels
+        assert!(!dot.contains("References"));
+        assert_eq!(dot.matches(" -> ").count(), 2);
+        // header (3) + nodes (3) + edges (2) + closing brace
+        assert_eq!(dot.lines().count(), 9);
+        assert!(dot.contains("[label=\"Migration script\""));
+    }
+
+    #[test]
+    fn test_mermaid_rendering() {
+        let graph = small_graph();
+        let mm = generate_mermaid(&graph);
+        let mut lines = mm.lines();
+
+        assert_eq!(lines.next(), Some("graph TD"));
+        let a = mermaid_id(&graph[0].id);
+        let b = mermaid_id(&graph[1].id);
+        assert!(a.starts_with('m') && !a.contains('-'));
+        assert!(mm.contains(&format!("  {}[\"Use 'libsql' for storage\"]", a)));
+        assert!(mm.contains(&format!("  {}[\"Schema (v2)\"]", b)));
+        assert!(mm.contains("[\"Migration script\"]"));
+        assert!(mm.contains(&format!("  {} -->|Extends| {}", a, b)));
+        assert_eq!(mm.matches("-->").count(), 2);
+    }
+
+    #[test]
+    fn test_json_and_node_cap() {
+        let graph = small_graph();
+        let json = generate_json(&graph);
+        assert_eq!(json["nodes"].as_array().unwrap().len(), 3);
+        assert_eq!(json["edges"].as_array().unwrap().len(), 2);
+        assert_eq!(json["edges"][0]["link_type"], "extends");
+
+        // Seed kept first, then most important reached memories
+        let seed = graph[2].clone();
+        let capped = cap_subgraph(vec![seed.clone()], graph.clone(), 2);
+        assert_eq!(capped.len(), 2);
+        assert_eq!(capped[0].id, seed.id);
+        assert_eq!(capped[1].id, graph[0].id);
+        // Dropped node's edges go with it
+        assert_eq!(edges(&capped).len(), 0);
+    }
+}
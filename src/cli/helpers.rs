@@ -4,8 +4,12 @@
 //! including database path resolution, MCP server startup, and JSON parsing.
 
 use mnemosyne_core::{
-    error::Result, mcp::EventSink, services::embeddings::EmbeddingService, ConfigManager,
-    ConnectionMode, LibsqlStorage, LlmConfig, LlmService, McpServer, ToolHandler,
+    error::{MnemosyneError, Result},
+    mcp::EventSink,
+    services::embeddings::EmbeddingService,
+    start_memory_monitoring, BackgroundScheduler, ConfigManager, ConnectionMode, EvolutionConfig,
+    LeakThresholds, LibsqlStorage, LlmConfig, LlmService, McpServer, McpTransport,
+    MemoryTypesConfig, Namespace, SearchConfig, ToolHandler,
 };
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -157,8 +161,8 @@ pub async fn detect_api_server() -> Option<String> {
     None
 }
 
-/// Start MCP server in stdio mode
-pub async fn start_mcp_server(db_path_arg: Option<String>) -> Result<()> {
+/// Start MCP server on `transport`
+pub async fn start_mcp_server(db_path_arg: Option<String>, transport: McpTransport) -> Result<()> {
     debug!("Starting MCP server...");
 
     // Initialize configuration
@@ -174,10 +178,17 @@ pub async fn start_mcp_server(db_path_arg: Option<String>) -> Result<()> {
     }
 
     // MCP server should create database if it doesn't exist (for first-time setup)
-    let storage = LibsqlStorage::new_with_validation(ConnectionMode::Local(db_path), true).await?;
+    let mut storage =
+        LibsqlStorage::new_with_validation(ConnectionMode::Local(db_path), true).await?;
+    // Long-running: repeated recalls can be served from the recall cache
+    storage.set_search_config(configured_search_settings());
+    if let Err(e) = storage.apply_fts_tokenizer().await {
+        warn!("Could not apply the configured keyword tokenizer: {}", e);
+    }
+    let storage = Arc::new(storage);
 
     // Initialize LLM service (will error on first use if no API key)
-    let llm = match LlmService::with_default() {
+    let llm = match LlmService::configured() {
         Ok(service) => {
             debug!("LLM service initialized successfully");
             Arc::new(service)
@@ -189,11 +200,12 @@ pub async fn start_mcp_server(db_path_arg: Option<String>) -> Result<()> {
 
             // Create a dummy service - it will error on use
             // This allows the server to start for basic operations
-            Arc::new(LlmService::new(LlmConfig {
+            Arc::new(LlmService::configured_with(LlmConfig {
                 api_key: String::new(),
                 model: "claude-haiku-4-5-20251001".to_string(),
                 max_tokens: 1024,
                 temperature: 0.7,
+                task_models: Default::default(),
             })?)
         }
     };
@@ -201,8 +213,13 @@ pub async fn start_mcp_server(db_path_arg: Option<String>) -> Result<()> {
     // Initialize embedding service (shares API key with LLM)
     let embeddings = {
         let config = LlmConfig::default();
-        Arc::new(EmbeddingService::new(config.api_key.clone(), config))
+        Arc::new(
+            EmbeddingService::new(config.api_key.clone(), config)
+                .with_redactor(llm.redactor().clone()),
+        )
     };
+    // Vector search leaves out embeddings from other models when configured to
+    storage.set_active_embedding_model(Some(embeddings.model_name().to_string()));
 
     // Try to start API server for dashboard connectivity
     use mnemosyne_core::api::{ApiServer, ApiServerConfig};
@@ -221,8 +238,15 @@ pub async fn start_mcp_server(db_path_arg: Option<String>) -> Result<()> {
             // Owner mode: We successfully bound port 3000, start API server
             drop(listener); // Release the listener, ApiServer will rebind
 
-            let api_server = ApiServer::new(api_config);
+            let api_server = ApiServer::new(api_config)
+                .with_storage(storage.clone())
+                .with_scheduler(Arc::new(BackgroundScheduler::with_default_jobs(
+                    configured_evolution_settings(),
+                    storage.clone(),
+                )));
             let event_broadcaster = api_server.broadcaster().clone();
+            llm.usage_tracker()
+                .set_broadcaster(event_broadcaster.clone());
 
             info!("API server starting on port 3000 (owner mode)");
             info!("Dashboard: mnemosyne-dash --api http://127.0.0.1:3000");
@@ -252,9 +276,15 @@ pub async fn start_mcp_server(db_path_arg: Option<String>) -> Result<()> {
         }
     };
 
+    // Watch for memory growth for the life of the server
+    let leak_broadcaster = match &event_sink {
+        EventSink::Local(broadcaster) => Some(broadcaster.clone()),
+        _ => None,
+    };
+    let _memory_monitor = start_memory_monitoring(LeakThresholds::default(), leak_broadcaster);
+
     // Initialize tool handler with event sink
-    let tool_handler =
-        ToolHandler::new_with_event_sink(Arc::new(storage), llm, embeddings, event_sink);
+    let tool_handler = ToolHandler::new_with_event_sink(storage, llm, embeddings, event_sink);
 
     // Create and run MCP server
     let mcp_server = McpServer::new(tool_handler);
@@ -263,7 +293,7 @@ pub async fn start_mcp_server(db_path_arg: Option<String>) -> Result<()> {
     if let Some(api_task) = api_server_task {
         // Owner mode: Run both MCP and API server
         tokio::select! {
-            result = mcp_server.run() => {
+            result = mcp_server.serve(transport) => {
                 result?;
             }
             result = api_task => {
@@ -278,7 +308,7 @@ pub async fn start_mcp_server(db_path_arg: Option<String>) -> Result<()> {
     } else {
         // Client mode: Run only MCP server
         tokio::select! {
-            result = mcp_server.run() => {
+            result = mcp_server.serve(transport) => {
                 result?;
             }
             _ = tokio::signal::ctrl_c() => {
@@ -313,24 +343,37 @@ pub async fn start_mcp_server_with_api(
         std::fs::create_dir_all(parent)?;
     }
 
-    let storage = LibsqlStorage::new_with_validation(ConnectionMode::Local(db_path), true).await?;
+    let mut storage =
+        LibsqlStorage::new_with_validation(ConnectionMode::Local(db_path), true).await?;
+    // Long-running: repeated recalls can be served from the recall cache
+    storage.set_search_config(configured_search_settings());
+    if let Err(e) = storage.apply_fts_tokenizer().await {
+        warn!("Could not apply the configured keyword tokenizer: {}", e);
+    }
+    let storage = Arc::new(storage);
 
     // Initialize LLM service
-    let llm = match LlmService::with_default() {
+    let llm = match LlmService::configured() {
         Ok(service) => Arc::new(service),
-        Err(_) => Arc::new(LlmService::new(LlmConfig {
+        Err(_) => Arc::new(LlmService::configured_with(LlmConfig {
             api_key: String::new(),
             model: "claude-haiku-4-5-20251001".to_string(),
             max_tokens: 1024,
             temperature: 0.7,
+            task_models: Default::default(),
         })?),
     };
 
     // Initialize embedding service
     let embeddings = {
         let config = LlmConfig::default();
-        Arc::new(EmbeddingService::new(config.api_key.clone(), config))
+        Arc::new(
+            EmbeddingService::new(config.api_key.clone(), config)
+                .with_redactor(llm.redactor().clone()),
+        )
     };
+    // Vector search leaves out embeddings from other models when configured to
+    storage.set_active_embedding_model(Some(embeddings.model_name().to_string()));
 
     // Parse API server address
     let socket_addr: SocketAddr = api_addr
@@ -342,16 +385,26 @@ pub async fn start_mcp_server_with_api(
         addr: socket_addr,
         event_capacity: api_capacity,
     };
-    let api_server = ApiServer::new(api_config);
+    let api_server = ApiServer::new(api_config)
+        .with_storage(storage.clone())
+        .with_scheduler(Arc::new(BackgroundScheduler::with_default_jobs(
+            configured_evolution_settings(),
+            storage.clone(),
+        )));
     let event_broadcaster = api_server.broadcaster().clone();
+    llm.usage_tracker()
+        .set_broadcaster(event_broadcaster.clone());
 
     info!("API server will be available at http://{}", socket_addr);
     info!("Dashboard: mnemosyne-dash --api http://{}", socket_addr);
 
+    // Watch for memory growth for the life of the server
+    let _memory_monitor =
+        start_memory_monitoring(LeakThresholds::default(), Some(event_broadcaster.clone()));
+
     // Initialize tool handler with event broadcasting
     let event_sink = EventSink::Local(event_broadcaster);
-    let tool_handler =
-        ToolHandler::new_with_event_sink(Arc::new(storage), llm, embeddings, event_sink);
+    let tool_handler = ToolHandler::new_with_event_sink(storage, llm, embeddings, event_sink);
 
     // Create MCP server
     let mcp_server = McpServer::new(tool_handler);
@@ -377,9 +430,42 @@ pub async fn start_mcp_server_with_api(
     Ok(())
 }
 
-/// Parse memory type from string with support for aliases
-pub fn parse_memory_type(type_str: &str) -> mnemosyne_core::MemoryType {
-    match type_str.to_lowercase().as_str() {
+/// Parse a memory type to filter by
+///
+/// Unlike [`parse_memory_type_with`], an unknown name is an error: filtering by
+/// Insight instead would quietly return the wrong memories.
+pub fn parse_memory_type_filter(type_str: &str) -> Result<mnemosyne_core::MemoryType> {
+    if let Some(memory_type) = builtin_memory_type(type_str) {
+        return Ok(memory_type);
+    }
+    let declared = configured_memory_types();
+    if let Some(memory_type) = declared.resolve(&type_str.to_lowercase()) {
+        return Ok(memory_type);
+    }
+
+    let help = declared.help_text();
+    let message = if help.is_empty() {
+        format!("Unknown memory type '{}'", type_str)
+    } else {
+        format!(
+            "Unknown memory type '{}'. Declared custom types:\n{}",
+            type_str, help
+        )
+    };
+    Err(MnemosyneError::ValidationError(message))
+}
+
+/// Parse memory type, resolving custom names against `declared`
+pub fn parse_memory_type_with(
+    type_str: &str,
+    declared: &MemoryTypesConfig,
+) -> mnemosyne_core::MemoryType {
+    builtin_memory_type(type_str).unwrap_or_else(|| declared_memory_type(type_str, declared))
+}
+
+/// Built-in memory type for a canonical name or alias
+fn builtin_memory_type(type_str: &str) -> Option<mnemosyne_core::MemoryType> {
+    let memory_type = match type_str.to_lowercase().as_str() {
         // Canonical names and aliases
         "architecture_decision" | "architecture" | "decision" => {
             mnemosyne_core::MemoryType::ArchitectureDecision
@@ -403,7 +489,63 @@ pub fn parse_memory_type(type_str: &str) -> mnemosyne_core::MemoryType {
         "task_breakdown" | "tasks" | "breakdown" => mnemosyne_core::MemoryType::TaskBreakdown,
         "quality_checklist" | "checklist" | "qa" => mnemosyne_core::MemoryType::QualityChecklist,
         "clarification" | "clarify" => mnemosyne_core::MemoryType::Clarification,
-        // Default to Insight for unknown types
-        _ => mnemosyne_core::MemoryType::Insight,
+        _ => return None,
+    };
+    Some(memory_type)
+}
+
+/// Search settings from the config layers (`[search]`)
+fn configured_search_settings() -> SearchConfig {
+    ConfigManager::load_section("search settings", |s| s.search)
+}
+
+/// Evolution job schedules from the config layers (`[evolution]`)
+fn configured_evolution_settings() -> EvolutionConfig {
+    ConfigManager::load_section("evolution settings", |s| s.evolution)
+}
+
+/// Custom memory types declared in the config layers (`[memory_types.custom]`)
+fn configured_memory_types() -> MemoryTypesConfig {
+    ConfigManager::load_section("custom memory types", |s| s.memory_types)
+}
+
+/// Declared custom type named `name`, or Insight with a warning listing the
+/// declared types
+fn declared_memory_type(name: &str, declared: &MemoryTypesConfig) -> mnemosyne_core::MemoryType {
+    let name = name.to_lowercase();
+    if let Some(memory_type) = declared.resolve(&name) {
+        return memory_type;
+    }
+
+    let help = declared.help_text();
+    if help.is_empty() {
+        warn!("Unknown memory type '{}', using insight", name);
+    } else {
+        warn!(
+            "Unknown memory type '{}', using insight. Declared custom types:\n{}",
+            name, help
+        );
+    }
+    mnemosyne_core::MemoryType::Insight
+}
+
+/// Parse a `project:<name>` / `session:<project>:<id>` namespace, defaulting to global
+pub fn parse_namespace(ns_str: &str) -> Namespace {
+    if let Some(project) = ns_str.strip_prefix("project:") {
+        Namespace::Project {
+            name: project.to_string(),
+        }
+    } else if let Some(session) = ns_str.strip_prefix("session:") {
+        let parts: Vec<&str> = session.split(':').collect();
+        if parts.len() == 2 {
+            Namespace::Session {
+                project: parts[0].to_string(),
+                session_id: parts[1].to_string(),
+            }
+        } else {
+            Namespace::Global
+        }
+    } else {
+        Namespace::Global
     }
 }
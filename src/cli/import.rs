@@ -0,0 +1,63 @@
+//! Memory import command
+//!
+//! Restores a JSON or JSONL export produced by `mnemosyne export`. Memories
+//! keep their original IDs, links and embeddings, and the whole file is
+//! written in one transaction: if any memory fails (e.g. its ID already
+//! exists), nothing is imported.
+
+use mnemosyne_core::{
+    error::{MnemosyneError, Result},
+    ConnectionMode, LibsqlStorage, MemoryNote,
+};
+use std::io::Read;
+use tracing::debug;
+
+use super::helpers::get_db_path;
+
+/// Parse an export as a JSON array, falling back to JSONL
+fn parse_memories(input: &str) -> Result<Vec<MemoryNote>> {
+    if input.trim_start().starts_with('[') {
+        return Ok(serde_json::from_str(input)?);
+    }
+
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            serde_json::from_str(line).map_err(|e| {
+                MnemosyneError::ValidationError(format!("Invalid memory on line {}: {}", i + 1, e))
+            })
+        })
+        .collect()
+}
+
+/// Handle memory import command
+pub async fn handle(input: Option<String>, global_db_path: Option<String>) -> Result<()> {
+    let contents = match input {
+        Some(ref path) => {
+            debug!("Importing memories from {}...", path);
+            std::fs::read_to_string(path)?
+        }
+        None => {
+            debug!("Importing memories from stdin...");
+            let mut buf = String::new();
+            std::io::stdin().read_to_string(&mut buf)?;
+            buf
+        }
+    };
+
+    let memories = parse_memories(&contents)?;
+    if memories.is_empty() {
+        eprintln!(" No memories to import");
+        return Ok(());
+    }
+
+    let db_path = get_db_path(global_db_path);
+    let storage = LibsqlStorage::new_with_validation(ConnectionMode::Local(db_path), true).await?;
+
+    storage.store_memories_atomic(&memories).await?;
+
+    eprintln!(" Imported {} memories", memories.len());
+    Ok(())
+}
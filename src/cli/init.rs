@@ -1,21 +1,34 @@
 //! Database initialization command
+//!
+//! Idempotent: creates the database if missing, otherwise applies pending
+//! migrations. Either way it reports the schema version; `--check` reports
+//! without changing anything.
 
 use mnemosyne_core::{
     error::Result, orchestration::events::AgentEvent, ConnectionMode, LibsqlStorage,
+    MigrationStatus,
 };
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use tracing::debug;
 
 use super::event_helpers;
 use super::helpers::get_default_db_path;
 
 /// Handle database initialization command
-pub async fn handle(database: Option<String>, global_db_path: Option<String>) -> Result<()> {
+pub async fn handle(
+    database: Option<String>,
+    global_db_path: Option<String>,
+    check: bool,
+) -> Result<()> {
     // Use provided database path or fall back to global/default
     let db_path = database
         .or(global_db_path)
         .unwrap_or_else(|| get_default_db_path().to_string_lossy().to_string());
 
+    if check {
+        return report_status(&db_path).await;
+    }
+
     event_helpers::with_event_lifecycle(
         "init",
         vec![format!("--database={}", db_path)],
@@ -23,22 +36,37 @@ pub async fn handle(database: Option<String>, global_db_path: Option<String>) ->
             debug!("Initializing database...");
             debug!("Database path: {}", db_path);
 
-            // Create parent directory if it doesn't exist
-            if let Some(parent) = PathBuf::from(&db_path).parent() {
-                std::fs::create_dir_all(parent)?;
-                debug!("Created directory: {}", parent.display());
+            let existed = Path::new(&db_path).exists();
+            let before = if existed {
+                Some(read_status(&db_path).await?)
+            } else {
+                // Create parent directory if it doesn't exist
+                if let Some(parent) = PathBuf::from(&db_path).parent() {
+                    std::fs::create_dir_all(parent)?;
+                    debug!("Created directory: {}", parent.display());
+                }
+                None
+            };
+
+            if let Some(status) = before.as_ref().filter(|status| status.is_current()) {
+                println!(
+                    " Database already initialized: {} (schema version {})",
+                    db_path, status.target_version
+                );
+                return Ok(());
             }
 
-            // Initialize storage (this will create the database and run migrations)
-            // Init command explicitly creates database if missing
-            let _storage =
+            // Opening with create-if-missing applies every pending migration
+            let storage =
                 LibsqlStorage::new_with_validation(ConnectionMode::Local(db_path.clone()), true)
                     .await?;
+            let after = storage.migration_status().await?;
+            let version = after.schema_version.unwrap_or(after.target_version);
 
-            // Count migrations that would be applied for a new database
-            // Based on libsql.rs::run_migrations() - LibSQL has 6 migrations, StandardSQLite has 7
-            // Since this is a new database, all applicable migrations are run
-            let migrations_applied = if cfg!(feature = "libsql") { 6 } else { 7 };
+            let migrations_applied = match &before {
+                Some(status) => status.pending.len(),
+                None => storage.get_applied_migrations().await?.len(),
+            };
 
             // Emit domain event
             event_helpers::emit_domain_event(AgentEvent::DatabaseInitialized {
@@ -47,9 +75,65 @@ pub async fn handle(database: Option<String>, global_db_path: Option<String>) ->
             })
             .await;
 
-            println!(" Database initialized: {}", db_path);
+            match before {
+                None => println!(
+                    " Database initialized: {} (schema version {})",
+                    db_path, version
+                ),
+                Some(status) => {
+                    println!(
+                        " Database migrated: {} (schema version {} -> {})",
+                        db_path,
+                        format_version(status.schema_version),
+                        version
+                    );
+                    for migration in &status.pending {
+                        println!("  applied {}", migration);
+                    }
+                }
+            }
             Ok(())
         },
     )
     .await
 }
+
+/// Schema status of an existing database, without migrating it
+async fn read_status(db_path: &str) -> Result<MigrationStatus> {
+    let storage = LibsqlStorage::new(ConnectionMode::LocalReadOnly(db_path.to_string())).await?;
+    storage.migration_status().await
+}
+
+/// Print whether `init` would change the database at `db_path`
+async fn report_status(db_path: &str) -> Result<()> {
+    if !Path::new(db_path).exists() {
+        println!("Database not found: {}", db_path);
+        println!("Run 'mnemosyne init' to create it.");
+        return Ok(());
+    }
+
+    let status = read_status(db_path).await?;
+    println!("Database:        {}", db_path);
+    println!(
+        "Schema version:  {} (current: {})",
+        format_version(status.schema_version),
+        status.target_version
+    );
+
+    if status.is_current() {
+        println!("Up to date.");
+    } else if status.pending.is_empty() {
+        println!("Schema version not recorded. Run 'mnemosyne init' to record it.");
+    } else {
+        println!("Pending migrations: {}", status.pending.len());
+        for migration in &status.pending {
+            println!("  {}", migration);
+        }
+        println!("Run 'mnemosyne init' to apply them.");
+    }
+    Ok(())
+}
+
+fn format_version(version: Option<u32>) -> String {
+    version.map_or_else(|| "unrecorded".to_string(), |v| v.to_string())
+}
@@ -13,18 +13,24 @@ pub mod event_bridge;
 pub mod event_helpers;
 pub mod evolve;
 pub mod export;
+pub mod forget;
 pub mod graph;
 pub mod helpers;
+pub mod import;
 pub mod init;
 pub mod interactive;
 pub mod internal;
 pub mod models;
+pub mod namespace;
 pub mod orchestrate;
 pub mod peer;
 pub mod recall;
+pub mod recall_interactive;
 pub mod remember;
 pub mod secrets;
 pub mod serve;
+pub mod stats;
 pub mod status;
+pub mod tags;
 pub mod tui;
 pub mod update;
@@ -0,0 +1,62 @@
+//! Namespace management command
+//!
+//! Moves memories when a project is renamed or two namespaces should become
+//! one. Both are single transactional UPDATEs in storage.
+
+use clap::Subcommand;
+use mnemosyne_core::{error::Result, ConnectionMode, LibsqlStorage, Namespace, StorageBackend};
+
+use super::event_helpers;
+use super::helpers::get_db_path;
+
+#[derive(Subcommand)]
+pub enum NamespaceAction {
+    /// Rename a namespace (fails if the new one already holds memories)
+    Rename {
+        /// Namespace to rename (e.g. project:old)
+        from: String,
+
+        /// New namespace (e.g. project:new)
+        to: String,
+    },
+
+    /// Move every memory in one namespace into another
+    Merge {
+        /// Namespace to empty
+        from: String,
+
+        /// Namespace receiving the memories
+        into: String,
+    },
+}
+
+/// Handle namespace management command
+pub async fn handle(action: NamespaceAction, global_db_path: Option<String>) -> Result<()> {
+    let db_path = get_db_path(global_db_path);
+    let storage = LibsqlStorage::new(ConnectionMode::Local(db_path)).await?;
+
+    match action {
+        NamespaceAction::Rename { from, to } => {
+            let args = vec![from.clone(), to.clone()];
+            event_helpers::with_event_lifecycle("namespace-rename", args, async {
+                let moved = storage
+                    .rename_namespace(&from.parse::<Namespace>()?, &to.parse::<Namespace>()?)
+                    .await?;
+                println!("Renamed {} to {} ({} memories)", from, to, moved);
+                Ok(())
+            })
+            .await
+        }
+        NamespaceAction::Merge { from, into } => {
+            let args = vec![from.clone(), into.clone()];
+            event_helpers::with_event_lifecycle("namespace-merge", args, async {
+                let moved = storage
+                    .merge_namespace(&from.parse::<Namespace>()?, &into.parse::<Namespace>()?)
+                    .await?;
+                println!("Merged {} into {} ({} memories)", from, into, moved);
+                Ok(())
+            })
+            .await
+        }
+    }
+}
@@ -2,22 +2,61 @@
 
 use mnemosyne_core::{
     api::{ApiServer, ApiServerConfig},
-    error::Result,
+    artifacts::{Artifact, ArtifactType, TaskBreakdown},
+    error::{MnemosyneError, Result},
     icons, launcher,
-    orchestration::events::AgentEvent,
+    orchestration::{events::AgentEvent, work_from_tasks},
 };
+use std::path::Path;
 use std::sync::Arc;
 use tracing::debug;
 
 use super::event_helpers;
 use super::helpers::{get_db_path, process_structured_plan};
 
+/// Where an `--plan` argument came from
+enum PlanSource {
+    /// TaskBreakdown artifact (`.md` file)
+    Artifact(Box<TaskBreakdown>),
+    /// JSON work plan, inline or from a file
+    Json(serde_json::Value),
+    /// Free-form prompt
+    Prompt,
+}
+
+/// Detect whether `plan` is an artifact file, a JSON plan or a prompt
+fn detect_plan(plan: &str) -> Result<PlanSource> {
+    let path = Path::new(plan);
+    if path.is_file() {
+        let contents = std::fs::read_to_string(path)?;
+
+        if path.extension().is_some_and(|ext| ext == "md") {
+            let breakdown = TaskBreakdown::from_markdown(&contents)?;
+            if breakdown.metadata.artifact_type != ArtifactType::TaskBreakdown {
+                return Err(MnemosyneError::ValidationError(format!(
+                    "{} is not a task breakdown artifact",
+                    plan
+                )));
+            }
+            return Ok(PlanSource::Artifact(Box::new(breakdown)));
+        }
+
+        return Ok(PlanSource::Json(serde_json::from_str(&contents)?));
+    }
+
+    match serde_json::from_str::<serde_json::Value>(plan) {
+        Ok(plan_json) => Ok(PlanSource::Json(plan_json)),
+        Err(_) => Ok(PlanSource::Prompt),
+    }
+}
+
 /// Handle multi-agent orchestration command
 pub async fn handle(
     plan: String,
     database: Option<String>,
     dashboard: bool,
     max_concurrent: u8,
+    dry_run: bool,
 ) -> Result<()> {
     let start_time = std::time::Instant::now();
 
@@ -92,22 +131,54 @@ pub async fn handle(
         (None, None, None)
     };
 
-    // Parse plan as JSON or treat as prompt
-    if let Ok(plan_json) = serde_json::from_str::<serde_json::Value>(&plan) {
-        debug!("Parsed work plan as JSON");
-        debug!("Plan: {:?}", plan_json);
-
-        // Process structured work plan
-        println!("{} Structured work plan detected:", icons::data::chart());
-        println!();
-        process_structured_plan(&plan_json);
-        println!();
-    } else {
-        debug!("Treating plan as plain text prompt");
-        println!("{} Prompt-based orchestration:", icons::action::edit());
-        println!("   {}", plan);
-        println!();
-    }
+    // Artifact file, JSON plan, or prompt
+    let (initial_prompt, work_items) = match detect_plan(&plan)? {
+        PlanSource::Artifact(breakdown) => {
+            let items = work_from_tasks(&breakdown)?;
+            debug!("Generated {} work items from task breakdown", items.len());
+
+            println!(
+                "{} Task breakdown artifact detected: {}",
+                icons::data::chart(),
+                breakdown.metadata.name
+            );
+            println!();
+            println!(
+                "  Queueing {} open task(s) in dependency order:",
+                items.len()
+            );
+            println!();
+            for (i, item) in items.iter().enumerate() {
+                println!("  {}. {}", i + 1, item.description);
+            }
+            println!();
+
+            let prompt = format!(
+                "Implement the task breakdown \"{}\". Its {} open tasks are queued with the orchestrator as work items.",
+                breakdown.metadata.name,
+                items.len()
+            );
+            (prompt, items)
+        }
+        PlanSource::Json(plan_json) => {
+            debug!("Parsed work plan as JSON");
+            debug!("Plan: {:?}", plan_json);
+
+            // Process structured work plan
+            println!("{} Structured work plan detected:", icons::data::chart());
+            println!();
+            process_structured_plan(&plan_json);
+            println!();
+            (plan, Vec::new())
+        }
+        PlanSource::Prompt => {
+            debug!("Treating plan as plain text prompt");
+            println!("{} Prompt-based orchestration:", icons::action::edit());
+            println!("   {}", plan);
+            println!();
+            (plan, Vec::new())
+        }
+    };
 
     // Launch orchestrated session with dashboard support
     println!(
@@ -118,9 +189,12 @@ pub async fn handle(
 
     launcher::launch_orchestrated_session(
         Some(db_path),
-        Some(plan),
+        Some(initial_prompt),
         event_broadcaster,
         state_manager,
+        dry_run,
+        None,
+        work_items,
     )
     .await?;
 
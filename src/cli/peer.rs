@@ -8,13 +8,23 @@ use mnemosyne_core::error::Result;
 #[derive(Subcommand, Debug, Clone)]
 pub enum PeerAction {
     /// Create an invite ticket for this node
-    Invite,
+    Invite {
+        /// Add this node ID to the peer allowlist
+        #[arg(long)]
+        peer: Option<String>,
+    },
 
     /// Join a peer using an invite ticket
     Join {
         /// The invite ticket
         ticket: String,
     },
+
+    /// Add a peer to the allowlist (enforced with [network] enforce_allowlist)
+    Allow {
+        /// The peer's node ID
+        node_id: String,
+    },
 }
 
 /// Handle peer commands
@@ -31,9 +41,9 @@ pub async fn handle(action: PeerAction) -> Result<()> {
     }
 
     match action {
-        PeerAction::Invite => {
+        PeerAction::Invite { peer } => {
             println!("Creating invite ticket...");
-            match ipc::create_invite(&socket_path).await {
+            match ipc::create_invite(&socket_path, peer).await {
                 Ok(ticket) => {
                     println!("Invite Ticket Created:");
                     println!("{}", ticket);
@@ -55,6 +65,15 @@ pub async fn handle(action: PeerAction) -> Result<()> {
                 }
             }
         }
+        PeerAction::Allow { node_id } => match ipc::allow_peer(&socket_path, node_id).await {
+            Ok(node_id) => {
+                println!("Peer allowed: {}", node_id);
+                println!("Saved to the peer allowlist; it only restricts connections when [network] enforce_allowlist = true.");
+            }
+            Err(e) => {
+                eprintln!("Failed to allow peer: {}", e);
+            }
+        },
     }
 
     Ok(())
@@ -1,130 +1,117 @@
 //! Memory recall/query command
 
 use mnemosyne_core::{
-    orchestration::events::AgentEvent, utils::string::truncate_at_char_boundary, ConnectionMode,
-    EmbeddingService, LibsqlStorage, LlmConfig, Namespace, RemoteEmbeddingService, StorageBackend,
+    error::{MnemosyneError, Result},
+    launcher::context::{render_context_block, ContextLoadConfig},
+    orchestration::events::AgentEvent,
+    types::{ScoreBreakdown, SearchQuery, SearchResult},
+    utils::string::truncate_at_char_boundary,
+    ConfigManager, ConnectionMode, EmbeddingService, LibsqlStorage, LlmConfig, Namespace,
+    RemoteEmbeddingService, StorageBackend,
 };
 use std::collections::HashMap;
-use tracing::debug;
+use tracing::{debug, warn};
 
 use super::event_bridge;
-use super::helpers::get_db_path;
+use super::helpers::{get_db_path, parse_memory_type_filter, parse_namespace};
+
+/// Type, tag, and importance filters for recall (empty: no restriction)
+#[derive(Debug, Clone, Default)]
+pub struct RecallFilters {
+    /// Type names or aliases; a memory of any of them matches
+    pub memory_types: Vec<String>,
+    pub tags_any: Vec<String>,
+    pub tags_all: Vec<String>,
+    pub min_importance: Option<u8>,
+}
+
+impl RecallFilters {
+    fn search_query(
+        &self,
+        query: &str,
+        namespace: Option<Namespace>,
+        max_results: usize,
+    ) -> Result<SearchQuery> {
+        Ok(SearchQuery {
+            query: query.to_string(),
+            namespace,
+            memory_types: self
+                .memory_types
+                .iter()
+                .map(|name| parse_memory_type_filter(name))
+                .collect::<Result<_>>()?,
+            tags_any: self.tags_any.clone(),
+            tags_all: self.tags_all.clone(),
+            min_importance: self.min_importance,
+            max_results,
+            ..SearchQuery::default()
+        })
+    }
+}
 
 /// Handle memory recall command
+///
+/// With `interactive`, opens the live recall browser when stdout is a
+/// terminal and falls back to plain output otherwise. With `explain`, each
+/// result shows the hybrid search signals behind its rank.
+#[allow(clippy::too_many_arguments)]
 pub async fn handle(
-    query: String,
+    query: Option<String>,
     namespace: Option<String>,
     limit: usize,
-    min_importance: Option<u8>,
+    filters: RecallFilters,
     format: String,
+    interactive: bool,
+    explain: bool,
     global_db_path: Option<String>,
-) -> mnemosyne_core::error::Result<()> {
+) -> Result<()> {
     let start_time = std::time::Instant::now();
 
-    // Emit CLI command started event
-    event_bridge::emit_command_started(
-        "recall",
-        vec![format!("--query={}", query), format!("--limit={}", limit)],
-    )
-    .await;
-
     // Initialize storage and services
     let db_path = get_db_path(global_db_path);
-    let storage = LibsqlStorage::new(ConnectionMode::Local(db_path.clone())).await?;
-
-    // Check if API key is available for vector search
-    let embedding_service_config = LlmConfig::default();
-    let has_api_key = !embedding_service_config.api_key.is_empty();
-
-    // Parse namespace
-    let ns = namespace.as_ref().map(|ns_str| {
-        if ns_str.starts_with("project:") {
-            let project = ns_str.strip_prefix("project:").unwrap();
-            Namespace::Project {
-                name: project.to_string(),
-            }
-        } else if ns_str.starts_with("session:") {
-            let parts: Vec<&str> = ns_str
-                .strip_prefix("session:")
-                .unwrap()
-                .split(':')
-                .collect();
-            if parts.len() == 2 {
-                Namespace::Session {
-                    project: parts[0].to_string(),
-                    session_id: parts[1].to_string(),
-                }
-            } else {
-                Namespace::Global
-            }
-        } else {
-            Namespace::Global
-        }
-    });
-
-    // Perform hybrid search (keyword + vector + graph)
-    let keyword_results = storage
-        .hybrid_search(&query, ns.clone(), limit * 2, true)
-        .await?;
-
-    // Vector search (optional - only if API key available)
-    let vector_results = if has_api_key {
-        match RemoteEmbeddingService::new(
-            embedding_service_config.api_key.clone(),
-            None, // Use default model
-            None, // Use default base URL
-        ) {
-            Ok(embedding_service) => match embedding_service.embed(&query).await {
-                Ok(query_embedding) => storage
-                    .vector_search(&query_embedding, limit * 2, ns.clone())
-                    .await
-                    .unwrap_or_default(),
-                Err(_) => Vec::new(),
-            },
-            Err(_) => Vec::new(),
+    let mut storage = LibsqlStorage::new(ConnectionMode::Local(db_path.clone())).await?;
+    let ns = namespace.as_deref().map(parse_namespace);
+    let mut context_tokens = ContextLoadConfig::default().max_context_tokens;
+    match ConfigManager::for_namespace(ns.as_ref().unwrap_or(&Namespace::Global)) {
+        Ok(effective) => {
+            context_tokens = effective.settings.context.max_context_tokens;
+            storage.set_search_config(effective.settings.search);
         }
-    } else {
-        debug!("Skipping vector search - no API key configured");
-        Vec::new()
-    };
-
-    // Merge results
-    let mut memory_scores = HashMap::new();
-
-    for result in keyword_results {
-        memory_scores
-            .entry(result.memory.id)
-            .or_insert((result.memory.clone(), vec![]))
-            .1
-            .push(result.score * 0.4);
+        Err(e) => warn!("Could not load search settings, using defaults: {}", e),
     }
+    if let Err(e) = storage.apply_fts_tokenizer().await {
+        warn!("Could not apply the configured keyword tokenizer: {}", e);
+    }
+    storage.set_explain_scores(explain);
 
-    for (memory_id, similarity) in vector_results {
-        // Fetch the memory for this ID
-        if let Ok(memory) = storage.get_memory(memory_id).await {
-            memory_scores
-                .entry(memory_id)
-                .or_insert((memory, vec![]))
-                .1
-                .push(similarity * 0.3);
+    if interactive {
+        if atty::is(atty::Stream::Stdout) && atty::is(atty::Stream::Stdin) {
+            return super::recall_interactive::run(
+                &storage,
+                query.unwrap_or_default(),
+                ns,
+                limit,
+                filters,
+            )
+            .await;
         }
+        debug!("Not a terminal, falling back to plain recall output");
     }
 
-    let mut results: Vec<_> = memory_scores
-        .into_iter()
-        .map(|(_, (memory, scores))| {
-            let total_score: f32 = scores.iter().sum();
-            (memory, total_score)
-        })
-        .collect();
+    let query = query.ok_or_else(|| {
+        MnemosyneError::ValidationError(
+            "--query is required unless recall runs interactively".to_string(),
+        )
+    })?;
 
-    results.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
-    results.truncate(limit);
+    // Emit CLI command started event
+    let correlation_id = event_bridge::emit_command_started(
+        "recall",
+        vec![format!("--query={}", query), format!("--limit={}", limit)],
+    )
+    .await;
 
-    // Filter by importance if specified
-    if let Some(min_imp) = min_importance {
-        results.retain(|(m, _)| m.importance >= min_imp);
-    }
+    let results = search(&storage, &query, ns, limit, &filters).await?;
 
     let result_count = results.len();
 
@@ -132,17 +119,22 @@ pub async fn handle(
     if format == "json" {
         let json_results: Vec<_> = results
             .iter()
-            .map(|(m, score)| {
-                serde_json::json!({
+            .map(|r| {
+                let m = &r.memory;
+                let mut json = serde_json::json!({
                     "id": m.id.to_string(),
                     "summary": m.summary,
                     "content": m.content,
                     "importance": m.importance,
                     "tags": m.tags,
                     "memory_type": format!("{:?}", m.memory_type),
-                    "score": score,
+                    "score": r.score,
                     "namespace": serde_json::to_string(&m.namespace).unwrap_or_default()
-                })
+                });
+                if let Some(breakdown) = &r.score_breakdown {
+                    json["score_breakdown"] = serde_json::json!(breakdown);
+                }
+                json
             })
             .collect();
 
@@ -155,16 +147,30 @@ pub async fn handle(
         );
     } else if results.is_empty() {
         eprintln!("No memories found matching '{}'", query);
+    } else if format == "context" {
+        // Ready to paste into a prompt, sized by the `[context]` token budget
+        println!("# Memories relevant to: {}\n", query);
+        print!(
+            "{}",
+            render_context_block(
+                results.iter().map(|r| (f64::from(r.score), &r.memory)),
+                context_tokens,
+            )
+        );
     } else {
         eprintln!("Found {} memories:\n", results.len());
-        for (i, (memory, score)) in results.iter().enumerate() {
+        for (i, result) in results.iter().enumerate() {
+            let memory = &result.memory;
             println!(
                 "{}. {} (score: {:.2}, importance: {}/10)",
                 i + 1,
                 memory.summary,
-                score,
+                result.score,
                 memory.importance
             );
+            if let Some(breakdown) = &result.score_breakdown {
+                println!("   Signals: {}", breakdown);
+            }
             println!("   ID: {}", memory.id);
             println!("   Tags: {}", memory.tags.join(", "));
             println!(
@@ -197,8 +203,130 @@ pub async fn handle(
         "recall",
         duration_ms,
         format!("Found {} results for query '{}'", result_count, query),
+        &correlation_id,
     )
     .await;
 
     Ok(())
 }
+
+/// Rank memories for a query (keyword + graph, plus vector when an API key is set)
+///
+/// Scores are re-weighted here; a hybrid `score_breakdown` (when the storage
+/// explains scores) is carried through with the CLI's own vector similarity
+/// folded into its `vector` signal.
+pub(crate) async fn search(
+    storage: &LibsqlStorage,
+    query: &str,
+    ns: Option<Namespace>,
+    limit: usize,
+    filters: &RecallFilters,
+) -> Result<Vec<SearchResult>> {
+    // Check if API key is available for vector search
+    let embedding_service_config = LlmConfig::default();
+    let has_api_key = !embedding_service_config.api_key.is_empty();
+
+    // Perform hybrid search (keyword + vector + graph), filtered in storage
+    let search_query = filters.search_query(query, ns.clone(), limit * 2)?;
+    let keyword_results = storage.search(&search_query).await?;
+    let explain = keyword_results.iter().any(|r| r.score_breakdown.is_some());
+
+    // Vector search (optional - only if API key available)
+    let vector_results = if has_api_key {
+        match RemoteEmbeddingService::new(
+            embedding_service_config.api_key.clone(),
+            None, // Use default model
+            None, // Use default base URL
+        ) {
+            Ok(embedding_service) => match embedding_service.embed(query).await {
+                Ok(query_embedding) => storage
+                    .vector_search(&query_embedding, limit * 2, ns.clone())
+                    .await
+                    .unwrap_or_default(),
+                Err(_) => Vec::new(),
+            },
+            Err(_) => Vec::new(),
+        }
+    } else {
+        debug!("Skipping vector search - no API key configured");
+        Vec::new()
+    };
+
+    // Merge results
+    let mut merged: HashMap<_, SearchResult> = HashMap::new();
+
+    for result in keyword_results {
+        let id = result.memory.id;
+        merged.insert(
+            id,
+            SearchResult {
+                score: result.score * 0.4,
+                ..result
+            },
+        );
+    }
+
+    for (memory_id, similarity) in vector_results {
+        if let Some(existing) = merged.get_mut(&memory_id) {
+            existing.score += similarity * 0.3;
+            if let Some(breakdown) = existing.score_breakdown.as_mut() {
+                breakdown.vector = breakdown.vector.max(similarity);
+            }
+        } else if let Ok(memory) = storage.get_memory(memory_id).await {
+            // Fetch the memory for this ID
+            if !search_query.matches(&memory) {
+                continue;
+            }
+            merged.insert(
+                memory_id,
+                SearchResult {
+                    memory,
+                    score: similarity * 0.3,
+                    match_reason: format!("vector_similarity ({:.2})", similarity),
+                    score_breakdown: explain.then(|| ScoreBreakdown {
+                        vector: similarity,
+                        ..Default::default()
+                    }),
+                },
+            );
+        }
+    }
+
+    let mut results: Vec<_> = merged.into_values().collect();
+
+    results.sort_by(|a, b| {
+        b.score
+            .partial_cmp(&a.score)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    results.truncate(limit);
+
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unknown_type_filter_is_rejected() {
+        let filters = RecallFilters {
+            memory_types: vec!["decision".to_string()],
+            ..RecallFilters::default()
+        };
+        let query = filters.search_query("storage", None, 10).unwrap();
+        assert_eq!(
+            query.memory_types,
+            vec![mnemosyne_core::MemoryType::ArchitectureDecision]
+        );
+
+        let filters = RecallFilters {
+            memory_types: vec!["not_a_real_type".to_string()],
+            ..RecallFilters::default()
+        };
+        assert!(matches!(
+            filters.search_query("storage", None, 10),
+            Err(MnemosyneError::ValidationError(_))
+        ));
+    }
+}
@@ -0,0 +1,429 @@
+//! Interactive recall (`mnemosyne recall -i`)
+//!
+//! A small full-screen browser over `hybrid_search`: typing re-runs the
+//! search once input settles, arrows move through ranked results, Enter shows
+//! the full memory, and Enter again opens the first related file (e.g. a spec
+//! artifact) in `$EDITOR`.
+
+use crossterm::event::{KeyCode, KeyEvent};
+use mnemosyne_core::{
+    error::Result,
+    tui::{EventLoop, TerminalConfig, TerminalManager, TuiEvent},
+    types::{MemoryNote, Namespace},
+    utils::string::truncate_at_char_boundary,
+    LibsqlStorage,
+};
+use ratatui::{
+    layout::{Constraint, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap},
+    Frame,
+};
+use std::path::Path;
+use std::time::{Duration, Instant};
+use tracing::debug;
+
+use super::recall::{search, RecallFilters};
+
+/// How long input must be idle before the search re-runs
+pub const RECALL_DEBOUNCE: Duration = Duration::from_millis(250);
+
+/// Event poll interval; bounds how late a debounced search can fire
+const TICK_RATE_MS: u64 = 50;
+
+/// Snippet length shown under each result
+const SNIPPET_CHARS: usize = 120;
+
+/// Fires once after input has been quiet for `delay`
+#[derive(Debug)]
+pub struct Debouncer {
+    delay: Duration,
+    pending_since: Option<Instant>,
+}
+
+impl Debouncer {
+    pub fn new(delay: Duration) -> Self {
+        Self {
+            delay,
+            pending_since: None,
+        }
+    }
+
+    /// Record input, restarting the quiet period
+    pub fn touch(&mut self, now: Instant) {
+        self.pending_since = Some(now);
+    }
+
+    /// Make the next `ready` check fire without waiting
+    pub fn fire_now(&mut self, now: Instant) {
+        self.pending_since = Some(now.checked_sub(self.delay).unwrap_or(now));
+    }
+
+    /// Whether the quiet period has elapsed; clears the pending input if so
+    pub fn ready(&mut self, now: Instant) -> bool {
+        match self.pending_since {
+            Some(since) if now.duration_since(since) >= self.delay => {
+                self.pending_since = None;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+/// What the caller should do after a key press
+#[derive(Debug, PartialEq, Eq)]
+pub enum BrowserAction {
+    None,
+    Quit,
+    OpenFile(String),
+}
+
+/// Browser state, kept separate from rendering and I/O
+pub struct RecallBrowser {
+    query: String,
+    results: Vec<(MemoryNote, f32)>,
+    selected: usize,
+    viewing: bool,
+    status: String,
+    debouncer: Debouncer,
+}
+
+impl RecallBrowser {
+    pub fn new(query: String) -> Self {
+        let mut debouncer = Debouncer::new(RECALL_DEBOUNCE);
+        if !query.is_empty() {
+            // Search the seed query right away
+            debouncer.fire_now(Instant::now());
+        }
+        Self {
+            query,
+            results: Vec::new(),
+            selected: 0,
+            viewing: false,
+            status: "Type to search".to_string(),
+            debouncer,
+        }
+    }
+
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    pub fn selected(&self) -> Option<&MemoryNote> {
+        self.results.get(self.selected).map(|(m, _)| m)
+    }
+
+    /// Whether a debounced search is due
+    pub fn search_due(&mut self, now: Instant) -> bool {
+        self.debouncer.ready(now)
+    }
+
+    pub fn set_results(&mut self, results: Vec<(MemoryNote, f32)>) {
+        self.status = format!("{} results", results.len());
+        self.results = results;
+        self.selected = 0;
+    }
+
+    pub fn set_status(&mut self, status: String) {
+        self.status = status;
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent, now: Instant) -> BrowserAction {
+        if self.viewing {
+            return match key.code {
+                KeyCode::Esc | KeyCode::Backspace | KeyCode::Left => {
+                    self.viewing = false;
+                    BrowserAction::None
+                }
+                KeyCode::Enter => match self
+                    .selected()
+                    .and_then(|m| m.related_files.first().cloned())
+                {
+                    Some(path) => BrowserAction::OpenFile(path),
+                    None => {
+                        self.status = "No related artifact to open".to_string();
+                        BrowserAction::None
+                    }
+                },
+                _ => BrowserAction::None,
+            };
+        }
+
+        match key.code {
+            KeyCode::Esc => return BrowserAction::Quit,
+            KeyCode::Up => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Down => {
+                if self.selected + 1 < self.results.len() {
+                    self.selected += 1;
+                }
+            }
+            KeyCode::Enter => self.viewing = self.selected().is_some(),
+            KeyCode::Backspace => {
+                if self.query.pop().is_some() {
+                    self.debouncer.touch(now);
+                }
+            }
+            KeyCode::Char(c) => {
+                self.query.push(c);
+                self.debouncer.touch(now);
+            }
+            _ => {}
+        }
+        BrowserAction::None
+    }
+
+    fn render(&self, frame: &mut Frame) {
+        let [input_area, body_area, footer_area] = Layout::vertical([
+            Constraint::Length(3),
+            Constraint::Min(1),
+            Constraint::Length(1),
+        ])
+        .areas(frame.area());
+
+        let input = Paragraph::new(self.query.as_str())
+            .block(Block::default().borders(Borders::ALL).title(" Recall "));
+        frame.render_widget(input, input_area);
+
+        match (self.viewing, self.selected()) {
+            (true, Some(memory)) => {
+                let detail = Paragraph::new(detail_lines(memory))
+                    .wrap(Wrap { trim: false })
+                    .block(Block::default().borders(Borders::ALL).title(" Memory "));
+                frame.render_widget(detail, body_area);
+            }
+            _ => {
+                let items: Vec<ListItem> = self
+                    .results
+                    .iter()
+                    .map(|(m, score)| {
+                        ListItem::new(vec![
+                            Line::from(vec![
+                                Span::styled(
+                                    m.summary.clone(),
+                                    Style::default().add_modifier(Modifier::BOLD),
+                                ),
+                                Span::styled(
+                                    format!("  {:.2} · {}/10", score, m.importance),
+                                    Style::default().fg(Color::DarkGray),
+                                ),
+                            ]),
+                            Line::from(format!(
+                                "  {}",
+                                truncate_at_char_boundary(
+                                    &m.content.replace('\n', " "),
+                                    SNIPPET_CHARS
+                                )
+                            )),
+                        ])
+                    })
+                    .collect();
+                let list = List::new(items)
+                    .block(Block::default().borders(Borders::ALL).title(" Results "))
+                    .highlight_style(Style::default().bg(Color::DarkGray));
+                let mut state = ListState::default().with_selected(Some(self.selected));
+                frame.render_stateful_widget(list, body_area, &mut state);
+            }
+        }
+
+        let help = if self.viewing {
+            "Enter: open artifact  Esc: back"
+        } else {
+            "↑/↓: select  Enter: view  Esc: quit"
+        };
+        let footer = Paragraph::new(format!("{}  │  {}", self.status, help))
+            .style(Style::default().fg(Color::DarkGray));
+        frame.render_widget(footer, footer_area);
+    }
+}
+
+fn detail_lines(memory: &MemoryNote) -> Vec<Line<'static>> {
+    let mut lines = vec![
+        Line::styled(
+            memory.summary.clone(),
+            Style::default().add_modifier(Modifier::BOLD),
+        ),
+        Line::from(format!(
+            "ID: {}  Importance: {}/10  Type: {:?}",
+            memory.id, memory.importance, memory.memory_type
+        )),
+        Line::from(format!("Tags: {}", memory.tags.join(", "))),
+    ];
+    if !memory.related_files.is_empty() {
+        lines.push(Line::from(format!(
+            "Files: {}",
+            memory.related_files.join(", ")
+        )));
+    }
+    lines.push(Line::from(""));
+    lines.extend(memory.content.lines().map(|l| Line::from(l.to_string())));
+    lines
+}
+
+/// Run the interactive recall browser until the user quits
+pub async fn run(
+    storage: &LibsqlStorage,
+    query: String,
+    namespace: Option<Namespace>,
+    limit: usize,
+    filters: RecallFilters,
+) -> Result<()> {
+    let config = TerminalConfig {
+        mouse_enabled: false,
+        ..TerminalConfig::default()
+    };
+    let mut terminal = Some(TerminalManager::new(config.clone())?);
+    let events = EventLoop::new(TICK_RATE_MS);
+    let mut browser = RecallBrowser::new(query);
+
+    loop {
+        if let Some(term) = terminal.as_mut() {
+            term.terminal_mut().draw(|f| browser.render(f))?;
+        }
+
+        if browser.search_due(Instant::now()) {
+            let query = browser.query().trim().to_string();
+            if query.is_empty() {
+                browser.set_results(Vec::new());
+            } else {
+                match search(storage, &query, namespace.clone(), limit, &filters).await {
+                    Ok(results) => browser
+                        .set_results(results.into_iter().map(|r| (r.memory, r.score)).collect()),
+                    Err(e) => browser.set_status(format!("Search failed: {}", e)),
+                }
+            }
+            continue;
+        }
+
+        match events.poll_event()? {
+            Some(TuiEvent::Quit) => break,
+            Some(TuiEvent::Key(key)) => match browser.handle_key(key, Instant::now()) {
+                BrowserAction::Quit => break,
+                BrowserAction::OpenFile(path) => {
+                    // Hand the terminal to the editor, then take it back
+                    terminal = None;
+                    open_in_editor(Path::new(&path));
+                    terminal = Some(TerminalManager::new(config.clone())?);
+                }
+                BrowserAction::None => {}
+            },
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+fn open_in_editor(path: &Path) {
+    let editor = std::env::var("EDITOR").unwrap_or_else(|_| "vi".to_string());
+    match std::process::Command::new(&editor).arg(path).status() {
+        Ok(status) => debug!("{} exited with {}", editor, status),
+        Err(e) => eprintln!("Failed to run {} {}: {}", editor, path.display(), e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+    use mnemosyne_core::types::{MemoryId, MemoryType};
+
+    fn memory(summary: &str, related_files: Vec<String>) -> MemoryNote {
+        let now = chrono::Utc::now();
+        MemoryNote {
+            id: MemoryId::new(),
+            namespace: Namespace::Global,
+            created_at: now,
+            updated_at: now,
+            content: summary.to_string(),
+            summary: summary.to_string(),
+            keywords: vec![],
+            tags: vec![],
+            context: String::new(),
+            memory_type: MemoryType::Insight,
+            importance: 5,
+            confidence: 0.9,
+            links: vec![],
+            related_files,
+            related_entities: vec![],
+            access_count: 0,
+            last_accessed_at: now,
+            expires_at: None,
+            is_archived: false,
+            superseded_by: None,
+            embedding: None,
+            embedding_model: "test".to_string(),
+        }
+    }
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn test_debouncer_waits_for_quiet_input() {
+        let start = Instant::now();
+        let mut debouncer = Debouncer::new(Duration::from_millis(100));
+        assert!(!debouncer.ready(start));
+
+        debouncer.touch(start);
+        debouncer.touch(start + Duration::from_millis(60));
+        // 100ms after the first keystroke but only 40ms after the last
+        assert!(!debouncer.ready(start + Duration::from_millis(100)));
+        assert!(debouncer.ready(start + Duration::from_millis(160)));
+        // Fires once per burst
+        assert!(!debouncer.ready(start + Duration::from_millis(300)));
+    }
+
+    #[test]
+    fn test_typing_schedules_one_search() {
+        let start = Instant::now();
+        let mut browser = RecallBrowser::new(String::new());
+        assert!(!browser.search_due(start));
+
+        for (i, c) in "auth".chars().enumerate() {
+            let at = start + Duration::from_millis(i as u64 * 30);
+            browser.handle_key(key(KeyCode::Char(c)), at);
+        }
+        assert_eq!(browser.query(), "auth");
+        assert!(!browser.search_due(start + Duration::from_millis(100)));
+        assert!(browser.search_due(start + Duration::from_millis(90) + RECALL_DEBOUNCE));
+        assert!(!browser.search_due(start + Duration::from_secs(5)));
+    }
+
+    #[test]
+    fn test_navigation_and_artifact_open() {
+        let mut browser = RecallBrowser::new("seed".to_string());
+        let now = Instant::now();
+        assert!(browser.search_due(now));
+
+        let plain = memory("Plain", vec![]);
+        let spec = memory(
+            "Spec",
+            vec![".mnemosyne/artifacts/specs/auth.md".to_string()],
+        );
+        browser.set_results(vec![(plain, 0.9), (spec, 0.5)]);
+
+        browser.handle_key(key(KeyCode::Up), now);
+        assert_eq!(browser.selected().unwrap().summary, "Plain");
+        browser.handle_key(key(KeyCode::Down), now);
+        browser.handle_key(key(KeyCode::Down), now);
+        assert_eq!(browser.selected().unwrap().summary, "Spec");
+
+        // Enter views, Enter again opens the artifact
+        assert_eq!(
+            browser.handle_key(key(KeyCode::Enter), now),
+            BrowserAction::None
+        );
+        assert_eq!(
+            browser.handle_key(key(KeyCode::Enter), now),
+            BrowserAction::OpenFile(".mnemosyne/artifacts/specs/auth.md".to_string())
+        );
+        browser.handle_key(key(KeyCode::Esc), now);
+        assert_eq!(
+            browser.handle_key(key(KeyCode::Esc), now),
+            BrowserAction::Quit
+        );
+    }
+}
@@ -1,21 +1,62 @@
 //! Memory creation command
 
 use mnemosyne_core::{
-    error::Result, icons, orchestration::events::AgentEvent, ConnectionMode, EmbeddingService,
-    LibsqlStorage, LlmConfig, LlmService, MemoryNote, Namespace, RemoteEmbeddingService,
-    StorageBackend,
+    config::FALLBACK_IMPORTANCE, error::Result, icons, orchestration::events::AgentEvent,
+    ConfigManager, ConnectionMode, EmbeddingService, LibsqlStorage, LlmConfig, LlmService,
+    MemoryNote, MnemosyneError, Namespace, RemoteEmbeddingService, StorageBackend,
 };
+use std::io::Read;
+use std::path::{Path, PathBuf};
 use tracing::{debug, warn};
 
 use super::event_bridge;
-use super::helpers::{get_db_path, parse_memory_type};
+use super::helpers::{get_db_path, parse_memory_type_with};
+
+/// Content given inline, as `-` for stdin, or with `--file`
+///
+/// Stdin and file content are kept byte for byte, trailing newline included.
+fn read_content(
+    content: Option<String>,
+    file: Option<&Path>,
+    mut stdin: impl Read,
+) -> Result<String> {
+    let content = match (content, file) {
+        (Some(_), Some(_)) => {
+            return Err(MnemosyneError::ValidationError(
+                "--content and --file cannot be used together".to_string(),
+            ))
+        }
+        (Some(content), None) if content == "-" => {
+            let mut buf = String::new();
+            stdin.read_to_string(&mut buf)?;
+            buf
+        }
+        (Some(content), None) => content,
+        (None, Some(path)) => std::fs::read_to_string(path).map_err(|e| {
+            MnemosyneError::ValidationError(format!("Cannot read {}: {}", path.display(), e))
+        })?,
+        (None, None) => {
+            return Err(MnemosyneError::ValidationError(
+                "Provide --content (or --content - for stdin) or --file".to_string(),
+            ))
+        }
+    };
+
+    if content.trim().is_empty() {
+        return Err(MnemosyneError::ValidationError(
+            "Content to remember is empty".to_string(),
+        ));
+    }
+    Ok(content)
+}
 
 /// Handle memory creation command
 #[allow(clippy::too_many_arguments)]
 pub async fn handle(
-    content: String,
+    content: Option<String>,
+    file: Option<PathBuf>,
     namespace: String,
-    importance: u8,
+    importance: Option<u8>,
     context: Option<String>,
     tags: Option<String>,
     memory_type: Option<String>,
@@ -23,13 +64,17 @@ pub async fn handle(
     global_db_path: Option<String>,
 ) -> Result<()> {
     let start_time = std::time::Instant::now();
+    let content = read_content(content, file.as_deref(), std::io::stdin().lock())?;
 
     // Emit CLI command started event
-    event_bridge::emit_command_started(
+    let correlation_id = event_bridge::emit_command_started(
         "remember",
         vec![
             format!("--content={}", content.chars().take(50).collect::<String>()),
-            format!("--importance={}", importance),
+            format!(
+                "--importance={}",
+                importance.map_or_else(|| "default".to_string(), |i| i.to_string())
+            ),
             format!("--namespace={}", namespace),
         ],
     )
@@ -38,7 +83,7 @@ pub async fn handle(
     // Initialize storage and services
     let db_path = get_db_path(global_db_path);
     // Remember command creates database if it doesn't exist (write implies initialize)
-    let storage =
+    let mut storage =
         LibsqlStorage::new_with_validation(ConnectionMode::Local(db_path.clone()), true).await?;
 
     // Check if API key is available for LLM enrichment
@@ -69,10 +114,24 @@ pub async fn handle(
         Namespace::Global
     };
 
+    // Resolve the type against the settings in effect for this namespace,
+    // which may declare their own custom types
+    let memory_types = match ConfigManager::for_namespace(&ns) {
+        Ok(effective) => effective.settings.memory_types,
+        Err(e) => {
+            warn!("Could not load settings for {}, using defaults: {}", ns, e);
+            Default::default()
+        }
+    };
+    let memory_type = memory_type
+        .as_deref()
+        .map(|type_str| parse_memory_type_with(type_str, &memory_types));
+    storage.set_memory_types(memory_types.clone());
+
     // Create or enrich memory
     let mut memory = if has_api_key {
         // Try to enrich memory with LLM, but fall back if it fails
-        let llm = LlmService::new(llm_config.clone())?;
+        let llm = LlmService::configured_with(llm_config.clone())?;
         let ctx = context.unwrap_or_else(|| "CLI input".to_string());
 
         match llm.enrich_memory(&content, &ctx).await {
@@ -116,10 +175,9 @@ pub async fn handle(
                     tags: Vec::new(),
                     context: ctx.clone(),
                     memory_type: memory_type
-                        .as_deref()
-                        .map(parse_memory_type)
+                        .clone()
                         .unwrap_or(mnemosyne_core::MemoryType::Insight),
-                    importance: importance.clamp(1, 10),
+                    importance: FALLBACK_IMPORTANCE,
                     confidence: 0.5,
                     links: Vec::new(),
                     related_files: Vec::new(),
@@ -164,10 +222,9 @@ pub async fn handle(
             tags: Vec::new(),
             context: ctx,
             memory_type: memory_type
-                .as_deref()
-                .map(parse_memory_type)
+                .clone()
                 .unwrap_or(mnemosyne_core::MemoryType::Insight),
-            importance: importance.clamp(1, 10),
+            importance: FALLBACK_IMPORTANCE,
             confidence: 0.5,
             links: Vec::new(),
             related_files: Vec::new(),
@@ -184,10 +241,13 @@ pub async fn handle(
 
     // Override with CLI parameters (in case LLM set different values)
     memory.namespace = ns;
-    memory.importance = importance.clamp(1, 10);
-    if let Some(ref type_str) = memory_type {
-        memory.memory_type = parse_memory_type(type_str);
+    if let Some(memory_type) = memory_type {
+        memory.memory_type = memory_type;
     }
+    // An explicit --importance wins; otherwise the type decides
+    memory.importance = importance
+        .unwrap_or_else(|| memory_types.default_importance(&memory.memory_type))
+        .clamp(1, 10);
 
     // Add custom tags if provided
     if let Some(tag_str) = tags {
@@ -203,7 +263,10 @@ pub async fn handle(
     if has_api_key {
         match RemoteEmbeddingService::new(llm_config.api_key.clone(), None, None) {
             Ok(embedding_service) => match embedding_service.embed(&memory.content).await {
-                Ok(embedding) => memory.embedding = Some(embedding),
+                Ok(embedding) => {
+                    memory.embedding = Some(embedding);
+                    memory.embedding_model = embedding_service.model_name().to_string();
+                }
                 Err(_) => {
                     debug!("Failed to generate embedding, storing without it");
                 }
@@ -254,8 +317,56 @@ pub async fn handle(
             "Stored memory {} (importance {})",
             memory.id, memory.importance
         ),
+        &correlation_id,
     )
     .await;
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MULTILINE: &str = "commit abc123\n    Fix race in watcher\n\n    Details\n";
+
+    #[test]
+    fn test_content_dash_reads_stdin() {
+        let content = read_content(Some("-".to_string()), None, MULTILINE.as_bytes()).unwrap();
+        assert_eq!(content, MULTILINE);
+    }
+
+    #[test]
+    fn test_file_content_keeps_newlines() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("note.md");
+        std::fs::write(&path, MULTILINE).unwrap();
+
+        let content = read_content(None, Some(&path), std::io::empty()).unwrap();
+        assert_eq!(content, MULTILINE);
+
+        let missing = read_content(None, Some(&dir.path().join("missing.md")), std::io::empty());
+        assert!(matches!(missing, Err(MnemosyneError::ValidationError(_))));
+    }
+
+    #[test]
+    fn test_content_and_file_are_exclusive() {
+        let result = read_content(
+            Some("inline".to_string()),
+            Some(Path::new("note.md")),
+            std::io::empty(),
+        );
+        match result {
+            Err(MnemosyneError::ValidationError(message)) => {
+                assert!(message.contains("cannot be used together"), "{}", message)
+            }
+            other => panic!("Expected ValidationError, got {:?}", other),
+        }
+
+        assert_eq!(
+            read_content(Some("inline".to_string()), None, std::io::empty()).unwrap(),
+            "inline"
+        );
+        assert!(read_content(Some("-".to_string()), None, std::io::empty()).is_err());
+    }
+}
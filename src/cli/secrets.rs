@@ -29,6 +29,12 @@ pub enum SecretsCommand {
         name: String,
     },
 
+    /// Delete a secret from the active backend
+    Delete {
+        /// Secret name
+        name: String,
+    },
+
     /// List configured secrets (names only)
     List,
 
@@ -47,7 +53,7 @@ pub async fn handle(command: SecretsCommand) -> Result<()> {
                     println!(
                         "{}  Secrets already initialized at: {}",
                         icons::status::warning(),
-                        secrets.secrets_file().display()
+                        secrets.backend().location()
                     );
                     print!("Reinitialize? This will overwrite existing secrets. [y/N]: ");
                     std::io::Write::flush(&mut std::io::stdout())?;
@@ -119,6 +125,27 @@ pub async fn handle(command: SecretsCommand) -> Result<()> {
             )
             .await
         }
+        SecretsCommand::Delete { name } => {
+            event_helpers::with_event_lifecycle(
+                "secrets delete",
+                vec![format!("--name={}", name)],
+                async move {
+                    if secrets.delete_secret(&name)? {
+                        println!("{} Secret '{}' deleted", icons::status::success(), name);
+
+                        event_helpers::emit_domain_event(AgentEvent::SecretsModified {
+                            operation: "delete".to_string(),
+                            secret_name: name.clone(),
+                        })
+                        .await;
+                    } else {
+                        println!("Secret '{}' not found", name);
+                    }
+                    Ok(())
+                },
+            )
+            .await
+        }
         SecretsCommand::List => {
             event_helpers::with_event_lifecycle("secrets list", vec![], async move {
                 if !secrets.is_initialized() {
@@ -149,9 +176,13 @@ pub async fn handle(command: SecretsCommand) -> Result<()> {
             event_helpers::with_event_lifecycle("secrets info", vec![], async move {
                 println!("Secrets Configuration");
                 println!();
+                let backend = secrets.backend();
+                println!("Backend:        {}", backend.name());
+                println!("Location:       {}", backend.location());
                 println!("Config dir:     {}", secrets.config_dir().display());
-                println!("Secrets file:   {}", secrets.secrets_file().display());
-                println!("Identity key:   {}", secrets.identity_file().display());
+                if backend.name() == "file" {
+                    println!("Identity key:   {}", secrets.identity_file().display());
+                }
                 println!(
                     "Initialized:    {}",
                     if secrets.is_initialized() {
@@ -169,14 +200,14 @@ pub async fn handle(command: SecretsCommand) -> Result<()> {
                         let _available = secrets.get_secret(&name).is_ok();
                         let status = "";
                         let source = if std::env::var(&name).is_ok() {
-                            " (env)"
+                            "env"
                         } else {
-                            " (file)"
+                            backend.name()
                         };
-                        println!("  {} {}{}", status, name, source);
+                        println!("  {} {} ({})", status, name, source);
                     }
                 } else {
-                    println!("Run 'mnemosyne secrets init' to set up encrypted secrets.");
+                    println!("Run 'mnemosyne secrets init' to set up secrets.");
                 }
                 Ok(())
             })
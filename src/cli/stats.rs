@@ -0,0 +1,70 @@
+//! Memory store statistics command
+
+use mnemosyne_core::{error::Result, ConnectionMode, LibsqlStorage};
+
+use super::event_helpers;
+use super::helpers::{get_db_path, parse_namespace};
+
+/// Number of tags shown in the overview
+const TOP_TAGS: usize = 10;
+
+/// Handle stats command
+pub async fn handle(
+    namespace: Option<String>,
+    json: bool,
+    global_db_path: Option<String>,
+) -> Result<()> {
+    let args = namespace
+        .iter()
+        .map(|ns| format!("--namespace={}", ns))
+        .collect();
+
+    event_helpers::with_event_lifecycle("stats", args, async {
+        let db_path = get_db_path(global_db_path);
+        let storage = LibsqlStorage::new(ConnectionMode::Local(db_path)).await?;
+        let ns = namespace.as_deref().map(parse_namespace);
+        let stats = storage.memory_stats(ns.as_ref(), TOP_TAGS).await?;
+
+        if json {
+            println!("{}", serde_json::to_string_pretty(&stats)?);
+            return Ok(());
+        }
+
+        println!(
+            "Memories:     {} total, {} active, {} archived",
+            stats.total, stats.active, stats.archived
+        );
+        println!("Importance:   {:.1} average", stats.average_importance);
+        println!(
+            "Embeddings:   {}/{} ({:.0}%)",
+            stats.embedded,
+            stats.active,
+            stats.embedding_coverage * 100.0
+        );
+        println!(
+            "Links:        {} ({:.2} per memory)",
+            stats.links, stats.link_density
+        );
+
+        if !stats.by_type.is_empty() {
+            println!();
+            println!("By type:");
+            let mut by_type: Vec<_> = stats.by_type.iter().collect();
+            by_type.sort_by(|a, b| b.1.cmp(a.1).then(a.0.cmp(b.0)));
+            for (memory_type, count) in by_type {
+                println!("  {:<22} {}", memory_type, count);
+            }
+        }
+
+        if !stats.top_tags.is_empty() {
+            println!();
+            println!("Top tags:");
+            for tag in &stats.top_tags {
+                println!("  {:<22} {}", tag.tag, tag.count);
+            }
+        }
+
+        Ok(())
+    })
+    .await
+}
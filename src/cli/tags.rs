@@ -0,0 +1,91 @@
+//! Tag management command
+//!
+//! Cleans up tag drift (`db`, `database`, `databases`) across a namespace.
+//! Renames and merges are single transactional UPDATEs in storage.
+
+use clap::Subcommand;
+use mnemosyne_core::{error::Result, ConnectionMode, LibsqlStorage};
+
+use super::event_helpers;
+use super::helpers::{get_db_path, parse_namespace};
+
+#[derive(Subcommand)]
+pub enum TagsAction {
+    /// List tags with the number of memories using each
+    List,
+
+    /// Rename a tag (merges into `new` where both are present)
+    Rename {
+        /// Tag to replace
+        old: String,
+
+        /// Replacement tag
+        new: String,
+    },
+
+    /// Merge two tags into one
+    Merge {
+        /// First tag to merge
+        a: String,
+
+        /// Second tag to merge
+        b: String,
+
+        /// Resulting tag (may be `a` or `b`)
+        into: String,
+    },
+}
+
+/// Handle tag management command
+pub async fn handle(
+    action: TagsAction,
+    namespace: Option<String>,
+    global_db_path: Option<String>,
+) -> Result<()> {
+    let db_path = get_db_path(global_db_path);
+    let storage = LibsqlStorage::new(ConnectionMode::Local(db_path)).await?;
+    let ns = namespace.as_deref().map(parse_namespace);
+
+    match action {
+        TagsAction::List => {
+            event_helpers::with_event_lifecycle("tags-list", vec![], async {
+                let counts = storage.tag_counts(ns.as_ref()).await?;
+                if counts.is_empty() {
+                    eprintln!("No tags found");
+                    return Ok(());
+                }
+                let width = counts.iter().map(|(t, _)| t.len()).max().unwrap_or(0);
+                for (tag, uses) in counts {
+                    println!("{:<width$}  {}", tag, uses, width = width);
+                }
+                Ok(())
+            })
+            .await
+        }
+        TagsAction::Rename { old, new } => {
+            let args = vec![old.clone(), new.clone()];
+            event_helpers::with_event_lifecycle("tags-rename", args, async {
+                let changed = storage
+                    .rename_tags(&[old.clone()], &new, ns.as_ref())
+                    .await?;
+                println!("Renamed '{}' to '{}' on {} memories", old, new, changed);
+                Ok(())
+            })
+            .await
+        }
+        TagsAction::Merge { a, b, into } => {
+            let args = vec![a.clone(), b.clone(), into.clone()];
+            event_helpers::with_event_lifecycle("tags-merge", args, async {
+                let changed = storage
+                    .rename_tags(&[a.clone(), b.clone()], &into, ns.as_ref())
+                    .await?;
+                println!(
+                    "Merged '{}' and '{}' into '{}' on {} memories",
+                    a, b, into, changed
+                );
+                Ok(())
+            })
+            .await
+        }
+    }
+}
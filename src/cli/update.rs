@@ -23,7 +23,7 @@ pub async fn handle(tools: Vec<String>, install: bool, check_only: bool) -> Resu
 async fn handle_check_only() -> Result<()> {
     println!("{}  Checking for updates...\n", icons::system::gear());
 
-    let checker = VersionChecker::new()?;
+    let checker = VersionChecker::from_config()?;
     let results = checker.check_all_tools().await?;
 
     let mut has_updates = false;
@@ -112,7 +112,7 @@ async fn handle_install_instructions(tools: Vec<String>) -> Result<()> {
 /// Perform tool updates
 async fn handle_updates(tools: Vec<String>) -> Result<()> {
     let manager = UpdateManager::new()?;
-    let checker = VersionChecker::new()?;
+    let checker = VersionChecker::from_config()?;
 
     // Determine which tools to update
     let tools_to_update: Vec<Tool> = if tools.is_empty() {
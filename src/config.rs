@@ -2,23 +2,41 @@
 //!
 //! Provides secure storage of API keys using multiple methods:
 //! 1. Environment variables (highest priority)
-//! 2. Age-encrypted config file (main method)
-//! 3. OS-level keychains (fallback with keyring-fallback feature):
+//! 2. The active secrets backend (`[secrets] backend`): age-encrypted config
+//!    file by default, or the OS keychain
+//! 3. Legacy OS keychain entry (fallback with keyring-fallback feature):
 //!    - macOS: Keychain
 //!    - Windows: Credential Manager
 //!    - Linux: Secret Service (libsecret)
+//!
+//! Non-secret settings are loaded in layers via [`ConfigManager::load_layered`].
+//! Each layer overrides individual fields of the layers below it:
+//! 1. Built-in defaults (lowest priority)
+//! 2. Global config file (`~/.config/mnemosyne/config.toml`)
+//! 3. Project config file (`.mnemosyne/config.toml`, searched upward from the CWD)
+//! 4. Environment variables (`MNEMOSYNE_<SECTION>__<KEY>`, highest priority)
+//!
+//! Any layer may also hold per-namespace override blocks such as
+//! `[namespace."project:myapp".search]`, merged over the result by
+//! [`LayeredConfig::for_namespace`].
 
 use crate::error::{MnemosyneError, Result};
+use crate::launcher::{context::ContextLoadConfig, LauncherSettings};
 use crate::secrets::SecretsManager;
+use crate::types::{MemoryType, Namespace};
 #[cfg(feature = "keyring-fallback")]
 use keyring::Entry;
 use secrecy::ExposeSecret;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::env;
-use std::path::PathBuf;
+use std::fmt;
+use std::path::{Path, PathBuf};
 use tracing::{debug, info, warn};
 
 /// Configuration for local embedding generation
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct EmbeddingConfig {
     /// Enable or disable embeddings globally
     pub enabled: bool,
@@ -40,8 +58,15 @@ pub struct EmbeddingConfig {
     pub show_download_progress: bool,
 }
 
+/// Largest `max_graph_nodes` accepted
+///
+/// A traversal binds every visited node as a query parameter, so this keeps
+/// those queries, with their other parameters, well under SQLite's limit.
+pub const MAX_GRAPH_NODES: usize = crate::storage::libsql::MAX_SQL_VARIABLES / 4;
+
 /// Configuration for hybrid search scoring weights
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct SearchConfig {
     /// Weight for vector similarity (0.0-1.0)
     pub vector_weight: f32,
@@ -66,6 +91,144 @@ pub struct SearchConfig {
 
     /// Maximum graph traversal depth
     pub max_graph_depth: usize,
+
+    /// Maximum memories a graph traversal returns, seeds included; each hop
+    /// keeps the strongest links first (at most [`MAX_GRAPH_NODES`])
+    pub max_graph_nodes: usize,
+
+    /// Attach a per-signal `ScoreBreakdown` to hybrid search results (debugging)
+    pub explain_scores: bool,
+
+    /// Recall results kept in the in-process cache (0 disables caching)
+    pub recall_cache_size: usize,
+
+    /// Seconds a cached recall stays valid
+    pub recall_cache_ttl_secs: u64,
+
+    /// Tokenizer for the keyword (FTS5) index; changing it rebuilds the index
+    pub fts_tokenizer: FtsTokenizer,
+
+    /// Vector search handling of memories embedded by a model other than the
+    /// active one
+    pub stale_embeddings: StaleEmbeddingPolicy,
+
+    /// Namespaces graph traversal may cross into from its seeds
+    pub namespace_boundary: NamespaceBoundary,
+}
+
+/// How far graph traversal may leave its seeds' namespaces
+/// (`search.namespace_boundary`)
+///
+/// Each memory reached is checked against the namespace of the seed its
+/// path started from, so a link cannot pull another project's memories
+/// into a project recall.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NamespaceBoundary {
+    /// Stay within the seed's namespace
+    Strict,
+
+    /// Stay within the seed's namespace, plus global memories
+    #[default]
+    AllowGlobal,
+
+    /// Follow links into any namespace
+    Any,
+}
+
+impl NamespaceBoundary {
+    /// Whether a path that started in `origin` may enter `neighbor`
+    pub fn allows(self, origin: &Namespace, neighbor: &Namespace) -> bool {
+        match self {
+            Self::Strict => origin == neighbor,
+            Self::AllowGlobal => origin == neighbor || *neighbor == Namespace::Global,
+            Self::Any => true,
+        }
+    }
+}
+
+/// What vector search does with embeddings from a previous model
+/// (`search.stale_embeddings`)
+///
+/// Vectors from different models are not comparable, so similarity scores
+/// against them are noise.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StaleEmbeddingPolicy {
+    /// Compare against every embedding regardless of model
+    Include,
+
+    /// Leave stale embeddings out of vector search
+    #[default]
+    Exclude,
+
+    /// Leave them out, and have `MemoryManager` re-embed them in the
+    /// background when it detects them
+    Reembed,
+}
+
+/// FTS5 tokenizer used for keyword search (`[search.fts_tokenizer]`)
+///
+/// FTS5 fixes the tokenizer when the index is created, so a change only takes
+/// effect once the index is rebuilt (see `LibsqlStorage::apply_fts_tokenizer`).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum FtsTokenizer {
+    /// Porter stemming over unicode61: "running" matches "run"
+    #[default]
+    Porter,
+
+    /// Unicode word tokenizer without stemming
+    Unicode61 {
+        /// 0 keeps diacritics, 1 or 2 fold them ("café" matches "cafe")
+        #[serde(default = "default_remove_diacritics")]
+        remove_diacritics: u8,
+
+        /// Extra characters treated as part of a token (e.g. "-_")
+        #[serde(default)]
+        tokenchars: String,
+
+        /// Extra characters treated as separators
+        #[serde(default)]
+        separators: String,
+    },
+
+    /// Character trigrams: substring matches and text without word breaks
+    /// (CJK). Query terms need at least three characters.
+    Trigram {
+        #[serde(default)]
+        case_sensitive: bool,
+    },
+}
+
+fn default_remove_diacritics() -> u8 {
+    1
+}
+
+impl FtsTokenizer {
+    /// Value of the FTS5 `tokenize` option
+    pub fn fts5_spec(&self) -> String {
+        match self {
+            Self::Porter => "porter".to_string(),
+            Self::Unicode61 {
+                remove_diacritics,
+                tokenchars,
+                separators,
+            } => {
+                let mut spec = format!("unicode61 remove_diacritics {}", remove_diacritics);
+                if !tokenchars.is_empty() {
+                    spec.push_str(&format!(" tokenchars '{}'", tokenchars.replace('\'', "''")));
+                }
+                if !separators.is_empty() {
+                    spec.push_str(&format!(" separators '{}'", separators.replace('\'', "''")));
+                }
+                spec
+            }
+            Self::Trigram { case_sensitive } => {
+                format!("trigram case_sensitive {}", u8::from(*case_sensitive))
+            }
+        }
+    }
 }
 
 impl Default for SearchConfig {
@@ -81,6 +244,14 @@ impl Default for SearchConfig {
             enable_vector_search: true,
             enable_graph_expansion: true,
             max_graph_depth: 2,
+            max_graph_nodes: 200,
+            explain_scores: false,
+
+            recall_cache_size: 0,
+            recall_cache_ttl_secs: 30,
+            fts_tokenizer: FtsTokenizer::Porter,
+            stale_embeddings: StaleEmbeddingPolicy::Exclude,
+            namespace_boundary: NamespaceBoundary::AllowGlobal,
         }
     }
 }
@@ -121,6 +292,154 @@ impl SearchConfig {
             )));
         }
 
+        if self.max_graph_nodes == 0 {
+            return Err(MnemosyneError::Config(config::ConfigError::Message(
+                "max_graph_nodes must be at least 1".to_string(),
+            )));
+        }
+
+        if self.max_graph_nodes > MAX_GRAPH_NODES {
+            return Err(MnemosyneError::Config(config::ConfigError::Message(
+                format!(
+                    "max_graph_nodes must be at most {}, got {}",
+                    MAX_GRAPH_NODES, self.max_graph_nodes
+                ),
+            )));
+        }
+
+        if self.recall_cache_size > 0 && self.recall_cache_ttl_secs == 0 {
+            return Err(MnemosyneError::Config(config::ConfigError::Message(
+                "recall_cache_ttl_secs must be at least 1 when the recall cache is enabled"
+                    .to_string(),
+            )));
+        }
+
+        if let FtsTokenizer::Unicode61 {
+            remove_diacritics, ..
+        } = self.fts_tokenizer
+        {
+            if remove_diacritics > 2 {
+                return Err(MnemosyneError::Config(config::ConfigError::Message(
+                    format!(
+                        "fts_tokenizer.remove_diacritics must be 0, 1, or 2, got {}",
+                        remove_diacritics
+                    ),
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Team-defined memory types (`[memory_types.custom]`)
+///
+/// Maps each custom type name to a description shown in help text, e.g.
+/// `security_finding = "Vulnerabilities found in review or audits"`.
+///
+/// `[memory_types.default_importance]` sets the importance `remember` gives
+/// a memory of each type when `--importance` isn't passed, e.g.
+/// `reference = 3`. Types left out keep their built-in default.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MemoryTypesConfig {
+    /// Custom type name to description
+    pub custom: BTreeMap<String, String>,
+
+    /// Type name to importance (1-10) used when none is given
+    pub default_importance: BTreeMap<String, u8>,
+}
+
+/// Importance for a type missing from `[memory_types.default_importance]`
+pub const FALLBACK_IMPORTANCE: u8 = 5;
+
+impl Default for MemoryTypesConfig {
+    fn default() -> Self {
+        // Decisions and constraints outlive most notes; references and
+        // agent events are cheap to find again
+        let default_importance = [
+            (MemoryType::Constitution, 9),
+            (MemoryType::ArchitectureDecision, 8),
+            (MemoryType::Constraint, 8),
+            (MemoryType::FeatureSpec, 7),
+            (MemoryType::ImplementationPlan, 7),
+            (MemoryType::BugFix, 6),
+            (MemoryType::CodePattern, 6),
+            (MemoryType::Preference, 6),
+            (MemoryType::Clarification, 6),
+            (MemoryType::QualityChecklist, 6),
+            (MemoryType::TaskBreakdown, 6),
+            (MemoryType::Configuration, 5),
+            (MemoryType::Entity, 5),
+            (MemoryType::Insight, 5),
+            (MemoryType::Task, 5),
+            (MemoryType::Reference, 4),
+            (MemoryType::AgentEvent, 3),
+        ]
+        .into_iter()
+        .map(|(memory_type, importance)| (memory_type.as_str().to_string(), importance))
+        .collect();
+
+        Self {
+            custom: BTreeMap::new(),
+            default_importance,
+        }
+    }
+}
+
+impl MemoryTypesConfig {
+    /// Built-in type, or a declared custom type, with this name
+    pub fn resolve(&self, name: &str) -> Option<MemoryType> {
+        match MemoryType::from_name(name) {
+            MemoryType::Custom(name) if !self.custom.contains_key(&name) => None,
+            memory_type => Some(memory_type),
+        }
+    }
+
+    /// One `name  description` line per declared custom type
+    pub fn help_text(&self) -> String {
+        let width = self.custom.keys().map(String::len).max().unwrap_or(0);
+        self.custom
+            .iter()
+            .map(|(name, description)| format!("{:<width$}  {}", name, description, width = width))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Importance for a new memory of this type when none is given
+    pub fn default_importance(&self, memory_type: &MemoryType) -> u8 {
+        self.default_importance
+            .get(memory_type.as_str())
+            .copied()
+            .unwrap_or(FALLBACK_IMPORTANCE)
+    }
+
+    /// Validate declared names and default importances
+    pub fn validate(&self) -> Result<()> {
+        for name in self.custom.keys() {
+            let memory_type = MemoryType::from_name(name);
+            if !memory_type.is_custom() {
+                return Err(config_error(format!(
+                    "memory_types.custom.{} shadows a built-in memory type",
+                    name
+                )));
+            }
+            memory_type.validate().map_err(config_error)?;
+        }
+        for (name, importance) in &self.default_importance {
+            if self.resolve(name).is_none() {
+                return Err(config_error(format!(
+                    "memory_types.default_importance.{} is not a known memory type",
+                    name
+                )));
+            }
+            if !(1..=10).contains(importance) {
+                return Err(config_error(format!(
+                    "memory_types.default_importance.{} must be between 1 and 10, got {}",
+                    name, importance
+                )));
+            }
+        }
         Ok(())
     }
 }
@@ -228,6 +547,407 @@ impl EmbeddingConfig {
     }
 }
 
+/// Prefix for environment variables that override config file values
+///
+/// Nested keys are separated by a double underscore, e.g.
+/// `MNEMOSYNE_SEARCH__VECTOR_WEIGHT=0.5` overrides `search.vector_weight`.
+pub const CONFIG_ENV_PREFIX: &str = "MNEMOSYNE_";
+
+/// Name of the config file in both the global and project config directories
+pub const CONFIG_FILE_NAME: &str = "config.toml";
+
+/// Where an effective configuration value came from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigSource {
+    /// Built-in default
+    Default,
+    /// Global config file (XDG config directory)
+    Global(PathBuf),
+    /// Project config file (`.mnemosyne/config.toml`)
+    Project(PathBuf),
+    /// Environment variable
+    Environment(String),
+}
+
+impl fmt::Display for ConfigSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigSource::Default => write!(f, "default"),
+            ConfigSource::Global(path) => write!(f, "global: {}", path.display()),
+            ConfigSource::Project(path) => write!(f, "project: {}", path.display()),
+            ConfigSource::Environment(var) => write!(f, "env: {}", var),
+        }
+    }
+}
+
+/// Typed settings resolved from the config layers
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MnemosyneSettings {
+    /// Hybrid search settings (`[search]`)
+    pub search: SearchConfig,
+
+    /// Embedding settings (`[embeddings]`)
+    pub embeddings: EmbeddingConfig,
+
+    /// Startup context budget and timeout (`[context]`)
+    pub context: ContextLoadConfig,
+
+    /// Claude Code launcher settings (`[launcher]`)
+    pub launcher: LauncherSettings,
+
+    /// Update check channel and cache (`[updates]`)
+    pub updates: crate::version_check::UpdateCheckSettings,
+
+    /// Per-role importance weights (`[importance.<role>]`)
+    pub importance: crate::agents::ImportanceProfiles,
+
+    /// Team-defined memory types (`[memory_types.custom]`)
+    pub memory_types: MemoryTypesConfig,
+
+    /// LLM token budget and per-task models (`[llm]`)
+    pub llm: crate::services::LlmSettings,
+
+    /// Secret storage backend (`[secrets]`)
+    pub secrets: crate::secrets::SecretsSettings,
+
+    /// P2P connection limits and peer allowlist (`[network]`)
+    pub network: crate::orchestration::NetworkConfig,
+
+    /// Background evolution job schedules (`[evolution]`)
+    pub evolution: crate::evolution::EvolutionConfig,
+}
+
+/// Effective configuration merged from all layers
+///
+/// Layers are merged per field, so a project file that only sets
+/// `search.vector_weight` keeps every other value from the global file
+/// and the built-in defaults.
+#[derive(Debug, Clone)]
+pub struct LayeredConfig {
+    /// Typed view of the merged values
+    pub settings: MnemosyneSettings,
+    values: toml::Table,
+    sources: BTreeMap<String, ConfigSource>,
+}
+
+impl LayeredConfig {
+    /// Merge defaults, optional global/project files, and environment overrides
+    ///
+    /// Only variables starting with [`CONFIG_ENV_PREFIX`] and containing a
+    /// `__` section separator are treated as overrides; others are ignored.
+    pub fn from_layers<I>(
+        global: Option<&Path>,
+        project: Option<&Path>,
+        env_vars: I,
+    ) -> Result<Self>
+    where
+        I: IntoIterator<Item = (String, String)>,
+    {
+        let mut values = toml::Table::new();
+        let mut sources = BTreeMap::new();
+
+        let defaults = match toml::Value::try_from(MnemosyneSettings::default()) {
+            Ok(toml::Value::Table(table)) => table,
+            Ok(_) => toml::Table::new(),
+            Err(e) => {
+                return Err(config_error(format!(
+                    "Failed to serialize default settings: {}",
+                    e
+                )))
+            }
+        };
+        merge_layer(
+            &mut values,
+            &mut sources,
+            defaults,
+            "",
+            &ConfigSource::Default,
+        );
+
+        if let Some(path) = global {
+            let layer = read_config_file(path)?;
+            let source = ConfigSource::Global(path.to_path_buf());
+            merge_layer(&mut values, &mut sources, layer, "", &source);
+        }
+
+        if let Some(path) = project {
+            let layer = read_config_file(path)?;
+            let source = ConfigSource::Project(path.to_path_buf());
+            merge_layer(&mut values, &mut sources, layer, "", &source);
+        }
+
+        let mut env_vars: Vec<(String, String)> = env_vars.into_iter().collect();
+        env_vars.sort();
+        for (name, raw) in env_vars {
+            if let Some(layer) = env_override(&name, &raw) {
+                let source = ConfigSource::Environment(name);
+                merge_layer(&mut values, &mut sources, layer, "", &source);
+            }
+        }
+
+        let config = Self {
+            settings: settings_from(base_values(&values))?,
+            values,
+            sources,
+        };
+
+        // Reject bad override blocks at load time, not on first use
+        for (key, overrides) in config.namespace_overrides() {
+            if !overrides.is_table() {
+                return Err(config_error(format!(
+                    "namespace.\"{}\" must be a table of overrides",
+                    key
+                )));
+            }
+            let mut values = base_values(&config.values);
+            merge_overrides(&mut values, overrides);
+            settings_from(values)
+                .map_err(|e| config_error(format!("In namespace.\"{}\": {}", key, e)))?;
+        }
+
+        Ok(config)
+    }
+
+    /// Settings with the override blocks for `namespace` merged on top
+    ///
+    /// Blocks are keyed by the namespace string (`[namespace."project:myapp".search]`).
+    /// A session namespace applies its project's block first, then its own.
+    pub fn for_namespace(&self, namespace: &Namespace) -> Result<EffectiveConfig> {
+        let mut keys = Vec::new();
+        if let Namespace::Session { project, .. } = namespace {
+            keys.push(
+                Namespace::Project {
+                    name: project.clone(),
+                }
+                .to_string(),
+            );
+        }
+        keys.push(namespace.to_string());
+
+        let overrides = self.namespace_overrides();
+        let mut values = base_values(&self.values);
+        let mut applied = Vec::new();
+        for key in keys {
+            if let Some(block) = overrides.get(&key) {
+                merge_overrides(&mut values, block);
+                applied.push(key);
+            }
+        }
+
+        Ok(EffectiveConfig {
+            namespace: namespace.clone(),
+            settings: settings_from(values)?,
+            applied,
+        })
+    }
+
+    /// Override blocks under `[namespace."<namespace>"]`
+    fn namespace_overrides(&self) -> toml::Table {
+        match self.values.get(NAMESPACE_OVERRIDES_KEY) {
+            Some(toml::Value::Table(overrides)) => overrides.clone(),
+            _ => toml::Table::new(),
+        }
+    }
+
+    /// Look up an effective value by dotted key (e.g. `search.vector_weight`)
+    pub fn get(&self, key: &str) -> Option<&toml::Value> {
+        let mut parts = key.split('.');
+        let mut current = self.values.get(parts.next()?)?;
+        for part in parts {
+            current = current.as_table()?.get(part)?;
+        }
+        Some(current)
+    }
+
+    /// Layer that supplied the effective value for a dotted key
+    pub fn source_of(&self, key: &str) -> Option<&ConfigSource> {
+        self.sources.get(key)
+    }
+
+    /// All effective leaf values with their sources, sorted by key
+    pub fn entries(&self) -> Vec<(String, &toml::Value, &ConfigSource)> {
+        self.sources
+            .iter()
+            .filter_map(|(key, source)| self.get(key).map(|v| (key.clone(), v, source)))
+            .collect()
+    }
+}
+
+/// Path of the global config file (`~/.config/mnemosyne/config.toml` on Linux)
+pub fn global_config_path() -> Option<PathBuf> {
+    directories::ProjectDirs::from("com", "mnemosyne", "mnemosyne")
+        .map(|dirs| dirs.config_dir().join(CONFIG_FILE_NAME))
+}
+
+/// Find the nearest `.mnemosyne/config.toml` at or above `cwd`
+pub fn find_project_config(cwd: &Path) -> Option<PathBuf> {
+    cwd.ancestors()
+        .map(|dir| dir.join(".mnemosyne").join(CONFIG_FILE_NAME))
+        .find(|path| path.is_file())
+}
+
+/// Settings in effect for one namespace (see [`LayeredConfig::for_namespace`])
+#[derive(Debug, Clone)]
+pub struct EffectiveConfig {
+    /// Namespace the settings were resolved for
+    pub namespace: Namespace,
+
+    /// Base settings with the namespace's override blocks merged on top
+    pub settings: MnemosyneSettings,
+
+    /// Override blocks applied, least specific first (e.g. `project:myapp`)
+    pub applied: Vec<String>,
+}
+
+/// Top-level table holding per-namespace override blocks
+const NAMESPACE_OVERRIDES_KEY: &str = "namespace";
+
+/// Merged values without the per-namespace override blocks
+fn base_values(values: &toml::Table) -> toml::Table {
+    let mut base = values.clone();
+    base.remove(NAMESPACE_OVERRIDES_KEY);
+    base
+}
+
+fn merge_overrides(values: &mut toml::Table, overrides: &toml::Value) {
+    if let toml::Value::Table(overrides) = overrides {
+        // Provenance is only tracked for the base layers
+        let mut sources = BTreeMap::new();
+        merge_layer(
+            values,
+            &mut sources,
+            overrides.clone(),
+            "",
+            &ConfigSource::Default,
+        );
+    }
+}
+
+/// Deserialize and validate merged values
+fn settings_from(values: toml::Table) -> Result<MnemosyneSettings> {
+    let settings: MnemosyneSettings = toml::Value::Table(values)
+        .try_into()
+        .map_err(|e| config_error(format!("Invalid configuration: {}", e)))?;
+    settings.search.validate()?;
+    settings.embeddings.validate()?;
+    settings.context.validate()?;
+    settings
+        .importance
+        .validate()
+        .map_err(|e| config_error(e.to_string()))?;
+    settings.memory_types.validate()?;
+    settings.llm.validate()?;
+    settings
+        .evolution
+        .validate()
+        .map_err(|e| config_error(e.to_string()))?;
+    Ok(settings)
+}
+
+fn config_error(message: String) -> MnemosyneError {
+    MnemosyneError::Config(config::ConfigError::Message(message))
+}
+
+fn read_config_file(path: &Path) -> Result<toml::Table> {
+    let content = std::fs::read_to_string(path).map_err(|e| {
+        MnemosyneError::Io(std::io::Error::new(
+            e.kind(),
+            format!("Failed to read config file {}: {}", path.display(), e),
+        ))
+    })?;
+
+    toml::from_str(&content).map_err(|e| {
+        config_error(format!(
+            "Failed to parse config file {}: {}",
+            path.display(),
+            e
+        ))
+    })
+}
+
+/// Merge `layer` into `base`, recording `source` for every leaf it sets
+fn merge_layer(
+    base: &mut toml::Table,
+    sources: &mut BTreeMap<String, ConfigSource>,
+    layer: toml::Table,
+    prefix: &str,
+    source: &ConfigSource,
+) {
+    for (key, value) in layer {
+        let path = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{}.{}", prefix, key)
+        };
+
+        let value = match (base.get_mut(&key), value) {
+            (Some(toml::Value::Table(existing)), toml::Value::Table(incoming)) => {
+                merge_layer(existing, sources, incoming, &path, source);
+                continue;
+            }
+            (_, value) => value,
+        };
+
+        // Replacing a subtree drops provenance of the keys it contained
+        let nested = format!("{}.", path);
+        sources.retain(|k, _| k != &path && !k.starts_with(&nested));
+        record_sources(sources, &value, &path, source);
+        base.insert(key, value);
+    }
+}
+
+fn record_sources(
+    sources: &mut BTreeMap<String, ConfigSource>,
+    value: &toml::Value,
+    path: &str,
+    source: &ConfigSource,
+) {
+    match value {
+        toml::Value::Table(table) => {
+            for (key, nested) in table {
+                record_sources(sources, nested, &format!("{}.{}", path, key), source);
+            }
+        }
+        _ => {
+            sources.insert(path.to_string(), source.clone());
+        }
+    }
+}
+
+/// Convert `MNEMOSYNE_SECTION__KEY=value` into a single-entry nested table
+fn env_override(name: &str, raw: &str) -> Option<toml::Table> {
+    let rest = name.strip_prefix(CONFIG_ENV_PREFIX)?;
+    if !rest.contains("__") {
+        return None;
+    }
+
+    let parts: Vec<String> = rest.split("__").map(|p| p.to_lowercase()).collect();
+    if parts.iter().any(|p| p.is_empty()) {
+        return None;
+    }
+
+    let mut value = parse_env_value(raw);
+    for part in parts.iter().skip(1).rev() {
+        let mut table = toml::Table::new();
+        table.insert(part.clone(), value);
+        value = toml::Value::Table(table);
+    }
+
+    let mut layer = toml::Table::new();
+    layer.insert(parts[0].clone(), value);
+    Some(layer)
+}
+
+/// Interpret an environment value as a TOML scalar, falling back to a string
+fn parse_env_value(raw: &str) -> toml::Value {
+    toml::from_str::<toml::Table>(&format!("value = {}", raw))
+        .ok()
+        .and_then(|mut table| table.remove("value"))
+        .filter(|value| !value.is_table())
+        .unwrap_or_else(|| toml::Value::String(raw.to_string()))
+}
+
 /// Service name for keyring storage
 const KEYRING_SERVICE: &str = "mnemosyne-memory-system";
 #[cfg(test)]
@@ -300,10 +1020,10 @@ impl ConfigManager {
 
     /// Get the Anthropic API key from:
     /// 1. Environment variable ANTHROPIC_API_KEY
-    /// 2. Age-encrypted config file
-    /// 3. OS keychain (fallback with keyring-fallback feature)
+    /// 2. Active secrets backend (encrypted file or OS keychain)
+    /// 3. Legacy OS keychain entry (fallback with keyring-fallback feature)
     pub fn get_api_key(&self) -> Result<String> {
-        // Try SecretsManager (checks env var, then encrypted file)
+        // Try SecretsManager (checks env var, then the active backend)
         match self.secrets.get_secret("ANTHROPIC_API_KEY") {
             Ok(secret) => {
                 debug!("Retrieved API key from secrets manager");
@@ -402,6 +1122,45 @@ impl ConfigManager {
         }
     }
 
+    /// Settings in effect for `namespace`, loaded from the current directory
+    ///
+    /// See [`LayeredConfig::for_namespace`] for how override blocks apply.
+    pub fn for_namespace(namespace: &Namespace) -> Result<EffectiveConfig> {
+        let cwd = env::current_dir().map_err(MnemosyneError::from)?;
+        Self::load_layered(&cwd)?.for_namespace(namespace)
+    }
+
+    /// Load settings layered as defaults < global < project < environment
+    ///
+    /// The project file is the nearest `.mnemosyne/config.toml` at or above
+    /// `cwd`. Missing files are skipped; malformed files are an error.
+    pub fn load_layered(cwd: &Path) -> Result<LayeredConfig> {
+        let global = global_config_path().filter(|path| path.is_file());
+        let project = find_project_config(cwd);
+
+        debug!(
+            "Loading layered config (global: {:?}, project: {:?})",
+            global, project
+        );
+
+        LayeredConfig::from_layers(global.as_deref(), project.as_deref(), env::vars())
+    }
+
+    /// One section of the layered settings for the current directory
+    ///
+    /// Falls back to the defaults, with a warning naming `what`, if the config
+    /// can't be loaded.
+    pub fn load_section<T: Default>(what: &str, section: impl FnOnce(MnemosyneSettings) -> T) -> T {
+        env::current_dir()
+            .map_err(MnemosyneError::from)
+            .and_then(|cwd| Self::load_layered(&cwd))
+            .map(|layered| section(layered.settings))
+            .unwrap_or_else(|e| {
+                warn!("Could not load {}, using defaults: {}", what, e);
+                T::default()
+            })
+    }
+
     /// Get the secrets manager reference
     pub fn secrets(&self) -> &SecretsManager {
         &self.secrets
@@ -635,6 +1394,21 @@ mod tests {
         assert!(config.validate().is_ok());
     }
 
+    #[test]
+    fn test_max_graph_nodes_bounds() {
+        let mut config = SearchConfig::default();
+        assert!(config.validate().is_ok());
+
+        config.max_graph_nodes = 0;
+        assert!(config.validate().is_err());
+
+        config.max_graph_nodes = MAX_GRAPH_NODES;
+        assert!(config.validate().is_ok());
+
+        config.max_graph_nodes = MAX_GRAPH_NODES + 1;
+        assert!(config.validate().is_err());
+    }
+
     #[test]
     fn test_embedding_config_all_supported_models() {
         let supported_models = vec![
@@ -653,4 +1427,332 @@ mod tests {
             assert!(config.validate().is_ok(), "Model {} should be valid", model);
         }
     }
+
+    // Layered config tests
+    fn write_config(dir: &Path, contents: &str) -> PathBuf {
+        std::fs::create_dir_all(dir).unwrap();
+        let path = dir.join(CONFIG_FILE_NAME);
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_layered_defaults_only() {
+        let config = LayeredConfig::from_layers(None, None, Vec::new()).unwrap();
+
+        assert_eq!(config.settings.search.max_graph_depth, 2);
+        assert_eq!(
+            config.source_of("search.vector_weight"),
+            Some(&ConfigSource::Default)
+        );
+        assert_eq!(
+            config.get("embeddings.model").and_then(|v| v.as_str()),
+            Some("nomic-embed-text-v1.5")
+        );
+    }
+
+    #[test]
+    fn test_layered_per_field_precedence() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let global = write_config(
+            &temp.path().join("global"),
+            "[search]\nvector_weight = 0.5\nmax_graph_depth = 3\n",
+        );
+        let project = write_config(
+            &temp.path().join("project/.mnemosyne"),
+            "[search]\nmax_graph_depth = 4\n",
+        );
+        let env_vars = vec![
+            (
+                "MNEMOSYNE_EMBEDDINGS__BATCH_SIZE".to_string(),
+                "64".to_string(),
+            ),
+            (
+                "MNEMOSYNE_DB_PATH".to_string(),
+                "/tmp/ignored.db".to_string(),
+            ),
+        ];
+
+        let config = LayeredConfig::from_layers(Some(&global), Some(&project), env_vars).unwrap();
+
+        // Global value survives where project doesn't override it
+        assert_eq!(config.settings.search.vector_weight, 0.5);
+        assert_eq!(
+            config.source_of("search.vector_weight"),
+            Some(&ConfigSource::Global(global.clone()))
+        );
+
+        // Project overrides global for the same field
+        assert_eq!(config.settings.search.max_graph_depth, 4);
+        assert_eq!(
+            config.source_of("search.max_graph_depth"),
+            Some(&ConfigSource::Project(project.clone()))
+        );
+
+        // Environment overrides everything
+        assert_eq!(config.settings.embeddings.batch_size, 64);
+        assert_eq!(
+            config.source_of("embeddings.batch_size"),
+            Some(&ConfigSource::Environment(
+                "MNEMOSYNE_EMBEDDINGS__BATCH_SIZE".to_string()
+            ))
+        );
+
+        // Untouched fields keep their defaults
+        assert_eq!(config.settings.search.keyword_weight, 0.30);
+        assert_eq!(
+            config.source_of("search.keyword_weight"),
+            Some(&ConfigSource::Default)
+        );
+    }
+
+    #[test]
+    fn test_layered_context_budget() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let project = write_config(
+            &temp.path().join(".mnemosyne"),
+            "[context]\ntimeout_ms = 2000\nmax_context_tokens = 8000\n",
+        );
+        let env_vars = vec![(
+            "MNEMOSYNE_CONTEXT__MAX_MEMORIES".to_string(),
+            "25".to_string(),
+        )];
+
+        let config = LayeredConfig::from_layers(None, Some(&project), env_vars).unwrap();
+        assert_eq!(config.settings.context.timeout_ms, 2000);
+        assert_eq!(config.settings.context.max_context_tokens, 8000);
+        assert_eq!(config.settings.context.max_memories, 25);
+        assert_eq!(config.settings.context.min_importance, 7);
+    }
+
+    #[test]
+    fn test_layered_env_string_value() {
+        let env_vars = vec![(
+            "MNEMOSYNE_EMBEDDINGS__DEVICE".to_string(),
+            "cuda".to_string(),
+        )];
+        let config = LayeredConfig::from_layers(None, None, env_vars).unwrap();
+        assert!(config.settings.embeddings.use_gpu());
+    }
+
+    #[test]
+    fn test_layered_invalid_value_rejected() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let project = write_config(temp.path(), "[search]\nvector_weight = 2.0\n");
+
+        assert!(LayeredConfig::from_layers(None, Some(&project), Vec::new()).is_err());
+    }
+
+    #[test]
+    fn test_layered_fts_tokenizer() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let project = write_config(
+            temp.path(),
+            "[search.fts_tokenizer]\nkind = \"unicode61\"\ntokenchars = \"-_\"\n",
+        );
+
+        let config = LayeredConfig::from_layers(None, Some(&project), Vec::new()).unwrap();
+        assert_eq!(
+            config.settings.search.fts_tokenizer.fts5_spec(),
+            "unicode61 remove_diacritics 1 tokenchars '-_'"
+        );
+
+        let env_vars = vec![(
+            "MNEMOSYNE_SEARCH__FTS_TOKENIZER__KIND".to_string(),
+            "trigram".to_string(),
+        )];
+        let config = LayeredConfig::from_layers(None, None, env_vars).unwrap();
+        assert_eq!(
+            config.settings.search.fts_tokenizer,
+            FtsTokenizer::Trigram {
+                case_sensitive: false
+            }
+        );
+
+        let invalid = write_config(
+            temp.path(),
+            "[search.fts_tokenizer]\nkind = \"unicode61\"\nremove_diacritics = 3\n",
+        );
+        assert!(LayeredConfig::from_layers(None, Some(&invalid), Vec::new()).is_err());
+    }
+
+    #[test]
+    fn test_layered_importance_profiles() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let project = write_config(
+            temp.path(),
+            "[importance.reviewer]\nbase = 0.9\naccess = 0.0\n",
+        );
+
+        let config = LayeredConfig::from_layers(None, Some(&project), Vec::new()).unwrap();
+        let reviewer = &config.settings.importance.reviewer;
+        assert_eq!(reviewer.base, 0.9);
+        assert_eq!(reviewer.access, 0.0);
+        // Fields left unset keep the role's built-in value
+        assert_eq!(reviewer.recency, 0.2);
+
+        let negative = write_config(temp.path(), "[importance.optimizer]\nbase = -1.0\n");
+        assert!(LayeredConfig::from_layers(None, Some(&negative), Vec::new()).is_err());
+    }
+
+    #[test]
+    fn test_layered_custom_memory_types() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let project = write_config(
+            temp.path(),
+            "[memory_types.custom]\nsecurity_finding = \"Vulnerabilities found in review\"\n",
+        );
+
+        let config = LayeredConfig::from_layers(None, Some(&project), Vec::new()).unwrap();
+        let types = &config.settings.memory_types;
+        assert_eq!(
+            types.resolve("security_finding"),
+            Some(MemoryType::Custom("security_finding".to_string()))
+        );
+        assert_eq!(types.resolve("bug_fix"), Some(MemoryType::BugFix));
+        assert_eq!(types.resolve("undeclared"), None);
+        assert!(types
+            .help_text()
+            .contains("Vulnerabilities found in review"));
+
+        let shadowing = write_config(temp.path(), "[memory_types.custom]\ninsight = \"x\"\n");
+        assert!(LayeredConfig::from_layers(None, Some(&shadowing), Vec::new()).is_err());
+        let malformed = write_config(
+            temp.path(),
+            "[memory_types.custom]\n\"Security Finding\" = \"x\"\n",
+        );
+        assert!(LayeredConfig::from_layers(None, Some(&malformed), Vec::new()).is_err());
+    }
+
+    #[test]
+    fn test_layered_evolution_jobs() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let project = write_config(
+            temp.path(),
+            "[evolution.archival]\nenabled = false\ninterval = 7200\n",
+        );
+
+        let config = LayeredConfig::from_layers(None, Some(&project), Vec::new()).unwrap();
+        let evolution = &config.settings.evolution;
+        assert!(!evolution.archival.enabled);
+        assert_eq!(
+            evolution.archival.interval,
+            std::time::Duration::from_secs(7200)
+        );
+        // Fields left unset keep the built-in schedule
+        assert_eq!(evolution.archival.batch_size, 500);
+        assert!(evolution.consolidation.enabled);
+
+        let too_frequent = write_config(temp.path(), "[evolution.importance]\ninterval = 60\n");
+        assert!(LayeredConfig::from_layers(None, Some(&too_frequent), Vec::new()).is_err());
+    }
+
+    #[test]
+    fn test_default_importance_by_type() {
+        let types = MemoryTypesConfig::default();
+        assert!(
+            types.default_importance(&MemoryType::ArchitectureDecision)
+                > types.default_importance(&MemoryType::Reference)
+        );
+        assert_eq!(
+            types.default_importance(&MemoryType::Custom("security_finding".to_string())),
+            FALLBACK_IMPORTANCE
+        );
+
+        let temp = tempfile::TempDir::new().unwrap();
+        let project = write_config(
+            temp.path(),
+            "[memory_types.custom]\nsecurity_finding = \"x\"\n\n\
+             [memory_types.default_importance]\nreference = 2\nsecurity_finding = 9\n",
+        );
+        let config = LayeredConfig::from_layers(None, Some(&project), Vec::new()).unwrap();
+        let types = &config.settings.memory_types;
+        assert_eq!(types.default_importance(&MemoryType::Reference), 2);
+        assert_eq!(
+            types.default_importance(&MemoryType::Custom("security_finding".to_string())),
+            9
+        );
+        // Types left unset keep their built-in default
+        assert_eq!(
+            types.default_importance(&MemoryType::ArchitectureDecision),
+            8
+        );
+
+        let out_of_range = write_config(
+            temp.path(),
+            "[memory_types.default_importance]\ninsight = 11\n",
+        );
+        assert!(LayeredConfig::from_layers(None, Some(&out_of_range), Vec::new()).is_err());
+        let undeclared = write_config(
+            temp.path(),
+            "[memory_types.default_importance]\nundeclared = 7\n",
+        );
+        assert!(LayeredConfig::from_layers(None, Some(&undeclared), Vec::new()).is_err());
+    }
+
+    #[test]
+    fn test_namespace_override_changes_search_weight() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let project = write_config(
+            temp.path(),
+            "[search]\nkeyword_weight = 0.25\n\n\
+             [namespace.\"project:myapp\".search]\nvector_weight = 0.6\n",
+        );
+        let config = LayeredConfig::from_layers(None, Some(&project), Vec::new()).unwrap();
+        let default_weight = SearchConfig::default().vector_weight;
+
+        // Base settings ignore override blocks
+        assert_eq!(config.settings.search.vector_weight, default_weight);
+
+        let myapp = config
+            .for_namespace(&Namespace::Project {
+                name: "myapp".to_string(),
+            })
+            .unwrap();
+        assert_eq!(myapp.settings.search.vector_weight, 0.6);
+        // Fields the block leaves unset keep the base value
+        assert_eq!(myapp.settings.search.keyword_weight, 0.25);
+        assert_eq!(myapp.applied, vec!["project:myapp".to_string()]);
+
+        let session = config
+            .for_namespace(&Namespace::Session {
+                project: "myapp".to_string(),
+                session_id: "s1".to_string(),
+            })
+            .unwrap();
+        assert_eq!(session.settings.search.vector_weight, 0.6);
+
+        for other in [
+            Namespace::Global,
+            Namespace::Project {
+                name: "other".to_string(),
+            },
+        ] {
+            let effective = config.for_namespace(&other).unwrap();
+            assert_eq!(effective.settings.search.vector_weight, default_weight);
+            assert_eq!(effective.settings.search.keyword_weight, 0.25);
+            assert!(effective.applied.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_invalid_namespace_override_rejected() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let project = write_config(
+            temp.path(),
+            "[namespace.\"project:myapp\".search]\nvector_weight = 3.0\n",
+        );
+        let err = LayeredConfig::from_layers(None, Some(&project), Vec::new()).unwrap_err();
+        assert!(err.to_string().contains("project:myapp"), "{}", err);
+    }
+
+    #[test]
+    fn test_find_project_config_searches_ancestors() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let project = write_config(&temp.path().join(".mnemosyne"), "");
+        let nested = temp.path().join("src/deeply/nested");
+        std::fs::create_dir_all(&nested).unwrap();
+
+        assert_eq!(find_project_config(&nested), Some(project));
+    }
 }
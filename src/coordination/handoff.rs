@@ -1,10 +1,21 @@
 //! Handoff coordination between Claude Code and ICS
 //!
 //! File-based protocol for seamless context editing integration.
+//!
+//! Either side may die mid-handoff, so neither waits forever:
+//! - Claude Code waits for a result with [`HandoffCoordinator::wait_for_result`],
+//!   which gives up with [`ExitReason::TimedOut`]
+//! - ICS accepts an intent with [`HandoffCoordinator::accept_intent`], which
+//!   answers a stale intent (too old, or its writer is dead) with a `TimedOut`
+//!   result instead of opening an editor nobody is waiting on
+//!
+//! Files are written to a temp file in the session directory and renamed into
+//! place, so a reader sees either the previous file or the complete new one,
+//! never a partial write.
 
 use anyhow::{Context as AnyhowContext, Result};
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::time::Duration;
 use tokio::time::timeout;
 
@@ -34,6 +45,10 @@ pub struct EditIntent {
 
     /// Context from conversation
     pub context: EditContext,
+
+    /// PID of the process that wrote the intent (set by `write_intent`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub writer_pid: Option<u32>,
 }
 
 /// Context from the conversation
@@ -87,10 +102,45 @@ pub enum ExitReason {
     UserCancelled,
     /// Error occurred
     Error,
-    /// Timeout
-    Timeout,
+    /// The other side never answered, or the intent was stale
+    #[serde(alias = "timeout")]
+    TimedOut,
+}
+
+/// Why an intent should not be acted on
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StaleIntent {
+    /// Written longer ago than the coordinator's threshold
+    Expired { age: Duration },
+    /// The process that wrote it is no longer running
+    WriterDead { pid: u32 },
+}
+
+impl std::fmt::Display for StaleIntent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StaleIntent::Expired { age } => {
+                write!(f, "edit intent is stale ({}s old)", age.as_secs())
+            }
+            StaleIntent::WriterDead { pid } => {
+                write!(f, "edit intent writer (pid {}) is no longer running", pid)
+            }
+        }
+    }
 }
 
+/// Outcome of [`HandoffCoordinator::accept_intent`]
+#[derive(Debug, Clone)]
+pub enum IntentStatus {
+    /// Fresh intent to act on
+    Ready(EditIntent),
+    /// Stale intent, already answered with this `TimedOut` result
+    Stale(EditResult),
+}
+
+/// Default age after which an unanswered intent is considered abandoned
+pub const DEFAULT_INTENT_STALE_AFTER: Duration = Duration::from_secs(60 * 60);
+
 /// Summary of semantic analysis performed in ICS
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SemanticAnalysisSummary {
@@ -114,6 +164,9 @@ pub struct SemanticAnalysisSummary {
 pub struct HandoffCoordinator {
     /// Session directory (.claude/sessions/)
     session_dir: PathBuf,
+
+    /// Intent age after which it is treated as abandoned
+    stale_after: Duration,
 }
 
 impl HandoffCoordinator {
@@ -126,17 +179,30 @@ impl HandoffCoordinator {
             })?;
         }
 
-        Ok(Self { session_dir })
+        Ok(Self {
+            session_dir,
+            stale_after: DEFAULT_INTENT_STALE_AFTER,
+        })
+    }
+
+    /// Set the intent age after which it is treated as abandoned
+    pub fn with_stale_after(mut self, stale_after: Duration) -> Self {
+        self.stale_after = stale_after;
+        self
     }
 
     /// Write edit intent for ICS to read
+    ///
+    /// Records this process as the writer unless the intent names one.
     pub fn write_intent(&self, intent: &EditIntent) -> Result<PathBuf> {
         let intent_path = self.session_dir.join("edit-intent.json");
 
+        let mut intent = intent.clone();
+        intent.writer_pid.get_or_insert_with(std::process::id);
         let json =
-            serde_json::to_string_pretty(intent).context("Failed to serialize edit intent")?;
+            serde_json::to_string_pretty(&intent).context("Failed to serialize edit intent")?;
 
-        std::fs::write(&intent_path, json)
+        write_atomic(&intent_path, &json)
             .with_context(|| format!("Failed to write intent to {:?}", intent_path))?;
 
         Ok(intent_path)
@@ -183,6 +249,56 @@ impl HandoffCoordinator {
         result
     }
 
+    /// Wait for the edit result, giving up with a `TimedOut` result
+    ///
+    /// Unlike [`Self::read_result`], running out of time is not an error:
+    /// the caller gets a result it can report and move on from.
+    pub async fn wait_for_result(&self, max_wait: Duration) -> Result<EditResult> {
+        match self.read_result(max_wait).await {
+            Ok(result) => Ok(result),
+            Err(_) if !self.session_dir.join("edit-result.json").exists() => {
+                let intent = self.read_intent().ok();
+                tracing::warn!("No ICS result after {}s, giving up", max_wait.as_secs_f32());
+                Ok(timed_out_result(
+                    intent.as_ref(),
+                    format!("No result from ICS within {}s", max_wait.as_secs_f32()),
+                ))
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Why an intent should not be acted on, if it shouldn't
+    pub fn intent_staleness(&self, intent: &EditIntent) -> Option<StaleIntent> {
+        let age = (chrono::Utc::now() - intent.timestamp)
+            .to_std()
+            .unwrap_or_default();
+        if age > self.stale_after {
+            return Some(StaleIntent::Expired { age });
+        }
+        match intent.writer_pid {
+            Some(pid) if !crate::orchestration::cross_process::process_exists(pid) => {
+                Some(StaleIntent::WriterDead { pid })
+            }
+            _ => None,
+        }
+    }
+
+    /// Read the intent (called by ICS), answering a stale one with a
+    /// `TimedOut` result instead of handing it over
+    pub fn accept_intent(&self) -> Result<IntentStatus> {
+        let intent = self.read_intent()?;
+        match self.intent_staleness(&intent) {
+            None => Ok(IntentStatus::Ready(intent)),
+            Some(stale) => {
+                tracing::warn!("Ignoring edit intent {}: {}", intent.session_id, stale);
+                let result = timed_out_result(Some(&intent), stale.to_string());
+                self.write_result(&result)?;
+                Ok(IntentStatus::Stale(result))
+            }
+        }
+    }
+
     /// Write edit result (called by ICS)
     pub fn write_result(&self, result: &EditResult) -> Result<PathBuf> {
         let result_path = self.session_dir.join("edit-result.json");
@@ -190,17 +306,20 @@ impl HandoffCoordinator {
         let json =
             serde_json::to_string_pretty(result).context("Failed to serialize edit result")?;
 
-        std::fs::write(&result_path, json)
+        write_atomic(&result_path, &json)
             .with_context(|| format!("Failed to write result to {:?}", result_path))?;
 
         Ok(result_path)
     }
 
     /// Read edit intent (called by ICS)
+    ///
+    /// Retries briefly if the file is missing, covering a writer that is
+    /// between creating the session directory and renaming the intent in.
     pub fn read_intent(&self) -> Result<EditIntent> {
         let intent_path = self.session_dir.join("edit-intent.json");
 
-        let json = std::fs::read_to_string(&intent_path)
+        let json = read_with_retry(&intent_path)
             .with_context(|| format!("Failed to read intent from {:?}", intent_path))?;
 
         serde_json::from_str(&json).context("Failed to parse edit intent JSON")
@@ -223,6 +342,72 @@ impl HandoffCoordinator {
     }
 }
 
+/// Attempts made by [`read_with_retry`] before reporting a missing file
+const MISSING_FILE_RETRIES: u32 = 3;
+
+/// Delay between [`read_with_retry`] attempts
+const MISSING_FILE_RETRY_DELAY: Duration = Duration::from_millis(20);
+
+/// Write `contents` to a temp file next to `path`, then rename it into place
+fn write_atomic(path: &Path, contents: &str) -> std::io::Result<()> {
+    use std::io::Write;
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    static TEMP_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+    let dir = path.parent().unwrap_or_else(|| Path::new("."));
+    let file_name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let temp_path = dir.join(format!(
+        ".{}.{}.{}.tmp",
+        file_name,
+        std::process::id(),
+        TEMP_COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+
+    let written = (|| {
+        let mut file = std::fs::File::create(&temp_path)?;
+        file.write_all(contents.as_bytes())?;
+        file.sync_all()?;
+        std::fs::rename(&temp_path, path)
+    })();
+    if written.is_err() {
+        let _ = std::fs::remove_file(&temp_path);
+    }
+    written
+}
+
+/// Read a file, retrying a few times while it does not exist
+fn read_with_retry(path: &Path) -> std::io::Result<String> {
+    let mut attempt = 1;
+    loop {
+        match std::fs::read_to_string(path) {
+            Err(e)
+                if e.kind() == std::io::ErrorKind::NotFound && attempt < MISSING_FILE_RETRIES =>
+            {
+                attempt += 1;
+                std::thread::sleep(MISSING_FILE_RETRY_DELAY);
+            }
+            other => return other,
+        }
+    }
+}
+
+fn timed_out_result(intent: Option<&EditIntent>, error: String) -> EditResult {
+    EditResult {
+        session_id: intent.map(|i| i.session_id.clone()).unwrap_or_default(),
+        timestamp: chrono::Utc::now(),
+        status: "error".to_string(),
+        file_path: intent.map(|i| i.file_path.clone()).unwrap_or_default(),
+        changes_made: false,
+        exit_reason: ExitReason::TimedOut,
+        analysis: None,
+        error: Some(error),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -246,6 +431,7 @@ mod tests {
                 relevant_memories: vec!["mem_123".to_string()],
                 related_files: vec!["src/api.rs".to_string()],
             },
+            writer_pid: None,
         };
 
         // Write intent
@@ -315,6 +501,7 @@ mod tests {
                 relevant_memories: vec![],
                 related_files: vec![],
             },
+            writer_pid: None,
         };
 
         let result = EditResult {
@@ -417,6 +604,7 @@ mod tests {
                             relevant_memories: vec![],
                             related_files: vec![],
                         },
+                        writer_pid: None,
                     };
 
                     // Each thread writes its own intent
@@ -465,6 +653,7 @@ mod tests {
                 relevant_memories: vec![],
                 related_files: vec![],
             },
+            writer_pid: None,
         };
 
         coordinator.write_intent(&intent).unwrap();
@@ -498,4 +687,143 @@ mod tests {
         let err_msg = result.unwrap_err().to_string();
         assert!(err_msg.contains("Failed to parse"));
     }
+
+    fn intent(session_id: &str) -> EditIntent {
+        EditIntent {
+            session_id: session_id.to_string(),
+            timestamp: chrono::Utc::now(),
+            action: "edit".to_string(),
+            file_path: PathBuf::from("/tmp/test.md"),
+            template: None,
+            readonly: false,
+            panel: None,
+            context: EditContext {
+                conversation_summary: "Test".to_string(),
+                relevant_memories: vec![],
+                related_files: vec![],
+            },
+            writer_pid: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_result_times_out() {
+        let temp_dir = TempDir::new().unwrap();
+        let coordinator = HandoffCoordinator::new(temp_dir.path().to_path_buf()).unwrap();
+        coordinator.write_intent(&intent("never-answered")).unwrap();
+
+        // ICS never writes a result
+        let result = coordinator
+            .wait_for_result(Duration::from_millis(300))
+            .await
+            .unwrap();
+
+        assert!(matches!(result.exit_reason, ExitReason::TimedOut));
+        assert_eq!(result.session_id, "never-answered");
+        assert!(!result.changes_made);
+        assert!(result.error.is_some());
+    }
+
+    #[test]
+    fn test_stale_intent_is_answered_with_timeout() {
+        let temp_dir = TempDir::new().unwrap();
+        let coordinator = HandoffCoordinator::new(temp_dir.path().to_path_buf())
+            .unwrap()
+            .with_stale_after(Duration::from_secs(60));
+
+        // Fresh intent from a live writer (this process)
+        coordinator.write_intent(&intent("fresh")).unwrap();
+        assert_eq!(
+            coordinator.read_intent().unwrap().writer_pid,
+            Some(std::process::id())
+        );
+        assert!(matches!(
+            coordinator.accept_intent().unwrap(),
+            IntentStatus::Ready(_)
+        ));
+
+        // Too old
+        let mut old = intent("old");
+        old.timestamp = chrono::Utc::now() - chrono::Duration::minutes(5);
+        coordinator.write_intent(&old).unwrap();
+        match coordinator.accept_intent().unwrap() {
+            IntentStatus::Stale(result) => {
+                assert!(matches!(result.exit_reason, ExitReason::TimedOut));
+                assert_eq!(result.session_id, "old");
+            }
+            IntentStatus::Ready(_) => panic!("expired intent was accepted"),
+        }
+        // The waiting side sees the answer
+        let answered: EditResult = serde_json::from_str(
+            &std::fs::read_to_string(temp_dir.path().join("edit-result.json")).unwrap(),
+        )
+        .unwrap();
+        assert!(matches!(answered.exit_reason, ExitReason::TimedOut));
+
+        // Writer has exited
+        #[cfg(unix)]
+        {
+            let mut child = std::process::Command::new("true").spawn().unwrap();
+            let dead_pid = child.id();
+            child.wait().unwrap();
+
+            let mut orphaned = intent("orphaned");
+            orphaned.writer_pid = Some(dead_pid);
+            assert_eq!(
+                coordinator.intent_staleness(&orphaned),
+                Some(StaleIntent::WriterDead { pid: dead_pid })
+            );
+        }
+    }
+
+    #[test]
+    fn test_legacy_timeout_exit_reason() {
+        let reason: ExitReason = serde_json::from_str("\"timeout\"").unwrap();
+        assert!(matches!(reason, ExitReason::TimedOut));
+    }
+
+    #[test]
+    fn test_concurrent_write_read_never_tears() {
+        let temp_dir = TempDir::new().unwrap();
+        let session_dir = temp_dir.path().to_path_buf();
+        let coordinator = HandoffCoordinator::new(session_dir.clone()).unwrap();
+        coordinator.write_intent(&intent("seed")).unwrap();
+
+        let done = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let writer = {
+            let session_dir = session_dir.clone();
+            let done = done.clone();
+            std::thread::spawn(move || {
+                let coordinator = HandoffCoordinator::new(session_dir).unwrap();
+                for i in 0..500 {
+                    // Vary the size so a torn read would be likely to cut JSON short
+                    let mut next = intent(&format!("session-{}", i));
+                    next.context.conversation_summary = "x".repeat((i % 50) * 200);
+                    coordinator.write_intent(&next).unwrap();
+                }
+                done.store(true, std::sync::atomic::Ordering::SeqCst);
+            })
+        };
+
+        let mut reads = 0;
+        while !done.load(std::sync::atomic::Ordering::SeqCst) {
+            if let Err(e) = coordinator.read_intent() {
+                panic!(
+                    "reader observed a torn intent after {} reads: {:#}",
+                    reads, e
+                );
+            }
+            reads += 1;
+        }
+        writer.join().unwrap();
+
+        assert_eq!(coordinator.read_intent().unwrap().session_id, "session-499");
+        // No temp files left behind
+        let leftovers: Vec<_> = std::fs::read_dir(&session_dir)
+            .unwrap()
+            .filter_map(|e| e.ok())
+            .filter(|e| e.file_name().to_string_lossy().ends_with(".tmp"))
+            .collect();
+        assert!(leftovers.is_empty());
+    }
 }
@@ -10,5 +10,6 @@
 mod handoff;
 
 pub use handoff::{
-    EditIntent, EditResult, ExitReason, HandoffCoordinator, SemanticAnalysisSummary,
+    EditContext, EditIntent, EditResult, ExitReason, HandoffCoordinator, IntentStatus,
+    SemanticAnalysisSummary, StaleIntent,
 };
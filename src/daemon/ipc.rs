@@ -12,10 +12,12 @@ use tracing::{error, info, warn};
 pub enum IpcMessage {
     /// Request for status
     GetStatus(oneshot::Sender<OrchestrationStatus>),
-    /// Request to create an invite
-    CreateInvite(oneshot::Sender<Result<String>>),
+    /// Request to create an invite, optionally bound to a peer
+    CreateInvite(Option<String>, oneshot::Sender<Result<String>>),
     /// Request to join a peer
     JoinPeer(String, oneshot::Sender<Result<String>>),
+    /// Request to allowlist a peer
+    AllowPeer(String, oneshot::Sender<Result<String>>),
 }
 
 /// IPC Command sent over the wire
@@ -27,10 +29,13 @@ pub enum IpcCommand {
     GetStatus,
     /// Create invite
     #[serde(rename = "invite")]
-    CreateInvite,
+    CreateInvite { peer: Option<String> },
     /// Join peer
     #[serde(rename = "join")]
     JoinPeer { ticket: String },
+    /// Allowlist peer
+    #[serde(rename = "allow")]
+    AllowPeer { node_id: String },
 }
 
 /// Start the IPC server
@@ -107,10 +112,10 @@ pub async fn start_ipc_server(socket_path: PathBuf, tx: mpsc::Sender<IpcMessage>
                                             ),
                                         }
                                     }
-                                    Ok(IpcCommand::CreateInvite) => {
+                                    Ok(IpcCommand::CreateInvite { peer }) => {
                                         let (resp_tx, resp_rx) = oneshot::channel();
                                         if let Err(e) =
-                                            tx.send(IpcMessage::CreateInvite(resp_tx)).await
+                                            tx.send(IpcMessage::CreateInvite(peer, resp_tx)).await
                                         {
                                             error!("Failed to send IPC message to engine: {}", e);
                                             return;
@@ -195,6 +200,50 @@ pub async fn start_ipc_server(socket_path: PathBuf, tx: mpsc::Sender<IpcMessage>
                                             ),
                                         }
                                     }
+                                    Ok(IpcCommand::AllowPeer { node_id }) => {
+                                        let (resp_tx, resp_rx) = oneshot::channel();
+                                        if let Err(e) =
+                                            tx.send(IpcMessage::AllowPeer(node_id, resp_tx)).await
+                                        {
+                                            error!("Failed to send IPC message to engine: {}", e);
+                                            return;
+                                        }
+
+                                        match resp_rx.await {
+                                            Ok(result) => {
+                                                // Convert error to string for serialization
+                                                let response: std::result::Result<String, String> =
+                                                    result.map_err(|e| e.to_string());
+                                                match serde_json::to_string(&response) {
+                                                    Ok(json) => {
+                                                        if let Err(e) =
+                                                            writer.write_all(json.as_bytes()).await
+                                                        {
+                                                            error!(
+                                                                "Failed to write IPC response: {}",
+                                                                e
+                                                            );
+                                                        }
+                                                        if let Err(e) =
+                                                            writer.write_all(b"\n").await
+                                                        {
+                                                            error!(
+                                                                "Failed to write newline: {}",
+                                                                e
+                                                            );
+                                                        }
+                                                    }
+                                                    Err(e) => {
+                                                        error!("Failed to serialize result: {}", e)
+                                                    }
+                                                }
+                                            }
+                                            Err(e) => error!(
+                                                "Failed to receive response from engine: {}",
+                                                e
+                                            ),
+                                        }
+                                    }
                                     Err(e) => {
                                         warn!(
                                             "Invalid IPC command received: {}. Error: {}",
@@ -255,8 +304,8 @@ pub async fn query_status(socket_path: &Path) -> Result<OrchestrationStatus> {
     }
 }
 
-/// Client to create an invite via IPC
-pub async fn create_invite(socket_path: &Path) -> Result<String> {
+/// Client to create an invite via IPC, optionally bound to a peer
+pub async fn create_invite(socket_path: &Path, peer: Option<String>) -> Result<String> {
     use tokio::io::AsyncWriteExt;
     use tokio::net::UnixStream;
 
@@ -264,7 +313,7 @@ pub async fn create_invite(socket_path: &Path) -> Result<String> {
         crate::error::MnemosyneError::Other(format!("Failed to connect to IPC socket: {}", e))
     })?;
 
-    let command = IpcCommand::CreateInvite;
+    let command = IpcCommand::CreateInvite { peer };
     let json = serde_json::to_string(&command).map_err(|e| {
         crate::error::MnemosyneError::Other(format!("Failed to serialize command: {}", e))
     })?;
@@ -332,3 +381,42 @@ pub async fn join_peer(socket_path: &Path, ticket: String) -> Result<String> {
         ))
     }
 }
+
+/// Client to allowlist a peer via IPC
+pub async fn allow_peer(socket_path: &Path, node_id: String) -> Result<String> {
+    use tokio::io::AsyncWriteExt;
+    use tokio::net::UnixStream;
+
+    let mut stream = UnixStream::connect(socket_path).await.map_err(|e| {
+        crate::error::MnemosyneError::Other(format!("Failed to connect to IPC socket: {}", e))
+    })?;
+
+    let command = IpcCommand::AllowPeer { node_id };
+    let json = serde_json::to_string(&command).map_err(|e| {
+        crate::error::MnemosyneError::Other(format!("Failed to serialize command: {}", e))
+    })?;
+
+    stream.write_all(json.as_bytes()).await.map_err(|e| {
+        crate::error::MnemosyneError::Other(format!("Failed to write to IPC socket: {}", e))
+    })?;
+    stream.write_all(b"\n").await.map_err(|e| {
+        crate::error::MnemosyneError::Other(format!("Failed to write newline: {}", e))
+    })?;
+
+    let reader = BufReader::new(stream);
+    let mut lines = reader.lines();
+
+    if let Some(line) = lines.next_line().await.map_err(|e| {
+        crate::error::MnemosyneError::Other(format!("Failed to read from IPC socket: {}", e))
+    })? {
+        let result: std::result::Result<String, String> =
+            serde_json::from_str(&line).map_err(|e| {
+                crate::error::MnemosyneError::Other(format!("Failed to deserialize result: {}", e))
+            })?;
+        result.map_err(crate::error::MnemosyneError::Other)
+    } else {
+        Err(crate::error::MnemosyneError::Other(
+            "IPC socket closed without response".to_string(),
+        ))
+    }
+}
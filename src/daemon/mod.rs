@@ -38,7 +38,7 @@ pub mod orchestration;
 
 use crate::error::{MnemosyneError, Result};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use tracing::{debug, info, warn};
 
 /// Daemon configuration
@@ -296,6 +296,28 @@ impl McpDaemon {
         }
     }
 
+    /// PID file location
+    pub fn pid_file(&self) -> &Path {
+        &self.config.pid_file
+    }
+
+    /// Remove the PID file left by a daemon that is no longer running
+    ///
+    /// Returns the dead process's PID, or `None` if there was nothing stale.
+    /// A PID file naming a live process is never removed.
+    pub fn clean_stale(&self) -> Result<Option<u32>> {
+        let DaemonStatus::Stale { pid } = self.status()? else {
+            return Ok(None);
+        };
+        // Checked again just before removal, in case the PID came back to life
+        if is_process_running(pid) {
+            return Ok(None);
+        }
+        self.remove_pid_file()?;
+        info!("Removed stale PID file for process {}", pid);
+        Ok(Some(pid))
+    }
+
     /// Health check - verify daemon is running and responding
     pub fn health_check(&self) -> Result<bool> {
         match self.status()? {
@@ -31,7 +31,7 @@ use crate::orchestration::supervision::{SupervisionConfig, SupervisionTree};
 use crate::storage::StorageBackend;
 use serde::{Deserialize, Serialize};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use tokio::sync::mpsc;
 use tracing::{debug, info, warn};
@@ -350,6 +350,36 @@ impl OrchestrationDaemon {
         })
     }
 
+    /// IPC socket location
+    pub fn socket_path(&self) -> &Path {
+        &self.config.socket_path
+    }
+
+    /// Remove the PID file and socket left by a daemon that is no longer running
+    ///
+    /// A socket nothing answers on is removed even without a PID file.
+    /// Returns the removed paths; nothing is removed while the PID file
+    /// names a live process.
+    pub async fn clean_stale(&self) -> Result<Vec<PathBuf>> {
+        let mut removed = Vec::new();
+        match self.status().await? {
+            OrchestrationStatus::Stale { pid } if !is_process_running(pid) => {
+                self.remove_pid_file()?;
+                removed.push(self.config.pid_file.clone());
+            }
+            OrchestrationStatus::NotRunning => {}
+            _ => return Ok(removed),
+        }
+        if self.config.socket_path.exists() {
+            self.remove_socket()?;
+            removed.push(self.config.socket_path.clone());
+        }
+        if !removed.is_empty() {
+            info!("Removed stale orchestration daemon files: {:?}", removed);
+        }
+        Ok(removed)
+    }
+
     /// Health check - verify daemon and all agents are running
     pub async fn health_check(&self) -> Result<bool> {
         match self.status().await? {
@@ -481,8 +511,8 @@ impl OrchestrationDaemon {
                                 warn!("Failed to send status reply: {:?}", e);
                             }
                         }
-                        IpcMessage::CreateInvite(reply_tx) => {
-                            let result = network.create_invite().await;
+                        IpcMessage::CreateInvite(peer, reply_tx) => {
+                            let result = network.create_invite(peer.as_deref()).await;
                             if let Err(e) = reply_tx.send(result) {
                                 warn!("Failed to send create invite reply: {:?}", e);
                             }
@@ -493,6 +523,12 @@ impl OrchestrationDaemon {
                                 warn!("Failed to send join peer reply: {:?}", e);
                             }
                         }
+                        IpcMessage::AllowPeer(node_id, reply_tx) => {
+                            let result = network.allow_peer(&node_id).await.map(|_| node_id);
+                            if let Err(e) = reply_tx.send(result) {
+                                warn!("Failed to send allow peer reply: {:?}", e);
+                            }
+                        }
                     }
                 }
             }
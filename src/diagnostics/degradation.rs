@@ -0,0 +1,220 @@
+//! Graceful degradation tracking
+//!
+//! Several subsystems fall back quietly when the LLM or embeddings are
+//! unavailable (keyword-only recall, remember without enrichment). Services
+//! report the outcome of each external call here so there is one place that
+//! says which services are degraded and why. A failure marks the service
+//! degraded; the next successful call clears it.
+//!
+//! State changes (not every call) publish a `DegradationChanged` event when a
+//! broadcaster is attached.
+
+use crate::api::{Event, EventBroadcaster};
+use crate::error::Result;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::{Arc, RwLock};
+use tracing::{info, warn};
+
+/// External service that can degrade
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DegradedService {
+    /// LLM enrichment (Anthropic API)
+    Llm,
+    /// Embedding generation (local model or Voyage AI)
+    Embeddings,
+}
+
+impl DegradedService {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Llm => "llm",
+            Self::Embeddings => "embeddings",
+        }
+    }
+}
+
+impl fmt::Display for DegradedService {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A service currently running in degraded mode
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Degradation {
+    pub service: DegradedService,
+
+    /// Error from the most recent failed call
+    pub reason: String,
+
+    /// When the service first failed (not updated by repeat failures)
+    pub since: DateTime<Utc>,
+}
+
+/// Shared record of degraded services (thread-safe, cheap to clone)
+#[derive(Clone, Default)]
+pub struct DegradationState {
+    degraded: Arc<RwLock<BTreeMap<DegradedService, Degradation>>>,
+    broadcaster: Arc<RwLock<Option<EventBroadcaster>>>,
+}
+
+impl DegradationState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Publish `DegradationChanged` events through `broadcaster`
+    pub fn set_broadcaster(&self, broadcaster: EventBroadcaster) {
+        if let Ok(mut slot) = self.broadcaster.write() {
+            *slot = Some(broadcaster);
+        }
+    }
+
+    /// Mark a service degraded, returning whether this changed the state
+    pub fn mark_degraded(&self, service: DegradedService, reason: impl Into<String>) -> bool {
+        let reason = reason.into();
+        let changed = match self.degraded.write() {
+            Ok(mut degraded) => match degraded.get_mut(&service) {
+                Some(existing) => {
+                    existing.reason = reason.clone();
+                    false
+                }
+                None => {
+                    degraded.insert(
+                        service,
+                        Degradation {
+                            service,
+                            reason: reason.clone(),
+                            since: Utc::now(),
+                        },
+                    );
+                    true
+                }
+            },
+            Err(_) => false,
+        };
+
+        if changed {
+            warn!("{} degraded: {}", service, reason);
+            self.publish(Event::degradation_changed(
+                service.to_string(),
+                true,
+                Some(reason),
+            ));
+        }
+        changed
+    }
+
+    /// Clear a service's degraded flag, returning whether it was set
+    pub fn mark_recovered(&self, service: DegradedService) -> bool {
+        let changed = self
+            .degraded
+            .write()
+            .map(|mut degraded| degraded.remove(&service).is_some())
+            .unwrap_or(false);
+
+        if changed {
+            info!("{} recovered", service);
+            self.publish(Event::degradation_changed(service.to_string(), false, None));
+        }
+        changed
+    }
+
+    /// Record the outcome of a call to `service`
+    pub fn record<T>(&self, service: DegradedService, result: &Result<T>) {
+        match result {
+            Ok(_) => {
+                self.mark_recovered(service);
+            }
+            Err(e) => {
+                self.mark_degraded(service, e.to_string());
+            }
+        }
+    }
+
+    pub fn is_degraded(&self, service: DegradedService) -> bool {
+        self.degraded
+            .read()
+            .map(|degraded| degraded.contains_key(&service))
+            .unwrap_or(false)
+    }
+
+    /// Currently degraded services, ordered by service
+    pub fn snapshot(&self) -> Vec<Degradation> {
+        self.degraded
+            .read()
+            .map(|degraded| degraded.values().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    fn publish(&self, event: Event) {
+        if let Ok(slot) = self.broadcaster.read() {
+            if let Some(broadcaster) = slot.as_ref() {
+                let _ = broadcaster.broadcast(event);
+            }
+        }
+    }
+}
+
+/// Global degradation state instance
+static DEGRADATION: once_cell::sync::Lazy<DegradationState> =
+    once_cell::sync::Lazy::new(DegradationState::new);
+
+/// Get the global degradation state
+pub fn global_degradation() -> &'static DegradationState {
+    &DEGRADATION
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::EventType;
+    use crate::error::MnemosyneError;
+
+    #[test]
+    fn test_failure_then_success_round_trip() {
+        let state = DegradationState::new();
+        let failed: Result<()> = Err(MnemosyneError::NetworkError("refused".to_string()));
+
+        state.record(DegradedService::Embeddings, &failed);
+        assert!(state.is_degraded(DegradedService::Embeddings));
+        assert!(!state.is_degraded(DegradedService::Llm));
+        let snapshot = state.snapshot();
+        assert_eq!(snapshot.len(), 1);
+        assert!(snapshot[0].reason.contains("refused"));
+
+        state.record(DegradedService::Embeddings, &Ok(()));
+        assert!(state.snapshot().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_events_only_on_change() {
+        let state = DegradationState::new();
+        let broadcaster = EventBroadcaster::new(16);
+        let mut rx = broadcaster.subscribe();
+        state.set_broadcaster(broadcaster);
+
+        assert!(state.mark_degraded(DegradedService::Llm, "no key"));
+        assert!(!state.mark_degraded(DegradedService::Llm, "still no key"));
+        assert!(state.mark_recovered(DegradedService::Llm));
+        assert!(!state.mark_recovered(DegradedService::Llm));
+
+        let mut changes = Vec::new();
+        while let Ok(event) = rx.try_recv() {
+            if let EventType::DegradationChanged {
+                service, degraded, ..
+            } = event.event_type
+            {
+                changes.push((service, degraded));
+            }
+        }
+        assert_eq!(
+            changes,
+            vec![("llm".to_string(), true), ("llm".to_string(), false)]
+        );
+    }
+}
@@ -0,0 +1,371 @@
+//! Per-operation latency histograms
+//!
+//! Call sites hold a [`time_operation`] guard for the duration of the work;
+//! dropping it records the elapsed time in the global [`LatencyRecorder`].
+//! Each histogram has fixed log-spaced buckets (four per doubling, from 1µs),
+//! so recording is a handful of relaxed atomic adds and percentiles are
+//! accurate to within one bucket (about 19%). When recording is disabled the
+//! guard does not read the clock.
+//!
+//! Percentiles are shown by `mnemosyne doctor --verbose` and exported for
+//! Prometheus by the API server at `/metrics/prometheus`.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+/// Buckets per doubling of latency
+const BUCKETS_PER_OCTAVE: usize = 4;
+
+/// Doublings covered above 1µs (2^36µs is about 19 hours)
+const OCTAVES: usize = 36;
+
+const BUCKET_COUNT: usize = BUCKETS_PER_OCTAVE * OCTAVES + 1;
+
+/// Timed operation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Operation {
+    KeywordSearch,
+    VectorSearch,
+    GraphTraverse,
+    Embedding,
+    LlmCall,
+}
+
+impl Operation {
+    pub const ALL: [Operation; 5] = [
+        Operation::KeywordSearch,
+        Operation::VectorSearch,
+        Operation::GraphTraverse,
+        Operation::Embedding,
+        Operation::LlmCall,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::KeywordSearch => "keyword_search",
+            Self::VectorSearch => "vector_search",
+            Self::GraphTraverse => "graph_traverse",
+            Self::Embedding => "embedding",
+            Self::LlmCall => "llm_call",
+        }
+    }
+
+    fn index(&self) -> usize {
+        *self as usize
+    }
+}
+
+impl fmt::Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Upper bound of bucket `index`, in microseconds
+fn bucket_upper_us(index: usize) -> f64 {
+    2f64.powf(index as f64 / BUCKETS_PER_OCTAVE as f64)
+}
+
+/// Smallest bucket whose upper bound covers `micros`
+fn bucket_for(micros: u64) -> usize {
+    if micros <= 1 {
+        return 0;
+    }
+    let index = ((micros as f64).log2() * BUCKETS_PER_OCTAVE as f64).ceil() as usize;
+    index.min(BUCKET_COUNT - 1)
+}
+
+/// Latency distribution of one operation
+pub struct LatencyHistogram {
+    buckets: Vec<AtomicU64>,
+    count: AtomicU64,
+    sum_us: AtomicU64,
+    max_us: AtomicU64,
+}
+
+impl Default for LatencyHistogram {
+    fn default() -> Self {
+        Self {
+            buckets: (0..BUCKET_COUNT).map(|_| AtomicU64::new(0)).collect(),
+            count: AtomicU64::new(0),
+            sum_us: AtomicU64::new(0),
+            max_us: AtomicU64::new(0),
+        }
+    }
+}
+
+impl LatencyHistogram {
+    pub fn record(&self, elapsed: Duration) {
+        let micros = elapsed.as_micros().min(u64::MAX as u128) as u64;
+        self.buckets[bucket_for(micros)].fetch_add(1, Ordering::Relaxed);
+        self.count.fetch_add(1, Ordering::Relaxed);
+        self.sum_us.fetch_add(micros, Ordering::Relaxed);
+        self.max_us.fetch_max(micros, Ordering::Relaxed);
+    }
+
+    pub fn count(&self) -> u64 {
+        self.count.load(Ordering::Relaxed)
+    }
+
+    /// Latency below which a `quantile` (0.0-1.0) of samples fall
+    ///
+    /// Reported as the upper bound of the bucket holding that sample, capped
+    /// at the largest recorded value; zero when nothing was recorded.
+    pub fn percentile(&self, quantile: f64) -> Duration {
+        let count = self.count();
+        if count == 0 {
+            return Duration::ZERO;
+        }
+        let rank = ((quantile.clamp(0.0, 1.0) * count as f64).ceil() as u64).max(1);
+        let max_us = self.max_us.load(Ordering::Relaxed);
+
+        let mut seen = 0;
+        for (index, bucket) in self.buckets.iter().enumerate() {
+            seen += bucket.load(Ordering::Relaxed);
+            if seen >= rank {
+                let upper = bucket_upper_us(index).min(max_us as f64);
+                return Duration::from_nanos((upper * 1000.0) as u64);
+            }
+        }
+        Duration::from_micros(max_us)
+    }
+
+    /// Count, mean, percentiles, and maximum
+    pub fn summary(&self, operation: Operation) -> LatencySummary {
+        let count = self.count();
+        let sum_us = self.sum_us.load(Ordering::Relaxed);
+        let ms = |d: Duration| d.as_secs_f64() * 1000.0;
+        LatencySummary {
+            operation,
+            count,
+            mean_ms: if count == 0 {
+                0.0
+            } else {
+                sum_us as f64 / count as f64 / 1000.0
+            },
+            p50_ms: ms(self.percentile(0.5)),
+            p90_ms: ms(self.percentile(0.9)),
+            p99_ms: ms(self.percentile(0.99)),
+            max_ms: self.max_us.load(Ordering::Relaxed) as f64 / 1000.0,
+            sum_ms: sum_us as f64 / 1000.0,
+        }
+    }
+
+    fn reset(&self) {
+        for bucket in &self.buckets {
+            bucket.store(0, Ordering::Relaxed);
+        }
+        self.count.store(0, Ordering::Relaxed);
+        self.sum_us.store(0, Ordering::Relaxed);
+        self.max_us.store(0, Ordering::Relaxed);
+    }
+}
+
+/// Latency percentiles of one operation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LatencySummary {
+    pub operation: Operation,
+    pub count: u64,
+    pub mean_ms: f64,
+    pub p50_ms: f64,
+    pub p90_ms: f64,
+    pub p99_ms: f64,
+    pub max_ms: f64,
+    pub sum_ms: f64,
+}
+
+impl fmt::Display for LatencySummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: n={} p50={:.1}ms p90={:.1}ms p99={:.1}ms max={:.1}ms",
+            self.operation, self.count, self.p50_ms, self.p90_ms, self.p99_ms, self.max_ms
+        )
+    }
+}
+
+/// Latency histograms for every [`Operation`]
+pub struct LatencyRecorder {
+    enabled: AtomicBool,
+    histograms: Vec<LatencyHistogram>,
+}
+
+impl LatencyRecorder {
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            enabled: AtomicBool::new(enabled),
+            histograms: Operation::ALL
+                .iter()
+                .map(|_| LatencyHistogram::default())
+                .collect(),
+        }
+    }
+
+    pub fn set_enabled(&self, enabled: bool) {
+        self.enabled.store(enabled, Ordering::Relaxed);
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::Relaxed)
+    }
+
+    /// Guard that records the time until it is dropped
+    pub fn start(&self, operation: Operation) -> OperationTimer<'_> {
+        OperationTimer {
+            recorder: self,
+            operation,
+            started: self.is_enabled().then(Instant::now),
+        }
+    }
+
+    pub fn record(&self, operation: Operation, elapsed: Duration) {
+        if self.is_enabled() {
+            self.histograms[operation.index()].record(elapsed);
+        }
+    }
+
+    pub fn histogram(&self, operation: Operation) -> &LatencyHistogram {
+        &self.histograms[operation.index()]
+    }
+
+    /// Summaries of the operations recorded at least once
+    pub fn summaries(&self) -> Vec<LatencySummary> {
+        Operation::ALL
+            .iter()
+            .map(|op| self.histogram(*op).summary(*op))
+            .filter(|summary| summary.count > 0)
+            .collect()
+    }
+
+    pub fn reset(&self) {
+        for histogram in &self.histograms {
+            histogram.reset();
+        }
+    }
+
+    /// Prometheus text exposition: one summary metric labelled by operation
+    pub fn export_prometheus(&self) -> String {
+        let mut output = String::new();
+        output.push_str(
+            "# HELP mnemosyne_operation_latency_seconds Latency of storage, embedding and LLM operations\n",
+        );
+        output.push_str("# TYPE mnemosyne_operation_latency_seconds summary\n");
+
+        for summary in self.summaries() {
+            let op = summary.operation.as_str();
+            for (quantile, ms) in [
+                ("0.5", summary.p50_ms),
+                ("0.9", summary.p90_ms),
+                ("0.99", summary.p99_ms),
+            ] {
+                output.push_str(&format!(
+                    "mnemosyne_operation_latency_seconds{{operation=\"{}\",quantile=\"{}\"}} {}\n",
+                    op,
+                    quantile,
+                    ms / 1000.0
+                ));
+            }
+            output.push_str(&format!(
+                "mnemosyne_operation_latency_seconds_sum{{operation=\"{}\"}} {}\n",
+                op,
+                summary.sum_ms / 1000.0
+            ));
+            output.push_str(&format!(
+                "mnemosyne_operation_latency_seconds_count{{operation=\"{}\"}} {}\n",
+                op, summary.count
+            ));
+        }
+
+        output
+    }
+}
+
+/// Records the time from creation to drop; see [`time_operation`]
+#[must_use = "the operation is timed until the guard is dropped"]
+pub struct OperationTimer<'a> {
+    recorder: &'a LatencyRecorder,
+    operation: Operation,
+    started: Option<Instant>,
+}
+
+impl Drop for OperationTimer<'_> {
+    fn drop(&mut self) {
+        if let Some(started) = self.started {
+            self.recorder.record(self.operation, started.elapsed());
+        }
+    }
+}
+
+/// Global latency recorder, enabled unless `MNEMOSYNE_LATENCY_TRACKING=0`
+static LATENCY: once_cell::sync::Lazy<LatencyRecorder> = once_cell::sync::Lazy::new(|| {
+    LatencyRecorder::new(std::env::var("MNEMOSYNE_LATENCY_TRACKING").as_deref() != Ok("0"))
+});
+
+/// Get the global latency recorder
+pub fn global_latency() -> &'static LatencyRecorder {
+    &LATENCY
+}
+
+/// Time `operation` in the global recorder until the guard is dropped
+pub fn time_operation(operation: Operation) -> OperationTimer<'static> {
+    LATENCY.start(operation)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn within(actual: f64, expected: f64, tolerance: f64) -> bool {
+        (actual - expected).abs() <= expected * tolerance
+    }
+
+    #[test]
+    fn test_percentiles_of_recorded_durations() {
+        let recorder = LatencyRecorder::new(true);
+        for ms in 1..=100 {
+            recorder.record(Operation::KeywordSearch, Duration::from_millis(ms));
+        }
+
+        let summary = recorder
+            .histogram(Operation::KeywordSearch)
+            .summary(Operation::KeywordSearch);
+        assert_eq!(summary.count, 100);
+        assert!(within(summary.p50_ms, 50.0, 0.2), "p50 {}", summary.p50_ms);
+        assert!(within(summary.p99_ms, 99.0, 0.2), "p99 {}", summary.p99_ms);
+        assert!(summary.p50_ms <= summary.p90_ms && summary.p90_ms <= summary.p99_ms);
+        assert!(summary.p99_ms <= summary.max_ms);
+        assert!((summary.mean_ms - 50.5).abs() < 1e-9);
+
+        // Other operations are untouched
+        assert_eq!(recorder.summaries().len(), 1);
+    }
+
+    #[test]
+    fn test_disabled_recorder_ignores_timers() {
+        let recorder = LatencyRecorder::new(false);
+        drop(recorder.start(Operation::Embedding));
+        recorder.record(Operation::Embedding, Duration::from_millis(5));
+        assert_eq!(recorder.histogram(Operation::Embedding).count(), 0);
+
+        recorder.set_enabled(true);
+        drop(recorder.start(Operation::Embedding));
+        assert_eq!(recorder.histogram(Operation::Embedding).count(), 1);
+    }
+
+    #[test]
+    fn test_prometheus_export() {
+        let recorder = LatencyRecorder::new(true);
+        recorder.record(Operation::LlmCall, Duration::from_millis(1200));
+
+        let text = recorder.export_prometheus();
+        assert!(text.contains("# TYPE mnemosyne_operation_latency_seconds summary"));
+        assert!(
+            text.contains("mnemosyne_operation_latency_seconds_count{operation=\"llm_call\"} 1")
+        );
+        assert!(text.contains("operation=\"llm_call\",quantile=\"0.99\"} 1.2"));
+        assert!(!text.contains("keyword_search"));
+    }
+}
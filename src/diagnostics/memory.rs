@@ -2,11 +2,55 @@
 //!
 //! This module provides memory tracking and profiling capabilities to diagnose
 //! OOM issues (exit code 143). It instruments hot paths and tracks allocations.
+//!
+//! Recorded snapshots are retained in a rolling window; a linear regression
+//! over process RSS flags sustained growth as a suspected leak.
 
+use crate::api::{Event, EventBroadcaster};
+use std::collections::VecDeque;
 use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use tracing::{debug, info, warn};
 
+/// Snapshots retained for leak detection (1 hour at the default 30s interval)
+const SNAPSHOT_HISTORY_LEN: usize = 120;
+
+/// Minimum snapshots before a growth trend is trusted
+const MIN_LEAK_SAMPLES: usize = 3;
+
+/// Thresholds for RSS leak detection
+#[derive(Debug, Clone, Copy)]
+pub struct LeakThresholds {
+    /// Growth rate (bytes/second) at or above which growth counts as a leak
+    pub min_growth_bytes_per_sec: f64,
+
+    /// How long RSS must grow monotonically before alerting
+    pub min_duration: Duration,
+}
+
+impl Default for LeakThresholds {
+    fn default() -> Self {
+        Self {
+            min_growth_bytes_per_sec: 1_048_576.0 / 60.0, // 1 MB/min
+            min_duration: Duration::from_secs(10 * 60),
+        }
+    }
+}
+
+/// Evidence for a suspected leak
+#[derive(Debug, Clone, Copy)]
+pub struct LeakReport {
+    /// Fitted RSS growth rate over the growing run (bytes/second)
+    pub growth_bytes_per_sec: f64,
+
+    /// Length of the monotonically growing run
+    pub duration: Duration,
+
+    /// Latest RSS
+    pub rss_bytes: u64,
+}
+
 /// Memory statistics tracker (thread-safe)
 #[derive(Clone)]
 pub struct MemoryTracker {
@@ -36,6 +80,9 @@ pub struct MemoryTracker {
 
     /// Active spawned tasks
     pub spawned_tasks: Arc<AtomicUsize>,
+
+    /// Rolling window of recorded snapshots (oldest first)
+    history: Arc<Mutex<VecDeque<MemorySnapshot>>>,
 }
 
 impl Default for MemoryTracker {
@@ -56,6 +103,7 @@ impl MemoryTracker {
             work_queue_size: Arc::new(AtomicUsize::new(0)),
             db_connections: Arc::new(AtomicUsize::new(0)),
             spawned_tasks: Arc::new(AtomicUsize::new(0)),
+            history: Arc::new(Mutex::new(VecDeque::with_capacity(SNAPSHOT_HISTORY_LEN))),
         }
     }
 
@@ -133,6 +181,83 @@ impl MemoryTracker {
             work_queue_size: self.work_queue_size.load(Ordering::Relaxed),
             db_connections: self.db_connections.load(Ordering::Relaxed),
             spawned_tasks: self.spawned_tasks.load(Ordering::Relaxed),
+            rss_bytes: current_rss_bytes().unwrap_or(0),
+            taken_at: Instant::now(),
+        }
+    }
+
+    /// Take a snapshot and add it to the leak-detection window
+    pub fn record_snapshot(&self) -> MemorySnapshot {
+        let snapshot = self.snapshot();
+        self.push_snapshot(snapshot.clone());
+        snapshot
+    }
+
+    /// Add a snapshot to the window, evicting the oldest when full
+    pub fn push_snapshot(&self, snapshot: MemorySnapshot) {
+        if let Ok(mut history) = self.history.lock() {
+            if history.len() == SNAPSHOT_HISTORY_LEN {
+                history.pop_front();
+            }
+            history.push_back(snapshot);
+        }
+    }
+
+    /// Retained snapshots, oldest first
+    pub fn history(&self) -> Vec<MemorySnapshot> {
+        self.history
+            .lock()
+            .map(|h| h.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// RSS growth rate (bytes/second) fitted over the whole window
+    pub fn growth_rate(&self) -> Option<f64> {
+        growth_rate(&self.history())
+    }
+
+    /// Check whether RSS has grown monotonically past `thresholds`
+    ///
+    /// Only the trailing run of non-decreasing RSS samples is considered, so
+    /// any drop (e.g. a cache eviction) resets the clock.
+    pub fn detect_leak(&self, thresholds: &LeakThresholds) -> Option<LeakReport> {
+        let history = self.history();
+        let run_start = history
+            .windows(2)
+            .rposition(|pair| pair[1].rss_bytes < pair[0].rss_bytes)
+            .map_or(0, |i| i + 1);
+        let run = &history[run_start..];
+
+        if run.len() < MIN_LEAK_SAMPLES {
+            return None;
+        }
+
+        let first = run.first()?;
+        let last = run.last()?;
+        let duration = last.taken_at.saturating_duration_since(first.taken_at);
+        let growth_bytes_per_sec = growth_rate(run)?;
+
+        if duration >= thresholds.min_duration
+            && growth_bytes_per_sec >= thresholds.min_growth_bytes_per_sec
+        {
+            Some(LeakReport {
+                growth_bytes_per_sec,
+                duration,
+                rss_bytes: last.rss_bytes,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Usage status, reporting `Leaking` when a leak is detected (unless
+    /// usage is already critical)
+    pub fn assess(&self, thresholds: &LeakThresholds) -> MemoryStatus {
+        let status = self.check_thresholds();
+        if status != MemoryStatus::Critical && self.detect_leak(thresholds).is_some() {
+            MemoryStatus::Leaking
+        } else {
+            status
         }
     }
 
@@ -212,6 +337,10 @@ pub struct MemorySnapshot {
     pub work_queue_size: usize,
     pub db_connections: usize,
     pub spawned_tasks: usize,
+    /// Process resident set size (0 if unavailable)
+    pub rss_bytes: u64,
+    /// When the snapshot was taken
+    pub taken_at: Instant,
 }
 
 impl MemorySnapshot {
@@ -227,7 +356,8 @@ impl MemorySnapshot {
              - Event Queue: {} items\n\
              - Work Queue: {} items\n\
              - DB Connections: {}\n\
-             - Spawned Tasks: {}",
+             - Spawned Tasks: {}\n\
+             - Process RSS: {:.2} MB",
             self.current_usage as f64 / 1_048_576.0,
             self.peak_usage as f64 / 1_048_576.0,
             self.total_allocated as f64 / 1_048_576.0,
@@ -237,10 +367,78 @@ impl MemorySnapshot {
             self.work_queue_size,
             self.db_connections,
             self.spawned_tasks,
+            self.rss_bytes as f64 / 1_048_576.0,
         )
     }
 }
 
+/// Least-squares slope of RSS over time (bytes/second)
+fn growth_rate(snapshots: &[MemorySnapshot]) -> Option<f64> {
+    let first = snapshots.first()?;
+    if snapshots.len() < 2 {
+        return None;
+    }
+
+    let points: Vec<(f64, f64)> = snapshots
+        .iter()
+        .map(|s| {
+            (
+                s.taken_at
+                    .saturating_duration_since(first.taken_at)
+                    .as_secs_f64(),
+                s.rss_bytes as f64,
+            )
+        })
+        .collect();
+
+    let n = points.len() as f64;
+    let mean_x = points.iter().map(|(x, _)| x).sum::<f64>() / n;
+    let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / n;
+
+    let covariance: f64 = points
+        .iter()
+        .map(|(x, y)| (x - mean_x) * (y - mean_y))
+        .sum();
+    let variance: f64 = points.iter().map(|(x, _)| (x - mean_x).powi(2)).sum();
+
+    if variance == 0.0 {
+        None
+    } else {
+        Some(covariance / variance)
+    }
+}
+
+/// Current process RSS in bytes
+fn current_rss_bytes() -> Option<u64> {
+    #[cfg(target_os = "linux")]
+    {
+        let status = std::fs::read_to_string("/proc/self/status").ok()?;
+        let kb = status
+            .lines()
+            .find_map(|line| line.strip_prefix("VmRSS:"))?
+            .trim()
+            .trim_end_matches("kB")
+            .trim()
+            .parse::<u64>()
+            .ok()?;
+        Some(kb * 1024)
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    {
+        // ps reports RSS in KB
+        let output = std::process::Command::new("ps")
+            .args(["-p", &std::process::id().to_string(), "-o", "rss="])
+            .output()
+            .ok()?;
+        let kb = String::from_utf8_lossy(&output.stdout)
+            .trim()
+            .parse::<u64>()
+            .ok()?;
+        Some(kb * 1024)
+    }
+}
+
 /// Memory usage status
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum MemoryStatus {
@@ -248,6 +446,7 @@ pub enum MemoryStatus {
     Moderate, // 40-60%
     High,     // 60-80%
     Critical, // > 80%
+    Leaking,  // Sustained monotonic RSS growth
 }
 
 /// Global memory tracker instance
@@ -260,17 +459,48 @@ pub fn global_memory_tracker() -> &'static MemoryTracker {
 }
 
 /// Start periodic memory monitoring (logs every 30 seconds)
-pub fn start_memory_monitoring() -> tokio::task::JoinHandle<()> {
+///
+/// Each tick records a snapshot and [`MemoryTracker::assess`]es it. When the
+/// status turns `Leaking` a warning is logged and, if a broadcaster is given,
+/// a `MemoryLeakSuspected` event is published (once per growing run).
+pub fn start_memory_monitoring(
+    leak_thresholds: LeakThresholds,
+    broadcaster: Option<EventBroadcaster>,
+) -> tokio::task::JoinHandle<()> {
     let tracker = global_memory_tracker().clone();
     tokio::spawn(async move {
         let mut interval = tokio::time::interval(tokio::time::Duration::from_secs(30));
+        let mut leak_reported = false;
         loop {
             interval.tick().await;
+            tracker.record_snapshot();
             tracker.log_statistics();
-            let status = tracker.check_thresholds();
-
-            if status == MemoryStatus::Critical {
-                warn!("CRITICAL: Memory usage is dangerously high, consider graceful shutdown");
+            match tracker.assess(&leak_thresholds) {
+                MemoryStatus::Critical => {
+                    warn!("CRITICAL: Memory usage is dangerously high, consider graceful shutdown");
+                }
+                MemoryStatus::Leaking if !leak_reported => {
+                    let Some(report) = tracker.detect_leak(&leak_thresholds) else {
+                        continue;
+                    };
+                    leak_reported = true;
+                    warn!(
+                        rss_mb = report.rss_bytes / 1_048_576,
+                        growth_mb_per_min =
+                            format!("{:.2}", report.growth_bytes_per_sec * 60.0 / 1_048_576.0),
+                        duration_secs = report.duration.as_secs(),
+                        "Possible memory leak: RSS growing steadily"
+                    );
+                    if let Some(broadcaster) = &broadcaster {
+                        let _ = broadcaster.broadcast(Event::memory_leak_suspected(
+                            report.rss_bytes,
+                            report.growth_bytes_per_sec,
+                            report.duration.as_secs(),
+                        ));
+                    }
+                }
+                MemoryStatus::Leaking => {}
+                _ => leak_reported = false,
             }
         }
     })
@@ -318,4 +548,69 @@ mod tests {
         assert_eq!(snapshot.work_queue_size, 5);
         assert_eq!(snapshot.db_connections, 1);
     }
+
+    /// Synthetic snapshot `secs` after `base` with the given RSS
+    fn synthetic(base: Instant, secs: u64, rss_mb: u64) -> MemorySnapshot {
+        let mut snapshot = MemoryTracker::new().snapshot();
+        snapshot.taken_at = base + Duration::from_secs(secs);
+        snapshot.rss_bytes = rss_mb * 1_048_576;
+        snapshot
+    }
+
+    #[test]
+    fn test_detects_monotonic_growth() {
+        let tracker = MemoryTracker::new();
+        let base = Instant::now();
+
+        // +1 MB every 30s (2 MB/min) for 10 minutes
+        for i in 0..=20 {
+            tracker.push_snapshot(synthetic(base, i * 30, 100 + i));
+        }
+
+        let rate = tracker.growth_rate().unwrap();
+        assert!((rate - 1_048_576.0 / 30.0).abs() < 1.0);
+
+        let report = tracker.detect_leak(&LeakThresholds::default()).unwrap();
+        assert_eq!(report.duration, Duration::from_secs(600));
+        assert_eq!(report.rss_bytes, 120 * 1_048_576);
+
+        let strict_rate = LeakThresholds {
+            min_growth_bytes_per_sec: 1_048_576.0, // 1 MB/s
+            ..LeakThresholds::default()
+        };
+        assert!(tracker.detect_leak(&strict_rate).is_none());
+    }
+
+    #[test]
+    fn test_growth_must_be_sustained() {
+        let tracker = MemoryTracker::new();
+        let base = Instant::now();
+
+        // Too short: 5 minutes of growth
+        for i in 0..=10 {
+            tracker.push_snapshot(synthetic(base, i * 30, 100 + i));
+        }
+        assert!(tracker.detect_leak(&LeakThresholds::default()).is_none());
+
+        // A drop resets the growing run even though the trend is upward
+        let tracker = MemoryTracker::new();
+        for i in 0..=20 {
+            let dip = if i == 15 { 5 } else { 0 };
+            tracker.push_snapshot(synthetic(base, i * 30, 100 + i - dip));
+        }
+        assert!(tracker.growth_rate().unwrap() > 0.0);
+        assert!(tracker.detect_leak(&LeakThresholds::default()).is_none());
+    }
+
+    #[test]
+    fn test_history_window_is_bounded() {
+        let tracker = MemoryTracker::new();
+        let base = Instant::now();
+        for i in 0..(SNAPSHOT_HISTORY_LEN as u64 + 10) {
+            tracker.push_snapshot(synthetic(base, i, 100));
+        }
+        let history = tracker.history();
+        assert_eq!(history.len(), SNAPSHOT_HISTORY_LEN);
+        assert_eq!(history[0].taken_at, base + Duration::from_secs(10));
+    }
 }
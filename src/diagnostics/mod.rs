@@ -3,10 +3,19 @@
 //! This module provides tools for diagnosing stability issues:
 //! - Memory profiling and tracking
 //! - Resource leak detection
-//! - Performance monitoring
+//! - Performance monitoring (per-operation latency histograms)
+//! - Degraded-service tracking
 
+pub mod degradation;
+pub mod latency;
 pub mod memory;
 
+pub use degradation::{global_degradation, Degradation, DegradationState, DegradedService};
+pub use latency::{
+    global_latency, time_operation, LatencyHistogram, LatencyRecorder, LatencySummary, Operation,
+    OperationTimer,
+};
 pub use memory::{
-    global_memory_tracker, start_memory_monitoring, MemorySnapshot, MemoryStatus, MemoryTracker,
+    global_memory_tracker, start_memory_monitoring, LeakReport, LeakThresholds, MemorySnapshot,
+    MemoryStatus, MemoryTracker,
 };
@@ -0,0 +1,189 @@
+//! Batch embedding of stored memories
+//!
+//! Backs `mnemosyne embed`. Memories that already carry an embedding from the
+//! current model are skipped unless `force` is set, so re-running an
+//! interrupted backfill picks up the memories it had not reached. A forced run
+//! re-embeds everything; it reports the last ID of each batch so the caller can
+//! checkpoint it and pass it back as `resume_after`.
+//!
+//! With `concurrency` above 1, several batches are embedded at once. Each
+//! request still goes through the service, so its rate limit handling
+//! (retry with backoff on 429) applies to every one of them.
+
+use crate::embeddings::EmbeddingService;
+use crate::error::Result;
+use crate::storage::libsql::LibsqlStorage;
+use crate::types::{MemoryId, MemoryNote, Namespace};
+use futures::stream::{self, StreamExt};
+use std::collections::BTreeMap;
+use tracing::warn;
+
+/// What to embed
+#[derive(Debug, Clone)]
+pub struct BackfillOptions {
+    /// Only memories in this namespace (all namespaces if `None`)
+    pub namespace: Option<Namespace>,
+
+    /// Re-embed memories that already have an embedding from the current model
+    pub force: bool,
+
+    /// Memories fetched and embedded per batch
+    pub batch_size: usize,
+
+    /// Continue after this memory ID (from a checkpoint)
+    pub resume_after: Option<MemoryId>,
+
+    /// Batches embedded at the same time
+    pub concurrency: usize,
+}
+
+impl Default for BackfillOptions {
+    fn default() -> Self {
+        Self {
+            namespace: None,
+            force: false,
+            batch_size: 32,
+            resume_after: None,
+            concurrency: 1,
+        }
+    }
+}
+
+/// Counts for a backfill, reported after each batch and on completion
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BackfillProgress {
+    /// Active memories in scope
+    pub total: usize,
+    /// Already embedded with the current model, or before the resume point
+    pub skipped: usize,
+    pub embedded: usize,
+    pub failed: usize,
+    /// Last memory of the latest batch finished along with every batch
+    /// before it; the resume point for a checkpoint
+    pub last_id: Option<MemoryId>,
+}
+
+/// One embedded batch, numbered in fetch order
+struct BatchOutcome {
+    index: usize,
+    embedded: usize,
+    failed: usize,
+    last_id: Option<MemoryId>,
+}
+
+impl BackfillProgress {
+    /// Memories skipped, embedded or failed so far
+    pub fn processed(&self) -> usize {
+        self.skipped + self.embedded + self.failed
+    }
+}
+
+/// Embed the memories selected by `options` with `service`
+///
+/// `on_batch` runs after every batch. A memory whose embedding fails is
+/// counted and logged, and the backfill moves on. Batches are fetched in ID
+/// order; up to `options.concurrency` of them are embedded at once.
+pub async fn backfill_embeddings<F>(
+    storage: &LibsqlStorage,
+    service: &dyn EmbeddingService,
+    options: &BackfillOptions,
+    mut on_batch: F,
+) -> Result<BackfillProgress>
+where
+    F: FnMut(&BackfillProgress),
+{
+    let model = service.model_name();
+    let namespace = options.namespace.as_ref();
+    let force = options.force;
+    let batch_size = options.batch_size.max(1);
+
+    let total = storage
+        .count_memories_needing_embedding(model, namespace, true, None)
+        .await?;
+    let pending = storage
+        .count_memories_needing_embedding(model, namespace, force, options.resume_after.as_ref())
+        .await?;
+    let mut progress = BackfillProgress {
+        total,
+        skipped: total.saturating_sub(pending),
+        ..BackfillProgress::default()
+    };
+
+    // `None` once the last (short or empty) page has been fetched
+    let batches = stream::unfold(Some(options.resume_after), move |after| async move {
+        let after = after?;
+        // Paging by ID keeps failed memories from being fetched again
+        match storage
+            .memories_needing_embedding(model, namespace, force, after.as_ref(), batch_size)
+            .await
+        {
+            Ok(batch) if batch.is_empty() => None,
+            Ok(batch) => {
+                let next = (batch.len() == batch_size).then(|| batch.last().map(|m| m.id));
+                Some((Ok(batch), next))
+            }
+            Err(e) => Some((Err(e), None)),
+        }
+    });
+    let mut outcomes = batches
+        .enumerate()
+        .map(move |(index, fetched)| async move {
+            match fetched {
+                Ok(batch) => Ok(embed_batch(storage, service, index, &batch).await),
+                Err(e) => Err(e),
+            }
+        })
+        .buffer_unordered(options.concurrency.max(1));
+
+    // Batches finish out of order; the resume point only advances past a
+    // batch once every earlier one is done
+    let mut finished = BTreeMap::new();
+    let mut next_index = 0;
+    while let Some(outcome) = outcomes.next().await {
+        let outcome = outcome?;
+        progress.embedded += outcome.embedded;
+        progress.failed += outcome.failed;
+        finished.insert(outcome.index, outcome.last_id);
+        while let Some(last_id) = finished.remove(&next_index) {
+            progress.last_id = last_id;
+            next_index += 1;
+        }
+        on_batch(&progress);
+    }
+
+    Ok(progress)
+}
+
+/// Embed and store each memory in `batch`, counting failures
+async fn embed_batch(
+    storage: &LibsqlStorage,
+    service: &dyn EmbeddingService,
+    index: usize,
+    batch: &[MemoryNote],
+) -> BatchOutcome {
+    let model = service.model_name();
+    let mut outcome = BatchOutcome {
+        index,
+        embedded: 0,
+        failed: 0,
+        last_id: batch.last().map(|memory| memory.id),
+    };
+    for memory in batch {
+        let stored = match service.embed(&memory.content).await {
+            Ok(embedding) => {
+                storage
+                    .store_memory_embedding(&memory.id, &embedding, model)
+                    .await
+            }
+            Err(e) => Err(e),
+        };
+        match stored {
+            Ok(()) => outcome.embedded += 1,
+            Err(e) => {
+                warn!("Failed to embed memory {}: {}", memory.id, e);
+                outcome.failed += 1;
+            }
+        }
+    }
+    outcome
+}
@@ -0,0 +1,132 @@
+//! Deterministic embeddings for tests
+//!
+//! Exercises vector search without a network call or model download: each
+//! text maps to a stable pseudo-random unit vector derived from its SHA-256
+//! digest, so the same text always embeds identically, on every platform and
+//! toolchain.
+
+use super::EmbeddingService;
+use crate::error::Result;
+use async_trait::async_trait;
+use sha2::{Digest, Sha256};
+
+/// Dimensions of the `memories.embedding` column
+pub const DETERMINISTIC_EMBEDDING_DIM: usize = 384;
+
+/// Embedding service that hashes content into a stable unit vector
+///
+/// Vectors carry no semantics: different texts are effectively unrelated,
+/// and only identical texts are similar.
+#[derive(Debug, Clone)]
+pub struct DeterministicEmbeddingService {
+    dimensions: usize,
+}
+
+impl DeterministicEmbeddingService {
+    /// Create a service producing vectors of `dimensions` components
+    pub fn new(dimensions: usize) -> Self {
+        Self { dimensions }
+    }
+
+    /// Embed synchronously
+    pub fn embed_sync(&self, text: &str) -> Vec<f32> {
+        let seed = Sha256::digest(text.as_bytes());
+
+        // Expand the digest in counter mode: block i = SHA-256(seed || i)
+        let mut embedding = Vec::with_capacity(self.dimensions);
+        let mut block_index: u64 = 0;
+        while embedding.len() < self.dimensions {
+            let block = Sha256::new()
+                .chain_update(seed)
+                .chain_update(block_index.to_le_bytes())
+                .finalize();
+            for chunk in block.chunks_exact(4) {
+                if embedding.len() == self.dimensions {
+                    break;
+                }
+                let bits = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+                // Map to [-1, 1]
+                embedding.push((bits as f64 / u32::MAX as f64 * 2.0 - 1.0) as f32);
+            }
+            block_index += 1;
+        }
+
+        let magnitude: f32 = embedding.iter().map(|x| x * x).sum::<f32>().sqrt();
+        if magnitude > 0.0 {
+            for value in &mut embedding {
+                *value /= magnitude;
+            }
+        }
+        embedding
+    }
+}
+
+impl Default for DeterministicEmbeddingService {
+    fn default() -> Self {
+        Self::new(DETERMINISTIC_EMBEDDING_DIM)
+    }
+}
+
+#[async_trait]
+impl EmbeddingService for DeterministicEmbeddingService {
+    async fn embed(&self, text: &str) -> Result<Vec<f32>> {
+        Ok(self.embed_sync(text))
+    }
+
+    async fn embed_batch(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>> {
+        Ok(texts.iter().map(|text| self.embed_sync(text)).collect())
+    }
+
+    fn dimensions(&self) -> usize {
+        self.dimensions
+    }
+
+    fn model_name(&self) -> &str {
+        "deterministic-test-embedding"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::embeddings::cosine_similarity;
+
+    #[tokio::test]
+    async fn test_same_content_same_vector() {
+        let service = DeterministicEmbeddingService::default();
+        let a = service.embed("Use libSQL for storage").await.unwrap();
+        let b = DeterministicEmbeddingService::default()
+            .embed("Use libSQL for storage")
+            .await
+            .unwrap();
+
+        assert_eq!(a, b);
+        assert_eq!(a.len(), DETERMINISTIC_EMBEDDING_DIM);
+        let magnitude: f32 = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+        assert!((magnitude - 1.0).abs() < 1e-4, "magnitude={}", magnitude);
+    }
+
+    #[tokio::test]
+    async fn test_different_content_differs() {
+        let service = DeterministicEmbeddingService::new(64);
+        let a = service.embed("Use libSQL for storage").await.unwrap();
+        let b = service.embed("Use libSQL for storage.").await.unwrap();
+
+        assert_ne!(a, b);
+        assert!(cosine_similarity(&a, &b) < 0.9);
+        assert_eq!(
+            service.embed_batch(&["x", "y"]).await.unwrap(),
+            vec![service.embed_sync("x"), service.embed_sync("y")]
+        );
+    }
+
+    #[test]
+    fn test_longer_vectors_extend_prefix() {
+        let v = DeterministicEmbeddingService::new(4).embed_sync("mnemosyne");
+        let again = DeterministicEmbeddingService::new(8).embed_sync("mnemosyne");
+        let prefix_norm: f32 = again[..4].iter().map(|x| x * x).sum::<f32>().sqrt();
+        for (x, y) in v.iter().zip(&again[..4]) {
+            assert!((x - y / prefix_norm).abs() < 1e-5);
+        }
+    }
+}
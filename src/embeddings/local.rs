@@ -7,6 +7,7 @@
 //! and subsequent runs load from cache.
 
 use crate::config::EmbeddingConfig;
+use crate::diagnostics::{global_degradation, time_operation, DegradedService, Operation};
 use crate::embeddings::EmbeddingService;
 use crate::error::{MnemosyneError, Result};
 use async_trait::async_trait;
@@ -106,12 +107,19 @@ impl LocalEmbeddingService {
     /// Embed a batch of texts in a blocking task
     ///
     /// This is the internal implementation that runs fastembed's synchronous
-    /// embed function in a Tokio blocking task.
+    /// embed function in a Tokio blocking task. The outcome updates the global
+    /// degradation state.
     async fn embed_batch_internal(&self, texts: Vec<String>) -> Result<Vec<Vec<f32>>> {
         if texts.is_empty() {
             return Ok(Vec::new());
         }
 
+        let result = self.run_model(texts).await;
+        global_degradation().record(DegradedService::Embeddings, &result);
+        result
+    }
+
+    async fn run_model(&self, texts: Vec<String>) -> Result<Vec<Vec<f32>>> {
         debug!("Embedding batch of {} texts", texts.len());
 
         let model = Arc::clone(&self.model);
@@ -154,6 +162,7 @@ impl LocalEmbeddingService {
 #[async_trait]
 impl EmbeddingService for LocalEmbeddingService {
     async fn embed(&self, text: &str) -> Result<Vec<f32>> {
+        let _timer = time_operation(Operation::Embedding);
         if text.is_empty() {
             return Err(MnemosyneError::ValidationError(
                 "Text cannot be empty".to_string(),
@@ -172,6 +181,7 @@ impl EmbeddingService for LocalEmbeddingService {
         if texts.is_empty() {
             return Ok(Vec::new());
         }
+        let _timer = time_operation(Operation::Embedding);
 
         // Validate all texts
         for (i, text) in texts.iter().enumerate() {
@@ -1,10 +1,16 @@
 //! Embedding generation services for vector similarity search
 //!
-//! Provides both remote (Voyage AI) and local (fastembed) embedding generation.
+//! Provides both remote (Voyage AI) and local (fastembed) embedding generation,
+//! plus a deterministic hash-based service for tests, and the batch backfill
+//! behind `mnemosyne embed`.
 
+pub mod backfill;
+pub mod deterministic;
 pub mod local;
 pub mod remote;
 
+pub use backfill::{backfill_embeddings, BackfillOptions, BackfillProgress};
+pub use deterministic::{DeterministicEmbeddingService, DETERMINISTIC_EMBEDDING_DIM};
 pub use local::LocalEmbeddingService;
 pub use remote::{EmbeddingService, RemoteEmbeddingService, VOYAGE_EMBEDDING_DIM};
 
@@ -4,6 +4,7 @@
 //! Provides high-quality semantic embeddings via Voyage AI's
 //! text embedding models for vector similarity search.
 
+use crate::diagnostics::{global_degradation, time_operation, DegradedService, Operation};
 use crate::error::{MnemosyneError, Result};
 use async_trait::async_trait;
 use reqwest::{Client, StatusCode};
@@ -126,7 +127,15 @@ impl RemoteEmbeddingService {
     }
 
     /// Call Voyage AI API with retry logic and rate limiting
+    ///
+    /// The final outcome (after retries) updates the global degradation state.
     async fn call_api_with_retry(&self, texts: &[String]) -> Result<VoyageResponse> {
+        let result = self.call_api_retrying(texts).await;
+        global_degradation().record(DegradedService::Embeddings, &result);
+        result
+    }
+
+    async fn call_api_retrying(&self, texts: &[String]) -> Result<VoyageResponse> {
         let mut retries = 0;
 
         loop {
@@ -275,6 +284,7 @@ impl RemoteEmbeddingService {
 impl EmbeddingService for RemoteEmbeddingService {
     async fn embed(&self, text: &str) -> Result<Vec<f32>> {
         self.validate_text(text)?;
+        let _timer = time_operation(Operation::Embedding);
 
         let texts = vec![text.to_string()];
         let response = self.call_api_with_retry(&texts).await?;
@@ -300,6 +310,7 @@ impl EmbeddingService for RemoteEmbeddingService {
         for text in texts {
             self.validate_text(text)?;
         }
+        let _timer = time_operation(Operation::Embedding);
 
         let mut all_embeddings = Vec::new();
 
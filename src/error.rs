@@ -20,9 +20,17 @@ pub enum MnemosyneError {
     #[error("LLM API error: {0}")]
     LlmApi(String),
 
-    /// LLM API timeout
-    #[error("LLM API timeout after {0}s")]
-    LlmTimeout(u64),
+    /// LLM API request exceeded its timeout
+    #[error("LLM API timeout after {0:?}")]
+    LlmTimeout(std::time::Duration),
+
+    /// LLM API request cancelled by its caller
+    #[error("LLM API request cancelled")]
+    LlmCancelled,
+
+    /// LLM token budget for the current window is spent
+    #[error("LLM token budget exceeded: {0}")]
+    LlmBudgetExceeded(String),
 
     /// LLM API retry limit exceeded
     #[error("LLM API retry limit exceeded after {0} attempts: {1}")]
@@ -137,6 +145,19 @@ pub enum MnemosyneError {
     Other(String),
 }
 
+impl MnemosyneError {
+    /// Whether the same request may succeed if retried
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            MnemosyneError::LlmTimeout(_)
+            | MnemosyneError::NetworkError(_)
+            | MnemosyneError::RateLimitExceeded(_) => true,
+            MnemosyneError::Http(e) => e.is_timeout() || e.is_connect(),
+            _ => false,
+        }
+    }
+}
+
 /// Result type alias for Mnemosyne operations
 pub type Result<T> = std::result::Result<T, MnemosyneError>;
 
@@ -164,6 +185,14 @@ mod tests {
         assert_eq!(err.to_string(), "Memory not found: test-id");
     }
 
+    #[test]
+    fn test_retryable_errors() {
+        assert!(MnemosyneError::LlmTimeout(std::time::Duration::from_millis(50)).is_retryable());
+        assert!(MnemosyneError::RateLimitExceeded("slow down".to_string()).is_retryable());
+        assert!(!MnemosyneError::LlmCancelled.is_retryable());
+        assert!(!MnemosyneError::AuthenticationError("bad key".to_string()).is_retryable());
+    }
+
     #[test]
     fn test_error_conversion() {
         let uuid_err = uuid::Uuid::parse_str("invalid");
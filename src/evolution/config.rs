@@ -175,6 +175,38 @@ impl EvolutionConfig {
         Ok(config)
     }
 
+    /// Apply a partial configuration on top of this one
+    ///
+    /// `overrides` has the same shape as the TOML file (e.g.
+    /// `{"importance": {"batch_size": 200}}`); omitted fields keep their
+    /// current values. The merged configuration is validated.
+    pub fn with_overrides(&self, overrides: serde_json::Value) -> Result<Self, ConfigError> {
+        fn merge(base: &mut serde_json::Value, overrides: serde_json::Value) {
+            match (base, overrides) {
+                (serde_json::Value::Object(base), serde_json::Value::Object(overrides)) => {
+                    for (key, value) in overrides {
+                        match base.get_mut(&key) {
+                            Some(existing) => merge(existing, value),
+                            None => {
+                                base.insert(key, value);
+                            }
+                        }
+                    }
+                }
+                (base, overrides) => *base = overrides,
+            }
+        }
+
+        let mut merged =
+            serde_json::to_value(self).map_err(|e| ConfigError::ValidationError(e.to_string()))?;
+        merge(&mut merged, overrides);
+
+        let config: EvolutionConfig = serde_json::from_value(merged)
+            .map_err(|e| ConfigError::ValidationError(e.to_string()))?;
+        config.validate()?;
+        Ok(config)
+    }
+
     /// Validate configuration values
     pub fn validate(&self) -> Result<(), ConfigError> {
         // Validate consolidation config
@@ -356,4 +388,31 @@ mod tests {
             deserialized.consolidation.batch_size
         );
     }
+
+    #[test]
+    fn test_with_overrides_merges_partial_config() {
+        let base = EvolutionConfig::default();
+        let config = base
+            .with_overrides(serde_json::json!({
+                "importance": { "batch_size": 200 },
+                "archival": { "enabled": false }
+            }))
+            .unwrap();
+
+        assert_eq!(config.importance.batch_size, 200);
+        assert_eq!(config.importance.interval, base.importance.interval);
+        assert!(!config.archival.enabled);
+        assert_eq!(config.link_decay.batch_size, base.link_decay.batch_size);
+    }
+
+    #[test]
+    fn test_with_overrides_validates() {
+        let result = EvolutionConfig::default()
+            .with_overrides(serde_json::json!({ "importance": { "batch_size": 0 } }));
+        assert!(result.is_err());
+
+        let result = EvolutionConfig::default()
+            .with_overrides(serde_json::json!({ "importance": { "batch_size": "many" } }));
+        assert!(result.is_err());
+    }
 }
@@ -10,7 +10,7 @@
 
 use super::config::JobConfig;
 use super::scheduler::{EvolutionJob, JobError, JobReport};
-use crate::services::llm::LlmService;
+use crate::services::llm::{LlmService, LlmTask};
 use crate::storage::libsql::LibsqlStorage;
 use crate::types::{MemoryId, MemoryNote};
 use crate::utils::string::truncate_at_char_boundary;
@@ -513,7 +513,7 @@ impl ConsolidationJob {
 
         // Call LLM API
         let response = llm
-            .call_api(&prompt)
+            .call_task(LlmTask::Consolidation, &prompt)
             .await
             .map_err(|e| JobError::ExecutionError(format!("LLM API call failed: {}", e)))?;
 
@@ -27,5 +27,6 @@ pub use links::LinkDecayJob;
 #[cfg(feature = "python")]
 pub use memory_evolution_dspy_adapter::MemoryEvolutionDSpyAdapter;
 pub use scheduler::{
-    BackgroundScheduler, EvolutionJob, JobError, JobReport, JobRun, JobStatus, SchedulerError,
+    BackgroundScheduler, EvolutionJob, JobError, JobReport, JobRun, JobState, JobStatus,
+    SchedulerError,
 };
@@ -4,11 +4,14 @@
 // and execution history tracking.
 
 use super::config::{EvolutionConfig, JobConfig};
+use super::{ArchivalJob, ConsolidationJob, ImportanceRecalibrator, LinkDecayJob};
+use crate::storage::libsql::LibsqlStorage;
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 use thiserror::Error;
 use tokio::time::{sleep, timeout};
@@ -36,6 +39,9 @@ pub enum SchedulerError {
     #[error("Scheduler is already running")]
     AlreadyRunning,
 
+    #[error("Unknown job: {0}")]
+    UnknownJob(String),
+
     #[error("Storage error: {0}")]
     StorageError(#[from] anyhow::Error),
 
@@ -116,11 +122,25 @@ pub enum JobStatus {
     Timeout,
 }
 
+/// A registered job's run state, as reported by [`BackgroundScheduler::status`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobState {
+    pub name: String,
+    /// Whether a run is in progress
+    pub running: bool,
+    /// Most recent completed run, if any since the scheduler started
+    pub last_run: Option<JobRun>,
+}
+
 /// Background job scheduler
 pub struct BackgroundScheduler {
     config: EvolutionConfig,
     jobs: Vec<Arc<dyn EvolutionJob>>,
     running: Arc<AtomicBool>,
+    /// Jobs with a run in progress
+    active: Mutex<HashSet<String>>,
+    /// Most recent run of each job
+    last_runs: Mutex<HashMap<String, JobRun>>,
     // Note: In a full implementation, this would hold:
     // - storage: Arc<LibSqlStorage> for tracking job runs
     // - llm: Arc<LlmService> for consolidation decisions
@@ -134,9 +154,22 @@ impl BackgroundScheduler {
             config,
             jobs: Vec::new(),
             running: Arc::new(AtomicBool::new(false)),
+            active: Mutex::new(HashSet::new()),
+            last_runs: Mutex::new(HashMap::new()),
         }
     }
 
+    /// Scheduler with the consolidation, importance, link decay, and
+    /// archival jobs registered against this storage
+    pub fn with_default_jobs(config: EvolutionConfig, storage: Arc<LibsqlStorage>) -> Self {
+        let mut scheduler = Self::new(config);
+        scheduler.register_job(Arc::new(ConsolidationJob::new(storage.clone())));
+        scheduler.register_job(Arc::new(ImportanceRecalibrator::new(storage.clone())));
+        scheduler.register_job(Arc::new(LinkDecayJob::new(storage.clone())));
+        scheduler.register_job(Arc::new(ArchivalJob::new(storage)));
+        scheduler
+    }
+
     /// Register a job with the scheduler
     pub fn register_job(&mut self, job: Arc<dyn EvolutionJob>) {
         self.jobs.push(job);
@@ -197,10 +230,39 @@ impl BackgroundScheduler {
         self.running.store(false, Ordering::SeqCst);
     }
 
+    /// Run a registered job now, outside its schedule
+    ///
+    /// Fails with [`JobError::AlreadyRunning`] while another run of the same
+    /// job (scheduled or triggered) is in progress.
+    pub async fn run_now(&self, job_name: &str) -> Result<JobReport, SchedulerError> {
+        let job = self
+            .jobs
+            .iter()
+            .find(|job| job.name() == job_name)
+            .cloned()
+            .ok_or_else(|| SchedulerError::UnknownJob(job_name.to_string()))?;
+        self.run_job(job.as_ref()).await
+    }
+
+    /// Run state of every registered job
+    pub fn status(&self) -> Vec<JobState> {
+        let active = self.active.lock().unwrap_or_else(|e| e.into_inner());
+        let last_runs = self.last_runs.lock().unwrap_or_else(|e| e.into_inner());
+        self.jobs
+            .iter()
+            .map(|job| JobState {
+                name: job.name().to_string(),
+                running: active.contains(job.name()),
+                last_run: last_runs.get(job.name()).cloned(),
+            })
+            .collect()
+    }
+
     /// Run a specific job with timeout
     async fn run_job(&self, job: &dyn EvolutionJob) -> Result<JobReport, SchedulerError> {
         let job_name = job.name();
         let job_config = self.get_job_config(job_name)?;
+        let _claim = RunClaim::acquire(&self.active, job_name)?;
 
         let start_time = Utc::now();
         let job_id = uuid::Uuid::new_v4().to_string();
@@ -322,14 +384,18 @@ impl BackgroundScheduler {
     }
 
     /// Record job run to database
-    async fn record_job_run(&self, _job_run: &JobRun) -> Result<(), SchedulerError> {
+    async fn record_job_run(&self, job_run: &JobRun) -> Result<(), SchedulerError> {
         // In full implementation, this would insert into evolution_job_runs table
-        // For now, just log
+        // For now, keep the latest run per job for `status`
         tracing::debug!(
             "Job run recorded: {} - {:?}",
-            _job_run.job_name,
-            _job_run.status
+            job_run.job_name,
+            job_run.status
         );
+        self.last_runs
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(job_run.job_name.clone(), job_run.clone());
         Ok(())
     }
 
@@ -346,6 +412,34 @@ impl BackgroundScheduler {
     }
 }
 
+/// Marks a job as running until dropped
+struct RunClaim<'a> {
+    active: &'a Mutex<HashSet<String>>,
+    job_name: String,
+}
+
+impl<'a> RunClaim<'a> {
+    fn acquire(active: &'a Mutex<HashSet<String>>, job_name: &str) -> Result<Self, JobError> {
+        let mut jobs = active.lock().unwrap_or_else(|e| e.into_inner());
+        if !jobs.insert(job_name.to_string()) {
+            return Err(JobError::AlreadyRunning);
+        }
+        Ok(Self {
+            active,
+            job_name: job_name.to_string(),
+        })
+    }
+}
+
+impl Drop for RunClaim<'_> {
+    fn drop(&mut self) {
+        self.active
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(&self.job_name);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -458,4 +552,56 @@ mod tests {
         assert_eq!(report.errors, 1);
         assert!(report.error_message.is_some());
     }
+
+    #[tokio::test]
+    async fn test_run_now_rejects_concurrent_runs() {
+        struct SlowJob;
+
+        #[async_trait]
+        impl EvolutionJob for SlowJob {
+            fn name(&self) -> &str {
+                "test_slow"
+            }
+
+            async fn run(&self, _config: &JobConfig) -> Result<JobReport, JobError> {
+                sleep(Duration::from_millis(50)).await;
+                Ok(JobReport {
+                    memories_processed: 1,
+                    changes_made: 0,
+                    duration: Duration::from_millis(50),
+                    errors: 0,
+                    error_message: None,
+                })
+            }
+
+            async fn should_run(&self) -> Result<bool, JobError> {
+                Ok(false)
+            }
+        }
+
+        let mut scheduler = BackgroundScheduler::new(EvolutionConfig::default());
+        scheduler.register_job(Arc::new(SlowJob));
+        assert!(scheduler.status()[0].last_run.is_none());
+
+        let (first, second) = tokio::join!(
+            scheduler.run_now("test_slow"),
+            scheduler.run_now("test_slow")
+        );
+        assert_eq!(first.unwrap().memories_processed, 1);
+        assert!(matches!(
+            second,
+            Err(SchedulerError::JobError(JobError::AlreadyRunning))
+        ));
+
+        // The claim is released once the run completes
+        assert!(scheduler.run_now("test_slow").await.is_ok());
+        let state = &scheduler.status()[0];
+        assert!(!state.running);
+        assert_eq!(state.last_run.as_ref().unwrap().status, JobStatus::Success);
+
+        assert!(matches!(
+            scheduler.run_now("missing").await,
+            Err(SchedulerError::UnknownJob(_))
+        ));
+    }
 }
@@ -7,16 +7,32 @@
 //! - Hook system verification
 //! - Memory statistics and growth
 //! - Performance benchmarks
+//! - Embedding backend reachability
 //! - Actor health monitoring
+//! - Stale daemon PID files and sockets
+//!
+//! [`liveness`] and [`readiness`] are the cheap probes behind the API
+//! server's `/healthz` and `/readyz`: liveness only says the process is up,
+//! readiness says whether its dependencies let it serve requests.
 
+use crate::embeddings::EmbeddingService;
 use crate::error::Result;
 use crate::storage::libsql::LibsqlStorage;
 use crate::storage::StorageBackend;
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 use tracing::{debug, info};
 
+/// Embedding calls slower than this are reported as degraded
+const EMBEDDING_DEGRADED_MS: u64 = 2000;
+
+/// Timeout for the test embedding call
+const EMBEDDING_PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Timeout for loading an already-downloaded local model
+const EMBEDDING_LOAD_TIMEOUT: Duration = Duration::from_secs(30);
+
 /// Health check status
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -34,6 +50,9 @@ pub struct CheckResult {
     pub message: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub details: Option<serde_json::Value>,
+    /// Failures only count toward the overall status in strict mode
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub non_fatal: bool,
 }
 
 impl CheckResult {
@@ -43,6 +62,7 @@ impl CheckResult {
             status: CheckStatus::Pass,
             message: message.into(),
             details: None,
+            non_fatal: false,
         }
     }
 
@@ -52,6 +72,7 @@ impl CheckResult {
             status: CheckStatus::Warn,
             message: message.into(),
             details: None,
+            non_fatal: false,
         }
     }
 
@@ -61,6 +82,7 @@ impl CheckResult {
             status: CheckStatus::Fail,
             message: message.into(),
             details: None,
+            non_fatal: false,
         }
     }
 
@@ -68,6 +90,21 @@ impl CheckResult {
         self.details = Some(details);
         self
     }
+
+    /// Mark as advisory: a failure is downgraded to a warning unless strict
+    pub fn non_fatal(mut self) -> Self {
+        self.non_fatal = true;
+        self
+    }
+
+    /// Status as counted toward the overall result
+    pub fn effective_status(&self, strict: bool) -> CheckStatus {
+        if self.non_fatal && !strict && self.status == CheckStatus::Fail {
+            CheckStatus::Warn
+        } else {
+            self.status
+        }
+    }
 }
 
 /// Overall health check summary
@@ -86,11 +123,153 @@ pub struct HealthStats {
     pub errors: usize,
 }
 
+/// Liveness probe result
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LivenessReport {
+    pub alive: bool,
+    pub pid: u32,
+    pub version: String,
+}
+
+/// Whether the process can serve requests
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Readiness {
+    Ready,
+    NotReady,
+}
+
+/// Readiness of one dependency
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DependencyStatus {
+    pub name: String,
+    pub ready: bool,
+    pub message: String,
+}
+
+impl DependencyStatus {
+    fn ready(name: &str, message: impl Into<String>) -> Self {
+        Self {
+            name: name.to_string(),
+            ready: true,
+            message: message.into(),
+        }
+    }
+
+    fn not_ready(name: &str, message: impl Into<String>) -> Self {
+        Self {
+            name: name.to_string(),
+            ready: false,
+            message: message.into(),
+        }
+    }
+}
+
+/// Readiness probe result with per-dependency status
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReadinessReport {
+    pub status: Readiness,
+    pub dependencies: Vec<DependencyStatus>,
+}
+
+impl ReadinessReport {
+    /// Ready when every dependency is (including when there are none)
+    pub fn from_dependencies(dependencies: Vec<DependencyStatus>) -> Self {
+        let status = if dependencies.iter().all(|d| d.ready) {
+            Readiness::Ready
+        } else {
+            Readiness::NotReady
+        };
+        Self {
+            status,
+            dependencies,
+        }
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.status == Readiness::Ready
+    }
+
+    /// Status of the dependency called `name`
+    pub fn dependency(&self, name: &str) -> Option<&DependencyStatus> {
+        self.dependencies.iter().find(|d| d.name == name)
+    }
+}
+
+/// Report that the process is up
+///
+/// Checks nothing else on purpose: a failed liveness probe means the process
+/// should be restarted, which doesn't fix an unreachable dependency.
+pub fn liveness() -> LivenessReport {
+    LivenessReport {
+        alive: true,
+        pid: std::process::id(),
+        version: env!("CARGO_PKG_VERSION").to_string(),
+    }
+}
+
+/// Check that storage answers queries, all migrations are applied, and
+/// embeddings are not degraded
+///
+/// Cheap enough to poll: embeddings are judged by the outcome of recent
+/// calls (see `diagnostics::global_degradation`) rather than a test
+/// embedding.
+pub async fn readiness(storage: &LibsqlStorage) -> ReadinessReport {
+    use crate::diagnostics::{global_degradation, DegradedService};
+
+    let mut dependencies = Vec::new();
+
+    dependencies.push(match storage.count_memories(None).await {
+        Ok(count) => DependencyStatus::ready("storage", format!("{} active memories", count)),
+        Err(e) => DependencyStatus::not_ready("storage", format!("query failed: {}", e)),
+    });
+
+    dependencies.push(match storage.migration_status().await {
+        Ok(status) if status.is_current() => DependencyStatus::ready(
+            "migrations",
+            format!("schema version {}", status.target_version),
+        ),
+        Ok(status) => DependencyStatus::not_ready(
+            "migrations",
+            format!(
+                "{} pending migrations, run: mnemosyne init",
+                status.pending.len()
+            ),
+        ),
+        Err(e) => DependencyStatus::not_ready(
+            "migrations",
+            format!("failed to read schema version: {}", e),
+        ),
+    });
+
+    let embeddings = global_degradation()
+        .snapshot()
+        .into_iter()
+        .find(|d| d.service == DegradedService::Embeddings);
+    dependencies.push(match embeddings {
+        Some(degradation) => DependencyStatus::not_ready(
+            "embeddings",
+            format!(
+                "degraded since {}: {}",
+                degradation.since.to_rfc3339(),
+                degradation.reason
+            ),
+        ),
+        None => DependencyStatus::ready("embeddings", "no recent failures"),
+    });
+
+    ReadinessReport::from_dependencies(dependencies)
+}
+
 /// Run all health checks
+///
+/// With `strict`, failures of non-fatal checks (e.g. an unreachable
+/// embedding backend) fail the overall status instead of warning.
 pub async fn run_health_checks(
     storage: &LibsqlStorage,
     verbose: bool,
     fix: bool,
+    strict: bool,
 ) -> Result<HealthSummary> {
     info!("🏥 Starting mnemosyne health checks...");
 
@@ -114,24 +293,34 @@ pub async fn run_health_checks(
     // Phase 6: Performance (MEDIUM)
     checks.extend(check_performance(storage, verbose).await?);
 
-    // Phase 7: Worktree Cleanup (LOW)
+    // Phase 7: Embedding Backend (MEDIUM, non-fatal)
+    checks.extend(check_embedding_backend(verbose).await?);
+
+    // Phase 8: Worktree Cleanup (LOW)
     checks.extend(check_worktree_cleanup(verbose, fix).await?);
 
-    // Phase 8: Version Updates (INFO)
+    // Phase 9: Daemon Files (LOW)
+    checks.extend(check_daemon_files(verbose, fix).await?);
+
+    // Phase 10: Version Updates (INFO)
     checks.extend(check_version_updates(verbose).await?);
 
-    // Calculate summary
+    Ok(summarize(checks, strict))
+}
+
+/// Tally check results into a summary
+fn summarize(checks: Vec<CheckResult>, strict: bool) -> HealthSummary {
     let passed = checks
         .iter()
-        .filter(|c| c.status == CheckStatus::Pass)
+        .filter(|c| c.effective_status(strict) == CheckStatus::Pass)
         .count();
     let warnings = checks
         .iter()
-        .filter(|c| c.status == CheckStatus::Warn)
+        .filter(|c| c.effective_status(strict) == CheckStatus::Warn)
         .count();
     let errors = checks
         .iter()
-        .filter(|c| c.status == CheckStatus::Fail)
+        .filter(|c| c.effective_status(strict) == CheckStatus::Fail)
         .count();
 
     let overall_status = if errors > 0 {
@@ -142,7 +331,7 @@ pub async fn run_health_checks(
         CheckStatus::Pass
     };
 
-    Ok(HealthSummary {
+    HealthSummary {
         status: overall_status,
         checks,
         summary: HealthStats {
@@ -151,7 +340,7 @@ pub async fn run_health_checks(
             warnings,
             errors,
         },
-    })
+    }
 }
 
 /// Check database health (file exists, readable, not corrupted)
@@ -280,6 +469,39 @@ async fn check_migration_consistency(
         }
     }
 
+    // Opening the database applies pending migrations, so any left over
+    // were skipped (read-only database) or failed
+    match storage.migration_status().await {
+        Ok(status) => {
+            let details = serde_json::json!({
+                "schema_version": status.schema_version,
+                "target_version": status.target_version,
+                "pending": status.pending,
+            });
+            let result = if status.is_current() {
+                CheckResult::pass(
+                    "schema_version",
+                    format!("Schema version {}", status.target_version),
+                )
+            } else {
+                CheckResult::warn(
+                    "schema_version",
+                    format!(
+                        "Schema not current ({} pending migrations), run: mnemosyne init",
+                        status.pending.len()
+                    ),
+                )
+            };
+            results.push(result.with_details(details));
+        }
+        Err(e) => {
+            results.push(
+                CheckResult::fail("schema_version", "Failed to read schema version")
+                    .with_details(serde_json::json!({ "error": e.to_string() })),
+            );
+        }
+    }
+
     Ok(results)
 }
 
@@ -453,11 +675,50 @@ async fn check_memory_statistics(
         }
     }
 
+    // Embeddings from a model other than the configured one are not
+    // comparable with query embeddings
+    let model =
+        crate::config::ConfigManager::load_section("embedding settings", |s| s.embeddings).model;
+    match storage.embedding_model_status(&model).await {
+        Ok(status) => {
+            let details = serde_json::json!({
+                "model": status.model,
+                "embedded": status.embedded,
+                "stale": status.stale,
+                "stale_fraction": status.stale_fraction(),
+            });
+            let result = if status.stale == 0 {
+                CheckResult::pass(
+                    "embedding_model",
+                    format!("All {} embeddings use '{}'", status.embedded, status.model),
+                )
+            } else {
+                CheckResult::warn(
+                    "embedding_model",
+                    format!(
+                        "{:.0}% of embeddings ({}/{}) are from a model other than '{}' (run 'mnemosyne embed --all')",
+                        status.stale_fraction() * 100.0,
+                        status.stale,
+                        status.embedded,
+                        status.model
+                    ),
+                )
+            };
+            results.push(result.with_details(details));
+        }
+        Err(e) => {
+            results.push(
+                CheckResult::warn("embedding_model", "Failed to check embedding models")
+                    .with_details(serde_json::json!({ "error": e.to_string() })),
+            );
+        }
+    }
+
     Ok(results)
 }
 
 /// Check performance benchmarks
-async fn check_performance(storage: &LibsqlStorage, _verbose: bool) -> Result<Vec<CheckResult>> {
+async fn check_performance(storage: &LibsqlStorage, verbose: bool) -> Result<Vec<CheckResult>> {
     debug!("Checking performance...");
     let mut results = Vec::new();
 
@@ -504,11 +765,285 @@ async fn check_performance(storage: &LibsqlStorage, _verbose: bool) -> Result<Ve
         }
     }
 
+    // Percentiles of the operations this process has timed so far
+    if verbose {
+        let summaries = crate::diagnostics::global_latency().summaries();
+        let message = if summaries.is_empty() {
+            "Latency: no operations recorded".to_string()
+        } else {
+            summaries
+                .iter()
+                .map(|summary| summary.to_string())
+                .collect::<Vec<_>>()
+                .join("\n   ")
+        };
+        results.push(
+            CheckResult::pass("operation_latency", message)
+                .with_details(serde_json::json!(summaries)),
+        );
+    }
+
+    Ok(results)
+}
+
+/// Check that the embedding backends actually produce embeddings
+///
+/// Remote (Voyage AI) is probed with a tiny test embedding when an API key is
+/// configured; the local model is loaded and probed only if it has already
+/// been downloaded, so doctor never triggers a model download.
+async fn check_embedding_backend(_verbose: bool) -> Result<Vec<CheckResult>> {
+    use crate::config::EmbeddingConfig;
+    use crate::embeddings::{LocalEmbeddingService, RemoteEmbeddingService};
+
+    debug!("Checking embedding backends...");
+    let mut results = Vec::new();
+
+    // Remote: same key source as remember/recall
+    let api_key = crate::services::LlmConfig::default().api_key;
+    if api_key.is_empty() {
+        results.push(CheckResult::pass(
+            "embedding_remote",
+            "OK - not configured (no API key), vector search uses local embeddings only",
+        ));
+    } else {
+        match RemoteEmbeddingService::new(api_key, None, None) {
+            Ok(service) => results.push(
+                probe_embedding_service("embedding_remote", &service, EMBEDDING_PROBE_TIMEOUT)
+                    .await,
+            ),
+            Err(e) => results.push(
+                CheckResult::fail(
+                    "embedding_remote",
+                    format!("failed - could not create client: {}", e),
+                )
+                .non_fatal(),
+            ),
+        }
+    }
+
+    // Local: confirm the model loads
+    let mut config = EmbeddingConfig::default();
+    config.show_download_progress = false;
+
+    let model_cached = std::fs::read_dir(&config.cache_dir)
+        .map(|mut entries| entries.next().is_some())
+        .unwrap_or(false);
+
+    if !config.enabled {
+        results.push(CheckResult::pass(
+            "embedding_local",
+            "OK - local embeddings disabled",
+        ));
+    } else if !model_cached {
+        results.push(
+            CheckResult::warn(
+                "embedding_local",
+                format!(
+                    "degraded - model '{}' not downloaded yet (run 'mnemosyne embed' to fetch it)",
+                    config.model
+                ),
+            )
+            .with_details(serde_json::json!({
+                "model": config.model,
+                "cache_dir": config.cache_dir.display().to_string(),
+            }))
+            .non_fatal(),
+        );
+    } else {
+        let model = config.model.clone();
+        let start = Instant::now();
+        match tokio::time::timeout(EMBEDDING_LOAD_TIMEOUT, LocalEmbeddingService::new(config)).await
+        {
+            Ok(Ok(service)) => {
+                let load_ms = start.elapsed().as_millis() as u64;
+                let probe =
+                    probe_embedding_service("embedding_local", &service, EMBEDDING_PROBE_TIMEOUT)
+                        .await;
+                let mut details = probe.details.clone().unwrap_or_default();
+                details["load_ms"] = serde_json::json!(load_ms);
+                results.push(probe.with_details(details));
+            }
+            Ok(Err(e)) => results.push(
+                CheckResult::fail(
+                    "embedding_local",
+                    format!("failed - model '{}' did not load: {}", model, e),
+                )
+                .non_fatal(),
+            ),
+            Err(_) => results.push(
+                CheckResult::fail(
+                    "embedding_local",
+                    format!(
+                        "failed - model '{}' did not load within {}s",
+                        model,
+                        EMBEDDING_LOAD_TIMEOUT.as_secs()
+                    ),
+                )
+                .non_fatal(),
+            ),
+        }
+    }
+
     Ok(results)
 }
 
-/// Check and cleanup stale git worktrees
+/// Embed a short test string and grade the backend by outcome and latency
+async fn probe_embedding_service(
+    name: &str,
+    service: &dyn EmbeddingService,
+    timeout: Duration,
+) -> CheckResult {
+    let start = Instant::now();
+    let outcome = tokio::time::timeout(timeout, service.embed("mnemosyne health check")).await;
+    let latency_ms = start.elapsed().as_millis() as u64;
+
+    let details = serde_json::json!({
+        "model": service.model_name(),
+        "dimensions": service.dimensions(),
+        "latency_ms": latency_ms,
+    });
+
+    let result = match outcome {
+        Ok(Ok(embedding)) if embedding.len() != service.dimensions() => CheckResult::fail(
+            name,
+            format!(
+                "failed - expected {} dimensions, got {}",
+                service.dimensions(),
+                embedding.len()
+            ),
+        ),
+        Ok(Ok(_)) if latency_ms > EMBEDDING_DEGRADED_MS => CheckResult::warn(
+            name,
+            format!("degraded - test embedding took {} ms", latency_ms),
+        ),
+        Ok(Ok(_)) => CheckResult::pass(name, format!("OK - test embedding in {} ms", latency_ms)),
+        Ok(Err(e)) => CheckResult::fail(name, format!("failed - {}", e)),
+        Err(_) => CheckResult::fail(
+            name,
+            format!("failed - no response within {} ms", timeout.as_millis()),
+        ),
+    };
+
+    result.with_details(details).non_fatal()
+}
+
+/// Check for (and with `fix`, remove) PID files and sockets left by daemons
+/// that exited without cleaning up
+async fn check_daemon_files(_verbose: bool, fix: bool) -> Result<Vec<CheckResult>> {
+    use crate::daemon::{orchestration::OrchestrationDaemon, McpDaemon};
+
+    debug!("Checking daemon PID files...");
+    Ok(vec![
+        check_mcp_daemon_files(&McpDaemon::new(), fix),
+        check_orchestration_daemon_files(&OrchestrationDaemon::new(), fix).await,
+    ])
+}
+
+fn check_mcp_daemon_files(daemon: &crate::daemon::McpDaemon, fix: bool) -> CheckResult {
+    use crate::daemon::DaemonStatus;
+
+    const NAME: &str = "mcp_daemon";
+    let pid_file = daemon.pid_file().display().to_string();
+    let pid = match daemon.status() {
+        Ok(DaemonStatus::Running { pid }) => {
+            return CheckResult::pass(NAME, format!("MCP daemon running (PID {})", pid));
+        }
+        Ok(DaemonStatus::NotRunning) => {
+            return CheckResult::pass(NAME, "MCP daemon not running, no PID file");
+        }
+        Ok(DaemonStatus::Stale { pid }) => pid,
+        Err(e) => {
+            return CheckResult::warn(NAME, "Failed to read MCP daemon PID file")
+                .with_details(serde_json::json!({ "pid_file": pid_file, "error": e.to_string() }));
+        }
+    };
+
+    if !fix {
+        return CheckResult::warn(
+            NAME,
+            format!(
+                "Stale MCP daemon PID file for exited process {} (run with --fix to remove it)",
+                pid
+            ),
+        )
+        .with_details(serde_json::json!({ "pid": pid, "pid_file": pid_file }));
+    }
+
+    match daemon.clean_stale() {
+        Ok(Some(pid)) => CheckResult::pass(
+            NAME,
+            format!(
+                "Removed stale MCP daemon PID file (PID {}); the daemon can be started again",
+                pid
+            ),
+        ),
+        Ok(None) => CheckResult::pass(NAME, "MCP daemon PID file is no longer stale"),
+        Err(e) => CheckResult::fail(NAME, "Failed to remove stale MCP daemon PID file")
+            .with_details(serde_json::json!({ "pid_file": pid_file, "error": e.to_string() })),
+    }
+}
+
+async fn check_orchestration_daemon_files(
+    daemon: &crate::daemon::orchestration::OrchestrationDaemon,
+    fix: bool,
+) -> CheckResult {
+    use crate::daemon::orchestration::OrchestrationStatus;
+
+    const NAME: &str = "orchestration_daemon";
+    let status = match daemon.status().await {
+        Ok(status) => status,
+        Err(e) => {
+            return CheckResult::warn(NAME, "Failed to read orchestration daemon status")
+                .with_details(serde_json::json!({ "error": e.to_string() }));
+        }
+    };
+    let orphaned_socket = daemon.socket_path().exists();
+    match status {
+        OrchestrationStatus::Running { pid, .. } | OrchestrationStatus::Degraded { pid, .. } => {
+            return CheckResult::pass(NAME, format!("Orchestration daemon running (PID {})", pid));
+        }
+        OrchestrationStatus::NotRunning if !orphaned_socket => {
+            return CheckResult::pass(NAME, "Orchestration daemon not running");
+        }
+        OrchestrationStatus::NotRunning | OrchestrationStatus::Stale { .. } => {}
+    }
+
+    if !fix {
+        return CheckResult::warn(
+            NAME,
+            "Stale orchestration daemon PID file or socket (run with --fix to remove them)",
+        )
+        .with_details(serde_json::json!({
+            "status": format!("{:?}", status),
+            "socket": daemon.socket_path().display().to_string(),
+        }));
+    }
+
+    match daemon.clean_stale().await {
+        Ok(removed) if removed.is_empty() => {
+            CheckResult::pass(NAME, "Orchestration daemon files are no longer stale")
+        }
+        Ok(removed) => CheckResult::pass(
+            NAME,
+            format!(
+                "Removed {} stale orchestration daemon file(s); the daemon can be started again",
+                removed.len()
+            ),
+        )
+        .with_details(serde_json::json!({ "removed": removed })),
+        Err(e) => CheckResult::fail(NAME, "Failed to remove stale orchestration daemon files")
+            .with_details(serde_json::json!({ "error": e.to_string() })),
+    }
+}
+
+/// Check for (and with `fix`, remove) orphaned mnemosyne worktrees
+///
+/// A worktree under `.mnemosyne/worktrees/` is kept if a live process in the
+/// cross-process registry owns it. Worktrees whose owner has exited, or that
+/// no registered process claims, are orphans left behind by crashed sessions.
 async fn check_worktree_cleanup(_verbose: bool, fix: bool) -> Result<Vec<CheckResult>> {
+    use crate::orchestration::{AgentId, CrossProcessCoordinator, WorktreeManager};
+
     debug!("Checking worktree status...");
     let mut results = Vec::new();
 
@@ -540,8 +1075,7 @@ async fn check_worktree_cleanup(_verbose: bool, fix: bool) -> Result<Vec<CheckRe
     };
 
     // Initialize worktree manager
-    use crate::orchestration::WorktreeManager;
-    let manager = match WorktreeManager::new(repo_root) {
+    let manager = match WorktreeManager::new(repo_root.clone()) {
         Ok(m) => m,
         Err(e) => {
             results.push(
@@ -552,8 +1086,8 @@ async fn check_worktree_cleanup(_verbose: bool, fix: bool) -> Result<Vec<CheckRe
         }
     };
 
-    // List all worktrees
-    let worktrees = match manager.list_worktrees() {
+    // Enumerate mnemosyne-created worktrees
+    let worktrees = match manager.list_managed_worktrees() {
         Ok(wt) => wt,
         Err(e) => {
             results.push(
@@ -564,10 +1098,7 @@ async fn check_worktree_cleanup(_verbose: bool, fix: bool) -> Result<Vec<CheckRe
         }
     };
 
-    // Count non-main worktrees (potential stale worktrees)
-    let agent_worktrees: Vec<_> = worktrees.iter().filter(|wt| !wt.is_main).collect();
-
-    if agent_worktrees.is_empty() {
+    if worktrees.is_empty() {
         results.push(CheckResult::pass(
             "worktree_check",
             "No agent worktrees found",
@@ -575,56 +1106,149 @@ async fn check_worktree_cleanup(_verbose: bool, fix: bool) -> Result<Vec<CheckRe
         return Ok(results);
     }
 
-    // If fix mode, cleanup all stale worktrees (no active agents)
-    if fix {
-        let cleaned = match manager.cleanup_stale(&[]) {
-            Ok(cleaned) => cleaned,
+    // Look up owners; without a readable registry nothing is provably orphaned
+    let coordinator =
+        match CrossProcessCoordinator::new(&repo_root.join(".mnemosyne"), AgentId::new()) {
+            Ok(c) => c,
             Err(e) => {
                 results.push(
-                    CheckResult::fail("worktree_cleanup", "Failed to cleanup worktrees")
+                    CheckResult::fail("worktree_check", "Failed to open process registry")
                         .with_details(serde_json::json!({ "error": e.to_string() })),
                 );
                 return Ok(results);
             }
         };
-
-        if cleaned.is_empty() {
-            results.push(CheckResult::pass(
-                "worktree_cleanup",
-                "No stale worktrees to clean",
-            ));
-        } else {
+    let processes = match coordinator.get_active_processes() {
+        Ok(p) => p,
+        Err(e) => {
             results.push(
-                CheckResult::pass(
-                    "worktree_cleanup",
-                    format!("Cleaned {} stale worktree(s)", cleaned.len()),
+                CheckResult::fail(
+                    "worktree_check",
+                    "Failed to read process registry; not touching worktrees",
                 )
-                .with_details(serde_json::json!({
-                    "cleaned_count": cleaned.len(),
-                })),
+                .with_details(serde_json::json!({ "error": e.to_string() })),
             );
+            return Ok(results);
         }
-    } else {
-        // Just report status
+    };
+
+    let mut active = Vec::new();
+    let mut orphaned = Vec::new();
+    for path in &worktrees {
+        let dir_name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+        let owners: Vec<_> = processes
+            .iter()
+            .filter(|p| {
+                p.worktree_path.as_deref() == Some(path.as_path())
+                    || p.agent_id.to_string() == dir_name
+            })
+            .collect();
+
+        if let Some(owner) = owners.iter().find(|p| p.is_alive()) {
+            active.push((path, format!("in use by pid {}", owner.pid)));
+        } else if let Some(owner) = owners.first() {
+            orphaned.push((path, format!("owner pid {} exited", owner.pid)));
+        } else {
+            orphaned.push((path, "no registered owner".to_string()));
+        }
+    }
+
+    let describe = |entries: &[(&PathBuf, String)]| {
+        entries
+            .iter()
+            .map(|(path, reason)| {
+                serde_json::json!({
+                    "path": path.display().to_string(),
+                    "reason": reason,
+                })
+            })
+            .collect::<Vec<_>>()
+    };
+
+    if orphaned.is_empty() {
+        results.push(
+            CheckResult::pass(
+                "worktree_check",
+                format!(
+                    "{} agent worktree(s), all in use by running sessions",
+                    active.len()
+                ),
+            )
+            .with_details(serde_json::json!({ "active": describe(&active) })),
+        );
+        return Ok(results);
+    }
+
+    if !fix {
+        let listing: Vec<String> = orphaned
+            .iter()
+            .map(|(path, reason)| format!("{} ({})", path.display(), reason))
+            .collect();
         results.push(
             CheckResult::warn(
                 "worktree_check",
                 format!(
-                    "Found {} agent worktree(s) - run with --fix to clean up stale worktrees",
-                    agent_worktrees.len()
+                    "Found {} orphaned worktree(s) - run with --fix to remove them:\n   - {}",
+                    orphaned.len(),
+                    listing.join("\n   - ")
                 ),
             )
             .with_details(serde_json::json!({
-                "worktree_count": agent_worktrees.len(),
-                "worktrees": agent_worktrees.iter().map(|wt| {
-                    serde_json::json!({
-                        "path": wt.path.display().to_string(),
-                        "branch": wt.branch,
-                    })
-                }).collect::<Vec<_>>(),
+                "worktree_count": orphaned.len(),
+                "active_count": active.len(),
+                "orphaned": describe(&orphaned),
+                "active": describe(&active),
             })),
         );
+        return Ok(results);
+    }
+
+    // Fix mode: remove orphans only, never worktrees with a live owner
+    let mut actions = Vec::new();
+    let mut cleaned = 0;
+    let mut failed = 0;
+    for (path, reason) in &orphaned {
+        match manager.remove_worktree_path(path) {
+            Ok(()) => {
+                cleaned += 1;
+                actions.push(format!("removed {} ({})", path.display(), reason));
+            }
+            Err(e) => {
+                failed += 1;
+                actions.push(format!("failed to remove {}: {}", path.display(), e));
+            }
+        }
+    }
+    for (path, reason) in &active {
+        actions.push(format!("kept {} ({})", path.display(), reason));
+    }
+
+    if let Err(e) = manager.prune() {
+        debug!("git worktree prune failed: {}", e);
     }
+    let pruned_registrations = coordinator
+        .cleanup_stale_processes()
+        .map(|stale| stale.len())
+        .unwrap_or(0);
+
+    let message = format!(
+        "Cleaned {} orphaned worktree(s):\n   - {}",
+        cleaned,
+        actions.join("\n   - ")
+    );
+    let details = serde_json::json!({
+        "cleaned_count": cleaned,
+        "failed_count": failed,
+        "kept_count": active.len(),
+        "pruned_registrations": pruned_registrations,
+        "actions": actions,
+    });
+
+    results.push(if failed > 0 {
+        CheckResult::fail("worktree_cleanup", message).with_details(details)
+    } else {
+        CheckResult::pass("worktree_cleanup", message).with_details(details)
+    });
 
     Ok(results)
 }
@@ -645,6 +1269,7 @@ pub fn print_health_summary(summary: &HealthSummary, verbose: bool) {
         let status_text = match check.status {
             CheckStatus::Pass => "PASS",
             CheckStatus::Warn => "WARN",
+            CheckStatus::Fail if check.non_fatal => "FAIL (non-fatal)",
             CheckStatus::Fail => "FAIL",
         };
 
@@ -682,8 +1307,19 @@ async fn check_version_updates(_verbose: bool) -> Result<Vec<CheckResult>> {
     debug!("Checking for version updates...");
     let mut results = Vec::new();
 
+    if crate::version_check::update_checks_disabled() {
+        results.push(CheckResult::pass(
+            "version_checks",
+            format!(
+                "Update checks disabled ({})",
+                crate::version_check::NO_UPDATE_CHECK_ENV
+            ),
+        ));
+        return Ok(results);
+    }
+
     // Create version checker
-    let checker = match crate::version_check::VersionChecker::new() {
+    let checker = match crate::version_check::VersionChecker::from_config() {
         Ok(c) => c,
         Err(e) => {
             results.push(CheckResult::fail(
@@ -757,3 +1393,132 @@ async fn check_version_updates(_verbose: bool) -> Result<Vec<CheckResult>> {
 
     Ok(results)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+
+    /// Embedding service that answers after `delay` or fails
+    struct StubEmbeddings {
+        delay: Duration,
+        dimensions: usize,
+        fail: bool,
+    }
+
+    #[async_trait]
+    impl EmbeddingService for StubEmbeddings {
+        async fn embed(&self, _text: &str) -> Result<Vec<f32>> {
+            tokio::time::sleep(self.delay).await;
+            if self.fail {
+                return Err(crate::error::MnemosyneError::NetworkError(
+                    "connection refused".to_string(),
+                ));
+            }
+            Ok(vec![0.1; 4])
+        }
+
+        async fn embed_batch(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>> {
+            Ok(texts.iter().map(|_| vec![0.1; 4]).collect())
+        }
+
+        fn dimensions(&self) -> usize {
+            self.dimensions
+        }
+
+        fn model_name(&self) -> &str {
+            "stub"
+        }
+    }
+
+    fn stub(delay_ms: u64, dimensions: usize, fail: bool) -> StubEmbeddings {
+        StubEmbeddings {
+            delay: Duration::from_millis(delay_ms),
+            dimensions,
+            fail,
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_probe_grades_embedding_backend() {
+        let timeout = Duration::from_secs(5);
+
+        let ok = probe_embedding_service("embed", &stub(10, 4, false), timeout).await;
+        assert_eq!(ok.status, CheckStatus::Pass);
+        assert!(ok.non_fatal);
+        assert!(ok.details.unwrap()["latency_ms"].as_u64().unwrap() >= 10);
+
+        let slow = probe_embedding_service("embed", &stub(3000, 4, false), timeout).await;
+        assert_eq!(slow.status, CheckStatus::Warn);
+        assert!(slow.message.starts_with("degraded"));
+
+        let error = probe_embedding_service("embed", &stub(10, 4, true), timeout).await;
+        assert_eq!(error.status, CheckStatus::Fail);
+
+        let hung = probe_embedding_service("embed", &stub(60_000, 4, false), timeout).await;
+        assert_eq!(hung.status, CheckStatus::Fail);
+        assert!(hung.message.contains("no response"));
+
+        let wrong_dims = probe_embedding_service("embed", &stub(10, 8, false), timeout).await;
+        assert_eq!(wrong_dims.status, CheckStatus::Fail);
+    }
+
+    #[test]
+    fn test_non_fatal_failures_only_fail_when_strict() {
+        let checks = || {
+            vec![
+                CheckResult::pass("database", "ok"),
+                CheckResult::fail("embedding_remote", "failed").non_fatal(),
+            ]
+        };
+
+        let lenient = summarize(checks(), false);
+        assert_eq!(lenient.status, CheckStatus::Warn);
+        assert_eq!(lenient.summary.errors, 0);
+
+        let strict = summarize(checks(), true);
+        assert_eq!(strict.status, CheckStatus::Fail);
+        assert_eq!(strict.summary.errors, 1);
+
+        let fatal = summarize(vec![CheckResult::fail("database", "failed")], false);
+        assert_eq!(fatal.status, CheckStatus::Fail);
+    }
+
+    fn daemon_with_pid(dir: &tempfile::TempDir, pid: u32) -> crate::daemon::McpDaemon {
+        let pid_file = dir.path().join("mnemosyne.pid");
+        std::fs::write(&pid_file, pid.to_string()).unwrap();
+        crate::daemon::McpDaemon::with_config(crate::daemon::DaemonConfig {
+            pid_file,
+            log_file: dir.path().join("mnemosyne.log"),
+            max_log_size: 1024,
+            db_path: None,
+        })
+    }
+
+    #[test]
+    fn test_fix_removes_stale_daemon_pid_file() {
+        let dir = tempfile::TempDir::new().unwrap();
+        // Above any kernel's pid_max, so never a live process
+        let daemon = daemon_with_pid(&dir, i32::MAX as u32);
+
+        let report = check_mcp_daemon_files(&daemon, false);
+        assert_eq!(report.status, CheckStatus::Warn);
+        assert!(daemon.pid_file().exists());
+
+        let fixed = check_mcp_daemon_files(&daemon, true);
+        assert_eq!(fixed.status, CheckStatus::Pass);
+        assert!(fixed.message.contains("Removed"));
+        assert!(!daemon.pid_file().exists());
+    }
+
+    #[test]
+    fn test_fix_keeps_live_daemon_pid_file() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let daemon = daemon_with_pid(&dir, std::process::id());
+
+        let fixed = check_mcp_daemon_files(&daemon, true);
+        assert_eq!(fixed.status, CheckStatus::Pass);
+        assert!(fixed.message.contains("running"));
+        assert!(daemon.pid_file().exists());
+    }
+}
@@ -1,7 +1,8 @@
-//! Icon system with Nerd Font glyphs (Font Awesome) and ASCII fallbacks
+//! Icon system with Nerd Font glyphs (Font Awesome), emoji and ASCII fallbacks
 //!
 //! Uses Font Awesome icons from Nerd Fonts for maximum compatibility.
-//! Automatically detects terminal capabilities and falls back to ASCII.
+//! Detects terminal capabilities at runtime and falls back to emoji or ASCII.
+//! Every lookup goes through [`mode()`], which is resolved once per process.
 //!
 //! # Usage
 //!
@@ -14,8 +15,13 @@
 //!
 //! # Environment Variables
 //!
-//! - `NERD_FONTS=1`: Force Nerd Font mode
-//! - `NERD_FONTS_DISABLED=1`: Force ASCII mode
+//! - `MNEMOSYNE_ICONS=auto|nerd|ascii|emoji`: Force a mode (default `auto`)
+//! - `NERD_FONTS=1`: Force Nerd Font mode (when `MNEMOSYNE_ICONS` is `auto`)
+//! - `NERD_FONTS_DISABLED=1`: Force ASCII mode (when `MNEMOSYNE_ICONS` is `auto`)
+//!
+//! In `auto` mode, non-UTF-8 locales (`LC_ALL`, `LC_CTYPE`, `LANG`) and
+//! `TERM=dumb`/`TERM=linux` get ASCII, known Nerd Font terminals get Nerd
+//! Font glyphs, and other UTF-8 terminals with emoji support get emoji.
 //!
 //! # Font Installation
 //!
@@ -26,6 +32,38 @@
 
 use std::sync::OnceLock;
 
+/// Which icon set to render
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IconMode {
+    /// Nerd Font glyphs (needs a patched font)
+    Nerd,
+    /// Colored Unicode symbols that render on any UTF-8 terminal
+    Ascii,
+    /// Color emoji
+    Emoji,
+}
+
+impl IconMode {
+    /// Parse a `MNEMOSYNE_ICONS` value; `None` for `auto` or unrecognized values
+    fn from_env_value(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "nerd" => Some(IconMode::Nerd),
+            "ascii" => Some(IconMode::Ascii),
+            "emoji" => Some(IconMode::Emoji),
+            _ => None,
+        }
+    }
+
+    /// Icon set for this mode
+    pub fn icon_set(self) -> &'static IconSet {
+        match self {
+            IconMode::Nerd => &NERD_ICONS,
+            IconMode::Ascii => &ASCII_ICONS,
+            IconMode::Emoji => &EMOJI_ICONS,
+        }
+    }
+}
+
 /// Icon set with colored glyphs
 #[derive(Debug, Clone)]
 pub struct IconSet {
@@ -137,60 +175,117 @@ const ASCII_ICONS: IconSet = IconSet {
     star: "\x1b[33m★\x1b[0m",      // Black star (yellow)
 };
 
-/// Global icon set (initialized once at runtime)
-static ICONS: OnceLock<&'static IconSet> = OnceLock::new();
+/// Emoji icons (color comes from the emoji font, no ANSI codes)
+const EMOJI_ICONS: IconSet = IconSet {
+    // Status
+    success: "✅",
+    error: "❌",
+    warning: "⚠️",
+    info: "ℹ️",
+    ready: "⭐",
 
-/// Detect if terminal supports Nerd Fonts
+    // Actions
+    target: "🎯",
+    search: "🔍",
+    edit: "✏️",
+    save: "💾",
+    link: "🔗",
+    build: "🔧",
+    launch: "🚀",
+    sync: "🔄",
+    lightning: "⚡",
+
+    // Data
+    chart: "📊",
+    database: "🗄️",
+    folder: "📁",
+    trending: "📈",
+    brain: "🧠",
+
+    // System
+    gear: "⚙️",
+    lightbulb: "💡",
+    palette: "🎨",
+    clock: "🕐",
+    star: "🌟",
+};
+
+/// Global icon mode (resolved once at runtime)
+static MODE: OnceLock<IconMode> = OnceLock::new();
+
+/// Detect the icon mode from an environment lookup
 ///
-/// Checks environment variables and known terminal types.
-/// Conservative default (ASCII) to prevent rendering issues.
-fn supports_nerd_fonts() -> bool {
+/// `MNEMOSYNE_ICONS` wins over everything; in `auto` mode the legacy
+/// `NERD_FONTS` switches come next, then terminal heuristics. Unknown
+/// terminals get the conservative ASCII set.
+fn detect_mode(env: impl Fn(&str) -> Option<String>) -> IconMode {
+    if let Some(mode) = env("MNEMOSYNE_ICONS").and_then(|v| IconMode::from_env_value(&v)) {
+        return mode;
+    }
+
     // User override (set by install script or manually)
-    if let Ok(val) = std::env::var("NERD_FONTS") {
-        return val == "1" || val.eq_ignore_ascii_case("true");
+    if let Some(val) = env("NERD_FONTS") {
+        if val == "1" || val.eq_ignore_ascii_case("true") {
+            return IconMode::Nerd;
+        }
+        return IconMode::Ascii;
     }
 
     // Explicit disable
-    if std::env::var("NERD_FONTS_DISABLED").is_ok() {
-        return false;
+    if env("NERD_FONTS_DISABLED").is_some() {
+        return IconMode::Ascii;
+    }
+
+    // Neither glyphs nor emoji survive a non-UTF-8 locale or a bare console
+    let term = env("TERM").unwrap_or_default().to_lowercase();
+    if !utf8_locale(&env) || term == "dumb" || term == "linux" {
+        return IconMode::Ascii;
     }
 
     // Known terminals with good Nerd Font support
-    if let Ok(term_program) = std::env::var("TERM_PROGRAM") {
-        let term_lower = term_program.to_lowercase();
-        if term_lower.contains("iterm")
-            || term_lower.contains("wezterm")
-            || term_lower.contains("alacritty")
-            || term_lower.contains("kitty")
-        {
-            return true;
-        }
+    let term_program = env("TERM_PROGRAM").unwrap_or_default().to_lowercase();
+    if ["iterm", "wezterm", "alacritty", "kitty", "ghostty"]
+        .iter()
+        .any(|t| term_program.contains(t) || term.contains(t))
+    {
+        return IconMode::Nerd;
     }
 
-    // Check TERM variable for kitty/alacritty
-    if let Ok(term) = std::env::var("TERM") {
-        let term_lower = term.to_lowercase();
-        if term_lower.contains("kitty") || term_lower.contains("alacritty") {
-            return true;
-        }
+    // Terminals that render color emoji out of the box
+    if term_program.contains("apple_terminal")
+        || term_program.contains("vscode")
+        || env("WT_SESSION").is_some()
+    {
+        return IconMode::Emoji;
     }
 
     // Conservative default: use ASCII unless explicitly enabled
     // This prevents rendering issues on unsupported terminals
-    false
+    IconMode::Ascii
 }
 
-/// Get the active icon set (initialized on first call)
+/// Whether the locale is UTF-8, per POSIX precedence (`LC_ALL` > `LC_CTYPE` > `LANG`)
 ///
-/// Automatically detects Nerd Font support and returns appropriate set.
+/// An unset locale is treated as UTF-8, as on most modern systems.
+fn utf8_locale(env: &impl Fn(&str) -> Option<String>) -> bool {
+    ["LC_ALL", "LC_CTYPE", "LANG"]
+        .iter()
+        .find_map(|var| env(*var).filter(|v| !v.is_empty()))
+        .map(|locale| {
+            let lower = locale.to_lowercase();
+            lower.contains("utf-8") || lower.contains("utf8")
+        })
+        .unwrap_or(true)
+}
+
+/// Active icon mode (detected on first call)
+pub fn mode() -> IconMode {
+    *MODE.get_or_init(|| detect_mode(|var| std::env::var(var).ok()))
+}
+
+/// Get the active icon set
 pub fn icons() -> &'static IconSet {
-    ICONS.get_or_init(|| {
-        if supports_nerd_fonts() {
-            &NERD_ICONS
-        } else {
-            &ASCII_ICONS
-        }
-    })
+    mode().icon_set()
 }
 
 /// Status icons (success, error, warning, info, ready)
@@ -353,22 +448,103 @@ mod tests {
         assert!(!system::gear().is_empty());
     }
 
+    /// Environment lookup backed by a fixed list of variables
+    fn env(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars: Vec<(String, String)> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        move |name| vars.iter().find(|(k, _)| k == name).map(|(_, v)| v.clone())
+    }
+
     #[test]
-    fn test_nerd_font_detection() {
+    fn test_mode_detection() {
         // Just verify it doesn't panic
-        let _ = supports_nerd_fonts();
+        let _ = mode();
+        assert!(std::ptr::eq(icons(), mode().icon_set()));
     }
 
     #[test]
     fn test_env_override() {
         // Test NERD_FONTS=1 enables nerd fonts
-        std::env::set_var("NERD_FONTS", "1");
-        assert!(supports_nerd_fonts());
-        std::env::remove_var("NERD_FONTS");
+        assert_eq!(detect_mode(env(&[("NERD_FONTS", "1")])), IconMode::Nerd);
 
         // Test NERD_FONTS_DISABLED disables them
-        std::env::set_var("NERD_FONTS_DISABLED", "1");
-        assert!(!supports_nerd_fonts());
-        std::env::remove_var("NERD_FONTS_DISABLED");
+        assert_eq!(
+            detect_mode(env(&[
+                ("NERD_FONTS_DISABLED", "1"),
+                ("TERM_PROGRAM", "iTerm.app")
+            ])),
+            IconMode::Ascii
+        );
+    }
+
+    #[test]
+    fn test_mnemosyne_icons_forces_mode() {
+        // Every detection signal points at Nerd Fonts...
+        let nerd_terminal = [
+            ("NERD_FONTS", "1"),
+            ("TERM_PROGRAM", "WezTerm"),
+            ("TERM", "xterm-kitty"),
+            ("LANG", "en_US.UTF-8"),
+        ];
+        assert_eq!(detect_mode(env(&nerd_terminal)), IconMode::Nerd);
+
+        // ...but the explicit mode still wins
+        for (value, expected) in [
+            ("ascii", IconMode::Ascii),
+            ("EMOJI", IconMode::Emoji),
+            ("nerd", IconMode::Nerd),
+        ] {
+            let mut vars = nerd_terminal.to_vec();
+            vars.push(("MNEMOSYNE_ICONS", value));
+            assert_eq!(detect_mode(env(&vars)), expected, "{}", value);
+        }
+
+        // Even on a terminal detection would downgrade to ASCII
+        assert_eq!(
+            detect_mode(env(&[
+                ("MNEMOSYNE_ICONS", "nerd"),
+                ("TERM", "dumb"),
+                ("LC_ALL", "C")
+            ])),
+            IconMode::Nerd
+        );
+
+        // `auto` falls through to detection
+        assert_eq!(
+            detect_mode(env(&[
+                ("MNEMOSYNE_ICONS", "auto"),
+                ("TERM_PROGRAM", "kitty")
+            ])),
+            IconMode::Nerd
+        );
+    }
+
+    #[test]
+    fn test_auto_detection_heuristics() {
+        assert_eq!(detect_mode(env(&[])), IconMode::Ascii);
+        assert_eq!(
+            detect_mode(env(&[("TERM_PROGRAM", "Apple_Terminal")])),
+            IconMode::Emoji
+        );
+        assert_eq!(
+            detect_mode(env(&[("TERM_PROGRAM", "vscode"), ("LANG", "en_US.UTF-8")])),
+            IconMode::Emoji
+        );
+
+        // Non-UTF-8 locales and bare consoles get ASCII, LC_ALL first
+        assert_eq!(
+            detect_mode(env(&[
+                ("TERM_PROGRAM", "iTerm.app"),
+                ("LC_ALL", "C"),
+                ("LANG", "en_US.UTF-8")
+            ])),
+            IconMode::Ascii
+        );
+        assert_eq!(
+            detect_mode(env(&[("TERM", "linux"), ("TERM_PROGRAM", "kitty")])),
+            IconMode::Ascii
+        );
     }
 }
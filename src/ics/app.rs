@@ -8,6 +8,7 @@ use super::{
     attribution::{AttributionEntry, AttributionPanel, AttributionPanelState},
     diagnostics_panel::{DiagnosticsPanel, DiagnosticsPanelState},
     editor::{Diagnostic, EditorState, EditorWidget, IcsEditor, Movement, Position, Validator},
+    input::InputAction,
     memory_panel::{MemoryPanel, MemoryPanelState},
     proposals::{ChangeProposal, ProposalsPanel, ProposalsPanelState},
     semantic::{SemanticAnalysis, SemanticAnalyzer},
@@ -46,6 +47,13 @@ pub enum PanelType {
     Holes,
 }
 
+/// Namespace for persisted holes, stable across ICS sessions
+fn hole_namespace() -> Namespace {
+    Namespace::Project {
+        name: "ics".to_string(),
+    }
+}
+
 /// Main ICS application
 pub struct IcsApp {
     /// Configuration
@@ -148,9 +156,15 @@ impl IcsApp {
         proposal_queue: Option<ProposalQueue>,
         event_broadcaster: Option<crate::api::EventBroadcaster>,
     ) -> Self {
+        let mut editor = IcsEditor::new();
+        if !config.entity_gazetteer.is_empty() {
+            editor.set_entity_gazetteer(config.entity_gazetteer.clone());
+        }
+        editor.set_semantic_storage(Arc::clone(&storage));
+
         Self {
             config,
-            editor: IcsEditor::new(),
+            editor,
             editor_state: EditorState::default(),
             state: AppState::Running,
             status: "ICS | Ctrl+Q: quit | Ctrl+S: save | Ctrl+M: memories | Ctrl+N: next hole | Ctrl+H: holes list | Ctrl+P: proposals | Ctrl+D: diagnostics".to_string(),
@@ -235,6 +249,44 @@ impl IcsApp {
         Ok(())
     }
 
+    /// Path of the active buffer, which keys its persisted holes
+    fn document_key(&self) -> Option<String> {
+        self.editor
+            .active_buffer()
+            .path
+            .as_ref()
+            .map(|path| path.display().to_string())
+    }
+
+    /// Reattach holes the active document left unresolved when last closed
+    pub async fn reattach_persisted_holes(&mut self) -> Result<usize> {
+        let Some(document) = self.document_key() else {
+            return Ok(0);
+        };
+        let memories =
+            super::holes::load_persisted_holes(self.storage.as_ref(), &document, &hole_namespace())
+                .await?;
+        let reattached = self.hole_navigator.reattach(&memories);
+        if !memories.is_empty() {
+            self.status = format!(
+                "{} unresolved hole(s) from earlier sessions",
+                memories.len()
+            );
+        }
+        Ok(reattached)
+    }
+
+    /// Save the active document's unresolved holes as task memories
+    pub async fn persist_unresolved_holes(&mut self) -> Result<super::holes::PersistedHoles> {
+        let Some(document) = self.document_key() else {
+            return Ok(Default::default());
+        };
+        Ok(self
+            .hole_navigator
+            .persist_unresolved(self.storage.as_ref(), &document, &hole_namespace())
+            .await?)
+    }
+
     /// Show a specific panel (for --panel CLI flag)
     pub fn show_panel(&mut self, panel: PanelType) {
         match panel {
@@ -258,7 +310,7 @@ impl IcsApp {
                 let hole_count = self.hole_navigator.hole_count();
                 let unresolved = self.hole_navigator.unresolved_holes().len();
                 self.status = format!(
-                    "Holes: {} total, {} unresolved | Use Ctrl+N/Ctrl+Shift+N to navigate",
+                    "Holes: {} total, {} unresolved | Use Ctrl+N/Ctrl+Shift+N to navigate, Alt+]/Alt+[ for unresolved",
                     hole_count, unresolved
                 );
             }
@@ -794,6 +846,12 @@ impl IcsApp {
         })?;
         let event_loop = EventLoop::default();
 
+        if self.config.persist_unresolved_holes {
+            if let Err(e) = self.reattach_persisted_holes().await {
+                tracing::warn!("Could not load persisted holes: {}", e);
+            }
+        }
+
         // Main event loop
         loop {
             // Render UI
@@ -813,6 +871,17 @@ impl IcsApp {
             tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
         }
 
+        if self.config.persist_unresolved_holes {
+            match self.persist_unresolved_holes().await {
+                Ok(persisted) => tracing::debug!(
+                    "Persisted {} unresolved hole(s), archived {} resolved",
+                    persisted.stored.len(),
+                    persisted.archived.len()
+                ),
+                Err(e) => tracing::warn!("Could not persist unresolved holes: {}", e),
+            }
+        }
+
         Ok(())
     }
 
@@ -823,6 +892,11 @@ impl IcsApp {
                 self.state = AppState::Quitting;
             }
             TuiEvent::Key(key) => {
+                if let Some(action) = InputAction::for_key(&key) {
+                    self.run_input_action(action);
+                    return Ok(());
+                }
+
                 let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
                 let buffer = self.editor.active_buffer_mut();
 
@@ -958,14 +1032,7 @@ impl IcsApp {
 
                     // Text input
                     (KeyCode::Char(c), false) => {
-                        if let Err(e) = buffer.insert_at_cursor(&c.to_string()) {
-                            self.status = format!("Insert failed: {}", e);
-                        } else {
-                            self.trigger_semantic_analysis();
-                            self.run_validation();
-                            // Trigger completion on @ or # or continue existing completion
-                            self.trigger_completion().await;
-                        }
+                        self.insert_char(c).await;
                     }
 
                     // Enter - accept completion if visible, otherwise newline
@@ -1136,7 +1203,7 @@ impl IcsApp {
                         let hole_count = self.hole_navigator.hole_count();
                         let unresolved = self.hole_navigator.unresolved_holes().len();
                         self.status = format!(
-                            "Holes: {} total, {} unresolved | Use Ctrl+N/Ctrl+Shift+N to navigate",
+                            "Holes: {} total, {} unresolved | Use Ctrl+N/Ctrl+Shift+N to navigate, Alt+]/Alt+[ for unresolved",
                             hole_count, unresolved
                         );
 
@@ -1166,6 +1233,74 @@ impl IcsApp {
         Ok(())
     }
 
+    /// Insert a typed character at the cursor
+    async fn insert_char(&mut self, c: char) {
+        let buffer = self.editor.active_buffer_mut();
+        if let Err(e) = buffer.insert_at_cursor(&c.to_string()) {
+            self.status = format!("Insert failed: {}", e);
+        } else {
+            self.trigger_semantic_analysis();
+            self.run_validation();
+            // Trigger completion on @ or # or continue existing completion
+            self.trigger_completion().await;
+        }
+    }
+
+    /// Run an action bound to a key chord
+    fn run_input_action(&mut self, action: InputAction) {
+        match action {
+            InputAction::NextUnresolvedHole | InputAction::PreviousUnresolvedHole => {
+                self.jump_to_unresolved_hole(action == InputAction::NextUnresolvedHole)
+            }
+        }
+    }
+
+    /// Move the cursor to the next (or previous) unresolved hole and
+    /// report the strategies available for resolving it
+    fn jump_to_unresolved_hole(&mut self, forward: bool) {
+        let symbols: Vec<String> = self
+            .symbol_registry
+            .read()
+            .map(|registry| {
+                registry
+                    .all_symbols()
+                    .into_iter()
+                    .map(|symbol| symbol.name.clone())
+                    .collect()
+            })
+            .unwrap_or_default();
+        self.hole_navigator.set_symbol_context(symbols);
+        self.hole_navigator.set_memory_context(&self.memories);
+
+        let buffer = self.editor.active_buffer_mut();
+        let from = buffer.cursor.position;
+        let hole = if forward {
+            self.hole_navigator.next_unresolved(from)
+        } else {
+            self.hole_navigator.previous_unresolved(from)
+        }
+        .cloned();
+
+        let Some(hole) = hole else {
+            self.status = "No unresolved holes".to_string();
+            return;
+        };
+
+        buffer.cursor.position = Position {
+            line: hole.line,
+            column: hole.column,
+        };
+        self.completion_popup.hide();
+
+        let strategies = self.hole_navigator.suggest_resolutions(&hole);
+        self.status = format!(
+            "Hole: {} - {} ({} resolution strategies)",
+            hole.kind.icon(),
+            hole.name,
+            strategies.len()
+        );
+    }
+
     /// Render UI
     fn render(&mut self, terminal: &mut TerminalManager) -> Result<()> {
         let buffer = self.editor.active_buffer();
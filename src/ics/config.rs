@@ -2,7 +2,13 @@
 //!
 //! Configuration options for the Integrated Context Studio
 
+use crate::ics::semantic_highlighter::tier2_relational::GazetteerEntry;
+use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Project gazetteer file, under `.mnemosyne/`
+const GAZETTEER_FILE_NAME: &str = "entities.toml";
 
 /// ICS configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -24,6 +30,28 @@ pub struct IcsConfig {
 
     /// Read-only mode (prevents saving)
     pub read_only: bool,
+
+    /// Project terms tagged by entity recognition
+    #[serde(default)]
+    pub entity_gazetteer: Vec<GazetteerEntry>,
+
+    /// Save unresolved holes as task memories on close and reattach them
+    /// when the document is reopened
+    #[serde(default)]
+    pub persist_unresolved_holes: bool,
+}
+
+impl IcsConfig {
+    /// Load the gazetteer from the nearest `.mnemosyne/entities.toml` at or
+    /// above `project_dir`
+    ///
+    /// Without such a file the gazetteer is left empty.
+    pub fn with_project_gazetteer(mut self, project_dir: &Path) -> Result<Self> {
+        if let Some(path) = find_gazetteer(project_dir) {
+            self.entity_gazetteer = load_gazetteer(&path)?;
+        }
+        Ok(self)
+    }
 }
 
 impl Default for IcsConfig {
@@ -35,6 +63,66 @@ impl Default for IcsConfig {
             max_file_size: 1024 * 1024, // 1MB
             theme: "default".to_string(),
             read_only: false,
+            entity_gazetteer: Vec::new(),
+            persist_unresolved_holes: false,
         }
     }
 }
+
+/// Find the nearest `.mnemosyne/entities.toml` at or above `dir`
+fn find_gazetteer(dir: &Path) -> Option<PathBuf> {
+    dir.ancestors()
+        .map(|dir| dir.join(".mnemosyne").join(GAZETTEER_FILE_NAME))
+        .find(|path| path.is_file())
+}
+
+/// Parse a gazetteer file:
+///
+/// ```toml
+/// [[entity]]
+/// term = "Mnemosyne"
+/// type = "Organization"
+/// ```
+pub fn load_gazetteer(path: &Path) -> Result<Vec<GazetteerEntry>> {
+    #[derive(Deserialize)]
+    struct GazetteerFile {
+        #[serde(default, rename = "entity")]
+        entities: Vec<GazetteerEntry>,
+    }
+
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read gazetteer {}", path.display()))?;
+    let file: GazetteerFile = toml::from_str(&content)
+        .with_context(|| format!("Failed to parse gazetteer {}", path.display()))?;
+    Ok(file.entities)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ics::semantic_highlighter::tier2_relational::EntityType;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_project_gazetteer_found_from_subdirectory() {
+        let dir = TempDir::new().unwrap();
+        std::fs::create_dir_all(dir.path().join(".mnemosyne")).unwrap();
+        std::fs::create_dir_all(dir.path().join("docs")).unwrap();
+        std::fs::write(
+            dir.path().join(".mnemosyne").join(GAZETTEER_FILE_NAME),
+            "[[entity]]\nterm = \"OODA\"\ntype = \"Concept\"\n",
+        )
+        .unwrap();
+
+        let config = IcsConfig::default()
+            .with_project_gazetteer(&dir.path().join("docs"))
+            .unwrap();
+        assert_eq!(
+            config.entity_gazetteer,
+            vec![GazetteerEntry {
+                term: "OODA".to_string(),
+                entity_type: EntityType::Concept,
+            }]
+        );
+    }
+}
@@ -3,17 +3,19 @@
 //! Provides conflict-free collaborative editing for multi-agent context engineering
 
 use anyhow::{Context as AnyhowContext, Result};
-use automerge::{transaction::Transactable, AutoCommit, ObjType, ReadDoc};
+use automerge::{transaction::Transactable, AutoCommit, ObjType, PatchAction, ReadDoc};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::cell::RefCell;
 use std::collections::VecDeque;
 use std::fs;
 use std::path::PathBuf;
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 use super::{CursorState, Language, Movement};
-use crate::ics::semantic_highlighter::SemanticHighlightEngine;
+use crate::ics::semantic_highlighter::{tier2_relational::GazetteerEntry, SemanticHighlightEngine};
+use crate::storage::StorageBackend;
 
 /// Default maximum operations before forced compaction
 const DEFAULT_MAX_OPS_BEFORE_COMPACT: usize = 1_000;
@@ -21,6 +23,12 @@ const DEFAULT_MAX_OPS_BEFORE_COMPACT: usize = 1_000;
 /// Default time interval before compaction (10 minutes)
 const DEFAULT_COMPACT_INTERVAL: Duration = Duration::from_secs(600);
 
+/// Typed characters within this interval of each other form one undo unit
+const UNDO_COALESCE_WINDOW: Duration = Duration::from_secs(1);
+
+/// Maximum undo (and redo) units kept
+const MAX_UNDO_UNITS: usize = 100;
+
 /// Buffer identifier
 pub type BufferId = usize;
 
@@ -78,6 +86,28 @@ pub struct Attribution {
     pub range: (usize, usize),
 }
 
+/// Kind of a recorded local edit
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EditKind {
+    Insert,
+    Delete,
+}
+
+/// One undo unit: a local edit, kept in step with merged remote edits so it
+/// can be inverted without disturbing them
+#[derive(Debug, Clone)]
+struct EditUnit {
+    kind: EditKind,
+    /// Where the edit's text starts (insert) or used to start (delete)
+    pos: usize,
+    /// Text inserted or deleted
+    text: String,
+    /// Started by a single typed character, so later ones may coalesce
+    typing: bool,
+    /// Time of the latest edit in the unit
+    at: Instant,
+}
+
 /// CRDT-based text buffer
 pub struct CrdtBuffer {
     /// Buffer ID
@@ -107,11 +137,11 @@ pub struct CrdtBuffer {
     /// Change attributions
     attributions: Vec<Attribution>,
 
-    /// Undo/redo stack (document snapshots before operations)
-    /// We store full document state to enable reliable undo/redo
-    /// Limited to 100 snapshots to manage memory usage
-    undo_stack: VecDeque<Vec<u8>>,
-    redo_stack: VecDeque<Vec<u8>>,
+    /// Undo/redo stacks of local edits (newest last)
+    /// Undoing applies an inverse edit rather than restoring a snapshot, so
+    /// remote edits merged in the meantime survive
+    undo_stack: VecDeque<EditUnit>,
+    redo_stack: VecDeque<EditUnit>,
 
     /// Semantic highlighting engine (optional, enabled per buffer)
     /// Wrapped in RefCell to allow interior mutability for highlight_line calls
@@ -183,7 +213,7 @@ impl CrdtBuffer {
         self.attributions.push(Attribution {
             actor: self.local_actor,
             timestamp: Utc::now(),
-            range: (0, content.len()),
+            range: (0, content.chars().count()),
         });
 
         Ok(())
@@ -191,16 +221,54 @@ impl CrdtBuffer {
 
     /// Insert text at position
     pub fn insert(&mut self, pos: usize, text: &str) -> Result<()> {
-        // Save current document state for undo (snapshot BEFORE operation)
-        let snapshot = self.doc.save();
-        self.undo_stack.push_back(snapshot);
-        if self.undo_stack.len() > 100 {
-            self.undo_stack.pop_front();
+        if text.is_empty() {
+            return Ok(());
         }
-        // Clear redo stack since we're making a new change
-        self.redo_stack.clear();
 
-        // Insert text
+        self.splice_insert(pos, text)?;
+        self.record_edit(EditUnit {
+            kind: EditKind::Insert,
+            pos,
+            typing: is_typed(text),
+            text: text.to_string(),
+            at: Instant::now(),
+        });
+
+        // Track operation for compaction (P1-1)
+        self.ops_since_compact += 1;
+        self.maybe_compact()?;
+
+        Ok(())
+    }
+
+    /// Delete text at position
+    pub fn delete(&mut self, pos: usize, len: usize) -> Result<()> {
+        // Check bounds
+        let text_len = self.text_len()?;
+        if pos >= text_len || len == 0 {
+            return Ok(());
+        }
+
+        let delete_len = len.min(text_len - pos);
+
+        let deleted = self.splice_delete(pos, delete_len)?;
+        self.record_edit(EditUnit {
+            kind: EditKind::Delete,
+            pos,
+            typing: is_typed(&deleted),
+            text: deleted,
+            at: Instant::now(),
+        });
+
+        // Track operation for compaction (P1-1)
+        self.ops_since_compact += 1;
+        self.maybe_compact()?;
+
+        Ok(())
+    }
+
+    /// Insert into the document, with attribution and semantic scheduling
+    fn splice_insert(&mut self, pos: usize, text: &str) -> Result<()> {
         self.doc.splice_text(&self.text_id, pos, 0, text)?;
         self.dirty = true;
 
@@ -208,7 +276,7 @@ impl CrdtBuffer {
         self.attributions.push(Attribution {
             actor: self.local_actor,
             timestamp: Utc::now(),
-            range: (pos, pos + text.len()),
+            range: (pos, pos + text.chars().count()),
         });
 
         // Schedule semantic analysis for changed region
@@ -222,33 +290,14 @@ impl CrdtBuffer {
             }
         }
 
-        // Track operation for compaction (P1-1)
-        self.ops_since_compact += 1;
-        self.maybe_compact()?;
-
         Ok(())
     }
 
-    /// Delete text at position
-    pub fn delete(&mut self, pos: usize, len: usize) -> Result<()> {
-        // Check bounds
-        let text_len = self.text_len()?;
-        if pos >= text_len {
-            return Ok(());
-        }
-
-        let delete_len = len.min(text_len - pos);
-
-        // Save current document state for undo (snapshot BEFORE operation)
-        let snapshot = self.doc.save();
-        self.undo_stack.push_back(snapshot);
-        if self.undo_stack.len() > 100 {
-            self.undo_stack.pop_front();
-        }
-        // Clear redo stack since we're making a new change
-        self.redo_stack.clear();
+    /// Delete from the document, returning the deleted text
+    fn splice_delete(&mut self, pos: usize, delete_len: usize) -> Result<String> {
+        // Automerge positions count code points, not bytes
+        let deleted: String = self.text()?.chars().skip(pos).take(delete_len).collect();
 
-        // Delete text
         self.doc
             .splice_text(&self.text_id, pos, delete_len as isize, "")?;
         self.dirty = true;
@@ -281,11 +330,25 @@ impl CrdtBuffer {
             }
         }
 
-        // Track operation for compaction (P1-1)
-        self.ops_since_compact += 1;
-        self.maybe_compact()?;
+        Ok(deleted)
+    }
 
-        Ok(())
+    /// Tag known project terms in semantic highlighting
+    pub fn set_entity_gazetteer(&self, entries: &[GazetteerEntry]) {
+        if let Some(ref engine_cell) = self.semantic_engine {
+            let mut engine = engine_cell.borrow_mut();
+            let mut settings = engine.settings().clone();
+            settings.relational.gazetteer = entries.to_vec();
+            engine.update_settings(settings);
+        }
+    }
+
+    /// Give semantic highlighting access to stored memories
+    pub fn set_semantic_storage(&mut self, storage: Arc<dyn StorageBackend>) {
+        self.semantic_engine = self
+            .semantic_engine
+            .take()
+            .map(|engine_cell| RefCell::new(engine_cell.into_inner().with_storage(storage)));
     }
 
     /// Get text content
@@ -301,7 +364,26 @@ impl CrdtBuffer {
 
     /// Merge changes from another buffer/agent
     pub fn merge_changes(&mut self, changes: &[u8]) -> Result<()> {
+        let before = self.doc.get_heads();
         self.doc.load_incremental(changes)?;
+        let after = self.doc.get_heads();
+
+        // Keep local undo history in step with the remote edits
+        for patch in self.doc.diff(&before, &after) {
+            if patch.obj != self.text_id {
+                continue;
+            }
+            match patch.action {
+                PatchAction::SpliceText { index, value, .. } => {
+                    self.transform_history(index, value.make_string().chars().count(), 0)
+                }
+                PatchAction::DeleteSeq { index, length } => {
+                    self.transform_history(index, 0, length)
+                }
+                _ => {}
+            }
+        }
+
         self.dirty = true;
         Ok(())
     }
@@ -334,60 +416,148 @@ impl CrdtBuffer {
             return Err(anyhow::anyhow!("Text object not found in loaded state"));
         }
 
+        // Local edit history doesn't apply to a replaced document
+        self.undo_stack.clear();
+        self.redo_stack.clear();
+
         Ok(())
     }
 
-    /// Undo last operation
+    /// Undo the last local edit
     ///
-    /// Restores the document to the state before the last operation.
-    /// Returns true if undo was performed, false if undo stack is empty.
+    /// Applies the inverse edit at the edit's current position, so remote
+    /// edits merged since are kept. Returns true if undo was performed, false
+    /// if the undo stack is empty.
     pub fn undo(&mut self) -> Result<bool> {
-        // Check if there's anything to undo
-        if self.undo_stack.is_empty() {
+        let Some(unit) = self.undo_stack.pop_back() else {
             return Ok(false);
-        }
-
-        // Save current state to redo stack
-        let current_state = self.doc.save();
-        self.redo_stack.push_back(current_state);
-        if self.redo_stack.len() > 100 {
-            self.redo_stack.pop_front();
-        }
+        };
 
-        // Restore previous state from undo stack
-        if let Some(previous_state) = self.undo_stack.pop_back() {
-            self.load_state(&previous_state)?;
-            self.dirty = true;
-            Ok(true)
-        } else {
-            Ok(false)
-        }
+        let inverse = self.invert(unit)?;
+        push_bounded(&mut self.redo_stack, inverse);
+        self.maybe_compact()?;
+        Ok(true)
     }
 
-    /// Redo last undone operation
+    /// Redo the last undone edit
     ///
-    /// Re-applies an operation that was undone.
-    /// Returns true if redo was performed, false if redo stack is empty.
+    /// Returns true if redo was performed, false if the redo stack is empty
+    /// (a new local edit empties it).
     pub fn redo(&mut self) -> Result<bool> {
-        // Check if there's anything to redo
-        if self.redo_stack.is_empty() {
+        let Some(unit) = self.redo_stack.pop_back() else {
             return Ok(false);
-        }
+        };
+
+        let inverse = self.invert(unit)?;
+        push_bounded(&mut self.undo_stack, inverse);
+        self.maybe_compact()?;
+        Ok(true)
+    }
 
-        // Save current state to undo stack
-        let current_state = self.doc.save();
-        self.undo_stack.push_back(current_state);
-        if self.undo_stack.len() > 100 {
-            self.undo_stack.pop_front();
+    /// Apply the inverse of `unit`, returning the unit that reverts it again
+    fn invert(&mut self, unit: EditUnit) -> Result<EditUnit> {
+        let pos = unit.pos.min(self.text_len()?);
+
+        let kind = match unit.kind {
+            EditKind::Insert => {
+                let len = unit.text.chars().count().min(self.text_len()? - pos);
+                self.splice_delete(pos, len)?;
+                EditKind::Delete
+            }
+            EditKind::Delete => {
+                self.splice_insert(pos, &unit.text)?;
+                EditKind::Insert
+            }
+        };
+        self.ops_since_compact += 1;
+
+        Ok(EditUnit {
+            kind,
+            pos,
+            text: unit.text,
+            typing: false,
+            at: Instant::now(),
+        })
+    }
+
+    /// Push a local edit onto the undo stack, coalescing runs of typing
+    fn record_edit(&mut self, unit: EditUnit) {
+        // A new local edit invalidates redo
+        self.redo_stack.clear();
+
+        if let Some(last) = self.undo_stack.back_mut() {
+            let coalesce = last.kind == unit.kind
+                && last.typing
+                && unit.typing
+                && unit.at.duration_since(last.at) < UNDO_COALESCE_WINDOW;
+
+            if coalesce {
+                match unit.kind {
+                    // Typing on from the end of the run
+                    EditKind::Insert if unit.pos == last.pos + last.text.chars().count() => {
+                        last.text.push_str(&unit.text);
+                        last.at = unit.at;
+                        return;
+                    }
+                    // Backspace: the run grows leftwards
+                    EditKind::Delete if unit.pos + unit.text.chars().count() == last.pos => {
+                        last.text.insert_str(0, &unit.text);
+                        last.pos = unit.pos;
+                        last.at = unit.at;
+                        return;
+                    }
+                    // Forward delete at the same spot
+                    EditKind::Delete if unit.pos == last.pos => {
+                        last.text.push_str(&unit.text);
+                        last.at = unit.at;
+                        return;
+                    }
+                    _ => {}
+                }
+            }
         }
 
-        // Restore next state from redo stack
-        if let Some(next_state) = self.redo_stack.pop_back() {
-            self.load_state(&next_state)?;
-            self.dirty = true;
-            Ok(true)
-        } else {
-            Ok(false)
+        push_bounded(&mut self.undo_stack, unit);
+    }
+
+    /// Shift undo history past a merged remote edit
+    ///
+    /// `inserted` characters were inserted at `index`, or `deleted` characters
+    /// removed from it. Remote text deleted from a unit's insertion is dropped from
+    /// the unit; remote text inserted inside one cuts the unit short there,
+    /// so undoing it never deletes the remote text.
+    fn transform_history(&mut self, index: usize, inserted: usize, deleted: usize) {
+        for unit in self.undo_stack.iter_mut().chain(self.redo_stack.iter_mut()) {
+            let present = match unit.kind {
+                EditKind::Insert => unit.text.chars().count(),
+                EditKind::Delete => 0,
+            };
+
+            if inserted > 0 {
+                if index <= unit.pos {
+                    unit.pos += inserted;
+                } else if index < unit.pos + present {
+                    let cut = byte_offset(&unit.text, index - unit.pos);
+                    unit.text.truncate(cut);
+                }
+            }
+
+            if deleted > 0 {
+                let end = index + deleted;
+                if end <= unit.pos {
+                    unit.pos -= deleted;
+                } else if index < unit.pos + present.max(1) {
+                    // Overlap: remove the remotely deleted part of our text
+                    let start = index.max(unit.pos);
+                    let stop = end.min(unit.pos + present);
+                    if start < stop {
+                        let range = byte_offset(&unit.text, start - unit.pos)
+                            ..byte_offset(&unit.text, stop - unit.pos);
+                        unit.text.replace_range(range, "");
+                    }
+                    unit.pos = unit.pos.min(index);
+                }
+            }
         }
     }
 
@@ -817,6 +987,26 @@ impl CrdtBuffer {
     }
 }
 
+/// Whether an edit is a single typed character (newlines end a run)
+fn is_typed(text: &str) -> bool {
+    let mut chars = text.chars();
+    matches!((chars.next(), chars.next()), (Some(c), None) if c != '\n')
+}
+
+fn push_bounded(stack: &mut VecDeque<EditUnit>, unit: EditUnit) {
+    stack.push_back(unit);
+    if stack.len() > MAX_UNDO_UNITS {
+        stack.pop_front();
+    }
+}
+
+/// Byte offset of the `chars`-th character of `text` (its length past the end)
+fn byte_offset(text: &str, chars: usize) -> usize {
+    text.char_indices()
+        .nth(chars)
+        .map_or(text.len(), |(i, _)| i)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1034,5 +1224,99 @@ mod tests {
         // Verify content is identical
         assert_eq!(buffer.text().unwrap(), expected_text);
         assert_eq!(buffer.ops_since_compact, 0);
+
+        // Undo history survives compaction
+        assert!(buffer.undo().unwrap());
+        assert_eq!(buffer.text().unwrap(), "Helloworld!");
+    }
+
+    #[test]
+    fn test_undo_coalesces_typing() {
+        let mut buffer = CrdtBuffer::new(0, Actor::Human, None).unwrap();
+
+        // A paste is its own unit; the typed run after it is one unit
+        buffer.insert(0, "Hello\n").unwrap();
+        for (i, ch) in "world".chars().enumerate() {
+            buffer.insert(6 + i, &ch.to_string()).unwrap();
+        }
+        // Two backspaces form one unit
+        buffer.delete(10, 1).unwrap();
+        buffer.delete(9, 1).unwrap();
+        assert_eq!(buffer.text().unwrap(), "Hello\nwor");
+
+        assert!(buffer.undo().unwrap());
+        assert_eq!(buffer.text().unwrap(), "Hello\nworld");
+        assert!(buffer.undo().unwrap());
+        assert_eq!(buffer.text().unwrap(), "Hello\n");
+        assert!(buffer.undo().unwrap());
+        assert_eq!(buffer.text().unwrap(), "");
+        assert!(!buffer.undo().unwrap());
+
+        assert!(buffer.redo().unwrap());
+        assert!(buffer.redo().unwrap());
+        assert_eq!(buffer.text().unwrap(), "Hello\nworld");
+        assert!(buffer.redo().unwrap());
+        assert_eq!(buffer.text().unwrap(), "Hello\nwor");
+        assert!(!buffer.redo().unwrap());
+    }
+
+    #[test]
+    fn test_undo_keeps_interleaved_remote_edits() {
+        let mut local = CrdtBuffer::new(0, Actor::Human, None).unwrap();
+        local.insert(0, "shared text").unwrap();
+        let mut remote = CrdtBuffer::new(0, Actor::Optimizer, None).unwrap();
+        remote.load_state(&local.save_state()).unwrap();
+
+        local.insert(11, " edited").unwrap();
+        local.delete(0, 7).unwrap();
+
+        // A concurrent remote edit lands before the local ones
+        remote.insert(0, ">> ").unwrap();
+        local.merge_changes(&remote.get_changes().unwrap()).unwrap();
+        assert_eq!(local.text().unwrap(), ">> text edited");
+
+        // Each undo inverts only its own edit, at its shifted position
+        assert!(local.undo().unwrap());
+        assert_eq!(local.text().unwrap(), ">> shared text edited");
+        assert!(local.undo().unwrap());
+        assert_eq!(local.text().unwrap(), ">> shared text");
+
+        assert!(local.redo().unwrap());
+        assert_eq!(local.text().unwrap(), ">> shared text edited");
+    }
+
+    #[test]
+    fn test_undo_redo_multibyte_text() {
+        let mut buffer = CrdtBuffer::new(0, Actor::Human, None).unwrap();
+        buffer.insert(0, "café 🎉 ").unwrap();
+        buffer.insert(7, "日本語").unwrap();
+        for (i, ch) in "ñü".chars().enumerate() {
+            buffer.insert(10 + i, &ch.to_string()).unwrap();
+        }
+        buffer.delete(2, 3).unwrap();
+        assert_eq!(buffer.text().unwrap(), "ca🎉 日本語ñü");
+
+        assert!(buffer.undo().unwrap());
+        assert_eq!(buffer.text().unwrap(), "café 🎉 日本語ñü");
+        assert!(buffer.undo().unwrap());
+        assert_eq!(buffer.text().unwrap(), "café 🎉 日本語");
+        assert!(buffer.undo().unwrap());
+        assert_eq!(buffer.text().unwrap(), "café 🎉 ");
+
+        assert!(buffer.redo().unwrap());
+        assert!(buffer.redo().unwrap());
+        assert!(buffer.redo().unwrap());
+        assert_eq!(buffer.text().unwrap(), "ca🎉 日本語ñü");
+    }
+
+    #[test]
+    fn test_new_edit_invalidates_redo() {
+        let mut buffer = CrdtBuffer::new(0, Actor::Human, None).unwrap();
+        buffer.insert(0, "one").unwrap();
+        assert!(buffer.undo().unwrap());
+
+        buffer.insert(0, "two").unwrap();
+        assert!(!buffer.redo().unwrap());
+        assert_eq!(buffer.text().unwrap(), "two");
     }
 }
@@ -22,22 +22,29 @@ pub use syntax::{HighlightKind, Language};
 pub use validation::{Diagnostic, Severity, Validator};
 pub use widget::{EditorState, EditorWidget};
 
+use crate::ics::semantic_highlighter::tier2_relational::GazetteerEntry;
+use crate::storage::StorageBackend;
+use anyhow::{bail, Result};
 use std::collections::HashMap;
 use std::path::PathBuf;
+use std::sync::Arc;
 
 /// Main editor managing multiple buffers
 ///
 /// # Invariants
 ///
 /// - `active_buffer` always refers to a buffer that exists in `buffers`
-/// - Buffer 0 always exists (created on initialization)
-/// - Buffers are never removed (only added)
+/// - Buffer 0 always exists (created on initialization, never closed)
 ///
 /// These invariants ensure that `active_buffer()` and `active_buffer_mut()` never panic.
 pub struct IcsEditor {
     buffers: HashMap<BufferId, CrdtBuffer>,
     active_buffer: BufferId,
     next_buffer_id: usize,
+    /// Project terms applied to every buffer's highlighting
+    entity_gazetteer: Vec<GazetteerEntry>,
+    /// Memory store given to every buffer's highlighting
+    semantic_storage: Option<Arc<dyn StorageBackend>>,
 }
 
 impl IcsEditor {
@@ -47,6 +54,8 @@ impl IcsEditor {
             buffers: HashMap::new(),
             active_buffer: 0,
             next_buffer_id: 1,
+            entity_gazetteer: Vec::new(),
+            semantic_storage: None,
         };
 
         // Create initial empty buffer (maintains invariant: buffer 0 always exists)
@@ -62,7 +71,13 @@ impl IcsEditor {
         let id = self.next_buffer_id;
         self.next_buffer_id += 1;
 
-        let buffer = CrdtBuffer::new(id, Actor::Human, path).expect("Failed to create buffer");
+        let mut buffer = CrdtBuffer::new(id, Actor::Human, path).expect("Failed to create buffer");
+        if !self.entity_gazetteer.is_empty() {
+            buffer.set_entity_gazetteer(&self.entity_gazetteer);
+        }
+        if let Some(ref storage) = self.semantic_storage {
+            buffer.set_semantic_storage(Arc::clone(storage));
+        }
         self.buffers.insert(id, buffer);
 
         id
@@ -100,6 +115,55 @@ impl IcsEditor {
         self.buffers.get_mut(&id)
     }
 
+    /// Tag known project terms in all current and future buffers
+    pub fn set_entity_gazetteer(&mut self, entries: Vec<GazetteerEntry>) {
+        for buffer in self.buffers.values() {
+            buffer.set_entity_gazetteer(&entries);
+        }
+        self.entity_gazetteer = entries;
+    }
+
+    /// Check highlighting in all current and future buffers against stored
+    /// memories
+    pub fn set_semantic_storage(&mut self, storage: Arc<dyn StorageBackend>) {
+        for buffer in self.buffers.values_mut() {
+            buffer.set_semantic_storage(Arc::clone(&storage));
+        }
+        self.semantic_storage = Some(storage);
+    }
+
+    /// Close a buffer
+    ///
+    /// Buffer 0 can't be closed, so a buffer always remains. Closing the
+    /// active buffer activates its neighbour (the next lower ID). A buffer
+    /// with unsaved changes is only closed with `force`.
+    pub fn close_buffer(&mut self, id: BufferId, force: bool) -> Result<()> {
+        let Some(buffer) = self.buffers.get(&id) else {
+            bail!("No buffer with ID {}", id);
+        };
+        if id == 0 {
+            bail!("Cannot close buffer 0; the editor always keeps one buffer open");
+        }
+        if buffer.dirty && !force {
+            bail!("Buffer {} has unsaved changes", id);
+        }
+
+        self.buffers.remove(&id);
+
+        if self.active_buffer == id {
+            // Buffer 0 remains, so there is always a lower neighbour
+            self.active_buffer = self
+                .buffers
+                .keys()
+                .copied()
+                .filter(|&b| b < id)
+                .max()
+                .unwrap_or(0);
+        }
+
+        Ok(())
+    }
+
     /// Set active buffer
     pub fn set_active_buffer(&mut self, id: BufferId) {
         if self.buffers.contains_key(&id) {
@@ -1,18 +1,45 @@
 //! Typed Holes Navigation and Management
 //!
 //! Provides navigation and AI-assisted resolution for typed holes:
-//! - Jump to next/previous hole (]h, [h)
+//! - Jump to next/previous unresolved hole (Alt+], Alt+[)
 //! - Go to hole by index (gh)
 //! - List all holes with context
 //! - AI suggestions for hole resolution
+//! - Resolution strategies drawn from known symbols and memories
+//! - Unresolved holes persisted as task memories and reattached on re-open
 //!
 //! Holes track ambiguities, contradictions, undefined references,
 //! and incomplete specifications in ICS documents.
 
+use crate::error::Result;
 use crate::ics::editor::Position;
 use crate::ics::semantic::{HoleKind, TypedHole};
+use crate::ics::symbols::SymbolRegistry;
+use crate::storage::{MemoryFilter, MemorySortOrder, StorageBackend};
+use crate::types::{MemoryId, MemoryNote, MemoryType, Namespace};
+use chrono::Utc;
 use std::collections::HashMap;
 
+/// Maximum edit distance for a known symbol to count as a likely match
+const SIMILAR_SYMBOL_DISTANCE: usize = 2;
+
+/// Tag on task memories recording a hole left unresolved when its document closed
+pub const UNRESOLVED_HOLE_TAG: &str = "unresolved-hole";
+
+/// Low enough that persisted holes don't crowd out decisions in recall
+const PERSISTED_HOLE_IMPORTANCE: u8 = 3;
+
+/// Most persisted holes loaded for one document
+const MAX_PERSISTED_HOLES: usize = 500;
+
+const HOLE_KINDS: [HoleKind; 5] = [
+    HoleKind::Unknown,
+    HoleKind::Ambiguous,
+    HoleKind::Undefined,
+    HoleKind::Contradiction,
+    HoleKind::Incomplete,
+];
+
 /// Hole navigation tracker
 pub struct HoleNavigator {
     /// All holes in document (sorted by position)
@@ -23,6 +50,61 @@ pub struct HoleNavigator {
 
     /// Hole resolution history
     resolutions: HashMap<String, HoleResolution>,
+
+    /// Known symbol names, for symbol-based suggestions
+    known_symbols: Vec<String>,
+
+    /// Memories in view, for memory-based suggestions
+    memories: Vec<MemoryContext>,
+
+    /// Whether holes have come from analysis, so absent ones are gone
+    analyzed: bool,
+
+    /// Holes this document left unresolved in earlier sessions
+    persisted: Vec<PersistedHole>,
+}
+
+/// A hole recorded as a task memory
+#[derive(Debug, Clone)]
+struct PersistedHole {
+    memory_id: MemoryId,
+    name: String,
+    kind: HoleKind,
+}
+
+impl PersistedHole {
+    /// Read back a memory made by [`hole_memory`]
+    fn from_memory(memory: &MemoryNote) -> Option<Self> {
+        let kind = HOLE_KINDS
+            .into_iter()
+            .find(|kind| memory.tags.contains(&kind_tag(*kind)))?;
+        Some(Self {
+            memory_id: memory.id,
+            name: memory.keywords.first()?.clone(),
+            kind,
+        })
+    }
+
+    fn matches(&self, hole: &TypedHole) -> bool {
+        self.name == hole.name && self.kind == hole.kind
+    }
+}
+
+/// What [`HoleNavigator::persist_unresolved`] changed
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PersistedHoles {
+    /// Task memories created for newly unresolved holes
+    pub stored: Vec<MemoryId>,
+    /// Task memories archived because their hole was resolved
+    pub archived: Vec<MemoryId>,
+}
+
+/// The parts of a memory used to match it against a hole
+#[derive(Debug, Clone)]
+struct MemoryContext {
+    id: MemoryId,
+    /// Lowercased summary and keywords
+    terms: Vec<String>,
 }
 
 /// Resolution status for a hole
@@ -59,6 +141,12 @@ pub enum ResolutionStrategy {
     /// Complete incomplete specification
     Complete,
 
+    /// Replace with an existing symbol of a similar name
+    UseSymbol(String),
+
+    /// Resolve from a stored memory that covers the term
+    UseMemory(MemoryId),
+
     /// Skip (defer resolution)
     Skip,
 }
@@ -70,9 +158,32 @@ impl HoleNavigator {
             holes: Vec::new(),
             current_index: None,
             resolutions: HashMap::new(),
+            known_symbols: Vec::new(),
+            memories: Vec::new(),
+            analyzed: false,
+            persisted: Vec::new(),
         }
     }
 
+    /// Set the symbol names that resolution suggestions may point to
+    pub fn set_symbol_context(&mut self, symbols: impl IntoIterator<Item = String>) {
+        self.known_symbols = symbols.into_iter().collect();
+    }
+
+    /// Set the memories that resolution suggestions may draw on
+    pub fn set_memory_context(&mut self, memories: &[MemoryNote]) {
+        self.memories = memories
+            .iter()
+            .map(|memory| MemoryContext {
+                id: memory.id,
+                terms: std::iter::once(&memory.summary)
+                    .chain(&memory.keywords)
+                    .map(|term| term.to_lowercase())
+                    .collect(),
+            })
+            .collect();
+    }
+
     /// Update holes from semantic analysis
     pub fn update_holes(&mut self, holes: Vec<TypedHole>) {
         // Sort holes by position (line, then column)
@@ -80,6 +191,7 @@ impl HoleNavigator {
         sorted_holes.sort_by(|a, b| a.line.cmp(&b.line).then_with(|| a.column.cmp(&b.column)));
 
         self.holes = sorted_holes;
+        self.analyzed = true;
 
         // Reset current index if out of bounds
         if let Some(idx) = self.current_index {
@@ -142,6 +254,58 @@ impl HoleNavigator {
         self.holes.last()
     }
 
+    /// Jump to the next unresolved hole after `from`, wrapping around
+    ///
+    /// Holes with an accepted resolution are skipped.
+    pub fn next_unresolved(&mut self, from: Position) -> Option<&TypedHole> {
+        let unresolved: Vec<usize> = self.unresolved_indices().collect();
+        let idx = unresolved
+            .iter()
+            .copied()
+            .find(|&idx| Self::is_after(&self.holes[idx], from))
+            .or_else(|| unresolved.first().copied())?;
+
+        self.current_index = Some(idx);
+        self.holes.get(idx)
+    }
+
+    /// Jump to the previous unresolved hole before `from`, wrapping around
+    pub fn previous_unresolved(&mut self, from: Position) -> Option<&TypedHole> {
+        let unresolved: Vec<usize> = self.unresolved_indices().collect();
+        let idx = unresolved
+            .iter()
+            .rev()
+            .copied()
+            .find(|&idx| Self::is_before(&self.holes[idx], from))
+            .or_else(|| unresolved.last().copied())?;
+
+        self.current_index = Some(idx);
+        self.holes.get(idx)
+    }
+
+    fn is_after(hole: &TypedHole, pos: Position) -> bool {
+        hole.line > pos.line || (hole.line == pos.line && hole.column > pos.column)
+    }
+
+    fn is_before(hole: &TypedHole, pos: Position) -> bool {
+        hole.line < pos.line || (hole.line == pos.line && hole.column < pos.column)
+    }
+
+    /// Indices of unresolved holes, in document order
+    fn unresolved_indices(&self) -> impl Iterator<Item = usize> + '_ {
+        self.holes
+            .iter()
+            .enumerate()
+            .filter(|(_, hole)| !self.is_resolved(&hole.name))
+            .map(|(idx, _)| idx)
+    }
+
+    fn is_resolved(&self, hole_name: &str) -> bool {
+        self.resolutions
+            .get(hole_name)
+            .is_some_and(|res| res.accepted)
+    }
+
     /// Go to hole by index
     pub fn go_to_hole(&mut self, index: usize) -> Option<&TypedHole> {
         if index < self.holes.len() {
@@ -225,6 +389,51 @@ impl HoleNavigator {
         suggestions
     }
 
+    /// Resolution strategies for a hole, most specific first
+    ///
+    /// Known symbols with a similar name and memories whose summary or
+    /// keywords mention the hole come before the generic strategy for the
+    /// hole's kind; `Skip` is always offered last.
+    pub fn suggest_resolutions(&self, hole: &TypedHole) -> Vec<ResolutionStrategy> {
+        let name = hole.name.trim_start_matches(['@', '?']).to_lowercase();
+        let mut strategies = Vec::new();
+
+        if !name.is_empty() {
+            if matches!(
+                hole.kind,
+                HoleKind::Unknown | HoleKind::Undefined | HoleKind::Ambiguous
+            ) {
+                let mut similar: Vec<(usize, &String)> = self
+                    .known_symbols
+                    .iter()
+                    .filter_map(|symbol| {
+                        let distance =
+                            SymbolRegistry::levenshtein_distance(&symbol.to_lowercase(), &name);
+                        (distance <= SIMILAR_SYMBOL_DISTANCE).then_some((distance, symbol))
+                    })
+                    .collect();
+                similar.sort();
+                strategies.extend(
+                    similar
+                        .into_iter()
+                        .map(|(_, symbol)| ResolutionStrategy::UseSymbol(symbol.clone())),
+                );
+            }
+
+            strategies.extend(
+                self.memories
+                    .iter()
+                    .filter(|memory| memory.terms.iter().any(|term| term.contains(&name)))
+                    .map(|memory| ResolutionStrategy::UseMemory(memory.id)),
+            );
+        }
+
+        strategies.push(Self::suggest_strategy(&hole.kind));
+        strategies.push(ResolutionStrategy::Skip);
+        strategies.dedup();
+        strategies
+    }
+
     /// Suggest resolution strategy for hole kind
     fn suggest_strategy(kind: &HoleKind) -> ResolutionStrategy {
         match kind {
@@ -237,7 +446,26 @@ impl HoleNavigator {
     }
 
     /// Accept a resolution for a hole
+    ///
+    /// The hole then drops out of [`Self::next_unresolved`] and
+    /// [`Self::previous_unresolved`].
     pub fn accept_resolution(&mut self, hole_name: &str, resolution: String) {
+        if !self.resolutions.contains_key(hole_name) {
+            let Some(hole) = self.holes.iter().find(|h| h.name == hole_name) else {
+                return;
+            };
+            self.resolutions.insert(
+                hole_name.to_string(),
+                HoleResolution {
+                    hole_name: hole_name.to_string(),
+                    strategy: Self::suggest_strategy(&hole.kind),
+                    ai_suggestions: Vec::new(),
+                    selected_resolution: None,
+                    accepted: false,
+                },
+            );
+        }
+
         if let Some(res) = self.resolutions.get_mut(hole_name) {
             res.selected_resolution = Some(resolution);
             res.accepted = true;
@@ -251,13 +479,8 @@ impl HoleNavigator {
 
     /// Get all unresolved holes
     pub fn unresolved_holes(&self) -> Vec<&TypedHole> {
-        self.holes
-            .iter()
-            .filter(|hole| {
-                self.resolutions
-                    .get(&hole.name)
-                    .is_none_or(|res| !res.accepted)
-            })
+        self.unresolved_indices()
+            .map(|idx| &self.holes[idx])
             .collect()
     }
 
@@ -276,6 +499,73 @@ impl HoleNavigator {
     pub fn clear_resolutions(&mut self) {
         self.resolutions.clear();
     }
+
+    /// Reattach holes persisted when this document was last closed
+    ///
+    /// `memories` come from [`load_persisted_holes`]. Returns how many match
+    /// a current hole by name and kind; holes found by later analysis match
+    /// the rest.
+    pub fn reattach(&mut self, memories: &[MemoryNote]) -> usize {
+        self.persisted = memories
+            .iter()
+            .filter_map(PersistedHole::from_memory)
+            .collect();
+        self.holes
+            .iter()
+            .filter(|hole| self.persisted_memory(hole).is_some())
+            .count()
+    }
+
+    /// Task memory recording this hole from an earlier session, if any
+    pub fn persisted_memory(&self, hole: &TypedHole) -> Option<MemoryId> {
+        self.persisted
+            .iter()
+            .find(|persisted| persisted.matches(hole))
+            .map(|persisted| persisted.memory_id)
+    }
+
+    /// Persist unresolved holes in `document` as task memories
+    ///
+    /// Holes already persisted keep their memory. Persisted holes that have
+    /// since been resolved, or are no longer found by analysis, are archived
+    /// so they stop resurfacing.
+    pub async fn persist_unresolved(
+        &mut self,
+        storage: &dyn StorageBackend,
+        document: &str,
+        namespace: &Namespace,
+    ) -> Result<PersistedHoles> {
+        let mut outcome = PersistedHoles::default();
+        let unresolved: Vec<TypedHole> = self.unresolved_holes().into_iter().cloned().collect();
+
+        for hole in &unresolved {
+            if self.persisted_memory(hole).is_some() {
+                continue;
+            }
+            let memory = hole_memory(hole, document, namespace.clone());
+            storage.store_memory(&memory).await?;
+            self.persisted.push(PersistedHole {
+                memory_id: memory.id,
+                name: hole.name.clone(),
+                kind: hole.kind,
+            });
+            outcome.stored.push(memory.id);
+        }
+
+        // Before analysis runs there's no telling which earlier holes are gone
+        if self.analyzed {
+            let (open, resolved): (Vec<_>, Vec<_>) = std::mem::take(&mut self.persisted)
+                .into_iter()
+                .partition(|persisted| unresolved.iter().any(|hole| persisted.matches(hole)));
+            self.persisted = open;
+            for persisted in resolved {
+                storage.archive_memory(persisted.memory_id).await?;
+                outcome.archived.push(persisted.memory_id);
+            }
+        }
+
+        Ok(outcome)
+    }
 }
 
 impl Default for HoleNavigator {
@@ -284,6 +574,71 @@ impl Default for HoleNavigator {
     }
 }
 
+/// Task memory recording `hole` as unresolved in `document`
+///
+/// The hole's name is the first keyword and its kind a `hole-<kind>` tag, so
+/// [`HoleNavigator::reattach`] can match it against later analysis.
+pub fn hole_memory(hole: &TypedHole, document: &str, namespace: Namespace) -> MemoryNote {
+    let now = Utc::now();
+    MemoryNote {
+        id: MemoryId::new(),
+        namespace,
+        created_at: now,
+        updated_at: now,
+        content: format!(
+            "Unresolved {:?} hole `{}` in {} at line {}: {}",
+            hole.kind,
+            hole.name,
+            document,
+            hole.line + 1,
+            hole.context
+        ),
+        summary: format!("TODO: resolve `{}` in {}", hole.name, document),
+        keywords: vec![hole.name.clone()],
+        tags: vec![UNRESOLVED_HOLE_TAG.to_string(), kind_tag(hole.kind)],
+        context: hole.context.clone(),
+        memory_type: MemoryType::Task,
+        importance: PERSISTED_HOLE_IMPORTANCE,
+        confidence: 0.8,
+        links: Vec::new(),
+        related_files: vec![document.to_string()],
+        related_entities: Vec::new(),
+        access_count: 0,
+        last_accessed_at: now,
+        expires_at: None,
+        is_archived: false,
+        superseded_by: None,
+        embedding: None,
+        embedding_model: String::new(),
+    }
+}
+
+/// Holes persisted for `document` in `namespace` and not yet resolved
+pub async fn load_persisted_holes(
+    storage: &dyn StorageBackend,
+    document: &str,
+    namespace: &Namespace,
+) -> Result<Vec<MemoryNote>> {
+    let filter = MemoryFilter {
+        namespace: Some(namespace.clone()),
+        memory_type: Some(MemoryType::Task),
+        tags: vec![UNRESOLVED_HOLE_TAG.to_string()],
+        ..Default::default()
+    };
+    let memories = storage
+        .list_memories_filtered(&filter, MAX_PERSISTED_HOLES, MemorySortOrder::Recent)
+        .await?;
+    Ok(memories
+        .into_iter()
+        .filter(|memory| memory.related_files.iter().any(|file| file == document))
+        .collect())
+}
+
+/// Tag carrying a persisted hole's kind (`hole-ambiguous`, ...)
+fn kind_tag(kind: HoleKind) -> String {
+    format!("hole-{:?}", kind).to_lowercase()
+}
+
 /// Format hole for display
 pub fn format_hole(hole: &TypedHole, show_context: bool) -> String {
     let icon = hole.kind.icon();
@@ -322,6 +677,35 @@ pub fn format_hole_with_suggestions(hole: &TypedHole, suggestions: &[String]) ->
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::{MemoryType, Namespace};
+    use chrono::Utc;
+
+    fn create_test_memory(summary: &str) -> MemoryNote {
+        MemoryNote {
+            id: MemoryId::new(),
+            namespace: Namespace::Global,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            content: "Test memory content".to_string(),
+            summary: summary.to_string(),
+            keywords: vec![],
+            tags: vec![],
+            context: "Test context".to_string(),
+            memory_type: MemoryType::Reference,
+            importance: 5,
+            confidence: 0.9,
+            links: vec![],
+            related_files: vec![],
+            related_entities: vec![],
+            access_count: 0,
+            last_accessed_at: Utc::now(),
+            expires_at: None,
+            is_archived: false,
+            superseded_by: None,
+            embedding: None,
+            embedding_model: "test".to_string(),
+        }
+    }
 
     fn create_test_hole(name: &str, line: usize, column: usize, kind: HoleKind) -> TypedHole {
         TypedHole {
@@ -427,6 +811,91 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_next_unresolved_wraps_around() {
+        let mut nav = HoleNavigator::new();
+        nav.update_holes(vec![
+            create_test_hole("first", 1, 0, HoleKind::Unknown),
+            create_test_hole("second", 5, 10, HoleKind::Undefined),
+            create_test_hole("third", 10, 0, HoleKind::Ambiguous),
+        ]);
+
+        let end = Position {
+            line: 20,
+            column: 0,
+        };
+        assert_eq!(nav.next_unresolved(end).unwrap().name, "first");
+        assert_eq!(nav.current_index(), Some(0));
+
+        let start = Position { line: 0, column: 0 };
+        assert_eq!(nav.previous_unresolved(start).unwrap().name, "third");
+
+        // Sitting on a hole moves past it
+        let on_second = Position {
+            line: 5,
+            column: 10,
+        };
+        assert_eq!(nav.next_unresolved(on_second).unwrap().name, "third");
+        assert_eq!(nav.previous_unresolved(on_second).unwrap().name, "first");
+    }
+
+    #[test]
+    fn test_resolved_hole_leaves_cycle() {
+        let mut nav = HoleNavigator::new();
+        nav.update_holes(vec![
+            create_test_hole("first", 1, 0, HoleKind::Unknown),
+            create_test_hole("second", 5, 10, HoleKind::Undefined),
+            create_test_hole("third", 10, 0, HoleKind::Ambiguous),
+        ]);
+
+        // No suggestions were generated first; accepting still resolves it
+        nav.accept_resolution("second", "Defined in glossary".to_string());
+        assert_eq!(nav.unresolved_holes().len(), 2);
+
+        let start = Position { line: 0, column: 0 };
+        assert_eq!(nav.next_unresolved(start).unwrap().name, "first");
+        let on_first = Position { line: 1, column: 0 };
+        assert_eq!(nav.next_unresolved(on_first).unwrap().name, "third");
+        let on_third = Position {
+            line: 10,
+            column: 0,
+        };
+        assert_eq!(nav.previous_unresolved(on_third).unwrap().name, "first");
+
+        nav.accept_resolution("first", "Clarified".to_string());
+        nav.accept_resolution("third", "Clarified".to_string());
+        assert!(nav.next_unresolved(start).is_none());
+        assert!(nav.previous_unresolved(start).is_none());
+    }
+
+    #[test]
+    fn test_suggest_resolutions_from_context() {
+        let mut nav = HoleNavigator::new();
+        nav.set_symbol_context(vec!["orchestrator".to_string(), "reviewer".to_string()]);
+
+        let memory = create_test_memory("Why the orchestratr is spelled that way");
+        let memory_id = memory.id;
+        nav.set_memory_context(&[memory]);
+
+        let hole = create_test_hole("orchestratr", 2, 0, HoleKind::Undefined);
+        assert_eq!(
+            nav.suggest_resolutions(&hole),
+            vec![
+                ResolutionStrategy::UseSymbol("orchestrator".to_string()),
+                ResolutionStrategy::UseMemory(memory_id),
+                ResolutionStrategy::Define,
+                ResolutionStrategy::Skip,
+            ]
+        );
+
+        // Nothing in context: just the generic strategy
+        let hole = create_test_hole("widget", 3, 0, HoleKind::Incomplete);
+        assert_eq!(
+            nav.suggest_resolutions(&hole),
+            vec![ResolutionStrategy::Complete, ResolutionStrategy::Skip]
+        );
+    }
+
     #[test]
     fn test_holes_by_kind() {
         let mut nav = HoleNavigator::new();
@@ -457,4 +926,75 @@ mod tests {
         let with_context = format_hole(&hole, true);
         assert!(with_context.contains("Context:"));
     }
+
+    #[tokio::test]
+    async fn test_unresolved_holes_persist_and_reattach() {
+        let storage = crate::storage::test_utils::create_test_storage_with_embedded_schema()
+            .await
+            .expect("Failed to create storage");
+        let namespace = Namespace::Project {
+            name: "ics".to_string(),
+        };
+        let document = "docs/spec.md";
+
+        // First session: resolve one hole, leave two
+        let mut nav = HoleNavigator::new();
+        nav.update_holes(vec![
+            create_test_hole("cache", 1, 0, HoleKind::Ambiguous),
+            create_test_hole("retries", 4, 2, HoleKind::Undefined),
+            create_test_hole("owner", 7, 0, HoleKind::Incomplete),
+        ]);
+        nav.accept_resolution("owner", "Platform team".to_string());
+
+        let closed = nav
+            .persist_unresolved(storage.as_ref(), document, &namespace)
+            .await
+            .unwrap();
+        assert_eq!(closed.stored.len(), 2);
+        assert!(closed.archived.is_empty());
+
+        let persisted = load_persisted_holes(storage.as_ref(), document, &namespace)
+            .await
+            .unwrap();
+        assert_eq!(persisted.len(), 2);
+        for memory in &persisted {
+            assert_eq!(memory.memory_type, MemoryType::Task);
+            assert!(memory.tags.contains(&UNRESOLVED_HOLE_TAG.to_string()));
+            assert!(memory.importance < 5);
+        }
+        // Other documents' holes stay separate
+        assert!(
+            load_persisted_holes(storage.as_ref(), "docs/other.md", &namespace)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+
+        // Reopen: analysis finds the same holes, which reattach to their memories
+        let mut reopened = HoleNavigator::new();
+        reopened.update_holes(vec![
+            create_test_hole("cache", 2, 0, HoleKind::Ambiguous),
+            create_test_hole("retries", 5, 2, HoleKind::Undefined),
+        ]);
+        assert_eq!(reopened.reattach(&persisted), 2);
+        let cache = reopened.holes()[0].clone();
+        assert!(closed
+            .stored
+            .contains(&reopened.persisted_memory(&cache).unwrap()));
+
+        // Resolving one and closing again archives it without duplicating the other
+        reopened.accept_resolution("cache", "Read-through LRU".to_string());
+        let closed_again = reopened
+            .persist_unresolved(storage.as_ref(), document, &namespace)
+            .await
+            .unwrap();
+        assert!(closed_again.stored.is_empty());
+        assert_eq!(closed_again.archived.len(), 1);
+
+        let remaining = load_persisted_holes(storage.as_ref(), document, &namespace)
+            .await
+            .unwrap();
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].keywords, vec!["retries".to_string()]);
+    }
 }
@@ -1,5 +1,71 @@
-//! Input handling (stub)
+//! Input handling
 //!
-//! To be implemented in Phase 6
+//! Maps editor key chords to actions. The editor is non-modal and inserts
+//! typed characters directly, so bindings use a modifier and plain keys are
+//! never held back:
+//! - `Alt+]` jumps to the next unresolved typed hole
+//! - `Alt+[` jumps to the previous unresolved typed hole
 
-// Placeholder for input handling
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+/// Action bound to a key chord
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputAction {
+    /// Jump to the next unresolved hole (`Alt+]`)
+    NextUnresolvedHole,
+    /// Jump to the previous unresolved hole (`Alt+[`)
+    PreviousUnresolvedHole,
+}
+
+impl InputAction {
+    /// Action bound to `key`, if any
+    pub fn for_key(key: &KeyEvent) -> Option<Self> {
+        if !key.modifiers.contains(KeyModifiers::ALT)
+            || key
+                .modifiers
+                .intersects(KeyModifiers::CONTROL | KeyModifiers::SUPER)
+        {
+            return None;
+        }
+
+        match key.code {
+            KeyCode::Char(']') => Some(Self::NextUnresolvedHole),
+            KeyCode::Char('[') => Some(Self::PreviousUnresolvedHole),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(c: char, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent::new(KeyCode::Char(c), modifiers)
+    }
+
+    #[test]
+    fn test_hole_bindings() {
+        assert_eq!(
+            InputAction::for_key(&key(']', KeyModifiers::ALT)),
+            Some(InputAction::NextUnresolvedHole)
+        );
+        assert_eq!(
+            InputAction::for_key(&key('[', KeyModifiers::ALT)),
+            Some(InputAction::PreviousUnresolvedHole)
+        );
+    }
+
+    #[test]
+    fn test_plain_keys_are_text() {
+        // Markdown such as `[here](...)` must type through
+        for c in ['[', ']', 'h'] {
+            assert_eq!(InputAction::for_key(&key(c, KeyModifiers::NONE)), None);
+        }
+        assert_eq!(
+            InputAction::for_key(&key(']', KeyModifiers::ALT | KeyModifiers::CONTROL)),
+            None
+        );
+        assert_eq!(InputAction::for_key(&key('h', KeyModifiers::ALT)), None);
+    }
+}
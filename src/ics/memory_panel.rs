@@ -294,7 +294,7 @@ impl<'a> MemoryPanel<'a> {
                 let content_preview = truncate_at_char_boundary(&memory.content, 57);
 
                 let category = memory.tags.first().map(|s| s.as_str()).unwrap_or_else(|| {
-                    match memory.memory_type {
+                    match &memory.memory_type {
                         crate::types::MemoryType::ArchitectureDecision => "Architecture",
                         crate::types::MemoryType::CodePattern => "Pattern",
                         crate::types::MemoryType::BugFix => "BugFix",
@@ -313,6 +313,7 @@ impl<'a> MemoryPanel<'a> {
                         crate::types::MemoryType::TaskBreakdown => "TaskBreakdown",
                         crate::types::MemoryType::QualityChecklist => "Checklist",
                         crate::types::MemoryType::Clarification => "Clarification",
+                        crate::types::MemoryType::Custom(name) => name.as_str(),
                     }
                 });
 
@@ -355,7 +356,7 @@ impl<'a> MemoryPanel<'a> {
                 .tags
                 .first()
                 .map(|s| s.as_str())
-                .unwrap_or_else(|| match memory.memory_type {
+                .unwrap_or_else(|| match &memory.memory_type {
                     crate::types::MemoryType::ArchitectureDecision => "Architecture",
                     crate::types::MemoryType::CodePattern => "Pattern",
                     crate::types::MemoryType::BugFix => "BugFix",
@@ -374,6 +375,7 @@ impl<'a> MemoryPanel<'a> {
                     crate::types::MemoryType::TaskBreakdown => "TaskBreakdown",
                     crate::types::MemoryType::QualityChecklist => "Checklist",
                     crate::types::MemoryType::Clarification => "Clarification",
+                    crate::types::MemoryType::Custom(name) => name.as_str(),
                 });
 
         // Format memory details
@@ -47,7 +47,7 @@ pub use config::IcsConfig;
 pub use diagnostics_panel::{DiagnosticsPanel, DiagnosticsPanelState};
 pub use editor::IcsEditor;
 pub use events::{AnalysisEvent, EditorEvent, IcsEvent};
-pub use holes::{HoleNavigator, HoleResolution, ResolutionStrategy};
+pub use holes::{HoleNavigator, HoleResolution, PersistedHoles, ResolutionStrategy};
 pub use markdown_highlight::{HighlightSource, HighlightedSpan, MarkdownHighlighter};
 pub use memory_panel::{MemoryAction, MemoryPanel, MemoryPanelState};
 pub use proposals::{ChangeProposal, ProposalStatus, ProposalsPanel, ProposalsPanelState};
@@ -1,14 +1,22 @@
 //! Caching system for semantic analysis results
 
+use super::settings::AnalyticalSettings;
+use super::{Result as SemanticResult, SemanticError};
 use lru::LruCache;
-use std::collections::HashMap;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::fs;
 use std::hash::{Hash, Hasher};
 use std::num::NonZeroUsize;
 use std::ops::Range;
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, RwLock};
-use std::time::{Duration, Instant};
+use std::time::{Duration, Instant, SystemTime};
+use tracing::warn;
 
 /// Content-based hash for caching
+///
+/// Stable across runs of the same build, so it can key persisted results.
+/// A toolchain upgrade that changes the hasher only costs cache misses.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct ContentHash(u64);
 
@@ -56,12 +64,16 @@ impl<T> CachedResult<T> {
 }
 
 /// LRU cache for relational analysis results
-pub struct RelationalCache<T> {
-    cache: RwLock<LruCache<Range<usize>, CachedResult<T>>>,
+///
+/// Keyed by byte range unless another key is given; paragraph results are
+/// keyed by [`ContentHash`], so they stay usable when an edit elsewhere
+/// shifts their offsets.
+pub struct RelationalCache<T, K = Range<usize>> {
+    cache: RwLock<LruCache<K, CachedResult<T>>>,
     ttl: Duration,
 }
 
-impl<T: Clone> RelationalCache<T> {
+impl<T: Clone, K: Hash + Eq> RelationalCache<T, K> {
     pub fn new(capacity: usize, ttl_seconds: u64) -> Self {
         Self {
             cache: RwLock::new(LruCache::new(NonZeroUsize::new(capacity).unwrap())),
@@ -70,9 +82,9 @@ impl<T: Clone> RelationalCache<T> {
     }
 
     /// Get cached result
-    pub fn get(&self, range: &Range<usize>) -> Option<CachedResult<T>> {
+    pub fn get(&self, key: &K) -> Option<CachedResult<T>> {
         let mut cache = self.cache.write().ok()?;
-        cache.get(range).and_then(|result| {
+        cache.get(key).and_then(|result| {
             if result.is_valid(self.ttl) {
                 Some(result.clone())
             } else {
@@ -82,9 +94,9 @@ impl<T: Clone> RelationalCache<T> {
     }
 
     /// Insert result into cache
-    pub fn insert(&self, range: Range<usize>, result: CachedResult<T>) {
+    pub fn insert(&self, key: K, result: CachedResult<T>) {
         if let Ok(mut cache) = self.cache.write() {
-            cache.put(range, result);
+            cache.put(key, result);
         }
     }
 
@@ -95,6 +107,35 @@ impl<T: Clone> RelationalCache<T> {
         }
     }
 
+    /// Get cache statistics
+    pub fn stats(&self) -> CacheStats {
+        if let Ok(cache) = self.cache.read() {
+            CacheStats {
+                size: cache.len(),
+                capacity: cache.cap().get(),
+            }
+        } else {
+            CacheStats {
+                size: 0,
+                capacity: 0,
+            }
+        }
+    }
+}
+
+impl<T: Clone> RelationalCache<T, ContentHash> {
+    /// Get cached result by content (computes hash)
+    pub fn get_by_content(&self, content: &str) -> Option<CachedResult<T>> {
+        self.get(&ContentHash::from_content(content))
+    }
+
+    /// Insert with content (computes hash)
+    pub fn insert_with_content(&self, content: &str, result: CachedResult<T>) {
+        self.insert(ContentHash::from_content(content), result);
+    }
+}
+
+impl<T: Clone> RelationalCache<T> {
     /// Invalidate cache entries overlapping with range
     pub fn invalidate_range(&self, range: &Range<usize>) {
         if let Ok(mut cache) = self.cache.write() {
@@ -111,21 +152,6 @@ impl<T: Clone> RelationalCache<T> {
             }
         }
     }
-
-    /// Get cache statistics
-    pub fn stats(&self) -> CacheStats {
-        if let Ok(cache) = self.cache.read() {
-            CacheStats {
-                size: cache.len(),
-                capacity: cache.cap().get(),
-            }
-        } else {
-            CacheStats {
-                size: 0,
-                capacity: 0,
-            }
-        }
-    }
 }
 
 /// Check if two ranges overlap
@@ -133,23 +159,100 @@ fn ranges_overlap(a: &Range<usize>, b: &Range<usize>) -> bool {
     a.start < b.end && b.start < a.end
 }
 
-/// Content-hash based cache for analytical results
+/// Default number of analytical results kept
+pub const DEFAULT_ANALYTICAL_CAPACITY: usize = 500;
+
+/// Content-hash based LRU cache for analytical results
+///
+/// Optionally backed by a directory with one JSON file per content hash.
+/// Entries are loaded at startup (most recently written first), written
+/// through on insert, and deleted when evicted. Since results are keyed by
+/// content they stay valid across runs; a loaded entry starts a fresh TTL.
 pub struct AnalyticalCache<T> {
-    cache: Arc<RwLock<HashMap<ContentHash, CachedResult<T>>>>,
+    cache: Arc<RwLock<LruCache<ContentHash, CachedResult<T>>>>,
     ttl: Duration,
+    store_dir: Option<PathBuf>,
 }
 
-impl<T: Clone> AnalyticalCache<T> {
+/// One analytical result as written to disk
+#[derive(Serialize, Deserialize)]
+struct PersistedResult<T> {
+    hash: u64,
+    /// Nanoseconds since the Unix epoch, for restoring LRU order
+    stored_at: u64,
+    confidence: f32,
+    data: T,
+}
+
+impl<T: Clone + Serialize + DeserializeOwned> AnalyticalCache<T> {
     pub fn new(ttl_seconds: u64) -> Self {
+        Self::with_capacity(DEFAULT_ANALYTICAL_CAPACITY, ttl_seconds)
+    }
+
+    /// Create an in-memory cache holding at most `capacity` results
+    pub fn with_capacity(capacity: usize, ttl_seconds: u64) -> Self {
+        let capacity = NonZeroUsize::new(capacity).unwrap_or(NonZeroUsize::MIN);
         Self {
-            cache: Arc::new(RwLock::new(HashMap::new())),
+            cache: Arc::new(RwLock::new(LruCache::new(capacity))),
             ttl: Duration::from_secs(ttl_seconds),
+            store_dir: None,
+        }
+    }
+
+    /// Persist results under `dir`, loading whatever earlier runs stored
+    ///
+    /// Unreadable entry files are skipped (and removed); entries beyond the
+    /// capacity are evicted oldest-first.
+    pub fn with_disk_store(mut self, dir: impl Into<PathBuf>) -> SemanticResult<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)
+            .map_err(|e| SemanticError::CacheError(format!("create {}: {}", dir.display(), e)))?;
+
+        let paths: Vec<PathBuf> = fs::read_dir(&dir)
+            .map_err(|e| SemanticError::CacheError(format!("read {}: {}", dir.display(), e)))?
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+            .collect();
+
+        let mut persisted = Vec::with_capacity(paths.len());
+        for path in paths {
+            let entry = fs::read(&path)
+                .ok()
+                .and_then(|bytes| serde_json::from_slice::<PersistedResult<T>>(&bytes).ok());
+            match entry {
+                Some(entry) => persisted.push(entry),
+                None => {
+                    warn!(
+                        "Dropping unreadable analytical cache entry {}",
+                        path.display()
+                    );
+                    let _ = fs::remove_file(&path);
+                }
+            }
+        }
+        // Oldest first, so the most recent end up most recently used
+        persisted.sort_by_key(|entry| entry.stored_at);
+
+        self.store_dir = Some(dir);
+        if let Ok(mut cache) = self.cache.write() {
+            for entry in persisted {
+                let hash = ContentHash(entry.hash);
+                let result = CachedResult::new(entry.data).with_confidence(entry.confidence);
+                if let Some((evicted, _)) = cache.push(hash, result) {
+                    if evicted != hash {
+                        self.remove_persisted(evicted);
+                    }
+                }
+            }
         }
+
+        Ok(self)
     }
 
     /// Get cached result by content hash
     pub fn get(&self, hash: ContentHash) -> Option<CachedResult<T>> {
-        let cache = self.cache.read().ok()?;
+        let mut cache = self.cache.write().ok()?;
         cache.get(&hash).and_then(|result| {
             if result.is_valid(self.ttl) {
                 Some(result.clone())
@@ -165,10 +268,29 @@ impl<T: Clone> AnalyticalCache<T> {
         self.get(hash)
     }
 
-    /// Insert result into cache
+    /// Insert result into cache, writing it through to disk if persistent
     pub fn insert(&self, hash: ContentHash, result: CachedResult<T>) {
+        let persisted = PersistedResult {
+            hash: hash.0,
+            stored_at: SystemTime::now()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .map_or(0, |d| d.as_nanos() as u64),
+            confidence: result.confidence,
+            data: result.data.clone(),
+        };
+
         if let Ok(mut cache) = self.cache.write() {
-            cache.insert(hash, result);
+            if let Some((evicted, _)) = cache.push(hash, result) {
+                if evicted != hash {
+                    self.remove_persisted(evicted);
+                }
+            }
+        }
+
+        if let Some(path) = self.entry_path(hash) {
+            if let Err(e) = write_atomic(&path, &persisted) {
+                warn!("Failed to persist analytical cache entry: {}", e);
+            }
         }
     }
 
@@ -181,24 +303,42 @@ impl<T: Clone> AnalyticalCache<T> {
     /// Clear expired entries
     pub fn clear_expired(&self) {
         if let Ok(mut cache) = self.cache.write() {
-            cache.retain(|_, result| result.is_valid(self.ttl));
+            let expired: Vec<ContentHash> = cache
+                .iter()
+                .filter(|(_, result)| !result.is_valid(self.ttl))
+                .map(|(hash, _)| *hash)
+                .collect();
+            for hash in expired {
+                cache.pop(&hash);
+                self.remove_persisted(hash);
+            }
         }
     }
 
-    /// Clear all entries
+    /// Clear all entries, including persisted ones
     pub fn clear(&self) {
         if let Ok(mut cache) = self.cache.write() {
             cache.clear();
         }
+
+        if let Some(ref dir) = self.store_dir {
+            if let Err(e) = clear_store_dir(dir) {
+                warn!(
+                    "Failed to clear analytical cache at {}: {}",
+                    dir.display(),
+                    e
+                );
+            }
+        }
     }
 
     /// Get cache statistics
     pub fn stats(&self) -> CacheStats {
         if let Ok(cache) = self.cache.read() {
-            let valid_count = cache.values().filter(|r| r.is_valid(self.ttl)).count();
+            let valid_count = cache.iter().filter(|(_, r)| r.is_valid(self.ttl)).count();
             CacheStats {
                 size: valid_count,
-                capacity: cache.len(),
+                capacity: cache.cap().get(),
             }
         } else {
             CacheStats {
@@ -207,6 +347,47 @@ impl<T: Clone> AnalyticalCache<T> {
             }
         }
     }
+
+    fn entry_path(&self, hash: ContentHash) -> Option<PathBuf> {
+        self.store_dir
+            .as_ref()
+            .map(|dir| dir.join(format!("{:016x}.json", hash.0)))
+    }
+
+    fn remove_persisted(&self, hash: ContentHash) {
+        if let Some(path) = self.entry_path(hash) {
+            let _ = fs::remove_file(path);
+        }
+    }
+}
+
+/// Write via a temp file and rename, so a crash never leaves half an entry
+fn write_atomic<T: Serialize>(path: &Path, value: &T) -> std::io::Result<()> {
+    let bytes = serde_json::to_vec(value)?;
+    let tmp_path = path.with_extension("tmp");
+    fs::write(&tmp_path, bytes)?;
+    fs::rename(&tmp_path, path)
+}
+
+/// Remove every persisted entry in a cache directory
+///
+/// Works without loading the cache, e.g. from a clear-cache command.
+pub fn clear_store_dir(dir: &Path) -> std::io::Result<()> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+        Err(e) => return Err(e),
+    };
+    for entry in entries {
+        let path = entry?.path();
+        if path
+            .extension()
+            .is_some_and(|ext| ext == "json" || ext == "tmp")
+        {
+            fs::remove_file(path)?;
+        }
+    }
+    Ok(())
 }
 
 /// Cache statistics
@@ -235,6 +416,9 @@ pub struct SemanticCache {
     /// Cache for Tier 2 relational analysis
     pub relational: RelationalCache<serde_json::Value>,
 
+    /// Cache for Tier 2 results of whole paragraphs, by content
+    pub paragraphs: RelationalCache<serde_json::Value, ContentHash>,
+
     /// Cache for Tier 3 analytical results
     pub analytical: AnalyticalCache<serde_json::Value>,
 }
@@ -243,13 +427,45 @@ impl SemanticCache {
     pub fn new(relational_capacity: usize, ttl_seconds: u64) -> Self {
         Self {
             relational: RelationalCache::new(relational_capacity, ttl_seconds),
+            paragraphs: RelationalCache::new(relational_capacity, ttl_seconds),
             analytical: AnalyticalCache::new(ttl_seconds),
         }
     }
 
-    /// Clear all caches
+    /// Create caches configured by Tier 3 settings
+    ///
+    /// With `persist_cache` enabled the analytical cache is backed by
+    /// [`SemanticCache::default_store_dir`]; if that can't be opened the
+    /// cache falls back to memory only.
+    pub fn from_settings(relational_capacity: usize, settings: &AnalyticalSettings) -> Self {
+        let ttl_seconds = settings.cache_ttl_seconds;
+        let in_memory = || AnalyticalCache::with_capacity(settings.cache_max_entries, ttl_seconds);
+
+        let store_dir = Self::default_store_dir().filter(|_| settings.persist_cache);
+        let analytical = match store_dir {
+            Some(dir) => in_memory().with_disk_store(dir).unwrap_or_else(|e| {
+                warn!("Analytical cache not persisted: {}", e);
+                in_memory()
+            }),
+            None => in_memory(),
+        };
+
+        Self {
+            relational: RelationalCache::new(relational_capacity, ttl_seconds),
+            paragraphs: RelationalCache::new(relational_capacity, ttl_seconds),
+            analytical,
+        }
+    }
+
+    /// Directory for persisted Tier 3 results
+    pub fn default_store_dir() -> Option<PathBuf> {
+        dirs::cache_dir().map(|dir| dir.join("mnemosyne").join("semantic_analysis"))
+    }
+
+    /// Clear all caches, including persisted Tier 3 results
     pub fn clear_all(&self) {
         self.relational.clear();
+        self.paragraphs.clear();
         self.analytical.clear();
     }
 
@@ -292,6 +508,15 @@ mod tests {
         assert_eq!(retrieved.unwrap().data, "test_data");
     }
 
+    #[test]
+    fn test_relational_cache_by_content() {
+        let cache: RelationalCache<String, ContentHash> = RelationalCache::new(10, 60);
+        cache.insert_with_content("Alice met Bob.", CachedResult::new("data".to_string()));
+
+        assert_eq!(cache.get_by_content("Alice met Bob.").unwrap().data, "data");
+        assert!(cache.get_by_content("Alice met Carol.").is_none());
+    }
+
     #[test]
     fn test_analytical_cache() {
         let cache = AnalyticalCache::new(60);
@@ -370,4 +595,69 @@ mod tests {
         assert!(!ranges_overlap(&(0..10), &(10..20))); // Adjacent (no overlap)
         assert!(!ranges_overlap(&(0..10), &(15..20))); // Separate
     }
+
+    #[test]
+    fn test_analytical_cache_lru_eviction() {
+        let cache = AnalyticalCache::with_capacity(2, 60);
+        cache.insert_with_content("a", CachedResult::new("A".to_string()));
+        cache.insert_with_content("b", CachedResult::new("B".to_string()));
+
+        // Touch "a" so "b" is least recently used
+        assert!(cache.get_by_content("a").is_some());
+        cache.insert_with_content("c", CachedResult::new("C".to_string()));
+
+        assert!(cache.get_by_content("a").is_some());
+        assert!(cache.get_by_content("b").is_none());
+        assert!(cache.get_by_content("c").is_some());
+        assert_eq!(cache.stats().capacity, 2);
+    }
+
+    #[test]
+    fn test_analytical_cache_persists_across_runs() {
+        let dir = tempfile::TempDir::new().unwrap();
+
+        let first = AnalyticalCache::with_capacity(10, 60)
+            .with_disk_store(dir.path())
+            .unwrap();
+        first.insert_with_content(
+            "The system must respond quickly.",
+            CachedResult::new(serde_json::json!({"segments": 2})).with_confidence(0.8),
+        );
+        drop(first);
+
+        let second: AnalyticalCache<serde_json::Value> = AnalyticalCache::with_capacity(10, 60)
+            .with_disk_store(dir.path())
+            .unwrap();
+        let cached = second
+            .get_by_content("The system must respond quickly.")
+            .unwrap();
+        assert_eq!(cached.data, serde_json::json!({"segments": 2}));
+        assert_eq!(cached.confidence, 0.8);
+    }
+
+    #[test]
+    fn test_analytical_cache_disk_eviction_and_clear() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let persisted = |dir: &Path| fs::read_dir(dir).unwrap().count();
+
+        let cache = AnalyticalCache::with_capacity(2, 60)
+            .with_disk_store(dir.path())
+            .unwrap();
+        for content in ["one", "two", "three"] {
+            cache.insert_with_content(content, CachedResult::new(content.to_string()));
+        }
+        // Evicted entries leave the disk too
+        assert_eq!(persisted(dir.path()), 2);
+
+        // A smaller capacity on reload keeps only the newest
+        let reloaded: AnalyticalCache<String> = AnalyticalCache::with_capacity(1, 60)
+            .with_disk_store(dir.path())
+            .unwrap();
+        assert_eq!(persisted(dir.path()), 1);
+        assert!(reloaded.get_by_content("three").is_some());
+
+        reloaded.clear();
+        assert_eq!(persisted(dir.path()), 0);
+        assert!(reloaded.get_by_content("three").is_none());
+    }
 }
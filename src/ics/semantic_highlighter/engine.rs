@@ -1,6 +1,6 @@
 //! Main semantic highlighting engine coordinating all three tiers
 
-use crate::LlmService;
+use crate::{storage::StorageBackend, LlmService};
 use ratatui::text::Line;
 use std::sync::Arc;
 use tokio::sync::mpsc;
@@ -68,7 +68,13 @@ impl SemanticHighlightEngine {
         settings: HighlightSettings,
         llm_service: Option<Arc<LlmService>>,
     ) -> Self {
-        let cache = Arc::new(SemanticCache::default());
+        // Only an engine that runs Tier 3 touches the on-disk analysis cache
+        let tier3_active = settings.enable_analytical && llm_service.is_some();
+        let cache = Arc::new(if tier3_active {
+            SemanticCache::from_settings(100, &settings.analytical)
+        } else {
+            SemanticCache::default()
+        });
 
         // Tier 1: Always enabled
         let structural = StructuralHighlighter::new();
@@ -105,6 +111,17 @@ impl SemanticHighlightEngine {
         }
     }
 
+    /// Give Tier 3 access to stored memories
+    ///
+    /// Used for contradiction checks against prior decisions when
+    /// `enable_memory_contradictions` is set; otherwise a no-op.
+    pub fn with_storage(mut self, storage: Arc<dyn StorageBackend>) -> Self {
+        self.analytical = self
+            .analytical
+            .map(|processor| processor.with_storage(storage));
+        self
+    }
+
     /// Highlight a single line of text
     ///
     /// This is the primary API for real-time highlighting.
@@ -170,11 +187,16 @@ impl SemanticHighlightEngine {
         self.cache.stats()
     }
 
-    /// Clear all caches
+    /// Clear all caches, including persisted Tier 3 results
     pub fn clear_caches(&self) {
         self.cache.clear_all();
     }
 
+    /// Current settings
+    pub fn settings(&self) -> &HighlightSettings {
+        &self.settings
+    }
+
     /// Update settings
     pub fn update_settings(&mut self, settings: HighlightSettings) {
         self.settings = settings;
@@ -231,6 +253,7 @@ impl SemanticHighlightEngine {
 pub struct EngineBuilder {
     settings: HighlightSettings,
     llm_service: Option<Arc<LlmService>>,
+    storage: Option<Arc<dyn StorageBackend>>,
 }
 
 impl EngineBuilder {
@@ -238,6 +261,7 @@ impl EngineBuilder {
         Self {
             settings: HighlightSettings::default(),
             llm_service: None,
+            storage: None,
         }
     }
 
@@ -251,8 +275,17 @@ impl EngineBuilder {
         self
     }
 
+    pub fn with_storage(mut self, storage: Arc<dyn StorageBackend>) -> Self {
+        self.storage = Some(storage);
+        self
+    }
+
     pub fn build(self) -> SemanticHighlightEngine {
-        SemanticHighlightEngine::with_settings(self.settings, self.llm_service)
+        let engine = SemanticHighlightEngine::with_settings(self.settings, self.llm_service);
+        match self.storage {
+            Some(storage) => engine.with_storage(storage),
+            None => engine,
+        }
     }
 }
 
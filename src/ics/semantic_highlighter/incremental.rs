@@ -50,6 +50,12 @@ impl DirtyRegions {
         self.regions.clear();
     }
 
+    /// Forget dirty regions lying entirely within `range`
+    pub fn clear_within(&mut self, range: &Range<usize>) {
+        self.regions
+            .retain(|r| r.start < range.start || r.end > range.end);
+    }
+
     /// Check if any region overlaps with given range
     pub fn overlaps(&self, range: &Range<usize>) -> bool {
         self.regions.iter().any(|r| ranges_overlap(r, range))
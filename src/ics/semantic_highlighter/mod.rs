@@ -94,6 +94,28 @@ pub enum SemanticError {
     #[error("LLM service error: {0}")]
     LlmError(String),
 
+    #[error("LLM request timed out: {0}")]
+    LlmTimeout(String),
+
+    #[error("Analysis cancelled")]
+    Cancelled,
+
     #[error("Invalid input: {0}")]
     InvalidInput(String),
 }
+
+impl SemanticError {
+    /// Wrap an error from an LLM call, keeping timeouts and cancellation typed
+    pub fn from_llm(err: crate::error::MnemosyneError) -> Self {
+        match err {
+            crate::error::MnemosyneError::LlmTimeout(_) => Self::LlmTimeout(err.to_string()),
+            crate::error::MnemosyneError::LlmCancelled => Self::Cancelled,
+            _ => Self::LlmError(err.to_string()),
+        }
+    }
+
+    /// Whether running the same analysis again may succeed
+    pub fn is_retryable(&self) -> bool {
+        !matches!(self, Self::Cancelled | Self::InvalidInput(_))
+    }
+}
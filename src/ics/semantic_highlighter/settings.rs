@@ -1,5 +1,7 @@
 //! Configuration and settings for semantic highlighting
 
+use super::cache::DEFAULT_ANALYTICAL_CAPACITY;
+use super::tier2_relational::GazetteerEntry;
 use serde::{Deserialize, Serialize};
 
 /// Main highlighting settings
@@ -63,6 +65,10 @@ pub struct RelationalSettings {
 
     /// Minimum confidence for entity recognition (0.0-1.0)
     pub min_entity_confidence: f32,
+
+    /// Project terms always tagged as entities
+    #[serde(default)]
+    pub gazetteer: Vec<GazetteerEntry>,
 }
 
 impl Default for RelationalSettings {
@@ -76,6 +82,7 @@ impl Default for RelationalSettings {
             enable_anaphora: true,
             max_coref_distance: 3,
             min_entity_confidence: 0.6,
+            gazetteer: Vec::new(),
         }
     }
 }
@@ -92,6 +99,17 @@ pub struct AnalyticalSettings {
     /// Enable contradiction detection
     pub enable_contradictions: bool,
 
+    /// Also check text against stored high-importance memories
+    ///
+    /// Costs a memory search and an extra API call per analysis, and needs
+    /// the engine to be given storage.
+    #[serde(default)]
+    pub enable_memory_contradictions: bool,
+
+    /// Minimum importance of memories checked for contradictions (1-10)
+    #[serde(default = "default_memory_contradiction_min_importance")]
+    pub memory_contradiction_min_importance: u8,
+
     /// Enable presupposition extraction
     pub enable_presuppositions: bool,
 
@@ -101,6 +119,10 @@ pub struct AnalyticalSettings {
     /// Maximum API calls per minute
     pub max_api_calls_per_minute: u32,
 
+    /// Seconds before an analysis API call is abandoned (and retried)
+    #[serde(default = "default_request_timeout_secs")]
+    pub request_timeout_secs: u64,
+
     /// Auto-analyze on idle
     pub auto_analyze_on_idle: bool,
 
@@ -109,6 +131,30 @@ pub struct AnalyticalSettings {
 
     /// Cache TTL in seconds
     pub cache_ttl_seconds: u64,
+
+    /// Persist analysis results to disk so restarts don't repeat API calls
+    #[serde(default = "default_persist_cache")]
+    pub persist_cache: bool,
+
+    /// Maximum cached analysis results (least recently used evicted first)
+    #[serde(default = "default_cache_max_entries")]
+    pub cache_max_entries: usize,
+}
+
+fn default_memory_contradiction_min_importance() -> u8 {
+    7
+}
+
+fn default_request_timeout_secs() -> u64 {
+    30
+}
+
+fn default_persist_cache() -> bool {
+    true
+}
+
+fn default_cache_max_entries() -> usize {
+    DEFAULT_ANALYTICAL_CAPACITY
 }
 
 impl Default for AnalyticalSettings {
@@ -117,12 +163,17 @@ impl Default for AnalyticalSettings {
             debounce_ms: 2000,
             enable_discourse: true,
             enable_contradictions: true,
+            enable_memory_contradictions: false,
+            memory_contradiction_min_importance: default_memory_contradiction_min_importance(),
             enable_presuppositions: true,
             enable_cross_reference: true,
             max_api_calls_per_minute: 10,
+            request_timeout_secs: default_request_timeout_secs(),
             auto_analyze_on_idle: false,
             cache_results: true,
             cache_ttl_seconds: 3600, // 1 hour
+            persist_cache: default_persist_cache(),
+            cache_max_entries: default_cache_max_entries(),
         }
     }
 }
@@ -177,4 +228,17 @@ mod tests {
         let deserialized: HighlightSettings = serde_json::from_str(&json).unwrap();
         assert_eq!(settings.enable_structural, deserialized.enable_structural);
     }
+
+    #[test]
+    fn test_analytical_settings_without_cache_fields() {
+        // Settings saved before disk persistence existed still load
+        let mut json = serde_json::to_value(AnalyticalSettings::default()).unwrap();
+        let object = json.as_object_mut().unwrap();
+        object.remove("persist_cache");
+        object.remove("cache_max_entries");
+
+        let settings: AnalyticalSettings = serde_json::from_value(json).unwrap();
+        assert!(settings.persist_cache);
+        assert_eq!(settings.cache_max_entries, DEFAULT_ANALYTICAL_CAPACITY);
+    }
 }
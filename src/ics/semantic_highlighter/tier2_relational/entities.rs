@@ -8,8 +8,11 @@
 //! - CONCEPT: Abstract technical concepts, methodologies
 //!
 //! Uses dictionary-based matching, capitalization patterns, and context clues.
+//! A user-supplied gazetteer of project terms (service names, acronyms) is
+//! matched on top, overriding overlapping low-confidence heuristic guesses.
 
 use crate::ics::semantic_highlighter::{
+    incremental::ranges_overlap,
     utils::EntityDictionaries,
     visualization::{HighlightSource, HighlightSpan, SpanMetadata},
     Result,
@@ -17,6 +20,10 @@ use crate::ics::semantic_highlighter::{
 use ratatui::style::{Color, Modifier, Style};
 use regex::Regex;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Confidence of gazetteer matches; heuristic matches below it lose overlaps
+const GAZETTEER_CONFIDENCE: f32 = 0.9;
 
 /// Entity type classification
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -61,12 +68,54 @@ pub struct Entity {
     pub confidence: f32,
 }
 
+/// A known project term and the entity type it is tagged with
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GazetteerEntry {
+    /// Term as it appears in text (matched case-sensitively, whole words)
+    pub term: String,
+    #[serde(rename = "type")]
+    pub entity_type: EntityType,
+}
+
+/// Compiled gazetteer: one alternation over all terms
+struct Gazetteer {
+    pattern: Regex,
+    types: HashMap<String, EntityType>,
+}
+
+impl Gazetteer {
+    fn new(entries: impl IntoIterator<Item = GazetteerEntry>) -> Option<Self> {
+        let types: HashMap<String, EntityType> = entries
+            .into_iter()
+            .filter(|e| !e.term.trim().is_empty())
+            .map(|e| (e.term.trim().to_string(), e.entity_type))
+            .collect();
+        if types.is_empty() {
+            return None;
+        }
+
+        // Longest terms first, so "Mnemosyne Core" beats "Mnemosyne"
+        let mut terms: Vec<&String> = types.keys().collect();
+        terms.sort_by(|a, b| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+        let alternation = terms
+            .iter()
+            .map(|t| regex::escape(t))
+            .collect::<Vec<_>>()
+            .join("|");
+        let pattern = Regex::new(&format!(r"\b(?:{})\b", alternation)).ok()?;
+
+        Some(Self { pattern, types })
+    }
+}
+
 /// Rule-based entity recognizer
 pub struct EntityRecognizer {
     /// Minimum confidence threshold
     threshold: f32,
     /// Patterns for entity detection
     patterns: EntityPatterns,
+    /// User-supplied project terms
+    gazetteer: Option<Gazetteer>,
 }
 
 /// Compiled regex patterns for entity detection
@@ -100,9 +149,16 @@ impl EntityRecognizer {
         Self {
             threshold: 0.5,
             patterns: EntityPatterns::new(),
+            gazetteer: None,
         }
     }
 
+    /// Tag known project terms (replaces any previous gazetteer)
+    pub fn with_gazetteer(mut self, entries: impl IntoIterator<Item = GazetteerEntry>) -> Self {
+        self.gazetteer = Gazetteer::new(entries);
+        self
+    }
+
     /// Set confidence threshold
     pub fn with_threshold(mut self, threshold: f32) -> Self {
         self.threshold = threshold.clamp(0.0, 1.0);
@@ -128,6 +184,14 @@ impl EntityRecognizer {
         // Detect concepts
         entities.extend(self.detect_concepts(text)?);
 
+        // Known project terms override overlapping low-confidence guesses
+        let known = self.detect_gazetteer_terms(text);
+        entities.retain(|e| {
+            e.confidence > GAZETTEER_CONFIDENCE
+                || !known.iter().any(|k| ranges_overlap(&e.range, &k.range))
+        });
+        entities.extend(known);
+
         // Filter by confidence threshold
         entities.retain(|e| e.confidence >= self.threshold);
 
@@ -163,6 +227,27 @@ impl EntityRecognizer {
             .collect()
     }
 
+    /// Detect user-supplied project terms
+    fn detect_gazetteer_terms(&self, text: &str) -> Vec<Entity> {
+        let Some(gazetteer) = &self.gazetteer else {
+            return Vec::new();
+        };
+
+        gazetteer
+            .pattern
+            .find_iter(text)
+            .filter_map(|mat| {
+                let entity_type = *gazetteer.types.get(mat.as_str())?;
+                Some(Entity {
+                    entity_type,
+                    text: mat.as_str().to_string(),
+                    range: mat.start()..mat.end(),
+                    confidence: GAZETTEER_CONFIDENCE,
+                })
+            })
+            .collect()
+    }
+
     /// Detect person names with titles
     fn detect_persons_with_titles(&self, text: &str) -> Result<Vec<Entity>> {
         let mut entities = Vec::new();
@@ -467,4 +552,38 @@ mod tests {
         assert_eq!(spans[0].range, 0..5);
         assert_eq!(spans[0].source, HighlightSource::Relational);
     }
+
+    #[test]
+    fn test_gazetteer_terms_recognized() {
+        let text = "Project Mnemosyne runs an OODA loop";
+        let entries = vec![
+            GazetteerEntry {
+                term: "Mnemosyne".to_string(),
+                entity_type: EntityType::Organization,
+            },
+            GazetteerEntry {
+                term: "OODA".to_string(),
+                entity_type: EntityType::Concept,
+            },
+        ];
+
+        // Heuristics alone only guess at "Project Mnemosyne" and miss "OODA"
+        let heuristic = EntityRecognizer::new().recognize(text).unwrap();
+        assert!(!heuristic.iter().any(|e| e.text == "OODA"));
+        assert!(!heuristic.iter().any(|e| e.text == "Mnemosyne"));
+
+        let entities = EntityRecognizer::new()
+            .with_gazetteer(entries)
+            .recognize(text)
+            .unwrap();
+        let found: Vec<(&str, EntityType)> = entities
+            .iter()
+            .map(|e| (e.text.as_str(), e.entity_type))
+            .collect();
+        assert!(found.contains(&("Mnemosyne", EntityType::Organization)));
+        assert!(found.contains(&("OODA", EntityType::Concept)));
+
+        // The gazetteer match displaced the overlapping low-confidence guess
+        assert!(!found.iter().any(|(t, _)| *t == "Project Mnemosyne"));
+    }
 }
@@ -12,6 +12,7 @@ use crate::ics::semantic_highlighter::{
 };
 use serde::{Deserialize, Serialize};
 use std::ops::Range;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, RwLock};
 use tokio::sync::mpsc;
 use tracing::{debug, info};
@@ -24,7 +25,7 @@ pub mod semantic_roles;
 
 pub use anaphora::{Anaphor, AnaphoraResolution, AnaphoraResolver};
 pub use coreference::{CorefChain, CoreferenceResolver, Mention};
-pub use entities::{Entity, EntityRecognizer, EntityType};
+pub use entities::{Entity, EntityRecognizer, EntityType, GazetteerEntry};
 pub use relationships::{RelationType, Relationship, RelationshipExtractor};
 pub use semantic_roles::{RoleAssignment, SemanticRole, SemanticRoleLabeler};
 
@@ -98,6 +99,9 @@ pub struct RelationalAnalyzer {
     dirty_regions: Arc<RwLock<DirtyRegions>>,
     debouncer: Arc<RwLock<Debouncer>>,
     analysis_tx: Option<mpsc::Sender<AnalysisRequest>>,
+
+    /// Regions analyzed synchronously because no cached result was usable
+    regions_analyzed: AtomicUsize,
 }
 
 impl RelationalAnalyzer {
@@ -124,7 +128,8 @@ impl RelationalAnalyzer {
             settings: settings.clone(),
             cache,
             entity_recognizer: EntityRecognizer::new()
-                .with_threshold(settings.min_entity_confidence),
+                .with_threshold(settings.min_entity_confidence)
+                .with_gazetteer(settings.gazetteer.clone()),
             coref_resolver: CoreferenceResolver::new()
                 .with_max_distance(settings.max_coref_distance)
                 .with_threshold(settings.min_entity_confidence),
@@ -137,6 +142,7 @@ impl RelationalAnalyzer {
             dirty_regions,
             debouncer,
             analysis_tx: Some(tx),
+            regions_analyzed: AtomicUsize::new(0),
         }
     }
 
@@ -162,46 +168,89 @@ impl RelationalAnalyzer {
     }
 
     /// Get cached highlights for text
+    ///
+    /// The text is split into paragraphs, and each paragraph's analysis is
+    /// cached by its content. Unchanged paragraphs reuse their result even
+    /// when an edit before them shifts their offsets, so an edit only
+    /// re-analyzes its own paragraph.
     pub fn highlight_cached(&self, text: &str) -> Result<Vec<HighlightSpan>> {
         let mut spans = Vec::new();
+        let mut reanalyzed = Vec::new();
+        for region in paragraph_regions(text) {
+            let region_text = &text[region.clone()];
+            let result = match self.cached_result(region_text) {
+                Some(result) => result,
+                None => {
+                    debug!("Analyzing region {:?} synchronously", region);
+                    let result = self.analyze(region_text);
+                    self.regions_analyzed.fetch_add(1, Ordering::Relaxed);
+                    self.cache_result(region_text, &result);
+                    reanalyzed.push(region.clone());
+                    result
+                }
+            };
 
-        // Try cache for full text range first
-        let full_range = 0..text.len();
-        if let Some(cached) = self.cache.relational.get(&full_range) {
-            if let Ok(result) = serde_json::from_value::<Tier2AnalysisResult>(cached.data) {
-                debug!("Cache hit for full text");
-                return Ok(result.to_spans(
-                    &self.entity_recognizer,
-                    &self.relation_extractor,
-                    &self.role_labeler,
-                    text,
-                ));
+            spans.extend(self.result_to_spans(&result, region_text, region.start));
+        }
+
+        // Dirty regions analyzed here don't need the background pass
+        if let Ok(mut dirty) = self.dirty_regions.write() {
+            for region in &reanalyzed {
+                dirty.clear_within(region);
             }
         }
 
-        // Cache miss - run analysis synchronously (still fast <200ms)
-        debug!("Cache miss - running synchronous analysis");
+        // Coreference and anaphora resolution create connections, not spans directly
+        // These would be used for visual connection rendering
 
-        // Run all analyzers
-        if let Ok(entities) = self.entity_recognizer.recognize(text) {
-            spans.extend(self.entity_recognizer.entities_to_spans(&entities));
-        }
+        Ok(spans)
+    }
 
-        if let Ok(relationships) = self.relation_extractor.extract(text) {
-            spans.extend(
-                self.relation_extractor
-                    .relationships_to_spans(&relationships, text),
-            );
-        }
+    /// Number of regions analyzed synchronously so far (cache misses)
+    pub fn regions_analyzed(&self) -> usize {
+        self.regions_analyzed.load(Ordering::Relaxed)
+    }
 
-        if let Ok(roles) = self.role_labeler.label(text) {
-            spans.extend(self.role_labeler.roles_to_spans(&roles));
+    /// Run all local analyzers over `text`
+    fn analyze(&self, text: &str) -> Tier2AnalysisResult {
+        Tier2AnalysisResult::Combined {
+            entities: self.entity_recognizer.recognize(text).unwrap_or_default(),
+            relationships: self.relation_extractor.extract(text).unwrap_or_default(),
+            roles: self.role_labeler.label(text).unwrap_or_default(),
         }
+    }
 
-        // Coreference and anaphora resolution create connections, not spans directly
-        // These would be used for visual connection rendering
+    /// Cached result for a paragraph with this content
+    fn cached_result(&self, text: &str) -> Option<Tier2AnalysisResult> {
+        let cached = self.cache.paragraphs.get_by_content(text)?;
+        serde_json::from_value(cached.data).ok()
+    }
 
-        Ok(spans)
+    fn cache_result(&self, text: &str, result: &Tier2AnalysisResult) {
+        if let Ok(json_value) = serde_json::to_value(result) {
+            let cached =
+                CachedResult::new(json_value).with_confidence(self.settings.min_entity_confidence);
+            self.cache.paragraphs.insert_with_content(text, cached);
+        }
+    }
+
+    /// Spans for a region's result, shifted from region to document offsets
+    fn result_to_spans(
+        &self,
+        result: &Tier2AnalysisResult,
+        region_text: &str,
+        offset: usize,
+    ) -> Vec<HighlightSpan> {
+        let mut spans = result.to_spans(
+            &self.entity_recognizer,
+            &self.relation_extractor,
+            &self.role_labeler,
+            region_text,
+        );
+        for span in &mut spans {
+            span.range = span.range.start + offset..span.range.end + offset;
+        }
+        spans
     }
 
     /// Schedule analysis for range (debounced)
@@ -279,8 +328,9 @@ impl RelationalAnalyzer {
 
                         // Run local analysis on region
                         // Create temporary analyzers with configured thresholds
-                        let entity_recognizer =
-                            EntityRecognizer::new().with_threshold(settings.min_entity_confidence);
+                        let entity_recognizer = EntityRecognizer::new()
+                            .with_threshold(settings.min_entity_confidence)
+                            .with_gazetteer(settings.gazetteer.clone());
                         let relation_extractor = RelationshipExtractor::new()
                             .with_threshold(settings.min_entity_confidence);
                         let role_labeler = SemanticRoleLabeler::new()
@@ -339,7 +389,9 @@ impl RelationalAnalyzer {
 
         // Update threshold for all analyzers
         let threshold = settings.min_entity_confidence;
-        self.entity_recognizer = EntityRecognizer::new().with_threshold(threshold);
+        self.entity_recognizer = EntityRecognizer::new()
+            .with_threshold(threshold)
+            .with_gazetteer(settings.gazetteer.clone());
         self.coref_resolver = CoreferenceResolver::new()
             .with_max_distance(settings.max_coref_distance)
             .with_threshold(threshold);
@@ -355,3 +407,48 @@ impl RelationalAnalyzer {
         }
     }
 }
+
+/// Byte ranges of paragraphs (blocks separated by blank lines)
+///
+/// The unit of incremental re-analysis: sentences rarely cross a blank line,
+/// so paragraphs can be analyzed independently.
+fn paragraph_regions(text: &str) -> Vec<Range<usize>> {
+    let mut regions = Vec::new();
+    let mut current: Option<Range<usize>> = None;
+    let mut offset = 0;
+
+    for line in text.split_inclusive('\n') {
+        let content = line.trim_end_matches(['\n', '\r']);
+        if content.trim().is_empty() {
+            regions.extend(current.take());
+        } else {
+            let end = offset + content.len();
+            match current.as_mut() {
+                Some(region) => region.end = end,
+                None => current = Some(offset..end),
+            }
+        }
+        offset += line.len();
+    }
+    regions.extend(current);
+
+    regions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paragraph_regions() {
+        let text = "First line\nstill first\n\n  \nSecond\r\n\nThird";
+        let paragraphs: Vec<&str> = paragraph_regions(text)
+            .into_iter()
+            .map(|r| &text[r])
+            .collect();
+        assert_eq!(
+            paragraphs,
+            vec!["First line\nstill first", "Second", "Third"]
+        );
+    }
+}
@@ -69,8 +69,8 @@ pub struct RequestBatcher {
     config: BatchConfig,
     /// Pending requests queue
     pending: Arc<Mutex<VecDeque<BatchRequest>>>,
-    /// Deduplication cache (content hash -> request IDs)
-    dedup_cache: Arc<RwLock<HashMap<String, Vec<String>>>>,
+    /// Deduplication cache ((content hash, analysis type) -> request IDs)
+    dedup_cache: Arc<RwLock<HashMap<(String, AnalysisType), Vec<String>>>>,
     /// Rate limiting state
     rate_limiter: Arc<Mutex<RateLimiter>>,
     /// Last batch processing time
@@ -136,20 +136,21 @@ impl RequestBatcher {
     }
 
     /// Submit a request for batching
+    ///
+    /// A request is a duplicate when the same analysis of the same content is
+    /// already queued or in flight; different analyses of one text are not.
     pub async fn submit(&self, request: BatchRequest) -> Result<()> {
         // Check deduplication
         let mut cache = self.dedup_cache.write().await;
-        if let Some(_existing) = cache.get(&request.content_hash) {
+        let key = (request.content_hash.clone(), request.analysis_type.clone());
+        if let Some(waiting) = cache.get_mut(&key) {
             // Already have this request, just add ID to waiting list
-            cache
-                .get_mut(&request.content_hash)
-                .unwrap()
-                .push(request.id.clone());
+            waiting.push(request.id.clone());
             return Ok(());
         }
 
         // New request - add to cache and queue
-        cache.insert(request.content_hash.clone(), vec![request.id.clone()]);
+        cache.insert(key, vec![request.id.clone()]);
         drop(cache);
 
         let mut pending = self.pending.lock().await;
@@ -234,11 +235,11 @@ impl RequestBatcher {
     }
 
     /// Clear deduplication cache for processed requests
+    ///
+    /// Releases every analysis type of each content hash.
     pub async fn clear_dedup(&self, content_hashes: &[String]) {
         let mut cache = self.dedup_cache.write().await;
-        for hash in content_hashes {
-            cache.remove(hash);
-        }
+        cache.retain(|(hash, _), _| !content_hashes.contains(hash));
     }
 
     /// Get current queue size
@@ -381,4 +382,23 @@ mod tests {
         assert_eq!(batch[0].id, "high"); // High priority first
         assert_eq!(batch[1].id, "low");
     }
+
+    #[tokio::test]
+    async fn test_different_analyses_of_same_content_are_kept() {
+        let batcher = RequestBatcher::new(BatchConfig::default());
+
+        for analysis_type in [AnalysisType::Discourse, AnalysisType::Coherence] {
+            let request = BatchRequest {
+                id: format!("test-{:?}", analysis_type),
+                text: "test text".to_string(),
+                content_hash: "hash1".to_string(),
+                analysis_type,
+                priority: 5,
+                submitted_at: Instant::now(),
+            };
+            batcher.submit(request).await.unwrap();
+        }
+
+        assert_eq!(batcher.queue_size().await, 2);
+    }
 }
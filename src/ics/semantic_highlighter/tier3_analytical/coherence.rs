@@ -0,0 +1,352 @@
+//! Coherence analysis using Claude API
+//!
+//! Scores how well a document hangs together:
+//! - Overall coherence, topic consistency and logical flow (`CoherenceScore`)
+//! - Sentence-to-sentence transitions, flagging the ones that break the flow
+//!
+//! Sentences are split locally and sent numbered, so the LLM scores
+//! transitions by index rather than guessing character offsets.
+
+use super::{discourse::CoherenceScore, RequestControl};
+use crate::{
+    ics::semantic_highlighter::{
+        utils::CommonPatterns,
+        visualization::{
+            Annotation, AnnotationType, HighlightSource, HighlightSpan, SpanMetadata,
+            UnderlineStyle,
+        },
+        Result, SemanticError,
+    },
+    LlmService, LlmTask,
+};
+use ratatui::style::{Color, Modifier, Style};
+use serde::{Deserialize, Serialize};
+use std::ops::Range;
+use std::sync::Arc;
+use tracing::{debug, warn};
+
+/// A transition into a sentence that doesn't follow from the one before
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CoherenceTransition {
+    /// Sentence where the flow breaks
+    pub range: Range<usize>,
+    /// Transition coherence (0.0-1.0)
+    pub score: f32,
+    /// Why the transition is weak
+    pub issue: String,
+}
+
+/// Document coherence plus its weak transitions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CoherenceAnalysis {
+    /// Whole-document assessment
+    pub score: CoherenceScore,
+    /// Transitions scoring below the analyzer's threshold
+    pub transitions: Vec<CoherenceTransition>,
+}
+
+/// Coherence analyzer using Claude API
+#[derive(Clone)]
+pub struct CoherenceAnalyzer {
+    llm_service: Arc<LlmService>,
+    threshold: f32,
+    control: RequestControl,
+}
+
+impl CoherenceAnalyzer {
+    pub fn new(llm_service: Arc<LlmService>) -> Self {
+        Self {
+            llm_service,
+            threshold: 0.5,
+            control: RequestControl::default(),
+        }
+    }
+
+    /// Set the timeout and cancellation token for LLM calls
+    pub fn with_request_control(mut self, control: RequestControl) -> Self {
+        self.control = control;
+        self
+    }
+
+    /// Set the score below which a transition is flagged
+    pub fn with_threshold(mut self, threshold: f32) -> Self {
+        self.threshold = threshold.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Analyze coherence of text
+    pub async fn analyze(&self, text: &str) -> Result<CoherenceAnalysis> {
+        let sentences = split_sentences(text);
+        let prompt = self.build_coherence_prompt(text, &sentences);
+
+        let response = self
+            .llm_service
+            .call_task_with(
+                LlmTask::Coherence,
+                &prompt,
+                self.control.timeout,
+                &self.control.cancel,
+            )
+            .await
+            .map_err(SemanticError::from_llm)?;
+
+        let analysis = self.parse_coherence_response(&response, &sentences)?;
+        debug!(
+            "Coherence analysis completed: overall {:.2}, {} weak transitions",
+            analysis.score.overall,
+            analysis.transitions.len()
+        );
+        Ok(analysis)
+    }
+
+    /// Parse the LLM response against the sentences it was shown
+    fn parse_coherence_response(
+        &self,
+        response: &str,
+        sentences: &[Range<usize>],
+    ) -> Result<CoherenceAnalysis> {
+        #[derive(Deserialize)]
+        struct TransitionJson {
+            to: usize,
+            score: f32,
+            #[serde(default)]
+            issue: String,
+        }
+
+        #[derive(Deserialize)]
+        struct CoherenceJson {
+            overall: f32,
+            topic_consistency: f32,
+            logical_flow: f32,
+            #[serde(default)]
+            issues: Vec<String>,
+            #[serde(default)]
+            transitions: Vec<TransitionJson>,
+        }
+
+        // Tolerate prose or code fences around the JSON object
+        let json = match (response.find('{'), response.rfind('}')) {
+            (Some(start), Some(end)) if start < end => &response[start..=end],
+            _ => response,
+        };
+        let parsed: CoherenceJson = serde_json::from_str(json)
+            .map_err(|e| SemanticError::AnalysisFailed(format!("JSON parse error: {}", e)))?;
+
+        let transitions = parsed
+            .transitions
+            .into_iter()
+            .filter_map(|t| {
+                // Sentence 0 has no transition into it
+                let Some(range) = sentences.get(t.to).filter(|_| t.to > 0) else {
+                    warn!(
+                        "Invalid coherence transition to sentence {} ({} sentences)",
+                        t.to,
+                        sentences.len()
+                    );
+                    return None;
+                };
+                let score = t.score.clamp(0.0, 1.0);
+                (score < self.threshold).then(|| CoherenceTransition {
+                    range: range.clone(),
+                    score,
+                    issue: t.issue,
+                })
+            })
+            .collect();
+
+        Ok(CoherenceAnalysis {
+            score: CoherenceScore {
+                overall: parsed.overall.clamp(0.0, 1.0),
+                topic_consistency: parsed.topic_consistency.clamp(0.0, 1.0),
+                logical_flow: parsed.logical_flow.clamp(0.0, 1.0),
+                issues: parsed.issues,
+            },
+            transitions,
+        })
+    }
+
+    /// Convert weak transitions to highlight spans
+    pub fn analysis_to_spans(&self, analysis: &CoherenceAnalysis) -> Vec<HighlightSpan> {
+        let score = &analysis.score;
+        analysis
+            .transitions
+            .iter()
+            .map(|transition| {
+                let tooltip = if transition.issue.is_empty() {
+                    format!("Weak transition (coherence {:.2})", transition.score)
+                } else {
+                    format!(
+                        "Weak transition (coherence {:.2}): {}",
+                        transition.score, transition.issue
+                    )
+                };
+
+                let mut metadata = SpanMetadata::new().with_entity_type("coherence");
+                metadata.properties.extend([
+                    ("transition".to_string(), format!("{:.2}", transition.score)),
+                    ("overall".to_string(), format!("{:.2}", score.overall)),
+                    (
+                        "topic_consistency".to_string(),
+                        format!("{:.2}", score.topic_consistency),
+                    ),
+                    (
+                        "logical_flow".to_string(),
+                        format!("{:.2}", score.logical_flow),
+                    ),
+                ]);
+
+                HighlightSpan {
+                    range: transition.range.clone(),
+                    style: Style::default()
+                        .fg(Color::LightYellow)
+                        .add_modifier(Modifier::UNDERLINED),
+                    source: HighlightSource::Analytical,
+                    annotation: Some(Annotation {
+                        annotation_type: AnnotationType::Warning,
+                        underline: Some(UnderlineStyle::Wavy),
+                        tooltip: Some(tooltip),
+                    }),
+                    confidence: 1.0 - transition.score,
+                    metadata: Some(metadata),
+                }
+            })
+            .collect()
+    }
+
+    /// Build prompt for coherence analysis
+    fn build_coherence_prompt(&self, text: &str, sentences: &[Range<usize>]) -> String {
+        let numbered = sentences
+            .iter()
+            .enumerate()
+            .map(|(i, range)| format!("[{}] {}", i, &text[range.clone()]))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        format!(
+            r#"Analyze the coherence and logical flow of the following text, given as numbered sentences.
+
+Sentences:
+{}
+
+Assess:
+1. Overall coherence (0.0-1.0)
+2. Topic consistency (0.0-1.0)
+3. Logical flow (0.0-1.0)
+4. Any coherence issues
+5. For each sentence after the first, how well it follows from the previous one (0.0-1.0)
+
+Respond ONLY with JSON, no additional text:
+{{
+  "overall": 0.8,
+  "topic_consistency": 0.85,
+  "logical_flow": 0.75,
+  "issues": ["list of issues"],
+  "transitions": [
+    {{"to": 1, "score": 0.9, "issue": ""}},
+    {{"to": 2, "score": 0.3, "issue": "Abrupt topic change"}}
+  ]
+}}"#,
+            numbered
+        )
+    }
+}
+
+/// Byte ranges of sentences, trimmed of surrounding whitespace
+pub(super) fn split_sentences(text: &str) -> Vec<Range<usize>> {
+    let mut sentences = Vec::new();
+    let mut start = 0;
+
+    let ends = CommonPatterns::sentence_boundary()
+        .find_iter(text)
+        .map(|m| (m.start() + 1, m.end()))
+        .chain(std::iter::once((text.len(), text.len())));
+
+    for (end, next_start) in ends {
+        let sentence = &text[start..end];
+        let trimmed = sentence.trim_start();
+        let begin = start + (sentence.len() - trimmed.len());
+        let finish = begin + trimmed.trim_end().len();
+        if begin < finish {
+            sentences.push(begin..finish);
+        }
+        start = next_start;
+    }
+
+    sentences
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LlmConfig;
+
+    fn analyzer() -> CoherenceAnalyzer {
+        let llm = LlmService::new(LlmConfig {
+            api_key: String::new(),
+            model: "claude-haiku-4-5-20251001".to_string(),
+            max_tokens: 1024,
+            temperature: 0.7,
+            task_models: Default::default(),
+        })
+        .unwrap();
+        CoherenceAnalyzer::new(Arc::new(llm))
+    }
+
+    const TEXT: &str = "The cache stores results. It evicts old entries first. Bananas are yellow.";
+
+    #[test]
+    fn test_split_sentences() {
+        let sentences = split_sentences(TEXT);
+        let texts: Vec<&str> = sentences.iter().map(|r| &TEXT[r.clone()]).collect();
+        assert_eq!(
+            texts,
+            vec![
+                "The cache stores results.",
+                "It evicts old entries first.",
+                "Bananas are yellow."
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_flags_weak_transitions() {
+        let analyzer = analyzer();
+        let sentences = split_sentences(TEXT);
+        let response = r#"Here you go:
+```json
+{"overall": 0.6, "topic_consistency": 0.4, "logical_flow": 0.7,
+ "issues": ["Unrelated final sentence"],
+ "transitions": [
+   {"to": 1, "score": 0.9},
+   {"to": 2, "score": 0.1, "issue": "Abrupt topic change"},
+   {"to": 7, "score": 0.0}
+ ]}
+```"#;
+
+        let analysis = analyzer
+            .parse_coherence_response(response, &sentences)
+            .unwrap();
+        assert_eq!(analysis.score.overall, 0.6);
+        assert_eq!(analysis.transitions.len(), 1);
+        assert_eq!(
+            &TEXT[analysis.transitions[0].range.clone()],
+            "Bananas are yellow."
+        );
+
+        let spans = analyzer.analysis_to_spans(&analysis);
+        assert_eq!(spans.len(), 1);
+        assert_eq!(spans[0].source, HighlightSource::Analytical);
+        let metadata = spans[0].metadata.as_ref().unwrap();
+        assert_eq!(metadata.properties["transition"], "0.10");
+        assert_eq!(metadata.properties["topic_consistency"], "0.40");
+    }
+
+    #[tokio::test]
+    async fn test_analyze_surfaces_llm_errors() {
+        // No API key: the request fails instead of returning placeholder scores
+        assert!(matches!(
+            analyzer().analyze(TEXT).await,
+            Err(SemanticError::LlmError(_))
+        ));
+    }
+}
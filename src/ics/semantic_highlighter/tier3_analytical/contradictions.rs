@@ -7,19 +7,27 @@
 //! - Temporal inconsistencies
 //!
 //! Uses Claude API for deep semantic understanding and reasoning.
+//!
+//! With [`ContradictionDetector::with_memory_search`], text is also checked
+//! against related high-importance memories ("use MySQL" vs a stored decision
+//! to use PostgreSQL); those contradictions carry the conflicting `MemoryId`.
 
 #![allow(dead_code)]
 
 #[cfg(feature = "python")]
 use super::dspy_integration::DSpySemanticBridge;
+use super::{coherence::split_sentences, RequestControl};
 use crate::{
     ics::semantic_highlighter::{
         visualization::{
             Annotation, AnnotationType, Connection, ConnectionType, HighlightSource, HighlightSpan,
+            SpanMetadata,
         },
         Result, SemanticError,
     },
-    LlmService,
+    storage::StorageBackend,
+    types::{MemoryId, MemoryNote, MemoryType},
+    LlmService, LlmTask,
 };
 use ratatui::style::{Color, Modifier, Style};
 use serde::{Deserialize, Serialize};
@@ -97,14 +105,33 @@ pub struct Contradiction {
 
     /// Confidence score (0.0-1.0)
     pub confidence: f32,
+
+    /// Stored memory the text contradicts
+    ///
+    /// When set, `statement2` is empty and `text2` is the memory's summary.
+    #[serde(default)]
+    pub memory_id: Option<MemoryId>,
+}
+
+/// Memories retrieved per analysis for cross-document checks
+const MAX_RELATED_MEMORIES: usize = 5;
+
+/// Where and how to look for contradicted memories
+#[derive(Clone)]
+struct MemorySearch {
+    storage: Arc<dyn StorageBackend>,
+    min_importance: u8,
 }
 
 /// Contradiction detector using Claude API or DSPy
 #[derive(Clone)]
 pub struct ContradictionDetector {
-    _llm_service: Arc<LlmService>,
+    llm_service: Arc<LlmService>,
     /// Minimum confidence threshold
     threshold: f32,
+    /// Cross-document checks against stored memories (opt-in)
+    memory_search: Option<MemorySearch>,
+    control: RequestControl,
     #[cfg(feature = "python")]
     dspy_bridge: Option<Arc<DSpySemanticBridge>>,
 }
@@ -112,8 +139,10 @@ pub struct ContradictionDetector {
 impl ContradictionDetector {
     pub fn new(llm_service: Arc<LlmService>) -> Self {
         Self {
-            _llm_service: llm_service,
+            llm_service,
             threshold: 0.7,
+            memory_search: None,
+            control: RequestControl::default(),
             #[cfg(feature = "python")]
             dspy_bridge: None,
         }
@@ -123,8 +152,10 @@ impl ContradictionDetector {
     #[cfg(feature = "python")]
     pub fn with_dspy(llm_service: Arc<LlmService>, dspy_bridge: Arc<DSpySemanticBridge>) -> Self {
         Self {
-            _llm_service: llm_service,
+            llm_service,
             threshold: 0.7,
+            memory_search: None,
+            control: RequestControl::default(),
             dspy_bridge: Some(dspy_bridge),
         }
     }
@@ -135,9 +166,57 @@ impl ContradictionDetector {
         self
     }
 
-    /// Detect contradictions in text
-    #[allow(unused_variables)] // text used with python feature
+    /// Set the timeout and cancellation token for LLM calls
+    pub fn with_request_control(mut self, control: RequestControl) -> Self {
+        self.control = control;
+        self
+    }
+
+    /// Also check text against stored memories of at least `min_importance`
+    ///
+    /// Costs a storage search and an extra LLM call per analysis.
+    pub fn with_memory_search(
+        mut self,
+        storage: Arc<dyn StorageBackend>,
+        min_importance: u8,
+    ) -> Self {
+        self.memory_search = Some(MemorySearch {
+            storage,
+            min_importance,
+        });
+        self
+    }
+
+    /// Detect contradictions within text and, if enabled, against memories
+    ///
+    /// Fails only if every enabled check fails.
     pub async fn detect(&self, text: &str) -> Result<Vec<Contradiction>> {
+        let in_text = self.detect_in_text(text).await;
+        if self.memory_search.is_none() {
+            return in_text;
+        }
+
+        let against_memories = self.detect_against_memories(text).await;
+        match (in_text, against_memories) {
+            (Ok(mut found), Ok(more)) => {
+                found.extend(more);
+                Ok(found)
+            }
+            (Ok(found), Err(e)) => {
+                warn!("Memory contradiction check failed: {}", e);
+                Ok(found)
+            }
+            (Err(e), Ok(found)) => {
+                debug!("In-text contradiction detection unavailable: {}", e);
+                Ok(found)
+            }
+            (Err(e), Err(_)) => Err(e),
+        }
+    }
+
+    /// Detect contradictions within text
+    #[allow(unused_variables)] // text used with python feature
+    pub async fn detect_in_text(&self, text: &str) -> Result<Vec<Contradiction>> {
         // Use DSPy if available (preferred path)
         #[cfg(feature = "python")]
         if let Some(bridge) = &self.dspy_bridge {
@@ -163,6 +242,123 @@ impl ContradictionDetector {
         ))
     }
 
+    /// High-importance memories related to text, most relevant first
+    pub async fn related_memories(&self, text: &str) -> Result<Vec<MemoryNote>> {
+        let Some(ref search) = self.memory_search else {
+            return Ok(Vec::new());
+        };
+
+        let results = search
+            .storage
+            .hybrid_search(text, None, MAX_RELATED_MEMORIES * 4, false)
+            .await
+            .map_err(|e| SemanticError::AnalysisFailed(format!("Memory search failed: {}", e)))?;
+
+        Ok(results
+            .into_iter()
+            .map(|r| r.memory)
+            .filter(|m| !m.is_archived && m.importance >= search.min_importance)
+            .take(MAX_RELATED_MEMORIES)
+            .collect())
+    }
+
+    /// Detect statements in text that contradict related stored memories
+    pub async fn detect_against_memories(&self, text: &str) -> Result<Vec<Contradiction>> {
+        let memories = self.related_memories(text).await?;
+        if memories.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let sentences = split_sentences(text);
+        let prompt = self.build_memory_prompt(text, &sentences, &memories);
+        let response = self
+            .llm_service
+            .call_task_with(
+                LlmTask::Contradiction,
+                &prompt,
+                self.control.timeout,
+                &self.control.cancel,
+            )
+            .await
+            .map_err(SemanticError::from_llm)?;
+
+        let found = self.parse_memory_response(&response, text, &sentences, &memories)?;
+        debug!(
+            "Memory contradiction check: {} found across {} memories",
+            found.len(),
+            memories.len()
+        );
+        Ok(found)
+    }
+
+    /// Parse a memory contradiction response against the sentences and
+    /// memories the LLM was shown
+    fn parse_memory_response(
+        &self,
+        response: &str,
+        text: &str,
+        sentences: &[Range<usize>],
+        memories: &[MemoryNote],
+    ) -> Result<Vec<Contradiction>> {
+        #[derive(Deserialize)]
+        struct MemoryContradictionJson {
+            sentence: usize,
+            memory: usize,
+            #[serde(rename = "type")]
+            contradiction_type: String,
+            explanation: String,
+            confidence: f32,
+        }
+
+        // Tolerate prose or code fences around the JSON array
+        let json = match (response.find('['), response.rfind(']')) {
+            (Some(start), Some(end)) if start < end => &response[start..=end],
+            _ => response,
+        };
+        let found: Vec<MemoryContradictionJson> = serde_json::from_str(json)
+            .map_err(|e| SemanticError::AnalysisFailed(format!("JSON parse error: {}", e)))?;
+
+        let result = found
+            .into_iter()
+            .filter_map(|c| {
+                let (Some(range), Some(memory)) =
+                    (sentences.get(c.sentence), memories.get(c.memory))
+                else {
+                    warn!(
+                        "Invalid memory contradiction: sentence {} / memory {}",
+                        c.sentence, c.memory
+                    );
+                    return None;
+                };
+
+                let contradiction_type = match c.contradiction_type.as_str() {
+                    "Direct" => ContradictionType::Direct,
+                    "Temporal" => ContradictionType::Temporal,
+                    "Semantic" => ContradictionType::Semantic,
+                    "Implication" => ContradictionType::Implication,
+                    other => {
+                        warn!("Unknown contradiction type: {}", other);
+                        return None;
+                    }
+                };
+
+                Some(Contradiction {
+                    statement1: range.clone(),
+                    text1: text[range.clone()].to_string(),
+                    statement2: 0..0,
+                    text2: memory.summary.clone(),
+                    contradiction_type,
+                    explanation: c.explanation,
+                    confidence: c.confidence.clamp(0.0, 1.0),
+                    memory_id: Some(memory.id),
+                })
+            })
+            .filter(|c| c.confidence >= self.threshold)
+            .collect();
+
+        Ok(result)
+    }
+
     /// Parse contradiction response from LLM
     fn parse_contradiction_response(
         &self,
@@ -227,6 +423,7 @@ impl ContradictionDetector {
                     contradiction_type,
                     explanation: c.explanation,
                     confidence: c.confidence.clamp(0.0, 1.0),
+                    memory_id: None,
                 })
             })
             .collect();
@@ -248,6 +445,30 @@ impl ContradictionDetector {
                 .fg(severity.color())
                 .add_modifier(Modifier::BOLD | Modifier::UNDERLINED);
 
+            // Against a memory, only the statement in this text is highlighted
+            if let Some(memory_id) = contradiction.memory_id {
+                spans.push(HighlightSpan {
+                    range: contradiction.statement1.clone(),
+                    style,
+                    source: HighlightSource::Analytical,
+                    annotation: Some(Annotation {
+                        annotation_type: AnnotationType::Contradiction,
+                        underline: None,
+                        tooltip: Some(format!(
+                            "Contradicts a prior decision (\"{}\"): {}",
+                            contradiction.text2, contradiction.explanation
+                        )),
+                    }),
+                    confidence: contradiction.confidence,
+                    metadata: Some(
+                        SpanMetadata::new()
+                            .with_entity_id(memory_id.to_string())
+                            .with_entity_type("memory_contradiction"),
+                    ),
+                });
+                continue;
+            }
+
             let annotation = Annotation {
                 annotation_type: AnnotationType::Contradiction,
                 underline: None,
@@ -289,7 +510,7 @@ impl ContradictionDetector {
     ) -> Vec<Connection> {
         contradictions
             .iter()
-            .filter(|c| c.confidence >= self.threshold)
+            .filter(|c| c.confidence >= self.threshold && c.memory_id.is_none())
             .map(|contradiction| Connection {
                 from: contradiction.statement1.clone(),
                 to: contradiction.statement2.clone(),
@@ -300,6 +521,59 @@ impl ContradictionDetector {
             .collect()
     }
 
+    /// Build prompt for checking text against stored memories
+    fn build_memory_prompt(
+        &self,
+        text: &str,
+        sentences: &[Range<usize>],
+        memories: &[MemoryNote],
+    ) -> String {
+        let numbered_sentences = sentences
+            .iter()
+            .enumerate()
+            .map(|(i, range)| format!("[{}] {}", i, &text[range.clone()]))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let numbered_memories = memories
+            .iter()
+            .enumerate()
+            .map(|(i, m)| {
+                let kind = if m.memory_type == MemoryType::ArchitectureDecision {
+                    "decision"
+                } else {
+                    "memory"
+                };
+                format!("[{}] ({}) {}: {}", i, kind, m.summary, m.content)
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        format!(
+            r#"Check whether any sentence of the new text contradicts a previously recorded project memory.
+
+Recorded memories:
+{}
+
+New text, as numbered sentences:
+{}
+
+Only report genuine conflicts (e.g. the text says "use MySQL" but a recorded decision chose PostgreSQL).
+Types: Direct, Temporal, Semantic, Implication.
+
+Respond ONLY with a JSON array, no additional text (empty array if none):
+[
+  {{
+    "sentence": 0,
+    "memory": 1,
+    "type": "Direct",
+    "explanation": "why the sentence conflicts with the memory",
+    "confidence": 0.9
+  }}
+]"#,
+            numbered_memories, numbered_sentences
+        )
+    }
+
     /// Build prompt for contradiction detection
     fn build_detection_prompt(&self, text: &str) -> String {
         format!(
@@ -399,6 +673,7 @@ mod tests {
             contradiction_type: ContradictionType::Direct,
             explanation: "Directly contradictory statements".to_string(),
             confidence: 0.95,
+            memory_id: None,
         };
 
         assert_eq!(contradiction.confidence, 0.95);
@@ -416,6 +691,7 @@ mod tests {
                 contradiction_type: ContradictionType::Direct,
                 explanation: "Test".to_string(),
                 confidence: 0.9,
+                memory_id: None,
             },
             Contradiction {
                 statement1: 20..25,
@@ -425,6 +701,7 @@ mod tests {
                 contradiction_type: ContradictionType::Semantic,
                 explanation: "Test".to_string(),
                 confidence: 0.5,
+                memory_id: None,
             },
         ];
 
@@ -435,4 +712,76 @@ mod tests {
         // Should only include the first one (confidence 0.9 > 0.7)
         // assert_eq!(connections.len(), 1);
     }
+
+    #[test]
+    fn test_memory_contradictions_annotated_with_memory_id() {
+        let llm = LlmService::new(crate::LlmConfig {
+            api_key: String::new(),
+            model: "claude-haiku-4-5-20251001".to_string(),
+            max_tokens: 1024,
+            temperature: 0.7,
+            task_models: Default::default(),
+        })
+        .unwrap();
+        let detector = ContradictionDetector::new(Arc::new(llm));
+
+        let now = chrono::Utc::now();
+        let decision = MemoryNote {
+            id: MemoryId::new(),
+            namespace: crate::types::Namespace::Global,
+            created_at: now,
+            updated_at: now,
+            content: "Decided to use PostgreSQL".to_string(),
+            summary: "Use PostgreSQL".to_string(),
+            keywords: Vec::new(),
+            tags: Vec::new(),
+            context: String::new(),
+            memory_type: MemoryType::ArchitectureDecision,
+            importance: 9,
+            confidence: 1.0,
+            links: Vec::new(),
+            related_files: Vec::new(),
+            related_entities: Vec::new(),
+            access_count: 0,
+            last_accessed_at: now,
+            expires_at: None,
+            is_archived: false,
+            superseded_by: None,
+            embedding: None,
+            embedding_model: "none".to_string(),
+        };
+
+        let text = "Caching stays in Redis. We will use MySQL.";
+        let sentences = split_sentences(text);
+        let response = r#"[
+            {"sentence": 1, "memory": 0, "type": "Direct", "explanation": "Different database", "confidence": 0.9},
+            {"sentence": 0, "memory": 0, "type": "Semantic", "explanation": "Weak", "confidence": 0.2},
+            {"sentence": 5, "memory": 0, "type": "Direct", "explanation": "Out of range", "confidence": 0.9}
+        ]"#;
+
+        let found = detector
+            .parse_memory_response(response, text, &sentences, std::slice::from_ref(&decision))
+            .unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].memory_id, Some(decision.id));
+        assert_eq!(found[0].text1, "We will use MySQL.");
+        assert_eq!(found[0].text2, "Use PostgreSQL");
+
+        // Only the statement in this text is highlighted, and no connection is drawn
+        let spans = detector.contradictions_to_spans(&found);
+        assert_eq!(spans.len(), 1);
+        let tooltip = spans[0]
+            .annotation
+            .as_ref()
+            .unwrap()
+            .tooltip
+            .as_ref()
+            .unwrap();
+        assert!(tooltip.starts_with("Contradicts a prior decision"));
+        assert_eq!(
+            spans[0].metadata.as_ref().unwrap().entity_id,
+            Some(decision.id.to_string())
+        );
+        assert!(detector.contradictions_to_connections(&found).is_empty());
+    }
 }
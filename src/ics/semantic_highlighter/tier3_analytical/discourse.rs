@@ -322,7 +322,7 @@ Example format:
         let _prompt = self.build_coherence_prompt(text);
 
         // Placeholder - would call LLM service
-        // let response = self.llm_service.call_api(&_prompt).await?;
+        // let response = self.llm_service.call_task(LlmTask::Discourse, &_prompt).await?;
 
         Ok(CoherenceScore {
             overall: 0.8,
@@ -449,6 +449,7 @@ impl DSpySemanticBridge {
             contradiction_type,
             explanation,
             confidence,
+            memory_id: None,
         })
     }
 
@@ -10,15 +10,19 @@ use crate::{
         visualization::HighlightSpan,
         Result,
     },
+    services::llm::DEFAULT_REQUEST_TIMEOUT,
+    storage::StorageBackend,
     LlmService,
 };
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
 use tracing::{debug, error, info, warn};
 
 pub mod batching;
+pub mod coherence;
 pub mod contradictions;
 pub mod discourse;
 pub mod pragmatics;
@@ -27,6 +31,7 @@ pub mod pragmatics;
 pub mod dspy_integration;
 
 pub use batching::{AnalysisType, BatchConfig, BatchRequest, RequestBatcher};
+pub use coherence::{CoherenceAnalysis, CoherenceAnalyzer, CoherenceTransition};
 pub use contradictions::{Contradiction, ContradictionDetector, ContradictionType};
 pub use discourse::{CoherenceScore, DiscourseAnalyzer, DiscourseRelation, DiscourseSegment};
 pub use pragmatics::{PragmaticElement, PragmaticType, PragmaticsAnalyzer, SpeechActType};
@@ -42,8 +47,35 @@ pub enum AnalysisResult {
     Discourse(Vec<DiscourseSegment>),
     Contradiction(Vec<Contradiction>),
     Pragmatics(Vec<PragmaticElement>),
+    Coherence(CoherenceAnalysis),
 }
 
+/// Timeout and cancellation applied to each analyzer's LLM calls
+#[derive(Debug, Clone)]
+pub struct RequestControl {
+    /// How long one call may take before it fails with a (retryable) timeout
+    pub timeout: Duration,
+    /// Stops in-flight and pending calls when fired
+    pub cancel: CancellationToken,
+}
+
+impl Default for RequestControl {
+    fn default() -> Self {
+        Self {
+            timeout: DEFAULT_REQUEST_TIMEOUT,
+            cancel: CancellationToken::new(),
+        }
+    }
+}
+
+/// Analysis types submitted for each Tier 3 request
+const ANALYSIS_TYPES: [AnalysisType; 4] = [
+    AnalysisType::Discourse,
+    AnalysisType::Contradiction,
+    AnalysisType::Pragmatics,
+    AnalysisType::Coherence,
+];
+
 impl AnalysisResult {
     /// Convert analysis results to highlight spans
     pub fn to_spans(
@@ -51,6 +83,7 @@ impl AnalysisResult {
         discourse: &DiscourseAnalyzer,
         contradiction: &ContradictionDetector,
         pragmatics: &PragmaticsAnalyzer,
+        coherence: &CoherenceAnalyzer,
     ) -> Vec<HighlightSpan> {
         match self {
             AnalysisResult::Discourse(segments) => discourse.segments_to_spans(segments),
@@ -58,23 +91,28 @@ impl AnalysisResult {
                 contradiction.contradictions_to_spans(contradictions)
             }
             AnalysisResult::Pragmatics(elements) => pragmatics.elements_to_spans(elements),
+            AnalysisResult::Coherence(analysis) => coherence.analysis_to_spans(analysis),
         }
     }
 }
 
 /// Analytical processor using Claude API
 ///
-/// Runs in background, batches requests, caches aggressively.
+/// Runs in background, batches requests, caches aggressively. Analysis calls
+/// time out after `request_timeout_secs`, and are cancelled when the
+/// processor stops.
 pub struct AnalyticalProcessor {
     _llm_service: Arc<LlmService>,
-    _settings: AnalyticalSettings,
+    settings: AnalyticalSettings,
     cache: Arc<SemanticCache>,
     request_rx: mpsc::Receiver<AnalysisRequest>,
+    cancel: CancellationToken,
 
     // Analyzers
     discourse_analyzer: DiscourseAnalyzer,
     contradiction_detector: ContradictionDetector,
     pragmatics_analyzer: PragmaticsAnalyzer,
+    coherence_analyzer: CoherenceAnalyzer,
 
     // Batching system
     batcher: Arc<RequestBatcher>,
@@ -94,20 +132,48 @@ impl AnalyticalProcessor {
             ..Default::default()
         };
 
+        let control = RequestControl {
+            timeout: Duration::from_secs(settings.request_timeout_secs),
+            cancel: CancellationToken::new(),
+        };
+
         Self {
             discourse_analyzer: DiscourseAnalyzer::new(Arc::clone(&llm_service)),
             contradiction_detector: ContradictionDetector::new(Arc::clone(&llm_service))
-                .with_threshold(0.7),
+                .with_threshold(0.7)
+                .with_request_control(control.clone()),
             pragmatics_analyzer: PragmaticsAnalyzer::new(Arc::clone(&llm_service))
                 .with_threshold(0.6),
+            coherence_analyzer: CoherenceAnalyzer::new(Arc::clone(&llm_service))
+                .with_threshold(0.5)
+                .with_request_control(control.clone()),
             batcher: Arc::new(RequestBatcher::new(batch_config)),
             _llm_service: llm_service,
-            _settings: settings,
+            settings,
             cache,
             request_rx,
+            cancel: control.cancel,
         }
     }
 
+    /// Token that cancels in-flight analysis calls and stops the processor
+    pub fn cancellation_token(&self) -> CancellationToken {
+        self.cancel.clone()
+    }
+
+    /// Give the processor memory storage
+    ///
+    /// With `enable_memory_contradictions` set, contradiction detection also
+    /// checks text against related high-importance memories.
+    pub fn with_storage(mut self, storage: Arc<dyn StorageBackend>) -> Self {
+        if self.settings.enable_memory_contradictions {
+            self.contradiction_detector = self
+                .contradiction_detector
+                .with_memory_search(storage, self.settings.memory_contradiction_min_importance);
+        }
+        self
+    }
+
     /// Get cached highlights (non-blocking)
     pub fn get_cached_highlights(&self, text: &str) -> Result<Vec<HighlightSpan>> {
         let mut spans = Vec::new();
@@ -120,6 +186,7 @@ impl AnalyticalProcessor {
                     &self.discourse_analyzer,
                     &self.contradiction_detector,
                     &self.pragmatics_analyzer,
+                    &self.coherence_analyzer,
                 ));
             }
         }
@@ -128,21 +195,38 @@ impl AnalyticalProcessor {
     }
 
     /// Background processing loop
+    ///
+    /// Runs until the request channel closes or the processor is cancelled;
+    /// either way (or if the task is aborted) in-flight calls are cancelled.
     pub async fn run(mut self) -> Result<()> {
+        let cancel = self.cancel.clone();
+        let _cancel_on_exit = cancel.clone().drop_guard();
+
         // Clone resources for batch processing task
         let batcher = Arc::clone(&self.batcher);
         let cache = Arc::clone(&self.cache);
-        let discourse = self.discourse_analyzer.clone();
-        let contradiction = self.contradiction_detector.clone();
-        let pragmatics = self.pragmatics_analyzer.clone();
+        let analyzers = Analyzers {
+            discourse: self.discourse_analyzer.clone(),
+            contradiction: self.contradiction_detector.clone(),
+            pragmatics: self.pragmatics_analyzer.clone(),
+            coherence: self.coherence_analyzer.clone(),
+        };
 
         // Spawn batch processing task
+        let batch_cancel = cancel.clone();
         tokio::spawn(async move {
-            Self::batch_processing_loop(batcher, discourse, contradiction, pragmatics, cache).await
+            Self::batch_processing_loop(batcher, analyzers, cache, batch_cancel).await
         });
 
         // Main request processing loop
-        while let Some(request) = self.request_rx.recv().await {
+        loop {
+            let request = tokio::select! {
+                _ = cancel.cancelled() => break,
+                request = self.request_rx.recv() => match request {
+                    Some(request) => request,
+                    None => break,
+                },
+            };
             match request {
                 AnalysisRequest::Full { text } => {
                     info!("Full document analysis requested (len: {})", text.len());
@@ -151,11 +235,7 @@ impl AnalyticalProcessor {
                     let hash = ContentHash::from_content(&text);
                     let timestamp = Instant::now();
 
-                    for analysis_type in [
-                        AnalysisType::Discourse,
-                        AnalysisType::Contradiction,
-                        AnalysisType::Pragmatics,
-                    ] {
+                    for analysis_type in ANALYSIS_TYPES {
                         let request = BatchRequest {
                             id: format!("{:?}-{:?}", hash, analysis_type),
                             text: text.clone(),
@@ -188,11 +268,7 @@ impl AnalyticalProcessor {
                     let hash = ContentHash::from_content(range_text);
                     let timestamp = Instant::now();
 
-                    for analysis_type in [
-                        AnalysisType::Discourse,
-                        AnalysisType::Contradiction,
-                        AnalysisType::Pragmatics,
-                    ] {
+                    for analysis_type in ANALYSIS_TYPES {
                         let request = BatchRequest {
                             id: format!("{:?}-{:?}-{:?}", hash, range, analysis_type),
                             text: range_text.to_string(),
@@ -220,14 +296,13 @@ impl AnalyticalProcessor {
     /// Batch processing loop
     async fn batch_processing_loop(
         batcher: Arc<RequestBatcher>,
-        discourse: DiscourseAnalyzer,
-        contradiction: ContradictionDetector,
-        pragmatics: PragmaticsAnalyzer,
+        analyzers: Analyzers,
         cache: Arc<SemanticCache>,
+        cancel: CancellationToken,
     ) {
         info!("Starting batch processing loop");
 
-        loop {
+        while !cancel.is_cancelled() {
             // Check if batch is ready
             if !batcher.should_process_batch().await {
                 tokio::time::sleep(Duration::from_millis(100)).await;
@@ -248,57 +323,73 @@ impl AnalyticalProcessor {
                 continue;
             }
 
-            info!("Processing batch of {} requests", batch.len());
-
-            // Group by analysis type
-            let mut discourse_requests = Vec::new();
-            let mut contradiction_requests = Vec::new();
-            let mut pragmatics_requests = Vec::new();
-
-            for req in batch {
-                match req.analysis_type {
-                    AnalysisType::Discourse => discourse_requests.push(req),
-                    AnalysisType::Contradiction => contradiction_requests.push(req),
-                    AnalysisType::Pragmatics => pragmatics_requests.push(req),
-                    AnalysisType::Coherence => {
-                        // Coherence analysis not yet fully implemented
-                        warn!("Coherence analysis requested but not implemented");
-                    }
-                }
-            }
+            Self::process_batch(&batcher, batch, &analyzers, &cache).await;
+        }
+    }
 
-            // Process each type in parallel
-            let discourse_handle = {
-                let cache = Arc::clone(&cache);
-                let analyzer = discourse.clone();
-                tokio::spawn(async move {
-                    Self::process_discourse_batch(discourse_requests, analyzer, cache).await
-                })
-            };
+    /// Run one batch through the analyzers, then release its dedup entries
+    async fn process_batch(
+        batcher: &RequestBatcher,
+        batch: Vec<BatchRequest>,
+        analyzers: &Analyzers,
+        cache: &Arc<SemanticCache>,
+    ) {
+        info!("Processing batch of {} requests", batch.len());
+
+        // Collected up front, since the requests move into the workers
+        let processed_hashes: Vec<String> =
+            batch.iter().map(|req| req.content_hash.clone()).collect();
+
+        let GroupedRequests {
+            discourse: discourse_requests,
+            contradiction: contradiction_requests,
+            pragmatics: pragmatics_requests,
+            coherence: coherence_requests,
+        } = GroupedRequests::from_batch(batch);
+
+        // Process each type in parallel
+        let discourse_handle = {
+            let cache = Arc::clone(cache);
+            let analyzer = analyzers.discourse.clone();
+            tokio::spawn(async move {
+                Self::process_discourse_batch(discourse_requests, analyzer, cache).await
+            })
+        };
 
-            let contradiction_handle = {
-                let cache = Arc::clone(&cache);
-                let analyzer = contradiction.clone();
-                tokio::spawn(async move {
-                    Self::process_contradiction_batch(contradiction_requests, analyzer, cache).await
-                })
-            };
+        let contradiction_handle = {
+            let cache = Arc::clone(cache);
+            let analyzer = analyzers.contradiction.clone();
+            tokio::spawn(async move {
+                Self::process_contradiction_batch(contradiction_requests, analyzer, cache).await
+            })
+        };
 
-            let pragmatics_handle = {
-                let cache = Arc::clone(&cache);
-                let analyzer = pragmatics.clone();
-                tokio::spawn(async move {
-                    Self::process_pragmatics_batch(pragmatics_requests, analyzer, cache).await
-                })
-            };
+        let pragmatics_handle = {
+            let cache = Arc::clone(cache);
+            let analyzer = analyzers.pragmatics.clone();
+            tokio::spawn(async move {
+                Self::process_pragmatics_batch(pragmatics_requests, analyzer, cache).await
+            })
+        };
 
-            // Wait for all to complete
-            let _ = tokio::join!(discourse_handle, contradiction_handle, pragmatics_handle);
+        let coherence_handle = {
+            let cache = Arc::clone(cache);
+            let analyzer = analyzers.coherence.clone();
+            tokio::spawn(async move {
+                Self::process_coherence_batch(coherence_requests, analyzer, cache).await
+            })
+        };
 
-            // Clear dedup cache for processed requests
-            let all_hashes: Vec<String> = Vec::new(); // Will be populated from requests
-            batcher.clear_dedup(&all_hashes).await;
-        }
+        // Wait for all to complete
+        let _ = tokio::join!(
+            discourse_handle,
+            contradiction_handle,
+            pragmatics_handle,
+            coherence_handle
+        );
+
+        // Identical requests may be submitted again now that these finished
+        batcher.clear_dedup(&processed_hashes).await;
     }
 
     /// Process discourse analysis batch
@@ -329,10 +420,11 @@ impl AnalyticalProcessor {
                     }
                     Err(e) => {
                         retries += 1;
-                        if retries >= max_retries {
+                        // Timeouts are retried; cancellation and bad input are not
+                        if !e.is_retryable() || retries >= max_retries {
                             error!(
                                 "Discourse analysis failed for request {} after {} attempts: {}",
-                                request.id, max_retries, e
+                                request.id, retries, e
                             );
                             break;
                         }
@@ -380,9 +472,9 @@ impl AnalyticalProcessor {
                     }
                     Err(e) => {
                         retries += 1;
-                        if retries >= max_retries {
+                        if !e.is_retryable() || retries >= max_retries {
                             error!("Contradiction detection failed for request {} after {} attempts: {}",
-                                   request.id, max_retries, e);
+                                   request.id, retries, e);
                             break;
                         }
 
@@ -426,10 +518,10 @@ impl AnalyticalProcessor {
                     }
                     Err(e) => {
                         retries += 1;
-                        if retries >= max_retries {
+                        if !e.is_retryable() || retries >= max_retries {
                             error!(
                                 "Pragmatics analysis failed for request {} after {} attempts: {}",
-                                request.id, max_retries, e
+                                request.id, retries, e
                             );
                             break;
                         }
@@ -445,4 +537,186 @@ impl AnalyticalProcessor {
             }
         }
     }
+
+    /// Process coherence analysis batch
+    async fn process_coherence_batch(
+        requests: Vec<BatchRequest>,
+        analyzer: CoherenceAnalyzer,
+        cache: Arc<SemanticCache>,
+    ) {
+        for request in requests {
+            debug!("Processing coherence request: {}", request.id);
+
+            // Retry with exponential backoff
+            let mut retries = 0;
+            let max_retries = 3;
+
+            loop {
+                match analyzer.analyze(&request.text).await {
+                    Ok(analysis) => {
+                        debug!(
+                            "Coherence analysis completed: {} weak transitions",
+                            analysis.transitions.len()
+                        );
+
+                        // Store in cache
+                        let result = AnalysisResult::Coherence(analysis);
+                        if let Ok(value) = serde_json::to_value(&result) {
+                            let cached = CachedResult::new(value);
+                            cache.analytical.insert_with_content(&request.text, cached);
+                        }
+                        break;
+                    }
+                    Err(e) => {
+                        retries += 1;
+                        if !e.is_retryable() || retries >= max_retries {
+                            error!(
+                                "Coherence analysis failed for request {} after {} attempts: {}",
+                                request.id, retries, e
+                            );
+                            break;
+                        }
+
+                        let backoff = Duration::from_millis(100 * 2_u64.pow(retries));
+                        warn!(
+                            "Coherence analysis failed (attempt {}/{}), retrying in {:?}: {}",
+                            retries, max_retries, backoff, e
+                        );
+                        tokio::time::sleep(backoff).await;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Analyzers shared with the batch processing task
+#[derive(Clone)]
+struct Analyzers {
+    discourse: DiscourseAnalyzer,
+    contradiction: ContradictionDetector,
+    pragmatics: PragmaticsAnalyzer,
+    coherence: CoherenceAnalyzer,
+}
+
+/// A batch split by analysis type
+#[derive(Default)]
+struct GroupedRequests {
+    discourse: Vec<BatchRequest>,
+    contradiction: Vec<BatchRequest>,
+    pragmatics: Vec<BatchRequest>,
+    coherence: Vec<BatchRequest>,
+}
+
+impl GroupedRequests {
+    fn from_batch(batch: Vec<BatchRequest>) -> Self {
+        let mut grouped = Self::default();
+        for req in batch {
+            match req.analysis_type {
+                AnalysisType::Discourse => grouped.discourse.push(req),
+                AnalysisType::Contradiction => grouped.contradiction.push(req),
+                AnalysisType::Pragmatics => grouped.pragmatics.push(req),
+                AnalysisType::Coherence => grouped.coherence.push(req),
+            }
+        }
+        grouped
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(analysis_type: AnalysisType) -> BatchRequest {
+        BatchRequest {
+            id: format!("req-{:?}", analysis_type),
+            text: "Some text. More text.".to_string(),
+            content_hash: "hash".to_string(),
+            analysis_type,
+            priority: 5,
+            submitted_at: Instant::now(),
+        }
+    }
+
+    #[test]
+    fn test_coherence_requests_are_dispatched() {
+        let grouped = GroupedRequests::from_batch(ANALYSIS_TYPES.map(request).to_vec());
+
+        assert_eq!(grouped.discourse.len(), 1);
+        assert_eq!(grouped.contradiction.len(), 1);
+        assert_eq!(grouped.pragmatics.len(), 1);
+        assert_eq!(grouped.coherence.len(), 1);
+        assert_eq!(grouped.coherence[0].analysis_type, AnalysisType::Coherence);
+    }
+
+    #[test]
+    fn test_coherence_result_round_trips_through_cache() {
+        let result = AnalysisResult::Coherence(CoherenceAnalysis {
+            score: CoherenceScore {
+                overall: 0.4,
+                topic_consistency: 0.5,
+                logical_flow: 0.3,
+                issues: vec!["Topic drift".to_string()],
+            },
+            transitions: vec![CoherenceTransition {
+                range: 11..21,
+                score: 0.2,
+                issue: "Abrupt topic change".to_string(),
+            }],
+        });
+
+        let value = serde_json::to_value(&result).unwrap();
+        let restored: AnalysisResult = serde_json::from_value(value).unwrap();
+        let AnalysisResult::Coherence(analysis) = restored else {
+            panic!("expected a coherence result");
+        };
+        assert_eq!(analysis.transitions[0].range, 11..21);
+        assert_eq!(analysis.score.issues, vec!["Topic drift".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_processed_content_can_be_resubmitted() {
+        // No API key: every analysis fails fast, which still counts as processed
+        let llm = Arc::new(
+            LlmService::new(crate::LlmConfig {
+                api_key: String::new(),
+                model: "claude-haiku-4-5-20251001".to_string(),
+                max_tokens: 1024,
+                temperature: 0.7,
+                task_models: Default::default(),
+            })
+            .unwrap(),
+        );
+        let analyzers = Analyzers {
+            discourse: DiscourseAnalyzer::new(Arc::clone(&llm)),
+            contradiction: ContradictionDetector::new(Arc::clone(&llm)),
+            pragmatics: PragmaticsAnalyzer::new(Arc::clone(&llm)),
+            coherence: CoherenceAnalyzer::new(llm),
+        };
+        let cache = Arc::new(SemanticCache::default());
+        let batcher = RequestBatcher::new(BatchConfig::default());
+
+        batcher
+            .submit(request(AnalysisType::Discourse))
+            .await
+            .unwrap();
+        let batch = batcher.get_batch().await.unwrap();
+        assert_eq!(batch.len(), 1);
+
+        // Still in flight: an identical request is deduplicated
+        batcher
+            .submit(request(AnalysisType::Discourse))
+            .await
+            .unwrap();
+        assert_eq!(batcher.queue_size().await, 0);
+
+        AnalyticalProcessor::process_batch(&batcher, batch, &analyzers, &cache).await;
+
+        // Processed: the same content is accepted again
+        batcher
+            .submit(request(AnalysisType::Discourse))
+            .await
+            .unwrap();
+        assert_eq!(batcher.queue_size().await, 1);
+    }
 }
@@ -2,6 +2,8 @@
 
 use ratatui::style::Style;
 use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
 use std::ops::Range;
 
 pub mod annotations;
@@ -150,55 +152,65 @@ impl SpanMerger {
     }
 
     /// Merge overlapping spans, keeping highest priority
+    ///
+    /// Where spans overlap the higher-priority one wins (ties go to the
+    /// earlier start, then to the span added first). A lower-priority span
+    /// keeps whatever it covers outside the winner, so it may come back as a
+    /// prefix, a suffix, or both. Output is sorted and non-overlapping.
     pub fn merge(mut self) -> Vec<HighlightSpan> {
+        self.spans.retain(|s| s.range.start < s.range.end);
         if self.spans.is_empty() {
             return Vec::new();
         }
 
-        // Sort by start position, then by priority (descending)
-        self.spans.sort_by(|a, b| {
-            a.range
-                .start
-                .cmp(&b.range.start)
-                .then_with(|| b.source.cmp(&a.source))
-        });
-
-        let mut result = Vec::new();
-        let mut current: Option<HighlightSpan> = None;
-
-        for span in self.spans {
-            match current.take() {
-                None => {
-                    current = Some(span);
-                }
-                Some(prev) => {
-                    if span.range.start < prev.range.end {
-                        // Overlapping - keep higher priority
-                        if span.source > prev.source {
-                            // Handle partial overlap
-                            if prev.range.start < span.range.start {
-                                // Add non-overlapping part of prev
-                                result.push(HighlightSpan {
-                                    range: prev.range.start..span.range.start,
-                                    ..prev.clone()
-                                });
-                            }
-                            current = Some(span);
-                        } else {
-                            // Keep prev, but may need to split
-                            current = Some(prev);
-                        }
-                    } else {
-                        // Non-overlapping
-                        result.push(prev);
-                        current = Some(span);
-                    }
-                }
+        // Stable sort, so insertion order breaks remaining ties
+        self.spans.sort_by_key(|s| s.range.start);
+
+        let mut boundaries: Vec<usize> = self
+            .spans
+            .iter()
+            .flat_map(|s| [s.range.start, s.range.end])
+            .collect();
+        boundaries.sort_unstable();
+        boundaries.dedup();
+
+        // Spans covering the current segment, best on top; expired ones are
+        // dropped lazily once they surface
+        let mut active: BinaryHeap<(HighlightSource, Reverse<usize>)> = BinaryHeap::new();
+        let mut next = 0;
+        let mut result: Vec<HighlightSpan> = Vec::new();
+        let mut last_winner: Option<usize> = None;
+
+        for window in boundaries.windows(2) {
+            let (seg_start, seg_end) = (window[0], window[1]);
+
+            while next < self.spans.len() && self.spans[next].range.start <= seg_start {
+                active.push((self.spans[next].source, Reverse(next)));
+                next += 1;
+            }
+            while active
+                .peek()
+                .is_some_and(|&(_, Reverse(i))| self.spans[i].range.end <= seg_start)
+            {
+                active.pop();
             }
-        }
 
-        if let Some(span) = current {
-            result.push(span);
+            let Some(&(_, Reverse(winner))) = active.peek() else {
+                last_winner = None;
+                continue;
+            };
+
+            match result.last_mut() {
+                // Same span continuing: extend rather than fragment it
+                Some(last) if last_winner == Some(winner) && last.range.end == seg_start => {
+                    last.range.end = seg_end;
+                }
+                _ => result.push(HighlightSpan {
+                    range: seg_start..seg_end,
+                    ..self.spans[winner].clone()
+                }),
+            }
+            last_winner = Some(winner);
         }
 
         result
@@ -269,4 +281,86 @@ mod tests {
             .iter()
             .any(|s| s.source == HighlightSource::Relational));
     }
+
+    fn merged(spans: Vec<(Range<usize>, HighlightSource)>) -> Vec<(Range<usize>, HighlightSource)> {
+        let mut merger = SpanMerger::new();
+        for (range, source) in spans {
+            merger.add(HighlightSpan::new(range, Style::default(), source));
+        }
+        merger
+            .merge()
+            .into_iter()
+            .map(|s| (s.range, s.source))
+            .collect()
+    }
+
+    #[test]
+    fn test_span_merger_keeps_prefix_of_lower() {
+        let result = merged(vec![
+            (0..10, HighlightSource::Syntax),
+            (5..15, HighlightSource::Relational),
+        ]);
+        assert_eq!(
+            result,
+            vec![
+                (0..5, HighlightSource::Syntax),
+                (5..15, HighlightSource::Relational),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_span_merger_keeps_suffix_of_lower() {
+        // Higher span starts first
+        let result = merged(vec![
+            (0..10, HighlightSource::Analytical),
+            (5..15, HighlightSource::Syntax),
+        ]);
+        assert_eq!(
+            result,
+            vec![
+                (0..10, HighlightSource::Analytical),
+                (10..15, HighlightSource::Syntax),
+            ]
+        );
+
+        // Same start, lower span runs longer
+        let result = merged(vec![
+            (0..15, HighlightSource::Syntax),
+            (0..10, HighlightSource::Structural),
+        ]);
+        assert_eq!(
+            result,
+            vec![
+                (0..10, HighlightSource::Structural),
+                (10..15, HighlightSource::Syntax),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_span_merger_splits_around_contained_higher() {
+        let result = merged(vec![
+            (0..20, HighlightSource::Syntax),
+            (5..10, HighlightSource::Relational),
+            (12..14, HighlightSource::Analytical),
+        ]);
+        assert_eq!(
+            result,
+            vec![
+                (0..5, HighlightSource::Syntax),
+                (5..10, HighlightSource::Relational),
+                (10..12, HighlightSource::Syntax),
+                (12..14, HighlightSource::Analytical),
+                (14..20, HighlightSource::Syntax),
+            ]
+        );
+
+        // A lower span fully inside a higher one disappears
+        let result = merged(vec![
+            (0..20, HighlightSource::Analytical),
+            (5..10, HighlightSource::Syntax),
+        ]);
+        assert_eq!(result, vec![(0..20, HighlightSource::Analytical)]);
+    }
 }
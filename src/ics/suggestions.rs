@@ -164,11 +164,12 @@ impl CompletionEngine {
         let mut all_completions = registry_completions;
         all_completions.extend(memory_completions);
 
-        // Sort by score (highest first)
+        // Sort by score (highest first), then alphabetically for a stable list
         all_completions.sort_by(|a, b| {
             b.score
                 .partial_cmp(&a.score)
                 .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.text.cmp(&b.text))
         });
 
         // Deduplicate by text
@@ -192,11 +193,12 @@ impl CompletionEngine {
         // Merge
         registry_completions.extend(filesystem_completions);
 
-        // Sort by score
+        // Sort by score, then alphabetically for a stable list
         registry_completions.sort_by(|a, b| {
             b.score
                 .partial_cmp(&a.score)
                 .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.text.cmp(&b.text))
         });
 
         // Deduplicate
@@ -466,4 +468,50 @@ mod tests {
         assert!(!completions.is_empty());
         assert!(completions.iter().any(|c| c.text == "@test_symbol"));
     }
+
+    #[tokio::test]
+    async fn test_symbol_completion_narrows_as_typed() {
+        let (engine, _temp) = create_test_engine().await;
+        let pos = Position { line: 0, column: 0 };
+        {
+            let mut registry = engine.symbol_registry.write().unwrap();
+            registry.register_symbol(
+                "@orchestrator",
+                pos,
+                SymbolKind::Function,
+                Some("Coordinates agents".to_string()),
+            );
+            registry.register_symbol("@order", pos, SymbolKind::Variable, None);
+        }
+
+        let texts: Vec<String> = engine
+            .get_completions("@or", 3)
+            .await
+            .into_iter()
+            .map(|c| c.text)
+            .collect();
+        assert_eq!(texts, vec!["@orchestrator", "@order"]);
+
+        let completions = engine.get_completions("@orch", 5).await;
+        assert_eq!(completions.len(), 1);
+        assert_eq!(completions[0].kind, SymbolKind::Function);
+        assert_eq!(completions[0].detail.as_deref(), Some("Coordinates agents"));
+
+        assert!(engine.get_completions("@zzz", 4).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_file_completion_from_registry() {
+        let (engine, _temp) = create_test_engine().await;
+        engine.symbol_registry.write().unwrap().register_file(
+            PathBuf::from("src/main.rs"),
+            Position { line: 0, column: 0 },
+            None,
+        );
+
+        let completions = engine.get_completions("#src/ma", 7).await;
+        assert!(completions
+            .iter()
+            .any(|c| c.text == "#src/main.rs" && c.kind == SymbolKind::File));
+    }
 }
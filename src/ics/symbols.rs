@@ -423,9 +423,9 @@ impl SymbolRegistry {
     ///
     /// Returns the minimum number of single-character edits (insertions, deletions, or substitutions)
     /// required to change one string into the other.
-    fn levenshtein_distance(s1: &str, s2: &str) -> usize {
-        let len1 = s1.len();
-        let len2 = s2.len();
+    pub(crate) fn levenshtein_distance(s1: &str, s2: &str) -> usize {
+        let len1 = s1.chars().count();
+        let len2 = s2.chars().count();
 
         if len1 == 0 {
             return len2;
@@ -9,21 +9,55 @@
 //! - Format as natural language summary
 //! - Include semantic links and relationships
 //! - Respect context budget allocation (20%)
+//! - Fill a token/memory budget greedily by importance and recency
 //! - Graceful degradation on errors
+//!
+//! [`render_context_block`] is the compact one-line-per-memory format shared
+//! with `mnemosyne recall --format context`.
+//!
+//! Budget and timeout are read from the `[context]` section of the
+//! config file (see [`crate::config::MnemosyneSettings`]).
 
-use crate::error::Result;
+use crate::error::{MnemosyneError, Result};
 use crate::storage::{MemorySortOrder, StorageBackend};
 use crate::types::{MemoryNote, Namespace};
 use crate::utils::string::truncate_at_char_boundary;
 use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 use std::sync::Arc;
 
-/// Configuration for context loading
-#[derive(Debug, Clone)]
+/// Candidates fetched per memory slot, so filtering still leaves enough
+const CANDIDATE_OVERFETCH: usize = 3;
+
+/// Age at which a memory's recency score has halved
+const RECENCY_HALF_LIFE_DAYS: f64 = 30.0;
+
+/// Share of the ranking score given to importance (the rest is recency)
+const IMPORTANCE_WEIGHT: f64 = 0.7;
+
+/// Approximate bytes per token when estimating context size
+const BYTES_PER_TOKEN: usize = 4;
+
+/// Tags shown per memory in a context block
+const CONTEXT_BLOCK_TAGS: usize = 3;
+
+/// Content shown for a memory without a summary
+const CONTEXT_BLOCK_FALLBACK_CHARS: usize = 100;
+
+/// Configuration for context loading (`[context]` in the config file)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct ContextLoadConfig {
     /// Maximum number of memories to load
     pub max_memories: usize,
 
+    /// Approximate token budget for the memories included
+    pub max_context_tokens: usize,
+
+    /// Give up on startup context after this long (milliseconds)
+    pub timeout_ms: u64,
+
     /// Minimum importance threshold (1-10)
     pub min_importance: u8,
 
@@ -38,6 +72,8 @@ impl Default for ContextLoadConfig {
     fn default() -> Self {
         Self {
             max_memories: 10,
+            max_context_tokens: 2_500,
+            timeout_ms: 500,
             min_importance: 7,
             max_size_bytes: 10 * 1024, // 10KB (20% of ~50KB context budget)
             include_metadata: true,
@@ -45,6 +81,26 @@ impl Default for ContextLoadConfig {
     }
 }
 
+impl ContextLoadConfig {
+    /// Validate context loading configuration
+    pub fn validate(&self) -> Result<()> {
+        if self.timeout_ms == 0 {
+            return Err(MnemosyneError::Config(config::ConfigError::Message(
+                "context.timeout_ms must be at least 1".to_string(),
+            )));
+        }
+        if self.min_importance > 10 {
+            return Err(MnemosyneError::Config(config::ConfigError::Message(
+                format!(
+                    "context.min_importance must be between 0 and 10, got {}",
+                    self.min_importance
+                ),
+            )));
+        }
+        Ok(())
+    }
+}
+
 /// Context loader for session startup
 pub struct ContextLoader {
     storage: Arc<dyn StorageBackend>,
@@ -80,20 +136,15 @@ impl ContextLoader {
             .storage
             .list_memories(
                 Some(ns),
-                config.max_memories * 2,
+                config.max_memories * CANDIDATE_OVERFETCH,
                 MemorySortOrder::Importance,
             )
             .await?;
 
-        // Filter by importance threshold
-        let filtered: Vec<_> = memories
-            .into_iter()
-            .filter(|m| m.importance >= config.min_importance)
-            .take(config.max_memories)
-            .collect();
+        let selected = select_within_budget(memories, config, Utc::now());
 
         // Format context
-        self.format_context(&filtered, namespace, config)
+        self.format_context(&selected, namespace, config)
     }
 
     /// Generate compact context for quick session startup
@@ -102,6 +153,8 @@ impl ContextLoader {
     pub async fn generate_compact_prompt(&self, namespace: &str) -> Result<String> {
         let compact_config = ContextLoadConfig {
             max_memories: 5,
+            max_context_tokens: 1_250,
+            timeout_ms: 500,
             min_importance: 8,
             max_size_bytes: 5 * 1024, // 5KB
             include_metadata: false,
@@ -127,27 +180,12 @@ impl ContextLoader {
         // Header
         output.push_str(&format!("# Project Context: {}\n\n", namespace));
 
-        // Group by importance tier
-        let critical: Vec<_> = memories.iter().filter(|m| m.importance >= 8).collect();
-        let important: Vec<_> = memories.iter().filter(|m| m.importance == 7).collect();
-
-        // Critical memories (detailed)
-        if !critical.is_empty() {
-            output.push_str("## Critical Memories (Importance ≥ 8)\n\n");
-            for mem in critical {
-                output.push_str(&format_memory(mem, true));
-            }
-            output.push('\n');
-        }
-
-        // Important memories (compact)
-        if !important.is_empty() {
-            output.push_str("## Important Memories (Importance 7)\n\n");
-            for mem in important {
-                output.push_str(&format_memory(mem, false));
-            }
-            output.push('\n');
-        }
+        let now = Utc::now();
+        output.push_str(&render_context_block(
+            memories.iter().map(|m| (rank_score(m, now), m)),
+            config.max_context_tokens,
+        ));
+        output.push('\n');
 
         // Knowledge graph summary
         if config.include_metadata {
@@ -176,6 +214,116 @@ impl ContextLoader {
     }
 }
 
+/// Pick the memories to include, filling the budget greedily
+///
+/// Memories below `min_importance` are dropped, the rest ranked by a blend
+/// of importance and recency. Each is taken in rank order if its formatted
+/// size still fits `max_context_tokens`; one that doesn't is skipped in
+/// favour of smaller, lower-ranked ones. Stops at `max_memories`.
+fn select_within_budget(
+    memories: Vec<MemoryNote>,
+    config: &ContextLoadConfig,
+    now: DateTime<Utc>,
+) -> Vec<MemoryNote> {
+    let mut ranked: Vec<(f64, MemoryNote)> = memories
+        .into_iter()
+        .filter(|m| m.importance >= config.min_importance)
+        .map(|m| (rank_score(&m, now), m))
+        .collect();
+    ranked.sort_by(|a, b| b.0.total_cmp(&a.0));
+
+    let mut selected = Vec::new();
+    let mut tokens_used = 0;
+    for (_, memory) in ranked {
+        if selected.len() >= config.max_memories {
+            break;
+        }
+        let tokens = estimate_tokens(&memory);
+        if tokens_used + tokens > config.max_context_tokens {
+            continue;
+        }
+        tokens_used += tokens;
+        selected.push(memory);
+    }
+
+    selected
+}
+
+/// Ranking score in 0.0-1.0: importance blended with exponential recency decay
+fn rank_score(memory: &MemoryNote, now: DateTime<Utc>) -> f64 {
+    let importance = f64::from(memory.importance.min(10)) / 10.0;
+    let age_days = (now - memory.updated_at).num_seconds().max(0) as f64 / 86_400.0;
+    let recency = 0.5_f64.powf(age_days / RECENCY_HALF_LIFE_DAYS);
+    IMPORTANCE_WEIGHT * importance + (1.0 - IMPORTANCE_WEIGHT) * recency
+}
+
+/// Approximate tokens a memory takes once formatted
+fn estimate_tokens(memory: &MemoryNote) -> usize {
+    context_line(memory).len().div_ceil(BYTES_PER_TOKEN)
+}
+
+/// Render memories as a compact context block for a prompt
+///
+/// One line per memory: summary with type, importance, age, and tags.
+/// Memories are ordered by relevance (highest first), then importance, and
+/// one repeating an earlier summary is dropped. Lines are added in order
+/// while they fit `max_tokens`; one that doesn't is skipped in favour of
+/// shorter ones after it.
+pub fn render_context_block<'a>(
+    memories: impl IntoIterator<Item = (f64, &'a MemoryNote)>,
+    max_tokens: usize,
+) -> String {
+    let mut ranked: Vec<(f64, &MemoryNote)> = memories.into_iter().collect();
+    ranked.sort_by(|a, b| {
+        b.0.total_cmp(&a.0)
+            .then(b.1.importance.cmp(&a.1.importance))
+    });
+
+    let mut seen = HashSet::new();
+    let mut block = String::new();
+    let mut tokens_used = 0;
+    for (_, memory) in ranked {
+        if !seen.insert(context_text(memory).to_lowercase()) {
+            continue;
+        }
+        let line = context_line(memory);
+        let tokens = line.len().div_ceil(BYTES_PER_TOKEN);
+        if tokens_used + tokens > max_tokens {
+            continue;
+        }
+        tokens_used += tokens;
+        block.push_str(&line);
+    }
+    block
+}
+
+/// One context block line
+fn context_line(memory: &MemoryNote) -> String {
+    let mut line = format!(
+        "- [{} {}/10, {}] {}",
+        format_memory_type(&memory.memory_type),
+        memory.importance,
+        relative_time(&memory.updated_at),
+        context_text(memory)
+    );
+    for tag in memory.tags.iter().take(CONTEXT_BLOCK_TAGS) {
+        line.push_str(" #");
+        line.push_str(tag);
+    }
+    line.push('\n');
+    line
+}
+
+/// Summary, or the start of the content when there is none
+fn context_text(memory: &MemoryNote) -> String {
+    let summary = memory.summary.trim();
+    if summary.is_empty() {
+        truncate_content(memory.content.trim(), CONTEXT_BLOCK_FALLBACK_CHARS)
+    } else {
+        summary.to_string()
+    }
+}
+
 /// Parse namespace string to Namespace enum
 fn parse_namespace(s: &str) -> Namespace {
     if s == "global" {
@@ -201,29 +349,8 @@ fn parse_namespace(s: &str) -> Namespace {
     }
 }
 
-/// Format a single memory for context display
-fn format_memory(mem: &MemoryNote, detailed: bool) -> String {
-    if detailed {
-        // Detailed format for critical memories
-        format!(
-            "**{}** — {} — {}\n{}\n\n",
-            mem.summary,
-            format_memory_type(&mem.memory_type),
-            relative_time(&mem.updated_at),
-            truncate_content(&mem.content, 200)
-        )
-    } else {
-        // Compact format for important memories
-        format!(
-            "- {} — {}\n",
-            mem.summary,
-            format_memory_type(&mem.memory_type)
-        )
-    }
-}
-
 /// Format memory type for display
-fn format_memory_type(mt: &crate::types::MemoryType) -> &'static str {
+fn format_memory_type(mt: &crate::types::MemoryType) -> &str {
     use crate::types::MemoryType;
     match mt {
         MemoryType::ArchitectureDecision => "Architecture",
@@ -244,6 +371,7 @@ fn format_memory_type(mt: &crate::types::MemoryType) -> &'static str {
         MemoryType::TaskBreakdown => "Task Breakdown",
         MemoryType::QualityChecklist => "Quality Checklist",
         MemoryType::Clarification => "Clarification",
+        MemoryType::Custom(name) => name.as_str(),
     }
 }
 
@@ -392,77 +520,179 @@ mod tests {
     }
 
     #[test]
-    fn test_format_memory_detailed() {
-        let memory = MemoryNote {
+    fn test_context_line() {
+        let mut memory = budget_memory("Test summary", 8, 0);
+        memory.content = "Test content for memory".to_string();
+        memory.tags = vec!["db".to_string(), "perf".to_string()];
+
+        let line = context_line(&memory);
+        assert_eq!(line, "- [Insight 8/10, just now] Test summary #db #perf\n");
+        assert!(!line.contains("Test content")); // Summaries stand in for content
+    }
+
+    #[test]
+    fn test_context_line_without_summary() {
+        let mut memory = budget_memory("", 7, 0);
+        memory.memory_type = MemoryType::CodePattern;
+        memory.content = "Test content".to_string();
+
+        let line = context_line(&memory);
+        assert!(line.contains("Pattern"));
+        assert!(line.contains("Test content"));
+    }
+
+    #[test]
+    fn test_context_block_orders_by_relevance_then_importance() {
+        let low = budget_memory("low relevance", 10, 0);
+        let tied_minor = budget_memory("tied minor", 5, 0);
+        let tied_major = budget_memory("tied major", 9, 0);
+        let duplicate = budget_memory("Tied Major", 9, 0);
+
+        let block = render_context_block(
+            [
+                (0.2, &low),
+                (0.8, &tied_minor),
+                (0.8, &tied_major),
+                (0.5, &duplicate),
+            ],
+            10_000,
+        );
+        let order: Vec<&str> = block
+            .lines()
+            .map(|line| line.split("] ").nth(1).unwrap())
+            .collect();
+        assert_eq!(order, vec!["tied major", "tied minor", "low relevance"]);
+    }
+
+    #[test]
+    fn test_context_block_respects_token_cap() {
+        let memories: Vec<MemoryNote> = (0..20)
+            .map(|i| budget_memory(&format!("memory number {}", i), 5, 0))
+            .collect();
+        let per_line = estimate_tokens(&memories[0]);
+        let cap = per_line * 5 + per_line / 2;
+
+        let block = render_context_block(
+            memories
+                .iter()
+                .enumerate()
+                .map(|(i, m)| (1.0 - i as f64 / 100.0, m)),
+            cap,
+        );
+        assert!(block.len().div_ceil(BYTES_PER_TOKEN) <= cap);
+        assert_eq!(block.lines().count(), 5);
+        assert!(block.starts_with("- [Insight 5/10, just now] memory number 0\n"));
+    }
+
+    #[test]
+    fn test_context_load_config_default() {
+        let config = ContextLoadConfig::default();
+        assert_eq!(config.max_memories, 10);
+        assert_eq!(config.max_context_tokens, 2_500);
+        assert_eq!(config.timeout_ms, 500);
+        assert_eq!(config.min_importance, 7);
+        assert_eq!(config.max_size_bytes, 10 * 1024);
+        assert!(config.include_metadata);
+        assert!(config.validate().is_ok());
+    }
+
+    fn budget_memory(summary: &str, importance: u8, age_days: i64) -> MemoryNote {
+        let updated = Utc::now() - chrono::Duration::days(age_days);
+        MemoryNote {
             id: MemoryId::new(),
             namespace: Namespace::Global,
-            created_at: Utc::now(),
-            updated_at: Utc::now(),
-            content: "Test content for memory".to_string(),
-            summary: "Test summary".to_string(),
+            created_at: updated,
+            updated_at: updated,
+            content: "x".repeat(180),
+            summary: summary.to_string(),
             keywords: vec![],
             tags: vec![],
             context: "".to_string(),
             memory_type: MemoryType::Insight,
-            importance: 8,
+            importance,
             confidence: 0.9,
             links: vec![],
             related_files: vec![],
             related_entities: vec![],
             access_count: 0,
-            last_accessed_at: Utc::now(),
+            last_accessed_at: updated,
             expires_at: None,
             is_archived: false,
             superseded_by: None,
             embedding: None,
             embedding_model: "voyage-2".to_string(),
+        }
+    }
+
+    fn budget_memories() -> Vec<MemoryNote> {
+        vec![
+            budget_memory("old critical", 9, 365),
+            budget_memory("fresh critical", 9, 0),
+            budget_memory("fresh important", 8, 1),
+            budget_memory("stale important", 8, 90),
+            budget_memory("below threshold", 3, 0),
+        ]
+    }
+
+    fn summaries(memories: &[MemoryNote]) -> Vec<&str> {
+        memories.iter().map(|m| m.summary.as_str()).collect()
+    }
+
+    #[test]
+    fn test_budget_ranks_by_importance_and_recency() {
+        let config = ContextLoadConfig {
+            min_importance: 5,
+            max_context_tokens: 100_000,
+            ..ContextLoadConfig::default()
         };
 
-        let formatted = format_memory(&memory, true);
-        assert!(formatted.contains("Test summary"));
-        assert!(formatted.contains("Insight"));
-        assert!(formatted.contains("Test content"));
+        let selected = select_within_budget(budget_memories(), &config, Utc::now());
+        assert_eq!(
+            summaries(&selected),
+            vec![
+                "fresh critical",
+                "fresh important",
+                "old critical",
+                "stale important"
+            ]
+        );
     }
 
     #[test]
-    fn test_format_memory_compact() {
-        let memory = MemoryNote {
-            id: MemoryId::new(),
-            namespace: Namespace::Global,
-            created_at: Utc::now(),
-            updated_at: Utc::now(),
-            content: "Test content".to_string(),
-            summary: "Test summary".to_string(),
-            keywords: vec![],
-            tags: vec![],
-            context: "".to_string(),
-            memory_type: MemoryType::CodePattern,
-            importance: 7,
-            confidence: 0.9,
-            links: vec![],
-            related_files: vec![],
-            related_entities: vec![],
-            access_count: 0,
-            last_accessed_at: Utc::now(),
-            expires_at: None,
-            is_archived: false,
-            superseded_by: None,
-            embedding: None,
-            embedding_model: "voyage-2".to_string(),
+    fn test_tight_budget_truncates() {
+        let per_memory = estimate_tokens(&budget_memory("fresh critical", 9, 0));
+        let tight = ContextLoadConfig {
+            min_importance: 5,
+            // Room for two memories, not three
+            max_context_tokens: per_memory * 2 + per_memory / 2,
+            ..ContextLoadConfig::default()
         };
+        let generous = ContextLoadConfig {
+            max_context_tokens: per_memory * 10,
+            ..tight.clone()
+        };
+
+        let few = select_within_budget(budget_memories(), &tight, Utc::now());
+        let many = select_within_budget(budget_memories(), &generous, Utc::now());
 
-        let formatted = format_memory(&memory, false);
-        assert!(formatted.contains("Test summary"));
-        assert!(formatted.contains("Pattern"));
-        assert!(!formatted.contains("Test content")); // Compact mode doesn't include content
+        assert_eq!(summaries(&few), vec!["fresh critical", "fresh important"]);
+        assert_eq!(many.len(), 4);
+
+        // The memory count cap applies even when tokens remain
+        let capped = ContextLoadConfig {
+            max_memories: 1,
+            ..generous
+        };
+        let one = select_within_budget(budget_memories(), &capped, Utc::now());
+        assert_eq!(summaries(&one), vec!["fresh critical"]);
     }
 
     #[test]
-    fn test_context_load_config_default() {
-        let config = ContextLoadConfig::default();
-        assert_eq!(config.max_memories, 10);
-        assert_eq!(config.min_importance, 7);
-        assert_eq!(config.max_size_bytes, 10 * 1024);
-        assert!(config.include_metadata);
+    fn test_context_config_validation() {
+        let config = ContextLoadConfig {
+            timeout_ms: 0,
+            ..ContextLoadConfig::default()
+        };
+        assert!(config.validate().is_err());
     }
 }
@@ -16,7 +16,8 @@
 //!
 //! #[tokio::main]
 //! async fn main() -> Result<(), Box<dyn std::error::Error>> {
-//!     launcher::launch_orchestrated_session(None, None, None).await?;
+//!     launcher::launch_orchestrated_session(None, None, None, None, false, None, Vec::new())
+//!         .await?;
 //!     Ok(())
 //! }
 //! ```
@@ -24,13 +25,30 @@
 pub mod agents;
 pub mod context;
 pub mod mcp;
+pub mod shutdown;
 pub mod ui;
 
 use crate::error::{MnemosyneError, Result};
+use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
 use std::process::Command;
 use tracing::{debug, warn};
 
+/// Environment variable naming the Claude Code binary to launch
+pub const CLAUDE_BINARY_ENV: &str = "CLAUDE_BINARY";
+
+/// Launcher settings from the config file (`[launcher]`)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LauncherSettings {
+    /// Claude Code binary to try before searching common install locations
+    pub claude_binary_path: Option<PathBuf>,
+
+    /// Record each session's raw terminal output to this file (`--transcript`
+    /// overrides it)
+    pub transcript_path: Option<PathBuf>,
+}
+
 /// Configuration for launching Claude Code sessions
 #[derive(Clone)]
 pub struct LauncherConfig {
@@ -46,6 +64,9 @@ pub struct LauncherConfig {
     /// Mnemosyne namespace (auto-detect from git if None)
     pub mnemosyne_namespace: Option<String>,
 
+    /// Claude Code binary to try first (searched for if None)
+    pub claude_binary_path: Option<PathBuf>,
+
     /// Database path (use default if None)
     pub mnemosyne_db_path: Option<String>,
 
@@ -67,6 +88,20 @@ pub struct LauncherConfig {
     /// Initial prompt to send to Claude Code (optional)
     pub initial_prompt: Option<String>,
 
+    /// Work items queued with the orchestrator once it starts, in order
+    pub work_items: Vec<crate::orchestration::WorkItem>,
+
+    /// Print the launch command instead of running it (default: false)
+    pub dry_run: bool,
+
+    /// Repository to launch in (current directory if None)
+    pub working_dir: Option<PathBuf>,
+
+    /// Run Claude Code behind a PTY and record its raw output here
+    /// (see [`crate::pty::read_transcript`]); None runs it directly. Behind
+    /// the PTY, agent markers in the output are also broadcast as events.
+    pub transcript_path: Option<PathBuf>,
+
     /// Optional event broadcaster for real-time API updates
     pub event_broadcaster: Option<crate::api::EventBroadcaster>,
 
@@ -81,6 +116,7 @@ impl Default for LauncherConfig {
             enable_subagents: true,
             max_concurrent_agents: 4,
             mnemosyne_namespace: None,
+            claude_binary_path: None,
             mnemosyne_db_path: None,
             load_context_on_start: true,
             context_config: context::ContextLoadConfig::default(),
@@ -88,6 +124,10 @@ impl Default for LauncherConfig {
             model: "sonnet".to_string(),
             enable_hooks: true,
             initial_prompt: None,
+            work_items: Vec::new(),
+            dry_run: false,
+            working_dir: None,
+            transcript_path: None,
             event_broadcaster: None,
             state_manager: None,
         }
@@ -101,6 +141,7 @@ impl std::fmt::Debug for LauncherConfig {
             .field("enable_subagents", &self.enable_subagents)
             .field("max_concurrent_agents", &self.max_concurrent_agents)
             .field("mnemosyne_namespace", &self.mnemosyne_namespace)
+            .field("claude_binary_path", &self.claude_binary_path)
             .field("mnemosyne_db_path", &self.mnemosyne_db_path)
             .field("load_context_on_start", &self.load_context_on_start)
             .field("context_config", &self.context_config)
@@ -108,6 +149,10 @@ impl std::fmt::Debug for LauncherConfig {
             .field("model", &self.model)
             .field("enable_hooks", &self.enable_hooks)
             .field("initial_prompt", &self.initial_prompt)
+            .field("work_items", &self.work_items.len())
+            .field("dry_run", &self.dry_run)
+            .field("working_dir", &self.working_dir)
+            .field("transcript_path", &self.transcript_path)
             .field("event_broadcaster", &self.event_broadcaster.is_some())
             .field("state_manager", &self.state_manager.is_some())
             .finish()
@@ -120,6 +165,15 @@ pub struct ClaudeCodeLauncher {
     claude_binary: PathBuf,
 }
 
+/// Session worktree chosen before launch, not yet created
+struct WorktreePlan {
+    manager: crate::orchestration::WorktreeManager,
+    agent_id: crate::orchestration::AgentId,
+    branch: String,
+    path: PathBuf,
+    repo_root: PathBuf,
+}
+
 impl ClaudeCodeLauncher {
     /// Create a new launcher with default configuration
     pub fn new() -> Result<Self> {
@@ -128,7 +182,7 @@ impl ClaudeCodeLauncher {
 
     /// Create a new launcher with custom configuration
     pub fn with_config(config: LauncherConfig) -> Result<Self> {
-        let claude_binary = detect_claude_binary()?;
+        let claude_binary = detect_claude_binary_with(config.claude_binary_path.as_deref())?;
 
         Ok(Self {
             config,
@@ -165,22 +219,81 @@ impl ClaudeCodeLauncher {
         };
 
         // STEP 1.25: Setup git worktree for branch isolation (if in git repo)
-        let worktree_info = self.setup_worktree_isolation()?;
-        if let Some((ref agent_id, ref worktree_path, ref repo_root)) = worktree_info {
-            debug!(
-                "Using git worktree for isolation: {}",
-                worktree_path.display()
-            );
-            // Change directory to worktree
-            std::env::set_current_dir(worktree_path).map_err(|e| {
-                // Cleanup worktree if we fail to change directory
-                self.cleanup_worktree(agent_id, repo_root);
+        // A session runs in the worktree (or the launch directory), and the
+        // original directory is restored afterwards, so an embedding process
+        // ends up where it started. A dry run only reports the planned
+        // worktree: it creates nothing and never changes directory.
+        let launch_dir = match &self.config.working_dir {
+            Some(dir) => dir.clone(),
+            None => std::env::current_dir().map_err(|e| {
                 MnemosyneError::Io(std::io::Error::new(
                     e.kind(),
-                    format!("Failed to change to worktree directory: {}", e),
+                    format!("Failed to get current directory: {}", e),
                 ))
-            })?;
+            })?,
+        };
+        let worktree_plan = self.plan_worktree(&launch_dir)?;
+
+        if self.config.dry_run {
+            return self.dry_run(Some(storage), worktree_plan.as_ref()).await;
+        }
+
+        let worktree_info = worktree_plan.and_then(|plan| self.setup_worktree_isolation(plan));
+        let worktree_path = worktree_info.as_ref().map(|(_, path, _)| path.as_path());
+        if let Some(path) = worktree_path {
+            debug!("Using git worktree for isolation: {}", path.display());
+        }
+
+        let original_cwd = std::env::current_dir().ok();
+        let session_dir = worktree_path.unwrap_or(&launch_dir);
+        std::env::set_current_dir(session_dir).map_err(|e| {
+            // Cleanup worktree if we fail to change directory
+            if let Some((ref agent_id, _, ref repo_root)) = worktree_info {
+                self.cleanup_worktree(agent_id, repo_root, original_cwd.as_deref());
+            }
+            MnemosyneError::Io(std::io::Error::new(
+                e.kind(),
+                format!(
+                    "Failed to change to session directory {}: {}",
+                    session_dir.display(),
+                    e
+                ),
+            ))
+        })?;
+
+        let memory_monitor = crate::diagnostics::start_memory_monitoring(
+            crate::diagnostics::LeakThresholds::default(),
+            self.config.event_broadcaster.clone(),
+        );
+        let result = self.run_session(storage).await;
+        memory_monitor.abort();
+
+        // STEP 7: Cleanup worktree (if we created one), on success or error,
+        // and before a forced exit
+        match worktree_info {
+            Some((ref agent_id, _, ref repo_root)) => {
+                self.cleanup_worktree(agent_id, repo_root, original_cwd.as_deref());
+            }
+            None => {
+                if let Some(cwd) = &original_cwd {
+                    restore_working_dir(cwd);
+                }
+            }
+        }
+
+        if let Ok(shutdown::SessionEnd::Forced) = result {
+            std::process::exit(shutdown::FORCED_EXIT_CODE);
         }
+        result.map(|_| ())
+    }
+
+    /// Run Claude Code alongside the orchestration engine (steps 1.5-6)
+    async fn run_session(
+        &self,
+        storage: std::sync::Arc<crate::storage::libsql::LibsqlStorage>,
+    ) -> Result<shutdown::SessionEnd> {
+        // Listen for SIGTERM before anything needs shutting down
+        let mut shutdown_signal = shutdown::ShutdownSignal::install()?;
 
         // STEP 1.5: Initialize OrchestrationEngine
         let orchestration_config = crate::orchestration::SupervisionConfig {
@@ -188,6 +301,8 @@ impl ClaudeCodeLauncher {
             restart_window_secs: 60,
             enable_subagents: self.config.enable_subagents,
             max_concurrent_agents: self.config.max_concurrent_agents as usize,
+            network: load_settings().network,
+            ..Default::default()
         };
 
         let orchestration_engine = match crate::orchestration::OrchestrationEngine::new_with_state(
@@ -216,94 +331,165 @@ impl ClaudeCodeLauncher {
             }
         };
 
-        // STEP 2: Generate startup context with timeout protection
-        let startup_prompt = if self.config.load_context_on_start {
-            match tokio::time::timeout(
-                std::time::Duration::from_millis(500),
-                self.generate_startup_context_with_storage(storage.clone()),
-            )
-            .await
-            {
-                Ok(Ok(context)) => {
-                    debug!("Loaded startup context ({} bytes)", context.len());
-                    debug!(
-                        "Context preview: {}...",
-                        &context.chars().take(100).collect::<String>()
-                    );
-                    context
-                }
-                Ok(Err(e)) => {
-                    warn!("Context loading failed: {}", e);
-                    String::new()
-                }
-                Err(_) => {
-                    warn!("Context loading timed out (>500ms)");
-                    String::new()
-                }
+        // STEP 1.75: Queue the planned work items
+        match &orchestration_engine {
+            Some(engine) => {
+                let queued = queue_work_items(engine, &self.config.work_items).await;
+                debug!("Queued {} work items", queued);
             }
-        } else {
-            debug!("Context loading disabled by configuration");
-            String::new()
-        };
+            None => warn_unqueued(&self.config.work_items),
+        }
 
-        // STEP 3: Generate agent and MCP configurations
-        let agent_config = self.generate_agent_config()?;
-        let mcp_config = self.generate_mcp_config()?;
+        // STEP 2: Generate startup context with timeout protection
+        let startup_prompt = self.load_startup_context(storage.clone()).await;
 
-        // STEP 4: Build command arguments
-        let args = self.build_command_args(&agent_config, &mcp_config, &startup_prompt);
+        // STEP 3-5: Generate configurations, build arguments, and execute
+        // Claude Code with the orchestration engine running
+        let child = self.generate_agent_config().and_then(|agent_config| {
+            let mcp_config = self.generate_mcp_config()?;
 
-        debug!(
-            "Launching Claude Code with {} bytes of startup context",
-            startup_prompt.len()
-        );
+            // STEP 4: Build command arguments
+            let args = self.build_command_args(&agent_config, &mcp_config, &startup_prompt);
 
-        // STEP 5: Execute Claude Code with orchestration engine running
-        let status = Command::new(&self.claude_binary)
-            .args(&args)
-            .status()
-            .map_err(|e| MnemosyneError::Other(format!("Failed to launch Claude Code: {}", e)))?;
+            debug!(
+                "Launching Claude Code with {} bytes of startup context",
+                startup_prompt.len()
+            );
 
-        // STEP 6: Graceful shutdown of orchestration engine
+            self.spawn_claude(&args)
+        });
+
+        // Wait for Claude Code to exit, or for SIGTERM. On SIGTERM, Claude
+        // Code and the engine share one grace period before a forced exit.
+        let mut deadline = None;
+        let status = match child {
+            Ok(child) => {
+                let pid = child.id();
+                let mut wait = tokio::task::spawn_blocking(move || child.wait());
+                tokio::select! {
+                    result = &mut wait => exit_status(result),
+                    _ = shutdown_signal.recv() => {
+                        warn!("Received SIGTERM, shutting down session");
+                        let grace_end = tokio::time::Instant::now() + shutdown::SHUTDOWN_GRACE_PERIOD;
+                        deadline = Some(grace_end);
+                        if let Some(pid) = pid {
+                            shutdown::terminate_child(pid);
+                        }
+                        match tokio::time::timeout_at(grace_end, wait).await {
+                            Ok(result) => exit_status(result),
+                            Err(_) => Err(MnemosyneError::Other(
+                                "Claude Code did not exit after SIGTERM".to_string(),
+                            )),
+                        }
+                    }
+                }
+            }
+            Err(e) => Err(e),
+        };
+
+        // STEP 6: Graceful shutdown of orchestration engine, even if the launch failed
         if let Some(mut engine) = orchestration_engine {
             debug!("Shutting down orchestration engine");
-            if let Err(e) = engine.stop().await {
+            let stopped = match deadline {
+                Some(grace_end) => {
+                    let remaining =
+                        grace_end.saturating_duration_since(tokio::time::Instant::now());
+                    match tokio::time::timeout_at(grace_end, engine.stop_with_timeout(remaining))
+                        .await
+                    {
+                        Ok(stopped) => stopped,
+                        Err(_) => {
+                            warn!(
+                                "Orchestration engine did not stop within {:?}, forcing exit",
+                                shutdown::SHUTDOWN_GRACE_PERIOD
+                            );
+                            return Ok(shutdown::SessionEnd::Forced);
+                        }
+                    }
+                }
+                None => engine.stop().await,
+            };
+            if let Err(e) = stopped {
                 warn!("Error during orchestration shutdown: {}", e);
             }
         }
 
-        // STEP 7: Cleanup worktree (if we created one)
-        if let Some((agent_id, _, repo_root)) = worktree_info {
-            self.cleanup_worktree(&agent_id, &repo_root);
+        if deadline.is_some() {
+            // Terminated on request; Claude Code's exit status is expected to be non-zero
+            if let Err(e) = status {
+                warn!("{}", e);
+            }
+            return Ok(shutdown::SessionEnd::Completed);
         }
 
-        if !status.success() {
+        let status = status?;
+        if !status.success {
             return Err(MnemosyneError::Other(format!(
                 "Claude Code exited with status: {:?}",
-                status.code()
+                status.code
             )));
         }
 
-        Ok(())
+        Ok(shutdown::SessionEnd::Completed)
+    }
+
+    /// Start Claude Code with `args`, behind a PTY if a transcript is requested
+    fn spawn_claude(&self, args: &[String]) -> Result<ClaudeProcess> {
+        let Some(transcript_path) = &self.config.transcript_path else {
+            return Command::new(&self.claude_binary)
+                .args(args)
+                .spawn()
+                .map(ClaudeProcess::Direct)
+                .map_err(|e| {
+                    MnemosyneError::Other(format!("Failed to launch Claude Code: {}", e))
+                });
+        };
+
+        debug!(
+            "Recording session transcript to {}",
+            transcript_path.display()
+        );
+        let pty_config = crate::pty::PtyConfig {
+            command: self.claude_binary.to_string_lossy().into_owned(),
+            args: args.to_vec(),
+            transcript_path: Some(transcript_path.clone()),
+            ..Default::default()
+        };
+        crate::pty::ClaudeCodeWrapper::new(pty_config)
+            .and_then(|wrapper| {
+                // Agent markers in the output populate the dashboard
+                match &self.config.event_broadcaster {
+                    Some(broadcaster) => wrapper.with_event_broadcaster(broadcaster.clone()),
+                    None => wrapper,
+                }
+                .attach()
+            })
+            .map(ClaudeProcess::Attached)
+            .map_err(|e| MnemosyneError::Other(format!("Failed to launch Claude Code: {}", e)))
     }
 
     /// Launch without context (fallback for storage errors)
     async fn launch_without_context(&self) -> Result<()> {
+        if self.config.dry_run {
+            return self.dry_run(None, None).await;
+        }
+
         debug!("Launching without startup context");
+        warn_unqueued(&self.config.work_items);
 
         let agent_config = self.generate_agent_config()?;
         let mcp_config = self.generate_mcp_config()?;
         let args = self.build_command_args(&agent_config, &mcp_config, "");
 
-        let status = Command::new(&self.claude_binary)
-            .args(&args)
-            .status()
-            .map_err(|e| MnemosyneError::Other(format!("Failed to launch Claude Code: {}", e)))?;
+        let status = self
+            .spawn_claude(&args)?
+            .wait()
+            .map_err(|e| MnemosyneError::Other(format!("Failed to wait for Claude Code: {}", e)))?;
 
-        if !status.success() {
+        if !status.success {
             return Err(MnemosyneError::Other(format!(
                 "Claude Code exited with status: {:?}",
-                status.code()
+                status.code
             )));
         }
 
@@ -336,6 +522,67 @@ impl ClaudeCodeLauncher {
         generator.generate_config()
     }
 
+    /// Load the startup context, or an empty string if disabled, failed or timed out
+    async fn load_startup_context(
+        &self,
+        storage: std::sync::Arc<dyn crate::storage::StorageBackend>,
+    ) -> String {
+        if !self.config.load_context_on_start {
+            debug!("Context loading disabled by configuration");
+            return String::new();
+        }
+
+        let timeout_ms = self.config.context_config.timeout_ms;
+        match tokio::time::timeout(
+            std::time::Duration::from_millis(timeout_ms),
+            self.generate_startup_context_with_storage(storage),
+        )
+        .await
+        {
+            Ok(Ok(context)) => {
+                debug!("Loaded startup context ({} bytes)", context.len());
+                debug!(
+                    "Context preview: {}...",
+                    &context.chars().take(100).collect::<String>()
+                );
+                context
+            }
+            Ok(Err(e)) => {
+                warn!("Context loading failed: {}", e);
+                String::new()
+            }
+            Err(_) => {
+                warn!(
+                    "Context loading timed out (>{}ms); raise context.timeout_ms in config.toml to allow more time",
+                    timeout_ms
+                );
+                String::new()
+            }
+        }
+    }
+
+    /// Print what would be launched, without starting Claude Code
+    ///
+    /// Still generates the startup context and both configs, so the printed
+    /// command is exactly what a real launch would run (secrets redacted).
+    async fn dry_run(
+        &self,
+        storage: Option<std::sync::Arc<dyn crate::storage::StorageBackend>>,
+        worktree: Option<&WorktreePlan>,
+    ) -> Result<()> {
+        let startup_prompt = match storage {
+            Some(storage) => self.load_startup_context(storage).await,
+            None => String::new(),
+        };
+
+        let agent_config = self.generate_agent_config()?;
+        let mcp_config = self.generate_mcp_config()?;
+        let args = self.build_command_args(&agent_config, &mcp_config, &startup_prompt);
+
+        println!("{}", dry_run_report(&self.claude_binary, &args, worktree));
+        Ok(())
+    }
+
     /// Generate startup context prompt with storage backend
     async fn generate_startup_context_with_storage(
         &self,
@@ -385,17 +632,15 @@ impl ClaudeCodeLauncher {
         args
     }
 
-    /// Setup git worktree for branch isolation
-    ///
-    /// Returns (agent_id, worktree_path, repo_root) for cleanup, or None if not in git repo
-    fn setup_worktree_isolation(
-        &self,
-    ) -> Result<Option<(crate::orchestration::AgentId, PathBuf, PathBuf)>> {
+    /// Decide whether the session gets its own git worktree, without
+    /// creating anything
+    fn plan_worktree(&self, repo_root: &Path) -> Result<Option<WorktreePlan>> {
         use crate::orchestration::{identity::AgentId, WorktreeManager};
 
         // Check if we're in a git repository
         if !Command::new("git")
             .args(["rev-parse", "--git-dir"])
+            .current_dir(repo_root)
             .output()
             .map(|o| o.status.success())
             .unwrap_or(false)
@@ -404,23 +649,12 @@ impl ClaudeCodeLauncher {
             return Ok(None);
         }
 
-        // Get current directory as repo root
-        let repo_root = std::env::current_dir().map_err(|e| {
-            MnemosyneError::Io(std::io::Error::new(
-                e.kind(),
-                format!("Failed to get current directory: {}", e),
-            ))
-        })?;
-
-        // Initialize worktree manager
-        let manager = WorktreeManager::new(repo_root.clone())?;
-
-        // Generate unique agent ID for this session
-        let agent_id = AgentId::new();
+        let repo_root = repo_root.to_path_buf();
 
         // Get current branch
-        let current_branch = Command::new("git")
+        let branch = Command::new("git")
             .args(["rev-parse", "--abbrev-ref", "HEAD"])
+            .current_dir(&repo_root)
             .output()
             .ok()
             .and_then(|o| {
@@ -434,11 +668,6 @@ impl ClaudeCodeLauncher {
             })
             .unwrap_or_else(|| "main".to_string());
 
-        debug!(
-            "Creating worktree for session {} on branch {}",
-            agent_id, current_branch
-        );
-
         // Check if we're in the main worktree on the target branch
         // If .git is a directory (not a file), we're in the main worktree
         let git_dir = repo_root.join(".git");
@@ -447,25 +676,49 @@ impl ClaudeCodeLauncher {
         if is_main_worktree {
             debug!(
                 "Already in main worktree on branch '{}', skipping worktree isolation",
-                current_branch
+                branch
             );
             return Ok(None);
         }
 
+        let manager = WorktreeManager::new(repo_root.clone())?;
+        // Generate unique agent ID for this session
+        let agent_id = AgentId::new();
+        let path = manager.get_worktree_path(&agent_id);
+
+        Ok(Some(WorktreePlan {
+            manager,
+            agent_id,
+            branch,
+            path,
+            repo_root,
+        }))
+    }
+
+    /// Setup git worktree for branch isolation
+    fn setup_worktree_isolation(
+        &self,
+        plan: WorktreePlan,
+    ) -> Option<(crate::orchestration::AgentId, PathBuf, PathBuf)> {
+        debug!(
+            "Creating worktree for session {} on branch {}",
+            plan.agent_id, plan.branch
+        );
+
         // Create worktree
-        match manager.create_worktree(&agent_id, &current_branch) {
+        match plan.manager.create_worktree(&plan.agent_id, &plan.branch) {
             Ok(worktree_path) => {
                 debug!("Created worktree at: {}", worktree_path.display());
 
                 // Register worktree with process coordinator for tracking
-                self.register_worktree(&agent_id, &worktree_path, &repo_root);
+                self.register_worktree(&plan.agent_id, &worktree_path, &plan.repo_root);
 
-                Ok(Some((agent_id, worktree_path, repo_root)))
+                Some((plan.agent_id, worktree_path, plan.repo_root))
             }
             Err(e) => {
                 warn!("Failed to create worktree: {}", e);
                 warn!("Continuing without worktree isolation");
-                Ok(None)
+                None
             }
         }
     }
@@ -499,9 +752,21 @@ impl ClaudeCodeLauncher {
     }
 
     /// Cleanup worktree for this session
-    fn cleanup_worktree(&self, agent_id: &crate::orchestration::AgentId, repo_root: &Path) {
+    ///
+    /// Changes back to `original_cwd` first, so the process doesn't end up in
+    /// (or inside the remains of) the removed worktree.
+    fn cleanup_worktree(
+        &self,
+        agent_id: &crate::orchestration::AgentId,
+        repo_root: &Path,
+        original_cwd: Option<&Path>,
+    ) {
         use crate::orchestration::WorktreeManager;
 
+        if let Some(cwd) = original_cwd {
+            restore_working_dir(cwd);
+        }
+
         debug!("Cleaning up worktree for session {}", agent_id);
 
         match WorktreeManager::new(repo_root.to_path_buf()) {
@@ -520,21 +785,41 @@ impl ClaudeCodeLauncher {
     }
 }
 
+/// Change back to the directory a session was launched from
+fn restore_working_dir(cwd: &Path) {
+    if let Err(e) = std::env::set_current_dir(cwd) {
+        warn!(
+            "Failed to restore working directory {}: {}",
+            cwd.display(),
+            e
+        );
+    }
+}
+
 /// Detect Claude Code binary location
 pub fn detect_claude_binary() -> Result<PathBuf> {
-    // Try common locations
-    let paths = vec![
-        "claude",                                // In PATH
-        "/usr/local/bin/claude",                 // Common install location
-        "/opt/homebrew/bin/claude",              // Homebrew on Apple Silicon
-        "/home/linuxbrew/.linuxbrew/bin/claude", // Homebrew on Linux
-    ];
-
-    for path in paths {
+    detect_claude_binary_with(None)
+}
+
+/// Detect Claude Code binary location, trying `configured` first
+///
+/// Search order: `configured` (the `claude_binary_path` setting), the
+/// `CLAUDE_BINARY` environment variable, then common install locations
+/// (PATH, Homebrew, `~/.local/bin`, npm global and nvm directories), and
+/// finally `which claude`. A candidate counts if `<candidate> --version`
+/// succeeds. The error lists every location tried.
+pub fn detect_claude_binary_with(configured: Option<&Path>) -> Result<PathBuf> {
+    let candidates = claude_binary_candidates(
+        configured,
+        std::env::var_os(CLAUDE_BINARY_ENV).map(PathBuf::from),
+        dirs::home_dir().as_deref(),
+    );
+
+    for path in &candidates {
         if let Ok(output) = Command::new(path).arg("--version").output() {
             if output.status.success() {
-                debug!("Found Claude Code at: {}", path);
-                return Ok(PathBuf::from(path));
+                debug!("Found Claude Code at: {}", path.display());
+                return Ok(path.clone());
             }
         }
     }
@@ -552,10 +837,63 @@ pub fn detect_claude_binary() -> Result<PathBuf> {
         }
     }
 
-    Err(MnemosyneError::Other(
-        "Claude Code binary not found. Please ensure Claude Code is installed and in your PATH."
-            .to_string(),
-    ))
+    Err(claude_not_found_error(&candidates))
+}
+
+/// Locations to probe for the Claude Code binary, in order
+fn claude_binary_candidates(
+    configured: Option<&Path>,
+    env_override: Option<PathBuf>,
+    home: Option<&Path>,
+) -> Vec<PathBuf> {
+    let mut candidates: Vec<PathBuf> = configured.map(Path::to_path_buf).into_iter().collect();
+    candidates.extend(env_override);
+
+    candidates.extend(
+        [
+            "claude",                                // In PATH
+            "/usr/local/bin/claude",                 // Common install location
+            "/opt/homebrew/bin/claude",              // Homebrew on Apple Silicon
+            "/home/linuxbrew/.linuxbrew/bin/claude", // Homebrew on Linux
+        ]
+        .map(PathBuf::from),
+    );
+
+    if let Some(home) = home {
+        candidates.push(home.join(".local/bin/claude")); // pipx-style user installs
+        candidates.push(home.join(".claude/local/claude")); // Claude Code local installer
+        candidates.push(home.join(".npm-global/bin/claude")); // npm with a user prefix
+    }
+
+    // nvm: the active version first, then every installed one, newest first
+    if let Some(nvm_bin) = std::env::var_os("NVM_BIN") {
+        candidates.push(PathBuf::from(nvm_bin).join("claude"));
+    }
+    if let Some(home) = home {
+        if let Ok(entries) = std::fs::read_dir(home.join(".nvm/versions/node")) {
+            let mut versions: Vec<PathBuf> = entries.flatten().map(|e| e.path()).collect();
+            versions.sort();
+            candidates.extend(versions.into_iter().rev().map(|v| v.join("bin/claude")));
+        }
+    }
+
+    let mut seen = std::collections::HashSet::new();
+    candidates.retain(|path| seen.insert(path.clone()));
+    candidates
+}
+
+/// "Not found" error listing every location that was tried
+fn claude_not_found_error(tried: &[PathBuf]) -> MnemosyneError {
+    let mut message = String::from("Claude Code binary not found. Tried:\n");
+    for path in tried {
+        message.push_str(&format!("  - {}\n", path.display()));
+    }
+    message.push_str("  - `which claude`\n");
+    message.push_str(&format!(
+        "Install Claude Code, or point {} or the `launcher.claude_binary_path` setting at it.",
+        CLAUDE_BINARY_ENV
+    ));
+    MnemosyneError::Other(message)
 }
 
 /// Get mnemosyne binary path
@@ -616,18 +954,65 @@ fn get_default_db_path() -> String {
         .to_string()
 }
 
+/// Human-readable launch command for `--dry-run`, with secrets redacted
+fn dry_run_report(
+    claude_binary: &Path,
+    args: &[String],
+    worktree: Option<&WorktreePlan>,
+) -> String {
+    let mut report = String::from("Dry run: Claude Code would be launched as:\n");
+    report.push_str(&format!("  {}\n", claude_binary.display()));
+    for arg in args {
+        report.push_str(&format!("    {}\n", redact_secrets(arg)));
+    }
+
+    match worktree {
+        Some(plan) => report.push_str(&format!(
+            "Working directory (worktree, created at launch): {} on branch {}\n",
+            plan.path.display(),
+            plan.branch
+        )),
+        None => report.push_str("Working directory: current (no worktree isolation)\n"),
+    }
+    report
+}
+
+/// Mask API keys, tokens and other secrets with the built-in LLM redaction
+/// rules, whatever `[llm.redaction]` says
+fn redact_secrets(text: &str) -> String {
+    crate::services::Redactor::default()
+        .redact(text)
+        .into_owned()
+}
+
+/// Launcher-related settings from the config file
+///
+/// Falls back to the defaults (with a warning) if the config can't be loaded.
+fn load_settings() -> crate::config::MnemosyneSettings {
+    crate::config::ConfigManager::load_section("launcher settings", |s| s)
+}
+
 /// Launch an orchestrated Claude Code session (convenience function)
 pub async fn launch_orchestrated_session(
     db_path: Option<String>,
     initial_prompt: Option<String>,
     event_broadcaster: Option<crate::api::EventBroadcaster>,
     state_manager: Option<std::sync::Arc<crate::api::StateManager>>,
+    dry_run: bool,
+    transcript_path: Option<PathBuf>,
+    work_items: Vec<crate::orchestration::WorkItem>,
 ) -> Result<()> {
+    let settings = load_settings();
     let config = LauncherConfig {
         mnemosyne_db_path: db_path,
         initial_prompt,
+        work_items,
         event_broadcaster,
         state_manager,
+        claude_binary_path: settings.launcher.claude_binary_path,
+        context_config: settings.context,
+        dry_run,
+        transcript_path: transcript_path.or(settings.launcher.transcript_path),
         ..Default::default()
     };
 
@@ -635,6 +1020,81 @@ pub async fn launch_orchestrated_session(
     launcher.launch().await
 }
 
+/// Submit `items` to the orchestrator's work queue in order, returning how
+/// many were queued
+///
+/// An item the orchestrator doesn't accept is logged and skipped.
+async fn queue_work_items(
+    engine: &crate::orchestration::OrchestrationEngine,
+    items: &[crate::orchestration::WorkItem],
+) -> usize {
+    let mut queued = 0;
+    for item in items {
+        match engine.submit_work(item.clone()).await {
+            Ok(_) => queued += 1,
+            Err(e) => warn!("Could not queue work item '{}': {}", item.description, e),
+        }
+    }
+    queued
+}
+
+/// Warn that planned work items are dropped because orchestration is unavailable
+fn warn_unqueued(items: &[crate::orchestration::WorkItem]) {
+    if !items.is_empty() {
+        warn!(
+            "Orchestration is unavailable; {} planned work items were not queued",
+            items.len()
+        );
+    }
+}
+
+/// Claude Code process started by the launcher
+enum ClaudeProcess {
+    /// Sharing the launcher's terminal
+    Direct(std::process::Child),
+    /// Behind a PTY that records the session transcript
+    Attached(crate::pty::AttachedSession),
+}
+
+impl ClaudeProcess {
+    fn id(&self) -> Option<u32> {
+        match self {
+            Self::Direct(child) => Some(child.id()),
+            Self::Attached(session) => session.process_id(),
+        }
+    }
+
+    /// Block until Claude Code exits
+    fn wait(self) -> std::io::Result<ClaudeExit> {
+        match self {
+            Self::Direct(mut child) => child.wait().map(|status| ClaudeExit {
+                success: status.success(),
+                code: status.code(),
+            }),
+            Self::Attached(session) => session.wait().map(|status| ClaudeExit {
+                success: status.success(),
+                code: i32::try_from(status.exit_code()).ok(),
+            }),
+        }
+    }
+}
+
+/// How Claude Code exited
+struct ClaudeExit {
+    success: bool,
+    /// Exit code, if it exited normally
+    code: Option<i32>,
+}
+
+/// Flatten the outcome of waiting for Claude Code on a blocking task
+fn exit_status(
+    result: std::result::Result<std::io::Result<ClaudeExit>, tokio::task::JoinError>,
+) -> Result<ClaudeExit> {
+    result
+        .map_err(|e| MnemosyneError::Other(format!("Wait task failed: {}", e)))?
+        .map_err(|e| MnemosyneError::Other(format!("Failed to wait for Claude Code: {}", e)))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -654,6 +1114,250 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_claude_binary_candidates_order() {
+        let home = Path::new("/home/dev");
+        let candidates = claude_binary_candidates(
+            Some(Path::new("/opt/tools/claude")),
+            Some(PathBuf::from("/custom/claude")),
+            Some(home),
+        );
+
+        assert_eq!(candidates[0], PathBuf::from("/opt/tools/claude"));
+        assert_eq!(candidates[1], PathBuf::from("/custom/claude"));
+        assert_eq!(candidates[2], PathBuf::from("claude"));
+        assert!(candidates.contains(&home.join(".local/bin/claude")));
+        assert!(candidates.contains(&home.join(".npm-global/bin/claude")));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    #[serial_test::serial]
+    fn test_claude_binary_env_override() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp = tempfile::TempDir::new().unwrap();
+        let binary = temp.path().join("my-claude");
+        std::fs::write(&binary, "#!/bin/sh\necho 'claude 1.0.0'\n").unwrap();
+        std::fs::set_permissions(&binary, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        let original = std::env::var_os(CLAUDE_BINARY_ENV);
+        std::env::set_var(CLAUDE_BINARY_ENV, &binary);
+        let detected = detect_claude_binary();
+        match original {
+            Some(value) => std::env::set_var(CLAUDE_BINARY_ENV, value),
+            None => std::env::remove_var(CLAUDE_BINARY_ENV),
+        }
+
+        assert_eq!(detected.unwrap(), binary);
+    }
+
+    #[test]
+    fn test_claude_not_found_lists_tried_paths() {
+        let tried = claude_binary_candidates(None, None, Some(Path::new("/home/dev")));
+        let message = claude_not_found_error(&tried).to_string();
+
+        for path in &tried {
+            assert!(
+                message.contains(&path.display().to_string()),
+                "{} missing from: {}",
+                path.display(),
+                message
+            );
+        }
+        assert!(message.contains("/home/dev/.local/bin/claude"));
+        assert!(message.contains("`which claude`"));
+        assert!(message.contains(CLAUDE_BINARY_ENV));
+    }
+
+    #[test]
+    fn test_redact_secrets() {
+        let mcp = r#"{"env":{"ANTHROPIC_API_KEY":"sk-ant-REDACTED","MNEMOSYNE_NAMESPACE":"global"}}"#;
+        let redacted = redact_secrets(mcp);
+        assert!(!redacted.contains("sk-ant-REDACTED"));
+        assert!(redacted.contains(r#""ANTHROPIC_API_KEY":"[REDACTED:api_key]""#));
+        assert!(redacted.contains(r#""MNEMOSYNE_NAMESPACE":"global""#));
+
+        assert_eq!(
+            redact_secrets("use key sk-ant-REDACTED now"),
+            "use key [REDACTED:api_key] now"
+        );
+        assert_eq!(redact_secrets("--model sonnet"), "--model sonnet");
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_does_not_spawn() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let repo = temp.path().join("repo");
+        std::fs::create_dir(&repo).unwrap();
+        let git = |dir: &Path, args: &[&str]| {
+            let status = Command::new("git")
+                .args(["-c", "user.name=Test", "-c", "user.email=test@example.com"])
+                .args(args)
+                .current_dir(dir)
+                .output()
+                .unwrap()
+                .status;
+            assert!(status.success(), "git {:?} failed", args);
+        };
+        git(&repo, &["init", "--quiet"]);
+        git(
+            &repo,
+            &["commit", "--quiet", "--allow-empty", "-m", "Initial commit"],
+        );
+        // Launching from a linked worktree is what plans a session worktree
+        git(
+            &repo,
+            &["worktree", "add", "--quiet", "-b", "feature", "../linked"],
+        );
+        let linked = temp.path().join("linked");
+
+        let config = LauncherConfig {
+            mnemosyne_db_path: Some(temp.path().join("dry-run.db").display().to_string()),
+            mnemosyne_namespace: Some("global".to_string()),
+            initial_prompt: Some("Fix the login flow".to_string()),
+            dry_run: true,
+            working_dir: Some(linked.clone()),
+            ..Default::default()
+        };
+        // A binary that can't exist: spawning it would fail the launch
+        let launcher = ClaudeCodeLauncher {
+            config,
+            claude_binary: temp.path().join("missing-claude"),
+        };
+
+        let plan = launcher.plan_worktree(&linked).unwrap().unwrap();
+        assert_eq!(plan.branch, "feature");
+
+        launcher.launch().await.unwrap();
+
+        // The dry run added no session worktree
+        assert!(!linked.join(".mnemosyne").join("worktrees").exists());
+        let worktrees = Command::new("git")
+            .args(["worktree", "list", "--porcelain"])
+            .current_dir(&repo)
+            .output()
+            .unwrap();
+        let listed = String::from_utf8_lossy(&worktrees.stdout);
+        assert_eq!(listed.matches("worktree ").count(), 2);
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_cleanup_restores_working_directory() {
+        let original = std::env::current_dir().unwrap();
+        let worktree = tempfile::TempDir::new().unwrap();
+        let launcher = ClaudeCodeLauncher {
+            config: LauncherConfig::default(),
+            claude_binary: PathBuf::from("claude"),
+        };
+
+        // As after setup_worktree_isolation: the process sits in the worktree
+        std::env::set_current_dir(worktree.path()).unwrap();
+        launcher.cleanup_worktree(
+            &crate::orchestration::AgentId::new(),
+            worktree.path(),
+            Some(&original),
+        );
+
+        assert_eq!(std::env::current_dir().unwrap(), original);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_pty_session_broadcasts_agent_events() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let broadcaster = crate::api::EventBroadcaster::new(16);
+        let mut events = broadcaster.subscribe();
+        let launcher = ClaudeCodeLauncher {
+            config: LauncherConfig {
+                transcript_path: Some(temp.path().join("session.transcript")),
+                event_broadcaster: Some(broadcaster),
+                ..Default::default()
+            },
+            // Stands in for Claude Code, printing its first argument
+            claude_binary: PathBuf::from("printf"),
+        };
+
+        let session = launcher
+            .spawn_claude(&["Executor: implementing feature\n".to_string()])
+            .unwrap();
+        assert!(matches!(session, ClaudeProcess::Attached(_)));
+        assert!(session.wait().unwrap().success);
+
+        let mut received = Vec::new();
+        while let Ok(event) = events.try_recv() {
+            received.push(event.event_type);
+        }
+        assert!(matches!(
+            received.first(),
+            Some(crate::api::EventType::AgentStarted { agent_id, .. }) if agent_id == "executor"
+        ));
+        assert!(matches!(
+            received.last(),
+            Some(crate::api::EventType::AgentCompleted { agent_id, .. }) if agent_id == "executor"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_queue_work_items_in_order() {
+        use crate::orchestration::{OrchestrationEngine, Phase, SupervisionConfig, WorkItem};
+
+        let storage: std::sync::Arc<dyn crate::storage::StorageBackend> = std::sync::Arc::new(
+            crate::storage::libsql::LibsqlStorage::new(
+                crate::storage::libsql::ConnectionMode::InMemory,
+            )
+            .await
+            .unwrap(),
+        );
+        let mut engine = OrchestrationEngine::new(storage, SupervisionConfig::default())
+            .await
+            .unwrap();
+        engine.start().await.unwrap();
+
+        // Reviewer items are not dispatched, so they stay queued
+        let schema = WorkItem::new(
+            "Review schema".to_string(),
+            agents::AgentRole::Reviewer,
+            Phase::PlanToArtifacts,
+            5,
+        );
+        let mut api = WorkItem::new(
+            "Review API".to_string(),
+            agents::AgentRole::Reviewer,
+            Phase::PlanToArtifacts,
+            5,
+        );
+        api.add_dependency(schema.id.clone());
+        let items = vec![schema, api];
+
+        assert_eq!(queue_work_items(&engine, &items).await, 2);
+
+        let queue = engine.work_queue().read().await;
+        for item in &items {
+            let queued = queue.get(&item.id).expect("work item queued");
+            assert_eq!(queued.dependencies, item.dependencies);
+        }
+        drop(queue);
+        engine.stop().await.unwrap();
+    }
+
+    #[test]
+    fn test_dry_run_report_lists_args() {
+        let args = vec![
+            "--model".to_string(),
+            "sonnet".to_string(),
+            "--append-system-prompt".to_string(),
+            "token=abc123".to_string(),
+        ];
+        let report = dry_run_report(Path::new("/usr/local/bin/claude"), &args, None);
+
+        assert!(report.contains("/usr/local/bin/claude"));
+        assert!(report.contains("    --model\n    sonnet\n"));
+        assert!(report.contains("token=[REDACTED]"));
+        assert!(report.contains("no worktree isolation"));
+    }
+
     #[test]
     fn test_detect_namespace() {
         let namespace = detect_namespace();
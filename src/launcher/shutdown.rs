@@ -0,0 +1,116 @@
+//! Termination signal handling for orchestrated sessions
+//!
+//! Without a handler, SIGTERM kills the launcher before the orchestration
+//! engine stops, dropping queued work and its events. The launcher installs
+//! a [`ShutdownSignal`] before starting Claude Code; when it fires, Claude
+//! Code is asked to exit and the engine gets [`SHUTDOWN_GRACE_PERIOD`] to
+//! drain before the process exits regardless.
+//!
+//! On Windows the console close and system shutdown events play the role of
+//! SIGTERM. SIGINT (Ctrl+C) is deliberately left alone on every platform:
+//! it belongs to the interactive Claude Code session in the same terminal.
+
+use crate::error::{MnemosyneError, Result};
+use std::time::Duration;
+
+/// How long the orchestration engine may take to drain after SIGTERM
+pub const SHUTDOWN_GRACE_PERIOD: Duration = Duration::from_secs(10);
+
+/// Exit code used when the grace period runs out (128 + SIGTERM)
+pub const FORCED_EXIT_CODE: i32 = 143;
+
+/// How an orchestrated session ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SessionEnd {
+    /// Claude Code exited and the engine stopped
+    Completed,
+    /// The engine outlived the grace period; once the session is cleaned
+    /// up the process exits with [`FORCED_EXIT_CODE`]
+    Forced,
+}
+
+/// Listener for the process termination signal
+///
+/// The handler is registered by [`install`](Self::install), so a signal
+/// delivered between install and [`recv`](Self::recv) is not lost.
+pub struct ShutdownSignal {
+    #[cfg(unix)]
+    sigterm: tokio::signal::unix::Signal,
+    #[cfg(windows)]
+    close: tokio::signal::windows::CtrlClose,
+    #[cfg(windows)]
+    shutdown: tokio::signal::windows::CtrlShutdown,
+}
+
+impl ShutdownSignal {
+    /// Register the termination handler
+    pub fn install() -> Result<Self> {
+        #[cfg(unix)]
+        {
+            use tokio::signal::unix::{signal, SignalKind};
+
+            let sigterm = signal(SignalKind::terminate()).map_err(|e| {
+                MnemosyneError::Other(format!("Failed to listen for SIGTERM: {}", e))
+            })?;
+            Ok(Self { sigterm })
+        }
+
+        #[cfg(windows)]
+        {
+            use tokio::signal::windows::{ctrl_close, ctrl_shutdown};
+
+            let close = ctrl_close().map_err(|e| {
+                MnemosyneError::Other(format!("Failed to listen for console close: {}", e))
+            })?;
+            let shutdown = ctrl_shutdown().map_err(|e| {
+                MnemosyneError::Other(format!("Failed to listen for system shutdown: {}", e))
+            })?;
+            Ok(Self { close, shutdown })
+        }
+
+        #[cfg(not(any(unix, windows)))]
+        {
+            Ok(Self {})
+        }
+    }
+
+    /// Wait until the process is asked to terminate
+    pub async fn recv(&mut self) {
+        #[cfg(unix)]
+        {
+            self.sigterm.recv().await;
+        }
+
+        #[cfg(windows)]
+        {
+            tokio::select! {
+                _ = self.close.recv() => {}
+                _ = self.shutdown.recv() => {}
+            }
+        }
+
+        #[cfg(not(any(unix, windows)))]
+        {
+            std::future::pending::<()>().await;
+        }
+    }
+}
+
+/// Ask a child process to exit (best effort)
+pub fn terminate_child(pid: u32) {
+    #[cfg(unix)]
+    {
+        use nix::sys::signal::{kill, Signal};
+        use nix::unistd::Pid;
+
+        match kill(Pid::from_raw(pid as i32), Signal::SIGTERM) {
+            Ok(()) | Err(nix::Error::ESRCH) => {}
+            Err(e) => tracing::warn!("Failed to forward SIGTERM to PID {}: {}", pid, e),
+        }
+    }
+
+    #[cfg(not(unix))]
+    {
+        let _ = pid;
+    }
+}
@@ -311,10 +311,14 @@ pub fn show_loading_step(step_name: &str) {
 /// Check for updates and show notification if available
 /// This is non-blocking and will timeout after 3 seconds
 pub async fn check_and_show_updates() {
-    use crate::version_check::VersionChecker;
+    use crate::version_check::{update_checks_disabled, VersionChecker};
 
-    // Create version checker
-    let checker = match VersionChecker::new() {
+    if update_checks_disabled() {
+        return;
+    }
+
+    // Create version checker (cached results answer without the network)
+    let checker = match VersionChecker::from_config() {
         Ok(c) => c,
         Err(_) => return, // Silently fail if we can't create checker
     };
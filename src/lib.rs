@@ -17,13 +17,15 @@
 //!
 //! # Example
 //!
-//! ```ignore
-//! use mnemosyne::{MemoryManager, Namespace, SearchQuery};
+//! ```
+//! use mnemosyne_core::{ConnectionMode, LibsqlStorage, MemoryManager, Namespace, SearchQuery};
+//! use std::sync::Arc;
 //!
 //! #[tokio::main]
-//! async fn main() -> anyhow::Result<()> {
-//!     let config = MnemosyneConfig::from_file("config.toml")?;
-//!     let manager = MemoryManager::new(config).await?;
+//! async fn main() -> mnemosyne_core::Result<()> {
+//!     let storage = LibsqlStorage::new_with_validation(ConnectionMode::InMemory, true).await?;
+//!     // Add `.with_llm(...)` and `.with_embeddings(...)` for enrichment and vector search
+//!     let manager = MemoryManager::new(Arc::new(storage));
 //!
 //!     // Store a memory
 //!     let id = manager.remember(
@@ -34,9 +36,10 @@
 //!
 //!     // Recall memories
 //!     let results = manager.recall(SearchQuery {
-//!         query: "database decisions".to_string(),
+//!         query: "PostgreSQL".to_string(),
 //!         ..Default::default()
 //!     }).await?;
+//!     assert_eq!(results[0].memory.id, id);
 //!
 //!     Ok(())
 //! }
@@ -57,6 +60,7 @@ pub mod health; // Health check system
 pub mod icons; // Nerd Font icons with ASCII fallbacks
 pub mod ics; // Integrated Context Studio
 pub mod launcher;
+pub mod manager; // High-level remember/recall API
 pub mod mcp;
 pub mod namespace;
 pub mod orchestration;
@@ -80,13 +84,18 @@ pub mod rpc;
 
 // Re-export commonly used types
 pub use agents::{AgentMemoryView, AgentRole, CustomImportanceScorer, MemoryAccessControl};
-pub use config::{ConfigManager, EmbeddingConfig, SearchConfig};
+pub use config::{
+    ConfigManager, ConfigSource, EffectiveConfig, EmbeddingConfig, FtsTokenizer, LayeredConfig,
+    MemoryTypesConfig, NamespaceBoundary, SearchConfig, StaleEmbeddingPolicy,
+};
 pub use diagnostics::{
-    global_memory_tracker, start_memory_monitoring, MemorySnapshot, MemoryStatus,
+    global_degradation, global_latency, global_memory_tracker, start_memory_monitoring,
+    time_operation, Degradation, DegradationState, DegradedService, LatencySummary, LeakThresholds,
+    MemorySnapshot, MemoryStatus,
 };
 pub use embeddings::{
-    cosine_similarity, EmbeddingService, LocalEmbeddingService, RemoteEmbeddingService,
-    VOYAGE_EMBEDDING_DIM,
+    backfill_embeddings, cosine_similarity, BackfillOptions, BackfillProgress, EmbeddingService,
+    LocalEmbeddingService, RemoteEmbeddingService, VOYAGE_EMBEDDING_DIM,
 };
 pub use error::{MnemosyneError, Result};
 pub use evaluation::{
@@ -97,17 +106,24 @@ pub use evolution::{
     ArchivalJob, BackgroundScheduler, ConsolidationJob, EvolutionConfig, EvolutionJob,
     ImportanceRecalibrator, JobConfig, JobReport, LinkDecayJob,
 };
-pub use mcp::{EventSink, McpServer, ToolHandler};
+pub use manager::MemoryManager;
+pub use mcp::{EventSink, McpServer, McpTransport, ToolHandler};
 pub use namespace::{NamespaceDetector, ProjectMetadata};
 pub use orchestration::{AgentEvent, OrchestrationEngine, SupervisionConfig, WorkItem, WorkQueue};
-pub use services::{LlmConfig, LlmService};
+pub use services::{
+    LlmConfig, LlmService, LlmSettings, LlmTask, RedactionSettings, Redactor, TokenBudget,
+    TokenUsage,
+};
 pub use storage::{
-    libsql::{ConnectionMode, LibsqlStorage},
+    libsql::{ConnectionMode, EmbeddingModelStatus, LibsqlStorage, MigrationStatus},
     StorageBackend,
 };
 pub use types::{
     ConsolidationDecision, LinkType, MemoryId, MemoryLink, MemoryNote, MemoryType, MemoryUpdates,
-    Namespace, SearchQuery, SearchResult,
+    Namespace, ScoreBreakdown, SearchQuery, SearchResult, TieBreak,
 };
 pub use update::{prompt_for_install, prompt_for_update, UpdateManager, UpdateResult};
-pub use version_check::{Tool, VersionCheckCache, VersionChecker, VersionInfo};
+pub use version_check::{
+    update_checks_disabled, ReleaseArtifact, Tool, UpdateChannel, UpdateCheckSettings,
+    VersionCheckCache, VersionChecker, VersionInfo,
+};
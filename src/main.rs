@@ -6,7 +6,7 @@
 mod cli;
 
 use clap::{Parser, Subcommand};
-use mnemosyne_core::{error::Result, launcher};
+use mnemosyne_core::{error::Result, launcher, mcp::McpTransport};
 use std::path::PathBuf;
 use tracing::{debug, error, info, warn, Level};
 use tracing_subscriber::{self, EnvFilter};
@@ -34,15 +34,36 @@ struct Cli {
     /// Database path (overrides MNEMOSYNE_DB_PATH env var and default)
     #[arg(long)]
     db_path: Option<String>,
+
+    /// Print the Claude Code launch command (secrets redacted) instead of running it
+    #[arg(long)]
+    dry_run: bool,
+
+    /// Record the session's raw terminal output to this file
+    /// (overrides `[launcher] transcript_path`)
+    #[arg(long, value_name = "PATH")]
+    transcript: Option<std::path::PathBuf>,
 }
 
 #[derive(Subcommand)]
 enum Commands {
-    /// Start MCP server (stdio mode with automatic API server)
+    /// Start MCP server (stdio by default, with automatic API server)
     ///
     /// Automatically starts API server on port 3000 (owner mode) or connects
     /// to existing API server (client mode) for dashboard observability.
-    Serve,
+    Serve {
+        /// How clients connect: stdio (one local client) or http (HTTP+SSE)
+        #[arg(long, default_value = "stdio", value_parser = ["stdio", "http"])]
+        transport: String,
+
+        /// Address the http transport listens on
+        #[arg(long, default_value = "127.0.0.1:3100")]
+        http_addr: String,
+
+        /// Bearer token http clients must send (required off loopback)
+        #[arg(long, env = "MNEMOSYNE_MCP_TOKEN", hide_env_values = true)]
+        http_token: Option<String>,
+    },
 
     /// Start HTTP API server for event streaming and state coordination
     ApiServer {
@@ -55,14 +76,18 @@ enum Commands {
         capacity: usize,
     },
 
-    /// Initialize database
+    /// Initialize database, or apply pending migrations if it exists
     Init {
         /// Database path
         #[arg(short, long)]
         database: Option<String>,
+
+        /// Only report the schema version and pending migrations
+        #[arg(long)]
+        check: bool,
     },
 
-    /// Export memories to Markdown
+    /// Export memories to JSON, JSONL, or Markdown
     Export {
         /// Output path (prints to stdout if not specified)
         #[arg(short, long)]
@@ -71,10 +96,53 @@ enum Commands {
         /// Namespace filter
         #[arg(short, long)]
         namespace: Option<String>,
+
+        /// Output format (json|jsonl|markdown); inferred from the output extension if omitted
+        #[arg(short, long)]
+        format: Option<String>,
+
+        /// Only export memories of this type
+        #[arg(short = 'y', long = "type")]
+        memory_type: Option<String>,
+
+        /// Only export memories with this tag (repeatable; all must match)
+        #[arg(short, long = "tag")]
+        tags: Vec<String>,
+
+        /// Minimum importance (1-10)
+        #[arg(long)]
+        min_importance: Option<u8>,
+
+        /// Only export memories created on or after this date (YYYY-MM-DD or RFC 3339)
+        #[arg(long)]
+        since: Option<String>,
+    },
+
+    /// Import memories from a JSON or JSONL export, preserving IDs
+    Import {
+        /// Input path (reads stdin if not specified)
+        #[arg(short, long)]
+        input: Option<String>,
     },
 
     /// Show system status
-    Status,
+    Status {
+        /// API server to ask for degraded services (default: the one
+        /// running on ports 3000-3010)
+        #[arg(long)]
+        api: Option<String>,
+    },
+
+    /// Summarize the memory store (counts, types, tags, embeddings, links)
+    Stats {
+        /// Namespace filter
+        #[arg(short, long)]
+        namespace: Option<String>,
+
+        /// Print JSON for scripting
+        #[arg(long)]
+        json: bool,
+    },
 
     /// Launch Integrated Context Studio (ICS) - Full-featured context editor
     ///
@@ -97,6 +165,10 @@ enum Commands {
         #[arg(long)]
         panel: Option<cli::edit::IcsPanel>,
 
+        /// Save unresolved holes as task memories on close and reattach them on reopen
+        #[arg(long)]
+        persist_holes: bool,
+
         /// Session context file for handoff coordination (hidden, for integration)
         #[arg(long, hide = true)]
         session_context: Option<PathBuf>,
@@ -127,7 +199,7 @@ enum Commands {
 
     /// Launch multi-agent orchestration system
     Orchestrate {
-        /// Work plan file (JSON) or prompt string
+        /// Work plan: prompt, JSON, JSON file, or task breakdown artifact (.md)
         #[arg(short, long)]
         plan: String,
 
@@ -146,21 +218,29 @@ enum Commands {
         /// Max concurrent agents (default: 4)
         #[arg(long, default_value = "4")]
         max_concurrent: u8,
+
+        /// Print the Claude Code launch command instead of running it
+        #[arg(long)]
+        dry_run: bool,
     },
 
     /// Remember new information (store a memory)
     Remember {
-        /// Content to remember
-        #[arg(short, long)]
-        content: String,
+        /// Content to remember ("-" reads it from stdin)
+        #[arg(short, long, required_unless_present = "file", conflicts_with = "file")]
+        content: Option<String>,
+
+        /// Read the content to remember from a file
+        #[arg(long)]
+        file: Option<PathBuf>,
 
         /// Namespace (e.g., "project:myapp" or "global")
         #[arg(short, long, default_value = "global")]
         namespace: String,
 
-        /// Importance (1-10)
-        #[arg(short, long, default_value = "5")]
-        importance: u8,
+        /// Importance (1-10); defaults by memory type (`[memory_types.default_importance]`)
+        #[arg(short, long)]
+        importance: Option<u8>,
 
         /// Additional context
         #[arg(long)]
@@ -170,7 +250,8 @@ enum Commands {
         #[arg(short, long)]
         tags: Option<String>,
 
-        /// Memory type (architecture|code_pattern|bug_fix|configuration|constraint|entity|insight|reference|preference|task|decision)
+        /// Memory type (architecture|code_pattern|bug_fix|configuration|constraint|entity|insight|reference|preference|task|decision),
+        /// or a custom type declared under `[memory_types.custom]` in config
         #[arg(short = 'y', long, alias = "type")]
         memory_type: Option<String>,
 
@@ -181,9 +262,9 @@ enum Commands {
 
     /// Recall memories (search and retrieve)
     Recall {
-        /// Search query
-        #[arg(short, long)]
-        query: String,
+        /// Search query (optional with --interactive, where it seeds the search box)
+        #[arg(short, long, required_unless_present = "interactive")]
+        query: Option<String>,
 
         /// Namespace filter
         #[arg(short, long)]
@@ -197,14 +278,44 @@ enum Commands {
         #[arg(long)]
         min_importance: Option<u8>,
 
-        /// Output format (text/json)
+        /// Only memories of this type (repeatable; any may match)
+        #[arg(short = 'y', long = "type")]
+        memory_types: Vec<String>,
+
+        /// Only memories with at least one of these tags (repeatable)
+        #[arg(long = "tag-any")]
+        tags_any: Vec<String>,
+
+        /// Only memories with all of these tags (repeatable)
+        #[arg(long = "tag-all")]
+        tags_all: Vec<String>,
+
+        /// Output format (text/json/context)
         #[arg(short, long, default_value = "text")]
         format: String,
+
+        /// Browse results live as you type (plain output when not a terminal)
+        #[arg(short, long)]
+        interactive: bool,
+
+        /// Show the keyword/vector/graph signals behind each score
+        #[arg(long)]
+        explain: bool,
+    },
+
+    /// Permanently delete a memory (cannot be undone)
+    Forget {
+        /// Memory ID
+        id: String,
+
+        /// Delete the memory; without this it is only shown
+        #[arg(long)]
+        confirm: bool,
     },
 
     /// Generate embeddings for memories
     Embed {
-        /// Embed all memories (regenerate all embeddings)
+        /// Embed all memories that lack an embedding from the current model
         #[arg(long)]
         all: bool,
 
@@ -220,11 +331,35 @@ enum Commands {
         #[arg(long, default_value = "32")]
         batch_size: usize,
 
+        /// Batches embedded at the same time (default: 1)
+        #[arg(long, default_value = "1", value_parser = clap::value_parser!(u16).range(1..))]
+        concurrency: u16,
+
+        /// Re-embed memories that already have an embedding from the current model
+        #[arg(long)]
+        force: bool,
+
         /// Show progress bar
         #[arg(long)]
         progress: bool,
     },
 
+    /// List, rename, and merge tags
+    Tags {
+        /// Namespace to operate on (all namespaces if omitted)
+        #[arg(short, long, global = true)]
+        namespace: Option<String>,
+
+        #[command(subcommand)]
+        action: cli::tags::TagsAction,
+    },
+
+    /// Rename or merge namespaces
+    Namespace {
+        #[command(subcommand)]
+        action: cli::namespace::NamespaceAction,
+    },
+
     /// Manage embedding models
     Models {
         #[command(subcommand)]
@@ -264,6 +399,10 @@ enum Commands {
         /// Namespace filter
         #[arg(short, long)]
         namespace: Option<String>,
+
+        /// Maximum number of nodes to render
+        #[arg(long, default_value = "100")]
+        max_nodes: usize,
     },
 
     /// Run health checks on the mnemosyne system
@@ -279,6 +418,10 @@ enum Commands {
         /// Output in JSON format
         #[arg(short, long)]
         json: bool,
+
+        /// Treat advisory failures (e.g. unreachable embedding backend) as errors
+        #[arg(long)]
+        strict: bool,
     },
 
     /// Check for and install tool updates
@@ -361,24 +504,50 @@ async fn main() -> Result<()> {
     // Handle --serve flag (start MCP server without Claude Code)
     if cli.serve && cli.command.is_none() {
         debug!("Starting MCP server (--serve mode)...");
-        return start_mcp_server(cli.db_path).await;
+        return start_mcp_server(cli.db_path, McpTransport::Stdio).await;
     }
 
     match cli.command {
-        Some(Commands::Serve) => cli::serve::handle(cli.db_path).await,
+        Some(Commands::Serve {
+            transport,
+            http_addr,
+            http_token,
+        }) => cli::serve::handle(cli.db_path, transport, http_addr, http_token).await,
         Some(Commands::ApiServer { addr, capacity }) => {
             cli::api_server::handle(addr, capacity).await
         }
-        Some(Commands::Init { database }) => cli::init::handle(database, cli.db_path.clone()).await,
-        Some(Commands::Export { output, namespace }) => {
-            cli::export::handle(output, namespace, cli.db_path.clone()).await
+        Some(Commands::Init { database, check }) => {
+            cli::init::handle(database, cli.db_path.clone(), check).await
+        }
+        Some(Commands::Export {
+            output,
+            namespace,
+            format,
+            memory_type,
+            tags,
+            min_importance,
+            since,
+        }) => {
+            let filters = cli::export::ExportFilters {
+                namespace,
+                memory_type,
+                tags,
+                min_importance,
+                since,
+            };
+            cli::export::handle(output, filters, format, cli.db_path.clone()).await
+        }
+        Some(Commands::Import { input }) => cli::import::handle(input, cli.db_path.clone()).await,
+        Some(Commands::Status { api }) => cli::status::handle(api, cli.db_path.clone()).await,
+        Some(Commands::Stats { namespace, json }) => {
+            cli::stats::handle(namespace, json, cli.db_path.clone()).await
         }
-        Some(Commands::Status) => cli::status::handle(cli.db_path.clone()).await,
         Some(Commands::Edit {
             file,
             readonly,
             template,
             panel,
+            persist_holes,
             session_context,
         }) => {
             cli::edit::handle(
@@ -386,6 +555,7 @@ async fn main() -> Result<()> {
                 readonly,
                 template,
                 panel,
+                persist_holes,
                 session_context,
                 cli.db_path.clone(),
             )
@@ -403,9 +573,11 @@ async fn main() -> Result<()> {
             dashboard,
             polling_interval: _,
             max_concurrent,
-        }) => cli::orchestrate::handle(plan, database, dashboard, max_concurrent).await,
+            dry_run,
+        }) => cli::orchestrate::handle(plan, database, dashboard, max_concurrent, dry_run).await,
         Some(Commands::Remember {
             content,
+            file,
             namespace,
             importance,
             context,
@@ -415,6 +587,7 @@ async fn main() -> Result<()> {
         }) => {
             cli::remember::handle(
                 content,
+                file,
                 namespace,
                 importance,
                 context,
@@ -430,23 +603,40 @@ async fn main() -> Result<()> {
             namespace,
             limit,
             min_importance,
+            memory_types,
+            tags_any,
+            tags_all,
             format,
+            interactive,
+            explain,
         }) => {
             cli::recall::handle(
                 query,
                 namespace,
                 limit,
-                min_importance,
+                cli::recall::RecallFilters {
+                    memory_types,
+                    tags_any,
+                    tags_all,
+                    min_importance,
+                },
                 format,
+                interactive,
+                explain,
                 cli.db_path.clone(),
             )
             .await
         }
+        Some(Commands::Forget { id, confirm }) => {
+            cli::forget::handle(id, confirm, cli.db_path.clone()).await
+        }
         Some(Commands::Embed {
             all,
             memory_id,
             namespace,
             batch_size,
+            concurrency,
+            force,
             progress,
         }) => {
             cli::embed::handle(
@@ -454,12 +644,20 @@ async fn main() -> Result<()> {
                 memory_id,
                 namespace,
                 batch_size,
+                usize::from(concurrency),
+                force,
                 progress,
                 cli.db_path.clone(),
             )
             .await
         }
         Some(Commands::Models { action }) => cli::models::handle(action).await,
+        Some(Commands::Tags { namespace, action }) => {
+            cli::tags::handle(action, namespace, cli.db_path.clone()).await
+        }
+        Some(Commands::Namespace { action }) => {
+            cli::namespace::handle(action, cli.db_path.clone()).await
+        }
         Some(Commands::Evolve { job }) => cli::evolve::handle(job, cli.db_path.clone()).await,
         Some(Commands::Graph {
             format,
@@ -467,15 +665,28 @@ async fn main() -> Result<()> {
             output,
             query,
             namespace,
+            max_nodes,
         }) => {
-            cli::graph::handle(format, depth, output, query, namespace, cli.db_path.clone()).await
+            cli::graph::handle(
+                format,
+                depth,
+                output,
+                query,
+                namespace,
+                max_nodes,
+                cli.db_path.clone(),
+            )
+            .await
         }
         Some(Commands::Artifact { command }) => {
             cli::artifact::handle(command, cli.db_path.clone()).await
         }
-        Some(Commands::Doctor { verbose, fix, json }) => {
-            cli::doctor::handle(verbose, fix, json, cli.db_path.clone()).await
-        }
+        Some(Commands::Doctor {
+            verbose,
+            fix,
+            json,
+            strict,
+        }) => cli::doctor::handle(verbose, fix, json, strict, cli.db_path.clone()).await,
         Some(Commands::Update {
             tools,
             install,
@@ -537,6 +748,9 @@ async fn main() -> Result<()> {
                 None, // No initial prompt
                 Some(event_broadcaster),
                 Some(state_manager),
+                cli.dry_run,
+                cli.transcript,
+                Vec::new(),
             )
             .await;
 
@@ -0,0 +1,222 @@
+//! High-level memory API for library users
+//!
+//! [`MemoryManager`] performs the same steps as the CLI and MCP `remember`
+//! and `recall` commands: LLM enrichment, embedding generation, storage, and
+//! hybrid search. Enrichment and embeddings are optional. Without them, or
+//! when a call fails, memories are stored with basic metadata, just as
+//! `mnemosyne remember` does without an API key.
+//!
+//! With embeddings, the manager tells storage which model embeds queries so
+//! vector search skips memories embedded by an earlier model. Under
+//! `StaleEmbeddingPolicy::Reembed`, a recall that finds such memories starts
+//! re-embedding them in the background.
+
+use crate::config::StaleEmbeddingPolicy;
+use crate::embeddings::{backfill_embeddings, BackfillOptions, BackfillProgress, EmbeddingService};
+use crate::error::{MnemosyneError, Result};
+use crate::services::LlmService;
+use crate::storage::libsql::LibsqlStorage;
+use crate::storage::StorageBackend;
+use crate::types::{MemoryId, MemoryNote, MemoryType, Namespace, SearchQuery, SearchResult};
+use chrono::Utc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use tracing::{debug, warn};
+
+/// Context recorded on memories stored through the library API
+const REMEMBER_CONTEXT: &str = "Library input";
+
+/// Stores and recalls memories through a single storage backend
+pub struct MemoryManager {
+    storage: Arc<LibsqlStorage>,
+    llm: Option<Arc<LlmService>>,
+    embeddings: Option<Arc<dyn EmbeddingService>>,
+    /// Set while a background re-embed is running
+    reembedding: Arc<AtomicBool>,
+}
+
+impl MemoryManager {
+    /// Manager without enrichment or embeddings
+    pub fn new(storage: Arc<LibsqlStorage>) -> Self {
+        Self {
+            storage,
+            llm: None,
+            embeddings: None,
+            reembedding: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// Enrich new memories with LLM-extracted summary, keywords, tags, type
+    /// and importance
+    pub fn with_llm(mut self, llm: Arc<LlmService>) -> Self {
+        self.llm = Some(llm);
+        self
+    }
+
+    /// Embed new memories for vector search
+    pub fn with_embeddings(mut self, embeddings: Arc<dyn EmbeddingService>) -> Self {
+        self.storage
+            .set_active_embedding_model(Some(embeddings.model_name().to_string()));
+        self.embeddings = Some(embeddings);
+        self
+    }
+
+    /// Storage backend, for operations beyond remember and recall
+    pub fn storage(&self) -> &Arc<LibsqlStorage> {
+        &self.storage
+    }
+
+    /// Store `content` in `namespace`, returning the new memory's ID
+    ///
+    /// `importance` (1-10) overrides the enriched or default importance; a
+    /// value outside that range is rejected. Enrichment and embedding
+    /// failures are logged and the memory is stored without them; storage
+    /// errors are returned.
+    pub async fn remember(
+        &self,
+        content: String,
+        namespace: Namespace,
+        importance: Option<u8>,
+    ) -> Result<MemoryId> {
+        if let Some(importance) = importance.filter(|i| !(1..=10).contains(i)) {
+            return Err(MnemosyneError::ValidationError(format!(
+                "Importance must be between 1-10, got {}",
+                importance
+            )));
+        }
+
+        let mut memory = match &self.llm {
+            Some(llm) if llm.has_api_key() => {
+                match llm.enrich_memory(&content, REMEMBER_CONTEXT).await {
+                    Ok(enriched) => enriched,
+                    Err(e) => {
+                        warn!(
+                            "LLM enrichment failed: {}, storing memory without enrichment",
+                            e
+                        );
+                        basic_memory(content)
+                    }
+                }
+            }
+            _ => {
+                debug!("Creating basic memory without LLM enrichment");
+                basic_memory(content)
+            }
+        };
+
+        memory.namespace = namespace;
+        if let Some(importance) = importance {
+            memory.importance = importance;
+        }
+
+        if let Some(embeddings) = &self.embeddings {
+            match embeddings.embed(&memory.content).await {
+                Ok(embedding) => {
+                    memory.embedding = Some(embedding);
+                    memory.embedding_model = embeddings.model_name().to_string();
+                }
+                Err(e) => warn!(
+                    "Embedding generation failed: {}, storing memory without embedding",
+                    e
+                ),
+            }
+        }
+
+        self.storage.store_memory(&memory).await?;
+        Ok(memory.id)
+    }
+
+    /// Hybrid search (keyword, vector, and graph) filtered by `query`
+    pub async fn recall(&self, query: SearchQuery) -> Result<Vec<SearchResult>> {
+        if self.storage.search_config().stale_embeddings == StaleEmbeddingPolicy::Reembed {
+            self.spawn_reembed().await;
+        }
+        self.storage.search(&query).await
+    }
+
+    /// Re-embed memories whose embedding is missing or from another model
+    ///
+    /// Returns the backfill counts; does nothing without embeddings.
+    pub async fn reembed_stale(&self) -> Result<BackfillProgress> {
+        match &self.embeddings {
+            Some(embeddings) => reembed(&self.storage, embeddings.as_ref()).await,
+            None => Ok(BackfillProgress::default()),
+        }
+    }
+
+    /// Start a background re-embed if stale embeddings exist and none is
+    /// already running
+    async fn spawn_reembed(&self) {
+        let Some(embeddings) = &self.embeddings else {
+            return;
+        };
+        if self.reembedding.load(Ordering::Acquire) {
+            return;
+        }
+        match self
+            .storage
+            .embedding_model_status(embeddings.model_name())
+            .await
+        {
+            Ok(status) if status.stale > 0 => {}
+            Ok(_) => return,
+            Err(e) => {
+                warn!("Failed to check embedding models: {}", e);
+                return;
+            }
+        }
+        if self.reembedding.swap(true, Ordering::AcqRel) {
+            return;
+        }
+
+        let storage = self.storage.clone();
+        let embeddings = embeddings.clone();
+        let reembedding = self.reembedding.clone();
+        tokio::spawn(async move {
+            match reembed(&storage, embeddings.as_ref()).await {
+                Ok(progress) => debug!(
+                    "Re-embedded {} memories ({} failed)",
+                    progress.embedded, progress.failed
+                ),
+                Err(e) => warn!("Background re-embedding failed: {}", e),
+            }
+            reembedding.store(false, Ordering::Release);
+        });
+    }
+}
+
+async fn reembed(
+    storage: &LibsqlStorage,
+    embeddings: &dyn EmbeddingService,
+) -> Result<BackfillProgress> {
+    backfill_embeddings(storage, embeddings, &BackfillOptions::default(), |_| {}).await
+}
+
+/// Memory with the metadata the CLI uses when enrichment is unavailable
+fn basic_memory(content: String) -> MemoryNote {
+    let now = Utc::now();
+    MemoryNote {
+        id: MemoryId::new(),
+        namespace: Namespace::Global,
+        created_at: now,
+        updated_at: now,
+        summary: content.chars().take(100).collect(),
+        content,
+        keywords: Vec::new(),
+        tags: Vec::new(),
+        context: REMEMBER_CONTEXT.to_string(),
+        memory_type: MemoryType::Insight,
+        importance: 5,
+        confidence: 0.5,
+        links: Vec::new(),
+        related_files: Vec::new(),
+        related_entities: Vec::new(),
+        access_count: 0,
+        last_accessed_at: now,
+        expires_at: None,
+        is_archived: false,
+        superseded_by: None,
+        embedding: None,
+        embedding_model: String::new(),
+    }
+}
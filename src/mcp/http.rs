@@ -0,0 +1,291 @@
+//! MCP HTTP+SSE transport
+//!
+//! Follows the MCP 2024-11-05 HTTP+SSE transport. A client opens
+//! `GET /sse` and receives an `endpoint` event naming its message URL, then
+//! POSTs JSON-RPC requests there. Each POST is acknowledged with `202
+//! Accepted`, and the response arrives on that client's event stream as a
+//! `message` event. Requests go to the same [`McpServer::handle_message`] as
+//! the stdio transport, so only the framing differs.
+//!
+//! Every tool can read and change memories, so requests are checked first:
+//! an `Origin` header must name a loopback host (against DNS rebinding from
+//! web pages), and when a token is configured the request must carry it as
+//! `Authorization: Bearer <token>`. Without a token the server only listens
+//! on loopback addresses.
+
+use super::protocol::JsonRpcResponse;
+use super::server::McpServer;
+use crate::error::{MnemosyneError, Result};
+use axum::{
+    extract::{Query, State},
+    http::{header, HeaderMap, StatusCode},
+    response::{
+        sse::{Event as SseEvent, KeepAlive},
+        Sse,
+    },
+    routing::{get, post},
+    Router,
+};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::sync::{Arc, Mutex};
+use tokio::net::TcpListener;
+use tokio::sync::mpsc;
+use tokio_stream::{wrappers::ReceiverStream, Stream, StreamExt as _};
+use tracing::{debug, info};
+
+/// Path clients open the event stream on
+pub const SSE_PATH: &str = "/sse";
+
+/// Path clients POST requests to, with their `sessionId` in the query
+pub const MESSAGE_PATH: &str = "/message";
+
+/// Responses queued per client before its POSTs wait for the stream
+const SESSION_BUFFER: usize = 32;
+
+/// Response channel of each connected client's event stream, by session id
+type Sessions = Arc<Mutex<HashMap<String, mpsc::Sender<JsonRpcResponse>>>>;
+
+#[derive(Clone)]
+struct HttpState {
+    server: Arc<McpServer>,
+    /// Bearer token every request must present, if set
+    token: Option<Arc<str>>,
+    sessions: Sessions,
+}
+
+impl HttpState {
+    /// Reject requests from foreign origins or without the token
+    fn authorize(&self, headers: &HeaderMap) -> std::result::Result<(), StatusCode> {
+        if let Some(origin) = headers.get(header::ORIGIN) {
+            let allowed = origin.to_str().map(is_loopback_origin).unwrap_or(false);
+            if !allowed {
+                debug!("Rejecting MCP request from origin {:?}", origin);
+                return Err(StatusCode::FORBIDDEN);
+            }
+        }
+
+        if let Some(token) = &self.token {
+            let presented = headers
+                .get(header::AUTHORIZATION)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.strip_prefix("Bearer "));
+            if !presented.is_some_and(|presented| constant_time_eq(presented, token)) {
+                return Err(StatusCode::UNAUTHORIZED);
+            }
+        }
+        Ok(())
+    }
+
+    fn session(&self, id: &str) -> Option<mpsc::Sender<JsonRpcResponse>> {
+        self.sessions
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(id)
+            .cloned()
+    }
+
+    fn end_session(&self, id: &str) {
+        end_session(&self.sessions, id);
+    }
+}
+
+fn end_session(sessions: &Sessions, id: &str) {
+    sessions
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .remove(id);
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MessageQuery {
+    session_id: String,
+}
+
+/// Removes its session when the client's event stream is dropped
+struct SessionGuard {
+    sessions: Sessions,
+    id: String,
+}
+
+impl Drop for SessionGuard {
+    fn drop(&mut self) {
+        debug!("MCP client disconnected (session {})", self.id);
+        end_session(&self.sessions, &self.id);
+    }
+}
+
+/// Whether `origin` (`scheme://host[:port]`) names a loopback host
+fn is_loopback_origin(origin: &str) -> bool {
+    let Some((_, authority)) = origin.split_once("://") else {
+        return false;
+    };
+    let host = match authority.strip_prefix('[') {
+        // IPv6 literal, e.g. [::1]:3100
+        Some(rest) => rest.split(']').next().unwrap_or_default(),
+        None => authority.split(':').next().unwrap_or_default(),
+    };
+    host.eq_ignore_ascii_case("localhost")
+        || host
+            .parse::<std::net::IpAddr>()
+            .is_ok_and(|ip| ip.is_loopback())
+}
+
+/// Compare without exiting early, so timing doesn't reveal the token
+fn constant_time_eq(a: &str, b: &str) -> bool {
+    a.len() == b.len()
+        && a.bytes()
+            .zip(b.bytes())
+            .fold(0u8, |diff, (x, y)| diff | (x ^ y))
+            == 0
+}
+
+/// Routes for the HTTP+SSE transport
+///
+/// With `token` set, every request must present it as a bearer token.
+pub fn router(server: Arc<McpServer>, token: Option<String>) -> Router {
+    let state = HttpState {
+        server,
+        token: token.map(Arc::from),
+        sessions: Arc::new(Mutex::new(HashMap::new())),
+    };
+    Router::new()
+        .route(SSE_PATH, get(sse_handler))
+        .route(MESSAGE_PATH, post(message_handler))
+        .with_state(state)
+}
+
+/// Serve MCP clients on `listener` until it closes
+///
+/// Refuses to listen on a non-loopback address without a `token`.
+pub async fn serve(
+    server: Arc<McpServer>,
+    listener: TcpListener,
+    token: Option<String>,
+) -> Result<()> {
+    let addr = listener.local_addr()?;
+    if token.is_none() && !addr.ip().is_loopback() {
+        return Err(MnemosyneError::ValidationError(format!(
+            "Refusing to serve MCP on non-loopback address {} without a token (set --http-token or MNEMOSYNE_MCP_TOKEN)",
+            addr
+        )));
+    }
+
+    info!("MCP server listening on http://{}{}", addr, SSE_PATH);
+    axum::serve(listener, router(server, token)).await?;
+    Ok(())
+}
+
+/// Open a client session: announce its message endpoint, then stream its
+/// responses
+async fn sse_handler(
+    State(state): State<HttpState>,
+    headers: HeaderMap,
+) -> std::result::Result<
+    Sse<impl Stream<Item = std::result::Result<SseEvent, Infallible>>>,
+    StatusCode,
+> {
+    state.authorize(&headers)?;
+
+    let session_id = uuid::Uuid::new_v4().to_string();
+    let (tx, rx) = mpsc::channel(SESSION_BUFFER);
+    state
+        .sessions
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(session_id.clone(), tx);
+    debug!("MCP client connected over SSE (session {})", session_id);
+
+    let endpoint = SseEvent::default()
+        .event("endpoint")
+        .data(format!("{}?sessionId={}", MESSAGE_PATH, session_id));
+    let guard = SessionGuard {
+        sessions: state.sessions.clone(),
+        id: session_id,
+    };
+    let responses = ReceiverStream::new(rx).map(move |response| {
+        // The stream owns the guard, so the session ends when it is dropped
+        let _session = &guard;
+        let data = serde_json::to_string(&response).unwrap_or_default();
+        Ok(SseEvent::default().event("message").data(data))
+    });
+
+    Ok(
+        Sse::new(tokio_stream::once(Ok(endpoint)).chain(responses))
+            .keep_alive(KeepAlive::default()),
+    )
+}
+
+/// Accept one JSON-RPC request; its response goes out on the session's stream
+async fn message_handler(
+    State(state): State<HttpState>,
+    Query(query): Query<MessageQuery>,
+    headers: HeaderMap,
+    body: String,
+) -> StatusCode {
+    if let Err(status) = state.authorize(&headers) {
+        return status;
+    }
+    let Some(tx) = state.session(&query.session_id) else {
+        return StatusCode::NOT_FOUND;
+    };
+
+    tokio::spawn(async move {
+        let response = state.server.handle_message(&body).await;
+        if tx.send(response).await.is_err() {
+            debug!(
+                "MCP client disconnected (session {}), dropping response",
+                query.session_id
+            );
+            state.end_session(&query.session_id);
+        }
+    });
+    StatusCode::ACCEPTED
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_session_ends_when_its_stream_is_dropped() {
+        let sessions: Sessions = Arc::default();
+        let (tx, rx) = mpsc::channel(1);
+        sessions.lock().unwrap().insert("a".to_string(), tx);
+
+        let guard = SessionGuard {
+            sessions: sessions.clone(),
+            id: "a".to_string(),
+        };
+        let stream = ReceiverStream::new(rx).map(move |response| {
+            let _session = &guard;
+            response
+        });
+        assert!(sessions.lock().unwrap().contains_key("a"));
+
+        drop(stream);
+        assert!(sessions.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_only_loopback_origins_are_allowed() {
+        for origin in [
+            "http://localhost:5173",
+            "http://127.0.0.1:3100",
+            "http://[::1]:3100",
+            "https://LOCALHOST",
+        ] {
+            assert!(is_loopback_origin(origin), "{}", origin);
+        }
+        for origin in [
+            "https://attacker.example",
+            "http://localhost.attacker.example",
+            "http://10.0.0.5:3100",
+            "null",
+        ] {
+            assert!(!is_loopback_origin(origin), "{}", origin);
+        }
+    }
+}
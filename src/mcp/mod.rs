@@ -1,12 +1,13 @@
 //! Model Context Protocol (MCP) server implementation
 //!
-//! Provides a JSON-RPC 2.0 server over stdio for Claude Code integration.
-//! Implements 8 core memory tools organized around the OODA loop.
+//! Provides a JSON-RPC 2.0 server over stdio or HTTP+SSE for Claude Code
+//! integration. Implements 8 core memory tools organized around the OODA loop.
 
+pub mod http;
 pub mod protocol;
 pub mod server;
 pub mod tools;
 
 pub use protocol::{JsonRpcError, JsonRpcRequest, JsonRpcResponse};
-pub use server::McpServer;
+pub use server::{McpServer, McpTransport};
 pub use tools::{EventSink, ToolHandler};
@@ -1,16 +1,43 @@
-//! MCP server with stdio transport
+//! MCP server
 //!
-//! Implements JSON-RPC 2.0 server that communicates over stdin/stdout.
-//! Handles tool discovery and execution.
+//! Implements JSON-RPC 2.0 request handling for tool discovery and
+//! execution. Messages arrive over stdio (one newline-delimited message per
+//! line) or over HTTP+SSE (see [`super::http`]); both transports hand each
+//! message to the same [`McpServer::handle_message`].
 
 use super::protocol::{JsonRpcError, JsonRpcRequest, JsonRpcResponse};
 use super::tools::ToolHandler;
 use crate::error::Result;
 use serde_json::Value;
-use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use std::fmt;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncWrite, AsyncWriteExt, BufReader};
 use tracing::{debug, error, info};
 
-/// MCP server that handles JSON-RPC requests over stdio
+/// How the server exchanges JSON-RPC messages with clients
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum McpTransport {
+    /// Newline-delimited messages on stdin/stdout, for one local client
+    Stdio,
+    /// HTTP+SSE on `addr`, for any number of local or remote clients;
+    /// with `token` set, clients must send it as a bearer token
+    Http {
+        addr: SocketAddr,
+        token: Option<String>,
+    },
+}
+
+impl fmt::Display for McpTransport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Stdio => write!(f, "stdio"),
+            Self::Http { addr, .. } => write!(f, "http://{}", addr),
+        }
+    }
+}
+
+/// MCP server that handles JSON-RPC requests
 pub struct McpServer {
     tool_handler: ToolHandler,
 }
@@ -21,14 +48,30 @@ impl McpServer {
         Self { tool_handler }
     }
 
+    /// Serve clients over `transport` until the connection or listener closes
+    pub async fn serve(self, transport: McpTransport) -> Result<()> {
+        match transport {
+            McpTransport::Stdio => self.run().await,
+            McpTransport::Http { addr, token } => {
+                let listener = tokio::net::TcpListener::bind(addr).await?;
+                super::http::serve(Arc::new(self), listener, token).await
+            }
+        }
+    }
+
     /// Run the server (blocking, processes stdin/stdout)
     pub async fn run(&self) -> Result<()> {
         info!("MCP server started, listening on stdin...");
+        self.serve_stdio(BufReader::new(tokio::io::stdin()), tokio::io::stdout())
+            .await
+    }
 
-        let stdin = tokio::io::stdin();
-        let mut reader = BufReader::new(stdin);
-        let mut stdout = tokio::io::stdout();
-
+    /// Answer newline-delimited requests from `reader` on `writer` until EOF
+    pub async fn serve_stdio<R, W>(&self, mut reader: R, mut stdout: W) -> Result<()>
+    where
+        R: AsyncBufRead + Unpin,
+        W: AsyncWrite + Unpin,
+    {
         let mut line = String::new();
 
         loop {
@@ -50,7 +93,7 @@ impl McpServer {
                     debug!("Received request: {}", line);
 
                     // Process request
-                    let response = self.process_request(line).await;
+                    let response = self.handle_message(line).await;
 
                     // Write response to stdout
                     let response_json = serde_json::to_string(&response).unwrap_or_else(|e| {
@@ -90,8 +133,8 @@ impl McpServer {
         Ok(())
     }
 
-    /// Process a single JSON-RPC request
-    async fn process_request(&self, line: &str) -> JsonRpcResponse {
+    /// Process a single JSON-RPC request, whichever transport it came over
+    pub async fn handle_message(&self, line: &str) -> JsonRpcResponse {
         // Parse request
         let request: JsonRpcRequest = match serde_json::from_str(line) {
             Ok(req) => req,
@@ -184,6 +184,14 @@ impl ToolHandler {
                     }
                 }),
             },
+            Tool {
+                name: "mnemosyne.list_namespaces".to_string(),
+                description: "List namespaces that hold memories, with the number of active memories in each. Use before recall to find which project or session to search.".to_string(),
+                input_schema: serde_json::json!({
+                    "type": "object",
+                    "properties": {}
+                }),
+            },
             // ORIENT tools
             Tool {
                 name: "mnemosyne.graph".to_string(),
@@ -328,6 +336,7 @@ impl ToolHandler {
         let result = match tool_name {
             "mnemosyne.recall" => self.recall(params).await,
             "mnemosyne.list" => self.list(params).await,
+            "mnemosyne.list_namespaces" => self.list_namespaces().await,
             "mnemosyne.graph" => self.graph(params).await,
             "mnemosyne.context" => self.context(params).await,
             "mnemosyne.remember" => self.remember(params).await,
@@ -504,6 +513,7 @@ impl ToolHandler {
                     memory,
                     score: total_score,
                     match_reason: format!("hybrid ({})", match_reason),
+                    score_breakdown: None,
                 }
             })
             .collect();
@@ -523,11 +533,16 @@ impl ToolHandler {
             results.retain(|r| r.memory.importance >= min_importance);
         }
 
-        // Increment access counts for returned memories
-        for result in &results {
-            if let Err(e) = self.storage.increment_access(result.memory.id).await {
-                warn!("Failed to increment access count: {}", e);
-            }
+        // Increment access counts for returned memories in one write, off the
+        // response path
+        if !results.is_empty() {
+            let storage = Arc::clone(&self.storage);
+            let ids: Vec<_> = results.iter().map(|r| r.memory.id).collect();
+            tokio::spawn(async move {
+                if let Err(e) = storage.increment_access_batch(&ids).await {
+                    warn!("Failed to increment access counts: {}", e);
+                }
+            });
         }
 
         // Emit event through event sink
@@ -597,6 +612,24 @@ impl ToolHandler {
         }))
     }
 
+    async fn list_namespaces(&self) -> Result<Value> {
+        let namespaces = self.storage.list_namespaces().await?;
+        let entries: Vec<Value> = namespaces
+            .iter()
+            .map(|entry| {
+                serde_json::json!({
+                    "namespace": entry.namespace.to_string(),
+                    "count": entry.count,
+                })
+            })
+            .collect();
+
+        Ok(serde_json::json!({
+            "namespaces": entries,
+            "count": entries.len(),
+        }))
+    }
+
     // === ORIENT Tools ===
 
     async fn graph(&self, params: Value) -> Result<Value> {
@@ -737,6 +770,7 @@ impl ToolHandler {
         debug!("Generating embedding for memory: {}", memory.id);
         let embedding = self.embeddings.generate_embedding(&memory.content).await?;
         memory.embedding = Some(embedding);
+        memory.embedding_model = self.embeddings.model_name().to_string();
 
         // Store memory (with embedding)
         self.storage.store_memory(&memory).await?;
@@ -907,6 +941,7 @@ impl ToolHandler {
             match self.embeddings.generate_embedding(&content).await {
                 Ok(new_embedding) => {
                     memory.embedding = Some(new_embedding);
+                    memory.embedding_model = self.embeddings.model_name().to_string();
                     info!("Regenerated embedding for updated memory");
                 }
                 Err(e) => {
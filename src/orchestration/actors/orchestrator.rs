@@ -71,11 +71,20 @@ pub struct OrchestratorState {
 
 impl OrchestratorState {
     pub fn new(storage: Arc<dyn StorageBackend>, namespace: Namespace) -> Self {
+        Self::with_work_queue(storage, namespace, Arc::new(RwLock::new(WorkQueue::new())))
+    }
+
+    /// Create state around an existing work queue (shared with the supervision tree)
+    pub fn with_work_queue(
+        storage: Arc<dyn StorageBackend>,
+        namespace: Namespace,
+        work_queue: SharedWorkQueue,
+    ) -> Self {
         // Create shutdown channel for graceful task termination
         let (shutdown_tx, _) = tokio::sync::broadcast::channel(1);
 
         Self {
-            work_queue: Arc::new(RwLock::new(WorkQueue::new())),
+            work_queue,
             events: EventPersistence::new(storage, namespace),
             optimizer: None,
             reviewer: None,
@@ -207,11 +216,23 @@ pub struct OrchestratorActor {
     storage: Arc<dyn StorageBackend>,
     #[allow(dead_code)]
     namespace: Namespace,
+    /// Work queue shared with the owner, surviving actor restarts
+    work_queue: Option<SharedWorkQueue>,
 }
 
 impl OrchestratorActor {
     pub fn new(storage: Arc<dyn StorageBackend>, namespace: Namespace) -> Self {
-        Self { storage, namespace }
+        Self {
+            storage,
+            namespace,
+            work_queue: None,
+        }
+    }
+
+    /// Use a work queue owned outside the actor instead of a private one
+    pub fn with_work_queue(mut self, work_queue: SharedWorkQueue) -> Self {
+        self.work_queue = Some(work_queue);
+        self
     }
 
     /// Handle work submission
@@ -223,6 +244,7 @@ impl OrchestratorActor {
         let agent = item.agent;
         let phase = item.phase;
         let description = item.description.clone();
+        let snapshot = Box::new(item.clone());
 
         {
             let mut queue = state.work_queue.write().await;
@@ -244,7 +266,7 @@ impl OrchestratorActor {
             }
         }
 
-        // Persist event
+        // Persist event (with the item snapshot, so the queue can be replayed)
         state
             .events
             .persist(AgentEvent::WorkItemAssigned {
@@ -252,6 +274,7 @@ impl OrchestratorActor {
                 item_id,
                 description,
                 phase,
+                item: Some(snapshot),
             })
             .await?;
 
@@ -310,7 +333,7 @@ impl OrchestratorActor {
                         // Note: route is async.
                         let router = router.clone();
                         tokio::spawn(async move {
-                            if let Err(e) = router.route(AgentRole::Executor, msg).await {
+                            if let Err(e) = router.route(AgentRole::Executor, msg, None).await {
                                 tracing::warn!("Failed to route to executor via router: {}", e);
                             }
                         });
@@ -652,6 +675,7 @@ impl OrchestratorActor {
             let item_id = item.id.clone();
             let phase = item.phase;
             let description = item.description.clone();
+            let snapshot = Box::new(item.clone());
 
             {
                 let mut queue = state.work_queue.write().await;
@@ -670,6 +694,7 @@ impl OrchestratorActor {
                     item_id,
                     description,
                     phase,
+                    item: Some(snapshot),
                 })
                 .await?;
         }
@@ -1008,7 +1033,10 @@ impl Actor for OrchestratorActor {
     ) -> std::result::Result<Self::State, ActorProcessingErr> {
         tracing::debug!("Orchestrator actor starting");
         let (storage, namespace) = args;
-        Ok(OrchestratorState::new(storage, namespace))
+        Ok(match &self.work_queue {
+            Some(queue) => OrchestratorState::with_work_queue(storage, namespace, queue.clone()),
+            None => OrchestratorState::new(storage, namespace),
+        })
     }
 
     async fn post_start(
@@ -11,7 +11,7 @@
 //!
 //! ## Core Responsibilities
 //!
-//! - **Quality Gates**: Enforce 8 quality gates before work completion
+//! - **Quality Gates**: Enforce 9 quality gates before work completion
 //! - **Semantic Validation**: LLM-based deep semantic analysis (3 pillars)
 //! - **Requirement Tracking**: Extract, track, and validate requirement satisfaction
 //! - **Improvement Guidance**: Generate actionable feedback for failed reviews
@@ -23,7 +23,7 @@
 //! 2. **Completeness**: Are all explicit requirements fully implemented?
 //! 3. **Correctness**: Is the logic sound and bug-free?
 //!
-//! ## Quality Gates (8 total)
+//! ## Quality Gates (9 total)
 //!
 //! All gates must pass for work completion:
 //! - Intent satisfied
@@ -34,6 +34,15 @@
 //! - Completeness (semantic)
 //! - Correctness (semantic)
 //! - Principled implementation
+//! - Constitution (the project constitution's own quality gates)
+//!
+//! ## Constitution Gates
+//!
+//! On startup the Reviewer loads the active constitution from
+//! `.mnemosyne/artifacts/constitution/project-constitution.md` and turns its
+//! quality gates into [`QualityGate`]s (see [`crate::orchestration::quality_gate`]).
+//! Each failing gate adds an issue citing the gate it violated, e.g.
+//! `Violates constitution gate "All tests pass": 3 tests failed`.
 //!
 //! ## LLM Integration
 //!
@@ -98,10 +107,14 @@
 //! - [`QualityGates`]: Individual gate definitions
 //! - User guide: `docs/guides/llm-reviewer.md`
 
+use crate::artifacts::{Artifact, Constitution};
 use crate::error::Result;
 use crate::launcher::agents::AgentRole;
 use crate::orchestration::events::{AgentEvent, EventPersistence};
 use crate::orchestration::messages::{OrchestratorMessage, ReviewerMessage, WorkResult};
+use crate::orchestration::quality_gate::{
+    gates_from_constitution, GateInput, GateVerdict, QualityGate,
+};
 use crate::orchestration::state::{Phase, WorkItem, WorkItemId};
 use crate::storage::StorageBackend;
 use crate::types::Namespace;
@@ -119,7 +132,7 @@ use crate::python_bindings::{
     collect_implementation_from_memories, execution_memories_to_python_format,
 };
 
-/// Quality gates that must pass (8 total: 5 existing + 3 pillars)
+/// Quality gates that must pass (9 total: 5 existing + 3 pillars + constitution)
 #[derive(Debug, Clone, Default)]
 pub struct QualityGates {
     // Existing gates
@@ -132,6 +145,8 @@ pub struct QualityGates {
     pub completeness: bool,
     pub correctness: bool,
     pub principled_implementation: bool,
+    // Project constitution's quality gates
+    pub constitution_satisfied: bool,
 }
 
 impl QualityGates {
@@ -144,6 +159,7 @@ impl QualityGates {
             && self.completeness
             && self.correctness
             && self.principled_implementation
+            && self.constitution_satisfied
     }
 }
 
@@ -240,13 +256,14 @@ pub struct ReviewerState {
 
     /// LLM client for fallback validation
     llm_client: Option<Arc<crate::services::LlmService>>,
+
+    /// Gates from the active constitution
+    constitution_gates: Vec<Box<dyn QualityGate>>,
 }
 
 impl ReviewerState {
     pub fn new(storage: Arc<dyn StorageBackend>, namespace: Namespace) -> Self {
-        let llm_client = crate::services::LlmService::with_default()
-            .ok()
-            .map(Arc::new);
+        let llm_client = crate::services::LlmService::configured().ok().map(Arc::new);
 
         if llm_client.is_some() {
             tracing::info!("Reviewer initialized with direct LLM client support");
@@ -268,7 +285,38 @@ impl ReviewerState {
             #[cfg(feature = "python")]
             python_bridge: None,
             llm_client,
+            constitution_gates: Vec::new(),
+        }
+    }
+
+    /// Load the active constitution from an artifacts directory
+    ///
+    /// Replaces any previously registered constitution gates. Returns `false`
+    /// when the directory has no constitution.
+    pub fn load_constitution(&mut self, artifacts_dir: &std::path::Path) -> Result<bool> {
+        let path = artifacts_dir.join("constitution/project-constitution.md");
+        if !path.exists() {
+            return Ok(false);
         }
+
+        let constitution = Constitution::from_markdown(&std::fs::read_to_string(&path)?)?;
+        self.register_constitution(&constitution);
+        Ok(true)
+    }
+
+    /// Enforce a constitution's quality gates during review
+    pub fn register_constitution(&mut self, constitution: &Constitution) {
+        self.constitution_gates = gates_from_constitution(constitution, self.llm_client.clone());
+        tracing::info!(
+            "Reviewer enforcing {} of {} constitution quality gates",
+            self.constitution_gates.len(),
+            constitution.quality_gates.len()
+        );
+    }
+
+    /// Enforce an additional quality gate during review
+    pub fn register_quality_gate(&mut self, gate: Box<dyn QualityGate>) {
+        self.constitution_gates.push(gate);
     }
 
     pub fn register_orchestrator(&mut self, orchestrator: ActorRef<OrchestratorMessage>) {
@@ -472,14 +520,19 @@ impl ReviewerActor {
         gates.principled_implementation = principled_passed;
         all_issues.extend(principled_issues);
 
+        let (constitution_passed, constitution_issues) =
+            Self::verify_constitution(state, &result, &work_item).await?;
+        gates.constitution_satisfied = constitution_passed;
+        all_issues.extend(constitution_issues);
+
         // Get test suggestions
         let suggested_tests = Self::suggest_missing_tests(state, &result).await?;
 
         let passed = gates.all_passed();
 
         tracing::info!(
-            "Review result: {} (8 gates: intent={}, tests={}, docs={}, anti_patterns={}, \
-            constraints={}, completeness={}, correctness={}, principled={})",
+            "Review result: {} (9 gates: intent={}, tests={}, docs={}, anti_patterns={}, \
+            constraints={}, completeness={}, correctness={}, principled={}, constitution={})",
             if passed { "PASS" } else { "FAIL" },
             gates.intent_satisfied,
             gates.tests_passing,
@@ -488,7 +541,8 @@ impl ReviewerActor {
             gates.constraints_maintained,
             gates.completeness,
             gates.correctness,
-            gates.principled_implementation
+            gates.principled_implementation,
+            gates.constitution_satisfied
         );
 
         // Generate improvement guidance if review failed
@@ -1222,6 +1276,9 @@ impl ReviewerActor {
         if !gates.principled_implementation {
             guidance.push_str("- Unprincipled implementation\n");
         }
+        if !gates.constitution_satisfied {
+            guidance.push_str("- Constitution quality gates violated\n");
+        }
 
         guidance.push_str(&format!("\nIssues Found ({}):\n", issues.len()));
         for (i, issue) in issues.iter().enumerate() {
@@ -1336,6 +1393,62 @@ impl ReviewerActor {
         Ok(approved)
     }
 
+    /// Evaluate the constitution's quality gates against completed work
+    ///
+    /// Each failing gate yields an issue citing the gate it violated. Gates
+    /// that error (e.g. LLM unavailable) are logged and don't block.
+    async fn verify_constitution(
+        state: &ReviewerState,
+        result: &WorkResult,
+        work_item: &WorkItem,
+    ) -> Result<(bool, Vec<String>)> {
+        if state.constitution_gates.is_empty() {
+            return Ok((true, Vec::new()));
+        }
+
+        let mut memories = Vec::new();
+        for memory_id in &result.memory_ids {
+            match state.storage.get_memory(*memory_id).await {
+                Ok(memory) => memories.push(memory),
+                Err(e) => tracing::warn!(
+                    "Failed to retrieve memory {} for constitution gates: {:?}",
+                    memory_id,
+                    e
+                ),
+            }
+        }
+
+        let input = GateInput {
+            work_item,
+            result,
+            memories: &memories,
+        };
+
+        let mut issues = Vec::new();
+        for gate in &state.constitution_gates {
+            match gate.evaluate(&input).await {
+                Ok(GateVerdict::Pass) => {}
+                Ok(GateVerdict::Fail(reason)) => {
+                    issues.push(format!(
+                        "Violates constitution gate \"{}\": {}",
+                        gate.principle(),
+                        reason
+                    ));
+                }
+                Err(e) => tracing::warn!(
+                    "Constitution gate \"{}\" could not be evaluated: {}",
+                    gate.principle(),
+                    e
+                ),
+            }
+        }
+
+        if !issues.is_empty() {
+            tracing::warn!("Constitution check failed: {} issues", issues.len());
+        }
+        Ok((issues.is_empty(), issues))
+    }
+
     /// Check quality gates for a work item
     async fn check_quality_gates(state: &ReviewerState, item_id: WorkItemId) -> Result<bool> {
         tracing::info!("Checking quality gates for: {:?}", item_id);
@@ -1362,7 +1475,16 @@ impl Actor for ReviewerActor {
     ) -> std::result::Result<Self::State, ActorProcessingErr> {
         tracing::debug!("Reviewer actor starting");
         let (storage, namespace) = args;
-        Ok(ReviewerState::new(storage, namespace))
+        let mut state = ReviewerState::new(storage, namespace);
+
+        let artifacts_dir = std::path::PathBuf::from(".mnemosyne/artifacts");
+        match state.load_constitution(&artifacts_dir) {
+            Ok(true) => tracing::debug!("Loaded project constitution for review"),
+            Ok(false) => tracing::debug!("No project constitution found"),
+            Err(e) => tracing::warn!("Failed to load project constitution: {}", e),
+        }
+
+        Ok(state)
     }
 
     async fn post_start(
@@ -1932,6 +2054,7 @@ mod tests {
             completeness: true,
             correctness: true,
             principled_implementation: true,
+            constitution_satisfied: true,
         };
 
         assert!(gates.all_passed());
@@ -1948,6 +2071,7 @@ mod tests {
             completeness: false, // This one fails
             correctness: true,
             principled_implementation: true,
+            constitution_satisfied: true,
         };
 
         assert!(!gates.all_passed());
@@ -2105,4 +2229,139 @@ mod tests {
             "Content should contain 'tests'"
         );
     }
+
+    struct FixedGate {
+        principle: &'static str,
+        verdict: GateVerdict,
+    }
+
+    #[async_trait::async_trait]
+    impl QualityGate for FixedGate {
+        fn principle(&self) -> &str {
+            self.principle
+        }
+
+        async fn evaluate(&self, _input: &GateInput<'_>) -> Result<GateVerdict> {
+            Ok(self.verdict.clone())
+        }
+    }
+
+    /// Review a work item against a single constitution gate, returning
+    /// whether completion is allowed and the reported issues
+    async fn review_with_gate(verdict: GateVerdict) -> (bool, Vec<String>) {
+        let temp_dir = TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("test.db");
+        let storage = Arc::new(
+            LibsqlStorage::new_with_validation(
+                crate::ConnectionMode::Local(db_path.to_str().unwrap().to_string()),
+                true,
+            )
+            .await
+            .expect("Failed to create test storage"),
+        );
+        let namespace = Namespace::Session {
+            project: "test".to_string(),
+            session_id: "test-session".to_string(),
+        };
+
+        let mut state = ReviewerState::new(storage, namespace);
+        state.register_quality_gate(Box::new(FixedGate {
+            principle: "Every public API is documented",
+            verdict,
+        }));
+
+        let work_item = WorkItem::new(
+            "Add login endpoint".to_string(),
+            AgentRole::Executor,
+            Phase::PlanToArtifacts,
+            5,
+        );
+        let result = WorkResult::success(work_item.id.clone(), Duration::from_secs(1));
+
+        let (passed, issues) = ReviewerActor::verify_constitution(&state, &result, &work_item)
+            .await
+            .unwrap();
+
+        // Every other gate passes; completion hinges on the constitution
+        let gates = QualityGates {
+            intent_satisfied: true,
+            tests_passing: true,
+            documentation_complete: true,
+            no_anti_patterns: true,
+            constraints_maintained: true,
+            completeness: true,
+            correctness: true,
+            principled_implementation: true,
+            constitution_satisfied: passed,
+        };
+        state.quality_results.insert(work_item.id.clone(), gates);
+
+        let allowed = ReviewerActor::check_quality_gates(&state, work_item.id)
+            .await
+            .unwrap();
+        (allowed, issues)
+    }
+
+    #[tokio::test]
+    async fn test_failing_constitution_gate_blocks_completion() {
+        let (allowed, issues) =
+            review_with_gate(GateVerdict::Fail("login() has no doc comment".to_string())).await;
+
+        assert!(!allowed);
+        assert_eq!(
+            issues,
+            vec![
+                "Violates constitution gate \"Every public API is documented\": \
+                 login() has no doc comment"
+                    .to_string()
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_passing_constitution_gate_allows_completion() {
+        let (allowed, issues) = review_with_gate(GateVerdict::Pass).await;
+
+        assert!(allowed);
+        assert!(issues.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_load_constitution_from_artifacts_dir() {
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Arc::new(
+            LibsqlStorage::new_with_validation(
+                crate::ConnectionMode::Local(
+                    temp_dir
+                        .path()
+                        .join("test.db")
+                        .to_str()
+                        .unwrap()
+                        .to_string(),
+                ),
+                true,
+            )
+            .await
+            .expect("Failed to create test storage"),
+        );
+        let mut state = ReviewerState::new(storage, Namespace::Global);
+
+        let artifacts_dir = temp_dir.path().join("artifacts");
+        assert!(!state.load_constitution(&artifacts_dir).unwrap());
+
+        let constitution = Constitution::builder("Test".to_string())
+            .principle("Simplicity first")
+            .quality_gate("All tests pass")
+            .quality_gate("No TODO comments")
+            .build();
+        std::fs::create_dir_all(artifacts_dir.join("constitution")).unwrap();
+        std::fs::write(
+            artifacts_dir.join("constitution/project-constitution.md"),
+            constitution.to_markdown().unwrap(),
+        )
+        .unwrap();
+
+        assert!(state.load_constitution(&artifacts_dir).unwrap());
+        assert_eq!(state.constitution_gates.len(), 2);
+    }
 }
@@ -169,7 +169,10 @@ impl BranchCoordinator {
             return self.approve_coordinator_access(request).await;
         }
 
-        // 3. Validate branch access using branch guard
+        // 3. Report conflicts with agents already on the branch
+        self.broadcast_conflicts(&request);
+
+        // 4. Validate branch access using branch guard
         match self.guard.validate_branch_access(
             &request.agent_identity,
             &request.target_branch,
@@ -186,6 +189,28 @@ impl BranchCoordinator {
         }
     }
 
+    /// Send the request's conflict assessment to the dashboard through the
+    /// notifier, listing the requesting agent with the ones it conflicts with
+    fn broadcast_conflicts(&self, request: &JoinRequest) {
+        let mut assessment = match self.guard.assess_branch_access(
+            &request.agent_identity.id,
+            &request.target_branch,
+            &request.intent,
+        ) {
+            Ok(Some(assessment)) => assessment,
+            Ok(None) => return,
+            Err(e) => {
+                tracing::warn!("Conflict assessment failed: {}", e);
+                return;
+            }
+        };
+
+        assessment
+            .conflicting_agents
+            .push(request.agent_identity.id.clone());
+        self.notifier.broadcast_assessment(&assessment);
+    }
+
     /// Auto-approve read-only access
     async fn approve_readonly_access(&self, request: JoinRequest) -> Result<JoinResponse> {
         let mut registry = self.registry.write().map_err(|e| {
@@ -523,6 +548,7 @@ impl BranchCoordinator {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::api::{EventBroadcaster, EventType};
     use crate::launcher::agents::AgentRole;
     use crate::orchestration::branch_registry::BranchRegistry;
     use crate::orchestration::conflict_detector::ConflictDetector;
@@ -533,6 +559,10 @@ mod tests {
     use std::sync::Arc;
 
     async fn setup_coordinator() -> BranchCoordinator {
+        setup_coordinator_with(None).await
+    }
+
+    async fn setup_coordinator_with(broadcaster: Option<EventBroadcaster>) -> BranchCoordinator {
         let registry = Arc::new(RwLock::new(BranchRegistry::new()));
 
         let guard = Arc::new(BranchGuard::new(registry.clone(), PathBuf::from(".")));
@@ -545,9 +575,14 @@ mod tests {
             notify_on_save: true,
             periodic_interval_minutes: 20,
             session_end_summary: true,
+            ..Default::default()
         };
 
-        let notifier = Arc::new(ConflictNotifier::new(notifier_config, file_tracker));
+        let mut notifier = ConflictNotifier::new(notifier_config, file_tracker);
+        if let Some(broadcaster) = broadcaster {
+            notifier = notifier.with_event_broadcaster(broadcaster);
+        }
+        let notifier = Arc::new(notifier);
 
         let git_wrapper = Arc::new(GitWrapper::new(registry.clone(), PathBuf::from(".")));
 
@@ -621,4 +656,48 @@ mod tests {
             _ => panic!("Expected Approved response for coordinator access"),
         }
     }
+
+    #[tokio::test]
+    async fn test_conflicting_join_is_broadcast() {
+        let broadcaster = EventBroadcaster::new(10);
+        let mut rx = broadcaster.subscribe();
+        let coordinator = setup_coordinator_with(Some(broadcaster)).await;
+
+        let identity = || AgentIdentity {
+            id: AgentId::new(),
+            role: AgentRole::Executor,
+            namespace: Namespace::Global,
+            branch: "main".to_string(),
+            working_dir: PathBuf::from("."),
+            spawned_at: Utc::now(),
+            parent_id: None,
+            is_coordinator: false,
+        };
+        let join = |agent_identity| JoinRequest {
+            agent_identity,
+            target_branch: "main".to_string(),
+            intent: WorkIntent::FullBranch,
+            mode: CoordinationMode::Coordinated,
+            work_items: vec![],
+        };
+
+        let first = identity();
+        let second = identity();
+        coordinator
+            .handle_join_request(join(first.clone()))
+            .await
+            .unwrap();
+        assert!(rx.try_recv().is_err(), "Empty branch has no conflict");
+
+        coordinator
+            .handle_join_request(join(second.clone()))
+            .await
+            .unwrap();
+        match rx.try_recv().unwrap().event_type {
+            EventType::ConflictDetected { agents, .. } => {
+                assert_eq!(agents, vec![first.id.to_string(), second.id.to_string()]);
+            }
+            other => panic!("Wrong event type: {:?}", other),
+        }
+    }
 }
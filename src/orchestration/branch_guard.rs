@@ -13,7 +13,9 @@
 
 use crate::error::{MnemosyneError, Result};
 use crate::orchestration::branch_registry::{BranchRegistry, CoordinationMode, WorkIntent};
-use crate::orchestration::conflict_detector::{ConflictAction, ConflictDetector};
+use crate::orchestration::conflict_detector::{
+    ConflictAction, ConflictAssessment, ConflictDetector,
+};
 use crate::orchestration::git_state::GitState;
 use crate::orchestration::git_wrapper::{GitOperationType, GitWrapper};
 use crate::orchestration::identity::{AgentId, AgentIdentity};
@@ -186,6 +188,36 @@ impl BranchGuard {
         }
     }
 
+    /// Assess how `intent` conflicts with other agents' assignments on
+    /// `target_branch`
+    ///
+    /// `None` when the guard or conflict detection is disabled, or when
+    /// nothing conflicts.
+    pub fn assess_branch_access(
+        &self,
+        agent_id: &AgentId,
+        target_branch: &str,
+        intent: &WorkIntent,
+    ) -> Result<Option<ConflictAssessment>> {
+        if !self.config.enabled || !self.config.conflict_detection {
+            return Ok(None);
+        }
+
+        let registry = self
+            .registry
+            .read()
+            .map_err(|e| MnemosyneError::Other(format!("Failed to read registry: {}", e)))?;
+        let others: Vec<_> = registry
+            .get_assignments(target_branch)
+            .into_iter()
+            .filter(|a| &a.agent_id != agent_id)
+            .collect();
+
+        Ok(self
+            .conflict_detector
+            .assess_conflict(&others, intent, agent_id))
+    }
+
     /// Validate intent compatibility for agent already on branch
     fn validate_intent_compatibility(
         &self,
@@ -279,8 +279,10 @@ impl ClaudeAgentBridge {
             *state = AgentState::Active;
         }
 
-        // Broadcast agent started event with task
-        let event = Event::agent_started_with_task(self.agent_id.clone(), item.description.clone());
+        // Broadcast agent started event with task (completion/failure echo the correlation ID)
+        let correlation_id = uuid::Uuid::new_v4().to_string();
+        let event = Event::agent_started_with_task(self.agent_id.clone(), item.description.clone())
+            .with_correlation_id(correlation_id.clone());
         if let Err(e) = self.event_tx.send(event) {
             warn!("Failed to broadcast agent started event: {}", e);
         }
@@ -319,7 +321,8 @@ impl ClaudeAgentBridge {
 
                     // Broadcast agent failed event
                     let error_msg = format!("{}", e);
-                    let event = Event::agent_failed(agent_id.clone(), error_msg.clone());
+                    let event = Event::agent_failed(agent_id.clone(), error_msg.clone())
+                        .with_correlation_id(correlation_id.clone());
                     let _ = event_tx.send(event);
 
                     MnemosyneError::Other(format!("Agent execution failed: {}", e))
@@ -330,7 +333,8 @@ impl ClaudeAgentBridge {
 
                 // Broadcast agent completed event
                 let summary = format!("Completed: {}", item.description);
-                let event = Event::agent_completed(agent_id, summary);
+                let event =
+                    Event::agent_completed(agent_id, summary).with_correlation_id(correlation_id);
                 let _ = event_tx.send(event);
 
                 Ok::<WorkResult, MnemosyneError>(result)
@@ -23,6 +23,7 @@
 //! on_save = true
 //! periodic_interval_minutes = 20
 //! session_end_summary = true
+//! broadcast_min_severity = "Error"
 //!
 //! [cross_process]
 //! enabled = true
@@ -41,6 +42,7 @@ use crate::error::{MnemosyneError, Result};
 use crate::orchestration::branch_coordinator::BranchCoordinatorConfig;
 use crate::orchestration::branch_guard::BranchGuardConfig;
 use crate::orchestration::branch_registry::CoordinationMode;
+use crate::orchestration::conflict_detector::ConflictSeverity;
 use crate::orchestration::conflict_notifier::NotificationConfig;
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
@@ -169,6 +171,10 @@ pub struct NotificationSettings {
     /// Session end summary
     #[serde(default = "default_true")]
     pub session_end_summary: bool,
+
+    /// Minimum severity broadcast to the dashboard as an API event
+    #[serde(default = "default_broadcast_min_severity")]
+    pub broadcast_min_severity: ConflictSeverity,
 }
 
 impl Default for NotificationSettings {
@@ -178,6 +184,7 @@ impl Default for NotificationSettings {
             on_save: true,
             periodic_interval_minutes: 20, // Per user requirement
             session_end_summary: true,
+            broadcast_min_severity: default_broadcast_min_severity(),
         }
     }
 }
@@ -266,6 +273,10 @@ fn default_periodic_interval() -> i64 {
     20 // Per user requirement
 }
 
+fn default_broadcast_min_severity() -> ConflictSeverity {
+    ConflictSeverity::Error
+}
+
 fn default_mnemosyne_dir() -> String {
     ".mnemosyne".to_string()
 }
@@ -370,6 +381,7 @@ impl BranchIsolationConfig {
             notify_on_save: self.notifications.on_save,
             periodic_interval_minutes: self.notifications.periodic_interval_minutes,
             session_end_summary: self.notifications.session_end_summary,
+            broadcast_min_severity: self.notifications.broadcast_min_severity,
         }
     }
 
@@ -4,6 +4,9 @@
 //! - **On every save**: Notify about NEW conflicts immediately
 //! - **Every 20 minutes**: Summary of ALL active conflicts
 //! - **Before session end**: Final summary of unresolved conflicts
+//! - **Dashboard**: Assessments at or above a configured severity are
+//!   broadcast as `ConflictDetected` API events. `BranchCoordinator` passes
+//!   the assessment of every join request here.
 //!
 //! # Design
 //!
@@ -12,6 +15,8 @@
 //! - Background task for periodic notifications
 //! - Session lifecycle hooks for final summary
 
+use crate::api::{Event, EventBroadcaster};
+use crate::orchestration::conflict_detector::{ConflictAssessment, ConflictSeverity};
 use crate::orchestration::file_tracker::{ActiveConflict, FileTracker};
 use crate::orchestration::identity::AgentId;
 use chrono::{DateTime, Duration, Utc};
@@ -33,6 +38,9 @@ pub struct NotificationConfig {
 
     /// Send final summary before session end (default: true)
     pub session_end_summary: bool,
+
+    /// Minimum severity broadcast as an API event (default: Error)
+    pub broadcast_min_severity: ConflictSeverity,
 }
 
 impl Default for NotificationConfig {
@@ -42,6 +50,7 @@ impl Default for NotificationConfig {
             notify_on_save: true,
             periodic_interval_minutes: 20,
             session_end_summary: true,
+            broadcast_min_severity: ConflictSeverity::Error,
         }
     }
 }
@@ -107,6 +116,9 @@ pub struct ConflictNotifier {
 
     /// Conflicts already notified to agents (avoid duplicate new-conflict alerts)
     notified_conflicts: Arc<RwLock<HashMap<AgentId, HashSet<String>>>>,
+
+    /// Dashboard event broadcaster (None = assessments aren't broadcast)
+    event_broadcaster: Option<EventBroadcaster>,
 }
 
 impl ConflictNotifier {
@@ -118,6 +130,7 @@ impl ConflictNotifier {
             last_periodic_notification: Arc::new(RwLock::new(HashMap::new())),
             notification_history: Arc::new(RwLock::new(Vec::new())),
             notified_conflicts: Arc::new(RwLock::new(HashMap::new())),
+            event_broadcaster: None,
         }
     }
 
@@ -126,6 +139,34 @@ impl ConflictNotifier {
         Self::new(NotificationConfig::default(), file_tracker)
     }
 
+    /// Broadcast conflict assessments to the dashboard
+    pub fn with_event_broadcaster(mut self, broadcaster: EventBroadcaster) -> Self {
+        self.event_broadcaster = Some(broadcaster);
+        self
+    }
+
+    /// Broadcast an assessment as a `ConflictDetected` event
+    ///
+    /// Assessments below `broadcast_min_severity` are dropped. Returns
+    /// whether an event was sent.
+    pub fn broadcast_assessment(&self, assessment: &ConflictAssessment) -> bool {
+        if !self.config.enabled || assessment.severity < self.config.broadcast_min_severity {
+            return false;
+        }
+        let Some(broadcaster) = &self.event_broadcaster else {
+            return false;
+        };
+
+        match broadcaster.broadcast(assessment_event(assessment)) {
+            Ok(_) => true,
+            Err(e) => {
+                // No subscribers (dashboard not connected)
+                tracing::debug!("Conflict event not delivered: {}", e);
+                false
+            }
+        }
+    }
+
     /// Notify agent of new conflicts (called on every save)
     ///
     /// Only notifies about conflicts that haven't been notified before.
@@ -469,6 +510,23 @@ impl ConflictNotifier {
     }
 }
 
+/// Translate an assessment into a dashboard event
+fn assessment_event(assessment: &ConflictAssessment) -> Event {
+    Event::conflict_detected(
+        assessment
+            .overlapping_paths
+            .iter()
+            .map(|path| path.display().to_string())
+            .collect(),
+        format!("{:?}", assessment.severity),
+        assessment
+            .conflicting_agents
+            .iter()
+            .map(ToString::to_string)
+            .collect(),
+    )
+}
+
 /// Format duration as human-readable "ago" string
 fn format_duration_ago(timestamp: DateTime<Utc>) -> String {
     let duration = Utc::now().signed_duration_since(timestamp);
@@ -637,6 +695,49 @@ mod tests {
         assert_eq!(history.len(), 1);
     }
 
+    #[test]
+    fn test_broadcast_assessment_above_threshold() {
+        use crate::api::EventType;
+        use crate::orchestration::conflict_detector::ConflictAction;
+
+        let detector = Arc::new(ConflictDetector::new());
+        let tracker = Arc::new(FileTracker::new(detector));
+        let broadcaster = EventBroadcaster::new(10);
+        let mut rx = broadcaster.subscribe();
+        let notifier = ConflictNotifier::with_default(tracker).with_event_broadcaster(broadcaster);
+
+        let agent1 = AgentId::new();
+        let agent2 = AgentId::new();
+        let assessment = |severity| ConflictAssessment {
+            severity,
+            action: ConflictAction::RequireCoordination,
+            conflicting_agents: vec![agent1.clone(), agent2.clone()],
+            overlapping_paths: vec![PathBuf::from("src/storage/libsql.rs")],
+            reason: "Same file".to_string(),
+            suggestions: vec![],
+        };
+
+        // Below the default Error threshold
+        assert!(!notifier.broadcast_assessment(&assessment(ConflictSeverity::Warning)));
+        assert!(notifier.broadcast_assessment(&assessment(ConflictSeverity::Block)));
+
+        let event = rx.try_recv().unwrap();
+        assert!(rx.try_recv().is_err());
+        match event.event_type {
+            EventType::ConflictDetected {
+                files,
+                severity,
+                agents,
+                ..
+            } => {
+                assert_eq!(files, vec!["src/storage/libsql.rs"]);
+                assert_eq!(severity, "Block");
+                assert_eq!(agents, vec![agent1.to_string(), agent2.to_string()]);
+            }
+            other => panic!("Wrong event type: {:?}", other),
+        }
+    }
+
     #[test]
     fn test_format_duration_ago() {
         let now = Utc::now();
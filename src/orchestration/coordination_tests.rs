@@ -42,6 +42,7 @@ mod e2e_tests {
             notify_on_save: true,
             periodic_interval_minutes: 20,
             session_end_summary: true,
+            ..Default::default()
         };
 
         let notifier = Arc::new(ConflictNotifier::new(notifier_config, file_tracker));
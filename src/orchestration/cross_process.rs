@@ -90,6 +90,13 @@ pub struct ProcessRegistration {
     pub worktree_path: Option<PathBuf>,
 }
 
+impl ProcessRegistration {
+    /// Whether the registered process is still running
+    pub fn is_alive(&self) -> bool {
+        process_exists(self.pid)
+    }
+}
+
 /// Cross-process coordinator
 pub struct CrossProcessCoordinator {
     /// Path to shared registry file
@@ -541,7 +548,7 @@ impl CrossProcessCoordinator {
 }
 
 /// Check if process exists (platform-specific)
-fn process_exists(pid: u32) -> bool {
+pub(crate) fn process_exists(pid: u32) -> bool {
     #[cfg(unix)]
     {
         use std::process::Command;
@@ -594,6 +601,7 @@ mod tests {
         assert_eq!(processes.len(), 1);
         assert_eq!(processes[0].agent_id, agent_id);
         assert_eq!(processes[0].pid, std::process::id());
+        assert!(processes[0].is_alive());
     }
 
     #[test]
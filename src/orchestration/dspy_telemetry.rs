@@ -37,6 +37,7 @@
 
 use crate::error::Result;
 use crate::orchestration::dspy_module_loader::ModuleVersion;
+pub use crate::services::usage::{CostCalculator, TokenUsage};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -84,27 +85,6 @@ pub enum EventType {
     Error,
 }
 
-/// Token usage for LLM calls
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
-pub struct TokenUsage {
-    /// Input/prompt tokens
-    pub input_tokens: u64,
-    /// Output/completion tokens
-    pub output_tokens: u64,
-    /// Total tokens
-    pub total_tokens: u64,
-}
-
-impl TokenUsage {
-    pub fn new(input_tokens: u64, output_tokens: u64) -> Self {
-        Self {
-            input_tokens,
-            output_tokens,
-            total_tokens: input_tokens + output_tokens,
-        }
-    }
-}
-
 impl DSpyEvent {
     /// Create a request event
     pub fn request(
@@ -184,68 +164,6 @@ impl DSpyEvent {
     }
 }
 
-/// Cost calculator for LLM API calls
-///
-/// Uses pricing data from Anthropic API (as of 2025-01-01).
-pub struct CostCalculator {
-    /// Pricing per 1M input tokens (USD)
-    input_price_per_1m: HashMap<String, f64>,
-    /// Pricing per 1M output tokens (USD)
-    output_price_per_1m: HashMap<String, f64>,
-}
-
-impl CostCalculator {
-    /// Create a new cost calculator with default Anthropic pricing
-    pub fn new() -> Self {
-        let mut input_price_per_1m = HashMap::new();
-        let mut output_price_per_1m = HashMap::new();
-
-        // Claude Sonnet 4 pricing (as of 2025-01-01)
-        input_price_per_1m.insert("claude-sonnet-4-20250514".to_string(), 3.0);
-        output_price_per_1m.insert("claude-sonnet-4-20250514".to_string(), 15.0);
-
-        // Claude Haiku 3.5 pricing
-        input_price_per_1m.insert("claude-3-5-haiku-20241022".to_string(), 1.0);
-        output_price_per_1m.insert("claude-3-5-haiku-20241022".to_string(), 5.0);
-
-        Self {
-            input_price_per_1m,
-            output_price_per_1m,
-        }
-    }
-
-    /// Calculate cost for a given model and token usage
-    ///
-    /// Returns cost in USD
-    pub fn calculate_cost(&self, model: &str, tokens: &TokenUsage) -> f64 {
-        let input_price = self.input_price_per_1m.get(model).copied().unwrap_or(3.0); // Default to Sonnet pricing
-        let output_price = self.output_price_per_1m.get(model).copied().unwrap_or(15.0);
-
-        let input_cost = (tokens.input_tokens as f64 / 1_000_000.0) * input_price;
-        let output_cost = (tokens.output_tokens as f64 / 1_000_000.0) * output_price;
-
-        input_cost + output_cost
-    }
-
-    /// Add custom pricing for a model
-    pub fn add_model_pricing(
-        &mut self,
-        model: impl Into<String>,
-        input_price: f64,
-        output_price: f64,
-    ) {
-        let model = model.into();
-        self.input_price_per_1m.insert(model.clone(), input_price);
-        self.output_price_per_1m.insert(model, output_price);
-    }
-}
-
-impl Default for CostCalculator {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
 /// Aggregated metrics for a module version
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ModuleMetrics {
@@ -7,14 +7,17 @@
 //! - Time-travel debugging
 //!
 //! Events are stored as Mnemosyne memories with type `AgentEvent`.
+//! `WorkItemAssigned` carries a snapshot of the submitted item, so
+//! [`ReplayedState`] can rebuild the work queue's unfinished items.
 
 use crate::error::Result;
 use crate::launcher::agents::AgentRole;
-use crate::orchestration::state::{AgentState, Phase, WorkItemId};
+use crate::orchestration::state::{AgentState, Phase, WorkItem, WorkItemId, WorkQueue};
 use crate::storage::StorageBackend;
 use crate::types::{MemoryId, MemoryNote, MemoryType, Namespace};
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::sync::Arc;
 
 /// Agent events for event sourcing
@@ -27,6 +30,9 @@ pub enum AgentEvent {
         item_id: WorkItemId,
         description: String,
         phase: Phase,
+        /// Snapshot of the submitted item, for replay (absent in older logs)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        item: Option<Box<WorkItem>>,
     },
 
     /// Work item started by agent
@@ -1200,16 +1206,46 @@ pub struct ReplayedState {
 
     /// Detected deadlocks
     pub deadlocks: Vec<Vec<WorkItemId>>,
+
+    /// Submitted work items that have not completed or failed
+    pub work_items: HashMap<WorkItemId, WorkItem>,
 }
 
 impl ReplayedState {
     /// Apply an event to the state
     pub fn apply(&mut self, event: AgentEvent) {
         match event {
+            AgentEvent::WorkItemAssigned {
+                item: Some(item), ..
+            } => {
+                // Events with equal timestamps may replay out of order, so
+                // don't resurrect an item whose outcome is already known
+                if !self.is_finished(&item.id) {
+                    self.work_items.insert(item.id.clone(), *item);
+                }
+            }
+            AgentEvent::WorkItemStarted { item_id, .. } => {
+                if let Some(item) = self.work_items.get_mut(&item_id) {
+                    item.transition(AgentState::Active);
+                }
+            }
+            AgentEvent::WorkItemRequeued {
+                item_id,
+                review_attempt,
+                ..
+            } => {
+                if let Some(item) = self.work_items.get_mut(&item_id) {
+                    item.transition(AgentState::Ready);
+                    item.started_at = None;
+                    item.review_attempt = review_attempt;
+                }
+            }
             AgentEvent::WorkItemCompleted { item_id, .. } => {
+                self.work_items.remove(&item_id);
                 self.completed_items.push(item_id);
             }
             AgentEvent::WorkItemFailed { item_id, error, .. } => {
+                self.work_items.remove(&item_id);
                 self.failed_items.push((item_id, error));
             }
             AgentEvent::PhaseTransition { to, .. } => {
@@ -1224,6 +1260,50 @@ impl ReplayedState {
             _ => {}
         }
     }
+
+    fn is_finished(&self, id: &WorkItemId) -> bool {
+        self.completed_items.contains(id) || self.failed_items.iter().any(|(f, _)| f == id)
+    }
+
+    /// Unfinished work items, oldest first
+    ///
+    /// Items that were in progress when the log ends (started, or awaiting
+    /// review) are reset to `Ready` so they run again.
+    pub fn unfinished_items(&self) -> Vec<WorkItem> {
+        let mut items: Vec<WorkItem> = self
+            .work_items
+            .values()
+            .cloned()
+            .map(|mut item| {
+                if item.state != AgentState::Ready {
+                    item.transition(AgentState::Ready);
+                    item.started_at = None;
+                }
+                item
+            })
+            .collect();
+        items.sort_by_key(|item| item.created_at);
+        items
+    }
+
+    /// Rebuild a work queue: phase, completed dependencies and unfinished items
+    ///
+    /// Returns the number of items re-queued.
+    pub fn restore_into(&self, queue: &mut WorkQueue) -> usize {
+        queue.restore_phase(self.current_phase);
+        for item_id in &self.completed_items {
+            queue.mark_completed(item_id);
+        }
+
+        let mut restored = 0;
+        for item in self.unfinished_items() {
+            match queue.add(item) {
+                Ok(()) => restored += 1,
+                Err(e) => tracing::warn!("Could not restore work item: {}", e),
+            }
+        }
+        restored
+    }
 }
 
 #[cfg(test)]
@@ -1317,6 +1397,66 @@ mod tests {
         assert_eq!(state.completed_items[0], item_id);
     }
 
+    fn assigned(item: &WorkItem) -> AgentEvent {
+        AgentEvent::WorkItemAssigned {
+            agent: item.agent,
+            item_id: item.id.clone(),
+            description: item.description.clone(),
+            phase: item.phase,
+            item: Some(Box::new(item.clone())),
+        }
+    }
+
+    #[test]
+    fn test_replay_rebuilds_unfinished_work() {
+        let new_item = |description: &str| {
+            WorkItem::new(
+                description.to_string(),
+                AgentRole::Executor,
+                Phase::PlanToArtifacts,
+                5,
+            )
+        };
+        let done = new_item("done");
+        let running = new_item("running");
+        let mut queued = new_item("queued");
+        queued.created_at = running.created_at + chrono::Duration::seconds(1);
+
+        let mut state = ReplayedState::default();
+        // Completion replayed before its submission (equal timestamps)
+        state.apply(AgentEvent::WorkItemCompleted {
+            agent: AgentRole::Executor,
+            item_id: done.id.clone(),
+            duration_ms: 10,
+            memory_ids: vec![],
+        });
+        for item in [&done, &running, &queued] {
+            state.apply(assigned(item));
+        }
+        state.apply(AgentEvent::WorkItemStarted {
+            agent: AgentRole::Executor,
+            item_id: running.id.clone(),
+            description: running.description.clone(),
+        });
+        state.apply(AgentEvent::PhaseTransition {
+            from: Phase::FullSpecToPlan,
+            to: Phase::PlanToArtifacts,
+            approved_by: AgentRole::Orchestrator,
+        });
+
+        let unfinished = state.unfinished_items();
+        let descriptions: Vec<&str> = unfinished.iter().map(|i| i.description.as_str()).collect();
+        assert_eq!(descriptions, vec!["running", "queued"]);
+        assert!(unfinished.iter().all(|i| i.state == AgentState::Ready));
+        assert!(unfinished[0].started_at.is_none());
+
+        let mut queue = WorkQueue::new();
+        assert_eq!(state.restore_into(&mut queue), 2);
+        assert_eq!(queue.current_phase(), Phase::PlanToArtifacts);
+        assert_eq!(queue.get_ready_items().len(), 2);
+        assert!(queue.get(&done.id).is_none());
+    }
+
     #[tokio::test]
     async fn test_event_to_api_event_mapping() {
         // Create a broadcaster for testing
@@ -38,6 +38,7 @@
 //! - `mnemosyne.delete`: Delete memory
 //! - `mnemosyne.consolidate`: Merge similar memories
 //! - `mnemosyne.list`: List recent memories
+//! - `mnemosyne.list_namespaces`: List namespaces with memory counts
 //!
 //! ## Future Enhancements
 //!
@@ -72,6 +73,7 @@ impl McpIntegration {
             "mnemosyne.delete",
             "mnemosyne.consolidate",
             "mnemosyne.list",
+            "mnemosyne.list_namespaces",
         ]
     }
 
@@ -90,7 +92,7 @@ mod tests {
     #[test]
     fn test_available_tools() {
         let tools = McpIntegration::available_tools();
-        assert_eq!(tools.len(), 8);
+        assert_eq!(tools.len(), 9);
         assert!(tools.contains(&"mnemosyne.recall"));
         assert!(tools.contains(&"mnemosyne.remember"));
     }
@@ -57,6 +57,7 @@ pub mod network;
 pub mod notification_task;
 pub mod prompts;
 pub mod proposal_queue;
+pub mod quality_gate;
 pub mod registry;
 pub mod skills;
 pub mod sse_subscriber;
@@ -123,22 +124,31 @@ pub use identity::{AgentId, AgentIdentity};
 pub use messages::{
     AgentMessage, ExecutorMessage, OptimizerMessage, OrchestratorMessage, ReviewerMessage,
 };
-pub use network::{AgentEndpoint, AgentProtocol, MessageRouter};
+pub use network::{AgentEndpoint, AgentProtocol, MessageRouter, NetworkConfig, PeerAllowlist};
 pub use notification_task::NotificationTaskHandle;
 pub use prompts::{
     ConflictDecision, ConflictPrompt, InteractivePrompter, JoinDecision, JoinRequestPrompt,
 };
 pub use proposal_queue::{ProposalQueue, ProposalSender, SendError};
+pub use quality_gate::{
+    gates_from_constitution, GateInput, GateVerdict, LlmGate, NoTodosGate, QualityGate,
+    TestsPassGate,
+};
 pub use registry::AgentRegistry;
 pub use skills::{get_skills_directory, SkillMatch, SkillMetadata, SkillsDiscovery};
 pub use sse_subscriber::{SseSubscriber, SseSubscriberConfig};
-pub use state::{AgentState, Phase, WorkItem, WorkQueue};
+pub use state::{AgentState, Phase, WorkItem, WorkItemId, WorkItemStatus, WorkQueue};
 pub use status_line::{ShellIntegration, StatusLine, StatusLineFormat, StatusLineProvider};
-pub use supervision::{SupervisionConfig, SupervisionTree};
+pub use supervision::{RestartPolicy, SupervisionConfig, SupervisionTree};
+pub use work_plan_templates::work_from_tasks;
 pub use worktree_manager::{WorktreeInfo, WorktreeManager};
 
-use crate::error::Result;
+use crate::error::{MnemosyneError, Result};
 use std::sync::Arc;
+use std::time::Duration;
+
+/// How long `submit_work` waits for the orchestrator to queue an item
+const SUBMIT_TIMEOUT: Duration = Duration::from_secs(5);
 
 /// Orchestration engine managing all four agents and their coordination
 pub struct OrchestrationEngine {
@@ -186,7 +196,7 @@ impl OrchestrationEngine {
         state_manager: Option<Arc<crate::api::StateManager>>,
     ) -> Result<Self> {
         // Initialize network layer
-        let network = Arc::new(network::NetworkLayer::new().await?);
+        let network = Arc::new(network::NetworkLayer::with_config(config.network.clone()).await?);
 
         // Create supervision tree with state management
         let supervision = SupervisionTree::new_with_state(
@@ -236,7 +246,7 @@ impl OrchestrationEngine {
         state_manager: Option<Arc<crate::api::StateManager>>,
     ) -> Result<Self> {
         // Initialize network layer
-        let network = Arc::new(network::NetworkLayer::new().await?);
+        let network = Arc::new(network::NetworkLayer::with_config(config.network.clone()).await?);
 
         // Create supervision tree with explicit namespace and state management
         let supervision = SupervisionTree::new_with_namespace_and_state(
@@ -259,6 +269,9 @@ impl OrchestrationEngine {
     }
 
     /// Start the orchestration engine
+    ///
+    /// Unfinished work recorded in this namespace's event log (e.g. by a
+    /// process that crashed) is re-queued; in-progress items run again.
     pub async fn start(&mut self) -> Result<()> {
         tracing::debug!("Starting orchestration engine");
 
@@ -274,10 +287,17 @@ impl OrchestrationEngine {
 
     /// Stop the orchestration engine gracefully
     pub async fn stop(&mut self) -> Result<()> {
+        self.stop_with_timeout(std::time::Duration::from_secs(30))
+            .await
+    }
+
+    /// Stop the orchestration engine, giving agents up to `timeout` to drain
+    /// their queued work before they are killed
+    pub async fn stop_with_timeout(&mut self, timeout: std::time::Duration) -> Result<()> {
         tracing::debug!("Stopping orchestration engine");
 
         // Stop supervision tree (graceful agent shutdown)
-        self.supervision.stop().await?;
+        self.supervision.stop_with_timeout(timeout).await?;
 
         // Stop network layer
         self.network.stop().await?;
@@ -286,11 +306,57 @@ impl OrchestrationEngine {
         Ok(())
     }
 
+    /// Whether any agent actors are still running
+    pub fn has_running_actors(&self) -> bool {
+        self.supervision.has_running_actors()
+    }
+
     /// Get reference to orchestrator actor
     pub fn orchestrator(&self) -> &ractor::ActorRef<OrchestratorMessage> {
         self.supervision.orchestrator()
     }
 
+    /// Orchestrator's work queue
+    pub fn work_queue(&self) -> &state::SharedWorkQueue {
+        self.supervision.work_queue()
+    }
+
+    /// Submit a work item to the orchestrator
+    ///
+    /// Returns the item's ID once the orchestrator has queued it; poll its
+    /// progress with [`work_status`](Self::work_status).
+    pub async fn submit_work(&self, item: WorkItem) -> Result<WorkItemId> {
+        let item_id = item.id.clone();
+        self.orchestrator()
+            .cast(OrchestratorMessage::SubmitWork(Box::new(item)))
+            .map_err(|e| MnemosyneError::ActorError(e.to_string()))?;
+
+        let queued = tokio::time::timeout(SUBMIT_TIMEOUT, async {
+            while self.work_queue().read().await.get(&item_id).is_none() {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await;
+
+        match queued {
+            Ok(()) => Ok(item_id),
+            Err(_) => Err(MnemosyneError::ActorError(format!(
+                "Orchestrator did not accept work item {} within {:?}",
+                item_id, SUBMIT_TIMEOUT
+            ))),
+        }
+    }
+
+    /// Current status of a submitted work item
+    pub async fn work_status(&self, item_id: &WorkItemId) -> Result<WorkItemStatus> {
+        self.work_queue()
+            .read()
+            .await
+            .get(item_id)
+            .map(WorkItemStatus::from)
+            .ok_or_else(|| MnemosyneError::NotFound(format!("work item {}", item_id)))
+    }
+
     /// Get reference to event broadcaster
     pub fn event_broadcaster(&self) -> Option<&crate::api::EventBroadcaster> {
         self.event_broadcaster.as_ref()
@@ -323,7 +389,13 @@ impl Drop for OrchestrationEngine {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::launcher::agents::AgentRole;
+    use crate::orchestration::messages::WorkResult;
+    use crate::orchestration::network::router::LocalAgent;
+    use crate::types::{MemoryId, MemoryNote, MemoryType, Namespace};
     use crate::{ConnectionMode, LibsqlStorage};
+    use ractor::{Actor, ActorProcessingErr, ActorRef};
+    use tempfile::TempDir;
 
     #[tokio::test]
     async fn test_engine_lifecycle() {
@@ -336,4 +408,224 @@ mod tests {
         engine.start().await.unwrap();
         engine.stop().await.unwrap();
     }
+
+    #[tokio::test]
+    async fn test_engine_recovers_unfinished_work_after_crash() {
+        let temp_dir = TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("engine.db");
+        let storage: Arc<dyn crate::storage::StorageBackend> = Arc::new(
+            LibsqlStorage::new_with_validation(
+                ConnectionMode::Local(db_path.to_str().unwrap().to_string()),
+                true,
+            )
+            .await
+            .unwrap(),
+        );
+        let namespace = Namespace::Session {
+            project: "test".to_string(),
+            session_id: "crash-recovery".to_string(),
+        };
+
+        let mut engine = OrchestrationEngine::new_with_namespace(
+            storage.clone(),
+            SupervisionConfig::default(),
+            namespace.clone(),
+        )
+        .await
+        .unwrap();
+        engine.start().await.unwrap();
+
+        // Reviewer items are not dispatched, so they stay queued
+        let items: Vec<WorkItem> = ["Review schema", "Review API"]
+            .into_iter()
+            .map(|d| WorkItem::new(d.to_string(), AgentRole::Reviewer, Phase::PromptToSpec, 5))
+            .collect();
+        for item in &items {
+            engine
+                .orchestrator()
+                .cast(OrchestratorMessage::SubmitWork(Box::new(item.clone())))
+                .unwrap();
+        }
+
+        // The first one was picked up before the crash
+        EventPersistence::new(storage.clone(), namespace.clone())
+            .persist(AgentEvent::WorkItemStarted {
+                agent: AgentRole::Reviewer,
+                item_id: items[0].id.clone(),
+                description: items[0].description.clone(),
+            })
+            .await
+            .unwrap();
+
+        let replay = EventReplay::new(storage.clone(), namespace.clone());
+        for _ in 0..50 {
+            let replayed = replay.replay().await.unwrap();
+            if items
+                .iter()
+                .all(|i| replayed.work_items.contains_key(&i.id))
+            {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+
+        // Crash: no graceful stop
+        drop(engine);
+        let orchestrator_name = format!("{}-orchestrator", namespace);
+        for _ in 0..50 {
+            if ractor::registry::where_is(orchestrator_name.clone()).is_none() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+
+        let mut engine = OrchestrationEngine::new_with_namespace(
+            storage,
+            SupervisionConfig::default(),
+            namespace,
+        )
+        .await
+        .unwrap();
+        engine.start().await.unwrap();
+
+        {
+            let queue = engine.work_queue().read().await;
+            for item in &items {
+                let recovered = queue.get(&item.id).expect("work item not recovered");
+                assert_eq!(recovered.description, item.description);
+                assert_eq!(recovered.state, AgentState::Ready);
+            }
+        }
+
+        engine.stop().await.unwrap();
+    }
+
+    /// Executor stand-in that reports every item done, citing `memory_id`
+    struct MockExecutor {
+        orchestrator: ActorRef<OrchestratorMessage>,
+        memory_id: MemoryId,
+    }
+
+    #[ractor::async_trait]
+    impl Actor for MockExecutor {
+        type Msg = ExecutorMessage;
+        type State = ();
+        type Arguments = ();
+
+        async fn pre_start(
+            &self,
+            _myself: ActorRef<Self::Msg>,
+            _args: Self::Arguments,
+        ) -> std::result::Result<Self::State, ActorProcessingErr> {
+            Ok(())
+        }
+
+        async fn handle(
+            &self,
+            _myself: ActorRef<Self::Msg>,
+            message: Self::Msg,
+            _state: &mut Self::State,
+        ) -> std::result::Result<(), ActorProcessingErr> {
+            if let ExecutorMessage::ExecuteWork(item) = message {
+                let mut result = WorkResult::success(item.id.clone(), Duration::from_millis(1));
+                result.memory_ids = vec![self.memory_id];
+                self.orchestrator
+                    .cast(OrchestratorMessage::WorkCompleted {
+                        item_id: item.id,
+                        result,
+                    })
+                    .map_err(|e| ActorProcessingErr::from(e.to_string()))?;
+            }
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_submit_work_and_poll_status() {
+        let storage: Arc<dyn crate::storage::StorageBackend> =
+            Arc::new(LibsqlStorage::new(ConnectionMode::InMemory).await.unwrap());
+        let namespace = Namespace::Session {
+            project: "test".to_string(),
+            session_id: "submit-work".to_string(),
+        };
+        let mut engine = OrchestrationEngine::new_with_namespace(
+            storage.clone(),
+            SupervisionConfig::default(),
+            namespace.clone(),
+        )
+        .await
+        .unwrap();
+        engine.start().await.unwrap();
+
+        // The Reviewer checks the execution memories the result cites
+        let now = chrono::Utc::now();
+        let memory = MemoryNote {
+            id: MemoryId::new(),
+            namespace,
+            created_at: now,
+            updated_at: now,
+            content: "Implemented the parser. All tests passed.".to_string(),
+            summary: "Parser implemented with tests passing".to_string(),
+            keywords: vec!["parser".to_string()],
+            tags: vec![],
+            context: "test context".to_string(),
+            memory_type: MemoryType::CodePattern,
+            importance: 5,
+            confidence: 0.8,
+            links: vec![],
+            related_files: vec![],
+            related_entities: vec![],
+            access_count: 0,
+            last_accessed_at: now,
+            expires_at: None,
+            is_archived: false,
+            superseded_by: None,
+            embedding: None,
+            embedding_model: String::new(),
+        };
+        storage.store_memory(&memory).await.unwrap();
+
+        let (executor, _) = Actor::spawn(
+            None,
+            MockExecutor {
+                orchestrator: engine.orchestrator().clone(),
+                memory_id: memory.id,
+            },
+            (),
+        )
+        .await
+        .unwrap();
+        engine
+            .network
+            .router()
+            .register_local(AgentRole::Executor, LocalAgent::Executor(executor.clone()))
+            .await;
+
+        let item = WorkItem::new(
+            "Implement the parser".to_string(),
+            AgentRole::Executor,
+            Phase::PromptToSpec,
+            5,
+        );
+        let item_id = engine.submit_work(item).await.unwrap();
+        assert_eq!(engine.work_status(&item_id).await.unwrap().id, item_id);
+
+        let mut status = engine.work_status(&item_id).await.unwrap();
+        for _ in 0..100 {
+            if status.is_finished() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            status = engine.work_status(&item_id).await.unwrap();
+        }
+        assert_eq!(status.state, AgentState::Complete);
+
+        assert!(matches!(
+            engine.work_status(&WorkItemId::new()).await,
+            Err(MnemosyneError::NotFound(_))
+        ));
+
+        executor.stop(None);
+        engine.stop().await.unwrap();
+    }
 }
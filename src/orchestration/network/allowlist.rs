@@ -0,0 +1,192 @@
+//! Peer Allowlist - Trusted Node IDs
+//!
+//! Restricts which peers may connect to this node. Enforcement is an explicit
+//! setting (`[network] enforce_allowlist`):
+//! - Not enforced: any peer with a ticket may connect
+//! - Enforced: only listed node IDs are accepted, and an empty list admits
+//!   no one
+//!
+//! Peers are added from configuration, when joined explicitly, when an
+//! invite is created for them, or at runtime via `mnemosyne peer allow`.
+//! With a store file, peers added at runtime are saved there and loaded
+//! again on the next start.
+
+use crate::error::{MnemosyneError, Result};
+use iroh::net::NodeId;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+/// Shared set of trusted node IDs
+#[derive(Debug, Clone, Default)]
+pub struct PeerAllowlist {
+    peers: Arc<RwLock<HashSet<NodeId>>>,
+    /// Reject peers not in `peers`
+    enforced: bool,
+    /// File the allowlist is saved to, one node ID per line
+    store: Option<Arc<PathBuf>>,
+}
+
+impl PeerAllowlist {
+    /// Create an allowlist from node ID strings
+    ///
+    /// The allowlist is not enforced until [`Self::with_enforcement`].
+    pub fn new<I, S>(node_ids: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let peers = node_ids
+            .into_iter()
+            .map(|id| parse_node_id(id.as_ref()))
+            .collect::<Result<HashSet<_>>>()?;
+        Ok(Self {
+            peers: Arc::new(RwLock::new(peers)),
+            ..Self::default()
+        })
+    }
+
+    /// Reject peers that are not allowlisted (or admit everyone)
+    pub fn with_enforcement(mut self, enforced: bool) -> Self {
+        self.enforced = enforced;
+        self
+    }
+
+    /// Load peers saved in `path`, and save peers allowed later back to it
+    ///
+    /// A missing file is an empty list; blank lines and `#` comments are
+    /// skipped.
+    pub async fn with_store(mut self, path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        match tokio::fs::read_to_string(&path).await {
+            Ok(content) => {
+                let saved = content
+                    .lines()
+                    .map(str::trim)
+                    .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                    .map(parse_node_id)
+                    .collect::<Result<Vec<_>>>()?;
+                self.peers.write().await.extend(saved);
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e.into()),
+        }
+        self.store = Some(Arc::new(path));
+        Ok(self)
+    }
+
+    /// Trust a peer; returns false if it was already trusted
+    ///
+    /// The peer is saved to the store file, if there is one.
+    pub async fn allow(&self, node_id: &str) -> Result<bool> {
+        let node_id = parse_node_id(node_id)?;
+        let mut peers = self.peers.write().await;
+        if !peers.insert(node_id) {
+            return Ok(false);
+        }
+        if let Some(path) = &self.store {
+            save(path, &peers).await?;
+        }
+        Ok(true)
+    }
+
+    /// Whether a peer may connect
+    ///
+    /// Always true while the allowlist is not enforced.
+    pub async fn is_allowed(&self, node_id: &NodeId) -> bool {
+        !self.enforced || self.peers.read().await.contains(node_id)
+    }
+
+    /// Whether connections are being restricted
+    pub fn is_enforced(&self) -> bool {
+        self.enforced
+    }
+
+    /// Trusted node IDs
+    pub async fn peers(&self) -> Vec<String> {
+        sorted_ids(&*self.peers.read().await)
+    }
+}
+
+fn sorted_ids(peers: &HashSet<NodeId>) -> Vec<String> {
+    let mut ids: Vec<String> = peers.iter().map(|id| id.to_string()).collect();
+    ids.sort();
+    ids
+}
+
+/// Replace the store file with `peers`, writing a temp file first so a crash
+/// never leaves it half written
+async fn save(path: &Path, peers: &HashSet<NodeId>) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+    let mut content = String::from("# Trusted peer node IDs, one per line\n");
+    for id in sorted_ids(peers) {
+        content.push_str(&id);
+        content.push('\n');
+    }
+    let tmp = path.with_extension("tmp");
+    tokio::fs::write(&tmp, content).await?;
+    tokio::fs::rename(&tmp, path).await?;
+    Ok(())
+}
+
+fn parse_node_id(node_id: &str) -> Result<NodeId> {
+    NodeId::from_str(node_id.trim())
+        .map_err(|e| MnemosyneError::NetworkError(format!("Invalid node ID {}: {}", node_id, e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::orchestration::network::AgentKeypair;
+
+    #[tokio::test]
+    async fn test_allowlist_enforcement() {
+        let trusted = AgentKeypair::generate().node_id();
+        let stranger = AgentKeypair::generate().node_id();
+
+        // Not enforced: everyone is admitted, even with peers listed
+        let allowlist = PeerAllowlist::default();
+        assert!(!allowlist.is_enforced());
+        assert!(allowlist.allow(&trusted.to_string()).await.unwrap());
+        assert!(!allowlist.allow(&trusted.to_string()).await.unwrap());
+        assert!(!allowlist.is_enforced());
+        assert!(allowlist.is_allowed(&stranger).await);
+
+        let allowlist = allowlist.with_enforcement(true);
+        assert!(allowlist.is_allowed(&trusted).await);
+        assert!(!allowlist.is_allowed(&stranger).await);
+
+        // Enforced and empty admits no one
+        let empty = PeerAllowlist::default().with_enforcement(true);
+        assert!(!empty.is_allowed(&trusted).await);
+
+        assert!(allowlist.allow("not-a-node-id").await.is_err());
+        assert!(PeerAllowlist::new(["bogus"]).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_allowed_peers_survive_restart() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("network").join("allowed_peers");
+        let trusted = AgentKeypair::generate().node_id();
+
+        let allowlist = PeerAllowlist::default()
+            .with_store(&path)
+            .await
+            .unwrap()
+            .with_enforcement(true);
+        assert!(allowlist.allow(&trusted.to_string()).await.unwrap());
+
+        let reloaded = PeerAllowlist::default()
+            .with_store(&path)
+            .await
+            .unwrap()
+            .with_enforcement(true);
+        assert_eq!(reloaded.peers().await, vec![trusted.to_string()]);
+        assert!(reloaded.is_allowed(&trusted).await);
+    }
+}
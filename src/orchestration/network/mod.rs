@@ -5,21 +5,73 @@
 //! - Protocol handlers for agent messages
 //! - Hybrid routing (local Ractor vs remote Iroh)
 //! - Peer discovery and connection management
+//! - Bounded inbound connections and streams ([`NetworkConfig`])
+//! - Optional allowlist of trusted peers ([`PeerAllowlist`])
 
+pub mod allowlist;
 pub mod endpoint;
 pub mod protocol;
 pub mod router;
 pub mod transport;
 
+pub use allowlist::PeerAllowlist;
 pub use endpoint::{AgentEndpoint, AgentKeypair};
 pub use protocol::AgentProtocol;
 pub use router::MessageRouter;
 
 use crate::error::Result;
 use crate::launcher::agents::AgentRole;
-use iroh::net::endpoint::{Incoming, RecvStream, SendStream};
+use iroh::net::endpoint::{get_remote_node_id, Incoming, RecvStream, SendStream};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use tokio::sync::{RwLock, Semaphore};
+
+/// Application error code sent when refusing a stream over the limit
+const STREAM_REFUSED: u32 = 1;
+
+/// Application error code sent when closing a connection from an untrusted peer
+const PEER_REJECTED: u32 = 2;
+
+/// Limits on inbound network resources
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NetworkConfig {
+    /// Max inbound connections handled at once; excess are refused
+    pub max_concurrent_connections: usize,
+
+    /// Max streams handled at once per connection; excess are reset
+    pub max_streams_per_connection: usize,
+
+    /// Trusted peer node IDs, in addition to those saved in `allowlist_file`
+    pub allowed_peers: Vec<String>,
+
+    /// Only admit allowlisted peers; off admits any peer with a ticket
+    pub enforce_allowlist: bool,
+
+    /// Where peers allowed at runtime are saved across restarts
+    pub allowlist_file: PathBuf,
+}
+
+/// Default location of the saved peer allowlist
+fn default_allowlist_file() -> PathBuf {
+    dirs::data_local_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("mnemosyne")
+        .join("allowed_peers")
+}
+
+impl Default for NetworkConfig {
+    fn default() -> Self {
+        Self {
+            max_concurrent_connections: 64,
+            max_streams_per_connection: 16,
+            allowed_peers: Vec::new(),
+            enforce_allowlist: false,
+            allowlist_file: default_allowlist_file(),
+        }
+    }
+}
 
 /// Network layer managing all distributed communication
 pub struct NetworkLayer {
@@ -35,6 +87,12 @@ pub struct NetworkLayer {
 
     /// Shared secret for authentication
     cluster_secret: Option<String>,
+
+    /// Inbound connection and stream limits
+    config: NetworkConfig,
+
+    /// Peers allowed to connect
+    allowlist: PeerAllowlist,
 }
 
 impl NetworkLayer {
@@ -43,21 +101,82 @@ impl NetworkLayer {
         self.router.clone()
     }
 
-    /// Create a new network layer
+    /// Create a new network layer with default limits
     pub async fn new() -> Result<Self> {
+        Self::with_config(NetworkConfig::default()).await
+    }
+
+    /// Create a new network layer with the given limits
+    pub async fn with_config(config: NetworkConfig) -> Result<Self> {
         let cluster_secret = std::env::var("MNEMOSYNE_CLUSTER_SECRET").ok();
         if cluster_secret.is_some() {
             tracing::info!("Cluster secret configured, authentication enabled");
         }
 
+        // Comma-separated node IDs, in addition to the configured ones
+        let env_peers = std::env::var("MNEMOSYNE_ALLOWED_PEERS").unwrap_or_default();
+        let allowlist = PeerAllowlist::new(
+            config
+                .allowed_peers
+                .iter()
+                .map(String::as_str)
+                .chain(env_peers.split(',').filter(|id| !id.trim().is_empty())),
+        )?
+        .with_store(&config.allowlist_file)
+        .await?
+        .with_enforcement(config.enforce_allowlist);
+        if allowlist.is_enforced() {
+            let peers = allowlist.peers().await;
+            if peers.is_empty() {
+                tracing::warn!("Peer allowlist enforced but empty, no peer can connect");
+            } else {
+                tracing::info!(
+                    "Peer allowlist enforced, only {} trusted peer(s) can connect",
+                    peers.len()
+                );
+            }
+        }
+
         Ok(Self {
             endpoint: Arc::new(RwLock::new(None)),
             router: Arc::new(MessageRouter::new()),
             started: Arc::new(RwLock::new(false)),
             cluster_secret,
+            config,
+            allowlist,
         })
     }
 
+    /// Inbound connection and stream limits
+    pub fn config(&self) -> &NetworkConfig {
+        &self.config
+    }
+
+    /// Trust a peer, saving it to the allowlist
+    ///
+    /// Connections are only restricted when `enforce_allowlist` is set.
+    pub async fn allow_peer(&self, node_id: &str) -> Result<()> {
+        if self.allowlist.allow(node_id).await? {
+            tracing::info!("Added {} to peer allowlist", node_id);
+        }
+        if !self.allowlist.is_enforced() {
+            tracing::warn!(
+                "Peer allowlist is not enforced; set [network] enforce_allowlist = true to reject other peers"
+            );
+        }
+        Ok(())
+    }
+
+    /// Whether only allowlisted peers may connect
+    pub fn is_allowlist_enforced(&self) -> bool {
+        self.allowlist.is_enforced()
+    }
+
+    /// Trusted peers (all peers may connect unless the allowlist is enforced)
+    pub async fn allowed_peers(&self) -> Vec<String> {
+        self.allowlist.peers().await
+    }
+
     /// Start the network layer
     pub async fn start(&self) -> Result<()> {
         let mut started = self.started.write().await;
@@ -89,9 +208,18 @@ impl NetworkLayer {
         let listener_endpoint = endpoint.clone();
         let listener_router = self.router.clone();
         let secret = self.cluster_secret.clone();
+        let config = self.config.clone();
+        let allowlist = self.allowlist.clone();
 
         tokio::spawn(async move {
-            run_listener_loop(listener_endpoint, listener_router, secret).await;
+            run_listener_loop(
+                listener_endpoint,
+                listener_router,
+                secret,
+                config,
+                allowlist,
+            )
+            .await;
         });
 
         *started = true;
@@ -132,9 +260,16 @@ impl NetworkLayer {
     }
 
     /// Create an invite ticket for this node
-    pub async fn create_invite(&self) -> Result<String> {
+    ///
+    /// With `expected_peer`, that node ID is added to the allowlist, so it can
+    /// connect while the allowlist is enforced. Creating an invite never turns
+    /// enforcement on.
+    pub async fn create_invite(&self, expected_peer: Option<&str>) -> Result<String> {
         let ep = self.endpoint.read().await;
         if let Some(endpoint) = ep.as_ref() {
+            if let Some(peer) = expected_peer {
+                self.allow_peer(peer).await?;
+            }
             endpoint.create_ticket().await
         } else {
             Err(crate::error::MnemosyneError::NetworkError(
@@ -159,6 +294,11 @@ impl NetworkLayer {
         if let Some(endpoint) = ep.as_ref() {
             let peer_node_id = endpoint.add_peer(ticket).await?;
 
+            // A peer we joined explicitly is trusted to connect back
+            if self.allowlist.is_enforced() {
+                self.allow_peer(&peer_node_id).await?;
+            }
+
             // Get our node ID
             let my_node_id = endpoint.node_id();
 
@@ -181,15 +321,32 @@ async fn run_listener_loop(
     endpoint: AgentEndpoint,
     router: Arc<MessageRouter>,
     secret: Option<String>,
+    config: NetworkConfig,
+    allowlist: PeerAllowlist,
 ) {
     tracing::info!("Network listener loop started");
+    let max_streams = config.max_streams_per_connection;
+    let connections = Arc::new(Semaphore::new(config.max_concurrent_connections));
     loop {
         match endpoint.accept().await {
             Some(incoming) => {
+                let Ok(permit) = connections.clone().try_acquire_owned() else {
+                    tracing::warn!(
+                        "Connection limit ({}) reached, refusing connection from {}",
+                        config.max_concurrent_connections,
+                        incoming.remote_address()
+                    );
+                    incoming.refuse();
+                    continue;
+                };
                 let router = router.clone();
                 let secret = secret.clone();
+                let allowlist = allowlist.clone();
                 tokio::spawn(async move {
-                    if let Err(e) = handle_connection(incoming, router, secret).await {
+                    let _permit = permit;
+                    if let Err(e) =
+                        handle_connection(incoming, router, secret, max_streams, allowlist).await
+                    {
                         tracing::warn!("Connection error: {}", e);
                     }
                 });
@@ -207,24 +364,52 @@ async fn handle_connection(
     incoming: Incoming,
     router: Arc<MessageRouter>,
     secret: Option<String>,
+    max_streams: usize,
+    allowlist: PeerAllowlist,
 ) -> Result<()> {
     // Accept connection
     let conn = incoming
         .await
         .map_err(|e| crate::error::MnemosyneError::NetworkError(e.to_string()))?;
 
-    // Note: remote_node_id() seems unavailable or requires different access in this Iroh version
-    // For now, we just log that we accepted a connection
-    tracing::debug!("Accepted connection from remote peer");
+    // Node ID authenticated by the peer's TLS certificate
+    let remote = get_remote_node_id(&conn).map_err(|e| {
+        crate::error::MnemosyneError::NetworkError(format!("Failed to identify remote peer: {}", e))
+    })?;
+    let remote_node_id = remote.to_string();
+
+    if !allowlist.is_allowed(&remote).await {
+        tracing::warn!(
+            "Rejected connection from peer {} (not in allowlist)",
+            remote_node_id
+        );
+        conn.close(PEER_REJECTED.into(), b"peer not allowed");
+        return Ok(());
+    }
+    tracing::debug!("Accepted connection from {}", remote_node_id);
 
     // Accept bidirectional streams
+    let streams = Arc::new(Semaphore::new(max_streams));
     loop {
         match conn.accept_bi().await {
-            Ok((send, recv)) => {
+            Ok((mut send, mut recv)) => {
+                let Ok(permit) = streams.clone().try_acquire_owned() else {
+                    tracing::warn!(
+                        "Peer {} exceeded {} concurrent streams, refusing stream",
+                        remote_node_id,
+                        max_streams
+                    );
+                    let _ = send.reset(STREAM_REFUSED.into());
+                    let _ = recv.stop(STREAM_REFUSED.into());
+                    continue;
+                };
                 let router = router.clone();
                 let secret = secret.clone();
+                let remote_node_id = remote_node_id.clone();
                 tokio::spawn(async move {
-                    if let Err(e) = handle_stream(send, recv, router, secret).await {
+                    let _permit = permit;
+                    if let Err(e) = handle_stream(send, recv, router, secret, remote_node_id).await
+                    {
                         tracing::warn!("Stream error: {}", e);
                     }
                 });
@@ -246,6 +431,7 @@ async fn handle_stream(
     mut recv: RecvStream,
     router: Arc<MessageRouter>,
     secret: Option<String>,
+    remote_node_id: String,
 ) -> Result<()> {
     // Perform handshake
     AgentProtocol::handshake_responder(&mut send, &mut recv, secret).await?;
@@ -255,9 +441,21 @@ async fn handle_stream(
 
     match msg {
         crate::orchestration::messages::AgentMessage::AnnounceRoles { roles, node_id } => {
-            tracing::info!("Received role announcement from {}: {:?}", node_id, roles);
+            tracing::info!(
+                "Received role announcement from {}: {:?}",
+                remote_node_id,
+                roles
+            );
+            // Register under the authenticated ID, not the claimed one
+            if node_id != remote_node_id {
+                tracing::warn!(
+                    "Peer {} announced roles as {}, ignoring claimed node ID",
+                    remote_node_id,
+                    node_id
+                );
+            }
             for role in roles {
-                router.register_remote(role, node_id.clone()).await;
+                router.register_remote(role, remote_node_id.clone()).await;
             }
         }
         msg => {
@@ -275,7 +473,7 @@ async fn handle_stream(
             };
 
             // Route message
-            if let Err(e) = router.route(role, msg).await {
+            if let Err(e) = router.route(role, msg, Some(&remote_node_id)).await {
                 tracing::warn!("Failed to route message to {:?}: {}", role, e);
                 // We could send an error back, but for now just log it
             }
@@ -306,4 +504,83 @@ mod tests {
 
         layer.stop().await.unwrap();
     }
+
+    #[tokio::test]
+    async fn test_excess_streams_are_refused() {
+        let server = AgentEndpoint::new().await.unwrap();
+        let client = AgentEndpoint::new().await.unwrap();
+
+        let port = server.inner().bound_sockets().0.port();
+        let server_addr = iroh::base::node_addr::NodeAddr::new(server.inner().node_id())
+            .with_direct_addresses([std::net::SocketAddr::from(([127, 0, 0, 1], port))]);
+
+        let accepting = server.clone();
+        let handler = tokio::spawn(async move {
+            let incoming = accepting.accept().await.unwrap();
+            handle_connection(
+                incoming,
+                Arc::new(MessageRouter::new()),
+                None,
+                1,
+                PeerAllowlist::default(),
+            )
+            .await
+        });
+
+        let conn = client.connect(&server_addr).await.unwrap();
+
+        // The first stream holds the only permit while the server awaits its message
+        let (mut send1, mut recv1) = client.open_stream(&conn).await.unwrap();
+        AgentProtocol::handshake_initiator(&mut send1, &mut recv1, None)
+            .await
+            .unwrap();
+
+        // A second concurrent stream is reset instead of handled
+        let (mut send2, mut recv2) = client.open_stream(&conn).await.unwrap();
+        assert!(
+            AgentProtocol::handshake_initiator(&mut send2, &mut recv2, None)
+                .await
+                .is_err()
+        );
+
+        conn.close(0u32.into(), b"done");
+        handler.await.unwrap().unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_non_allowlisted_peer_is_dropped() {
+        let server = AgentEndpoint::new().await.unwrap();
+        let client = AgentEndpoint::new().await.unwrap();
+
+        let port = server.inner().bound_sockets().0.port();
+        let server_addr = iroh::base::node_addr::NodeAddr::new(server.inner().node_id())
+            .with_direct_addresses([std::net::SocketAddr::from(([127, 0, 0, 1], port))]);
+
+        // Only some other node is trusted
+        let trusted = AgentKeypair::generate().node_id().to_string();
+        let allowlist = PeerAllowlist::new([trusted])
+            .unwrap()
+            .with_enforcement(true);
+
+        let accepting = server.clone();
+        let handler = tokio::spawn(async move {
+            let incoming = accepting.accept().await.unwrap();
+            handle_connection(
+                incoming,
+                Arc::new(MessageRouter::new()),
+                None,
+                16,
+                allowlist,
+            )
+            .await
+        });
+
+        let conn = client.connect(&server_addr).await.unwrap();
+        handler.await.unwrap().unwrap();
+
+        // The server closed the connection without serving any stream
+        let reason = conn.closed().await;
+        assert!(reason.to_string().contains("peer not allowed"));
+        assert!(client.open_stream(&conn).await.is_err());
+    }
 }
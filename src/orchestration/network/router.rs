@@ -33,6 +33,16 @@ pub enum AgentLocation {
     Remote(String),
 }
 
+impl AgentLocation {
+    /// Agent ID: the actor ID of a local agent, or the node ID of a remote one
+    pub fn id(&self) -> String {
+        match self {
+            AgentLocation::Local(agent) => agent.id(),
+            AgentLocation::Remote(node_id) => node_id.clone(),
+        }
+    }
+}
+
 /// Local agent references
 #[derive(Debug, Clone)]
 pub enum LocalAgent {
@@ -42,6 +52,18 @@ pub enum LocalAgent {
     Executor(ActorRef<ExecutorMessage>),
 }
 
+impl LocalAgent {
+    /// Actor ID of the agent
+    pub fn id(&self) -> String {
+        match self {
+            LocalAgent::Orchestrator(actor) => actor.get_id().to_string(),
+            LocalAgent::Optimizer(actor) => actor.get_id().to_string(),
+            LocalAgent::Reviewer(actor) => actor.get_id().to_string(),
+            LocalAgent::Executor(actor) => actor.get_id().to_string(),
+        }
+    }
+}
+
 /// Message router for hybrid local/remote routing
 pub struct MessageRouter {
     /// Agent registry mapping role to list of locations
@@ -52,6 +74,9 @@ pub struct MessageRouter {
 
     /// Remote transport for sending messages
     transport: Arc<RwLock<Option<Arc<dyn RemoteTransport>>>>,
+
+    /// Remote node that last sent a message to each agent, by agent ID
+    senders: Arc<RwLock<HashMap<String, String>>>,
 }
 
 impl MessageRouter {
@@ -61,6 +86,7 @@ impl MessageRouter {
             registry: Arc::new(RwLock::new(HashMap::new())),
             rr_indices: Arc::new(RwLock::new(HashMap::new())),
             transport: Arc::new(RwLock::new(None)),
+            senders: Arc::new(RwLock::new(HashMap::new())),
         }
     }
 
@@ -106,7 +132,17 @@ impl MessageRouter {
     }
 
     /// Route a message to the appropriate agent (Load Balanced)
-    pub async fn route(&self, to: AgentRole, message: AgentMessage) -> Result<(), String> {
+    ///
+    /// `from` is the authenticated node ID of a remote sender, or `None` for
+    /// messages originating locally. It is recorded against the agent the
+    /// message is routed to, so replies can be addressed back with
+    /// [`MessageRouter::reply`].
+    pub async fn route(
+        &self,
+        to: AgentRole,
+        message: AgentMessage,
+        from: Option<&str>,
+    ) -> Result<(), String> {
         let registry = self.registry.read().await;
 
         let locations = registry.get(&to).ok_or_else(|| {
@@ -124,6 +160,13 @@ impl MessageRouter {
         let location = &locations[*idx % locations.len()];
         *idx = (*idx + 1) % locations.len();
 
+        if let Some(sender) = from {
+            let agent_id = location.id();
+            tracing::debug!("Message for {} from remote node {}", agent_id, sender);
+            let mut senders = self.senders.write().await;
+            senders.insert(agent_id, sender.to_string());
+        }
+
         match location {
             AgentLocation::Local(agent) => self.route_local(agent, message).await,
             AgentLocation::Remote(node_id) => {
@@ -158,6 +201,18 @@ impl MessageRouter {
         }
     }
 
+    /// Remote node that most recently sent a message to the agent with
+    /// `agent_id` (see [`AgentLocation::id`])
+    pub async fn last_sender(&self, agent_id: &str) -> Option<String> {
+        let senders = self.senders.read().await;
+        senders.get(agent_id).cloned()
+    }
+
+    /// Send a reply directly to a remote node, e.g. a recorded sender
+    pub async fn reply(&self, node_id: &str, message: &AgentMessage) -> Result<(), String> {
+        self.send_remote(node_id, message).await
+    }
+
     /// Send message to remote agent
     async fn send_remote(&self, node_id: &str, message: &AgentMessage) -> Result<(), String> {
         let transport_lock = self.transport.read().await;
@@ -239,6 +294,7 @@ impl std::fmt::Debug for MessageRouter {
             .field("registry", &self.registry)
             .field("rr_indices", &self.rr_indices)
             .field("transport", &"RemoteTransport")
+            .field("senders", &self.senders)
             .finish()
     }
 }
@@ -302,7 +358,7 @@ mod tests {
         // Route message
         let msg = AgentMessage::Orchestrator(OrchestratorMessage::Initialize);
         router
-            .route(AgentRole::Executor, msg.clone())
+            .route(AgentRole::Executor, msg.clone(), Some("node-456"))
             .await
             .unwrap();
 
@@ -314,5 +370,49 @@ mod tests {
             AgentMessage::Orchestrator(OrchestratorMessage::Initialize) => {}
             _ => panic!("Wrong message type"),
         }
+        drop(sent);
+
+        // The sender is recorded and can be replied to
+        assert_eq!(
+            router.last_sender("node-123").await.as_deref(),
+            Some("node-456")
+        );
+        router.reply("node-456", &msg).await.unwrap();
+        assert_eq!(sent_messages.read().await[1].0, "node-456");
+    }
+
+    #[tokio::test]
+    async fn test_senders_are_recorded_per_agent() {
+        let router = MessageRouter::new();
+        router
+            .set_transport(Arc::new(MockTransport {
+                sent_messages: Arc::new(RwLock::new(Vec::new())),
+            }))
+            .await;
+
+        // Two agents with the same role keep their own senders
+        router
+            .register_remote(AgentRole::Executor, "executor-a".to_string())
+            .await;
+        router
+            .register_remote(AgentRole::Executor, "executor-b".to_string())
+            .await;
+
+        let msg = AgentMessage::Orchestrator(OrchestratorMessage::Initialize);
+        for sender in ["node-1", "node-2"] {
+            router
+                .route(AgentRole::Executor, msg.clone(), Some(sender))
+                .await
+                .unwrap();
+        }
+
+        assert_eq!(
+            router.last_sender("executor-a").await.as_deref(),
+            Some("node-1")
+        );
+        assert_eq!(
+            router.last_sender("executor-b").await.as_deref(),
+            Some("node-2")
+        );
     }
 }
@@ -316,6 +316,7 @@ mod tests {
             notify_on_save: true,
             periodic_interval_minutes: 20,
             session_end_summary: true,
+            ..Default::default()
         };
 
         let notifier = Arc::new(ConflictNotifier::new(config, file_tracker));
@@ -355,6 +356,7 @@ mod tests {
             notify_on_save: true,
             periodic_interval_minutes: 20,
             session_end_summary: true,
+            ..Default::default()
         };
 
         let notifier = Arc::new(ConflictNotifier::new(config, file_tracker));
@@ -389,6 +391,7 @@ mod tests {
             notify_on_save: true,
             periodic_interval_minutes: 20,
             session_end_summary: true,
+            ..Default::default()
         };
 
         let notifier = Arc::new(ConflictNotifier::new(config, file_tracker));
@@ -0,0 +1,306 @@
+//! Constitution quality gates
+//!
+//! The project `Constitution` lists quality gates ("All tests pass", "No TODOs
+//! in shipped code", ...). The Reviewer turns each one into a [`QualityGate`]
+//! and evaluates it against completed work; a failing gate blocks completion
+//! with a message citing the gate it violated.
+//!
+//! Gates come in two flavours:
+//! - **Code-checkable**: decided from the work result and its memories
+//!   ([`TestsPassGate`], [`NoTodosGate`])
+//! - **LLM-evaluated**: anything else, judged by [`LlmGate`] when an LLM
+//!   client is available
+
+use crate::artifacts::Constitution;
+use crate::error::Result;
+use crate::orchestration::messages::WorkResult;
+use crate::orchestration::state::WorkItem;
+use crate::services::LlmService;
+use crate::types::MemoryNote;
+use async_trait::async_trait;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::sync::Arc;
+
+/// Markers of unfinished work rejected by [`NoTodosGate`]: upper-case comment
+/// markers as whole words, and Rust's placeholder macros
+static TODO_MARKER_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"\b(?:TODO|FIXME|UNIMPLEMENTED|NOT IMPLEMENTED)\b|\bXXX:|\b(?:todo|unimplemented)!\(",
+    )
+    .expect("Valid TODO marker regex")
+});
+
+/// Completed work handed to each gate
+pub struct GateInput<'a> {
+    /// Work item under review
+    pub work_item: &'a WorkItem,
+    /// Executor's result
+    pub result: &'a WorkResult,
+    /// Memories recorded during execution
+    pub memories: &'a [MemoryNote],
+}
+
+/// Outcome of evaluating one gate
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GateVerdict {
+    Pass,
+    /// Failed, with the reason
+    Fail(String),
+}
+
+/// A check that completed work must pass before it is accepted
+#[async_trait]
+pub trait QualityGate: Send + Sync {
+    /// Constitution text this gate enforces, cited when it fails
+    fn principle(&self) -> &str;
+
+    /// Evaluate the gate against completed work
+    async fn evaluate(&self, input: &GateInput<'_>) -> Result<GateVerdict>;
+}
+
+/// Passes when the work succeeded without reporting errors
+pub struct TestsPassGate {
+    principle: String,
+}
+
+impl TestsPassGate {
+    pub fn new(principle: impl Into<String>) -> Self {
+        Self {
+            principle: principle.into(),
+        }
+    }
+}
+
+#[async_trait]
+impl QualityGate for TestsPassGate {
+    fn principle(&self) -> &str {
+        &self.principle
+    }
+
+    async fn evaluate(&self, input: &GateInput<'_>) -> Result<GateVerdict> {
+        if !input.result.success {
+            let reason = input
+                .result
+                .error
+                .clone()
+                .unwrap_or_else(|| "work did not succeed".to_string());
+            return Ok(GateVerdict::Fail(reason));
+        }
+
+        let failing = input.memories.iter().find(|m| {
+            let content = m.content.to_lowercase();
+            content.contains("tests failed") || content.contains("test failed")
+        });
+        Ok(match failing {
+            Some(memory) => {
+                GateVerdict::Fail(format!("memory {} reports failing tests", memory.id))
+            }
+            None => GateVerdict::Pass,
+        })
+    }
+}
+
+/// Passes when neither the result nor its memories contain unfinished-work markers
+pub struct NoTodosGate {
+    principle: String,
+}
+
+impl NoTodosGate {
+    pub fn new(principle: impl Into<String>) -> Self {
+        Self {
+            principle: principle.into(),
+        }
+    }
+}
+
+#[async_trait]
+impl QualityGate for NoTodosGate {
+    fn principle(&self) -> &str {
+        &self.principle
+    }
+
+    async fn evaluate(&self, input: &GateInput<'_>) -> Result<GateVerdict> {
+        let texts = input
+            .result
+            .data
+            .iter()
+            .map(String::as_str)
+            .chain(input.memories.iter().map(|m| m.content.as_str()));
+
+        for text in texts {
+            if let Some(marker) = TODO_MARKER_RE.find(text) {
+                return Ok(GateVerdict::Fail(format!(
+                    "found unfinished-work marker {}",
+                    marker.as_str()
+                )));
+            }
+        }
+        Ok(GateVerdict::Pass)
+    }
+}
+
+/// Asks the LLM whether the work satisfies the gate
+pub struct LlmGate {
+    principle: String,
+    llm: Arc<LlmService>,
+}
+
+impl LlmGate {
+    pub fn new(principle: impl Into<String>, llm: Arc<LlmService>) -> Self {
+        Self {
+            principle: principle.into(),
+            llm,
+        }
+    }
+}
+
+#[async_trait]
+impl QualityGate for LlmGate {
+    fn principle(&self) -> &str {
+        &self.principle
+    }
+
+    async fn evaluate(&self, input: &GateInput<'_>) -> Result<GateVerdict> {
+        let mut implementation = format!("Task: {}\n\n", input.work_item.description);
+        for memory in input.memories {
+            implementation.push_str(&format!("{}\n{}\n\n", memory.summary, memory.content));
+        }
+
+        let verification = self
+            .llm
+            .verify_requirements(std::slice::from_ref(&self.principle), &implementation)
+            .await?;
+
+        Ok(if verification.passed {
+            GateVerdict::Pass
+        } else if verification.issues.is_empty() {
+            GateVerdict::Fail("LLM review found the gate unmet".to_string())
+        } else {
+            GateVerdict::Fail(verification.issues.join("; "))
+        })
+    }
+}
+
+/// Build gates for each of a constitution's quality gates
+///
+/// Gates about tests or TODOs are checked in code; the rest need `llm` and
+/// are skipped (with a debug log) without one.
+pub fn gates_from_constitution(
+    constitution: &Constitution,
+    llm: Option<Arc<LlmService>>,
+) -> Vec<Box<dyn QualityGate>> {
+    let mut gates: Vec<Box<dyn QualityGate>> = Vec::new();
+
+    for gate in &constitution.quality_gates {
+        let lower = gate.to_lowercase();
+        if lower.contains("todo") || lower.contains("fixme") {
+            gates.push(Box::new(NoTodosGate::new(gate)));
+        } else if lower.contains("test") && !lower.contains("coverage") {
+            gates.push(Box::new(TestsPassGate::new(gate)));
+        } else if let Some(ref llm) = llm {
+            gates.push(Box::new(LlmGate::new(gate, Arc::clone(llm))));
+        } else {
+            tracing::debug!("No LLM available, skipping constitution gate: {}", gate);
+        }
+    }
+
+    gates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::launcher::agents::AgentRole;
+    use crate::orchestration::state::Phase;
+    use std::time::Duration;
+
+    fn work() -> (WorkItem, WorkResult) {
+        let item = WorkItem::new(
+            "Add login".to_string(),
+            AgentRole::Executor,
+            Phase::PlanToArtifacts,
+            5,
+        );
+        let result = WorkResult::success(item.id.clone(), Duration::from_secs(1));
+        (item, result)
+    }
+
+    #[test]
+    fn test_gates_from_constitution_without_llm() {
+        let constitution = Constitution::builder("Test".to_string())
+            .quality_gate("All tests pass")
+            .quality_gate("No TODO comments in merged code")
+            .quality_gate("Public APIs are documented")
+            .build();
+
+        let gates = gates_from_constitution(&constitution, None);
+        let principles: Vec<&str> = gates.iter().map(|g| g.principle()).collect();
+        assert_eq!(
+            principles,
+            vec!["All tests pass", "No TODO comments in merged code"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_code_checkable_gates() {
+        let (item, mut result) = work();
+
+        let input = GateInput {
+            work_item: &item,
+            result: &result,
+            memories: &[],
+        };
+        assert_eq!(
+            TestsPassGate::new("All tests pass")
+                .evaluate(&input)
+                .await
+                .unwrap(),
+            GateVerdict::Pass
+        );
+        assert_eq!(
+            NoTodosGate::new("No TODOs").evaluate(&input).await.unwrap(),
+            GateVerdict::Pass
+        );
+
+        // Prose mentioning todos is not a marker
+        for prose in [
+            "Added a todo list view",
+            "Fixed the mastodon TODOs import",
+            "Syncs tasks from Todoist",
+        ] {
+            result.data = Some(prose.to_string());
+            let input = GateInput {
+                work_item: &item,
+                result: &result,
+                memories: &[],
+            };
+            assert_eq!(
+                NoTodosGate::new("No TODOs").evaluate(&input).await.unwrap(),
+                GateVerdict::Pass,
+                "{}",
+                prose
+            );
+        }
+
+        result.data = Some("fn login() { todo!() } // TODO: hash".to_string());
+        result.success = false;
+        result.error = Some("3 tests failed".to_string());
+        let input = GateInput {
+            work_item: &item,
+            result: &result,
+            memories: &[],
+        };
+        assert_eq!(
+            TestsPassGate::new("All tests pass")
+                .evaluate(&input)
+                .await
+                .unwrap(),
+            GateVerdict::Fail("3 tests failed".to_string())
+        );
+        assert!(matches!(
+            NoTodosGate::new("No TODOs").evaluate(&input).await.unwrap(),
+            GateVerdict::Fail(_)
+        ));
+    }
+}
@@ -378,6 +378,7 @@ mod tests {
         let api_event = ApiEvent {
             id: "test-1".to_string(),
             instance_id: None,
+            correlation_id: None,
             event_type: EventType::CliCommandStarted {
                 command: "status".to_string(),
                 args: vec![],
@@ -400,6 +401,7 @@ mod tests {
         let api_event = ApiEvent {
             id: "test-2".to_string(),
             instance_id: None,
+            correlation_id: None,
             event_type: EventType::MemoryStored {
                 memory_id: "mem-123".to_string(),
                 summary: "Test memory".to_string(),
@@ -425,6 +427,7 @@ mod tests {
         let api_event = ApiEvent {
             id: "test-3".to_string(),
             instance_id: None,
+            correlation_id: None,
             event_type: EventType::Heartbeat {
                 instance_id: Some("test".to_string()),
                 timestamp: chrono::Utc::now(),
@@ -334,6 +334,52 @@ impl WorkItem {
     }
 }
 
+/// Progress of a submitted work item
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkItemStatus {
+    /// Work item ID
+    pub id: WorkItemId,
+
+    /// Current state
+    pub state: AgentState,
+
+    /// Work Plan Protocol phase
+    pub phase: Phase,
+
+    /// Number of review attempts so far
+    pub review_attempt: u32,
+
+    /// Error message if failed
+    pub error: Option<String>,
+
+    /// Started timestamp
+    pub started_at: Option<DateTime<Utc>>,
+
+    /// Completed timestamp
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+impl WorkItemStatus {
+    /// Whether the item has completed or failed
+    pub fn is_finished(&self) -> bool {
+        matches!(self.state, AgentState::Complete | AgentState::Error)
+    }
+}
+
+impl From<&WorkItem> for WorkItemStatus {
+    fn from(item: &WorkItem) -> Self {
+        Self {
+            id: item.id.clone(),
+            state: item.state,
+            phase: item.phase,
+            review_attempt: item.review_attempt,
+            error: item.error.clone(),
+            started_at: item.started_at,
+            completed_at: item.completed_at,
+        }
+    }
+}
+
 /// Default maximum work items in queue
 pub const DEFAULT_MAX_WORK_ITEMS: usize = 10_000;
 
@@ -479,6 +525,14 @@ impl WorkQueue {
             .collect()
     }
 
+    /// Number of work items not yet complete or failed
+    pub fn unfinished_count(&self) -> usize {
+        self.items
+            .values()
+            .filter(|item| !matches!(item.state, AgentState::Complete | AgentState::Error))
+            .count()
+    }
+
     /// Get all active work items
     pub fn get_active_items(&self) -> Vec<&WorkItem> {
         self.items
@@ -512,6 +566,11 @@ impl WorkQueue {
         Ok(())
     }
 
+    /// Set the phase without transition checks, when restoring from the event log
+    pub fn restore_phase(&mut self, phase: Phase) {
+        self.current_phase = phase;
+    }
+
     /// Get current phase
     pub fn current_phase(&self) -> Phase {
         self.current_phase
@@ -9,6 +9,25 @@
 //! [branch:feature/test|mode:coordinated(2)|conflicts:1⚠]
 //! ```
 //!
+//! # Templates
+//!
+//! `StatusLineFormat::Template` renders a user template; only the segments
+//! it names are shown:
+//!
+//! | Token | Segment |
+//! |-------|---------|
+//! | `%b`  | Branch |
+//! | `%m`  | Coordination mode |
+//! | `%c`  | Conflict count |
+//! | `%n`  | Mnemosyne namespace |
+//! | `%a`  | Active agent count |
+//! | `%q`  | Work queue depth |
+//! | `%d`  | `*` if the git working tree is dirty |
+//! | `%%`  | Literal `%` |
+//!
+//! Unknown tokens are left as written, e.g. `"%n %q items%z"` renders as
+//! `"project:app 3 items%z"`.
+//!
 //! # Integration
 //!
 //! This module can be integrated with:
@@ -18,13 +37,24 @@
 //! - Status bars (i3bar, waybar)
 
 use crate::error::Result;
+use crate::ics::agent_status::AgentActivity;
 use crate::orchestration::branch_coordinator::BranchCoordinator;
 use crate::orchestration::branch_registry::CoordinationMode;
+use crate::orchestration::git_state::GitState;
 use crate::orchestration::identity::AgentIdentity;
+use crate::orchestration::registry::AgentRegistry;
+use crate::orchestration::state::SharedWorkQueue;
+use crate::types::Namespace;
 use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// How long a git dirty check is reused before re-running git
+const GIT_CACHE_DURATION: Duration = Duration::from_secs(2);
 
 /// Status line information
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct StatusLine {
     /// Current branch
     pub branch: String,
@@ -40,6 +70,22 @@ pub struct StatusLine {
 
     /// Is current agent blocked
     pub blocked: bool,
+
+    /// Mnemosyne namespace (if the provider knows it)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+
+    /// Agents currently analyzing, proposing or waiting
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active_agents: Option<usize>,
+
+    /// Unfinished work items in the queue
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub queue_depth: Option<usize>,
+
+    /// Whether the git working tree has uncommitted changes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub git_dirty: Option<bool>,
 }
 
 impl StatusLine {
@@ -130,18 +176,104 @@ impl StatusLine {
         result.push(']');
         result
     }
+
+    /// Render a user template (see the module docs for tokens)
+    ///
+    /// Segments the provider couldn't fetch render as empty strings.
+    pub fn format_template(&self, template: &str) -> String {
+        let optional = |value: Option<usize>| value.map(|v| v.to_string()).unwrap_or_default();
+
+        let mut result = String::with_capacity(template.len());
+        let mut chars = template.chars().peekable();
+        while let Some(ch) = chars.next() {
+            if ch != '%' {
+                result.push(ch);
+                continue;
+            }
+            let segment = match chars.peek() {
+                Some('b') => self.branch.clone(),
+                Some('m') => match self.mode {
+                    CoordinationMode::Isolated => "iso".to_string(),
+                    CoordinationMode::Coordinated => format!("coord({})", self.agent_count),
+                },
+                Some('c') => self.conflict_count.to_string(),
+                Some('n') => self.namespace.clone().unwrap_or_default(),
+                Some('a') => optional(self.active_agents),
+                Some('q') => optional(self.queue_depth),
+                Some('d') if self.git_dirty == Some(true) => "*".to_string(),
+                Some('d') => String::new(),
+                Some('%') => "%".to_string(),
+                // Unknown token (or trailing %): leave as written
+                _ => {
+                    result.push('%');
+                    continue;
+                }
+            };
+            chars.next();
+            result.push_str(&segment);
+        }
+        result
+    }
 }
 
 /// Status line provider
+///
+/// Optional segments are only fetched when their source is configured.
+/// Each is cheap: the work queue is read without waiting on its lock, and
+/// the git dirty check runs off the async runtime and is cached briefly.
 pub struct StatusLineProvider {
     coordinator: BranchCoordinator,
     agent: AgentIdentity,
+    namespace: Option<Namespace>,
+    agent_registry: Option<AgentRegistry>,
+    work_queue: Option<SharedWorkQueue>,
+    repo_root: Option<PathBuf>,
+    cache: Mutex<SegmentCache>,
+}
+
+/// Last fetched values of the optional segments
+#[derive(Default)]
+struct SegmentCache {
+    queue_depth: Option<usize>,
+    git_dirty: Option<(Instant, bool)>,
 }
 
 impl StatusLineProvider {
     /// Create a new status line provider
     pub fn new(coordinator: BranchCoordinator, agent: AgentIdentity) -> Self {
-        Self { coordinator, agent }
+        Self {
+            coordinator,
+            agent,
+            namespace: None,
+            agent_registry: None,
+            work_queue: None,
+            repo_root: None,
+            cache: Mutex::new(SegmentCache::default()),
+        }
+    }
+
+    /// Show the namespace segment
+    pub fn with_namespace(mut self, namespace: Namespace) -> Self {
+        self.namespace = Some(namespace);
+        self
+    }
+
+    /// Show the active agent count from a registry
+    pub fn with_agent_registry(mut self, registry: AgentRegistry) -> Self {
+        self.agent_registry = Some(registry);
+        self
+    }
+
+    /// Show the depth of a work queue
+    pub fn with_work_queue(mut self, work_queue: SharedWorkQueue) -> Self {
+        self.work_queue = Some(work_queue);
+        self
+    }
+
+    /// Show whether this repository's working tree is dirty
+    pub fn with_repo_root(mut self, repo_root: impl Into<PathBuf>) -> Self {
+        self.repo_root = Some(repo_root.into());
+        self
     }
 
     /// Get current status line information
@@ -176,11 +308,71 @@ impl StatusLineProvider {
             agent_count,
             conflict_count,
             blocked,
+            namespace: self.namespace.as_ref().map(ToString::to_string),
+            active_agents: self.active_agents().await,
+            queue_depth: self.queue_depth(),
+            git_dirty: self.git_dirty().await,
         })
     }
 
+    async fn active_agents(&self) -> Option<usize> {
+        let registry = self.agent_registry.as_ref()?;
+        let agents = registry.list_agents().await;
+        Some(
+            agents
+                .iter()
+                .filter(|agent| {
+                    matches!(
+                        agent.activity,
+                        AgentActivity::Analyzing
+                            | AgentActivity::Proposing
+                            | AgentActivity::Waiting
+                    )
+                })
+                .count(),
+        )
+    }
+
+    /// Unfinished items, or the last known depth while the queue is locked
+    fn queue_depth(&self) -> Option<usize> {
+        let work_queue = self.work_queue.as_ref()?;
+        let mut cache = self.cache.lock().unwrap_or_else(|e| e.into_inner());
+        if let Ok(queue) = work_queue.try_read() {
+            cache.queue_depth = Some(queue.unfinished_count());
+        }
+        cache.queue_depth
+    }
+
+    async fn git_dirty(&self) -> Option<bool> {
+        let repo_root = self.repo_root.clone()?;
+        {
+            let cache = self.cache.lock().unwrap_or_else(|e| e.into_inner());
+            if let Some((at, dirty)) = cache.git_dirty {
+                if at.elapsed() < GIT_CACHE_DURATION {
+                    return Some(dirty);
+                }
+            }
+        }
+
+        let state = tokio::task::spawn_blocking(move || GitState::from_repo_root(&repo_root))
+            .await
+            .ok()?;
+        match state {
+            Ok(state) => {
+                let dirty = !state.is_clean;
+                let mut cache = self.cache.lock().unwrap_or_else(|e| e.into_inner());
+                cache.git_dirty = Some((Instant::now(), dirty));
+                Some(dirty)
+            }
+            Err(e) => {
+                tracing::debug!("Git status unavailable for status line: {}", e);
+                None
+            }
+        }
+    }
+
     /// Get status line formatted for display
-    pub async fn get_formatted(&self, format: StatusLineFormat) -> Result<String> {
+    pub async fn get_formatted(&self, format: &StatusLineFormat) -> Result<String> {
         let status = self.get_status().await?;
 
         Ok(match format {
@@ -188,12 +380,13 @@ impl StatusLineProvider {
             StatusLineFormat::Detailed => status.format_detailed(),
             StatusLineFormat::Json => status.format_json()?,
             StatusLineFormat::Ansi => status.format_ansi(),
+            StatusLineFormat::Template(template) => status.format_template(template),
         })
     }
 }
 
 /// Status line format options
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum StatusLineFormat {
     /// Compact format for narrow displays
     Compact,
@@ -206,6 +399,9 @@ pub enum StatusLineFormat {
 
     /// ANSI color-coded format
     Ansi,
+
+    /// User template with `%` tokens choosing the segments shown
+    Template(String),
 }
 
 /// Shell integration helper
@@ -266,6 +462,7 @@ mod tests {
             agent_count: 1,
             conflict_count: 0,
             blocked: false,
+            ..Default::default()
         };
 
         assert_eq!(status.format_compact(), "[main|iso]");
@@ -279,6 +476,7 @@ mod tests {
             agent_count: 3,
             conflict_count: 0,
             blocked: false,
+            ..Default::default()
         };
 
         assert_eq!(status.format_compact(), "[feature/test|coord(3)]");
@@ -292,6 +490,7 @@ mod tests {
             agent_count: 2,
             conflict_count: 1,
             blocked: false,
+            ..Default::default()
         };
 
         assert_eq!(status.format_compact(), "[main|coord(2)|conflicts:1⚠]");
@@ -305,6 +504,7 @@ mod tests {
             agent_count: 1,
             conflict_count: 0,
             blocked: true,
+            ..Default::default()
         };
 
         assert_eq!(status.format_compact(), "[main|iso|BLOCKED]");
@@ -318,6 +518,7 @@ mod tests {
             agent_count: 2,
             conflict_count: 1,
             blocked: false,
+            ..Default::default()
         };
 
         let detailed = status.format_detailed();
@@ -334,9 +535,44 @@ mod tests {
             agent_count: 1,
             conflict_count: 0,
             blocked: false,
+            ..Default::default()
         };
 
         let json = status.format_json().unwrap();
         assert!(json.contains("\"branch\":\"main\""));
     }
+
+    fn full_status() -> StatusLine {
+        StatusLine {
+            branch: "main".to_string(),
+            mode: CoordinationMode::Coordinated,
+            agent_count: 2,
+            conflict_count: 1,
+            blocked: false,
+            namespace: Some("project:app".to_string()),
+            active_agents: Some(3),
+            queue_depth: Some(7),
+            git_dirty: Some(true),
+        }
+    }
+
+    #[test]
+    fn test_format_template_all_tokens() {
+        let rendered = full_status().format_template("%n %b%d [%m] a:%a q:%q c:%c 100%%");
+        assert_eq!(rendered, "project:app main* [coord(2)] a:3 q:7 c:1 100%");
+
+        // Missing segments render empty
+        let status = StatusLine {
+            branch: "main".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(status.format_template("%b%d|%n|%q"), "main||");
+    }
+
+    #[test]
+    fn test_format_template_unknown_tokens_literal() {
+        let status = full_status();
+        assert_eq!(status.format_template("%z %q%"), "%z 7%");
+        assert_eq!(status.format_template("%%q"), "%q");
+    }
 }
@@ -5,7 +5,7 @@
 //! - Optimizer, Reviewer, Executor (supervised children)
 //!
 //! Provides:
-//! - Automatic restart on failure
+//! - Restart of dead agents, limited per role by a [`RestartPolicy`]
 //! - Graceful shutdown
 //! - Actor registry
 
@@ -20,15 +20,28 @@ use crate::orchestration::messages::{
 use crate::orchestration::network;
 use crate::orchestration::proposal_queue::ProposalQueue;
 use crate::orchestration::registry::AgentRegistry;
+use crate::orchestration::state::{SharedWorkQueue, WorkQueue};
 use crate::storage::StorageBackend;
 use crate::types::Namespace;
-use ractor::{Actor, ActorRef};
+use ractor::{Actor, ActorCell, ActorRef, ActorStatus, RactorErr};
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
 
+/// Restart limits for an agent
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RestartPolicy {
+    /// Max restarts within the window before giving up
+    pub max_restarts: usize,
+
+    /// Time window for restart counting
+    pub restart_window_secs: u64,
+}
+
 /// Supervision configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SupervisionConfig {
@@ -43,6 +56,27 @@ pub struct SupervisionConfig {
 
     /// Max concurrent agents
     pub max_concurrent_agents: usize,
+
+    /// Per-role overrides of `max_restarts`/`restart_window_secs`
+    #[serde(default)]
+    pub role_policies: HashMap<AgentRole, RestartPolicy>,
+
+    /// Network connection and stream limits
+    #[serde(default)]
+    pub network: network::NetworkConfig,
+}
+
+impl SupervisionConfig {
+    /// Restart policy for a role: its override, or the global limits
+    pub fn restart_policy(&self, role: AgentRole) -> RestartPolicy {
+        self.role_policies
+            .get(&role)
+            .copied()
+            .unwrap_or(RestartPolicy {
+                max_restarts: self.max_restarts,
+                restart_window_secs: self.restart_window_secs,
+            })
+    }
 }
 
 impl Default for SupervisionConfig {
@@ -52,16 +86,49 @@ impl Default for SupervisionConfig {
             restart_window_secs: 60,
             enable_subagents: true,
             max_concurrent_agents: 4,
+            role_policies: HashMap::new(),
+            network: network::NetworkConfig::default(),
         }
     }
 }
 
+/// Recent restarts per role, for enforcing [`RestartPolicy`] limits
+#[derive(Debug, Default)]
+struct RestartTracker {
+    restarts: HashMap<AgentRole, VecDeque<Instant>>,
+}
+
+impl RestartTracker {
+    /// Record a restart of `role` at `now` if the policy still allows one
+    fn try_restart(&mut self, role: AgentRole, policy: RestartPolicy, now: Instant) -> bool {
+        let window = Duration::from_secs(policy.restart_window_secs);
+        let history = self.restarts.entry(role).or_default();
+        while history
+            .front()
+            .is_some_and(|t| now.duration_since(*t) >= window)
+        {
+            history.pop_front();
+        }
+
+        if history.len() >= policy.max_restarts {
+            return false;
+        }
+        history.push_back(now);
+        true
+    }
+}
+
 /// Supervision tree managing all agents
 pub struct SupervisionTree {
-    /// Configuration (WIP)
-    #[allow(dead_code)]
+    /// Configuration
     config: SupervisionConfig,
 
+    /// Recent restarts, checked against each role's restart policy
+    restarts: RestartTracker,
+
+    /// Roles that exceeded their restart limit and stay down
+    abandoned: HashSet<AgentRole>,
+
     /// Storage backend
     storage: Arc<dyn StorageBackend>,
 
@@ -75,6 +142,9 @@ pub struct SupervisionTree {
     /// Agent registry for status tracking
     registry: AgentRegistry,
 
+    /// Orchestrator's work queue, rebuilt from the event log on start
+    work_queue: SharedWorkQueue,
+
     /// Proposal queue for agent-to-ICS communication
     proposal_queue: ProposalQueue,
 
@@ -156,10 +226,13 @@ impl SupervisionTree {
 
         Ok(Self {
             config,
+            restarts: RestartTracker::default(),
+            abandoned: HashSet::new(),
             storage,
             network,
             namespace,
             registry: AgentRegistry::new(),
+            work_queue: Arc::new(tokio::sync::RwLock::new(WorkQueue::new())),
             proposal_queue: ProposalQueue::new(),
             event_broadcaster,
             state_manager,
@@ -193,10 +266,13 @@ impl SupervisionTree {
     ) -> Result<Self> {
         Ok(Self {
             config,
+            restarts: RestartTracker::default(),
+            abandoned: HashSet::new(),
             storage,
             network,
             namespace,
             registry: AgentRegistry::new(),
+            work_queue: Arc::new(tokio::sync::RwLock::new(WorkQueue::new())),
             proposal_queue: ProposalQueue::new(),
             event_broadcaster,
             state_manager,
@@ -221,152 +297,16 @@ impl SupervisionTree {
         // In production, this creates names like "optimizer-session:project:session-123"
         let name_prefix = format!("{}", self.namespace);
 
-        // Spawn Optimizer
-        let optimizer_id = format!("{}-optimizer", name_prefix);
-        let (optimizer_ref, _) = Actor::spawn(
-            Some(optimizer_id.clone()),
-            OptimizerActor::new(self.storage.clone(), self.namespace.clone()),
-            (self.storage.clone(), self.namespace.clone()),
-        )
-        .await
-        .map_err(|e| crate::error::MnemosyneError::ActorError(e.to_string()))?;
-
-        // Register event broadcaster BEFORE Initialize to avoid race condition
-        if let Some(broadcaster) = &self.event_broadcaster {
-            optimizer_ref
-                .cast(OptimizerMessage::RegisterEventBroadcaster(
-                    broadcaster.clone(),
-                ))
-                .map_err(|e| {
-                    tracing::warn!("Failed to register broadcaster with Optimizer: {:?}", e);
-                    crate::error::MnemosyneError::ActorError(e.to_string())
-                })?;
-            tracing::debug!("Event broadcaster registered with Optimizer");
-        }
-
-        optimizer_ref
-            .cast(OptimizerMessage::Initialize)
-            .map_err(|e| crate::error::MnemosyneError::ActorError(e.to_string()))?;
-
-        // Register in registry
-        self.registry
-            .register(
-                optimizer_id.clone(),
-                "Optimizer".to_string(),
-                AgentRole::Optimizer,
-            )
-            .await;
-
-        // Notify dashboard about agent startup
-        self.notify_agent_started(&optimizer_id, "Optimizer").await;
-
-        self.optimizer = Some(optimizer_ref);
-
-        // Spawn Reviewer
-        let reviewer_id = format!("{}-reviewer", name_prefix);
-        let (reviewer_ref, _) = Actor::spawn(
-            Some(reviewer_id.clone()),
-            ReviewerActor::new(self.storage.clone(), self.namespace.clone()),
-            (self.storage.clone(), self.namespace.clone()),
-        )
-        .await
-        .map_err(|e| crate::error::MnemosyneError::ActorError(e.to_string()))?;
-
-        // Register event broadcaster BEFORE Initialize to avoid race condition
-        if let Some(broadcaster) = &self.event_broadcaster {
-            reviewer_ref
-                .cast(ReviewerMessage::RegisterEventBroadcaster(
-                    broadcaster.clone(),
-                ))
-                .map_err(|e| {
-                    tracing::warn!("Failed to register broadcaster with Reviewer: {:?}", e);
-                    crate::error::MnemosyneError::ActorError(e.to_string())
-                })?;
-            tracing::debug!("Event broadcaster registered with Reviewer");
-        }
-
-        reviewer_ref
-            .cast(ReviewerMessage::Initialize)
-            .map_err(|e| crate::error::MnemosyneError::ActorError(e.to_string()))?;
-
-        // Register in registry
-        self.registry
-            .register(
-                reviewer_id.clone(),
-                "Reviewer".to_string(),
-                AgentRole::Reviewer,
-            )
-            .await;
-
-        // Notify dashboard about agent startup
-        self.notify_agent_started(&reviewer_id, "Reviewer").await;
-
-        self.reviewer = Some(reviewer_ref.clone());
-
-        // Initialize Python reviewer for LLM validation (feature-gated)
-        #[cfg(feature = "python")]
-        {
-            tracing::info!("Initializing Python reviewer for LLM validation");
-            match Self::initialize_python_reviewer() {
-                Ok(py_reviewer) => {
-                    reviewer_ref
-                        .cast(ReviewerMessage::RegisterPythonReviewer { py_reviewer })
-                        .map_err(|e| crate::error::MnemosyneError::ActorError(e.to_string()))?;
-                    tracing::info!("Python reviewer registered successfully");
-                }
-                Err(e) => {
-                    tracing::warn!("Failed to initialize Python reviewer, continuing without LLM validation: {}", e);
-                    tracing::warn!("Reviewer will fall back to pattern-matching validation");
-                }
-            }
-        }
-
-        // Spawn Executor
-        let executor_id = format!("{}-executor", name_prefix);
-        let (executor_ref, _) = Actor::spawn(
-            Some(executor_id.clone()),
-            ExecutorActor::new(self.storage.clone(), self.namespace.clone()),
-            (self.storage.clone(), self.namespace.clone()),
-        )
-        .await
-        .map_err(|e| crate::error::MnemosyneError::ActorError(e.to_string()))?;
-
-        // Register event broadcaster BEFORE Initialize to avoid race condition
-        if let Some(broadcaster) = &self.event_broadcaster {
-            executor_ref
-                .cast(ExecutorMessage::RegisterEventBroadcaster(
-                    broadcaster.clone(),
-                ))
-                .map_err(|e| {
-                    tracing::warn!("Failed to register broadcaster with Executor: {:?}", e);
-                    crate::error::MnemosyneError::ActorError(e.to_string())
-                })?;
-            tracing::debug!("Event broadcaster registered with Executor");
-        }
-
-        executor_ref
-            .cast(ExecutorMessage::Initialize)
-            .map_err(|e| crate::error::MnemosyneError::ActorError(e.to_string()))?;
-
-        // Register in registry
-        self.registry
-            .register(
-                executor_id.clone(),
-                "Executor".to_string(),
-                AgentRole::Executor,
-            )
-            .await;
-
-        // Notify dashboard about agent startup
-        self.notify_agent_started(&executor_id, "Executor").await;
-
-        self.executor = Some(executor_ref);
+        self.spawn_optimizer().await?;
+        self.spawn_reviewer().await?;
+        self.spawn_executor().await?;
 
         // Spawn Orchestrator (root supervisor)
         let orchestrator_id = format!("{}-orchestrator", name_prefix);
         let (orchestrator_ref, _) = Actor::spawn(
             Some(orchestrator_id.clone()),
-            OrchestratorActor::new(self.storage.clone(), self.namespace.clone()),
+            OrchestratorActor::new(self.storage.clone(), self.namespace.clone())
+                .with_work_queue(self.work_queue.clone()),
             (self.storage.clone(), self.namespace.clone()),
         )
         .await
@@ -405,41 +345,7 @@ impl SupervisionTree {
         self.orchestrator = Some(orchestrator_ref.clone());
 
         // Wire agents together - send agent references to connect the mesh
-        if let (Some(optimizer), Some(reviewer), Some(executor)) = (
-            self.optimizer.as_ref(),
-            self.reviewer.as_ref(),
-            self.executor.as_ref(),
-        ) {
-            // Wire Orchestrator with Optimizer, Reviewer, Executor
-            orchestrator_ref
-                .cast(OrchestratorMessage::RegisterAgents {
-                    optimizer: optimizer.clone(),
-                    reviewer: reviewer.clone(),
-                    executor: executor.clone(),
-                })
-                .map_err(|e| crate::error::MnemosyneError::ActorError(e.to_string()))?;
-
-            // Wire Orchestrator with Router (for distributed dispatch)
-            orchestrator_ref
-                .cast(OrchestratorMessage::RegisterRouter(self.network.router()))
-                .map_err(|e| crate::error::MnemosyneError::ActorError(e.to_string()))?;
-
-            // Wire Optimizer with Orchestrator
-            optimizer
-                .cast(OptimizerMessage::RegisterOrchestrator(
-                    orchestrator_ref.clone(),
-                ))
-                .map_err(|e| crate::error::MnemosyneError::ActorError(e.to_string()))?;
-
-            // Wire Reviewer with Orchestrator
-            reviewer
-                .cast(ReviewerMessage::RegisterOrchestrator(
-                    orchestrator_ref.clone(),
-                ))
-                .map_err(|e| crate::error::MnemosyneError::ActorError(e.to_string()))?;
-
-            tracing::debug!("Agents wired: Full mesh topology established");
-        }
+        self.wire_agents()?;
 
         // Event broadcaster registration moved to immediately after actor spawn
         // to avoid race condition with Initialize message
@@ -623,6 +529,210 @@ impl SupervisionTree {
         Ok(())
     }
 
+    /// Spawn the Optimizer and register it
+    async fn spawn_optimizer(&mut self) -> Result<()> {
+        let name_prefix = format!("{}", self.namespace);
+
+        // Spawn Optimizer
+        let optimizer_id = format!("{}-optimizer", name_prefix);
+        let (optimizer_ref, _) = Actor::spawn(
+            Some(optimizer_id.clone()),
+            OptimizerActor::new(self.storage.clone(), self.namespace.clone()),
+            (self.storage.clone(), self.namespace.clone()),
+        )
+        .await
+        .map_err(|e| crate::error::MnemosyneError::ActorError(e.to_string()))?;
+
+        // Register event broadcaster BEFORE Initialize to avoid race condition
+        if let Some(broadcaster) = &self.event_broadcaster {
+            optimizer_ref
+                .cast(OptimizerMessage::RegisterEventBroadcaster(
+                    broadcaster.clone(),
+                ))
+                .map_err(|e| {
+                    tracing::warn!("Failed to register broadcaster with Optimizer: {:?}", e);
+                    crate::error::MnemosyneError::ActorError(e.to_string())
+                })?;
+            tracing::debug!("Event broadcaster registered with Optimizer");
+        }
+
+        optimizer_ref
+            .cast(OptimizerMessage::Initialize)
+            .map_err(|e| crate::error::MnemosyneError::ActorError(e.to_string()))?;
+
+        // Register in registry
+        self.registry
+            .register(
+                optimizer_id.clone(),
+                "Optimizer".to_string(),
+                AgentRole::Optimizer,
+            )
+            .await;
+
+        // Notify dashboard about agent startup
+        self.notify_agent_started(&optimizer_id, "Optimizer").await;
+
+        self.optimizer = Some(optimizer_ref);
+
+        Ok(())
+    }
+
+    /// Spawn the Reviewer and register it
+    async fn spawn_reviewer(&mut self) -> Result<()> {
+        let name_prefix = format!("{}", self.namespace);
+
+        // Spawn Reviewer
+        let reviewer_id = format!("{}-reviewer", name_prefix);
+        let (reviewer_ref, _) = Actor::spawn(
+            Some(reviewer_id.clone()),
+            ReviewerActor::new(self.storage.clone(), self.namespace.clone()),
+            (self.storage.clone(), self.namespace.clone()),
+        )
+        .await
+        .map_err(|e| crate::error::MnemosyneError::ActorError(e.to_string()))?;
+
+        // Register event broadcaster BEFORE Initialize to avoid race condition
+        if let Some(broadcaster) = &self.event_broadcaster {
+            reviewer_ref
+                .cast(ReviewerMessage::RegisterEventBroadcaster(
+                    broadcaster.clone(),
+                ))
+                .map_err(|e| {
+                    tracing::warn!("Failed to register broadcaster with Reviewer: {:?}", e);
+                    crate::error::MnemosyneError::ActorError(e.to_string())
+                })?;
+            tracing::debug!("Event broadcaster registered with Reviewer");
+        }
+
+        reviewer_ref
+            .cast(ReviewerMessage::Initialize)
+            .map_err(|e| crate::error::MnemosyneError::ActorError(e.to_string()))?;
+
+        // Register in registry
+        self.registry
+            .register(
+                reviewer_id.clone(),
+                "Reviewer".to_string(),
+                AgentRole::Reviewer,
+            )
+            .await;
+
+        // Notify dashboard about agent startup
+        self.notify_agent_started(&reviewer_id, "Reviewer").await;
+
+        self.reviewer = Some(reviewer_ref.clone());
+
+        // Initialize Python reviewer for LLM validation (feature-gated)
+        #[cfg(feature = "python")]
+        {
+            tracing::info!("Initializing Python reviewer for LLM validation");
+            match Self::initialize_python_reviewer() {
+                Ok(py_reviewer) => {
+                    reviewer_ref
+                        .cast(ReviewerMessage::RegisterPythonReviewer { py_reviewer })
+                        .map_err(|e| crate::error::MnemosyneError::ActorError(e.to_string()))?;
+                    tracing::info!("Python reviewer registered successfully");
+                }
+                Err(e) => {
+                    tracing::warn!("Failed to initialize Python reviewer, continuing without LLM validation: {}", e);
+                    tracing::warn!("Reviewer will fall back to pattern-matching validation");
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Spawn the Executor and register it
+    async fn spawn_executor(&mut self) -> Result<()> {
+        let name_prefix = format!("{}", self.namespace);
+
+        // Spawn Executor
+        let executor_id = format!("{}-executor", name_prefix);
+        let (executor_ref, _) = Actor::spawn(
+            Some(executor_id.clone()),
+            ExecutorActor::new(self.storage.clone(), self.namespace.clone()),
+            (self.storage.clone(), self.namespace.clone()),
+        )
+        .await
+        .map_err(|e| crate::error::MnemosyneError::ActorError(e.to_string()))?;
+
+        // Register event broadcaster BEFORE Initialize to avoid race condition
+        if let Some(broadcaster) = &self.event_broadcaster {
+            executor_ref
+                .cast(ExecutorMessage::RegisterEventBroadcaster(
+                    broadcaster.clone(),
+                ))
+                .map_err(|e| {
+                    tracing::warn!("Failed to register broadcaster with Executor: {:?}", e);
+                    crate::error::MnemosyneError::ActorError(e.to_string())
+                })?;
+            tracing::debug!("Event broadcaster registered with Executor");
+        }
+
+        executor_ref
+            .cast(ExecutorMessage::Initialize)
+            .map_err(|e| crate::error::MnemosyneError::ActorError(e.to_string()))?;
+
+        // Register in registry
+        self.registry
+            .register(
+                executor_id.clone(),
+                "Executor".to_string(),
+                AgentRole::Executor,
+            )
+            .await;
+
+        // Notify dashboard about agent startup
+        self.notify_agent_started(&executor_id, "Executor").await;
+
+        self.executor = Some(executor_ref);
+
+        Ok(())
+    }
+
+    /// Send each agent the references it needs (full mesh)
+    fn wire_agents(&self) -> Result<()> {
+        if let (Some(orchestrator_ref), Some(optimizer), Some(reviewer), Some(executor)) = (
+            self.orchestrator.as_ref(),
+            self.optimizer.as_ref(),
+            self.reviewer.as_ref(),
+            self.executor.as_ref(),
+        ) {
+            // Wire Orchestrator with Optimizer, Reviewer, Executor
+            orchestrator_ref
+                .cast(OrchestratorMessage::RegisterAgents {
+                    optimizer: optimizer.clone(),
+                    reviewer: reviewer.clone(),
+                    executor: executor.clone(),
+                })
+                .map_err(|e| crate::error::MnemosyneError::ActorError(e.to_string()))?;
+
+            // Wire Orchestrator with Router (for distributed dispatch)
+            orchestrator_ref
+                .cast(OrchestratorMessage::RegisterRouter(self.network.router()))
+                .map_err(|e| crate::error::MnemosyneError::ActorError(e.to_string()))?;
+
+            // Wire Optimizer with Orchestrator
+            optimizer
+                .cast(OptimizerMessage::RegisterOrchestrator(
+                    orchestrator_ref.clone(),
+                ))
+                .map_err(|e| crate::error::MnemosyneError::ActorError(e.to_string()))?;
+
+            // Wire Reviewer with Orchestrator
+            reviewer
+                .cast(ReviewerMessage::RegisterOrchestrator(
+                    orchestrator_ref.clone(),
+                ))
+                .map_err(|e| crate::error::MnemosyneError::ActorError(e.to_string()))?;
+
+            tracing::debug!("Agents wired: Full mesh topology established");
+        }
+
+        Ok(())
+    }
+
     /// Bootstrap Work Plan Protocol
     ///
     /// Initializes the orchestration system by submitting initial work items.
@@ -630,15 +740,20 @@ impl SupervisionTree {
     /// when agents are spawned, rather than waiting for external triggers.
     ///
     /// Strategy:
-    /// 1. Check for existing active work (resume if present)
-    /// 2. If none, create session initialization work items
-    /// 3. Submit work to orchestrator to begin processing
+    /// 1. Replay this namespace's event log, re-queuing unfinished work
+    /// 2. Otherwise check for existing active work (resume if present)
+    /// 3. If none, create session initialization work items
+    /// 4. Submit work to orchestrator to begin processing
     async fn bootstrap_work_plan_protocol(&mut self) -> Result<()> {
         use crate::orchestration::state::AgentState;
         use crate::orchestration::work_plan_templates;
 
         tracing::info!("Bootstrapping Work Plan Protocol");
 
+        if self.recover_work_from_events().await? > 0 {
+            return Ok(());
+        }
+
         // Check if there are existing active work items to resume
         // We check Ready, Active, and Blocked states
         let mut existing_items = Vec::new();
@@ -705,6 +820,45 @@ impl SupervisionTree {
         Ok(())
     }
 
+    /// Rebuild the work queue from the event log after a crash or restart
+    ///
+    /// Returns the number of work items re-queued; work that was in progress
+    /// is reset to `Ready` and dispatched again.
+    async fn recover_work_from_events(&self) -> Result<usize> {
+        use crate::orchestration::events::EventReplay;
+
+        let replayed = match EventReplay::new(self.storage.clone(), self.namespace.clone())
+            .replay()
+            .await
+        {
+            Ok(replayed) => replayed,
+            Err(e) => {
+                tracing::warn!("Could not replay orchestration events: {}", e);
+                return Ok(0);
+            }
+        };
+        if replayed.work_items.is_empty() {
+            return Ok(0);
+        }
+
+        let restored = {
+            let mut queue = self.work_queue.write().await;
+            replayed.restore_into(&mut queue)
+        };
+        tracing::info!(
+            "Recovered {} unfinished work items from the event log",
+            restored
+        );
+
+        if let Some(ref orchestrator) = self.orchestrator {
+            orchestrator
+                .cast(OrchestratorMessage::GetReadyWork)
+                .map_err(|e| crate::error::MnemosyneError::ActorError(e.to_string()))?;
+        }
+
+        Ok(restored)
+    }
+
     /// Initialize Python Claude SDK agent bridge for a given role
     ///
     /// This spawns a Python Claude SDK agent using the ClaudeAgentBridge,
@@ -810,43 +964,31 @@ impl SupervisionTree {
             }
         }
 
-        // Stop in reverse order (children first, then supervisor)
-        if let Some(executor) = self.executor.take() {
-            executor.stop(None);
-            tracing::debug!("Sent stop signal to executor");
-        }
-
-        if let Some(reviewer) = self.reviewer.take() {
-            reviewer.stop(None);
-            tracing::debug!("Sent stop signal to reviewer");
+        // Drain in dispatch order so work the orchestrator hands out while
+        // draining still reaches the agents behind it. Each actor finishes
+        // its queued messages (persisting their events) before it exits.
+        let deadline = tokio::time::Instant::now() + timeout;
+        let mut drained = true;
+        if let Some(orchestrator) = self.orchestrator.take() {
+            drained &= drain_actor(orchestrator.get_cell(), "orchestrator", deadline).await;
         }
-
         if let Some(optimizer) = self.optimizer.take() {
-            optimizer.stop(None);
-            tracing::debug!("Sent stop signal to optimizer");
+            drained &= drain_actor(optimizer.get_cell(), "optimizer", deadline).await;
         }
-
-        if let Some(orchestrator) = self.orchestrator.take() {
-            orchestrator.stop(None);
-            tracing::debug!("Sent stop signal to orchestrator");
+        if let Some(reviewer) = self.reviewer.take() {
+            drained &= drain_actor(reviewer.get_cell(), "reviewer", deadline).await;
+        }
+        if let Some(executor) = self.executor.take() {
+            drained &= drain_actor(executor.get_cell(), "executor", deadline).await;
         }
 
-        // Wait for actors to stop gracefully with timeout (P1-2: 30s default)
-        tokio::select! {
-            _ = tokio::time::sleep(timeout) => {
-                let elapsed = stop_start.elapsed();
-                tracing::warn!(
-                    "Actor shutdown timeout ({:?}) exceeded after {:?}. \
-                     Some actors may not have stopped gracefully.",
-                    timeout, elapsed
-                );
-            }
-            _ = async {
-                // Give actors time to complete cleanup
-                tokio::time::sleep(std::time::Duration::from_millis(100)).await;
-            } => {
-                tracing::debug!("Actors stopped gracefully");
-            }
+        if !drained {
+            tracing::warn!(
+                "Actor shutdown timeout ({:?}) exceeded after {:?}; \
+                 remaining actors were killed",
+                timeout,
+                stop_start.elapsed()
+            );
         }
 
         let total_elapsed = stop_start.elapsed();
@@ -855,6 +997,11 @@ impl SupervisionTree {
         Ok(())
     }
 
+    /// Orchestrator's work queue
+    pub fn work_queue(&self) -> &SharedWorkQueue {
+        &self.work_queue
+    }
+
     /// Get reference to orchestrator
     pub fn orchestrator(&self) -> &ActorRef<OrchestratorMessage> {
         self.orchestrator
@@ -927,43 +1074,122 @@ impl SupervisionTree {
 
     /// Check if all agents are healthy and running
     ///
-    /// Returns true if all 4 agent actor references exist and are accessible.
+    /// Returns true if all 4 agents exist and none has stopped, except those
+    /// abandoned after exceeding their restart limit.
     /// This is a lightweight health check that doesn't send messages to agents.
     pub async fn is_healthy(&self) -> bool {
-        // Check if all agent references exist
-        self.orchestrator.is_some()
-            && self.optimizer.is_some()
-            && self.reviewer.is_some()
-            && self.executor.is_some()
+        self.dead_agents()
+            .iter()
+            .all(|role| self.abandoned.contains(role))
+    }
+
+    /// Roles whose actor is missing or has stopped
+    fn dead_agents(&self) -> Vec<AgentRole> {
+        fn is_dead<T>(actor: &Option<ActorRef<T>>) -> bool {
+            actor.as_ref().is_none_or(|actor| {
+                matches!(
+                    actor.get_status(),
+                    ActorStatus::Stopping | ActorStatus::Stopped
+                )
+            })
+        }
+
+        [
+            (AgentRole::Orchestrator, is_dead(&self.orchestrator)),
+            (AgentRole::Optimizer, is_dead(&self.optimizer)),
+            (AgentRole::Reviewer, is_dead(&self.reviewer)),
+            (AgentRole::Executor, is_dead(&self.executor)),
+        ]
+        .into_iter()
+        .filter_map(|(role, dead)| dead.then_some(role))
+        .collect()
     }
 
     /// Restart failed agents
     ///
-    /// Checks each agent and restarts any that have failed.
-    /// The supervision tree (Ractor) handles automatic restart on failure,
-    /// so this is primarily for explicit restart requests.
+    /// Restarts each dead agent while its role's [`RestartPolicy`] allows;
+    /// an agent that exceeds its limit stays down. Losing the Orchestrator
+    /// restarts the whole tree.
     pub async fn restart_failed_agents(&mut self) -> Result<()> {
-        // In Ractor supervision trees, actors automatically restart on failure
-        // This method is a no-op for now but can be extended to:
-        // - Manually restart specific actors
-        // - Clear actor state on restart
-        // - Broadcast restart events
-
-        // Check if agents are still alive
-        if self.orchestrator.is_none()
-            || self.optimizer.is_none()
-            || self.reviewer.is_none()
-            || self.executor.is_none()
-        {
-            tracing::warn!("Some agents missing, restarting supervision tree");
-            // Stop any remaining agents
-            self.stop().await?;
-            // Restart all agents
-            self.start().await?;
+        let now = Instant::now();
+        let dead = self.dead_agents();
+
+        if dead.contains(&AgentRole::Orchestrator) {
+            if self.permit_restart(AgentRole::Orchestrator, now) {
+                tracing::warn!("Orchestrator down, restarting supervision tree");
+                self.stop().await?;
+                self.start().await?;
+            }
+            return Ok(());
+        }
+
+        let mut restarted = false;
+        for role in dead {
+            if !self.permit_restart(role, now) {
+                continue;
+            }
+            tracing::warn!("{:?} agent down, restarting", role);
+            match role {
+                AgentRole::Optimizer => self.spawn_optimizer().await?,
+                AgentRole::Reviewer => self.spawn_reviewer().await?,
+                AgentRole::Executor => self.spawn_executor().await?,
+                AgentRole::Orchestrator => unreachable!("handled above"),
+            }
+            restarted = true;
+        }
+
+        if restarted {
+            self.wire_agents()?;
         }
 
         Ok(())
     }
+
+    /// Check (and record) a restart against the role's policy
+    fn permit_restart(&mut self, role: AgentRole, now: Instant) -> bool {
+        if self.abandoned.contains(&role) {
+            return false;
+        }
+
+        let policy = self.config.restart_policy(role);
+        if self.restarts.try_restart(role, policy, now) {
+            return true;
+        }
+
+        tracing::error!(
+            "{:?} agent exceeded {} restarts in {}s, not restarting",
+            role,
+            policy.max_restarts,
+            policy.restart_window_secs
+        );
+        self.abandoned.insert(role);
+        false
+    }
+}
+
+/// Drain an actor's mailbox and wait for it to exit, killing it if `deadline`
+/// passes first. Returns whether the actor drained in time.
+async fn drain_actor(actor: ActorCell, name: &str, deadline: tokio::time::Instant) -> bool {
+    let remaining = deadline.saturating_duration_since(tokio::time::Instant::now());
+    match actor.drain_and_wait(Some(remaining)).await {
+        Ok(()) => {
+            tracing::debug!("{} drained and stopped", name);
+            true
+        }
+        Err(RactorErr::Timeout) => {
+            tracing::warn!(
+                "{} did not drain before the shutdown deadline, killing",
+                name
+            );
+            actor.kill();
+            false
+        }
+        Err(e) => {
+            // Mailbox already closed: the actor stopped on its own
+            tracing::debug!("{} already stopped: {}", name, e);
+            true
+        }
+    }
 }
 
 #[cfg(test)]
@@ -994,6 +1220,94 @@ mod tests {
         tree.stop().await.unwrap();
     }
 
+    #[test]
+    fn test_restart_policy_falls_back_to_global() {
+        let reviewer = RestartPolicy {
+            max_restarts: 10,
+            restart_window_secs: 30,
+        };
+        let config = SupervisionConfig {
+            role_policies: HashMap::from([(AgentRole::Reviewer, reviewer)]),
+            ..SupervisionConfig::default()
+        };
+
+        assert_eq!(config.restart_policy(AgentRole::Reviewer), reviewer);
+        assert_eq!(
+            config.restart_policy(AgentRole::Executor),
+            RestartPolicy {
+                max_restarts: 3,
+                restart_window_secs: 60,
+            }
+        );
+    }
+
+    #[test]
+    fn test_restart_tracker_window() {
+        let policy = RestartPolicy {
+            max_restarts: 2,
+            restart_window_secs: 60,
+        };
+        let mut tracker = RestartTracker::default();
+        let start = Instant::now();
+
+        assert!(tracker.try_restart(AgentRole::Executor, policy, start));
+        assert!(tracker.try_restart(AgentRole::Executor, policy, start));
+        assert!(!tracker.try_restart(AgentRole::Executor, policy, start));
+        // Limits are counted per role
+        assert!(tracker.try_restart(AgentRole::Reviewer, policy, start));
+        // Old restarts age out of the window
+        assert!(tracker.try_restart(AgentRole::Executor, policy, start + Duration::from_secs(61)));
+    }
+
+    #[tokio::test]
+    async fn test_per_role_restart_limits() {
+        let storage = Arc::new(LibsqlStorage::new(ConnectionMode::InMemory).await.unwrap());
+        let network = Arc::new(network::NetworkLayer::new().await.unwrap());
+        let config = SupervisionConfig {
+            max_restarts: 0,
+            role_policies: HashMap::from([(
+                AgentRole::Reviewer,
+                RestartPolicy {
+                    max_restarts: 5,
+                    restart_window_secs: 60,
+                },
+            )]),
+            ..SupervisionConfig::default()
+        };
+        let mut tree = SupervisionTree::new(config, storage, network)
+            .await
+            .unwrap();
+        tree.start().await.unwrap();
+        assert!(tree.is_healthy().await);
+
+        // Both agents die
+        let reviewer = tree.reviewer.clone().unwrap();
+        let executor = tree.executor.clone().unwrap();
+        reviewer.stop(None);
+        executor.stop(None);
+        for _ in 0..50 {
+            if tree.dead_agents().len() == 2 {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        assert_eq!(
+            tree.dead_agents(),
+            vec![AgentRole::Reviewer, AgentRole::Executor]
+        );
+
+        tree.restart_failed_agents().await.unwrap();
+
+        // The Reviewer is under its own limit; the Executor's global limit is 0
+        assert_eq!(tree.dead_agents(), vec![AgentRole::Executor]);
+        assert_ne!(tree.reviewer.as_ref().unwrap().get_id(), reviewer.get_id());
+        assert_eq!(tree.executor.as_ref().unwrap().get_id(), executor.get_id());
+        // The abandoned Executor doesn't keep the tree unhealthy
+        assert!(tree.is_healthy().await);
+
+        tree.stop().await.unwrap();
+    }
+
     /// E2E test for retry workflow with requirement tracking
     ///
     /// Scenario:
@@ -3,8 +3,11 @@
 //! Pre-defined work item templates for each phase of the Work Plan Protocol.
 //! These templates bootstrap the orchestration system to execute the 4-phase workflow automatically.
 
+use crate::artifacts::TaskBreakdown;
+use crate::error::Result;
 use crate::launcher::agents::AgentRole;
-use crate::orchestration::state::{Phase, WorkItem};
+use crate::orchestration::state::{Phase, WorkItem, WorkItemId};
+use std::collections::HashMap;
 
 /// Create initial work items for session initialization
 ///
@@ -216,6 +219,48 @@ pub fn create_phase4_work_items(plan_tasks: Vec<String>) -> Vec<WorkItem> {
     work_items
 }
 
+/// Create Phase 4 work items from a `TaskBreakdown` artifact
+///
+/// Each open task becomes one Executor work item, returned in dependency
+/// order with `depends_on` edges mapped to work item dependencies. Tasks
+/// already checked off are skipped, and edges to them count as satisfied.
+/// Fails if the breakdown has duplicate IDs, unknown dependencies or a cycle.
+pub fn work_from_tasks(breakdown: &TaskBreakdown) -> Result<Vec<WorkItem>> {
+    let ordered = breakdown.topological_order()?;
+
+    let mut ids: HashMap<&str, WorkItemId> = HashMap::new();
+    let mut work_items = Vec::new();
+
+    for (index, task) in ordered.into_iter().enumerate() {
+        if task.completed {
+            continue;
+        }
+
+        let description = match task.story {
+            Some(ref story) => format!("[{}] [{}] {}", task.id, story, task.description),
+            None => format!("[{}] {}", task.id, task.description),
+        };
+        let mut item = WorkItem::new(
+            description,
+            AgentRole::Executor,
+            Phase::PlanToArtifacts,
+            10 - (index.min(5) as u8), // Earlier tasks unblock more work
+        );
+
+        // Dependencies always precede their dependents in topological order
+        for dep in &task.depends_on {
+            if let Some(dep_id) = ids.get(dep.as_str()) {
+                item.add_dependency(dep_id.clone());
+            }
+        }
+
+        ids.insert(task.id.as_str(), item.id.clone());
+        work_items.push(item);
+    }
+
+    Ok(work_items)
+}
+
 /// Helper to extract task descriptions from a plan
 ///
 /// This is a placeholder - in practice, this would parse
@@ -298,4 +343,99 @@ This is a note, not a task.
         assert_eq!(tasks[1], "Implement JWT generation");
         assert_eq!(tasks[2], "Add middleware integration");
     }
+
+    fn task(id: &str, deps: &[&str]) -> crate::artifacts::Task {
+        crate::artifacts::Task {
+            id: id.to_string(),
+            description: format!("Task {}", id),
+            parallelizable: false,
+            story: None,
+            completed: false,
+            depends_on: deps.iter().map(|d| d.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_work_from_tasks_orders_work_queue() {
+        use crate::artifacts::TaskPhase;
+        use crate::orchestration::state::WorkQueue;
+
+        // T005 is listed first but needs everything else
+        let mut breakdown = TaskBreakdown::new("auth".to_string(), "Auth".to_string());
+        breakdown.add_phase(TaskPhase {
+            name: "Setup".to_string(),
+            tasks: vec![
+                task("T005", &["T003", "T004"]),
+                task("T001", &[]),
+                task("T002", &["T001"]),
+            ],
+        });
+        breakdown.add_phase(TaskPhase {
+            name: "Core".to_string(),
+            tasks: vec![task("T003", &["T002"]), task("T004", &["T001"])],
+        });
+
+        let items = work_from_tasks(&breakdown).unwrap();
+        assert_eq!(items.len(), 5);
+        assert!(items.iter().all(|i| i.phase == Phase::PlanToArtifacts));
+
+        let label = |item: &WorkItem| item.description[1..5].to_string();
+        let position: HashMap<String, usize> = items
+            .iter()
+            .enumerate()
+            .map(|(i, item)| (label(item), i))
+            .collect();
+        let deps_of = |id: &str| items[position[id]].dependencies.clone();
+        assert!(deps_of("T001").is_empty());
+        assert_eq!(deps_of("T002"), vec![items[position["T001"]].id.clone()]);
+        assert_eq!(deps_of("T005").len(), 2);
+
+        // Drain the queue wave by wave, completing whatever is ready
+        let mut queue = WorkQueue::new();
+        for item in &items {
+            queue.add(item.clone()).unwrap();
+        }
+        let mut waves = Vec::new();
+        loop {
+            let mut ready: Vec<String> = queue.get_ready_items().into_iter().map(label).collect();
+            if ready.is_empty() {
+                break;
+            }
+            ready.sort();
+            for id in &ready {
+                queue.mark_completed(&items[position[id]].id);
+            }
+            waves.push(ready);
+        }
+
+        assert_eq!(
+            waves,
+            vec![
+                vec!["T001".to_string()],
+                vec!["T002".to_string(), "T004".to_string()],
+                vec!["T003".to_string()],
+                vec!["T005".to_string()],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_work_from_tasks_skips_completed_and_rejects_cycles() {
+        use crate::artifacts::TaskPhase;
+
+        let mut done = task("T001", &[]);
+        done.completed = true;
+        let mut breakdown = TaskBreakdown::new("auth".to_string(), "Auth".to_string());
+        breakdown.add_phase(TaskPhase {
+            name: "Setup".to_string(),
+            tasks: vec![done, task("T002", &["T001"])],
+        });
+
+        let items = work_from_tasks(&breakdown).unwrap();
+        assert_eq!(items.len(), 1);
+        assert!(items[0].dependencies.is_empty());
+
+        breakdown.phases[0].tasks[0].depends_on = vec!["T002".to_string()];
+        assert!(work_from_tasks(&breakdown).is_err());
+    }
 }
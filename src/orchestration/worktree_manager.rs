@@ -463,39 +463,7 @@ impl WorktreeManager {
 
             if !is_active {
                 tracing::info!("Cleaning up stale worktree: {}", path.display());
-
-                // Remove using git worktree remove
-                let output = Command::new("git")
-                    .arg("worktree")
-                    .arg("remove")
-                    .arg(&path)
-                    .arg("--force")
-                    .current_dir(&self.repo_root)
-                    .output()
-                    .map_err(|e| {
-                        MnemosyneError::Other(format!(
-                            "Failed to execute git worktree remove: {}",
-                            e
-                        ))
-                    })?;
-
-                if !output.status.success() {
-                    tracing::warn!(
-                        "git worktree remove failed for {}: {}",
-                        path.display(),
-                        String::from_utf8_lossy(&output.stderr)
-                    );
-                }
-
-                // Manual cleanup if directory still exists
-                if path.exists() {
-                    std::fs::remove_dir_all(&path).map_err(|e| {
-                        MnemosyneError::Io(std::io::Error::new(
-                            e.kind(),
-                            format!("Failed to remove worktree directory: {}", e),
-                        ))
-                    })?;
-                }
+                self.remove_worktree_path(&path)?;
 
                 // Try to reconstruct AgentId from directory name (may not be possible with 8-char format)
                 // For now, just use a placeholder
@@ -506,6 +474,76 @@ impl WorktreeManager {
         Ok(cleaned)
     }
 
+    /// List worktree directories created by mnemosyne
+    ///
+    /// Returns every directory under `.mnemosyne/worktrees/`, whether or not
+    /// git still tracks it. Directory names are short agent IDs.
+    pub fn list_managed_worktrees(&self) -> Result<Vec<PathBuf>> {
+        if !self.worktree_base.exists() {
+            return Ok(vec![]);
+        }
+
+        let entries = std::fs::read_dir(&self.worktree_base).map_err(|e| {
+            MnemosyneError::Io(std::io::Error::new(
+                e.kind(),
+                format!("Failed to read worktree directory: {}", e),
+            ))
+        })?;
+
+        let mut paths = Vec::new();
+        for entry in entries {
+            let entry = entry.map_err(|e| {
+                MnemosyneError::Io(std::io::Error::new(
+                    e.kind(),
+                    format!("Failed to read directory entry: {}", e),
+                ))
+            })?;
+            if entry.path().is_dir() {
+                paths.push(entry.path());
+            }
+        }
+
+        paths.sort();
+        Ok(paths)
+    }
+
+    /// Remove a managed worktree by path
+    ///
+    /// Runs `git worktree remove --force`, then deletes the directory if git
+    /// could not (e.g. its metadata was already pruned).
+    pub fn remove_worktree_path(&self, path: &Path) -> Result<()> {
+        let output = Command::new("git")
+            .arg("worktree")
+            .arg("remove")
+            .arg(path)
+            .arg("--force")
+            .current_dir(&self.repo_root)
+            .output()
+            .map_err(|e| {
+                MnemosyneError::Other(format!("Failed to execute git worktree remove: {}", e))
+            })?;
+
+        if !output.status.success() {
+            tracing::warn!(
+                "git worktree remove failed for {}: {}",
+                path.display(),
+                String::from_utf8_lossy(&output.stderr)
+            );
+        }
+
+        // Manual cleanup if directory still exists
+        if path.exists() {
+            std::fs::remove_dir_all(path).map_err(|e| {
+                MnemosyneError::Io(std::io::Error::new(
+                    e.kind(),
+                    format!("Failed to remove worktree directory: {}", e),
+                ))
+            })?;
+        }
+
+        Ok(())
+    }
+
     /// Get worktree path for an agent
     ///
     /// Returns the path where the agent's worktree is or should be located.
@@ -806,6 +844,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_list_and_remove_managed_worktrees() {
+        let temp_dir = setup_git_repo();
+        let manager = WorktreeManager::new(temp_dir.path().to_path_buf()).unwrap();
+        assert!(manager.list_managed_worktrees().unwrap().is_empty());
+
+        let agent = AgentId::new();
+        let path = manager.create_worktree(&agent, "feature-test").unwrap();
+        assert_eq!(
+            manager.list_managed_worktrees().unwrap(),
+            vec![path.clone()]
+        );
+
+        manager.remove_worktree_path(&path).unwrap();
+        assert!(!manager.worktree_exists(&agent));
+        assert!(manager.list_managed_worktrees().unwrap().is_empty());
+    }
+
     #[test]
     fn test_branch_isolation() {
         let temp_dir = setup_git_repo();
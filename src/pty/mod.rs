@@ -5,11 +5,14 @@
 //! - Stream interception for agent detection
 //! - Output parsing for semantic highlighting
 //! - Bidirectional communication with wrapped process
+//! - Raw session transcript capture
 
 mod parser;
 mod session;
+mod transcript;
 mod wrapper;
 
 pub use parser::{AgentMarker, OutputParser, ParsedChunk};
-pub use session::{PtyConfig, PtyOutput, PtySession};
-pub use wrapper::ClaudeCodeWrapper;
+pub use session::{PtyConfig, PtyOutput, PtyResizeHandle, PtySession};
+pub use transcript::{read_transcript, TranscriptChunk, TranscriptWriter};
+pub use wrapper::{AgentEventEmitter, AttachedSession, ClaudeCodeWrapper};
@@ -1,10 +1,12 @@
 //! PTY session management
 #![allow(dead_code)]
 
+use super::transcript::TranscriptWriter;
 use anyhow::{Context, Result};
-use portable_pty::{CommandBuilder, NativePtySystem, PtySize, PtySystem};
+use portable_pty::{Child, CommandBuilder, MasterPty, NativePtySystem, PtySize, PtySystem};
 use std::io::{Read, Write};
-use std::sync::Arc;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
 use tokio::sync::RwLock;
 
 /// PTY configuration
@@ -18,19 +20,90 @@ pub struct PtyConfig {
     pub command: String,
     /// Command arguments
     pub args: Vec<String>,
+    /// Persist the raw output stream to this file (see [`super::read_transcript`])
+    pub transcript_path: Option<PathBuf>,
 }
 
 impl Default for PtyConfig {
+    /// Defaults to the size of the current terminal (80x24 if unavailable)
     fn default() -> Self {
+        let (cols, rows) = current_terminal_size();
         Self {
-            cols: 80,
-            rows: 24,
+            cols,
+            rows,
             command: "claude".to_string(),
             args: vec![],
+            transcript_path: None,
         }
     }
 }
 
+/// Size of the controlling terminal, falling back to 80x24
+fn current_terminal_size() -> (u16, u16) {
+    match crossterm::terminal::size() {
+        Ok((cols, rows)) if cols > 0 && rows > 0 => (cols, rows),
+        _ => (80, 24),
+    }
+}
+
+/// Cloneable handle for resizing a PTY
+///
+/// Uses its own lock, separate from the reader and writer, so a resize
+/// never waits behind a blocking read during heavy output.
+#[derive(Clone)]
+pub struct PtyResizeHandle {
+    master: Arc<Mutex<Box<dyn MasterPty + Send>>>,
+}
+
+impl PtyResizeHandle {
+    /// Propagate a new window size to the wrapped process (TIOCSWINSZ)
+    pub fn resize(&self, cols: u16, rows: u16) -> Result<()> {
+        let master = self
+            .master
+            .lock()
+            .map_err(|_| anyhow::anyhow!("PTY master lock poisoned"))?;
+        master
+            .resize(PtySize {
+                rows,
+                cols,
+                pixel_width: 0,
+                pixel_height: 0,
+            })
+            .context("Failed to resize PTY")
+    }
+
+    /// Current PTY size as (cols, rows)
+    pub fn size(&self) -> Result<(u16, u16)> {
+        let master = self
+            .master
+            .lock()
+            .map_err(|_| anyhow::anyhow!("PTY master lock poisoned"))?;
+        let size = master.get_size().context("Failed to query PTY size")?;
+        Ok((size.cols, size.rows))
+    }
+
+    /// Forward SIGWINCH from the controlling terminal to the PTY
+    ///
+    /// For non-TUI mode; in TUI mode resize events arrive through crossterm
+    /// and should be passed to [`PtySession::resize`] instead.
+    #[cfg(unix)]
+    pub fn forward_sigwinch(self) -> Result<tokio::task::JoinHandle<()>> {
+        use tokio::signal::unix::{signal, SignalKind};
+
+        let mut winch =
+            signal(SignalKind::window_change()).context("Failed to listen for SIGWINCH")?;
+
+        Ok(tokio::spawn(async move {
+            while winch.recv().await.is_some() {
+                let (cols, rows) = current_terminal_size();
+                if let Err(e) = self.resize(cols, rows) {
+                    tracing::warn!("Failed to propagate terminal resize: {}", e);
+                }
+            }
+        }))
+    }
+}
+
 /// PTY output chunk
 #[derive(Debug, Clone)]
 pub struct PtyOutput {
@@ -49,8 +122,23 @@ pub struct PtySession {
     writer: Arc<RwLock<Box<dyn Write + Send>>>,
     /// Reader handle
     reader: Arc<RwLock<Box<dyn Read + Send>>>,
+    /// Resize handle (owns the PTY master)
+    resizer: PtyResizeHandle,
     /// Configuration
     config: PtyConfig,
+    /// Raw transcript writer (if enabled)
+    transcript: Option<TranscriptWriter>,
+    /// Wrapped process
+    child: Box<dyn Child + Send + Sync>,
+}
+
+/// Owned handles of a PTY session, for driving it from plain threads
+pub(super) struct SessionParts {
+    pub reader: Box<dyn Read + Send>,
+    pub writer: Box<dyn Write + Send>,
+    pub resizer: PtyResizeHandle,
+    pub child: Box<dyn Child + Send + Sync>,
+    pub transcript: Option<TranscriptWriter>,
 }
 
 impl PtySession {
@@ -79,23 +167,39 @@ impl PtySession {
             .take_writer()
             .context("Failed to take PTY writer")?;
 
-        // Build command
+        // Build command, in our working directory (portable-pty defaults to $HOME)
         let mut cmd = CommandBuilder::new(&config.command);
         for arg in &config.args {
             cmd.arg(arg);
         }
+        if let Ok(cwd) = std::env::current_dir() {
+            cmd.cwd(cwd);
+        }
 
-        // Spawn child process
-        let _child = pty_pair
+        // Spawn child process, then close our end of the slave so reads see
+        // EOF once the process exits
+        let child = pty_pair
             .slave
             .spawn_command(cmd)
             .context("Failed to spawn command")?;
+        drop(pty_pair.slave);
+
+        let transcript = config
+            .transcript_path
+            .as_ref()
+            .map(TranscriptWriter::open)
+            .transpose()?;
 
         Ok(Self {
             pty_system,
             writer: Arc::new(RwLock::new(writer)),
             reader: Arc::new(RwLock::new(reader)),
+            resizer: PtyResizeHandle {
+                master: Arc::new(Mutex::new(pty_pair.master)),
+            },
             config,
+            transcript,
+            child,
         })
     }
 
@@ -108,6 +212,9 @@ impl PtySession {
             Ok(0) => Ok(None), // EOF
             Ok(n) => {
                 buffer.truncate(n);
+                if let Some(transcript) = &self.transcript {
+                    transcript.record(&buffer);
+                }
                 Ok(Some(PtyOutput {
                     data: buffer,
                     is_stderr: false,
@@ -127,14 +234,66 @@ impl PtySession {
     }
 
     /// Resize PTY
-    pub async fn resize(&self, _cols: u16, _rows: u16) -> Result<()> {
-        // Note: portable_pty doesn't expose resize directly on the master
-        // This would need additional implementation
-        Ok(())
+    pub async fn resize(&self, cols: u16, rows: u16) -> Result<()> {
+        self.resizer.resize(cols, rows)
+    }
+
+    /// Get a cloneable resize handle (e.g. for a SIGWINCH listener)
+    pub fn resize_handle(&self) -> PtyResizeHandle {
+        self.resizer.clone()
     }
 
     /// Get configuration
     pub fn config(&self) -> &PtyConfig {
         &self.config
     }
+
+    /// Transcript file path (if capture is enabled)
+    pub fn transcript_path(&self) -> Option<&std::path::Path> {
+        self.transcript.as_ref().map(|t| t.path())
+    }
+
+    /// Process ID of the wrapped command
+    pub fn process_id(&self) -> Option<u32> {
+        self.child.process_id()
+    }
+
+    /// Take the session apart into owned handles
+    pub(super) fn into_parts(self) -> Result<SessionParts> {
+        let reader = Arc::try_unwrap(self.reader)
+            .map_err(|_| anyhow::anyhow!("PTY reader is still shared"))?
+            .into_inner();
+        let writer = Arc::try_unwrap(self.writer)
+            .map_err(|_| anyhow::anyhow!("PTY writer is still shared"))?
+            .into_inner();
+
+        Ok(SessionParts {
+            reader,
+            writer,
+            resizer: self.resizer,
+            child: self.child,
+            transcript: self.transcript,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_resize_propagates_to_pty() {
+        let config = PtyConfig {
+            cols: 80,
+            rows: 24,
+            command: "cat".to_string(),
+            ..Default::default()
+        };
+        let session = PtySession::new(config).unwrap();
+        assert_eq!(session.resize_handle().size().unwrap(), (80, 24));
+
+        session.resize(120, 40).await.unwrap();
+        assert_eq!(session.resize_handle().size().unwrap(), (120, 40));
+    }
 }
@@ -0,0 +1,218 @@
+//! Raw session transcript capture
+//!
+//! Tees PTY output to a file on a background thread so slow disk writes
+//! never stall the PTY read loop. Each chunk is stored as a header line
+//! followed by the exact bytes read, so ANSI sequences survive untouched:
+//!
+//! ```text
+//! [2025-11-09T12:00:00.123456Z] 42
+//! <42 raw bytes>
+//! ```
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use std::fs::{File, OpenOptions};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, Sender, TryRecvError};
+use std::thread::JoinHandle;
+use tracing::warn;
+
+/// A single captured output chunk
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TranscriptChunk {
+    /// When the chunk was read from the PTY
+    pub timestamp: DateTime<Utc>,
+    /// Raw bytes, including any escape sequences
+    pub data: Vec<u8>,
+}
+
+/// Asynchronous transcript writer
+pub struct TranscriptWriter {
+    /// Destination file
+    path: PathBuf,
+    /// Channel to the writer thread (dropped on close)
+    tx: Option<Sender<TranscriptChunk>>,
+    /// Writer thread handle
+    handle: Option<JoinHandle<()>>,
+}
+
+impl TranscriptWriter {
+    /// Open (append) the transcript file and start the writer thread
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent).with_context(|| {
+                format!("Failed to create transcript directory {}", parent.display())
+            })?;
+        }
+
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .with_context(|| format!("Failed to open transcript {}", path.display()))?;
+
+        let (tx, rx) = mpsc::channel();
+        let handle = std::thread::Builder::new()
+            .name("pty-transcript".to_string())
+            .spawn(move || Self::run(file, rx))
+            .context("Failed to spawn transcript writer")?;
+
+        Ok(Self {
+            path,
+            tx: Some(tx),
+            handle: Some(handle),
+        })
+    }
+
+    /// Queue a chunk for writing (never blocks on I/O)
+    pub fn record(&self, data: &[u8]) {
+        if let Some(tx) = &self.tx {
+            let chunk = TranscriptChunk {
+                timestamp: Utc::now(),
+                data: data.to_vec(),
+            };
+            if tx.send(chunk).is_err() {
+                warn!("Transcript writer stopped; dropping output chunk");
+            }
+        }
+    }
+
+    /// Transcript file path
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Flush pending chunks and stop the writer thread
+    pub fn close(&mut self) {
+        self.tx.take();
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+
+    /// Writer loop: drain queued chunks, flushing whenever the queue is idle
+    fn run(file: File, rx: Receiver<TranscriptChunk>) {
+        let mut out = BufWriter::new(file);
+        loop {
+            let chunk = match rx.try_recv() {
+                Ok(chunk) => chunk,
+                Err(TryRecvError::Empty) => {
+                    if let Err(e) = out.flush() {
+                        warn!("Failed to flush transcript: {}", e);
+                    }
+                    match rx.recv() {
+                        Ok(chunk) => chunk,
+                        Err(_) => break,
+                    }
+                }
+                Err(TryRecvError::Disconnected) => break,
+            };
+
+            if let Err(e) = write_chunk(&mut out, &chunk) {
+                warn!("Failed to write transcript chunk: {}", e);
+            }
+        }
+
+        if let Err(e) = out.flush() {
+            warn!("Failed to flush transcript: {}", e);
+        }
+    }
+}
+
+impl Drop for TranscriptWriter {
+    fn drop(&mut self) {
+        self.close();
+    }
+}
+
+fn write_chunk(out: &mut impl Write, chunk: &TranscriptChunk) -> std::io::Result<()> {
+    writeln!(
+        out,
+        "[{}] {}",
+        chunk
+            .timestamp
+            .to_rfc3339_opts(chrono::SecondsFormat::Micros, true),
+        chunk.data.len()
+    )?;
+    out.write_all(&chunk.data)?;
+    out.write_all(b"\n")
+}
+
+/// Read a transcript file back into its chunks
+pub fn read_transcript(path: &Path) -> Result<Vec<TranscriptChunk>> {
+    let bytes = std::fs::read(path)
+        .with_context(|| format!("Failed to read transcript {}", path.display()))?;
+
+    let mut chunks = Vec::new();
+    let mut pos = 0;
+    while pos < bytes.len() {
+        let header_end = bytes[pos..]
+            .iter()
+            .position(|&b| b == b'\n')
+            .map(|i| pos + i)
+            .context("Truncated transcript header")?;
+        let header =
+            std::str::from_utf8(&bytes[pos..header_end]).context("Invalid transcript header")?;
+
+        let (stamp, len) = header
+            .strip_prefix('[')
+            .and_then(|h| h.split_once("] "))
+            .context("Malformed transcript header")?;
+        let timestamp = DateTime::parse_from_rfc3339(stamp)
+            .context("Invalid transcript timestamp")?
+            .with_timezone(&Utc);
+        let len: usize = len.parse().context("Invalid transcript chunk length")?;
+
+        let data_start = header_end + 1;
+        let data_end = data_start + len;
+        if data_end > bytes.len() {
+            anyhow::bail!("Truncated transcript chunk");
+        }
+
+        chunks.push(TranscriptChunk {
+            timestamp,
+            data: bytes[data_start..data_end].to_vec(),
+        });
+        pos = data_end + 1;
+    }
+
+    Ok(chunks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_transcript_round_trip_preserves_ansi() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("logs/session.transcript");
+
+        let mut writer = TranscriptWriter::open(&path).unwrap();
+        writer.record(b"\x1b[31mred\x1b[0m\r\n");
+        writer.record(b"plain [bracket] 12\n\n");
+        writer.close();
+
+        let chunks = read_transcript(&path).unwrap();
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].data, b"\x1b[31mred\x1b[0m\r\n");
+        assert_eq!(chunks[1].data, b"plain [bracket] 12\n\n");
+        assert!(chunks[0].timestamp <= chunks[1].timestamp);
+    }
+
+    #[test]
+    fn test_transcript_appends() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("session.transcript");
+
+        TranscriptWriter::open(&path).unwrap().record(b"first");
+        TranscriptWriter::open(&path).unwrap().record(b"second");
+
+        let chunks = read_transcript(&path).unwrap();
+        let data: Vec<_> = chunks.iter().map(|c| c.data.as_slice()).collect();
+        assert_eq!(data, vec![b"first".as_slice(), b"second".as_slice()]);
+    }
+}
@@ -1,8 +1,124 @@
 //! Claude Code wrapper with PTY interception
 
-use super::{OutputParser, ParsedChunk, PtyConfig, PtySession};
-use anyhow::Result;
+use super::session::SessionParts;
+use super::{AgentMarker, OutputParser, ParsedChunk, PtyConfig, PtySession};
+use crate::api::{Event, EventBroadcaster};
+use anyhow::{Context, Result};
+use regex::Regex;
+use std::io::{IsTerminal, Read, Write};
+use std::sync::OnceLock;
+use std::time::Duration;
 use tokio::sync::mpsc;
+use tracing::debug;
+
+/// How long [`AttachedSession::wait`] lets output drain after the process
+/// exits; a grandchild holding the terminal open must not stall the caller
+const OUTPUT_DRAIN_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Translates agent markers detected in PTY output into API events
+///
+/// Mapping:
+/// - A line naming a new agent starts it (`AgentStarted`, with the line as task)
+///   and completes the previously active agent (`AgentCompleted`).
+/// - A line naming an agent and a completion word (done/finished/completed)
+///   completes that agent.
+/// - A line naming an agent that is also an error line fails it (`AgentFailed`).
+/// - [`AgentMarker::Unknown`] is ignored; bare mentions of "agent" are too noisy.
+pub struct AgentEventEmitter {
+    /// Event broadcaster
+    broadcaster: EventBroadcaster,
+    /// Agent currently considered active
+    active: Option<AgentMarker>,
+}
+
+impl AgentEventEmitter {
+    /// Create emitter publishing to `broadcaster`
+    pub fn new(broadcaster: EventBroadcaster) -> Self {
+        Self {
+            broadcaster,
+            active: None,
+        }
+    }
+
+    /// Agent ID used in emitted events
+    pub fn agent_id(marker: AgentMarker) -> String {
+        marker.display_name().to_lowercase()
+    }
+
+    /// Map a parsed chunk to events without broadcasting them
+    pub fn map_chunk(&mut self, chunk: &ParsedChunk) -> Vec<Event> {
+        let marker = match chunk.agent {
+            Some(AgentMarker::Unknown) | None => return Vec::new(),
+            Some(marker) => marker,
+        };
+        let agent_id = Self::agent_id(marker);
+        let text = chunk.text.trim().to_string();
+        let mut events = Vec::new();
+
+        if chunk.is_error {
+            if self.active == Some(marker) {
+                self.active = None;
+            }
+            events.push(Event::agent_failed(agent_id, text));
+            return events;
+        }
+
+        if Self::is_completion_line(&text) {
+            if self.active == Some(marker) {
+                self.active = None;
+            }
+            events.push(Event::agent_completed(agent_id, text));
+            return events;
+        }
+
+        if self.active != Some(marker) {
+            if let Some(previous) = self.active.take() {
+                events.push(Event::agent_completed(
+                    Self::agent_id(previous),
+                    format!("Handed off to {}", marker.display_name()),
+                ));
+            }
+            self.active = Some(marker);
+            events.push(Event::agent_started_with_task(agent_id, text));
+        }
+
+        events
+    }
+
+    /// Map and broadcast a parsed chunk, returning the number of events sent
+    pub fn emit(&mut self, chunk: &ParsedChunk) -> usize {
+        let events = self.map_chunk(chunk);
+        let count = events.len();
+        for event in events {
+            self.send(event);
+        }
+        count
+    }
+
+    /// Complete the active agent (call when the wrapped session ends)
+    pub fn finish(&mut self) {
+        if let Some(active) = self.active.take() {
+            self.send(Event::agent_completed(
+                Self::agent_id(active),
+                "Session ended".to_string(),
+            ));
+        }
+    }
+
+    fn send(&self, event: Event) {
+        // No subscribers is not an error; the dashboard may not be connected
+        if let Err(e) = self.broadcaster.broadcast(event) {
+            debug!("No subscribers for PTY agent event: {}", e);
+        }
+    }
+
+    fn is_completion_line(line: &str) -> bool {
+        static COMPLETION_REGEX: OnceLock<Regex> = OnceLock::new();
+        let regex = COMPLETION_REGEX
+            .get_or_init(|| Regex::new(r"(?i)\b(completed?|finished|done)\b").unwrap());
+        regex.is_match(line)
+    }
+}
 
 /// Claude Code wrapper
 pub struct ClaudeCodeWrapper {
@@ -14,6 +130,8 @@ pub struct ClaudeCodeWrapper {
     output_tx: mpsc::UnboundedSender<ParsedChunk>,
     /// Output receiver
     output_rx: Option<mpsc::UnboundedReceiver<ParsedChunk>>,
+    /// Agent event emitter (if connected to the API)
+    agent_events: Option<AgentEventEmitter>,
 }
 
 impl ClaudeCodeWrapper {
@@ -28,9 +146,16 @@ impl ClaudeCodeWrapper {
             parser,
             output_tx,
             output_rx: Some(output_rx),
+            agent_events: None,
         })
     }
 
+    /// Broadcast detected agent activity as API events
+    pub fn with_event_broadcaster(mut self, broadcaster: EventBroadcaster) -> Self {
+        self.agent_events = Some(AgentEventEmitter::new(broadcaster));
+        self
+    }
+
     /// Take the output receiver (can only be called once)
     pub fn take_output_receiver(&mut self) -> Option<mpsc::UnboundedReceiver<ParsedChunk>> {
         self.output_rx.take()
@@ -46,14 +171,262 @@ impl ClaudeCodeWrapper {
         if let Some(output) = self.session.read().await? {
             let chunks = self.parser.parse(&output.data);
             for chunk in chunks {
+                if let Some(emitter) = &mut self.agent_events {
+                    emitter.emit(&chunk);
+                }
                 let _ = self.output_tx.send(chunk);
             }
         }
         Ok(())
     }
 
+    /// Complete any active agent (call when the session ends)
+    pub fn finish_agents(&mut self) {
+        if let Some(emitter) = &mut self.agent_events {
+            emitter.finish();
+        }
+    }
+
     /// Resize terminal
     pub async fn resize(&self, cols: u16, rows: u16) -> Result<()> {
         self.session.resize(cols, rows).await
     }
+
+    /// Forward terminal resizes (SIGWINCH) to the wrapped process
+    #[cfg(unix)]
+    pub fn forward_terminal_resizes(&self) -> Result<tokio::task::JoinHandle<()>> {
+        self.session.resize_handle().forward_sigwinch()
+    }
+
+    /// Run attached to the current terminal instead of being polled
+    ///
+    /// Stdin is forwarded to the wrapped process and its output echoed to
+    /// stdout byte for byte, so the session looks as it would unwrapped,
+    /// while the transcript is recorded, agent events are broadcast and
+    /// parsed chunks still reach the output receiver. The terminal is in raw
+    /// mode (when stdin is a terminal) until the returned session is dropped.
+    pub fn attach(self) -> Result<AttachedSession> {
+        let SessionParts {
+            mut reader,
+            mut writer,
+            resizer,
+            child,
+            transcript,
+        } = self.session.into_parts()?;
+        let mut parser = self.parser;
+        let mut agent_events = self.agent_events;
+        let output_tx = self.output_tx;
+
+        let (done_tx, output_done) = std::sync::mpsc::channel();
+        std::thread::Builder::new()
+            .name("pty-output".to_string())
+            .spawn(move || {
+                let mut stdout = std::io::stdout();
+                let mut buffer = [0u8; 4096];
+                loop {
+                    let data = match reader.read(&mut buffer) {
+                        Ok(0) | Err(_) => break,
+                        Ok(n) => &buffer[..n],
+                    };
+                    if let Some(transcript) = &transcript {
+                        transcript.record(data);
+                    }
+                    let _ = stdout.write_all(data).and_then(|()| stdout.flush());
+                    for chunk in parser.parse(data) {
+                        if let Some(emitter) = &mut agent_events {
+                            emitter.emit(&chunk);
+                        }
+                        let _ = output_tx.send(chunk);
+                    }
+                }
+                if let Some(emitter) = &mut agent_events {
+                    emitter.finish();
+                }
+                // Flush the transcript before reporting the output drained
+                drop(transcript);
+                let _ = done_tx.send(());
+            })
+            .context("Failed to spawn PTY output thread")?;
+
+        // Blocks on stdin for the rest of the process; it stops at the first
+        // write after the wrapped process is gone
+        std::thread::Builder::new()
+            .name("pty-input".to_string())
+            .spawn(move || {
+                let mut stdin = std::io::stdin();
+                let mut buffer = [0u8; 1024];
+                loop {
+                    let data = match stdin.read(&mut buffer) {
+                        Ok(0) | Err(_) => break,
+                        Ok(n) => &buffer[..n],
+                    };
+                    if writer
+                        .write_all(data)
+                        .and_then(|()| writer.flush())
+                        .is_err()
+                    {
+                        break;
+                    }
+                }
+            })
+            .context("Failed to spawn PTY input thread")?;
+
+        #[cfg(unix)]
+        let resize_forwarder = match tokio::runtime::Handle::try_current() {
+            Ok(_) => resizer.forward_sigwinch().ok(),
+            Err(_) => None,
+        };
+        #[cfg(not(unix))]
+        drop(resizer);
+
+        let raw_mode =
+            std::io::stdin().is_terminal() && crossterm::terminal::enable_raw_mode().is_ok();
+
+        Ok(AttachedSession {
+            child,
+            output_done,
+            raw_mode,
+            #[cfg(unix)]
+            resize_forwarder,
+        })
+    }
+}
+
+/// Wrapped process running attached to the current terminal
+///
+/// Created by [`ClaudeCodeWrapper::attach`]. Dropping it restores the
+/// terminal mode; it does not stop the process.
+pub struct AttachedSession {
+    /// Wrapped process
+    child: Box<dyn portable_pty::Child + Send + Sync>,
+    /// Signalled once the output thread has drained the PTY
+    output_done: std::sync::mpsc::Receiver<()>,
+    /// Whether `attach` switched the terminal to raw mode
+    raw_mode: bool,
+    /// SIGWINCH listener propagating terminal resizes
+    #[cfg(unix)]
+    resize_forwarder: Option<tokio::task::JoinHandle<()>>,
+}
+
+impl AttachedSession {
+    /// Process ID of the wrapped command
+    pub fn process_id(&self) -> Option<u32> {
+        self.child.process_id()
+    }
+
+    /// Block until the wrapped command exits and its output is drained
+    pub fn wait(mut self) -> std::io::Result<portable_pty::ExitStatus> {
+        let status = self.child.wait();
+        let _ = self.output_done.recv_timeout(OUTPUT_DRAIN_TIMEOUT);
+        status
+    }
+}
+
+impl Drop for AttachedSession {
+    fn drop(&mut self) {
+        if self.raw_mode {
+            let _ = crossterm::terminal::disable_raw_mode();
+        }
+        #[cfg(unix)]
+        if let Some(forwarder) = self.resize_forwarder.take() {
+            forwarder.abort();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::EventType;
+
+    fn summarize(event: &Event) -> (String, String) {
+        match &event.event_type {
+            EventType::AgentStarted { agent_id, .. } => ("started".into(), agent_id.clone()),
+            EventType::AgentCompleted { agent_id, .. } => ("completed".into(), agent_id.clone()),
+            EventType::AgentFailed { agent_id, .. } => ("failed".into(), agent_id.clone()),
+            other => panic!("Unexpected event: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_canned_stream_broadcasts_agent_events() {
+        let broadcaster = EventBroadcaster::new(32);
+        let mut rx = broadcaster.subscribe();
+        let mut emitter = AgentEventEmitter::new(broadcaster);
+        let mut parser = OutputParser::new();
+
+        let stream: &[&[u8]] = &[
+            b"Orchestrator: planning work\n",
+            b"plain output line\nOrchestrator: still planning\n",
+            b"Executor: implementing feature",
+            b"\nExecutor done with task\n",
+            b"Reviewer: checking results\n",
+            b"Reviewer error: tests fail\n",
+            b"Optimizer: loading skills\n",
+        ];
+        for data in stream {
+            for chunk in parser.parse(data) {
+                emitter.emit(&chunk);
+            }
+        }
+        emitter.finish();
+
+        let mut received = Vec::new();
+        while let Ok(event) = rx.try_recv() {
+            received.push(summarize(&event));
+        }
+
+        let expected: Vec<(String, String)> = [
+            ("started", "orchestrator"),
+            ("completed", "orchestrator"),
+            ("started", "executor"),
+            ("completed", "executor"),
+            ("started", "reviewer"),
+            ("failed", "reviewer"),
+            ("started", "optimizer"),
+            ("completed", "optimizer"),
+        ]
+        .iter()
+        .map(|(kind, id)| (kind.to_string(), id.to_string()))
+        .collect();
+
+        assert_eq!(received, expected);
+    }
+
+    #[test]
+    fn test_unknown_agent_ignored() {
+        let mut emitter = AgentEventEmitter::new(EventBroadcaster::new(4));
+        let chunk = ParsedChunk {
+            text: "some agent said hi".to_string(),
+            agent: Some(AgentMarker::Unknown),
+            is_error: false,
+            is_tool_use: false,
+        };
+        assert!(emitter.map_chunk(&chunk).is_empty());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_attached_session_records_transcript() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let path = temp.path().join("session.transcript");
+        let config = PtyConfig {
+            command: "printf".to_string(),
+            args: vec!["\x1b[1mExecutor: working\x1b[0m\n".to_string()],
+            transcript_path: Some(path.clone()),
+            ..Default::default()
+        };
+
+        let session = ClaudeCodeWrapper::new(config).unwrap().attach().unwrap();
+        assert!(session.process_id().is_some());
+        assert!(session.wait().unwrap().success());
+
+        let recorded: Vec<u8> = crate::pty::read_transcript(&path)
+            .unwrap()
+            .into_iter()
+            .flat_map(|chunk| chunk.data)
+            .collect();
+        // Escape sequences are kept as written
+        assert!(String::from_utf8_lossy(&recorded).contains("\x1b[1mExecutor: working\x1b[0m"));
+    }
 }
@@ -0,0 +1,252 @@
+//! PyEventSubscriber - Stream orchestration events into Python.
+//!
+//! Events are forwarded from an `EventBroadcaster` (in-process) or from the
+//! API server's SSE stream (cross-process) onto a channel drained by a
+//! background thread. Python iterates the channel without holding the GIL
+//! while waiting, so other Python threads keep running.
+//!
+//! ```python
+//! from mnemosyne_core import PyEventSubscriber
+//!
+//! with PyEventSubscriber("http://localhost:3000") as subscriber:
+//!     for event in subscriber:
+//!         if event["type"] == "agent_completed":
+//!             print(event["agent_id"], event["result"])
+//! ```
+
+use pyo3::prelude::*;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::Duration;
+use tokio::sync::{broadcast, oneshot};
+use tracing::{debug, warn};
+
+use super::json::json_to_py;
+use crate::api::{Event, EventBroadcaster};
+
+/// How often a blocked iterator wakes up to check for Python signals (Ctrl-C).
+const SIGNAL_CHECK_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Python iterator over orchestration events (as dicts).
+///
+/// Closing the subscriber (or leaving a `with` block) unsubscribes and ends
+/// iteration once already-received events are drained.
+#[pyclass]
+pub struct PyEventSubscriber {
+    /// Events forwarded by the background thread
+    rx: Arc<Mutex<Receiver<serde_json::Value>>>,
+
+    /// Signals the background thread to unsubscribe
+    shutdown: Option<oneshot::Sender<()>>,
+
+    /// Background forwarding thread
+    worker: Option<JoinHandle<()>>,
+}
+
+#[pymethods]
+impl PyEventSubscriber {
+    /// Subscribe to the API server's event stream.
+    ///
+    /// Args:
+    ///     api_url: API server base URL (default: http://localhost:3000)
+    #[new]
+    #[pyo3(signature = (api_url=None))]
+    fn new(api_url: Option<String>) -> PyResult<Self> {
+        let url = format!(
+            "{}/events",
+            api_url
+                .unwrap_or_else(|| "http://localhost:3000".to_string())
+                .trim_end_matches('/')
+        );
+
+        Self::spawn(move |tx, shutdown| forward_sse(url, tx, shutdown))
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))
+    }
+
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&self, py: Python<'_>) -> PyResult<Option<PyObject>> {
+        loop {
+            match self.recv(py, SIGNAL_CHECK_INTERVAL) {
+                Ok(value) => return json_to_py(py, &value).map(Some),
+                Err(RecvTimeoutError::Timeout) => py.check_signals()?,
+                Err(RecvTimeoutError::Disconnected) => return Ok(None),
+            }
+        }
+    }
+
+    /// Wait for the next event.
+    ///
+    /// Args:
+    ///     timeout: Seconds to wait (default: 1.0)
+    ///
+    /// Returns:
+    ///     dict | None: The event, or None on timeout or after close
+    #[pyo3(signature = (timeout=1.0))]
+    fn next_event(&self, py: Python<'_>, timeout: f64) -> PyResult<Option<PyObject>> {
+        match self.recv(py, Duration::from_secs_f64(timeout.max(0.0))) {
+            Ok(value) => json_to_py(py, &value).map(Some),
+            Err(_) => Ok(None),
+        }
+    }
+
+    /// Unsubscribe and stop the background thread.
+    fn close(&mut self, py: Python<'_>) {
+        if let Some(shutdown) = self.shutdown.take() {
+            let _ = shutdown.send(());
+        }
+        if let Some(worker) = self.worker.take() {
+            py.allow_threads(move || {
+                let _ = worker.join();
+            });
+        }
+    }
+
+    /// Whether the subscriber is still receiving events.
+    #[getter]
+    fn is_open(&self) -> bool {
+        self.worker.as_ref().is_some_and(|w| !w.is_finished())
+    }
+
+    fn __enter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    #[pyo3(signature = (*_args))]
+    fn __exit__(&mut self, py: Python<'_>, _args: &Bound<'_, pyo3::types::PyTuple>) -> bool {
+        self.close(py);
+        false
+    }
+}
+
+impl PyEventSubscriber {
+    /// Subscribe to an in-process broadcaster.
+    pub fn from_broadcaster(broadcaster: &EventBroadcaster) -> std::io::Result<Self> {
+        let events = broadcaster.subscribe();
+        Self::spawn(move |tx, shutdown| forward_broadcast(events, tx, shutdown))
+    }
+
+    fn spawn<F>(forward: F) -> std::io::Result<Self>
+    where
+        F: FnOnce(Sender<serde_json::Value>, oneshot::Receiver<()>) + Send + 'static,
+    {
+        let (tx, rx) = mpsc::channel();
+        let (shutdown_tx, shutdown_rx) = oneshot::channel();
+
+        let worker = std::thread::Builder::new()
+            .name("py-event-subscriber".to_string())
+            .spawn(move || forward(tx, shutdown_rx))?;
+
+        Ok(Self {
+            rx: Arc::new(Mutex::new(rx)),
+            shutdown: Some(shutdown_tx),
+            worker: Some(worker),
+        })
+    }
+
+    /// Receive with the GIL released.
+    fn recv(
+        &self,
+        py: Python<'_>,
+        timeout: Duration,
+    ) -> Result<serde_json::Value, RecvTimeoutError> {
+        let rx = Arc::clone(&self.rx);
+        py.allow_threads(move || match rx.lock() {
+            Ok(rx) => rx.recv_timeout(timeout),
+            Err(_) => Err(RecvTimeoutError::Disconnected),
+        })
+    }
+}
+
+impl Drop for PyEventSubscriber {
+    fn drop(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            let _ = shutdown.send(());
+        }
+    }
+}
+
+/// Run a forwarding future on a dedicated single-threaded runtime.
+fn run_forwarder<F: std::future::Future<Output = ()>>(future: F) {
+    match tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+    {
+        Ok(runtime) => runtime.block_on(future),
+        Err(e) => warn!("Event subscriber: failed to start runtime: {}", e),
+    }
+}
+
+fn forward_broadcast(
+    mut events: broadcast::Receiver<Event>,
+    tx: Sender<serde_json::Value>,
+    mut shutdown: oneshot::Receiver<()>,
+) {
+    run_forwarder(async move {
+        loop {
+            tokio::select! {
+                _ = &mut shutdown => break,
+                received = events.recv() => match received {
+                    Ok(event) => match serde_json::to_value(&event) {
+                        Ok(value) => {
+                            if tx.send(value).is_err() {
+                                break;
+                            }
+                        }
+                        Err(e) => warn!("Event subscriber: failed to serialize event: {}", e),
+                    },
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        warn!("Event subscriber lagged, skipped {} events", skipped);
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                },
+            }
+        }
+        debug!("Event subscriber: broadcaster forwarding stopped");
+    });
+}
+
+fn forward_sse(url: String, tx: Sender<serde_json::Value>, mut shutdown: oneshot::Receiver<()>) {
+    use es::Client;
+    use eventsource_client as es;
+    use tokio_stream::StreamExt;
+
+    run_forwarder(async move {
+        let client = match es::ClientBuilder::for_url(&url) {
+            Ok(builder) => builder.build(),
+            Err(e) => {
+                warn!("Event subscriber: invalid URL {}: {}", url, e);
+                return;
+            }
+        };
+        let mut stream = client.stream();
+
+        loop {
+            tokio::select! {
+                _ = &mut shutdown => break,
+                item = stream.next() => match item {
+                    Some(Ok(es::SSE::Event(event))) => {
+                        match serde_json::from_str::<serde_json::Value>(&event.data) {
+                            Ok(value) => {
+                                if tx.send(value).is_err() {
+                                    break;
+                                }
+                            }
+                            Err(e) => debug!("Event subscriber: skipping non-JSON event: {}", e),
+                        }
+                    }
+                    Some(Ok(_)) => {}
+                    Some(Err(e)) => {
+                        warn!("Event subscriber: stream error: {}", e);
+                        break;
+                    }
+                    None => break,
+                },
+            }
+        }
+        debug!("Event subscriber: SSE forwarding stopped");
+    });
+}
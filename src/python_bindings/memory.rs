@@ -5,7 +5,9 @@
 
 use crate::types::{
     MemoryId as RustMemoryId, MemoryNote as RustMemoryNote, Namespace as RustNamespace,
+    SearchResult as RustSearchResult,
 };
+use crate::utils::string::snippet_around;
 use pyo3::prelude::*;
 
 /// Python wrapper for MemoryId.
@@ -213,3 +215,46 @@ impl From<&RustMemoryNote> for PyMemory {
         }
     }
 }
+
+/// Maximum characters in a search result snippet.
+const SNIPPET_CHARS: usize = 160;
+
+/// Python wrapper for a scored search result.
+#[pyclass]
+#[derive(Clone)]
+pub struct PySearchResult {
+    #[pyo3(get)]
+    pub memory: PyMemory,
+
+    #[pyo3(get)]
+    pub score: f32,
+
+    #[pyo3(get)]
+    pub matched_snippet: String,
+
+    #[pyo3(get)]
+    pub match_reason: String,
+}
+
+#[pymethods]
+impl PySearchResult {
+    fn __repr__(&self) -> String {
+        format!(
+            "SearchResult(id='{}', score={:.3})",
+            &self.memory.id[..8],
+            self.score
+        )
+    }
+}
+
+impl PySearchResult {
+    /// Build from a Rust search result, extracting a snippet around the query.
+    pub fn from_result(result: &RustSearchResult, query: &str) -> Self {
+        PySearchResult {
+            memory: PyMemory::from(&result.memory),
+            score: result.score,
+            matched_snippet: snippet_around(&result.memory.content, query, SNIPPET_CHARS),
+            match_reason: result.match_reason.clone(),
+        }
+    }
+}
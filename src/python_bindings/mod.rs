@@ -5,16 +5,20 @@
 //! - Low-latency storage operations (<1ms vs 20-50ms subprocess)
 //! - High-frequency context monitoring (10ms polling)
 //! - Shared memory coordination between agents
+//! - Streaming orchestration events without polling
 
 mod coordination;
 mod evaluation;
+mod events;
+mod json;
 mod memory;
 mod reviewer;
 mod storage;
 
 use pyo3::prelude::*;
 
-// Re-export reviewer helpers for use in Rust code
+// Re-export for use in Rust code (e.g. subscribing to an in-process broadcaster)
+pub use events::PyEventSubscriber;
 pub use reviewer::{collect_implementation_from_memories, execution_memories_to_python_format};
 
 /// PyO3 module initialization.
@@ -29,10 +33,14 @@ fn mnemosyne_core(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<memory::PyMemory>()?;
     m.add_class::<memory::PyMemoryId>()?;
     m.add_class::<memory::PyNamespace>()?;
+    m.add_class::<memory::PySearchResult>()?;
 
     // Coordination primitives
     m.add_class::<coordination::PyCoordinator>()?;
 
+    // Event streaming
+    m.add_class::<events::PyEventSubscriber>()?;
+
     // Evaluation system
     m.add_class::<evaluation::PyFeedbackCollector>()?;
     m.add_class::<evaluation::PyFeatureExtractor>()?;
@@ -7,8 +7,13 @@ use pyo3::prelude::*;
 use pyo3::types::PyDict;
 use pyo3::Bound;
 use std::sync::Arc;
-use tokio::sync::Mutex;
 
+use super::json::{json_to_py, py_to_json};
+use super::memory::PySearchResult;
+use crate::evolution::{
+    ArchivalJob, ConsolidationJob, EvolutionConfig, EvolutionJob, ImportanceRecalibrator,
+    LinkDecayJob,
+};
 use crate::storage::{
     libsql::{ConnectionMode, LibsqlStorage},
     MemorySortOrder, StorageBackend,
@@ -17,11 +22,12 @@ use crate::types::{MemoryId, MemoryNote, Namespace};
 
 /// Python wrapper for Mnemosyne storage.
 ///
-/// Thread-safe via Arc<Mutex<LibsqlStorage>>. Async operations are converted
-/// to sync via tokio runtime for Python compatibility.
+/// Thread-safe via Arc<LibsqlStorage> (the database handle synchronizes
+/// internally). Async operations are converted to sync via tokio runtime for
+/// Python compatibility.
 #[pyclass]
 pub struct PyStorage {
-    inner: Arc<Mutex<LibsqlStorage>>,
+    inner: Arc<LibsqlStorage>,
     runtime: tokio::runtime::Runtime,
 }
 
@@ -47,7 +53,7 @@ impl PyStorage {
             .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
 
         Ok(PyStorage {
-            inner: Arc::new(Mutex::new(storage)),
+            inner: Arc::new(storage),
             runtime,
         })
     }
@@ -65,7 +71,7 @@ impl PyStorage {
 
         self.runtime
             .block_on(async {
-                let storage = self.inner.lock().await;
+                let storage = &self.inner;
                 storage.store_memory(&note).await
             })
             .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
@@ -85,7 +91,7 @@ impl PyStorage {
             .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
 
         let result = self.runtime.block_on(async {
-            let storage = self.inner.lock().await;
+            let storage = &self.inner;
             storage.get_memory(memory_id).await
         });
 
@@ -122,7 +128,7 @@ impl PyStorage {
         let results = self
             .runtime
             .block_on(async {
-                let storage = self.inner.lock().await;
+                let storage = &self.inner;
                 storage
                     .hybrid_search(&query, ns, limit.unwrap_or(10), true)
                     .await
@@ -137,6 +143,50 @@ impl PyStorage {
         })
     }
 
+    /// Recall memories with relevance scores.
+    ///
+    /// Runs hybrid search (keyword + graph + vector) with the GIL released,
+    /// so other Python threads keep running during the query.
+    ///
+    /// Args:
+    ///     query: Search query string
+    ///     namespace: Optional namespace filter (e.g., "project:mnemosyne")
+    ///     limit: Maximum results (default: 10)
+    ///
+    /// Returns:
+    ///     list[PySearchResult]: Results with `memory`, `score`, and `matched_snippet`
+    #[pyo3(signature = (query, namespace=None, limit=10))]
+    fn recall(
+        &self,
+        py: Python<'_>,
+        query: String,
+        namespace: Option<String>,
+        limit: usize,
+    ) -> PyResult<Vec<PySearchResult>> {
+        let ns = namespace
+            .as_ref()
+            .map(|s| parse_namespace(s))
+            .transpose()
+            .map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
+
+        let handle = self.runtime.handle().clone();
+        let inner = Arc::clone(&self.inner);
+        let search_query = query.clone();
+
+        let results = py
+            .allow_threads(move || {
+                handle.block_on(
+                    async move { inner.hybrid_search(&search_query, ns, limit, true).await },
+                )
+            })
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+
+        Ok(results
+            .iter()
+            .map(|r| PySearchResult::from_result(r, &query))
+            .collect())
+    }
+
     /// List recent memories.
     ///
     /// Args:
@@ -160,7 +210,7 @@ impl PyStorage {
         let results = self
             .runtime
             .block_on(async {
-                let storage = self.inner.lock().await;
+                let storage = &self.inner;
                 storage
                     .list_memories(ns, limit.unwrap_or(20), MemorySortOrder::Recent)
                     .await
@@ -175,6 +225,76 @@ impl PyStorage {
         })
     }
 
+    /// Run a single evolution job against this storage.
+    ///
+    /// Args:
+    ///     job: "importance", "links", "archival", or "consolidation"
+    ///     config: Optional overrides in evolution config (TOML) shape, e.g.
+    ///         {"importance": {"batch_size": 200}}
+    ///
+    /// Returns:
+    ///     dict: Job report with keys: job, memories_processed, changes_made,
+    ///         duration (ms), errors, error_message
+    ///
+    /// Raises:
+    ///     ValueError: Unknown job name or invalid config
+    #[pyo3(signature = (job, config=None))]
+    fn run_evolution(
+        &self,
+        py: Python<'_>,
+        job: &str,
+        config: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<PyObject> {
+        let evolution_config = match config {
+            Some(dict) => EvolutionConfig::default().with_overrides(py_to_json(dict.as_any())?),
+            None => Ok(EvolutionConfig::default()),
+        }
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+        let storage = Arc::clone(&self.inner);
+        let (runner, job_config): (Box<dyn EvolutionJob>, _) = match job {
+            "importance" => (
+                Box::new(ImportanceRecalibrator::new(storage)),
+                evolution_config.importance,
+            ),
+            "links" => (
+                Box::new(LinkDecayJob::new(storage)),
+                evolution_config.link_decay,
+            ),
+            "archival" => (
+                Box::new(ArchivalJob::new(storage)),
+                evolution_config.archival,
+            ),
+            "consolidation" => (
+                Box::new(ConsolidationJob::with_config(
+                    storage,
+                    None,
+                    evolution_config.consolidation_config,
+                )),
+                evolution_config.consolidation,
+            ),
+            other => {
+                return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                    "Unknown evolution job '{}'. Expected one of: importance, links, archival, consolidation",
+                    other
+                )))
+            }
+        };
+
+        let handle = self.runtime.handle().clone();
+        let report = py
+            .allow_threads(move || handle.block_on(async move { runner.run(&job_config).await }))
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+
+        let mut value = serde_json::to_value(&report)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+        if let Some(obj) = value.as_object_mut() {
+            obj.insert("job".to_string(), serde_json::Value::from(job));
+        }
+
+        json_to_py(py, &value)
+    }
+
     /// Get context statistics.
     ///
     /// Returns:
@@ -190,7 +310,7 @@ impl PyStorage {
         let count = self
             .runtime
             .block_on(async {
-                let storage = self.inner.lock().await;
+                let storage = &self.inner;
                 storage.count_memories(ns).await
             })
             .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
@@ -88,6 +88,8 @@ pub fn memory_type_to_proto(mt: InternalMemoryType) -> i32 {
         InternalMemoryType::TaskBreakdown => generated::MemoryType::TaskBreakdown,
         InternalMemoryType::QualityChecklist => generated::MemoryType::QualityChecklist,
         InternalMemoryType::Clarification => generated::MemoryType::Clarification,
+        // No wire representation for team-defined types
+        InternalMemoryType::Custom(_) => generated::MemoryType::Unspecified,
     };
     proto_type as i32
 }
@@ -9,10 +9,12 @@ impl From<MnemosyneError> for Status {
             MnemosyneError::Database(msg) => (Code::Internal, format!("Database error: {}", msg)),
             MnemosyneError::Migration(msg) => (Code::Internal, format!("Migration error: {}", msg)),
             MnemosyneError::LlmApi(msg) => (Code::Unavailable, format!("LLM API error: {}", msg)),
-            MnemosyneError::LlmTimeout(secs) => (
+            MnemosyneError::LlmTimeout(timeout) => (
                 Code::DeadlineExceeded,
-                format!("LLM request timed out after {} seconds", secs),
+                format!("LLM request timed out after {:?}", timeout),
             ),
+            MnemosyneError::LlmCancelled => (Code::Cancelled, err.to_string()),
+            MnemosyneError::LlmBudgetExceeded(_) => (Code::ResourceExhausted, err.to_string()),
             MnemosyneError::LlmRetryExhausted(attempts, msg) => (
                 Code::Unavailable,
                 format!("LLM retry exhausted after {} attempts: {}", attempts, msg),
@@ -8,3 +8,6 @@
 
 // Include generated code from build.rs
 tonic::include_proto!("mnemosyne.v1");
+
+/// Encoded descriptors for all mnemosyne.v1 protos (for server reflection)
+pub const FILE_DESCRIPTOR_SET: &[u8] = tonic::include_file_descriptor_set!("mnemosyne_descriptor");
@@ -1,40 +1,162 @@
 //! gRPC server setup
+//!
+//! Optional features add standard gRPC tooling support:
+//! - `rpc-reflection`: `grpc.reflection.v1` server reflection (grpcurl, Postman)
+//! - `rpc-health`: `grpc.health.v1.Health`, reporting SERVING once storage
+//!   answers a query and NOT_SERVING once shutdown begins
 
+use crate::api::EventBroadcaster;
+use crate::rpc::generated::event_service_server::EventServiceServer;
 use crate::rpc::generated::health_service_server::HealthServiceServer;
 use crate::rpc::generated::memory_service_server::MemoryServiceServer;
-use crate::rpc::services::{HealthServiceImpl, MemoryServiceImpl};
+use crate::rpc::services::{EventServiceImpl, HealthServiceImpl, MemoryServiceImpl};
 use crate::services::LlmService;
 use crate::storage::StorageBackend;
 use anyhow::Result;
+use std::future::Future;
 use std::sync::Arc;
+use tokio::net::TcpListener;
+use tokio_stream::wrappers::TcpListenerStream;
 use tonic::transport::Server;
 use tracing::info;
 
 pub struct RpcServer {
+    #[cfg_attr(not(feature = "rpc-health"), allow(dead_code))]
+    storage: Arc<dyn StorageBackend>,
     health_service: HealthServiceImpl,
     memory_service: MemoryServiceImpl,
+    event_service: EventServiceImpl,
 }
 
 impl RpcServer {
     pub fn new(storage: Arc<dyn StorageBackend>, llm: Option<Arc<LlmService>>) -> Self {
+        // Memory events published by the memory service reach StreamEvents
+        // subscribers through one shared broadcaster
+        let broadcaster = EventBroadcaster::default();
         Self {
+            storage: Arc::clone(&storage),
             health_service: HealthServiceImpl::new(),
-            memory_service: MemoryServiceImpl::new(storage, llm),
+            memory_service: MemoryServiceImpl::new(storage, llm)
+                .with_event_broadcaster(broadcaster.clone()),
+            event_service: EventServiceImpl::new(broadcaster),
         }
     }
 
+    /// Publish and stream events on an existing broadcaster (e.g. the API
+    /// server's) instead of a private one
+    pub fn with_event_broadcaster(mut self, broadcaster: EventBroadcaster) -> Self {
+        self.memory_service = self
+            .memory_service
+            .with_event_broadcaster(broadcaster.clone());
+        self.event_service = EventServiceImpl::new(broadcaster);
+        self
+    }
+
+    /// Serve until Ctrl-C
     pub async fn serve(self, addr: impl Into<String>) -> Result<()> {
+        self.serve_with_shutdown(addr, async {
+            let _ = tokio::signal::ctrl_c().await;
+        })
+        .await
+    }
+
+    /// Serve until `shutdown` resolves
+    pub async fn serve_with_shutdown<F>(self, addr: impl Into<String>, shutdown: F) -> Result<()>
+    where
+        F: Future<Output = ()> + Send,
+    {
         let addr_str = addr.into();
-        let addr = addr_str.parse()?;
+        let addr: std::net::SocketAddr = addr_str.parse()?;
+        let listener = TcpListener::bind(addr).await?;
+
+        self.serve_listener(listener, shutdown).await
+    }
 
-        info!("Starting mnemosyne RPC server on {}", addr);
+    /// Serve on an already-bound listener until `shutdown` resolves
+    pub async fn serve_listener<F>(self, listener: TcpListener, shutdown: F) -> Result<()>
+    where
+        F: Future<Output = ()> + Send,
+    {
+        info!(
+            "Starting mnemosyne RPC server on {}",
+            listener.local_addr()?
+        );
 
-        Server::builder()
+        #[cfg(feature = "rpc-health")]
+        let (mut health_reporter, grpc_health_service) = tonic_health::server::health_reporter();
+
+        #[cfg(feature = "rpc-health")]
+        {
+            let status = match self.storage.count_memories(None).await {
+                Ok(_) => tonic_health::ServingStatus::Serving,
+                Err(e) => {
+                    tracing::warn!("Storage unreachable, reporting NOT_SERVING: {}", e);
+                    tonic_health::ServingStatus::NotServing
+                }
+            };
+            set_health_status(&mut health_reporter, status).await;
+        }
+
+        let router = Server::builder()
             .add_service(HealthServiceServer::new(self.health_service))
             .add_service(MemoryServiceServer::new(self.memory_service))
-            .serve(addr)
+            .add_service(EventServiceServer::new(self.event_service));
+
+        #[cfg(feature = "rpc-health")]
+        let router = router.add_service(grpc_health_service);
+
+        #[cfg(feature = "rpc-reflection")]
+        let router = router.add_service(reflection_service()?);
+
+        router
+            .serve_with_incoming_shutdown(TcpListenerStream::new(listener), async move {
+                shutdown.await;
+                info!("Shutting down mnemosyne RPC server");
+
+                #[cfg(feature = "rpc-health")]
+                set_health_status(
+                    &mut health_reporter,
+                    tonic_health::ServingStatus::NotServing,
+                )
+                .await;
+            })
             .await?;
 
         Ok(())
     }
 }
+
+/// Report `status` for the server as a whole ("") and for each service
+#[cfg(feature = "rpc-health")]
+async fn set_health_status(
+    reporter: &mut tonic_health::server::HealthReporter,
+    status: tonic_health::ServingStatus,
+) {
+    use tonic::server::NamedService;
+
+    for service in [
+        "",
+        <HealthServiceServer<HealthServiceImpl> as NamedService>::NAME,
+        <MemoryServiceServer<MemoryServiceImpl> as NamedService>::NAME,
+        <EventServiceServer<EventServiceImpl> as NamedService>::NAME,
+    ] {
+        reporter.set_service_status(service, status).await;
+    }
+}
+
+/// Reflection over mnemosyne's protos (plus grpc.health.v1 when enabled)
+#[cfg(feature = "rpc-reflection")]
+fn reflection_service() -> Result<
+    tonic_reflection::server::v1::ServerReflectionServer<
+        impl tonic_reflection::server::v1::ServerReflection,
+    >,
+> {
+    let builder = tonic_reflection::server::Builder::configure()
+        .register_encoded_file_descriptor_set(crate::rpc::generated::FILE_DESCRIPTOR_SET);
+
+    #[cfg(feature = "rpc-health")]
+    let builder =
+        builder.register_encoded_file_descriptor_set(tonic_health::pb::FILE_DESCRIPTOR_SET);
+
+    Ok(builder.build_v1()?)
+}
@@ -0,0 +1,112 @@
+//! EventService implementation
+//!
+//! Bridges the in-process `EventBroadcaster` to gRPC server streams. Each
+//! subscriber gets a bounded channel; when a client reads slowly the
+//! forwarding task waits on the channel instead of buffering without limit,
+//! and the broadcast receiver drops (and reports) the oldest events.
+
+use crate::api::{Event, EventBroadcaster};
+use crate::rpc::generated::event_service_server::EventService;
+use crate::rpc::generated::*;
+use tokio::sync::{broadcast, mpsc};
+use tonic::{Request, Response, Status};
+use tracing::{debug, warn};
+
+/// Per-subscriber buffer between the broadcaster and the gRPC stream
+const EVENT_STREAM_BUFFER: usize = 32;
+
+pub struct EventServiceImpl {
+    broadcaster: EventBroadcaster,
+}
+
+impl EventServiceImpl {
+    pub fn new(broadcaster: EventBroadcaster) -> Self {
+        Self { broadcaster }
+    }
+}
+
+/// Convert an API event into its wire form
+fn event_to_proto(event: &Event) -> Result<EventMessage, serde_json::Error> {
+    let payload = serde_json::to_value(event)?;
+    let event_type = payload
+        .get("type")
+        .and_then(|t| t.as_str())
+        .unwrap_or_default()
+        .to_string();
+
+    Ok(EventMessage {
+        id: event.id.clone(),
+        instance_id: event.instance_id.clone(),
+        event_type,
+        payload_json: payload.to_string(),
+    })
+}
+
+#[tonic::async_trait]
+impl EventService for EventServiceImpl {
+    type StreamEventsStream = tokio_stream::wrappers::ReceiverStream<Result<EventMessage, Status>>;
+
+    async fn stream_events(
+        &self,
+        request: Request<StreamEventsRequest>,
+    ) -> Result<Response<Self::StreamEventsStream>, Status> {
+        let filter = request.into_inner().event_types;
+        let mut events = self.broadcaster.subscribe();
+        let (tx, rx) = mpsc::channel(EVENT_STREAM_BUFFER);
+
+        tokio::spawn(async move {
+            loop {
+                let event = tokio::select! {
+                    _ = tx.closed() => break, // Client disconnected
+                    received = events.recv() => match received {
+                        Ok(event) => event,
+                        Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                            warn!("RPC event stream lagged, skipped {} events", skipped);
+                            continue;
+                        }
+                        Err(broadcast::error::RecvError::Closed) => break,
+                    },
+                };
+
+                let message = match event_to_proto(&event) {
+                    Ok(message) => message,
+                    Err(e) => {
+                        warn!("Failed to serialize event {}: {}", event.id, e);
+                        continue;
+                    }
+                };
+
+                if !filter.is_empty() && !filter.contains(&message.event_type) {
+                    continue;
+                }
+
+                // Waits while the client's buffer is full
+                if tx.send(Ok(message)).await.is_err() {
+                    break;
+                }
+            }
+            debug!("RPC event stream closed");
+        });
+
+        Ok(Response::new(tokio_stream::wrappers::ReceiverStream::new(
+            rx,
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_to_proto() {
+        let event = Event::agent_started("executor".to_string());
+        let message = event_to_proto(&event).unwrap();
+
+        assert_eq!(message.id, event.id);
+        assert_eq!(message.event_type, "agent_started");
+
+        let payload: serde_json::Value = serde_json::from_str(&message.payload_json).unwrap();
+        assert_eq!(payload["agent_id"], "executor");
+    }
+}
@@ -1,5 +1,6 @@
 //! MemoryService implementation
 
+use crate::api::{Event, EventBroadcaster};
 use crate::rpc::generated::memory_service_server::MemoryService;
 use crate::rpc::generated::{self as generated, *};
 use crate::services::LlmService;
@@ -7,15 +8,37 @@ use crate::storage::StorageBackend;
 use crate::types::{MemoryId, MemoryNote as InternalMemoryNote, MemoryType as InternalMemoryType};
 use std::sync::Arc;
 use tonic::{Request, Response, Status};
+use tracing::debug;
+
+/// Results buffered ahead of a slow `RecallStream` client
+const RECALL_STREAM_BUFFER: usize = 16;
 
 pub struct MemoryServiceImpl {
     storage: Arc<dyn StorageBackend>,
     llm: Option<Arc<LlmService>>,
+    events: EventBroadcaster,
 }
 
 impl MemoryServiceImpl {
     pub fn new(storage: Arc<dyn StorageBackend>, llm: Option<Arc<LlmService>>) -> Self {
-        Self { storage, llm }
+        Self {
+            storage,
+            llm,
+            events: EventBroadcaster::default(),
+        }
+    }
+
+    /// Publish stored, recalled and archived memory events on `broadcaster`
+    pub fn with_event_broadcaster(mut self, broadcaster: EventBroadcaster) -> Self {
+        self.events = broadcaster;
+        self
+    }
+
+    fn publish(events: &EventBroadcaster, event: Event) {
+        // Fails only when nobody is subscribed
+        if events.broadcast(event).is_err() {
+            debug!("No event subscribers");
+        }
     }
 }
 
@@ -75,6 +98,10 @@ impl MemoryService for MemoryServiceImpl {
             .store_memory(&memory)
             .await
             .map_err(|e| Status::from(e))?;
+        Self::publish(
+            &self.events,
+            Event::memory_stored(memory_id.to_string(), memory.summary.clone()),
+        );
 
         Ok(Response::new(StoreMemoryResponse {
             memory_id: memory_id.to_string(),
@@ -179,6 +206,10 @@ impl MemoryService for MemoryServiceImpl {
             .archive_memory(memory_id)
             .await
             .map_err(|e| Status::from(e))?;
+        Self::publish(
+            &self.events,
+            Event::memory_archived(memory_id.to_string(), "deleted over RPC".to_string()),
+        );
 
         Ok(Response::new(DeleteMemoryResponse { success: true }))
     }
@@ -252,6 +283,10 @@ impl MemoryService for MemoryServiceImpl {
             .map_err(|e| Status::from(e))?;
 
         let total_matches = results.len();
+        Self::publish(
+            &self.events,
+            Event::memory_recalled(req.query.clone(), total_matches),
+        );
 
         // Convert to proto SearchResults
         let proto_results: Vec<generated::SearchResult> = results
@@ -495,35 +530,49 @@ impl MemoryService for MemoryServiceImpl {
             None => None,
         };
 
-        // Perform hybrid search (same as Recall)
-        let results = self
-            .storage
-            .hybrid_search(
+        // Bounded so a slow client applies backpressure to the search
+        let (tx, rx) = tokio::sync::mpsc::channel(RECALL_STREAM_BUFFER);
+        let (results_tx, mut results_rx) = tokio::sync::mpsc::channel(RECALL_STREAM_BUFFER);
+        let storage = Arc::clone(&self.storage);
+        let events = self.events.clone();
+
+        // Search in the background so the stream opens immediately and
+        // search failures arrive as a stream error. Each result is sent as
+        // soon as the search produces it.
+        tokio::spawn(async move {
+            let search = storage.hybrid_search_stream(
                 &req.query,
                 namespace,
                 req.max_results.min(1000) as usize,
                 true, // expand_graph
-            )
-            .await
-            .map_err(|e| Status::from(e))?;
-
-        // Create a channel for streaming results
-        let (tx, rx) = tokio::sync::mpsc::channel(100);
-
-        // Spawn task to send results progressively
-        tokio::spawn(async move {
-            for result in results {
-                let proto_result = generated::SearchResult {
-                    memory: Some(crate::rpc::conversions::memory_note_to_proto(result.memory)),
-                    score: result.score,
-                    semantic_score: None,
-                    fts_score: None,
-                    graph_score: None,
-                };
+                results_tx,
+            );
+            let forward = async {
+                let mut sent = 0;
+                while let Some(result) = results_rx.recv().await {
+                    let proto_result = generated::SearchResult {
+                        memory: Some(crate::rpc::conversions::memory_note_to_proto(result.memory)),
+                        score: result.score,
+                        semantic_score: None,
+                        fts_score: None,
+                        graph_score: None,
+                    };
+
+                    // Waits while the client's buffer is full; dropping
+                    // `results_rx` on disconnect stops the search
+                    if tx.send(Ok(proto_result)).await.is_err() {
+                        break;
+                    }
+                    sent += 1;
+                }
+                sent
+            };
 
-                if tx.send(Ok(proto_result)).await.is_err() {
-                    // Client disconnected
-                    break;
+            let (outcome, sent) = tokio::join!(search, forward);
+            match outcome {
+                Ok(()) => Self::publish(&events, Event::memory_recalled(req.query.clone(), sent)),
+                Err(e) => {
+                    let _ = tx.send(Err(Status::from(e))).await;
                 }
             }
         });
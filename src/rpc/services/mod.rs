@@ -1,7 +1,9 @@
 //! gRPC service implementations
 
+pub mod events;
 pub mod health;
 pub mod memory;
 
+pub use events::EventServiceImpl;
 pub use health::HealthServiceImpl;
 pub use memory::MemoryServiceImpl;
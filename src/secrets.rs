@@ -1,12 +1,15 @@
-//! Built-in encrypted secrets management using age encryption
+//! Built-in secrets management with pluggable storage backends
 //!
-//! Provides zero-dependency secrets management with age encryption.
-//! Secrets stored in ~/.config/mnemosyne/secrets.age
+//! Secrets live in one [`SecretBackend`], selected with `[secrets] backend`:
+//! - `file` (default): age-encrypted file at ~/.config/mnemosyne/secrets.age
+//! - `keyring`: OS keychain (macOS Keychain, Windows Credential Manager,
+//!   Linux Secret Service), requires the keyring-fallback feature
 //!
-//! Priority order:
+//! The keyring backend falls back to the encrypted file when no keychain is
+//! available. Priority order:
 //! 1. Environment variables
-//! 2. Encrypted config file (~/.config/mnemosyne/secrets.age)
-//! 3. OS Keychain (fallback, requires keyring-fallback feature)
+//! 2. Active backend
+//! 3. Legacy OS keychain entry (API key only, see `ConfigManager`)
 
 use age::{
     armor::{ArmoredReader, ArmoredWriter, Format},
@@ -15,11 +18,12 @@ use age::{
 use anyhow::{Context, Result};
 use directories::ProjectDirs;
 use secrecy::{ExposeSecret, SecretString};
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs;
 use std::io::{Read, Write};
-use std::path::PathBuf;
-use tracing::{debug, info};
+use std::path::{Path, PathBuf};
+use tracing::{debug, info, warn};
 
 /// Required secrets (always needed)
 pub const REQUIRED_SECRETS: &[&str] = &["ANTHROPIC_API_KEY"];
@@ -27,62 +31,179 @@ pub const REQUIRED_SECRETS: &[&str] = &["ANTHROPIC_API_KEY"];
 /// Optional secrets (for Turso Cloud deployments)
 pub const OPTIONAL_SECRETS: &[&str] = &["TURSO_AUTH_TOKEN"];
 
-/// Secrets manager with age encryption
-pub struct SecretsManager {
-    config_dir: PathBuf,
+/// Keychain service name for secrets stored by the keyring backend
+pub const KEYRING_SERVICE: &str = "mnemosyne-secrets";
+
+/// Where secrets are stored (`[secrets] backend`)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SecretBackendKind {
+    /// Age-encrypted file in the config directory
+    #[default]
+    File,
+    /// OS keychain
+    Keyring,
+}
+
+/// Secrets settings (`[secrets]` in the config file)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SecretsSettings {
+    /// Backend holding secrets
+    pub backend: SecretBackendKind,
+}
+
+/// Storage for named secrets
+///
+/// Environment variables are resolved by [`SecretsManager`], not backends.
+pub trait SecretBackend: Send + Sync {
+    /// Short name shown by `mnemosyne secrets info`
+    fn name(&self) -> &'static str;
+
+    /// Human-readable storage location
+    fn location(&self) -> String;
+
+    /// Whether first-time setup has run
+    fn is_initialized(&self) -> bool;
+
+    /// First-time setup, before any secret is stored
+    fn initialize(&self) -> Result<()> {
+        Ok(())
+    }
+
+    /// Stored value of `name`, if any
+    fn get(&self, name: &str) -> Result<Option<SecretString>>;
+
+    /// Store `value` under `name`, replacing any previous value
+    fn set(&self, name: &str, value: &str) -> Result<()>;
+
+    /// Remove `name`; returns whether it was stored
+    fn delete(&self, name: &str) -> Result<bool>;
+
+    /// Names of stored secrets, sorted
+    fn list(&self) -> Result<Vec<String>>;
+}
+
+/// Secrets in an age-encrypted file, keyed by an identity file beside it
+pub struct EncryptedFileBackend {
     identity_file: PathBuf,
     secrets_file: PathBuf,
 }
 
-impl SecretsManager {
-    /// Initialize secrets manager with standard config directory
-    pub fn new() -> Result<Self> {
-        let dirs = ProjectDirs::from("com", "mnemosyne", "mnemosyne")
-            .context("Failed to determine config directory")?;
+impl EncryptedFileBackend {
+    /// Backend storing `secrets.age` and `identity.key` in `config_dir`
+    pub fn new(config_dir: &Path) -> Self {
+        Self {
+            identity_file: config_dir.join("identity.key"),
+            secrets_file: config_dir.join("secrets.age"),
+        }
+    }
 
-        let config_dir = dirs.config_dir().to_path_buf();
-        Self::new_with_config_dir(config_dir)
+    fn identity(&self) -> Result<age::x25519::Identity> {
+        let identity_str =
+            fs::read_to_string(&self.identity_file).context("Failed to read identity file")?;
+        identity_str
+            .parse::<age::x25519::Identity>()
+            .map_err(|e| anyhow::anyhow!("Failed to parse identity: {}", e))
     }
 
-    /// Initialize secrets manager with custom config directory (for testing)
-    pub fn new_with_config_dir(config_dir: PathBuf) -> Result<Self> {
-        let identity_file = config_dir.join("identity.key");
-        let secrets_file = config_dir.join("secrets.age");
+    /// Load and decrypt secrets
+    fn load_secrets(&self) -> Result<HashMap<String, String>> {
+        let identity = self.identity()?;
 
-        // Create config directory if it doesn't exist
-        fs::create_dir_all(&config_dir).with_context(|| {
-            format!(
-                "Failed to create config directory: {}",
-                config_dir.display()
+        let encrypted = fs::read(&self.secrets_file).context("Failed to read secrets file")?;
+
+        let decryptor = Decryptor::new(ArmoredReader::new(&encrypted[..]))
+            .map_err(|e| anyhow::anyhow!("Failed to create decryptor: {}", e))?;
+
+        let mut decrypted = vec![];
+        let mut reader = decryptor
+            .decrypt(std::iter::once(&identity as &dyn age::Identity))
+            .context("Failed to decrypt secrets (wrong key?)")?;
+        reader
+            .read_to_end(&mut decrypted)
+            .context("Failed to read decrypted data")?;
+
+        let secrets_str =
+            String::from_utf8(decrypted).context("Decrypted data is not valid UTF-8")?;
+        let secrets: HashMap<String, String> =
+            serde_json::from_str(&secrets_str).context("Failed to parse secrets JSON")?;
+
+        debug!("Loaded {} secrets from encrypted file", secrets.len());
+        Ok(secrets)
+    }
+
+    /// Encrypt and save secrets
+    fn save_secrets(&self, secrets: &HashMap<String, String>) -> Result<()> {
+        // Ensure initialized
+        if !self.identity_file.exists() {
+            anyhow::bail!("Secrets not initialized. Run: mnemosyne secrets init");
+        }
+        let recipient = self.identity()?.to_public();
+
+        let secrets_json =
+            serde_json::to_string_pretty(secrets).context("Failed to serialize secrets")?;
+
+        let recipient_box: Box<dyn age::Recipient + Send> = Box::new(recipient);
+        let encryptor =
+            Encryptor::with_recipients(std::iter::once(&*recipient_box as &dyn age::Recipient))
+                .context("Failed to create encryptor")?;
+
+        let mut encrypted = vec![];
+        let mut writer = encryptor
+            .wrap_output(
+                ArmoredWriter::wrap_output(&mut encrypted, Format::AsciiArmor)
+                    .context("Failed to create armored writer")?,
             )
-        })?;
+            .context("Failed to wrap encryptor")?;
 
-        debug!(
-            "Secrets manager initialized (config_dir: {})",
-            config_dir.display()
-        );
+        writer
+            .write_all(secrets_json.as_bytes())
+            .context("Failed to write encrypted data")?;
+        writer.finish().and_then(|armor| armor.finish())?;
 
-        Ok(Self {
-            config_dir,
-            identity_file,
-            secrets_file,
-        })
+        fs::write(&self.secrets_file, encrypted).context("Failed to write secrets file")?;
+
+        // Set file permissions to 0600 on Unix
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mut perms = fs::metadata(&self.secrets_file)?.permissions();
+            perms.set_mode(0o600);
+            fs::set_permissions(&self.secrets_file, perms)?;
+        }
+
+        debug!("Saved {} secrets to encrypted file", secrets.len());
+        Ok(())
     }
 
-    /// Check if secrets are initialized
-    pub fn is_initialized(&self) -> bool {
+    fn load_or_empty(&self) -> Result<HashMap<String, String>> {
+        if self.secrets_file.exists() {
+            self.load_secrets()
+        } else {
+            Ok(HashMap::new())
+        }
+    }
+}
+
+impl SecretBackend for EncryptedFileBackend {
+    fn name(&self) -> &'static str {
+        "file"
+    }
+
+    fn location(&self) -> String {
+        self.secrets_file.display().to_string()
+    }
+
+    fn is_initialized(&self) -> bool {
         self.identity_file.exists() && self.secrets_file.exists()
     }
 
-    /// Initialize on first run (generates keypair, prompts for secrets)
-    pub fn initialize_interactive(&self) -> Result<()> {
-        println!("\n🔐 Mnemosyne Secrets Setup");
-        println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+    /// Generate the age keypair
+    fn initialize(&self) -> Result<()> {
         println!("\nGenerating encryption key...");
 
-        // Generate age keypair
         let key = age::x25519::Identity::generate();
-        let recipient = key.to_public();
 
         // Save identity (private key) with secure permissions
         let key_str = key.to_string();
@@ -98,15 +219,257 @@ impl SecretsManager {
             fs::set_permissions(&self.identity_file, perms)?;
         }
 
+        // Start from an empty store encrypted to the new key
+        self.save_secrets(&HashMap::new())?;
+
         info!("Encryption key generated and saved");
         println!(
             "{} Encryption key saved to: {}",
             crate::icons::status::success(),
             self.identity_file.display()
         );
+        Ok(())
+    }
 
-        // Prompt for required secrets
-        let mut secrets = HashMap::new();
+    fn get(&self, name: &str) -> Result<Option<SecretString>> {
+        if !self.secrets_file.exists() {
+            return Ok(None);
+        }
+        Ok(self
+            .load_secrets()?
+            .remove(name)
+            .map(|value| SecretString::new(value.into())))
+    }
+
+    fn set(&self, name: &str, value: &str) -> Result<()> {
+        let mut secrets = self.load_or_empty()?;
+        secrets.insert(name.to_string(), value.to_string());
+        self.save_secrets(&secrets)
+    }
+
+    fn delete(&self, name: &str) -> Result<bool> {
+        let mut secrets = self.load_or_empty()?;
+        if secrets.remove(name).is_none() {
+            return Ok(false);
+        }
+        self.save_secrets(&secrets)?;
+        Ok(true)
+    }
+
+    fn list(&self) -> Result<Vec<String>> {
+        let mut names: Vec<String> = self.load_or_empty()?.into_keys().collect();
+        names.sort();
+        Ok(names)
+    }
+}
+
+/// Secrets in the OS keychain, one entry per secret under a service name
+///
+/// Keychains cannot enumerate entries, so the names are kept in an extra
+/// index entry.
+#[cfg(feature = "keyring-fallback")]
+pub struct KeyringBackend {
+    service: String,
+}
+
+#[cfg(feature = "keyring-fallback")]
+impl KeyringBackend {
+    /// Entry holding the JSON list of stored names
+    const INDEX_USER: &'static str = "__mnemosyne_index__";
+
+    pub fn new(service: impl Into<String>) -> Self {
+        Self {
+            service: service.into(),
+        }
+    }
+
+    /// Whether a keychain is reachable (e.g. a Secret Service daemon on Linux)
+    pub fn is_available(&self) -> bool {
+        match self.read(Self::INDEX_USER) {
+            Ok(_) => true,
+            Err(e) => {
+                debug!("OS keychain unavailable: {}", e);
+                false
+            }
+        }
+    }
+
+    fn entry(&self, user: &str) -> Result<keyring::Entry> {
+        keyring::Entry::new(&self.service, user)
+            .map_err(|e| anyhow::anyhow!("Failed to access keychain: {}", e))
+    }
+
+    fn read(&self, user: &str) -> Result<Option<String>> {
+        match self.entry(user)?.get_password() {
+            Ok(value) => Ok(Some(value)),
+            Err(keyring::Error::NoEntry) => Ok(None),
+            Err(e) => Err(anyhow::anyhow!("Failed to read keychain: {}", e)),
+        }
+    }
+
+    fn index(&self) -> Result<Vec<String>> {
+        match self.read(Self::INDEX_USER)? {
+            Some(json) => serde_json::from_str(&json).context("Failed to parse keychain index"),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    fn save_index(&self, names: &[String]) -> Result<()> {
+        let json = serde_json::to_string(names).context("Failed to serialize keychain index")?;
+        self.entry(Self::INDEX_USER)?
+            .set_password(&json)
+            .map_err(|e| anyhow::anyhow!("Failed to write keychain index: {}", e))
+    }
+}
+
+#[cfg(feature = "keyring-fallback")]
+impl SecretBackend for KeyringBackend {
+    fn name(&self) -> &'static str {
+        "keyring"
+    }
+
+    fn location(&self) -> String {
+        format!("OS keychain (service: {})", self.service)
+    }
+
+    fn is_initialized(&self) -> bool {
+        self.index().map(|names| !names.is_empty()).unwrap_or(false)
+    }
+
+    fn get(&self, name: &str) -> Result<Option<SecretString>> {
+        Ok(self
+            .read(name)?
+            .map(|value| SecretString::new(value.into())))
+    }
+
+    fn set(&self, name: &str, value: &str) -> Result<()> {
+        self.entry(name)?
+            .set_password(value)
+            .map_err(|e| anyhow::anyhow!("Failed to write keychain: {}", e))?;
+
+        let mut names = self.index()?;
+        if !names.iter().any(|n| n == name) {
+            names.push(name.to_string());
+            names.sort();
+            self.save_index(&names)?;
+        }
+        Ok(())
+    }
+
+    fn delete(&self, name: &str) -> Result<bool> {
+        let existed = match self.entry(name)?.delete_credential() {
+            Ok(()) => true,
+            Err(keyring::Error::NoEntry) => false,
+            Err(e) => anyhow::bail!("Failed to delete from keychain: {}", e),
+        };
+
+        let mut names = self.index()?;
+        let before = names.len();
+        names.retain(|n| n != name);
+        if names.len() != before {
+            self.save_index(&names)?;
+        }
+        Ok(existed)
+    }
+
+    fn list(&self) -> Result<Vec<String>> {
+        self.index()
+    }
+}
+
+/// Secrets manager resolving environment variables, then the active backend
+pub struct SecretsManager {
+    config_dir: PathBuf,
+    backend: Box<dyn SecretBackend>,
+}
+
+impl SecretsManager {
+    /// Initialize secrets manager with standard config directory and the
+    /// backend selected by `[secrets] backend`
+    pub fn new() -> Result<Self> {
+        let dirs = ProjectDirs::from("com", "mnemosyne", "mnemosyne")
+            .context("Failed to determine config directory")?;
+
+        let config_dir = dirs.config_dir().to_path_buf();
+        let kind = configured_backend_kind();
+        Self::new_with_backend_kind(config_dir, kind)
+    }
+
+    /// Initialize secrets manager with custom config directory (for testing)
+    ///
+    /// Uses the encrypted file backend.
+    pub fn new_with_config_dir(config_dir: PathBuf) -> Result<Self> {
+        Self::new_with_backend_kind(config_dir, SecretBackendKind::File)
+    }
+
+    /// Initialize secrets manager with `kind`, falling back to the encrypted
+    /// file when the keychain is unavailable
+    pub fn new_with_backend_kind(config_dir: PathBuf, kind: SecretBackendKind) -> Result<Self> {
+        // Create config directory if it doesn't exist
+        fs::create_dir_all(&config_dir).with_context(|| {
+            format!(
+                "Failed to create config directory: {}",
+                config_dir.display()
+            )
+        })?;
+
+        let file_backend = || Box::new(EncryptedFileBackend::new(&config_dir));
+        let backend: Box<dyn SecretBackend> = match kind {
+            SecretBackendKind::File => file_backend(),
+            #[cfg(feature = "keyring-fallback")]
+            SecretBackendKind::Keyring => {
+                let keyring = KeyringBackend::new(KEYRING_SERVICE);
+                if keyring.is_available() {
+                    Box::new(keyring)
+                } else {
+                    warn!("OS keychain unavailable, using encrypted file for secrets");
+                    file_backend()
+                }
+            }
+            #[cfg(not(feature = "keyring-fallback"))]
+            SecretBackendKind::Keyring => {
+                warn!("Built without keyring support, using encrypted file for secrets");
+                file_backend()
+            }
+        };
+
+        debug!(
+            "Secrets manager initialized (config_dir: {}, backend: {})",
+            config_dir.display(),
+            backend.name()
+        );
+
+        Ok(Self {
+            config_dir,
+            backend,
+        })
+    }
+
+    /// Initialize secrets manager with an explicit backend
+    pub fn with_backend(config_dir: PathBuf, backend: Box<dyn SecretBackend>) -> Self {
+        Self {
+            config_dir,
+            backend,
+        }
+    }
+
+    /// Active storage backend
+    pub fn backend(&self) -> &dyn SecretBackend {
+        self.backend.as_ref()
+    }
+
+    /// Check if secrets are initialized
+    pub fn is_initialized(&self) -> bool {
+        self.backend.is_initialized()
+    }
+
+    /// Initialize on first run (prepares the backend, prompts for secrets)
+    pub fn initialize_interactive(&self) -> Result<()> {
+        println!("\n🔐 Mnemosyne Secrets Setup");
+        println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+
+        // Prompt before touching the backend so an empty answer changes nothing
+        let mut secrets = Vec::new();
 
         for secret_name in REQUIRED_SECRETS {
             println!("\n{} (required)", secret_name);
@@ -124,7 +487,7 @@ impl SecretsManager {
                 anyhow::bail!("Required secret '{}' cannot be empty", secret_name);
             }
 
-            secrets.insert(secret_name.to_string(), value.to_string());
+            secrets.push((secret_name.to_string(), value.to_string()));
         }
 
         // Prompt for optional secrets
@@ -140,22 +503,21 @@ impl SecretsManager {
             let value = input.trim();
 
             if !value.is_empty() {
-                secrets.insert(secret_name.to_string(), value.to_string());
+                secrets.push((secret_name.to_string(), value.to_string()));
                 info!("Optional secret '{}' configured", secret_name);
             }
         }
 
-        // Encrypt and save secrets
-        self.save_secrets(&secrets, &recipient)?;
+        self.backend.initialize()?;
+        for (name, value) in &secrets {
+            self.backend.set(name, value)?;
+        }
 
-        println!(
-            "\n{} Secrets encrypted and saved!",
-            crate::icons::status::success()
-        );
+        println!("\n{} Secrets saved!", crate::icons::status::success());
         println!(
             "{} Location: {}",
             crate::icons::status::success(),
-            self.secrets_file.display()
+            self.backend.location()
         );
         println!("\nYou can update secrets anytime with: mnemosyne secrets set <KEY>");
 
@@ -173,13 +535,14 @@ impl SecretsManager {
             }
         }
 
-        // 2. Decrypt from age file
-        if self.secrets_file.exists() {
-            let secrets = self.load_secrets()?;
-            if let Some(value) = secrets.get(name) {
-                debug!("Retrieved secret '{}' from encrypted config", name);
-                return Ok(SecretString::new(value.clone().into()));
-            }
+        // 2. Active backend
+        if let Some(value) = self.backend.get(name)? {
+            debug!(
+                "Retrieved secret '{}' from {} backend",
+                name,
+                self.backend.name()
+            );
+            return Ok(value);
         }
 
         anyhow::bail!(
@@ -189,34 +552,13 @@ impl SecretsManager {
         )
     }
 
-    /// Set a secret (encrypt and save)
+    /// Set a secret in the active backend
     pub fn set_secret(&self, name: &str, value: &str) -> Result<()> {
         if value.is_empty() {
             anyhow::bail!("Secret value cannot be empty");
         }
 
-        // Ensure initialized
-        if !self.identity_file.exists() {
-            anyhow::bail!("Secrets not initialized. Run: mnemosyne secrets init");
-        }
-
-        let mut secrets = if self.secrets_file.exists() {
-            self.load_secrets()?
-        } else {
-            HashMap::new()
-        };
-
-        secrets.insert(name.to_string(), value.to_string());
-
-        // Load recipient from identity file
-        let identity_str =
-            fs::read_to_string(&self.identity_file).context("Failed to read identity file")?;
-        let identity = identity_str
-            .parse::<age::x25519::Identity>()
-            .map_err(|e| anyhow::anyhow!("Failed to parse identity: {}", e))?;
-        let recipient = identity.to_public();
-
-        self.save_secrets(&secrets, &recipient)?;
+        self.backend.set(name, value)?;
 
         info!("Secret '{}' updated", name);
         println!(
@@ -228,88 +570,18 @@ impl SecretsManager {
         Ok(())
     }
 
-    /// Load and decrypt secrets
-    fn load_secrets(&self) -> Result<HashMap<String, String>> {
-        let identity_str =
-            fs::read_to_string(&self.identity_file).context("Failed to read identity file")?;
-        let identity = identity_str
-            .parse::<age::x25519::Identity>()
-            .map_err(|e| anyhow::anyhow!("Failed to parse identity: {}", e))?;
-
-        let encrypted = fs::read(&self.secrets_file).context("Failed to read secrets file")?;
-
-        let decryptor = Decryptor::new(ArmoredReader::new(&encrypted[..]))
-            .map_err(|e| anyhow::anyhow!("Failed to create decryptor: {}", e))?;
-
-        let mut decrypted = vec![];
-        let mut reader = decryptor
-            .decrypt(std::iter::once(&identity as &dyn age::Identity))
-            .context("Failed to decrypt secrets (wrong key?)")?;
-        reader
-            .read_to_end(&mut decrypted)
-            .context("Failed to read decrypted data")?;
-
-        let secrets_str =
-            String::from_utf8(decrypted).context("Decrypted data is not valid UTF-8")?;
-        let secrets: HashMap<String, String> =
-            serde_json::from_str(&secrets_str).context("Failed to parse secrets JSON")?;
-
-        debug!("Loaded {} secrets from encrypted file", secrets.len());
-        Ok(secrets)
-    }
-
-    /// Encrypt and save secrets
-    fn save_secrets(
-        &self,
-        secrets: &HashMap<String, String>,
-        recipient: &age::x25519::Recipient,
-    ) -> Result<()> {
-        let secrets_json =
-            serde_json::to_string_pretty(secrets).context("Failed to serialize secrets")?;
-
-        let recipient_box: Box<dyn age::Recipient + Send> = Box::new(recipient.clone());
-        let encryptor =
-            Encryptor::with_recipients(std::iter::once(&*recipient_box as &dyn age::Recipient))
-                .context("Failed to create encryptor")?;
-
-        let mut encrypted = vec![];
-        let mut writer = encryptor
-            .wrap_output(
-                ArmoredWriter::wrap_output(&mut encrypted, Format::AsciiArmor)
-                    .context("Failed to create armored writer")?,
-            )
-            .context("Failed to wrap encryptor")?;
-
-        writer
-            .write_all(secrets_json.as_bytes())
-            .context("Failed to write encrypted data")?;
-        writer.finish().and_then(|armor| armor.finish())?;
-
-        fs::write(&self.secrets_file, encrypted).context("Failed to write secrets file")?;
-
-        // Set file permissions to 0600 on Unix
-        #[cfg(unix)]
-        {
-            use std::os::unix::fs::PermissionsExt;
-            let mut perms = fs::metadata(&self.secrets_file)?.permissions();
-            perms.set_mode(0o600);
-            fs::set_permissions(&self.secrets_file, perms)?;
+    /// Remove a secret from the active backend; returns whether it existed
+    pub fn delete_secret(&self, name: &str) -> Result<bool> {
+        let existed = self.backend.delete(name)?;
+        if existed {
+            info!("Secret '{}' deleted", name);
         }
-
-        debug!("Saved {} secrets to encrypted file", secrets.len());
-        Ok(())
+        Ok(existed)
     }
 
     /// List configured secrets (shows names only, not values)
     pub fn list_secrets(&self) -> Result<Vec<String>> {
-        if !self.secrets_file.exists() {
-            return Ok(vec![]);
-        }
-
-        let secrets = self.load_secrets()?;
-        let mut names: Vec<String> = secrets.keys().cloned().collect();
-        names.sort();
-        Ok(names)
+        self.backend.list()
     }
 
     /// Get config directory path
@@ -317,14 +589,14 @@ impl SecretsManager {
         &self.config_dir
     }
 
-    /// Get identity file path
-    pub fn identity_file(&self) -> &PathBuf {
-        &self.identity_file
+    /// Get identity file path (file backend)
+    pub fn identity_file(&self) -> PathBuf {
+        self.config_dir.join("identity.key")
     }
 
-    /// Get secrets file path
-    pub fn secrets_file(&self) -> &PathBuf {
-        &self.secrets_file
+    /// Get secrets file path (file backend)
+    pub fn secrets_file(&self) -> PathBuf {
+        self.config_dir.join("secrets.age")
     }
 }
 
@@ -334,6 +606,11 @@ impl Default for SecretsManager {
     }
 }
 
+/// Backend selected in the config layers, or the file backend
+fn configured_backend_kind() -> SecretBackendKind {
+    crate::config::ConfigManager::load_section("secrets settings", |s| s.secrets.backend)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -341,13 +618,7 @@ mod tests {
 
     fn create_test_manager() -> (SecretsManager, TempDir) {
         let temp_dir = TempDir::new().unwrap();
-        let config_dir = temp_dir.path().to_path_buf();
-
-        let manager = SecretsManager {
-            config_dir: config_dir.clone(),
-            identity_file: config_dir.join("identity.key"),
-            secrets_file: config_dir.join("secrets.age"),
-        };
+        let manager = SecretsManager::new_with_config_dir(temp_dir.path().to_path_buf()).unwrap();
 
         (manager, temp_dir)
     }
@@ -364,7 +635,7 @@ mod tests {
 
         // Generate and save identity
         let key = age::x25519::Identity::generate();
-        fs::write(&manager.identity_file, key.to_string().expose_secret()).unwrap();
+        fs::write(manager.identity_file(), key.to_string().expose_secret()).unwrap();
 
         // Set a secret
         manager.set_secret("TEST_KEY", "test_value").unwrap();
@@ -380,7 +651,7 @@ mod tests {
 
         // Generate and save identity
         let key = age::x25519::Identity::generate();
-        fs::write(&manager.identity_file, key.to_string().expose_secret()).unwrap();
+        fs::write(manager.identity_file(), key.to_string().expose_secret()).unwrap();
 
         // Set a secret in file
         manager.set_secret("TEST_KEY", "file_value").unwrap();
@@ -402,7 +673,7 @@ mod tests {
 
         // Generate and save identity
         let key = age::x25519::Identity::generate();
-        fs::write(&manager.identity_file, key.to_string().expose_secret()).unwrap();
+        fs::write(manager.identity_file(), key.to_string().expose_secret()).unwrap();
 
         // Initially empty
         assert_eq!(manager.list_secrets().unwrap().len(), 0);
@@ -417,4 +688,43 @@ mod tests {
         assert!(secrets.contains(&"KEY1".to_string()));
         assert!(secrets.contains(&"KEY2".to_string()));
     }
+
+    #[test]
+    fn test_delete_secret() {
+        let (manager, _temp) = create_test_manager();
+        manager.backend().initialize().unwrap();
+        assert!(manager.is_initialized());
+
+        manager.set_secret("KEY1", "value1").unwrap();
+        assert!(manager.delete_secret("KEY1").unwrap());
+        assert!(!manager.delete_secret("KEY1").unwrap());
+        assert!(manager.get_secret("KEY1").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "keyring-fallback")]
+    #[ignore] // Writes to the real OS keychain, run with --ignored
+    fn test_keyring_backend_round_trip() {
+        let backend = KeyringBackend::new("mnemosyne-secrets-test");
+        if !backend.is_available() {
+            eprintln!("Skipping: no OS keychain available");
+            return;
+        }
+        let temp_dir = TempDir::new().unwrap();
+        let manager =
+            SecretsManager::with_backend(temp_dir.path().to_path_buf(), Box::new(backend));
+        assert_eq!(manager.backend().name(), "keyring");
+
+        let name = "MNEMOSYNE_KEYRING_TEST_SECRET";
+        manager.set_secret(name, "keyring_value").unwrap();
+        assert_eq!(
+            manager.get_secret(name).unwrap().expose_secret(),
+            "keyring_value"
+        );
+        assert!(manager.list_secrets().unwrap().contains(&name.to_string()));
+
+        assert!(manager.delete_secret(name).unwrap());
+        assert!(manager.get_secret(name).is_err());
+        assert!(!manager.list_secrets().unwrap().contains(&name.to_string()));
+    }
 }
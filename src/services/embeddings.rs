@@ -3,8 +3,10 @@
 //! Generates fixed-size vector embeddings for memory content to enable
 //! semantic similarity search.
 
+use crate::diagnostics::{global_degradation, time_operation, DegradedService, Operation};
 use crate::error::Result;
 use crate::services::llm::LlmConfig;
+use crate::services::redaction::Redactor;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
 use std::collections::hash_map::DefaultHasher;
@@ -14,11 +16,15 @@ use tracing::{debug, warn};
 /// Embedding dimension (using 384 for compatibility with all-MiniLM-L6-v2)
 pub const EMBEDDING_DIM: usize = 384;
 
+/// Recorded as the `embedding_model` of embeddings from [`EmbeddingService`]
+pub const CONCEPT_EMBEDDING_MODEL: &str = "llm-concepts";
+
 /// Embedding generation service
 pub struct EmbeddingService {
     client: Client,
     api_key: String,
     config: LlmConfig,
+    redactor: Redactor,
 }
 
 #[derive(Serialize)]
@@ -52,9 +58,22 @@ impl EmbeddingService {
             client: Client::new(),
             api_key,
             config,
+            redactor: Redactor::default(),
         }
     }
 
+    /// Name recorded with the embeddings this service generates
+    pub fn model_name(&self) -> &str {
+        CONCEPT_EMBEDDING_MODEL
+    }
+
+    /// Redact text sent for concept extraction with `redactor` instead of the
+    /// built-in rules
+    pub fn with_redactor(mut self, redactor: Redactor) -> Self {
+        self.redactor = redactor;
+        self
+    }
+
     /// Generate embedding vector for text
     ///
     /// Uses LLM to extract semantic features, then converts to fixed-size vector.
@@ -62,6 +81,7 @@ impl EmbeddingService {
     /// Can be upgraded to dedicated embedding models (Voyage AI, OpenAI, etc.) later.
     pub async fn generate_embedding(&self, text: &str) -> Result<Vec<f32>> {
         debug!("Generating embedding for text ({} chars)", text.len());
+        let _timer = time_operation(Operation::Embedding);
 
         // For very short text, use simpler approach
         if text.len() < 50 {
@@ -76,7 +96,10 @@ impl EmbeddingService {
             text.chars().take(1000).collect::<String>()
         );
 
-        match self.call_llm(&prompt).await {
+        let result = self.call_llm(&prompt).await;
+        global_degradation().record(DegradedService::Embeddings, &result);
+
+        match result {
             Ok(concepts) => {
                 // Convert concepts to embedding vector
                 Ok(Self::concepts_to_embedding(&concepts, text))
@@ -95,7 +118,7 @@ impl EmbeddingService {
             model: self.config.model.clone(),
             messages: vec![Message {
                 role: "user".to_string(),
-                content: prompt.to_string(),
+                content: self.redactor.redact(prompt).into_owned(),
             }],
             max_tokens: 200,
             temperature: 0.3, // Low temperature for consistent extraction
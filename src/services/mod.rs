@@ -4,6 +4,10 @@
 
 pub mod embeddings;
 pub mod llm;
+pub mod redaction;
+pub mod usage;
 
 pub use embeddings::{cosine_similarity, EmbeddingService, EMBEDDING_DIM};
-pub use llm::{LlmConfig, LlmService};
+pub use llm::{LlmConfig, LlmService, LlmTask};
+pub use redaction::{RedactionRule, RedactionSettings, Redactor};
+pub use usage::{CostCalculator, LlmSettings, TokenBudget, TokenUsage, UsageTracker};
@@ -0,0 +1,387 @@
+//! Token usage accounting for the Anthropic API
+//!
+//! Every completion reports the input and output tokens it used. A
+//! [`UsageTracker`] keeps the running total for one `LlmService`, enforces an
+//! optional token budget over a sliding window, and publishes an `LlmUsage`
+//! event per call when a broadcaster is attached so the dashboard can show
+//! spend. Costs are list-price estimates from [`CostCalculator`], which the
+//! DSPy telemetry shares.
+
+use crate::api::{Event, EventBroadcaster};
+use crate::error::{MnemosyneError, Result};
+use crate::services::llm::LlmTask;
+use crate::services::redaction::RedactionSettings;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::sync::{Mutex, RwLock};
+use std::time::{Duration, Instant};
+use tracing::warn;
+
+/// Token budget, model choices and redaction for LLM calls (`[llm]` in the config file)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LlmSettings {
+    /// Input plus output tokens allowed per budget window (0 disables the cap)
+    pub token_budget: u64,
+
+    /// Length of the sliding budget window (seconds)
+    pub budget_window_secs: u64,
+
+    /// Model per task (`[llm.models]`, e.g. `contradiction = "..."`); tasks
+    /// not listed use the default model
+    pub models: BTreeMap<LlmTask, String>,
+
+    /// Redaction applied to prompts before they are sent (`[llm.redaction]`)
+    pub redaction: RedactionSettings,
+}
+
+impl Default for LlmSettings {
+    fn default() -> Self {
+        Self {
+            token_budget: 0,
+            budget_window_secs: 3600,
+            models: BTreeMap::new(),
+            redaction: RedactionSettings::default(),
+        }
+    }
+}
+
+impl LlmSettings {
+    /// Validate LLM settings
+    ///
+    /// Redaction patterns are not checked here. An invalid one is skipped
+    /// when the service is built, rather than discarding the whole config
+    /// and the budget with it; see
+    /// [`Redactor::new_lenient`](crate::services::Redactor::new_lenient).
+    pub fn validate(&self) -> Result<()> {
+        if self.token_budget > 0 && self.budget_window_secs == 0 {
+            return Err(MnemosyneError::Config(config::ConfigError::Message(
+                "llm.budget_window_secs must be at least 1 when llm.token_budget is set"
+                    .to_string(),
+            )));
+        }
+        if let Some((task, _)) = self
+            .models
+            .iter()
+            .find(|(_, model)| model.trim().is_empty())
+        {
+            return Err(MnemosyneError::Config(config::ConfigError::Message(
+                format!("llm.models.{} must name a model", task.as_str()),
+            )));
+        }
+        Ok(())
+    }
+
+    /// The configured cap, if any
+    pub fn token_budget(&self) -> Option<TokenBudget> {
+        (self.token_budget > 0).then(|| TokenBudget {
+            max_tokens: self.token_budget,
+            window: Duration::from_secs(self.budget_window_secs),
+        })
+    }
+}
+
+/// Token counts for one or more LLM calls
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenUsage {
+    /// Input/prompt tokens
+    pub input_tokens: u64,
+    /// Output/completion tokens
+    pub output_tokens: u64,
+    /// Total tokens
+    pub total_tokens: u64,
+}
+
+impl TokenUsage {
+    pub fn new(input_tokens: u64, output_tokens: u64) -> Self {
+        Self {
+            input_tokens,
+            output_tokens,
+            total_tokens: input_tokens + output_tokens,
+        }
+    }
+
+    /// Usage of this and `other` combined
+    pub fn add(&self, other: &TokenUsage) -> Self {
+        Self::new(
+            self.input_tokens + other.input_tokens,
+            self.output_tokens + other.output_tokens,
+        )
+    }
+}
+
+/// At most `max_tokens` within any `window`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TokenBudget {
+    pub max_tokens: u64,
+    pub window: Duration,
+}
+
+/// Cost calculator for LLM API calls
+///
+/// Uses pricing data from Anthropic API (as of 2025-01-01).
+pub struct CostCalculator {
+    /// Pricing per 1M input tokens (USD)
+    input_price_per_1m: HashMap<String, f64>,
+    /// Pricing per 1M output tokens (USD)
+    output_price_per_1m: HashMap<String, f64>,
+}
+
+impl CostCalculator {
+    /// Create a new cost calculator with default Anthropic pricing
+    pub fn new() -> Self {
+        let mut input_price_per_1m = HashMap::new();
+        let mut output_price_per_1m = HashMap::new();
+
+        // Claude Sonnet 4 pricing (as of 2025-01-01)
+        input_price_per_1m.insert("claude-sonnet-4-20250514".to_string(), 3.0);
+        output_price_per_1m.insert("claude-sonnet-4-20250514".to_string(), 15.0);
+
+        // Claude Haiku 3.5 pricing
+        input_price_per_1m.insert("claude-3-5-haiku-20241022".to_string(), 1.0);
+        output_price_per_1m.insert("claude-3-5-haiku-20241022".to_string(), 5.0);
+
+        // Claude Haiku 4.5 pricing (the `LlmService` default)
+        input_price_per_1m.insert("claude-haiku-4-5-20251001".to_string(), 1.0);
+        output_price_per_1m.insert("claude-haiku-4-5-20251001".to_string(), 5.0);
+
+        Self {
+            input_price_per_1m,
+            output_price_per_1m,
+        }
+    }
+
+    /// Calculate cost for a given model and token usage
+    ///
+    /// Returns cost in USD
+    pub fn calculate_cost(&self, model: &str, tokens: &TokenUsage) -> f64 {
+        let input_price = self.input_price_per_1m.get(model).copied().unwrap_or(3.0); // Default to Sonnet pricing
+        let output_price = self.output_price_per_1m.get(model).copied().unwrap_or(15.0);
+
+        let input_cost = (tokens.input_tokens as f64 / 1_000_000.0) * input_price;
+        let output_cost = (tokens.output_tokens as f64 / 1_000_000.0) * output_price;
+
+        input_cost + output_cost
+    }
+
+    /// Add custom pricing for a model
+    pub fn add_model_pricing(
+        &mut self,
+        model: impl Into<String>,
+        input_price: f64,
+        output_price: f64,
+    ) {
+        let model = model.into();
+        self.input_price_per_1m.insert(model.clone(), input_price);
+        self.output_price_per_1m.insert(model, output_price);
+    }
+}
+
+impl Default for CostCalculator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Default)]
+struct UsageState {
+    total: TokenUsage,
+    total_cost_usd: f64,
+    /// Tokens per call inside the budget window, oldest first
+    recent: VecDeque<(Instant, u64)>,
+}
+
+/// Running token totals and budget enforcement for an LLM service
+#[derive(Default)]
+pub struct UsageTracker {
+    budget: Option<TokenBudget>,
+    costs: CostCalculator,
+    state: Mutex<UsageState>,
+    broadcaster: RwLock<Option<EventBroadcaster>>,
+}
+
+impl UsageTracker {
+    pub fn new(budget: Option<TokenBudget>) -> Self {
+        Self {
+            budget,
+            ..Self::default()
+        }
+    }
+
+    pub fn budget(&self) -> Option<TokenBudget> {
+        self.budget
+    }
+
+    /// Publish an `LlmUsage` event for each recorded call
+    pub fn set_broadcaster(&self, broadcaster: EventBroadcaster) {
+        if let Ok(mut slot) = self.broadcaster.write() {
+            *slot = Some(broadcaster);
+        }
+    }
+
+    /// Tokens used since the tracker was created
+    pub fn total(&self) -> TokenUsage {
+        self.lock().total
+    }
+
+    /// Estimated cost in USD since the tracker was created
+    pub fn total_cost_usd(&self) -> f64 {
+        self.lock().total_cost_usd
+    }
+
+    /// Tokens used within the budget window (0 without a budget)
+    pub fn window_tokens(&self) -> u64 {
+        match self.budget {
+            Some(budget) => {
+                let mut state = self.lock();
+                Self::expire(&mut state, budget.window);
+                state.recent.iter().map(|(_, tokens)| tokens).sum()
+            }
+            None => 0,
+        }
+    }
+
+    /// Fail with `LlmBudgetExceeded` once the window's budget is spent
+    ///
+    /// Called before each request; a call that starts under budget may
+    /// finish over it.
+    pub fn check_budget(&self) -> Result<()> {
+        let Some(budget) = self.budget else {
+            return Ok(());
+        };
+
+        let used = self.window_tokens();
+        if used >= budget.max_tokens {
+            return Err(MnemosyneError::LlmBudgetExceeded(format!(
+                "{} of {} tokens used in the last {}s",
+                used,
+                budget.max_tokens,
+                budget.window.as_secs()
+            )));
+        }
+        Ok(())
+    }
+
+    /// Add one completed call to the totals
+    pub fn record(&self, model: &str, usage: TokenUsage) {
+        let cost_usd = self.costs.calculate_cost(model, &usage);
+        let (total, total_cost_usd) = {
+            let mut state = self.lock();
+            state.total = state.total.add(&usage);
+            state.total_cost_usd += cost_usd;
+            if let Some(budget) = self.budget {
+                state.recent.push_back((Instant::now(), usage.total_tokens));
+                Self::expire(&mut state, budget.window);
+            }
+            (state.total, state.total_cost_usd)
+        };
+
+        if let Some(budget) = self.budget {
+            if self.window_tokens() >= budget.max_tokens {
+                warn!(
+                    "LLM token budget of {} per {}s reached; further calls will fail until usage ages out",
+                    budget.max_tokens,
+                    budget.window.as_secs()
+                );
+            }
+        }
+
+        if let Ok(slot) = self.broadcaster.read() {
+            if let Some(broadcaster) = slot.as_ref() {
+                let _ = broadcaster.broadcast(Event::llm_usage(
+                    model.to_string(),
+                    usage.input_tokens,
+                    usage.output_tokens,
+                    cost_usd,
+                    total.total_tokens,
+                    total_cost_usd,
+                ));
+            }
+        }
+    }
+
+    fn expire(state: &mut UsageState, window: Duration) {
+        while let Some((at, _)) = state.recent.front() {
+            if at.elapsed() < window {
+                break;
+            }
+            state.recent.pop_front();
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, UsageState> {
+        // Counters only; a panicking holder leaves nothing inconsistent
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::EventType;
+
+    #[test]
+    fn test_invalid_redaction_rule_keeps_budget() {
+        let settings = LlmSettings {
+            token_budget: 500,
+            redaction: RedactionSettings {
+                enabled: true,
+                rules: vec![crate::services::redaction::RedactionRule {
+                    name: "broken".to_string(),
+                    pattern: "(".to_string(),
+                }],
+            },
+            ..LlmSettings::default()
+        };
+        assert!(settings.validate().is_ok());
+        assert_eq!(settings.token_budget().map(|b| b.max_tokens), Some(500));
+        assert!(crate::services::Redactor::new_lenient(&settings.redaction).is_enabled());
+    }
+
+    #[test]
+    fn test_budget_window_expires() {
+        let tracker = UsageTracker::new(Some(TokenBudget {
+            max_tokens: 100,
+            window: Duration::from_millis(50),
+        }));
+        tracker.record("claude-haiku-4-5-20251001", TokenUsage::new(80, 30));
+        assert!(matches!(
+            tracker.check_budget(),
+            Err(MnemosyneError::LlmBudgetExceeded(_))
+        ));
+
+        std::thread::sleep(Duration::from_millis(60));
+        assert_eq!(tracker.window_tokens(), 0);
+        assert!(tracker.check_budget().is_ok());
+        // The running total is not windowed
+        assert_eq!(tracker.total(), TokenUsage::new(80, 30));
+    }
+
+    #[test]
+    fn test_record_publishes_usage_event() {
+        let tracker = UsageTracker::new(None);
+        let broadcaster = EventBroadcaster::new(8);
+        let mut rx = broadcaster.subscribe();
+        tracker.set_broadcaster(broadcaster);
+
+        tracker.record("claude-haiku-4-5-20251001", TokenUsage::new(1_000_000, 0));
+        tracker.record("claude-haiku-4-5-20251001", TokenUsage::new(0, 200_000));
+
+        rx.try_recv().unwrap();
+        match rx.try_recv().unwrap().event_type {
+            EventType::LlmUsage {
+                output_tokens,
+                cost_usd,
+                total_tokens,
+                total_cost_usd,
+                ..
+            } => {
+                assert_eq!(output_tokens, 200_000);
+                assert!((cost_usd - 1.0).abs() < 1e-9);
+                assert_eq!(total_tokens, 1_200_000);
+                assert!((total_cost_usd - 2.0).abs() < 1e-9);
+            }
+            other => panic!("Wrong event type: {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,172 @@
+//! Merging of hybrid search signals
+//!
+//! Keyword, vector and graph search each return their own candidate lists,
+//! and the same memory often turns up in more than one of them. Candidates
+//! are merged here into a single entry per memory that carries every
+//! signal's score, so a memory is scored once from all of its signals
+//! instead of once per signal.
+
+use crate::config::SearchConfig;
+use crate::types::MemoryId;
+use std::collections::HashMap;
+
+/// Per-signal scores for one memory
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct SignalScores {
+    /// 1.0 when the memory matched the keyword index
+    pub keyword: f32,
+    /// Best cosine similarity reported by vector search
+    pub vector: f32,
+    /// 1.0 when graph expansion reached the memory
+    pub graph: f32,
+    /// Hops from the nearest seed; 0.0 for memories found directly
+    pub depth: f32,
+}
+
+impl SignalScores {
+    /// Graph contribution, decaying with distance from the seeds
+    pub fn graph_depth_score(&self) -> f32 {
+        if self.graph > 0.0 {
+            1.0 / (1.0 + self.depth)
+        } else {
+            0.0
+        }
+    }
+
+    /// Weighted sum of every signal plus the memory's importance and recency
+    pub fn fused(&self, config: &SearchConfig, importance: f32, recency: f32) -> f32 {
+        config.keyword_weight * self.keyword
+            + config.vector_weight * self.vector
+            + config.graph_weight * self.graph_depth_score()
+            + config.importance_weight * importance
+            + config.recency_weight * recency
+    }
+}
+
+/// Hybrid search candidates, one entry per memory in first-seen order
+#[derive(Debug, Default)]
+pub struct FusedCandidates {
+    entries: Vec<(MemoryId, SignalScores)>,
+    index: HashMap<MemoryId, usize>,
+}
+
+impl FusedCandidates {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn entry(&mut self, id: MemoryId, initial: SignalScores) -> &mut SignalScores {
+        let position = *self.index.entry(id).or_insert_with(|| {
+            self.entries.push((id, initial));
+            self.entries.len() - 1
+        });
+        &mut self.entries[position].1
+    }
+
+    /// Record a keyword match
+    pub fn add_keyword(&mut self, id: MemoryId) {
+        self.entry(id, SignalScores::default()).keyword = 1.0;
+    }
+
+    /// Record a vector match, keeping the best similarity seen for `id`
+    pub fn add_vector(&mut self, id: MemoryId, similarity: f32) {
+        let scores = self.entry(id, SignalScores::default());
+        scores.vector = scores.vector.max(similarity);
+    }
+
+    /// Record a memory reached by graph expansion
+    ///
+    /// Memories first found by graph expansion sit one hop out; ones already
+    /// found directly keep their depth of zero.
+    pub fn add_graph(&mut self, id: MemoryId) {
+        let scores = self.entry(
+            id,
+            SignalScores {
+                depth: 1.0,
+                ..SignalScores::default()
+            },
+        );
+        scores.graph = 1.0;
+        scores.depth = scores.depth.min(1.0);
+    }
+
+    /// The first `n` memories found, used as graph expansion seeds
+    pub fn seeds(&self, n: usize) -> Vec<MemoryId> {
+        self.entries.iter().take(n).map(|(id, _)| *id).collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl IntoIterator for FusedCandidates {
+    type Item = (MemoryId, SignalScores);
+    type IntoIter = std::vec::IntoIter<(MemoryId, SignalScores)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_overlapping_signals_merge_into_one_entry() {
+        let (a, b, c, d) = (
+            MemoryId::new(),
+            MemoryId::new(),
+            MemoryId::new(),
+            MemoryId::new(),
+        );
+        let mut candidates = FusedCandidates::new();
+        for id in [a, b] {
+            candidates.add_keyword(id);
+        }
+        for (id, similarity) in [(b, 0.9), (c, 0.5), (b, 0.7)] {
+            candidates.add_vector(id, similarity);
+        }
+        for id in [a, d] {
+            candidates.add_graph(id);
+        }
+
+        let merged: Vec<_> = candidates.into_iter().collect();
+        let ids: HashSet<_> = merged.iter().map(|(id, _)| *id).collect();
+        assert_eq!(merged.len(), 4);
+        assert_eq!(ids.len(), merged.len(), "each memory appears once");
+        assert_eq!(
+            merged.iter().map(|(id, _)| *id).collect::<Vec<_>>(),
+            vec![a, b, c, d]
+        );
+
+        let scores: HashMap<_, _> = merged.into_iter().collect();
+        assert_eq!(
+            scores[&b],
+            SignalScores {
+                keyword: 1.0,
+                vector: 0.9,
+                graph: 0.0,
+                depth: 0.0,
+            }
+        );
+        assert_eq!(scores[&a].graph_depth_score(), 1.0);
+        assert_eq!(scores[&d].graph_depth_score(), 0.5);
+
+        // The fused score sums both signals for a memory found by each
+        let config = SearchConfig::default();
+        let keyword_only = SignalScores {
+            keyword: 1.0,
+            ..SignalScores::default()
+        };
+        let fused = scores[&b].fused(&config, 0.5, 1.0);
+        let expected = keyword_only.fused(&config, 0.5, 1.0) + config.vector_weight * 0.9;
+        assert!((fused - expected).abs() < 1e-6);
+    }
+}
@@ -3,20 +3,153 @@
 //! Provides persistent storage using Turso/libSQL with native vector search,
 //! FTS5 for keyword search, and efficient indexing for graph traversal.
 
+use crate::config::{NamespaceBoundary, StaleEmbeddingPolicy};
+use crate::diagnostics::{time_operation, Operation};
 use crate::embeddings::{EmbeddingService, LocalEmbeddingService};
 use crate::error::{MnemosyneError, Result};
-use crate::storage::StorageBackend;
-use crate::types::{MemoryId, MemoryLink, MemoryNote, Namespace, SearchResult};
+use crate::storage::fusion::{FusedCandidates, SignalScores};
+use crate::storage::migrations::MigrationRegistry;
+use crate::storage::recall_cache::{RecallCache, RecallCacheKey, RecallCacheStats};
+use crate::storage::{NamespaceCount, StorageBackend};
+use crate::types::{
+    MemoryId, MemoryLink, MemoryNote, Namespace, SearchQuery, SearchResult, TieBreak,
+};
 use async_trait::async_trait;
 use chrono::Utc;
 use libsql::{params, Builder, Connection, Database};
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 use std::str::FromStr;
 use std::sync::Arc;
 use tracing::{debug, info, warn};
 
+/// Most `?` parameters one statement may bind (SQLite's
+/// `SQLITE_MAX_VARIABLE_NUMBER` default since 3.32)
+pub(crate) const MAX_SQL_VARIABLES: usize = 32766;
+
+/// Rows that refer to a memory, removed with it by `delete_memory`
+///
+/// Each statement takes the memory ID as `?1` and runs only if its table
+/// exists, since the LibSQL and standard SQLite schemas differ. Evaluation
+/// features go before the evaluations they belong to. The keyword index is
+/// cleaned up by triggers; `memory_vectors` is deleted from here because the
+/// migrations that add its trigger are not applied everywhere.
+const MEMORY_REFERENCES: &[(&str, &str)] = &[
+    (
+        "relevance_features",
+        "DELETE FROM relevance_features WHERE evaluation_id IN
+         (SELECT id FROM context_evaluations WHERE context_type = 'memory' AND context_id = ?1)",
+    ),
+    (
+        "context_evaluations",
+        "DELETE FROM context_evaluations WHERE context_type = 'memory' AND context_id = ?1",
+    ),
+    (
+        "memory_links",
+        "DELETE FROM memory_links WHERE source_id = ?1 OR target_id = ?1",
+    ),
+    (
+        "memory_embeddings",
+        "DELETE FROM memory_embeddings WHERE memory_id = ?1",
+    ),
+    (
+        "memory_vectors",
+        "DELETE FROM memory_vectors WHERE memory_id = ?1",
+    ),
+    (
+        "memory_modification_log",
+        "DELETE FROM memory_modification_log WHERE memory_id = ?1",
+    ),
+    (
+        "memory_modifications",
+        "DELETE FROM memory_modifications WHERE memory_id = ?1",
+    ),
+    (
+        "importance_history",
+        "DELETE FROM importance_history WHERE memory_id = ?1",
+    ),
+    (
+        "memory_coaccesses",
+        "DELETE FROM memory_coaccesses WHERE memory_id_1 = ?1 OR memory_id_2 = ?1",
+    ),
+    (
+        "memories",
+        "UPDATE memories SET superseded_by = NULL WHERE superseded_by = ?1",
+    ),
+];
+
+/// Where `namespace` ends up when `from` moves to `to`, if it moves
+///
+/// A project's sessions keep their session IDs when the project moves to
+/// another project; moved anywhere else they join `to` itself.
+fn moved_namespace(namespace: &Namespace, from: &Namespace, to: &Namespace) -> Option<Namespace> {
+    if namespace == from {
+        return Some(to.clone());
+    }
+    match (namespace, from) {
+        (
+            Namespace::Session {
+                project,
+                session_id,
+            },
+            Namespace::Project { name },
+        ) if project == name => Some(match to {
+            Namespace::Project { name } => Namespace::Session {
+                project: name.clone(),
+                session_id: session_id.clone(),
+            },
+            _ => to.clone(),
+        }),
+        _ => None,
+    }
+}
+
+/// `tokenize` option from an FTS5 `CREATE VIRTUAL TABLE` statement
+///
+/// Falls back to FTS5's default tokenizer when the option is absent.
+fn fts_tokenize_spec(create_sql: &str) -> String {
+    let Some(start) = create_sql.to_ascii_lowercase().find("tokenize") else {
+        return "unicode61".to_string();
+    };
+    let rest = create_sql[start + "tokenize".len()..]
+        .trim_start()
+        .trim_start_matches('=')
+        .trim_start();
+
+    let mut chars = rest.chars().peekable();
+    match chars.peek().copied() {
+        Some(quote @ ('\'' | '"')) => {
+            chars.next();
+            let mut spec = String::new();
+            while let Some(c) = chars.next() {
+                if c == quote {
+                    // A doubled quote is an escaped quote
+                    if chars.peek() == Some(&quote) {
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                spec.push(c);
+            }
+            spec
+        }
+        _ => rest
+            .split(|c: char| c == ',' || c == ')')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_string(),
+    }
+}
+
+/// Compare tokenizer specs regardless of spacing
+fn normalize_fts_spec(spec: &str) -> String {
+    spec.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
 /// Parse SQL file into individual statements, handling multi-line constructs like triggers
-fn parse_sql_statements(sql: &str) -> Vec<String> {
+pub(crate) fn parse_sql_statements(sql: &str) -> Vec<String> {
     let mut statements = Vec::new();
     let mut current = String::new();
     let mut depth: i32 = 0; // Track BEGIN/END nesting depth
@@ -59,6 +192,116 @@ fn parse_sql_statements(sql: &str) -> Vec<String> {
     statements
 }
 
+/// Schema state of a database, as reported by `mnemosyne init`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MigrationStatus {
+    /// Recorded schema version (`None` before versions were recorded)
+    pub schema_version: Option<u32>,
+    /// Version this build migrates to
+    pub target_version: u32,
+    /// Migrations not yet applied, in order
+    pub pending: Vec<String>,
+}
+
+impl MigrationStatus {
+    /// Whether a migration run would change nothing
+    pub fn is_current(&self) -> bool {
+        self.pending.is_empty() && self.schema_version == Some(self.target_version)
+    }
+}
+
+/// `SearchQuery` type, tag, and importance filters as SQL conditions on a
+/// `memories` row
+///
+/// Conditions are written against `{t}`, replaced by the table name or alias
+/// of the query they are added to.
+#[derive(Debug, Default)]
+struct RowFilter {
+    conditions: Vec<String>,
+    values: Vec<libsql::Value>,
+}
+
+impl RowFilter {
+    fn from_query(query: &SearchQuery) -> Self {
+        let mut filter = Self::default();
+        let placeholders = |n: usize| vec!["?"; n].join(", ");
+
+        if !query.memory_types.is_empty() {
+            filter.conditions.push(format!(
+                "{{t}}.memory_type IN ({})",
+                placeholders(query.memory_types.len())
+            ));
+            filter.values.extend(
+                query
+                    .memory_types
+                    .iter()
+                    .map(|t| libsql::Value::Text(t.as_str().to_string())),
+            );
+        }
+        if !query.tags_any.is_empty() {
+            filter.conditions.push(format!(
+                "EXISTS (SELECT 1 FROM json_each({{t}}.tags) WHERE value IN ({}))",
+                placeholders(query.tags_any.len())
+            ));
+            filter.values.extend(
+                query
+                    .tags_any
+                    .iter()
+                    .map(|tag| libsql::Value::Text(tag.clone())),
+            );
+        }
+        for tag in &query.tags_all {
+            filter
+                .conditions
+                .push("EXISTS (SELECT 1 FROM json_each({t}.tags) WHERE value = ?)".to_string());
+            filter.values.push(libsql::Value::Text(tag.clone()));
+        }
+        if let Some(min_importance) = query.min_importance {
+            filter.conditions.push("{t}.importance >= ?".to_string());
+            filter
+                .values
+                .push(libsql::Value::Integer(min_importance as i64));
+        }
+
+        filter
+    }
+
+    /// `AND`-prefixed conditions on `table` (empty without filters)
+    fn clause(&self, table: &str) -> String {
+        self.conditions
+            .iter()
+            .map(|condition| format!(" AND {}", condition.replace("{t}", table)))
+            .collect()
+    }
+
+    /// Parameters for [`clause`](Self::clause), in order
+    fn values(&self) -> impl Iterator<Item = libsql::Value> + '_ {
+        self.values.iter().cloned()
+    }
+}
+
+/// Embedding coverage relative to one model
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct EmbeddingModelStatus {
+    /// The model compared against
+    pub model: String,
+    /// Active memories with an embedding
+    pub embedded: usize,
+    /// Of those, embedded by a different model
+    pub stale: usize,
+}
+
+impl EmbeddingModelStatus {
+    /// Share of embedded memories on a stale model (0.0 when none are embedded)
+    pub fn stale_fraction(&self) -> f64 {
+        if self.embedded == 0 {
+            0.0
+        } else {
+            self.stale as f64 / self.embedded as f64
+        }
+    }
+}
+
 /// Database schema type
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum SchemaType {
@@ -72,9 +315,21 @@ pub enum SchemaType {
 pub struct LibsqlStorage {
     db: Database,
     embedding_service: Option<Arc<LocalEmbeddingService>>,
+    /// Model that embeds queries; vector search compares only against
+    /// memories embedded by it, per `search_config.stale_embeddings`
+    active_embedding_model: std::sync::RwLock<Option<String>>,
+    /// Set once stale embeddings have been reported
+    stale_embeddings_reported: std::sync::atomic::AtomicBool,
     search_config: crate::config::SearchConfig,
+    /// Custom memory types writes may use (`[memory_types.custom]`)
+    memory_types: crate::config::MemoryTypesConfig,
+    /// Set when `search_config.recall_cache_size` is non-zero
+    recall_cache: Option<Arc<RecallCache>>,
     schema_type: SchemaType,
     db_path: String,
+    /// Open for the lifetime of an in-memory database, which SQLite frees
+    /// when its last connection closes
+    memory_keepalive: Option<Connection>,
 }
 
 /// Database connection mode
@@ -391,7 +646,14 @@ impl LibsqlStorage {
                 })?
             }
             ConnectionMode::InMemory => {
-                Builder::new_local(":memory:").build().await.map_err(|e| {
+                // Every connection to ":memory:" opens a separate, empty
+                // database. A uniquely named shared-cache database is seen by
+                // all of this handle's connections and by no other handle.
+                let uri = format!(
+                    "file:mnemosyne-{}?mode=memory&cache=shared",
+                    uuid::Uuid::new_v4()
+                );
+                Builder::new_local(uri).build().await.map_err(|e| {
                     MnemosyneError::Database(format!("Failed to create in-memory database: {}", e))
                 })?
             }
@@ -435,6 +697,13 @@ impl LibsqlStorage {
 
         debug!("LibSQL database connection established");
 
+        let memory_keepalive = match mode {
+            ConnectionMode::InMemory => Some(db.connect().map_err(|e| {
+                MnemosyneError::Database(format!("Failed to connect to in-memory database: {}", e))
+            })?),
+            _ => None,
+        };
+
         // Detect schema type by checking if embedding column exists in memories table
         // LibSQL schema: embedding stored as F32_BLOB in memories table (native vector support)
         // StandardSQLite schema: embeddings stored in separate memory_embeddings table
@@ -460,9 +729,16 @@ impl LibsqlStorage {
         let storage = Self {
             db,
             embedding_service: None,
+            active_embedding_model: std::sync::RwLock::new(None),
+            stale_embeddings_reported: std::sync::atomic::AtomicBool::new(false),
             search_config: crate::config::SearchConfig::default(),
+            memory_types: crate::config::ConfigManager::load_section("custom memory types", |s| {
+                s.memory_types
+            }),
+            recall_cache: None,
             schema_type,
             db_path,
+            memory_keepalive,
         };
 
         // Verify database health and run migrations (skip for read-only databases)
@@ -570,9 +846,14 @@ impl LibsqlStorage {
         Self {
             db,
             embedding_service: None,
+            active_embedding_model: std::sync::RwLock::new(None),
+            stale_embeddings_reported: std::sync::atomic::AtomicBool::new(false),
             search_config: crate::config::SearchConfig::default(),
+            memory_types: crate::config::MemoryTypesConfig::default(),
+            recall_cache: None,
             schema_type: SchemaType::LibSQL, // Use LibSQL schema (F32_BLOB support)
             db_path: ":memory:".to_string(), // Test databases typically use in-memory
+            memory_keepalive: None,
         }
     }
 
@@ -619,135 +900,124 @@ impl LibsqlStorage {
         Ok(())
     }
 
+    /// Registered migrations for this database's schema type
+    pub fn migration_registry(&self) -> MigrationRegistry {
+        match self.schema_type {
+            SchemaType::LibSQL => MigrationRegistry::libsql(),
+            SchemaType::StandardSQLite => MigrationRegistry::sqlite(),
+        }
+    }
+
+    /// Schema version this build migrates to: the newest registered migration
+    pub fn target_schema_version(&self) -> u32 {
+        self.migration_registry().latest_version()
+    }
+
+    /// Schema version recorded by the last migration run
+    ///
+    /// `None` for uninitialized databases and for databases last migrated
+    /// before versions were recorded.
+    pub async fn schema_version(&self) -> Result<Option<u32>> {
+        if !self.table_exists("_schema_version").await? {
+            return Ok(None);
+        }
+
+        let conn = self.get_conn()?;
+        let mut rows = conn
+            .query(
+                "SELECT version FROM _schema_version WHERE id = 1",
+                params![],
+            )
+            .await?;
+        match rows.next().await? {
+            Some(row) => Ok(Some(row.get::<i64>(0)? as u32)),
+            None => Ok(None),
+        }
+    }
+
+    /// Recorded and target schema versions, and the migrations not yet applied
+    pub async fn migration_status(&self) -> Result<MigrationStatus> {
+        let conn = self.get_conn()?;
+        let registry = self.migration_registry();
+        let pending = registry
+            .pending(&conn)
+            .await?
+            .into_iter()
+            .map(|migration| migration.name.to_string())
+            .collect();
+
+        Ok(MigrationStatus {
+            schema_version: self.schema_version().await?,
+            target_version: registry.latest_version(),
+            pending,
+        })
+    }
+
     /// Run database migrations
+    ///
+    /// Applies each pending migration from [`Self::migration_registry`], then
+    /// records the resulting schema version in `_schema_version`.
     pub async fn run_migrations(&self) -> Result<()> {
-        debug!("Running database migrations...");
+        debug!(
+            "Running database migrations (schema type: {:?})...",
+            self.schema_type
+        );
 
-        // Get a connection for migrations
         let conn = self.get_conn()?;
+        let registry = self.migration_registry();
+        registry.apply_pending(&conn).await?;
+        self.record_schema_version(&conn, &registry).await?;
 
-        // Create migrations tracking table if it doesn't exist
+        debug!("Database migrations completed");
+        Ok(())
+    }
+
+    /// Revert migrations newer than `version`, for development rollbacks
+    ///
+    /// Fails without changing anything if one of them is irreversible.
+    /// Returns the reverted versions, newest first.
+    pub async fn rollback_migrations(&self, version: u32) -> Result<Vec<u32>> {
+        let conn = self.get_conn()?;
+        let registry = self.migration_registry();
+        let reverted = registry.rollback_to(&conn, version).await?;
+        self.record_schema_version(&conn, &registry).await?;
+        Ok(reverted)
+    }
+
+    /// Record the newest applied migration as the schema version
+    async fn record_schema_version(
+        &self,
+        conn: &Connection,
+        registry: &MigrationRegistry,
+    ) -> Result<()> {
+        let version = registry
+            .applied_versions(conn)
+            .await?
+            .into_iter()
+            .max()
+            .unwrap_or(0);
         conn.execute(
-            "CREATE TABLE IF NOT EXISTS _migrations_applied (
-                migration_name TEXT PRIMARY KEY,
-                applied_at INTEGER NOT NULL
+            "CREATE TABLE IF NOT EXISTS _schema_version (
+                id INTEGER PRIMARY KEY CHECK (id = 1),
+                version INTEGER NOT NULL,
+                updated_at INTEGER NOT NULL
             )",
             params![],
         )
         .await
         .map_err(|e| {
-            MnemosyneError::Migration(format!("Failed to create migrations table: {}", e))
+            MnemosyneError::Migration(format!("Failed to create schema version table: {}", e))
         })?;
+        conn.execute(
+            "INSERT INTO _schema_version (id, version, updated_at) VALUES (1, ?, ?)
+             ON CONFLICT(id) DO UPDATE SET version = excluded.version, updated_at = excluded.updated_at
+             WHERE version != excluded.version",
+            params![version as i64, Utc::now().timestamp()],
+        )
+        .await
+        .map_err(|e| MnemosyneError::Migration(format!("Failed to record schema version: {}", e)))?;
 
-        // Manually run migrations for better control
-        let manifest_dir = env!("CARGO_MANIFEST_DIR");
-        let migration_folder = match self.schema_type {
-            SchemaType::LibSQL => "libsql",
-            SchemaType::StandardSQLite => "sqlite",
-        };
-        let migrations_path = std::path::PathBuf::from(manifest_dir)
-            .join("migrations")
-            .join(migration_folder);
-
-        debug!(
-            "Migrations path: {:?} (schema type: {:?})",
-            migrations_path, self.schema_type
-        );
-
-        // Read and execute migration files in order - different files for each schema type
-        let migration_files: Vec<&str> = match self.schema_type {
-            SchemaType::LibSQL => vec![
-                "001_initial_schema.sql",
-                "002_add_indexes.sql",
-                "003_audit_trail.sql",
-                "011_work_items.sql",
-                "012_requirement_tracking.sql",
-                "015_version_check_cache.sql",
-                // Note: LibSQL schema uses native embedding column in memories table (F32_BLOB)
-            ],
-            SchemaType::StandardSQLite => vec![
-                "001_initial_schema.sql",
-                "002_add_indexes.sql",
-                "003_fix_fts_triggers.sql",
-                // 007_evolution.sql is obsolete (columns already in 001_initial_schema.sql)
-                "011_work_items.sql",
-                "012_requirement_tracking.sql",
-                "013_add_task_and_agent_event_types.sql",
-                "014_add_specification_workflow_types.sql",
-                "016_version_check_cache.sql",
-                // 015_fix_audit_log_schema.sql is only for production databases affected by ghost migration 003
-                // Fresh databases from 001_initial_schema.sql already have correct audit_log schema
-                // Note: SQLite schema uses separate memory_embeddings table
-                // 003_add_vector_search.sql is disabled (requires sqlite-vec extension)
-            ],
-        };
-
-        for migration_file in migration_files {
-            // Check if migration already applied
-            let mut rows = conn
-                .query(
-                    "SELECT COUNT(*) FROM _migrations_applied WHERE migration_name = ?",
-                    params![migration_file],
-                )
-                .await?;
-
-            let already_applied = if let Some(row) = rows.next().await? {
-                row.get::<i64>(0).unwrap_or(0)
-            } else {
-                0
-            };
-
-            if already_applied > 0 {
-                debug!("Skipping already applied migration: {}", migration_file);
-                continue;
-            }
-            let file_path = migrations_path.join(migration_file);
-            debug!("Executing migration: {:?}", file_path);
-
-            let sql = std::fs::read_to_string(&file_path).map_err(|e| {
-                MnemosyneError::Migration(format!(
-                    "Failed to read migration file {}: {}",
-                    migration_file, e
-                ))
-            })?;
-
-            // Execute the migration SQL
-            // Parse SQL statements properly, handling multi-line statements like triggers
-            let statements = parse_sql_statements(&sql);
-            debug!(
-                "Parsed {} statements from {}",
-                statements.len(),
-                migration_file
-            );
-            for (i, statement) in statements.iter().enumerate() {
-                let statement = statement.trim();
-                if !statement.is_empty() {
-                    debug!("Executing statement {}/{}", i + 1, statements.len());
-                    conn.execute(statement, params![]).await.map_err(|e| {
-                        MnemosyneError::Migration(format!(
-                            "Failed to execute statement #{} in {}: {}\nStatement: {}",
-                            i + 1,
-                            migration_file,
-                            e,
-                            &statement[..statement.len().min(300)]
-                        ))
-                    })?;
-                }
-            }
-
-            // Record migration as applied
-            let now = Utc::now().timestamp();
-            conn.execute(
-                "INSERT INTO _migrations_applied (migration_name, applied_at) VALUES (?, ?)",
-                params![migration_file, now],
-            )
-            .await
-            .map_err(|e| MnemosyneError::Migration(format!("Failed to record migration: {}", e)))?;
-
-            info!("Executed migration: {}", migration_file);
-        }
-
-        debug!("Database migrations completed");
+        debug!("Schema version {}", version);
         Ok(())
     }
 
@@ -914,25 +1184,18 @@ impl LibsqlStorage {
         }
     }
 
-    /// Get list of applied migrations from _migrations_applied table
+    /// Names of applied migrations, oldest first
     pub async fn get_applied_migrations(&self) -> Result<Vec<String>> {
         let conn = self.get_conn()?;
+        let registry = self.migration_registry();
+        let applied = registry.applied_versions(&conn).await?;
 
-        // Check if migrations table exists first
-        if !self.table_exists("_migrations_applied").await? {
-            return Ok(Vec::new());
-        }
-
-        let query = "SELECT migration_name FROM _migrations_applied ORDER BY applied_at";
-        let mut rows = conn.query(query, ()).await?;
-
-        let mut migrations = Vec::new();
-        while let Some(row) = rows.next().await? {
-            let name: String = row.get(0)?;
-            migrations.push(name);
-        }
-
-        Ok(migrations)
+        Ok(registry
+            .migrations()
+            .iter()
+            .filter(|migration| applied.contains(&migration.version))
+            .map(|migration| migration.name.to_string())
+            .collect())
     }
 
     /// Get importance distribution as a HashMap<importance_level, count>
@@ -1025,25 +1288,8 @@ impl LibsqlStorage {
         let context: String = row.get(8)?;
 
         let memory_type_str: String = row.get(9)?;
-        let memory_type = match memory_type_str.as_str() {
-            "architecture_decision" => crate::types::MemoryType::ArchitectureDecision,
-            "code_pattern" => crate::types::MemoryType::CodePattern,
-            "bug_fix" => crate::types::MemoryType::BugFix,
-            "configuration" => crate::types::MemoryType::Configuration,
-            "constraint" => crate::types::MemoryType::Constraint,
-            "entity" => crate::types::MemoryType::Entity,
-            "insight" => crate::types::MemoryType::Insight,
-            "reference" => crate::types::MemoryType::Reference,
-            "preference" => crate::types::MemoryType::Preference,
-            "task" => crate::types::MemoryType::Task,
-            "agent_event" => crate::types::MemoryType::AgentEvent,
-            _ => {
-                return Err(MnemosyneError::Other(format!(
-                    "Unknown memory type: {}",
-                    memory_type_str
-                )))
-            }
-        };
+        // Stored as the snake_case name; unknown names are custom types
+        let memory_type = crate::types::MemoryType::from(memory_type_str);
 
         let importance: i64 = row.get(10)?;
         let confidence: f64 = row.get(11)?;
@@ -1174,13 +1420,102 @@ impl LibsqlStorage {
 
     /// Set the embedding service for this storage backend
     pub fn set_embedding_service(&mut self, service: Arc<LocalEmbeddingService>) {
+        self.set_active_embedding_model(Some(service.model_name().to_string()));
         self.embedding_service = Some(service);
     }
 
+    /// Record the model that embeds queries
+    ///
+    /// Set by [`set_embedding_service`](Self::set_embedding_service); call it
+    /// directly when queries are embedded elsewhere (e.g. by `MemoryManager`).
+    pub fn set_active_embedding_model(&self, model: Option<String>) {
+        if let Ok(mut active) = self.active_embedding_model.write() {
+            *active = model;
+        }
+        self.stale_embeddings_reported
+            .store(false, std::sync::atomic::Ordering::Relaxed);
+        if let Some(cache) = &self.recall_cache {
+            cache.clear();
+        }
+    }
+
+    /// Model that embeds queries, if known
+    pub fn active_embedding_model(&self) -> Option<String> {
+        self.active_embedding_model
+            .read()
+            .ok()
+            .and_then(|active| active.clone())
+    }
+
+    /// How many active memories carry an embedding, and how many of those
+    /// come from a model other than `model`
+    pub async fn embedding_model_status(&self, model: &str) -> Result<EmbeddingModelStatus> {
+        let has_embedding = match self.schema_type {
+            SchemaType::LibSQL => "embedding IS NOT NULL",
+            SchemaType::StandardSQLite => {
+                "EXISTS (SELECT 1 FROM memory_embeddings e WHERE e.memory_id = memories.id)"
+            }
+        };
+
+        let conn = self.get_conn()?;
+        let mut rows = conn
+            .query(
+                &format!(
+                    "SELECT COUNT(*), COALESCE(SUM(embedding_model != ?), 0) \
+                     FROM memories WHERE is_archived = 0 AND {has_embedding}"
+                ),
+                params![model.to_string()],
+            )
+            .await?;
+        let (embedded, stale): (i64, i64) = match rows.next().await? {
+            Some(row) => (row.get(0)?, row.get(1)?),
+            None => (0, 0),
+        };
+
+        Ok(EmbeddingModelStatus {
+            model: model.to_string(),
+            embedded: embedded as usize,
+            stale: stale as usize,
+        })
+    }
+
+    /// Active model to restrict vector search to, unless stale embeddings are
+    /// included
+    ///
+    /// Warns once per model when stale embeddings exist.
+    async fn vector_model_filter(&self) -> Result<Option<String>> {
+        use std::sync::atomic::Ordering;
+
+        let Some(model) = self.active_embedding_model() else {
+            return Ok(None);
+        };
+        let policy = self.search_config.stale_embeddings;
+
+        if !self.stale_embeddings_reported.swap(true, Ordering::Relaxed) {
+            let status = self.embedding_model_status(&model).await?;
+            if status.stale > 0 {
+                warn!(
+                    "{} of {} embedded memories were embedded by a model other than '{}' ({}); run 'mnemosyne embed --all' to re-embed them",
+                    status.stale,
+                    status.embedded,
+                    model,
+                    match policy {
+                        StaleEmbeddingPolicy::Include => "still compared in vector search",
+                        StaleEmbeddingPolicy::Exclude => "excluded from vector search",
+                        StaleEmbeddingPolicy::Reembed => "excluded until re-embedded",
+                    }
+                );
+            }
+        }
+
+        Ok((policy != StaleEmbeddingPolicy::Include).then_some(model))
+    }
+
     /// Generate and store embedding for a memory
     ///
     /// This method generates an embedding for the given memory content and stores it
-    /// in the memory_vectors table. If embeddings are disabled (no service), this is a no-op.
+    /// with [`store_memory_embedding`](Self::store_memory_embedding), tagged with the
+    /// service's model name. If embeddings are disabled (no service), this is a no-op.
     ///
     /// # Arguments
     /// * `memory_id` - The ID of the memory to embed
@@ -1210,8 +1545,8 @@ impl LibsqlStorage {
         debug!("Generating embedding for memory: {}", memory_id);
         let embedding = service.embed(content).await?;
 
-        // Store in memory_vectors table
-        self.store_embedding(memory_id, &embedding).await?;
+        self.store_memory_embedding(memory_id, &embedding, service.model_name())
+            .await?;
 
         info!(
             "Successfully generated and stored embedding for memory: {}",
@@ -1295,91 +1630,1334 @@ impl LibsqlStorage {
         Ok(())
     }
 
-    /// Set the search configuration
-    pub fn set_search_config(&mut self, config: crate::config::SearchConfig) {
-        self.search_config = config;
-    }
-
-    /// Perform vector similarity search
-    ///
-    /// Searches for memories with embeddings similar to the query embedding.
-    /// Uses sqlite-vec's vec_distance_cosine for similarity.
-    ///
-    /// # Arguments
-    /// * `query_embedding` - The query embedding vector
-    /// * `limit` - Maximum number of results
-    /// * `namespace` - Optional namespace filter
+    /// Store `embedding` as the memory's vector and record `model` as its
+    /// `embedding_model`
     ///
-    /// # Returns
-    /// * Vector of (MemoryId, similarity_score) tuples, sorted by similarity (desc)
-    pub async fn vector_search(
+    /// Writes wherever the schema keeps embeddings: the `embedding` column on
+    /// LibSQL, the `memory_embeddings` table on standard SQLite.
+    pub async fn store_memory_embedding(
         &self,
-        query_embedding: &[f32],
-        limit: usize,
-        namespace: Option<Namespace>,
-    ) -> Result<Vec<(MemoryId, f32)>> {
-        // Skip if vector search is disabled
-        if !self.search_config.enable_vector_search {
-            debug!("Vector search disabled in config");
-            return Ok(Vec::new());
-        }
-
+        memory_id: &MemoryId,
+        embedding: &[f32],
+        model: &str,
+    ) -> Result<()> {
         let conn = self.get_conn()?;
+        let tx = conn.transaction().await?;
 
-        // Convert query embedding to JSON for libsql vector functions
-        let query_json = serde_json::to_string(query_embedding)?;
-
-        // Build query using native libsql vector functions (no vec0 extension needed)
-        // Queries the memories table's embedding column (F32_BLOB)
-        let sql = if namespace.is_some() {
-            r#"
-            SELECT id, vector_distance_cos(embedding, vector32(?)) as distance
-            FROM memories
-            WHERE embedding IS NOT NULL
-              AND is_archived = 0
-              AND namespace = ?
-            ORDER BY distance ASC
-            LIMIT ?
-            "#
-            .to_string()
-        } else {
-            r#"
-            SELECT id, vector_distance_cos(embedding, vector32(?)) as distance
-            FROM memories
-            WHERE embedding IS NOT NULL
-              AND is_archived = 0
-            ORDER BY distance ASC
-            LIMIT ?
-            "#
-            .to_string()
-        };
-
-        let mut rows = if let Some(ns) = &namespace {
-            let ns_json = serde_json::to_string(ns)?;
-            conn.query(&sql, params![query_json, ns_json, limit as i64])
+        let updated = match self.schema_type {
+            SchemaType::LibSQL => {
+                tx.execute(
+                    "UPDATE memories SET embedding = vector32(?), embedding_model = ? WHERE id = ?",
+                    params![
+                        serde_json::to_string(embedding)?,
+                        model.to_string(),
+                        memory_id.to_string()
+                    ],
+                )
                 .await?
-        } else {
-            conn.query(&sql, params![query_json, limit as i64]).await?
+            }
+            SchemaType::StandardSQLite => {
+                let updated = tx
+                    .execute(
+                        "UPDATE memories SET embedding_model = ? WHERE id = ?",
+                        params![model.to_string(), memory_id.to_string()],
+                    )
+                    .await?;
+                if updated > 0 {
+                    let bytes: Vec<u8> = embedding.iter().flat_map(|x| x.to_le_bytes()).collect();
+                    tx.execute(
+                        "INSERT OR REPLACE INTO memory_embeddings (memory_id, embedding, dimension) VALUES (?, ?, ?)",
+                        params![memory_id.to_string(), bytes, embedding.len() as i64],
+                    )
+                    .await?;
+                }
+                updated
+            }
         };
 
-        let mut results = Vec::new();
-        while let Some(row) = rows.next().await? {
-            let memory_id_str: String = row.get(0)?;
-            let distance: f64 = row.get(1)?;
-
-            // Convert distance to similarity (0 = identical, 2 = opposite)
-            // Similarity = 1 - (distance / 2), range [0, 1]
-            let similarity = 1.0 - (distance as f32 / 2.0);
+        if updated == 0 {
+            tx.rollback().await?;
+            return Err(MnemosyneError::MemoryNotFound(memory_id.to_string()));
+        }
+        tx.commit().await?;
 
-            let memory_id = MemoryId(uuid::Uuid::parse_str(&memory_id_str)?);
-            results.push((memory_id, similarity));
+        // Vector search may now rank this memory into recalls it was absent from
+        let mut rows = conn
+            .query(
+                "SELECT namespace FROM memories WHERE id = ?",
+                params![memory_id.to_string()],
+            )
+            .await?;
+        if let Some(row) = rows.next().await? {
+            let namespace: Namespace = serde_json::from_str(&row.get::<String>(0)?)?;
+            self.invalidate_recalls(&namespace);
         }
+        self.invalidate_recalls_of(*memory_id);
 
-        debug!("Vector search found {} results", results.len());
-        Ok(results)
+        Ok(())
     }
 
-    // ========================================================================
+    /// Active memories without an embedding from `model`, in ID order
+    ///
+    /// With `force`, every active memory in scope is returned. `after` pages
+    /// through the results: pass the last ID of the previous page.
+    pub async fn memories_needing_embedding(
+        &self,
+        model: &str,
+        namespace: Option<&Namespace>,
+        force: bool,
+        after: Option<&MemoryId>,
+        limit: usize,
+    ) -> Result<Vec<MemoryNote>> {
+        let (clause, mut values) =
+            self.embedding_candidates_filter(model, namespace, force, after)?;
+        values.push(libsql::Value::Integer(limit as i64));
+
+        let conn = self.get_conn()?;
+        let mut rows = conn
+            .query(
+                &format!("SELECT * FROM memories WHERE {clause} ORDER BY id LIMIT ?"),
+                values,
+            )
+            .await?;
+
+        let mut memories = Vec::new();
+        while let Some(row) = rows.next().await? {
+            memories.push(self.row_to_memory(&row).await?);
+        }
+        Ok(memories)
+    }
+
+    /// Number of memories [`memories_needing_embedding`](Self::memories_needing_embedding)
+    /// would return without a limit
+    pub async fn count_memories_needing_embedding(
+        &self,
+        model: &str,
+        namespace: Option<&Namespace>,
+        force: bool,
+        after: Option<&MemoryId>,
+    ) -> Result<usize> {
+        let (clause, values) = self.embedding_candidates_filter(model, namespace, force, after)?;
+
+        let conn = self.get_conn()?;
+        let mut rows = conn
+            .query(
+                &format!("SELECT COUNT(*) FROM memories WHERE {clause}"),
+                values,
+            )
+            .await?;
+        let count: i64 = match rows.next().await? {
+            Some(row) => row.get(0)?,
+            None => 0,
+        };
+        Ok(count as usize)
+    }
+
+    fn embedding_candidates_filter(
+        &self,
+        model: &str,
+        namespace: Option<&Namespace>,
+        force: bool,
+        after: Option<&MemoryId>,
+    ) -> Result<(String, Vec<libsql::Value>)> {
+        let mut clause = String::from("is_archived = 0");
+        let mut values = Vec::new();
+
+        if let Some(ns) = namespace {
+            clause.push_str(" AND namespace = ?");
+            values.push(libsql::Value::Text(serde_json::to_string(ns)?));
+        }
+        if let Some(id) = after {
+            clause.push_str(" AND id > ?");
+            values.push(libsql::Value::Text(id.to_string()));
+        }
+        if !force {
+            let missing = match self.schema_type {
+                SchemaType::LibSQL => "embedding IS NULL",
+                SchemaType::StandardSQLite => {
+                    "NOT EXISTS (SELECT 1 FROM memory_embeddings e WHERE e.memory_id = memories.id)"
+                }
+            };
+            clause.push_str(&format!(" AND ({missing} OR embedding_model != ?)"));
+            values.push(libsql::Value::Text(model.to_string()));
+        }
+
+        Ok((clause, values))
+    }
+
+    /// Set the search configuration
+    ///
+    /// Rebuilds the recall cache, since cached results were scored under the
+    /// previous settings.
+    pub fn set_search_config(&mut self, config: crate::config::SearchConfig) {
+        self.recall_cache = std::num::NonZeroUsize::new(config.recall_cache_size).map(|size| {
+            Arc::new(RecallCache::new(
+                size,
+                std::time::Duration::from_secs(config.recall_cache_ttl_secs),
+            ))
+        });
+        self.search_config = config;
+    }
+
+    /// Current search configuration
+    pub fn search_config(&self) -> &crate::config::SearchConfig {
+        &self.search_config
+    }
+
+    /// Set the custom memory types writes may use
+    ///
+    /// Defaults to the types declared in the config layers; set this when a
+    /// namespace's settings declare their own.
+    pub fn set_memory_types(&mut self, memory_types: crate::config::MemoryTypesConfig) {
+        self.memory_types = memory_types;
+    }
+
+    /// Attach a `ScoreBreakdown` to hybrid search results
+    pub fn set_explain_scores(&mut self, explain: bool) {
+        self.search_config.explain_scores = explain;
+        if let Some(cache) = &self.recall_cache {
+            cache.clear();
+        }
+    }
+
+    /// Recall cache counters, or `None` when caching is disabled
+    pub fn recall_cache_stats(&self) -> Option<RecallCacheStats> {
+        self.recall_cache.as_ref().map(|cache| cache.stats())
+    }
+
+    /// Drop cached recalls a write to `namespace` may have changed
+    fn invalidate_recalls(&self, namespace: &Namespace) {
+        if let Some(cache) = &self.recall_cache {
+            cache.invalidate_namespace(namespace);
+        }
+    }
+
+    /// Drop cached recalls that returned `id`
+    fn invalidate_recalls_of(&self, id: MemoryId) {
+        if let Some(cache) = &self.recall_cache {
+            cache.invalidate_memory(id);
+        }
+    }
+
+    /// Rebuild the keyword index if it uses a different tokenizer than
+    /// `search_config.fts_tokenizer`
+    ///
+    /// FTS5 fixes the tokenizer when the table is created, so this recreates
+    /// `memories_fts` with the configured tokenizer and re-indexes every
+    /// memory from the `memories` table. The sync triggers refer to the table
+    /// by name and keep working. Returns whether the index was rebuilt.
+    pub async fn apply_fts_tokenizer(&self) -> Result<bool> {
+        let wanted = self.search_config.fts_tokenizer.fts5_spec();
+        let conn = self.get_conn()?;
+
+        let mut rows = conn
+            .query(
+                "SELECT sql FROM sqlite_master WHERE type = 'table' AND name = 'memories_fts'",
+                params![],
+            )
+            .await?;
+        let current = match rows.next().await? {
+            Some(row) => fts_tokenize_spec(&row.get::<String>(0)?),
+            None => {
+                return Err(MnemosyneError::Database(
+                    "Keyword index table memories_fts is missing".to_string(),
+                ))
+            }
+        };
+        drop(rows);
+
+        if normalize_fts_spec(&current) == normalize_fts_spec(&wanted) {
+            return Ok(false);
+        }
+
+        info!(
+            "Rebuilding keyword index with tokenizer '{}' (was '{}')",
+            wanted, current
+        );
+        let tx = conn.transaction().await?;
+        tx.execute("DROP TABLE memories_fts", params![]).await?;
+        tx.execute(
+            &format!(
+                r#"
+                CREATE VIRTUAL TABLE memories_fts USING fts5(
+                    content,
+                    summary,
+                    keywords,
+                    tags,
+                    context,
+                    content='memories',
+                    content_rowid='rowid',
+                    tokenize='{}'
+                )
+                "#,
+                wanted.replace('\'', "''")
+            ),
+            params![],
+        )
+        .await?;
+        tx.execute(
+            "INSERT INTO memories_fts(memories_fts) VALUES ('rebuild')",
+            params![],
+        )
+        .await?;
+        tx.commit().await.map_err(Self::map_commit_error)?;
+
+        if let Some(cache) = &self.recall_cache {
+            cache.clear();
+        }
+        Ok(true)
+    }
+
+    /// Hybrid search driven by a `SearchQuery`
+    ///
+    /// Runs `hybrid_search` with graph expansion, with the query's type, tag,
+    /// and importance filters applied in SQL by every search path, and adds
+    /// `importance_boost * importance / 10` to each fused score before
+    /// ranking. Archived memories are never candidates.
+    pub async fn search(&self, query: &SearchQuery) -> Result<Vec<SearchResult>> {
+        debug!(
+            "Search: {} (importance_boost: {})",
+            query.query, query.importance_boost
+        );
+
+        let cache_key = match &self.recall_cache {
+            Some(cache) => {
+                let key = RecallCacheKey::new(
+                    &query.query,
+                    query.namespace.as_ref(),
+                    format!("search:{}", serde_json::to_string(query)?),
+                );
+                if let Some(results) = cache.get(&key) {
+                    debug!("Search served from recall cache");
+                    return Ok(results);
+                }
+                Some(key)
+            }
+            None => None,
+        };
+
+        let mut results = self
+            .hybrid_candidates(
+                &query.query,
+                query.namespace.clone(),
+                query.max_results,
+                true,
+                &RowFilter::from_query(query),
+            )
+            .await?;
+
+        if query.importance_boost != 0.0 {
+            for result in &mut results {
+                result.score += query.importance_boost * (result.memory.importance as f32 / 10.0);
+                if let Some(breakdown) = &mut result.score_breakdown {
+                    breakdown.fused = result.score;
+                }
+            }
+        }
+
+        Self::rank_results(&mut results, query.max_results, &query.tie_breaks);
+        if let (Some(cache), Some(key)) = (&self.recall_cache, cache_key) {
+            cache.insert(key, results.clone());
+        }
+        Ok(results)
+    }
+
+    /// Score every hybrid search candidate, unsorted
+    async fn hybrid_candidates(
+        &self,
+        query: &str,
+        namespace: Option<Namespace>,
+        max_results: usize,
+        expand_graph: bool,
+        filter: &RowFilter,
+    ) -> Result<Vec<SearchResult>> {
+        let candidates = self
+            .fuse_candidates(query, namespace, max_results, expand_graph, filter)
+            .await?;
+
+        // Fetch all memories and compute final scores
+        let now = Utc::now();
+        let mut scored_results = Vec::new();
+        for (memory_id, signals) in candidates {
+            match self.get_memory(memory_id).await {
+                Ok(memory) => scored_results.push(self.score_candidate(memory, signals, now)),
+                Err(e) => warn!("Failed to fetch memory {}: {}", memory_id, e),
+            }
+        }
+
+        Ok(scored_results)
+    }
+
+    /// Collect keyword, vector and graph signals, one entry per memory
+    async fn fuse_candidates(
+        &self,
+        query: &str,
+        namespace: Option<Namespace>,
+        max_results: usize,
+        expand_graph: bool,
+        filter: &RowFilter,
+    ) -> Result<FusedCandidates> {
+        // Collect scores from different sources, one entry per memory
+        let mut candidates = FusedCandidates::new();
+
+        // 1. Keyword search
+        let keyword_results = self
+            .keyword_candidates(query, namespace.clone(), filter)
+            .await?;
+        debug!("Keyword search found {} results", keyword_results.len());
+
+        for result in &keyword_results {
+            candidates.add_keyword(result.memory.id);
+        }
+
+        // 2. Vector search (if embedding service available and query non-empty)
+        if !query.is_empty()
+            && self.embedding_service.is_some()
+            && self.search_config.enable_vector_search
+        {
+            // Generate query embedding
+            if let Some(service) = &self.embedding_service {
+                match service.embed(query).await {
+                    Ok(query_embedding) => {
+                        // Perform vector search
+                        let vector_results = self
+                            .vector_candidates(
+                                &query_embedding,
+                                max_results * 2,
+                                namespace.clone(),
+                                filter,
+                            )
+                            .await?;
+                        debug!("Vector search found {} results", vector_results.len());
+
+                        for (memory_id, similarity) in vector_results {
+                            candidates.add_vector(memory_id, similarity);
+                        }
+                    }
+                    Err(e) => {
+                        warn!("Failed to generate query embedding: {}", e);
+                    }
+                }
+            }
+        }
+
+        // 3. Graph expansion (if enabled)
+        let use_graph = expand_graph && self.search_config.enable_graph_expansion;
+        if use_graph && !candidates.is_empty() {
+            debug!("Expanding graph from {} seed memories", candidates.len());
+            let seed_ids = candidates.seeds(5);
+            let graph_memories = self
+                .graph_candidates(
+                    &seed_ids,
+                    self.search_config.max_graph_depth,
+                    namespace.clone(),
+                    filter,
+                )
+                .await?;
+
+            for memory in graph_memories {
+                candidates.add_graph(memory.id);
+            }
+        }
+
+        if candidates.is_empty() {
+            debug!("No results from any search source");
+        }
+        Ok(candidates)
+    }
+
+    /// Final score of one candidate from its signals and the memory's
+    /// importance and age
+    fn score_candidate(
+        &self,
+        memory: MemoryNote,
+        signals: SignalScores,
+        now: chrono::DateTime<Utc>,
+    ) -> SearchResult {
+        // Compute component scores
+        let importance_score = memory.importance as f32 / 10.0;
+        let recency_score = Self::recency_score(memory.created_at, now);
+        let SignalScores {
+            keyword: keyword_score,
+            vector: vector_score,
+            ..
+        } = signals;
+        let graph_depth_score = signals.graph_depth_score();
+
+        // Compute weighted final score using config weights
+        let final_score = signals.fused(&self.search_config, importance_score, recency_score);
+
+        // Determine match reason
+        let match_reason = if vector_score > keyword_score && vector_score > graph_depth_score {
+            format!("vector_similarity ({:.2})", final_score)
+        } else if keyword_score > 0.0 {
+            format!("keyword_match ({:.2})", final_score)
+        } else {
+            format!("graph_expansion ({:.2})", final_score)
+        };
+
+        let score_breakdown =
+            self.search_config
+                .explain_scores
+                .then_some(crate::types::ScoreBreakdown {
+                    keyword: keyword_score,
+                    vector: vector_score,
+                    graph: graph_depth_score,
+                    importance: importance_score,
+                    recency: recency_score,
+                    fused: final_score,
+                });
+
+        SearchResult {
+            memory,
+            score: final_score,
+            match_reason,
+            score_breakdown,
+        }
+    }
+
+    fn recency_score(created_at: chrono::DateTime<Utc>, now: chrono::DateTime<Utc>) -> f32 {
+        let age_days = (now - created_at).num_days() as f32;
+        (-age_days / 30.0).exp()
+    }
+
+    /// Rank hybrid search candidates without loading them, then load and
+    /// send the top `max_results` one at a time, in ranked order
+    ///
+    /// Ranking only needs each candidate's importance and creation time, so
+    /// the first result is sent as soon as it is loaded instead of after
+    /// every candidate is. Stops early once `tx` is closed. Returns the
+    /// results sent.
+    async fn stream_ranked_candidates(
+        &self,
+        candidates: FusedCandidates,
+        max_results: usize,
+        tx: &tokio::sync::mpsc::Sender<SearchResult>,
+    ) -> Result<Vec<SearchResult>> {
+        if candidates.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let candidates: Vec<(MemoryId, SignalScores)> = candidates.into_iter().collect();
+        let conn = self.get_conn()?;
+        let mut ranking_inputs: HashMap<String, (u8, chrono::DateTime<Utc>)> = HashMap::new();
+        for chunk in candidates.chunks(MAX_SQL_VARIABLES) {
+            let placeholders = vec!["?"; chunk.len()].join(",");
+            let mut rows = conn
+                .query(
+                    &format!(
+                        "SELECT id, importance, created_at FROM memories WHERE id IN ({placeholders})"
+                    ),
+                    libsql::params_from_iter(
+                        chunk
+                            .iter()
+                            .map(|(id, _)| libsql::Value::Text(id.to_string())),
+                    ),
+                )
+                .await?;
+            while let Some(row) = rows.next().await? {
+                let importance: i64 = row.get(1)?;
+                let created_at: String = row.get(2)?;
+                let created_at = chrono::DateTime::parse_from_rfc3339(&created_at)
+                    .map_err(|e| MnemosyneError::Database(format!("Invalid created_at: {}", e)))?
+                    .with_timezone(&Utc);
+                ranking_inputs.insert(row.get(0)?, (importance as u8, created_at));
+            }
+        }
+
+        // Same score and order as `rank_results` with no tie-breaks
+        let now = Utc::now();
+        let mut ranked: Vec<(f32, MemoryId, SignalScores)> = candidates
+            .into_iter()
+            .filter_map(|(id, signals)| {
+                let (importance, created_at) = ranking_inputs.get(&id.to_string())?;
+                let score = signals.fused(
+                    &self.search_config,
+                    *importance as f32 / 10.0,
+                    Self::recency_score(*created_at, now),
+                );
+                let score = if score.is_nan() {
+                    f32::NEG_INFINITY
+                } else {
+                    score
+                };
+                Some((score, id, signals))
+            })
+            .collect();
+        ranked.sort_by(|a, b| b.0.total_cmp(&a.0).then_with(|| a.1 .0.cmp(&b.1 .0)));
+        ranked.truncate(max_results);
+
+        let mut sent = Vec::with_capacity(ranked.len());
+        for (_, memory_id, signals) in ranked {
+            let memory = match self.get_memory(memory_id).await {
+                Ok(memory) => memory,
+                Err(e) => {
+                    warn!("Failed to fetch memory {}: {}", memory_id, e);
+                    continue;
+                }
+            };
+            let result = self.score_candidate(memory, signals, now);
+            if tx.send(result.clone()).await.is_err() {
+                debug!("Hybrid search stream closed after {} results", sent.len());
+                break;
+            }
+            sent.push(result);
+        }
+        Ok(sent)
+    }
+
+    /// Keyword search restricted by `filter`
+    async fn keyword_candidates(
+        &self,
+        query: &str,
+        namespace: Option<Namespace>,
+        filter: &RowFilter,
+    ) -> Result<Vec<SearchResult>> {
+        debug!("Keyword search: {} (namespace: {:?})", query, namespace);
+        let _timer = time_operation(Operation::KeywordSearch);
+
+        let namespace_filter = match namespace {
+            Some(ns) => Some(serde_json::to_string(&ns).map_err(|e| {
+                MnemosyneError::Database(format!("Failed to serialize namespace: {}", e))
+            })?),
+            None => None,
+        };
+
+        // Convert multi-word queries to OR logic for FTS5
+        // "database architecture" -> "database OR architecture"
+        // This matches user expectations: show results containing ANY of the search terms
+        // Each term is escaped to handle hyphens and other FTS5 special characters
+        let fts_query = if query.contains(' ') {
+            query
+                .split_whitespace()
+                .map(Self::escape_fts5_query)
+                .collect::<Vec<String>>()
+                .join(" OR ")
+        } else {
+            Self::escape_fts5_query(query)
+        };
+
+        // Handle empty query - return all memories in namespace (no FTS5)
+        let mut param_values: Vec<libsql::Value> = Vec::new();
+        let (mut sql, order) = if query.trim().is_empty() {
+            // Empty query: list all memories (filtered by namespace if provided)
+            (
+                "SELECT m.* FROM memories m WHERE m.is_archived = 0".to_string(),
+                " ORDER BY m.importance DESC, m.created_at DESC",
+            )
+        } else {
+            // Non-empty query: use FTS5 full-text search with OR logic
+            param_values.push(libsql::Value::Text(fts_query));
+            (
+                "SELECT m.* FROM memories m WHERE m.rowid IN (\
+                     SELECT rowid FROM memories_fts WHERE memories_fts MATCH ?\
+                 ) AND m.is_archived = 0"
+                    .to_string(),
+                "",
+            )
+        };
+        if let Some(ns) = namespace_filter {
+            sql.push_str(" AND m.namespace = ?");
+            param_values.push(libsql::Value::Text(ns));
+        }
+        sql.push_str(&filter.clause("m"));
+        param_values.extend(filter.values());
+        sql.push_str(order);
+        sql.push_str(" LIMIT 20");
+
+        let conn = self.get_conn()?;
+        let mut rows = conn
+            .query(&sql, libsql::params_from_iter(param_values))
+            .await?;
+
+        let mut results = Vec::new();
+        while let Some(row) = rows.next().await? {
+            let memory = self.row_to_memory(&row).await?;
+            results.push(SearchResult {
+                memory,
+                score: 0.8,
+                match_reason: "keyword_match".to_string(),
+                score_breakdown: None,
+            });
+        }
+
+        debug!("Keyword search found {} results", results.len());
+        Ok(results)
+    }
+
+    /// Graph traversal returning only memories that pass `filter`
+    ///
+    /// The filter applies to the returned memories, not the walk: links
+    /// through non-matching memories are still followed. The configured
+    /// `NamespaceBoundary` does limit the walk: a memory outside it is
+    /// neither returned nor expanded from.
+    async fn graph_candidates(
+        &self,
+        seed_ids: &[MemoryId],
+        max_hops: usize,
+        namespace: Option<Namespace>,
+        filter: &RowFilter,
+    ) -> Result<Vec<MemoryNote>> {
+        debug!(
+            "Graph traverse from {} seeds, max {} hops, namespace: {:?}",
+            seed_ids.len(),
+            max_hops,
+            namespace
+        );
+
+        if seed_ids.is_empty() || max_hops == 0 {
+            return Ok(vec![]);
+        }
+        let _timer = time_operation(Operation::GraphTraverse);
+
+        // Breadth-first, one query per hop. Each hop admits neighbors by link
+        // strength, then importance, until the node budget is spent, so a
+        // dense namespace cannot expand into thousands of memories.
+        let max_nodes = self
+            .search_config
+            .max_graph_nodes
+            .clamp(1, crate::config::MAX_GRAPH_NODES);
+        let ns_json = namespace.as_ref().map(serde_json::to_string).transpose()?;
+        let conn = self.get_conn()?;
+
+        let mut order: Vec<String> = Vec::new();
+        let mut visited: HashSet<String> = HashSet::new();
+        for id in seed_ids.iter().map(|id| id.to_string()) {
+            if order.len() >= max_nodes {
+                break;
+            }
+            if visited.insert(id.clone()) {
+                order.push(id);
+            }
+        }
+
+        // Namespace each path started in, keyed by the memories on it
+        let boundary = self.search_config.namespace_boundary;
+        let mut origins: HashMap<String, Namespace> = HashMap::new();
+        if boundary != NamespaceBoundary::Any {
+            let placeholders = vec!["?"; order.len()].join(",");
+            let mut rows = conn
+                .query(
+                    &format!("SELECT id, namespace FROM memories WHERE id IN ({placeholders})"),
+                    libsql::params_from_iter(
+                        order.iter().map(|id| libsql::Value::Text(id.clone())),
+                    ),
+                )
+                .await?;
+            while let Some(row) = rows.next().await? {
+                let namespace: String = row.get(1)?;
+                origins.insert(row.get(0)?, serde_json::from_str(&namespace)?);
+            }
+        }
+
+        let mut frontier = order.clone();
+        for _ in 0..max_hops {
+            if frontier.is_empty() || order.len() >= max_nodes {
+                break;
+            }
+
+            // Each frontier node is bound with the namespace its path started
+            // in, so the boundary is applied in the query. Grouping keeps one
+            // row per neighbor (its strongest link), which leaves visited
+            // nodes from earlier hops as the only rows that can be skipped,
+            // and the LIMIT covers exactly those plus the remaining budget.
+            let frontier_rows = vec!["(?, ?)"; frontier.len()].join(",");
+            let namespace_filter = if ns_json.is_some() {
+                "AND n.namespace = ?"
+            } else {
+                ""
+            };
+            let boundary_filter = match boundary {
+                NamespaceBoundary::Strict => "AND n.namespace = f.origin",
+                NamespaceBoundary::AllowGlobal => "AND (n.namespace = f.origin OR n.namespace = ?)",
+                NamespaceBoundary::Any => "",
+            };
+            let sql = format!(
+                r#"
+                WITH frontier(id, origin) AS (VALUES {frontier_rows})
+                SELECT n.id, f.origin, MAX(ml.strength) AS strength
+                FROM frontier f
+                JOIN memory_links ml ON f.id IN (ml.source_id, ml.target_id)
+                JOIN memories n
+                  ON n.id = CASE WHEN ml.source_id = f.id THEN ml.target_id ELSE ml.source_id END
+                WHERE n.is_archived = 0
+                  AND n.id NOT IN (SELECT id FROM frontier)
+                  {namespace_filter} {boundary_filter}
+                GROUP BY n.id
+                ORDER BY strength DESC, n.importance DESC
+                LIMIT ?
+                "#
+            );
+
+            let mut param_values: Vec<libsql::Value> = Vec::with_capacity(frontier.len() * 2 + 3);
+            for id in &frontier {
+                param_values.push(libsql::Value::Text(id.clone()));
+                param_values.push(match origins.get(id) {
+                    Some(origin) => libsql::Value::Text(serde_json::to_string(origin)?),
+                    None => libsql::Value::Null,
+                });
+            }
+            if let Some(ns) = &ns_json {
+                param_values.push(libsql::Value::Text(ns.clone()));
+            }
+            if boundary == NamespaceBoundary::AllowGlobal {
+                param_values.push(libsql::Value::Text(serde_json::to_string(
+                    &Namespace::Global,
+                )?));
+            }
+            let earlier_hops = order.len() - frontier.len();
+            let limit = max_nodes - order.len() + earlier_hops;
+            param_values.push(libsql::Value::Integer(limit as i64));
+
+            let mut rows = conn
+                .query(&sql, libsql::params_from_iter(param_values))
+                .await?;
+            let mut next = Vec::new();
+            while let Some(row) = rows.next().await? {
+                let id: String = row.get(0)?;
+                if visited.contains(&id) {
+                    continue;
+                }
+                if boundary != NamespaceBoundary::Any {
+                    // Paths keep the namespace they started in
+                    let origin: String = row.get(1)?;
+                    origins.insert(id.clone(), serde_json::from_str(&origin)?);
+                }
+                visited.insert(id.clone());
+                order.push(id.clone());
+                next.push(id);
+                if order.len() >= max_nodes {
+                    debug!("Graph traversal stopped at {} node budget", max_nodes);
+                    break;
+                }
+            }
+            frontier = next;
+        }
+
+        // Load in traversal order; seeds are filtered like every other node
+        let placeholders = vec!["?"; order.len()].join(",");
+        let namespace_filter = if ns_json.is_some() {
+            "AND namespace = ?"
+        } else {
+            ""
+        };
+        let sql = format!(
+            "SELECT * FROM memories WHERE id IN ({placeholders}) AND is_archived = 0 {namespace_filter}{}",
+            filter.clause("memories")
+        );
+        let mut param_values: Vec<libsql::Value> = order
+            .iter()
+            .map(|id| libsql::Value::Text(id.clone()))
+            .collect();
+        if let Some(ns) = ns_json {
+            param_values.push(libsql::Value::Text(ns));
+        }
+        param_values.extend(filter.values());
+
+        let mut rows = conn
+            .query(&sql, libsql::params_from_iter(param_values))
+            .await?;
+        let mut by_id = HashMap::new();
+        while let Some(row) = rows.next().await? {
+            let memory = self.row_to_memory(&row).await?;
+            by_id.insert(memory.id.to_string(), memory);
+        }
+        let results: Vec<MemoryNote> = order.iter().filter_map(|id| by_id.remove(id)).collect();
+
+        debug!("Graph traversal found {} memories", results.len());
+        Ok(results)
+    }
+
+    /// Sort by score, then `tie_breaks`, then memory ID, and keep the top
+    /// `max_results`
+    fn rank_results(results: &mut Vec<SearchResult>, max_results: usize, tie_breaks: &[TieBreak]) {
+        // Treat NaN scores as lowest priority
+        for result in results.iter_mut() {
+            if result.score.is_nan() {
+                result.score = f32::NEG_INFINITY;
+            }
+        }
+        results.sort_by(|a, b| {
+            std::iter::once(TieBreak::Score)
+                .chain(tie_breaks.iter().copied())
+                .map(|key| key.compare(a, b))
+                .find(|ordering| ordering.is_ne())
+                .unwrap_or_else(|| a.memory.id.0.cmp(&b.memory.id.0))
+        });
+        results.truncate(max_results);
+    }
+
+    /// Perform vector similarity search
+    ///
+    /// Searches for memories with embeddings similar to the query embedding.
+    /// Uses sqlite-vec's vec_distance_cosine for similarity.
+    ///
+    /// # Arguments
+    /// * `query_embedding` - The query embedding vector
+    /// * `limit` - Maximum number of results
+    /// * `namespace` - Optional namespace filter
+    ///
+    /// # Returns
+    /// * Vector of (MemoryId, similarity_score) tuples, sorted by similarity (desc)
+    pub async fn vector_search(
+        &self,
+        query_embedding: &[f32],
+        limit: usize,
+        namespace: Option<Namespace>,
+    ) -> Result<Vec<(MemoryId, f32)>> {
+        self.vector_candidates(query_embedding, limit, namespace, &RowFilter::default())
+            .await
+    }
+
+    /// Vector search restricted by `filter`
+    async fn vector_candidates(
+        &self,
+        query_embedding: &[f32],
+        limit: usize,
+        namespace: Option<Namespace>,
+        filter: &RowFilter,
+    ) -> Result<Vec<(MemoryId, f32)>> {
+        // Skip if vector search is disabled
+        if !self.search_config.enable_vector_search {
+            debug!("Vector search disabled in config");
+            return Ok(Vec::new());
+        }
+        let _timer = time_operation(Operation::VectorSearch);
+
+        let model = self.vector_model_filter().await?;
+        let conn = self.get_conn()?;
+
+        // Build query using native libsql vector functions (no vec0 extension needed)
+        // Queries the memories table's embedding column (F32_BLOB)
+        let mut sql = String::from(
+            r#"
+            SELECT id, vector_distance_cos(embedding, vector32(?)) as distance
+            FROM memories
+            WHERE embedding IS NOT NULL
+              AND is_archived = 0
+            "#,
+        );
+        // Convert query embedding to JSON for libsql vector functions
+        let mut values = vec![libsql::Value::Text(serde_json::to_string(query_embedding)?)];
+        if let Some(ns) = &namespace {
+            sql.push_str("  AND namespace = ?\n");
+            values.push(libsql::Value::Text(serde_json::to_string(ns)?));
+        }
+        if let Some(model) = model {
+            // Vectors from another model are not comparable with the query
+            sql.push_str("  AND embedding_model = ?\n");
+            values.push(libsql::Value::Text(model));
+        }
+        sql.push_str(&filter.clause("memories"));
+        values.extend(filter.values());
+        sql.push_str(" ORDER BY distance ASC LIMIT ?");
+        values.push(libsql::Value::Integer(limit as i64));
+
+        let mut rows = conn.query(&sql, values).await?;
+
+        let mut results = Vec::new();
+        while let Some(row) = rows.next().await? {
+            let memory_id_str: String = row.get(0)?;
+            let distance: f64 = row.get(1)?;
+
+            // Convert distance to similarity (0 = identical, 2 = opposite)
+            // Similarity = 1 - (distance / 2), range [0, 1]
+            let similarity = 1.0 - (distance as f32 / 2.0);
+
+            let memory_id = MemoryId(uuid::Uuid::parse_str(&memory_id_str)?);
+            results.push((memory_id, similarity));
+        }
+
+        debug!("Vector search found {} results", results.len());
+        Ok(results)
+    }
+
+    /// List active memories matching every field set in `filter`
+    ///
+    /// Like `list_memories`, but all filtering happens in SQL so only matching
+    /// rows are loaded. Tags must all be present on a memory to match.
+    pub async fn list_memories_filtered(
+        &self,
+        filter: &crate::storage::MemoryFilter,
+        limit: usize,
+        sort_by: crate::storage::MemorySortOrder,
+    ) -> Result<Vec<MemoryNote>> {
+        use crate::storage::MemorySortOrder;
+
+        debug!(
+            "Listing memories (filter: {:?}, limit: {}, sort: {:?})",
+            filter, limit, sort_by
+        );
+
+        let mut conditions = vec!["is_archived = 0".to_string()];
+        let mut param_values: Vec<libsql::Value> = Vec::new();
+
+        if let Some(ns) = &filter.namespace {
+            conditions.push("namespace = ?".to_string());
+            param_values.push(libsql::Value::Text(serde_json::to_string(ns)?));
+        }
+        if let Some(memory_type) = &filter.memory_type {
+            conditions.push("memory_type = ?".to_string());
+            param_values.push(libsql::Value::Text(memory_type.as_str().to_string()));
+        }
+        for tag in &filter.tags {
+            conditions.push(
+                "EXISTS (SELECT 1 FROM json_each(memories.tags) WHERE value = ?)".to_string(),
+            );
+            param_values.push(libsql::Value::Text(tag.clone()));
+        }
+        if let Some(min_importance) = filter.min_importance {
+            conditions.push("importance >= ?".to_string());
+            param_values.push(libsql::Value::Integer(min_importance as i64));
+        }
+        if let Some(since) = filter.since {
+            conditions.push("created_at >= ?".to_string());
+            param_values.push(libsql::Value::Text(since.to_rfc3339()));
+        }
+
+        let order_clause = match sort_by {
+            MemorySortOrder::Recent => "created_at DESC",
+            MemorySortOrder::Importance => "importance DESC, created_at DESC",
+            MemorySortOrder::AccessCount => "access_count DESC, created_at DESC",
+        };
+        let sql = format!(
+            "SELECT * FROM memories WHERE {} ORDER BY {} LIMIT ?",
+            conditions.join(" AND "),
+            order_clause
+        );
+        param_values.push(libsql::Value::Integer(limit as i64));
+
+        let conn = self.get_conn()?;
+        let mut rows = conn
+            .query(&sql, libsql::params_from_iter(param_values))
+            .await?;
+
+        let mut memories = Vec::new();
+        while let Some(row) = rows.next().await? {
+            memories.push(self.row_to_memory(&row).await?);
+        }
+
+        debug!("Listed {} filtered memories", memories.len());
+        Ok(memories)
+    }
+
+    /// Count active memories per tag, most used first
+    pub async fn tag_counts(&self, namespace: Option<&Namespace>) -> Result<Vec<(String, usize)>> {
+        let mut sql = String::from(
+            "SELECT tag.value, COUNT(DISTINCT memories.id) AS uses \
+             FROM memories, json_each(memories.tags) AS tag \
+             WHERE memories.is_archived = 0",
+        );
+        let mut param_values: Vec<libsql::Value> = Vec::new();
+        if let Some(ns) = namespace {
+            sql.push_str(" AND memories.namespace = ?");
+            param_values.push(libsql::Value::Text(serde_json::to_string(ns)?));
+        }
+        sql.push_str(" GROUP BY tag.value ORDER BY uses DESC, tag.value");
+
+        let conn = self.get_conn()?;
+        let mut rows = conn
+            .query(&sql, libsql::params_from_iter(param_values))
+            .await?;
+
+        let mut counts = Vec::new();
+        while let Some(row) = rows.next().await? {
+            let tag: String = row.get(0)?;
+            let uses: i64 = row.get(1)?;
+            counts.push((tag, uses as usize));
+        }
+        Ok(counts)
+    }
+
+    /// Aggregate counts for `mnemosyne stats`, computed in SQL
+    ///
+    /// Runs a handful of aggregate queries rather than loading memories.
+    pub async fn memory_stats(
+        &self,
+        namespace: Option<&Namespace>,
+        top_tags: usize,
+    ) -> Result<crate::storage::MemoryStats> {
+        use crate::storage::{MemoryStats, TagCount};
+
+        let ns_json = namespace.map(serde_json::to_string).transpose()?;
+        let ns_clause = if ns_json.is_some() {
+            " AND memories.namespace = ?"
+        } else {
+            ""
+        };
+        let ns_params = || {
+            ns_json
+                .iter()
+                .map(|ns| libsql::Value::Text(ns.clone()))
+                .collect::<Vec<_>>()
+        };
+        let has_embedding = match self.schema_type {
+            SchemaType::LibSQL => "memories.embedding IS NOT NULL",
+            SchemaType::StandardSQLite => {
+                "EXISTS (SELECT 1 FROM memory_embeddings e WHERE e.memory_id = memories.id)"
+            }
+        };
+
+        let conn = self.get_conn()?;
+        let mut stats = MemoryStats::default();
+
+        let sql = format!(
+            r#"
+            SELECT
+                COUNT(*),
+                COALESCE(SUM(is_archived = 0), 0),
+                COALESCE(SUM(is_archived = 1), 0),
+                AVG(CASE WHEN is_archived = 0 THEN importance END),
+                COALESCE(SUM(is_archived = 0 AND {has_embedding}), 0)
+            FROM memories
+            WHERE 1 = 1{ns_clause}
+            "#
+        );
+        let mut rows = conn
+            .query(&sql, libsql::params_from_iter(ns_params()))
+            .await?;
+        if let Some(row) = rows.next().await? {
+            stats.total = row.get::<i64>(0)? as usize;
+            stats.active = row.get::<i64>(1)? as usize;
+            stats.archived = row.get::<i64>(2)? as usize;
+            stats.average_importance = row.get::<Option<f64>>(3)?.unwrap_or(0.0);
+            stats.embedded = row.get::<i64>(4)? as usize;
+        }
+
+        let sql = format!(
+            "SELECT memory_type, COUNT(*) FROM memories \
+             WHERE is_archived = 0{ns_clause} GROUP BY memory_type"
+        );
+        let mut rows = conn
+            .query(&sql, libsql::params_from_iter(ns_params()))
+            .await?;
+        while let Some(row) = rows.next().await? {
+            let memory_type: String = row.get(0)?;
+            let count: i64 = row.get(1)?;
+            stats.by_type.insert(memory_type, count as usize);
+        }
+
+        let sql = format!(
+            "SELECT COUNT(*) FROM memory_links \
+             JOIN memories ON memories.id = memory_links.source_id \
+             WHERE memories.is_archived = 0{ns_clause}"
+        );
+        let mut rows = conn
+            .query(&sql, libsql::params_from_iter(ns_params()))
+            .await?;
+        if let Some(row) = rows.next().await? {
+            stats.links = row.get::<i64>(0)? as usize;
+        }
+
+        stats.top_tags = self
+            .tag_counts(namespace)
+            .await?
+            .into_iter()
+            .take(top_tags)
+            .map(|(tag, count)| TagCount { tag, count })
+            .collect();
+
+        if stats.active > 0 {
+            stats.embedding_coverage = stats.embedded as f64 / stats.active as f64;
+            stats.link_density = stats.links as f64 / stats.active as f64;
+        }
+        Ok(stats)
+    }
+
+    /// Replace every tag in `from` with `to`, returning how many memories changed
+    ///
+    /// A memory carrying `to` already (or several of `from`) ends up with a
+    /// single `to`; other tags keep their order. Archived memories are
+    /// included so a later unarchive doesn't bring the old tag back. The
+    /// update runs in one transaction.
+    pub async fn rename_tags(
+        &self,
+        from: &[String],
+        to: &str,
+        namespace: Option<&Namespace>,
+    ) -> Result<usize> {
+        let to = to.trim();
+        if to.is_empty() {
+            return Err(MnemosyneError::ValidationError(
+                "Tag name cannot be empty".to_string(),
+            ));
+        }
+        let from: Vec<&String> = from.iter().filter(|t| t.as_str() != to).collect();
+        if from.is_empty() {
+            return Ok(0);
+        }
+
+        let placeholders = vec!["?"; from.len()].join(", ");
+        let mut sql = format!(
+            r#"
+            UPDATE memories SET
+                tags = (
+                    SELECT json_group_array(value) FROM (
+                        SELECT CASE WHEN value IN ({placeholders}) THEN ? ELSE value END AS value,
+                               MIN(key) AS position
+                        FROM json_each(memories.tags)
+                        GROUP BY 1
+                        ORDER BY position
+                    )
+                ),
+                updated_at = ?
+            WHERE EXISTS (
+                SELECT 1 FROM json_each(memories.tags) WHERE value IN ({placeholders})
+            )
+            "#
+        );
+
+        let tag_values = from.iter().map(|t| libsql::Value::Text(t.to_string()));
+        let mut param_values: Vec<libsql::Value> = tag_values.clone().collect();
+        param_values.push(libsql::Value::Text(to.to_string()));
+        param_values.push(libsql::Value::Text(Utc::now().to_rfc3339()));
+        param_values.extend(tag_values);
+        if let Some(ns) = namespace {
+            sql.push_str(" AND namespace = ?");
+            param_values.push(libsql::Value::Text(serde_json::to_string(ns)?));
+        }
+
+        let conn = self.get_conn()?;
+        let tx = conn.transaction().await?;
+        let changed = tx
+            .execute(&sql, libsql::params_from_iter(param_values))
+            .await?;
+        tx.commit().await.map_err(Self::map_commit_error)?;
+
+        // The audit log only accepts its fixed set of operations
+        self.log_audit(
+            "update",
+            None,
+            serde_json::json!({
+                "action": "rename_tags",
+                "from": from,
+                "to": to,
+                "namespace": namespace,
+                "memories_changed": changed,
+            }),
+        )
+        .await?;
+
+        // Tag filters in cached searches may now match differently
+        if let Some(cache) = &self.recall_cache {
+            cache.clear();
+        }
+
+        debug!("Renamed tags {:?} -> {} on {} memories", from, to, changed);
+        Ok(changed as usize)
+    }
+
+    /// Move memories and evaluation records from `from` to `to`, returning
+    /// how many memories moved
+    ///
+    /// Archived memories move too. Without `merge`, fails before changing
+    /// anything if a destination namespace already holds memories. The
+    /// update runs in one transaction.
+    async fn move_namespace(&self, from: &Namespace, to: &Namespace, merge: bool) -> Result<usize> {
+        to.validate().map_err(MnemosyneError::ValidationError)?;
+        if from == to {
+            return Err(MnemosyneError::ValidationError(format!(
+                "Cannot move namespace {} onto itself",
+                from
+            )));
+        }
+
+        let conn = self.get_conn()?;
+        let mut memory_moves = Vec::new();
+        let mut rows = conn
+            .query("SELECT DISTINCT namespace FROM memories", params![])
+            .await?;
+        while let Some(row) = rows.next().await? {
+            let stored: String = row.get(0)?;
+            let namespace: Namespace = serde_json::from_str(&stored)?;
+            if let Some(dest) = moved_namespace(&namespace, from, to) {
+                memory_moves.push((stored, serde_json::to_string(&dest)?, dest));
+            }
+        }
+
+        if !merge {
+            for (_, dest_json, dest) in &memory_moves {
+                let mut rows = conn
+                    .query(
+                        "SELECT COUNT(*) FROM memories WHERE namespace = ?",
+                        params![dest_json.clone()],
+                    )
+                    .await?;
+                let count: i64 = match rows.next().await? {
+                    Some(row) => row.get(0)?,
+                    None => 0,
+                };
+                if count > 0 {
+                    return Err(MnemosyneError::ValidationError(format!(
+                        "Namespace {} already has memories; merge into it instead",
+                        dest
+                    )));
+                }
+            }
+        }
+
+        // Evaluation records name namespaces by their display form
+        let mut evaluation_moves = Vec::new();
+        if self.table_exists("context_evaluations").await? {
+            let mut rows = conn
+                .query(
+                    "SELECT DISTINCT namespace FROM context_evaluations",
+                    params![],
+                )
+                .await?;
+            while let Some(row) = rows.next().await? {
+                let stored: String = row.get(0)?;
+                let Ok(namespace) = stored.parse::<Namespace>() else {
+                    continue;
+                };
+                if let Some(dest) = moved_namespace(&namespace, from, to) {
+                    evaluation_moves.push((stored, dest.to_string()));
+                }
+            }
+        }
+
+        let now = Utc::now().to_rfc3339();
+        let tx = conn.transaction().await?;
+        let mut moved = 0;
+        for (stored, dest_json, _) in &memory_moves {
+            moved += tx
+                .execute(
+                    "UPDATE memories SET namespace = ?, updated_at = ? WHERE namespace = ?",
+                    params![dest_json.clone(), now.clone(), stored.clone()],
+                )
+                .await?;
+        }
+        for (stored, dest) in &evaluation_moves {
+            tx.execute(
+                "UPDATE context_evaluations SET namespace = ? WHERE namespace = ?",
+                params![dest.clone(), stored.clone()],
+            )
+            .await?;
+        }
+        tx.commit().await.map_err(Self::map_commit_error)?;
+
+        // Cached recalls are keyed by namespace
+        if let Some(cache) = &self.recall_cache {
+            cache.clear();
+        }
+
+        self.log_audit(
+            "update",
+            None,
+            serde_json::json!({
+                "action": if merge { "merge_namespace" } else { "rename_namespace" },
+                "from": from,
+                "to": to,
+                "memories_moved": moved,
+            }),
+        )
+        .await?;
+
+        debug!("Moved {} memories from {} to {}", moved, from, to);
+        Ok(moved as usize)
+    }
+
+    // ========================================================================
     // Evolution System Methods
     // ========================================================================
 
@@ -1430,6 +3008,10 @@ impl LibsqlStorage {
             ],
         )
         .await?;
+        // Importance feeds every recall score, so any cached ranking may change
+        if let Some(cache) = &self.recall_cache {
+            cache.clear();
+        }
 
         Ok(())
     }
@@ -1486,6 +3068,7 @@ impl LibsqlStorage {
             params![now.timestamp(), now.to_rfc3339(), memory_id.to_string()],
         )
         .await?;
+        self.invalidate_recalls_of(*memory_id);
 
         Ok(())
     }
@@ -1507,6 +3090,10 @@ impl LibsqlStorage {
             params![Utc::now().to_rfc3339(), memory_id.to_string()],
         )
         .await?;
+        // The memory may rejoin any cached recall in its namespace
+        if let Some(cache) = &self.recall_cache {
+            cache.clear();
+        }
 
         Ok(())
     }
@@ -1565,6 +3152,10 @@ impl LibsqlStorage {
             ],
         )
         .await?;
+        // The superseded memory is archived and must leave cached recalls
+        if let Some(cache) = &self.recall_cache {
+            cache.clear();
+        }
 
         Ok(())
     }
@@ -1785,25 +3376,51 @@ impl LibsqlStorage {
             Err(MnemosyneError::MemoryNotFound(memory_id.to_string()))
         }
     }
-}
 
-#[async_trait]
-impl StorageBackend for LibsqlStorage {
-    async fn store_memory(&self, memory: &MemoryNote) -> Result<()> {
-        debug!("Storing memory: {}", memory.id);
+    /// Store a batch of memories in a single transaction, keeping their IDs
+    ///
+    /// Either every memory (and link) is written or none are. Used by
+    /// `mnemosyne import` to restore exports; links may point at any memory
+    /// in the batch regardless of order. Embeddings are stored as provided
+    /// rather than regenerated.
+    pub async fn store_memories_atomic(&self, memories: &[MemoryNote]) -> Result<()> {
+        debug!("Storing {} memories atomically", memories.len());
+        for memory in memories {
+            self.ensure_valid(memory)?;
+        }
 
-        let conn = self.get_conn().map_err(|e| {
-            let error_msg = e.to_string();
-            if error_msg.contains("readonly") || error_msg.contains("permission") {
-                MnemosyneError::Database(
-                    "Cannot write to database: read-only or permission denied. Check file permissions and ensure WAL files (.db-wal, .db-shm) are writable.".to_string()
-                )
-            } else {
-                e
-            }
-        })?;
+        let conn = self.get_conn()?;
         let tx = conn.transaction().await?;
 
+        for memory in memories {
+            self.insert_memory_row(&tx, memory).await?;
+        }
+        for memory in memories {
+            self.insert_memory_links(&tx, memory).await?;
+        }
+
+        tx.commit().await.map_err(Self::map_commit_error)?;
+
+        for memory in memories {
+            self.log_audit(
+                "create",
+                Some(memory.id),
+                serde_json::json!({
+                    "namespace": memory.namespace,
+                    "memory_type": memory.memory_type,
+                    "importance": memory.importance,
+                    "imported": true,
+                }),
+            )
+            .await?;
+        }
+
+        debug!("Stored {} memories atomically", memories.len());
+        Ok(())
+    }
+
+    /// Insert a memory's row (without links) inside an open transaction
+    async fn insert_memory_row(&self, tx: &libsql::Transaction, memory: &MemoryNote) -> Result<()> {
         // Insert memory metadata - schema varies by database type
         // LibSQL: embedding column with F32_BLOB type
         // StandardSQLite: embeddings stored separately in memory_embeddings table
@@ -1874,11 +3491,7 @@ impl StorageBackend for LibsqlStorage {
                     serde_json::to_string(&memory.keywords)?,
                     serde_json::to_string(&memory.tags)?,
                     memory.context.clone(),
-                    serde_json::to_value(memory.memory_type)?
-                        .as_str()
-                        .ok_or_else(|| MnemosyneError::Database(
-                            "Failed to serialize memory_type as string".to_string()
-                        ))?,
+                    memory.memory_type.as_str(),
                     memory.importance as i64,
                     memory.confidence as f64,
                     serde_json::to_string(&memory.related_files)?,
@@ -1907,11 +3520,7 @@ impl StorageBackend for LibsqlStorage {
                     serde_json::to_string(&memory.keywords)?,
                     serde_json::to_string(&memory.tags)?,
                     memory.context.clone(),
-                    serde_json::to_value(memory.memory_type)?
-                        .as_str()
-                        .ok_or_else(|| MnemosyneError::Database(
-                            "Failed to serialize memory_type as string".to_string()
-                        ))?,
+                    memory.memory_type.as_str(),
                     memory.importance as i64,
                     memory.confidence as f64,
                     serde_json::to_string(&memory.related_files)?,
@@ -1927,7 +3536,17 @@ impl StorageBackend for LibsqlStorage {
             .await?;
         }
 
-        // Store links
+        Ok(())
+    }
+
+    /// Insert a memory's outgoing links inside an open transaction
+    ///
+    /// Link targets must already exist, so batch inserts add every row first.
+    async fn insert_memory_links(
+        &self,
+        tx: &libsql::Transaction,
+        memory: &MemoryNote,
+    ) -> Result<()> {
         for link in &memory.links {
             let link_type_str = serde_json::to_value(link.link_type)?
                 .as_str()
@@ -1953,20 +3572,74 @@ impl StorageBackend for LibsqlStorage {
             .await?;
         }
 
-        tx.commit().await.map_err(|e| {
+        Ok(())
+    }
+
+    /// Reject a memory that fails `MemoryNote::validate`, or whose custom type
+    /// is not declared, before touching the database
+    fn ensure_valid(&self, memory: &MemoryNote) -> Result<()> {
+        memory.validate().map_err(|problems| {
+            MnemosyneError::ValidationError(format!(
+                "Invalid memory {}: {}",
+                memory.id,
+                problems.join("; ")
+            ))
+        })?;
+
+        if self
+            .memory_types
+            .resolve(memory.memory_type.as_str())
+            .is_none()
+        {
+            return Err(MnemosyneError::ValidationError(format!(
+                "Invalid memory {}: custom memory type '{}' is not declared under [memory_types.custom]",
+                memory.id, memory.memory_type
+            )));
+        }
+        Ok(())
+    }
+
+    /// Translate a failed commit into an actionable error
+    fn map_commit_error(e: libsql::Error) -> MnemosyneError {
+        let error_msg = e.to_string();
+        if error_msg.contains("readonly") || error_msg.contains("permission") {
+            MnemosyneError::Database(
+                "Transaction failed: database is read-only. Ensure file and WAL files have write permissions.".to_string()
+            )
+        } else if error_msg.contains("locked") || error_msg.contains("busy") {
+            MnemosyneError::Database(
+                "Transaction failed: database is locked. Another process may be writing."
+                    .to_string(),
+            )
+        } else {
+            MnemosyneError::Database(format!("Transaction commit failed: {}", error_msg))
+        }
+    }
+}
+
+#[async_trait]
+impl StorageBackend for LibsqlStorage {
+    async fn store_memory(&self, memory: &MemoryNote) -> Result<()> {
+        debug!("Storing memory: {}", memory.id);
+        self.ensure_valid(memory)?;
+
+        let conn = self.get_conn().map_err(|e| {
             let error_msg = e.to_string();
             if error_msg.contains("readonly") || error_msg.contains("permission") {
                 MnemosyneError::Database(
-                    "Transaction failed: database is read-only. Ensure file and WAL files have write permissions.".to_string()
-                )
-            } else if error_msg.contains("locked") || error_msg.contains("busy") {
-                MnemosyneError::Database(
-                    "Transaction failed: database is locked. Another process may be writing.".to_string()
+                    "Cannot write to database: read-only or permission denied. Check file permissions and ensure WAL files (.db-wal, .db-shm) are writable.".to_string()
                 )
             } else {
-                MnemosyneError::Database(format!("Transaction commit failed: {}", error_msg))
+                e
             }
         })?;
+        let tx = conn.transaction().await?;
+
+        self.insert_memory_row(&tx, memory).await?;
+        self.insert_memory_links(&tx, memory).await?;
+
+        tx.commit().await.map_err(Self::map_commit_error)?;
+        self.invalidate_recalls(&memory.namespace);
 
         self.log_audit(
             "create",
@@ -2065,6 +3738,7 @@ impl StorageBackend for LibsqlStorage {
 
     async fn update_memory(&self, memory: &MemoryNote) -> Result<()> {
         debug!("Updating memory: {}", memory.id);
+        self.ensure_valid(memory)?;
 
         let conn = self.get_conn()?;
         let tx = conn.transaction().await?;
@@ -2088,7 +3762,8 @@ impl StorageBackend for LibsqlStorage {
                     related_entities = ?,
                     is_archived = ?,
                     superseded_by = ?,
-                    embedding = vector32(?)
+                    embedding = vector32(?),
+                    embedding_model = ?
                 WHERE id = ?
                 "#,
                 params![
@@ -2105,6 +3780,7 @@ impl StorageBackend for LibsqlStorage {
                     if memory.is_archived { 1i64 } else { 0i64 },
                     memory.superseded_by.map(|id| id.to_string()),
                     embedding_json,
+                    memory.embedding_model.clone(),
                     memory.id.to_string(),
                 ],
             )
@@ -2193,6 +3869,9 @@ impl StorageBackend for LibsqlStorage {
                 MnemosyneError::Database(format!("Transaction commit failed: {}", error_msg))
             }
         })?;
+        // Recalls that returned the memory cover a namespace it moved out of
+        self.invalidate_recalls(&memory.namespace);
+        self.invalidate_recalls_of(memory.id);
 
         self.log_audit(
             "update",
@@ -2218,6 +3897,8 @@ impl StorageBackend for LibsqlStorage {
             params![Utc::now().to_rfc3339(), id.to_string()],
         )
         .await?;
+        // Archiving only removes the memory from results
+        self.invalidate_recalls_of(id);
 
         self.log_audit("archive", Some(id), serde_json::json!({}))
             .await?;
@@ -2225,6 +3906,61 @@ impl StorageBackend for LibsqlStorage {
         Ok(())
     }
 
+    async fn delete_memory(&self, id: MemoryId) -> Result<()> {
+        debug!("Deleting memory: {}", id);
+
+        let mut statements = Vec::new();
+        for (table, sql) in MEMORY_REFERENCES {
+            if self.table_exists(table).await? {
+                statements.push(*sql);
+            }
+        }
+        let audited = self.table_exists("audit_log").await?;
+
+        let conn = self.get_conn()?;
+        // Overwrite the freed pages, so the content isn't left in the file.
+        // The connection outlives this call, so the setting is restored after.
+        let secure_delete = super::migrations::pragma_enabled(&conn, "secure_delete").await?;
+        conn.execute("PRAGMA secure_delete = ON", ()).await?;
+        let id_str = id.to_string();
+        let result = async {
+            let tx = conn.transaction().await?;
+            for sql in statements {
+                tx.execute(sql, params![id_str.clone()]).await?;
+            }
+            let deleted = tx
+                .execute("DELETE FROM memories WHERE id = ?", params![id_str.clone()])
+                .await?;
+            if deleted == 0 {
+                tx.rollback().await?;
+                return Err(MnemosyneError::MemoryNotFound(id_str.clone()));
+            }
+            // Earlier audit rows are kept; this one records the deletion
+            // without the content
+            if audited {
+                tx.execute(
+                    "INSERT INTO audit_log (operation, memory_id, metadata) VALUES ('delete', ?, '{}')",
+                    params![id_str.clone()],
+                )
+                .await?;
+            }
+            tx.commit().await.map_err(Self::map_commit_error)
+        }
+        .await;
+        if !secure_delete {
+            conn.execute("PRAGMA secure_delete = OFF", ()).await?;
+        }
+        result?;
+
+        // Other memories lost their links to it
+        if let Some(cache) = &self.recall_cache {
+            cache.clear();
+        }
+
+        info!("Permanently deleted memory {}", id);
+        Ok(())
+    }
+
     async fn vector_search(
         &self,
         embedding: &[f32],
@@ -2235,53 +3971,37 @@ impl StorageBackend for LibsqlStorage {
             "Vector search (limit: {}, namespace: {:?})",
             limit, namespace
         );
+        let _timer = time_operation(Operation::VectorSearch);
 
+        let model = self.vector_model_filter().await?;
         let conn = self.get_conn()?;
-        let query_embedding = serde_json::to_string(embedding)?;
 
-        let sql = if namespace.is_some() {
-            format!(
-                r#"
-                SELECT
-                    id, namespace, created_at, updated_at, content, summary,
-                    keywords, tags, context, memory_type, importance, confidence,
-                    related_files, related_entities, access_count, last_accessed_at,
-                    expires_at, is_archived, superseded_by, embedding_model,
-                    vector_distance_cos(embedding, vector32(?)) as distance
-                FROM memories
-                WHERE embedding IS NOT NULL
-                  AND is_archived = 0
-                  AND namespace = ?
-                ORDER BY distance ASC
-                LIMIT {}
-                "#,
-                limit
-            )
-        } else {
-            format!(
-                r#"
-                SELECT
-                    id, namespace, created_at, updated_at, content, summary,
-                    keywords, tags, context, memory_type, importance, confidence,
-                    related_files, related_entities, access_count, last_accessed_at,
-                    expires_at, is_archived, superseded_by, embedding_model,
-                    vector_distance_cos(embedding, vector32(?)) as distance
-                FROM memories
-                WHERE embedding IS NOT NULL
-                  AND is_archived = 0
-                ORDER BY distance ASC
-                LIMIT {}
-                "#,
-                limit
-            )
-        };
+        let mut sql = String::from(
+            r#"
+            SELECT
+                id, namespace, created_at, updated_at, content, summary,
+                keywords, tags, context, memory_type, importance, confidence,
+                related_files, related_entities, access_count, last_accessed_at,
+                expires_at, is_archived, superseded_by, embedding_model,
+                vector_distance_cos(embedding, vector32(?)) as distance
+            FROM memories
+            WHERE embedding IS NOT NULL
+              AND is_archived = 0
+            "#,
+        );
+        let mut values = vec![libsql::Value::Text(serde_json::to_string(embedding)?)];
+        if let Some(ref ns) = namespace {
+            sql.push_str("  AND namespace = ?\n");
+            values.push(libsql::Value::Text(serde_json::to_string(ns)?));
+        }
+        if let Some(model) = model {
+            // Vectors from another model are not comparable with the query
+            sql.push_str("  AND embedding_model = ?\n");
+            values.push(libsql::Value::Text(model));
+        }
+        sql.push_str(&format!("ORDER BY distance ASC LIMIT {}", limit));
 
-        let mut rows = if let Some(ref ns) = namespace {
-            let ns_json = serde_json::to_string(ns)?;
-            conn.query(&sql, params![query_embedding, ns_json]).await?
-        } else {
-            conn.query(&sql, params![query_embedding]).await?
-        };
+        let mut rows = conn.query(&sql, values).await?;
 
         let mut results = Vec::new();
         while let Some(row) = rows.next().await? {
@@ -2293,6 +4013,7 @@ impl StorageBackend for LibsqlStorage {
                 memory,
                 score: similarity,
                 match_reason: format!("Vector similarity: {:.2}", similarity),
+                score_breakdown: None,
             });
         }
 
@@ -2305,96 +4026,8 @@ impl StorageBackend for LibsqlStorage {
         query: &str,
         namespace: Option<Namespace>,
     ) -> Result<Vec<SearchResult>> {
-        debug!("Keyword search: {} (namespace: {:?})", query, namespace);
-
-        let namespace_filter = match namespace {
-            Some(ns) => Some(serde_json::to_string(&ns).map_err(|e| {
-                MnemosyneError::Database(format!("Failed to serialize namespace: {}", e))
-            })?),
-            None => None,
-        };
-
-        // Convert multi-word queries to OR logic for FTS5
-        // "database architecture" -> "database OR architecture"
-        // This matches user expectations: show results containing ANY of the search terms
-        // Each term is escaped to handle hyphens and other FTS5 special characters
-        let fts_query = if query.contains(' ') {
-            query
-                .split_whitespace()
-                .map(Self::escape_fts5_query)
-                .collect::<Vec<String>>()
-                .join(" OR ")
-        } else {
-            Self::escape_fts5_query(query)
-        };
-
-        // Handle empty query - return all memories in namespace (no FTS5)
-        let conn = self.get_conn()?;
-        let mut rows = if query.trim().is_empty() {
-            // Empty query: list all memories (filtered by namespace if provided)
-            let sql = if namespace_filter.is_some() {
-                r#"
-                SELECT * FROM memories
-                WHERE namespace = ? AND is_archived = 0
-                ORDER BY importance DESC, created_at DESC
-                LIMIT 20
-                "#
-            } else {
-                r#"
-                SELECT * FROM memories
-                WHERE is_archived = 0
-                ORDER BY importance DESC, created_at DESC
-                LIMIT 20
-                "#
-            };
-
-            if let Some(ref ns) = namespace_filter {
-                conn.query(sql, params![ns.clone()]).await?
-            } else {
-                conn.query(sql, params![]).await?
-            }
-        } else {
-            // Non-empty query: use FTS5 full-text search with OR logic
-            let sql = if namespace_filter.is_some() {
-                r#"
-                SELECT m.* FROM memories m
-                WHERE m.rowid IN (
-                    SELECT rowid FROM memories_fts WHERE memories_fts MATCH ?
-                )
-                AND m.namespace = ?
-                AND m.is_archived = 0
-                LIMIT 20
-                "#
-            } else {
-                r#"
-                SELECT m.* FROM memories m
-                WHERE m.rowid IN (
-                    SELECT rowid FROM memories_fts WHERE memories_fts MATCH ?
-                )
-                AND m.is_archived = 0
-                LIMIT 20
-                "#
-            };
-
-            if let Some(ref ns) = namespace_filter {
-                conn.query(sql, params![fts_query, ns.clone()]).await?
-            } else {
-                conn.query(sql, params![fts_query]).await?
-            }
-        };
-
-        let mut results = Vec::new();
-        while let Some(row) = rows.next().await? {
-            let memory = self.row_to_memory(&row).await?;
-            results.push(SearchResult {
-                memory,
-                score: 0.8,
-                match_reason: "keyword_match".to_string(),
-            });
-        }
-
-        debug!("Keyword search found {} results", results.len());
-        Ok(results)
+        self.keyword_candidates(query, namespace, &RowFilter::default())
+            .await
     }
 
     async fn graph_traverse(
@@ -2403,82 +4036,8 @@ impl StorageBackend for LibsqlStorage {
         max_hops: usize,
         namespace: Option<Namespace>,
     ) -> Result<Vec<MemoryNote>> {
-        debug!(
-            "Graph traverse from {} seeds, max {} hops, namespace: {:?}",
-            seed_ids.len(),
-            max_hops,
-            namespace
-        );
-
-        if seed_ids.is_empty() || max_hops == 0 {
-            return Ok(vec![]);
-        }
-
-        let seed_strings: Vec<String> = seed_ids.iter().map(|id| id.to_string()).collect();
-        let placeholders = seed_strings
-            .iter()
-            .map(|_| "?")
-            .collect::<Vec<_>>()
-            .join(",");
-
-        // Add namespace filter if provided
-        let namespace_filter = if namespace.is_some() {
-            "AND m.namespace = ?"
-        } else {
-            ""
-        };
-
-        let sql = format!(
-            r#"
-            WITH RECURSIVE graph_walk(memory_id, depth) AS (
-                SELECT id, 0 FROM memories WHERE id IN ({placeholders})
-                UNION
-                SELECT
-                    CASE
-                        WHEN ml.source_id = gw.memory_id THEN ml.target_id
-                        ELSE ml.source_id
-                    END as memory_id,
-                    gw.depth + 1
-                FROM graph_walk gw
-                JOIN memory_links ml ON (
-                    ml.source_id = gw.memory_id OR ml.target_id = gw.memory_id
-                )
-                WHERE gw.depth < ?
-            )
-            SELECT DISTINCT m.*
-            FROM memories m
-            JOIN graph_walk gw ON m.id = gw.memory_id
-            WHERE m.is_archived = 0 {namespace_filter}
-            ORDER BY gw.depth, m.importance DESC
-            "#,
-            placeholders = placeholders,
-            namespace_filter = namespace_filter
-        );
-
-        let conn = self.get_conn()?;
-        let mut param_values: Vec<libsql::Value> = seed_strings
-            .iter()
-            .map(|s| libsql::Value::Text(s.clone()))
-            .collect();
-        param_values.push(libsql::Value::Integer(max_hops as i64));
-
-        // Add namespace parameter if provided
-        if let Some(ns) = namespace {
-            let ns_json = serde_json::to_string(&ns)?;
-            param_values.push(libsql::Value::Text(ns_json));
-        }
-
-        let mut rows = conn
-            .query(&sql, libsql::params_from_iter(param_values))
-            .await?;
-
-        let mut results = Vec::new();
-        while let Some(row) = rows.next().await? {
-            results.push(self.row_to_memory(&row).await?);
-        }
-
-        debug!("Graph traversal found {} memories", results.len());
-        Ok(results)
+        self.graph_candidates(seed_ids, max_hops, namespace, &RowFilter::default())
+            .await
     }
 
     async fn find_consolidation_candidates(
@@ -2566,6 +4125,29 @@ impl StorageBackend for LibsqlStorage {
         Ok(())
     }
 
+    async fn increment_access_batch(&self, ids: &[MemoryId]) -> Result<()> {
+        if ids.is_empty() {
+            return Ok(());
+        }
+
+        let ids_json =
+            serde_json::to_string(&ids.iter().map(|id| id.to_string()).collect::<Vec<_>>())?;
+        let conn = self.get_conn()?;
+        conn.execute(
+            r#"
+            UPDATE memories
+            SET access_count = access_count
+                    + (SELECT COUNT(*) FROM json_each(?1) WHERE value = memories.id),
+                last_accessed_at = strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+            WHERE id IN (SELECT value FROM json_each(?1))
+            "#,
+            params![ids_json],
+        )
+        .await?;
+
+        Ok(())
+    }
+
     async fn count_memories(&self, namespace: Option<Namespace>) -> Result<usize> {
         let conn = self.get_conn()?;
         let (sql, params_vec) = if let Some(ns) = namespace {
@@ -2595,6 +4177,38 @@ impl StorageBackend for LibsqlStorage {
         }
     }
 
+    async fn list_namespaces(&self) -> Result<Vec<NamespaceCount>> {
+        let conn = self.get_conn()?;
+        let mut rows = conn
+            .query(
+                "SELECT namespace, COUNT(*) AS count FROM memories
+                 WHERE is_archived = 0
+                 GROUP BY namespace
+                 ORDER BY count DESC, namespace",
+                params![],
+            )
+            .await?;
+
+        let mut namespaces = Vec::new();
+        while let Some(row) = rows.next().await? {
+            let namespace: String = row.get(0)?;
+            let count: i64 = row.get(1)?;
+            namespaces.push(NamespaceCount {
+                namespace: serde_json::from_str(&namespace)?,
+                count: count as usize,
+            });
+        }
+        Ok(namespaces)
+    }
+
+    async fn rename_namespace(&self, from: &Namespace, to: &Namespace) -> Result<usize> {
+        self.move_namespace(from, to, false).await
+    }
+
+    async fn merge_namespace(&self, from: &Namespace, into: &Namespace) -> Result<usize> {
+        self.move_namespace(from, into, true).await
+    }
+
     async fn hybrid_search(
         &self,
         query: &str,
@@ -2604,133 +4218,104 @@ impl StorageBackend for LibsqlStorage {
     ) -> Result<Vec<SearchResult>> {
         debug!("Hybrid search: {} (expand_graph: {})", query, expand_graph);
 
-        // Collect scores from different sources
-        let mut memory_scores: std::collections::HashMap<MemoryId, (f32, f32, f32, f32)> =
-            std::collections::HashMap::new(); // (keyword, vector, graph, depth)
-
-        // 1. Keyword search
-        let keyword_results = self.keyword_search(query, namespace.clone()).await?;
-        debug!("Keyword search found {} results", keyword_results.len());
+        let cache_key = match &self.recall_cache {
+            Some(cache) => {
+                let key = RecallCacheKey::new(
+                    query,
+                    namespace.as_ref(),
+                    format!("hybrid:{}:{}", max_results, expand_graph),
+                );
+                if let Some(results) = cache.get(&key) {
+                    debug!("Hybrid search served from recall cache");
+                    return Ok(results);
+                }
+                Some(key)
+            }
+            None => None,
+        };
 
-        for result in &keyword_results {
-            memory_scores.insert(result.memory.id, (1.0, 0.0, 0.0, 0.0));
+        let mut scored_results = self
+            .hybrid_candidates(
+                query,
+                namespace,
+                max_results,
+                expand_graph,
+                &RowFilter::default(),
+            )
+            .await?;
+        Self::rank_results(&mut scored_results, max_results, &[]);
+        if let (Some(cache), Some(key)) = (&self.recall_cache, cache_key) {
+            cache.insert(key, scored_results.clone());
         }
 
-        // 2. Vector search (if embedding service available and query non-empty)
-        if !query.is_empty()
-            && self.embedding_service.is_some()
-            && self.search_config.enable_vector_search
-        {
-            // Generate query embedding
-            if let Some(service) = &self.embedding_service {
-                match service.embed(query).await {
-                    Ok(query_embedding) => {
-                        // Perform vector search
-                        let vector_results = self
-                            .vector_search(&query_embedding, max_results * 2, namespace.clone())
-                            .await?;
-                        debug!("Vector search found {} results", vector_results.len());
+        debug!("Hybrid search returned {} results", scored_results.len());
+        Ok(scored_results)
+    }
 
-                        for (memory_id, similarity) in vector_results {
-                            let entry = memory_scores
-                                .entry(memory_id)
-                                .or_insert((0.0, 0.0, 0.0, 0.0));
-                            entry.1 = similarity; // Update vector score
+    async fn hybrid_search_stream(
+        &self,
+        query: &str,
+        namespace: Option<Namespace>,
+        max_results: usize,
+        expand_graph: bool,
+        results: tokio::sync::mpsc::Sender<SearchResult>,
+    ) -> Result<()> {
+        debug!(
+            "Streaming hybrid search: {} (expand_graph: {})",
+            query, expand_graph
+        );
+
+        // Shares `hybrid_search`'s cache entries, since results are the same
+        let cache_key = match &self.recall_cache {
+            Some(cache) => {
+                let key = RecallCacheKey::new(
+                    query,
+                    namespace.as_ref(),
+                    format!("hybrid:{}:{}", max_results, expand_graph),
+                );
+                if let Some(cached) = cache.get(&key) {
+                    debug!("Hybrid search served from recall cache");
+                    for result in cached {
+                        if results.send(result).await.is_err() {
+                            break;
                         }
                     }
-                    Err(e) => {
-                        warn!("Failed to generate query embedding: {}", e);
-                    }
+                    return Ok(());
                 }
+                Some(key)
             }
-        }
+            None => None,
+        };
 
-        // 3. Graph expansion (if enabled)
-        let use_graph = expand_graph && self.search_config.enable_graph_expansion;
-        if use_graph && !memory_scores.is_empty() {
-            debug!("Expanding graph from {} seed memories", memory_scores.len());
-            let seed_ids: Vec<_> = memory_scores.keys().take(5).copied().collect();
-            let graph_memories = self
-                .graph_traverse(
-                    &seed_ids,
-                    self.search_config.max_graph_depth,
-                    namespace.clone(),
-                )
-                .await?;
+        let candidates = self
+            .fuse_candidates(
+                query,
+                namespace,
+                max_results,
+                expand_graph,
+                &RowFilter::default(),
+            )
+            .await?;
+        let sent = self
+            .stream_ranked_candidates(candidates, max_results, &results)
+            .await?;
 
-            for memory in graph_memories {
-                let entry = memory_scores
-                    .entry(memory.id)
-                    .or_insert((0.0, 0.0, 0.0, 1.0));
-                entry.2 = 1.0; // Mark as graph-expanded
-                entry.3 = entry.3.min(1.0); // Update depth
+        // Cache only complete result sets
+        if !results.is_closed() {
+            if let (Some(cache), Some(key)) = (&self.recall_cache, cache_key) {
+                cache.insert(key, sent);
             }
         }
+        Ok(())
+    }
 
-        // If no results from any source, return empty
-        if memory_scores.is_empty() {
-            debug!("No results from any search source");
-            return Ok(vec![]);
-        }
-
-        // 4. Fetch all memories and compute final scores
-        let now = Utc::now();
-        let mut scored_results = Vec::new();
-
-        for (memory_id, (keyword_score, vector_score, graph_score, depth)) in memory_scores {
-            // Fetch full memory
-            let memory = match self.get_memory(memory_id).await {
-                Ok(m) => m,
-                Err(e) => {
-                    warn!("Failed to fetch memory {}: {}", memory_id, e);
-                    continue;
-                }
-            };
-
-            // Compute component scores
-            let importance_score = memory.importance as f32 / 10.0;
-            let age_days = (now - memory.created_at).num_days() as f32;
-            let recency_score = (-age_days / 30.0).exp();
-            let graph_depth_score = if graph_score > 0.0 {
-                1.0 / (1.0 + depth)
-            } else {
-                0.0
-            };
-
-            // Compute weighted final score using config weights
-            let final_score = self.search_config.keyword_weight * keyword_score
-                + self.search_config.vector_weight * vector_score
-                + self.search_config.graph_weight * graph_depth_score
-                + self.search_config.importance_weight * importance_score
-                + self.search_config.recency_weight * recency_score;
-
-            // Determine match reason
-            let match_reason = if vector_score > keyword_score && vector_score > graph_depth_score {
-                format!("vector_similarity ({:.2})", final_score)
-            } else if keyword_score > 0.0 {
-                format!("keyword_match ({:.2})", final_score)
-            } else {
-                format!("graph_expansion ({:.2})", final_score)
-            };
-
-            scored_results.push(SearchResult {
-                memory,
-                score: final_score,
-                match_reason,
-            });
-        }
-
-        // Sort by score and limit results
-        // Handle potential NaN values gracefully - treat them as lowest priority
-        scored_results.sort_by(|a, b| {
-            b.score
-                .partial_cmp(&a.score)
-                .unwrap_or(std::cmp::Ordering::Less)
-        });
-        scored_results.truncate(max_results);
-
-        debug!("Hybrid search returned {} results", scored_results.len());
-        Ok(scored_results)
+    async fn list_memories_filtered(
+        &self,
+        filter: &crate::storage::MemoryFilter,
+        limit: usize,
+        sort_by: crate::storage::MemorySortOrder,
+    ) -> Result<Vec<MemoryNote>> {
+        LibsqlStorage::list_memories_filtered(self, filter, limit, sort_by).await
     }
 
     async fn list_memories(
@@ -2862,6 +4447,7 @@ impl StorageBackend for LibsqlStorage {
                 "archive" => crate::agents::access_control::ModificationType::Archive,
                 "unarchive" => crate::agents::access_control::ModificationType::Unarchive,
                 "supersede" => crate::agents::access_control::ModificationType::Supersede,
+                "denied" => crate::agents::access_control::ModificationType::Denied,
                 _ => {
                     return Err(MnemosyneError::Other(format!(
                         "Unknown modification type: {}",
@@ -2923,6 +4509,7 @@ impl StorageBackend for LibsqlStorage {
                 "archive" => crate::agents::access_control::ModificationType::Archive,
                 "unarchive" => crate::agents::access_control::ModificationType::Unarchive,
                 "supersede" => crate::agents::access_control::ModificationType::Supersede,
+                "denied" => crate::agents::access_control::ModificationType::Denied,
                 _ => continue, // Skip unknown types
             };
 
@@ -3812,6 +5399,7 @@ impl LibsqlStorage {
 
             Ok(Some(crate::version_check::VersionCheckCache {
                 tool,
+                channel: Default::default(),
                 latest_version,
                 release_url,
                 checked_at: checked_at as u64,
@@ -0,0 +1,634 @@
+//! Versioned, reversible schema migrations
+//!
+//! A [`MigrationRegistry`] holds the ordered migrations for one schema type.
+//! Each migration has a version number, an `up` step, and optionally a
+//! `down` step that reverts it; steps are SQL or a Rust function for changes
+//! SQL cannot express. Every step runs in its own transaction, and applied
+//! versions are recorded in the `migrations` table, so running the registry
+//! twice applies nothing the second time.
+//!
+//! Databases migrated before the registry existed recorded file names in
+//! `_migrations_applied`; those records are carried over on first use.
+
+use crate::error::{MnemosyneError, Result};
+use chrono::Utc;
+use libsql::{params, Connection};
+use std::future::Future;
+use std::pin::Pin;
+use tracing::{debug, info};
+
+/// Future returned by a Rust migration step
+pub type MigrationFuture<'a> = Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>>;
+
+/// Rust migration step, run inside the migration's transaction
+pub type MigrationFn = for<'a> fn(&'a Connection) -> MigrationFuture<'a>;
+
+/// One direction of a migration
+#[derive(Clone, Copy)]
+pub enum MigrationStep {
+    /// Semicolon-separated SQL statements (triggers may contain inner `;`)
+    Sql(&'static str),
+    /// Rust function for data changes SQL cannot express
+    Rust(MigrationFn),
+}
+
+impl MigrationStep {
+    async fn run(&self, conn: &Connection, name: &str) -> Result<()> {
+        match self {
+            MigrationStep::Sql(sql) => {
+                let statements = super::libsql::parse_sql_statements(sql);
+                debug!("Parsed {} statements from {}", statements.len(), name);
+                for (i, statement) in statements.iter().enumerate() {
+                    let statement = statement.trim();
+                    if statement.is_empty() {
+                        continue;
+                    }
+                    conn.execute(statement, params![]).await.map_err(|e| {
+                        MnemosyneError::Migration(format!(
+                            "Failed to execute statement #{} in {}: {}\nStatement: {}",
+                            i + 1,
+                            name,
+                            e,
+                            &statement[..statement.len().min(300)]
+                        ))
+                    })?;
+                }
+                Ok(())
+            }
+            MigrationStep::Rust(step) => step(conn).await,
+        }
+    }
+}
+
+/// A versioned schema change
+#[derive(Clone)]
+pub struct Migration {
+    /// Position in the registry; unique and increasing
+    pub version: u32,
+    /// Identifies the migration in logs and `mnemosyne init` output
+    pub name: &'static str,
+    up: MigrationStep,
+    down: Option<MigrationStep>,
+}
+
+impl Migration {
+    /// Migration applying `up` SQL
+    pub fn sql(version: u32, name: &'static str, up: &'static str) -> Self {
+        Self {
+            version,
+            name,
+            up: MigrationStep::Sql(up),
+            down: None,
+        }
+    }
+
+    /// Migration applying a Rust function
+    pub fn rust(version: u32, name: &'static str, up: MigrationFn) -> Self {
+        Self {
+            version,
+            name,
+            up: MigrationStep::Rust(up),
+            down: None,
+        }
+    }
+
+    /// Revert with `down` SQL
+    pub fn with_down_sql(mut self, down: &'static str) -> Self {
+        self.down = Some(MigrationStep::Sql(down));
+        self
+    }
+
+    /// Revert with a Rust function
+    pub fn with_down(mut self, down: MigrationFn) -> Self {
+        self.down = Some(MigrationStep::Rust(down));
+        self
+    }
+
+    /// Whether the migration can be rolled back
+    pub fn is_reversible(&self) -> bool {
+        self.down.is_some()
+    }
+}
+
+/// Ordered migrations for one schema type
+#[derive(Clone)]
+pub struct MigrationRegistry {
+    migrations: Vec<Migration>,
+}
+
+impl MigrationRegistry {
+    /// Registry of `migrations`, sorted by version
+    ///
+    /// Fails if two migrations share a version.
+    pub fn new(mut migrations: Vec<Migration>) -> Result<Self> {
+        migrations.sort_by_key(|m| m.version);
+        if let Some(pair) = migrations
+            .windows(2)
+            .find(|pair| pair[0].version == pair[1].version)
+        {
+            return Err(MnemosyneError::Migration(format!(
+                "Migrations {} and {} share version {}",
+                pair[0].name, pair[1].name, pair[0].version
+            )));
+        }
+        Ok(Self { migrations })
+    }
+
+    /// Migrations for the LibSQL schema (embeddings as F32_BLOB in `memories`)
+    pub fn libsql() -> Self {
+        Self::new(vec![
+            Migration::sql(
+                1,
+                "001_initial_schema.sql",
+                include_str!("../../migrations/libsql/001_initial_schema.sql"),
+            ),
+            Migration::sql(
+                2,
+                "002_add_indexes.sql",
+                include_str!("../../migrations/libsql/002_add_indexes.sql"),
+            ),
+            Migration::sql(
+                3,
+                "003_audit_trail.sql",
+                include_str!("../../migrations/libsql/003_audit_trail.sql"),
+            ),
+            Migration::sql(
+                11,
+                "011_work_items.sql",
+                include_str!("../../migrations/libsql/011_work_items.sql"),
+            )
+            .with_down_sql(DOWN_WORK_ITEMS),
+            Migration::sql(
+                12,
+                "012_requirement_tracking.sql",
+                include_str!("../../migrations/libsql/012_requirement_tracking.sql"),
+            )
+            .with_down_sql(DOWN_REQUIREMENT_TRACKING),
+            Migration::sql(
+                15,
+                "015_version_check_cache.sql",
+                include_str!("../../migrations/libsql/015_version_check_cache.sql"),
+            )
+            .with_down_sql(DOWN_VERSION_CHECK_CACHE),
+            Migration::rust(17, "017_custom_memory_types", relax_memory_type_check),
+            Migration::sql(
+                18,
+                "018_audit_delete_operation.sql",
+                include_str!("../../migrations/libsql/018_audit_delete_operation.sql"),
+            ),
+        ])
+        .expect("LibSQL migration versions are unique")
+    }
+
+    /// Migrations for the standard SQLite schema (separate embeddings table)
+    ///
+    /// 007_evolution.sql is not registered, but it is not obsolete: it is the
+    /// only source of `memories.archived_at`, which storage still reads and
+    /// writes on databases that ran it, and which 017 keeps.
+    /// 015_fix_audit_log_schema.sql only repairs databases affected by ghost
+    /// migration 003, and 003_add_vector_search.sql needs sqlite-vec.
+    pub fn sqlite() -> Self {
+        Self::new(vec![
+            Migration::sql(
+                1,
+                "001_initial_schema.sql",
+                include_str!("../../migrations/sqlite/001_initial_schema.sql"),
+            ),
+            Migration::sql(
+                2,
+                "002_add_indexes.sql",
+                include_str!("../../migrations/sqlite/002_add_indexes.sql"),
+            ),
+            Migration::sql(
+                3,
+                "003_fix_fts_triggers.sql",
+                include_str!("../../migrations/sqlite/003_fix_fts_triggers.sql"),
+            ),
+            Migration::sql(
+                11,
+                "011_work_items.sql",
+                include_str!("../../migrations/sqlite/011_work_items.sql"),
+            )
+            .with_down_sql(DOWN_WORK_ITEMS_SQLITE),
+            Migration::sql(
+                12,
+                "012_requirement_tracking.sql",
+                include_str!("../../migrations/sqlite/012_requirement_tracking.sql"),
+            )
+            .with_down_sql(DOWN_REQUIREMENT_TRACKING),
+            Migration::sql(
+                13,
+                "013_add_task_and_agent_event_types.sql",
+                include_str!("../../migrations/sqlite/013_add_task_and_agent_event_types.sql"),
+            ),
+            Migration::sql(
+                14,
+                "014_add_specification_workflow_types.sql",
+                include_str!("../../migrations/sqlite/014_add_specification_workflow_types.sql"),
+            ),
+            Migration::sql(
+                16,
+                "016_version_check_cache.sql",
+                include_str!("../../migrations/sqlite/016_version_check_cache.sql"),
+            )
+            .with_down_sql(DOWN_VERSION_CHECK_CACHE),
+            Migration::rust(17, "017_custom_memory_types", relax_memory_type_check),
+            Migration::sql(
+                18,
+                "018_audit_delete_operation.sql",
+                include_str!("../../migrations/sqlite/018_audit_delete_operation.sql"),
+            ),
+        ])
+        .expect("SQLite migration versions are unique")
+    }
+
+    /// Registered migrations, oldest first
+    pub fn migrations(&self) -> &[Migration] {
+        &self.migrations
+    }
+
+    /// Version of the newest registered migration (0 when empty)
+    pub fn latest_version(&self) -> u32 {
+        self.migrations.last().map_or(0, |m| m.version)
+    }
+
+    /// Versions recorded as applied, oldest first
+    ///
+    /// Reads without writing, so it is safe on databases opened for
+    /// inspection only.
+    pub async fn applied_versions(&self, conn: &Connection) -> Result<Vec<u32>> {
+        if table_exists(conn, "migrations").await? {
+            let mut rows = conn
+                .query("SELECT version FROM migrations ORDER BY version", params![])
+                .await?;
+            let mut versions = Vec::new();
+            while let Some(row) = rows.next().await? {
+                versions.push(row.get::<i64>(0)? as u32);
+            }
+            return Ok(versions);
+        }
+
+        let legacy = legacy_applied_names(conn).await?;
+        Ok(self
+            .migrations
+            .iter()
+            .filter(|m| legacy.iter().any(|name| name == m.name))
+            .map(|m| m.version)
+            .collect())
+    }
+
+    /// Registered migrations not yet applied, oldest first
+    pub async fn pending(&self, conn: &Connection) -> Result<Vec<&Migration>> {
+        let applied = self.applied_versions(conn).await?;
+        Ok(self
+            .migrations
+            .iter()
+            .filter(|m| !applied.contains(&m.version))
+            .collect())
+    }
+
+    /// Apply every pending migration in order; returns the applied versions
+    ///
+    /// Stops at the first failure. Migrations before it stay applied, and the
+    /// failed one leaves no trace.
+    pub async fn apply_pending(&self, conn: &Connection) -> Result<Vec<u32>> {
+        self.ensure_table(conn).await?;
+
+        let mut applied = Vec::new();
+        for migration in self.pending(conn).await? {
+            debug!("Applying migration {}", migration.name);
+            in_transaction(conn, migration.name, |tx| async move {
+                migration.up.run(&tx, migration.name).await?;
+                tx.execute(
+                    "INSERT INTO migrations (version, name, applied_at) VALUES (?, ?, ?)",
+                    params![
+                        migration.version as i64,
+                        migration.name,
+                        Utc::now().timestamp()
+                    ],
+                )
+                .await
+                .map_err(|e| {
+                    MnemosyneError::Migration(format!("Failed to record migration: {}", e))
+                })?;
+                Ok(tx)
+            })
+            .await?;
+            info!("Executed migration: {}", migration.name);
+            applied.push(migration.version);
+        }
+        Ok(applied)
+    }
+
+    /// Revert applied migrations newer than `target`, newest first; returns
+    /// the reverted versions
+    ///
+    /// Fails before changing anything if one of them has no `down` step.
+    pub async fn rollback_to(&self, conn: &Connection, target: u32) -> Result<Vec<u32>> {
+        self.ensure_table(conn).await?;
+
+        let applied = self.applied_versions(conn).await?;
+        let to_revert: Vec<&Migration> = self
+            .migrations
+            .iter()
+            .rev()
+            .filter(|m| m.version > target && applied.contains(&m.version))
+            .collect();
+
+        if let Some(irreversible) = to_revert.iter().find(|m| !m.is_reversible()) {
+            return Err(MnemosyneError::Migration(format!(
+                "Cannot roll back to version {}: {} has no down step",
+                target, irreversible.name
+            )));
+        }
+
+        let mut reverted = Vec::new();
+        for migration in to_revert {
+            let down = migration.down.expect("checked reversible above");
+            debug!("Reverting migration {}", migration.name);
+            in_transaction(conn, migration.name, |tx| async move {
+                down.run(&tx, migration.name).await?;
+                tx.execute(
+                    "DELETE FROM migrations WHERE version = ?",
+                    params![migration.version as i64],
+                )
+                .await?;
+                Ok(tx)
+            })
+            .await?;
+            info!("Reverted migration: {}", migration.name);
+            reverted.push(migration.version);
+        }
+        Ok(reverted)
+    }
+
+    /// Create the `migrations` table, carrying over legacy records
+    async fn ensure_table(&self, conn: &Connection) -> Result<()> {
+        if table_exists(conn, "migrations").await? {
+            return Ok(());
+        }
+
+        let carried_over = self.applied_versions(conn).await?;
+        conn.execute(
+            "CREATE TABLE migrations (
+                version INTEGER PRIMARY KEY,
+                name TEXT NOT NULL,
+                applied_at INTEGER NOT NULL
+            )",
+            params![],
+        )
+        .await
+        .map_err(|e| {
+            MnemosyneError::Migration(format!("Failed to create migrations table: {}", e))
+        })?;
+
+        let now = Utc::now().timestamp();
+        for migration in self
+            .migrations
+            .iter()
+            .filter(|m| carried_over.contains(&m.version))
+        {
+            conn.execute(
+                "INSERT INTO migrations (version, name, applied_at) VALUES (?, ?, ?)",
+                params![migration.version as i64, migration.name, now],
+            )
+            .await?;
+        }
+        if !carried_over.is_empty() {
+            debug!(
+                "Carried over {} migrations from _migrations_applied",
+                carried_over.len()
+            );
+        }
+        Ok(())
+    }
+}
+
+const DOWN_WORK_ITEMS: &str = "DROP TABLE IF EXISTS work_items;";
+
+/// The SQLite schema also gains the modification log in 011 (LibSQL in 003)
+const DOWN_WORK_ITEMS_SQLITE: &str = "
+DROP TABLE IF EXISTS memory_modification_log;
+DROP TABLE IF EXISTS work_items;
+";
+
+const DOWN_REQUIREMENT_TRACKING: &str = "
+DROP INDEX IF EXISTS idx_work_items_requirements;
+ALTER TABLE work_items DROP COLUMN implementation_evidence;
+ALTER TABLE work_items DROP COLUMN requirement_status;
+ALTER TABLE work_items DROP COLUMN requirements;
+";
+
+const DOWN_VERSION_CHECK_CACHE: &str = "
+DROP INDEX IF EXISTS idx_version_cache_checked_at;
+DROP TABLE IF EXISTS version_check_cache;
+";
+
+/// 017: replace the fixed `memory_type` list with a non-empty check so
+/// custom types (`MemoryType::Custom`) can be stored
+///
+/// SQLite cannot alter a CHECK constraint, so `memories` is rebuilt from its
+/// own stored definition with only that clause replaced. Columns differ by
+/// history (legacy databases carry `archived_at` from 007 and the agent
+/// columns from 008), so every existing column is copied along with the
+/// rowid the FTS index references, and the indexes, triggers, and views that
+/// depend on the table are recreated from their stored SQL.
+fn relax_memory_type_check(conn: &Connection) -> MigrationFuture<'_> {
+    Box::pin(async move {
+        let mut rows = conn
+            .query(
+                "SELECT sql FROM sqlite_master WHERE type = 'table' AND name = 'memories'",
+                params![],
+            )
+            .await?;
+        let table_sql: String = match rows.next().await? {
+            Some(row) => row.get(0)?,
+            None => return Ok(()),
+        };
+        let Some(relaxed) = relax_memory_type_clause(&table_sql) else {
+            debug!("memories.memory_type has no fixed type list, nothing to rebuild");
+            return Ok(());
+        };
+        let Some(columns_start) = relaxed.find('(') else {
+            return Err(MnemosyneError::Migration(
+                "Unexpected memories table definition".to_string(),
+            ));
+        };
+
+        // Views and triggers naming the table must go before the swap, or
+        // the rename fails on their dangling references
+        let mut rows = conn
+            .query(
+                "SELECT type, name, sql FROM sqlite_master
+                 WHERE sql IS NOT NULL
+                   AND ((type = 'index' AND tbl_name = 'memories')
+                        OR (type IN ('trigger', 'view') AND sql LIKE '%memories%'))
+                 ORDER BY rowid",
+                params![],
+            )
+            .await?;
+        let mut dependents = Vec::new();
+        while let Some(row) = rows.next().await? {
+            dependents.push((
+                row.get::<String>(0)?,
+                row.get::<String>(1)?,
+                row.get::<String>(2)?,
+            ));
+        }
+
+        let mut rows = conn
+            .query("SELECT name FROM pragma_table_info('memories')", params![])
+            .await?;
+        let mut columns = vec!["rowid".to_string()];
+        while let Some(row) = rows.next().await? {
+            columns.push(format!(
+                "\"{}\"",
+                row.get::<String>(0)?.replace('"', "\"\"")
+            ));
+        }
+        let columns = columns.join(", ");
+
+        for (kind, name, _) in dependents.iter().filter(|(kind, ..)| kind != "index") {
+            conn.execute(
+                &format!("DROP {} IF EXISTS \"{}\"", kind.to_uppercase(), name),
+                params![],
+            )
+            .await?;
+        }
+
+        conn.execute(
+            &format!("CREATE TABLE memories_new {}", &relaxed[columns_start..]),
+            params![],
+        )
+        .await?;
+        conn.execute(
+            &format!(
+                "INSERT INTO memories_new ({}) SELECT {} FROM memories",
+                columns, columns
+            ),
+            params![],
+        )
+        .await?;
+        conn.execute("DROP TABLE memories", params![]).await?;
+        conn.execute("ALTER TABLE memories_new RENAME TO memories", params![])
+            .await?;
+
+        for (_, _, sql) in &dependents {
+            conn.execute(sql, params![]).await?;
+        }
+        if table_exists(conn, "memories_fts").await? {
+            conn.execute(
+                "INSERT INTO memories_fts(memories_fts) VALUES ('rebuild')",
+                params![],
+            )
+            .await?;
+        }
+        Ok(())
+    })
+}
+
+/// `sql` with its `CHECK(memory_type IN (...))` clause replaced by a
+/// non-empty check, or `None` if it has no such clause
+fn relax_memory_type_clause(sql: &str) -> Option<String> {
+    let upper = sql.to_ascii_uppercase();
+    let mut search_from = 0;
+    while let Some(offset) = upper[search_from..].find("CHECK") {
+        let start = search_from + offset;
+        search_from = start + "CHECK".len();
+
+        let rest = &upper[search_from..];
+        let open = search_from + rest.len() - rest.trim_start().len();
+        if upper.as_bytes().get(open) != Some(&b'(') {
+            continue;
+        }
+        let mut depth = 0;
+        let close = upper[open..].char_indices().find_map(|(i, c)| {
+            match c {
+                '(' => depth += 1,
+                ')' => depth -= 1,
+                _ => {}
+            }
+            (depth == 0).then_some(open + i)
+        })?;
+
+        let condition = upper[open + 1..close].trim_start().trim_start_matches('"');
+        let is_type_list = condition
+            .strip_prefix("MEMORY_TYPE")
+            .is_some_and(|rest| rest.trim_start_matches('"').trim_start().starts_with("IN"));
+        if is_type_list {
+            return Some(format!(
+                "{}CHECK(length(memory_type) > 0){}",
+                &sql[..start],
+                &sql[close + 1..]
+            ));
+        }
+    }
+    None
+}
+
+/// Run `body` in a transaction with foreign keys off
+///
+/// SQLite ignores `PRAGMA foreign_keys` inside a transaction, so table
+/// rebuilds such as 017 would cascade deletes into `memory_links` unless
+/// enforcement is disabled first.
+async fn in_transaction<F, Fut>(conn: &Connection, name: &str, body: F) -> Result<()>
+where
+    F: FnOnce(libsql::Transaction) -> Fut,
+    Fut: Future<Output = Result<libsql::Transaction>>,
+{
+    let foreign_keys = pragma_enabled(conn, "foreign_keys").await?;
+    if foreign_keys {
+        conn.execute("PRAGMA foreign_keys = OFF", params![]).await?;
+    }
+
+    let result = async {
+        let tx = conn.transaction().await.map_err(|e| {
+            MnemosyneError::Migration(format!("Failed to begin transaction for {}: {}", name, e))
+        })?;
+        // Dropping the transaction on error rolls it back
+        let tx = body(tx).await?;
+        tx.commit()
+            .await
+            .map_err(|e| MnemosyneError::Migration(format!("Failed to commit {}: {}", name, e)))
+    }
+    .await;
+
+    if foreign_keys {
+        conn.execute("PRAGMA foreign_keys = ON", params![]).await?;
+    }
+    result
+}
+
+pub(super) async fn pragma_enabled(conn: &Connection, pragma: &str) -> Result<bool> {
+    let mut rows = conn.query(&format!("PRAGMA {}", pragma), params![]).await?;
+    Ok(match rows.next().await? {
+        Some(row) => row.get::<i64>(0)? != 0,
+        None => false,
+    })
+}
+
+async fn table_exists(conn: &Connection, table: &str) -> Result<bool> {
+    let mut rows = conn
+        .query(
+            "SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = ?",
+            params![table],
+        )
+        .await?;
+    Ok(rows.next().await?.is_some())
+}
+
+/// File names recorded before the registry existed
+async fn legacy_applied_names(conn: &Connection) -> Result<Vec<String>> {
+    if !table_exists(conn, "_migrations_applied").await? {
+        return Ok(Vec::new());
+    }
+
+    let mut rows = conn
+        .query("SELECT migration_name FROM _migrations_applied", params![])
+        .await?;
+    let mut names = Vec::new();
+    while let Some(row) = rows.next().await? {
+        names.push(row.get::<String>(0)?);
+    }
+    Ok(names)
+}
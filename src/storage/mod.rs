@@ -3,7 +3,10 @@
 //! Provides abstractions and implementations for persistent storage of memories,
 //! embeddings, links, and audit logs.
 
+pub mod fusion;
 pub mod libsql;
+pub mod migrations;
+pub mod recall_cache;
 pub mod vectors;
 
 #[cfg(test)]
@@ -15,8 +18,11 @@ pub mod libsql_workitem_tests;
 use crate::agents::access_control::{ModificationLog, ModificationType};
 use crate::agents::AgentRole;
 use crate::error::Result;
-use crate::types::{MemoryId, MemoryNote, Namespace, SearchResult};
+use crate::types::{MemoryId, MemoryNote, MemoryType, Namespace, SearchResult};
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::collections::BTreeMap;
 
 /// Storage backend trait defining all required operations
 #[async_trait]
@@ -33,6 +39,13 @@ pub trait StorageBackend: Send + Sync {
     /// Archive a memory (soft delete)
     async fn archive_memory(&self, id: MemoryId) -> Result<()>;
 
+    /// Permanently delete a memory with its links, modification history, and
+    /// evaluation records
+    ///
+    /// Unlike `archive_memory` this cannot be undone; it exists for content
+    /// that must not be kept, such as a leaked secret.
+    async fn delete_memory(&self, id: MemoryId) -> Result<()>;
+
     /// Vector similarity search
     async fn vector_search(
         &self,
@@ -49,6 +62,8 @@ pub trait StorageBackend: Send + Sync {
     ) -> Result<Vec<SearchResult>>;
 
     /// Graph traversal from seed memories
+    ///
+    /// Returns at most `SearchConfig::max_graph_nodes` memories, seeds first.
     async fn graph_traverse(
         &self,
         seed_ids: &[MemoryId],
@@ -65,9 +80,32 @@ pub trait StorageBackend: Send + Sync {
     /// Increment access counter
     async fn increment_access(&self, id: MemoryId) -> Result<()>;
 
+    /// Increment access counters in one write
+    ///
+    /// An ID listed `n` times is incremented by `n`.
+    async fn increment_access_batch(&self, ids: &[MemoryId]) -> Result<()>;
+
     /// Get memory count by namespace
     async fn count_memories(&self, namespace: Option<Namespace>) -> Result<usize>;
 
+    /// Namespaces holding active memories, largest first
+    async fn list_namespaces(&self) -> Result<Vec<NamespaceCount>>;
+
+    /// Move every memory in `from` to the unused namespace `to`, returning
+    /// how many memories moved
+    ///
+    /// A project's sessions move with it. Evaluation records follow their
+    /// namespace. Fails without changes if `to` already holds memories; use
+    /// `merge_namespace` to combine namespaces.
+    async fn rename_namespace(&self, from: &Namespace, to: &Namespace) -> Result<usize>;
+
+    /// Move every memory in `from` into `into`, which may already hold
+    /// memories, returning how many memories moved
+    ///
+    /// Memory IDs and links don't depend on the namespace, so no memory is
+    /// renumbered and links between the two namespaces are kept.
+    async fn merge_namespace(&self, from: &Namespace, into: &Namespace) -> Result<usize>;
+
     /// Hybrid search combining keyword + graph traversal
     /// (vector similarity deferred to v2.0)
     async fn hybrid_search(
@@ -78,6 +116,29 @@ pub trait StorageBackend: Send + Sync {
         expand_graph: bool,
     ) -> Result<Vec<SearchResult>>;
 
+    /// `hybrid_search` that sends each result on `results` as soon as it is
+    /// ready, in ranked order, and stops early once the receiver is dropped
+    ///
+    /// The default sends `hybrid_search`'s results once they are all ready.
+    async fn hybrid_search_stream(
+        &self,
+        query: &str,
+        namespace: Option<Namespace>,
+        max_results: usize,
+        expand_graph: bool,
+        results: tokio::sync::mpsc::Sender<SearchResult>,
+    ) -> Result<()> {
+        for result in self
+            .hybrid_search(query, namespace, max_results, expand_graph)
+            .await?
+        {
+            if results.send(result).await.is_err() {
+                break;
+            }
+        }
+        Ok(())
+    }
+
     /// List recent or important memories
     async fn list_memories(
         &self,
@@ -86,6 +147,14 @@ pub trait StorageBackend: Send + Sync {
         sort_by: MemorySortOrder,
     ) -> Result<Vec<MemoryNote>>;
 
+    /// List memories matching every field set in `filter`
+    async fn list_memories_filtered(
+        &self,
+        filter: &MemoryFilter,
+        limit: usize,
+        sort_by: MemorySortOrder,
+    ) -> Result<Vec<MemoryNote>>;
+
     /// Store a modification log entry in the audit trail
     async fn store_modification_log(&self, log: &ModificationLog) -> Result<()>;
 
@@ -133,3 +202,59 @@ pub enum MemorySortOrder {
     Importance,
     AccessCount,
 }
+
+/// Filters for listing memories; every field that is set must match
+#[derive(Debug, Clone, Default)]
+pub struct MemoryFilter {
+    /// Only memories in this namespace
+    pub namespace: Option<Namespace>,
+    /// Only memories of this type
+    pub memory_type: Option<MemoryType>,
+    /// Only memories carrying all of these tags
+    pub tags: Vec<String>,
+    /// Only memories at or above this importance
+    pub min_importance: Option<u8>,
+    /// Only memories created at or after this time
+    pub since: Option<DateTime<Utc>>,
+}
+
+/// Aggregate overview of a memory store (`mnemosyne stats`)
+///
+/// Everything except `total` and `archived` describes active memories only.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct MemoryStats {
+    /// All memories, archived included
+    pub total: usize,
+    /// Memories not archived
+    pub active: usize,
+    /// Archived memories
+    pub archived: usize,
+    /// Active memories per type (snake_case type name)
+    pub by_type: BTreeMap<String, usize>,
+    /// Most used tags, most used first
+    pub top_tags: Vec<TagCount>,
+    /// Mean importance (0 when there are no active memories)
+    pub average_importance: f64,
+    /// Active memories with an embedding
+    pub embedded: usize,
+    /// Fraction of active memories with an embedding (0.0-1.0)
+    pub embedding_coverage: f64,
+    /// Links leaving active memories
+    pub links: usize,
+    /// Links per active memory
+    pub link_density: f64,
+}
+
+/// Number of memories carrying a tag
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TagCount {
+    pub tag: String,
+    pub count: usize,
+}
+
+/// Number of active memories in a namespace
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct NamespaceCount {
+    pub namespace: Namespace,
+    pub count: usize,
+}
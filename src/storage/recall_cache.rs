@@ -0,0 +1,210 @@
+//! In-process cache of recall results
+//!
+//! Agents often repeat the same recall within a few seconds, and each one
+//! re-runs the full hybrid search. The cache holds recent result lists keyed
+//! by query, namespace and filters for a short TTL. Writes through the same
+//! storage handle invalidate entries for the namespace they touch; writes
+//! from other processes are only picked up when entries expire.
+
+use crate::types::{MemoryId, Namespace, SearchResult};
+use lru::LruCache;
+use serde::Serialize;
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Identifies one recall: the query text, its namespace scope, and every
+/// other parameter that shapes the results
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RecallCacheKey {
+    query: String,
+    /// `None` for searches across all namespaces
+    namespace: Option<String>,
+    filters: String,
+}
+
+impl RecallCacheKey {
+    /// `filters` must encode every parameter besides query and namespace
+    pub fn new(query: &str, namespace: Option<&Namespace>, filters: impl Into<String>) -> Self {
+        Self {
+            query: query.to_string(),
+            namespace: namespace.map(Namespace::to_string),
+            filters: filters.into(),
+        }
+    }
+}
+
+struct CachedResults {
+    results: Vec<SearchResult>,
+    cached_at: Instant,
+}
+
+/// Point-in-time recall cache counters
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct RecallCacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    /// Entries dropped by writes
+    pub invalidations: u64,
+    /// Entries currently cached
+    pub entries: usize,
+}
+
+impl RecallCacheStats {
+    /// Fraction of lookups served from the cache
+    pub fn hit_rate(&self) -> f64 {
+        let lookups = self.hits + self.misses;
+        if lookups == 0 {
+            0.0
+        } else {
+            self.hits as f64 / lookups as f64
+        }
+    }
+}
+
+/// LRU cache of recall results with a per-entry TTL
+pub struct RecallCache {
+    entries: Mutex<LruCache<RecallCacheKey, CachedResults>>,
+    ttl: Duration,
+    hits: AtomicU64,
+    misses: AtomicU64,
+    invalidations: AtomicU64,
+}
+
+impl RecallCache {
+    pub fn new(capacity: NonZeroUsize, ttl: Duration) -> Self {
+        Self {
+            entries: Mutex::new(LruCache::new(capacity)),
+            ttl,
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+            invalidations: AtomicU64::new(0),
+        }
+    }
+
+    /// Cached results for `key` if still fresh, recording a hit or miss
+    pub fn get(&self, key: &RecallCacheKey) -> Option<Vec<SearchResult>> {
+        let mut entries = self.lock();
+        let found = match entries.get(key) {
+            Some(cached) if cached.cached_at.elapsed() < self.ttl => Some(cached.results.clone()),
+            Some(_) => {
+                entries.pop(key);
+                None
+            }
+            None => None,
+        };
+        drop(entries);
+
+        let counter = if found.is_some() {
+            &self.hits
+        } else {
+            &self.misses
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+        found
+    }
+
+    pub fn insert(&self, key: RecallCacheKey, results: Vec<SearchResult>) {
+        self.lock().put(
+            key,
+            CachedResults {
+                results,
+                cached_at: Instant::now(),
+            },
+        );
+    }
+
+    /// Drop entries whose results a write to `namespace` may change: those
+    /// scoped to it, and those spanning all namespaces
+    pub fn invalidate_namespace(&self, namespace: &Namespace) {
+        let namespace = namespace.to_string();
+        self.invalidate_where(|key, _| {
+            key.namespace
+                .as_ref()
+                .is_none_or(|scoped| *scoped == namespace)
+        });
+    }
+
+    /// Drop entries whose results include `id`
+    pub fn invalidate_memory(&self, id: MemoryId) {
+        self.invalidate_where(|_, cached| cached.results.iter().any(|r| r.memory.id == id));
+    }
+
+    pub fn clear(&self) {
+        let mut entries = self.lock();
+        self.invalidations
+            .fetch_add(entries.len() as u64, Ordering::Relaxed);
+        entries.clear();
+    }
+
+    pub fn stats(&self) -> RecallCacheStats {
+        RecallCacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            invalidations: self.invalidations.load(Ordering::Relaxed),
+            entries: self.lock().len(),
+        }
+    }
+
+    fn invalidate_where(&self, stale: impl Fn(&RecallCacheKey, &CachedResults) -> bool) {
+        let mut entries = self.lock();
+        let keys: Vec<RecallCacheKey> = entries
+            .iter()
+            .filter(|(key, cached)| stale(key, cached))
+            .map(|(key, _)| key.clone())
+            .collect();
+        for key in &keys {
+            entries.pop(key);
+        }
+        self.invalidations
+            .fetch_add(keys.len() as u64, Ordering::Relaxed);
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, LruCache<RecallCacheKey, CachedResults>> {
+        // The cache holds no invariants a panicking holder could break
+        self.entries.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_entries_expire_after_ttl() {
+        let cache = RecallCache::new(NonZeroUsize::new(4).unwrap(), Duration::ZERO);
+        let key = RecallCacheKey::new("query", None, "");
+        cache.insert(key.clone(), Vec::new());
+
+        assert!(cache.get(&key).is_none());
+        assert_eq!(cache.stats().entries, 0);
+        assert_eq!(cache.stats().misses, 1);
+    }
+
+    #[test]
+    fn test_invalidate_namespace_keeps_other_scopes() {
+        let cache = RecallCache::new(NonZeroUsize::new(4).unwrap(), Duration::from_secs(60));
+        let project = Namespace::Project {
+            name: "app".to_string(),
+        };
+        let other = Namespace::Project {
+            name: "other".to_string(),
+        };
+        let scoped = RecallCacheKey::new("q", Some(&project), "");
+        let unscoped = RecallCacheKey::new("q", None, "");
+        let elsewhere = RecallCacheKey::new("q", Some(&other), "");
+        cache.insert(scoped.clone(), Vec::new());
+        cache.insert(unscoped.clone(), Vec::new());
+        cache.insert(elsewhere.clone(), Vec::new());
+
+        cache.invalidate_namespace(&project);
+
+        assert!(cache.get(&scoped).is_none());
+        assert!(cache.get(&unscoped).is_none());
+        assert!(cache.get(&elsewhere).is_some());
+        let stats = cache.stats();
+        assert_eq!(stats.invalidations, 2);
+        assert!((stats.hit_rate() - 1.0 / 3.0).abs() < 1e-9);
+    }
+}
@@ -43,18 +43,7 @@ CREATE TABLE IF NOT EXISTS memories (
     keywords TEXT NOT NULL,
     tags TEXT NOT NULL,
     context TEXT NOT NULL,
-    memory_type TEXT NOT NULL CHECK(memory_type IN (
-        'architecture_decision',
-        'code_pattern',
-        'bug_fix',
-        'configuration',
-        'constraint',
-        'entity',
-        'insight',
-        'reference',
-        'preference',
-        'agent_event'
-    )),
+    memory_type TEXT NOT NULL CHECK(length(memory_type) > 0),
     importance INTEGER NOT NULL CHECK(importance BETWEEN 1 AND 10),
     confidence REAL NOT NULL CHECK(confidence BETWEEN 0.0 AND 1.0),
     related_files TEXT NOT NULL DEFAULT '[]',
@@ -127,6 +127,14 @@ impl TuiApp {
             shortcut: None,
         });
 
+        command_palette.add_command(super::Command {
+            id: "ics:clear-analysis-cache".to_string(),
+            name: "Clear Analysis Cache".to_string(),
+            description: "Delete cached semantic analysis results".to_string(),
+            category: super::CommandCategory::Ics,
+            shortcut: None,
+        });
+
         command_palette.add_command(super::Command {
             id: "ics:focus-editor".to_string(),
             name: "Focus ICS Editor".to_string(),
@@ -139,6 +147,11 @@ impl TuiApp {
         let help_overlay = HelpOverlay::new();
         let notifications = NotificationManager::new();
 
+        // Restore palette history from earlier sessions
+        if let Some(history) = load_history("palette_history") {
+            command_palette = command_palette.with_history(history);
+        }
+
         Ok(Self {
             terminal,
             event_loop,
@@ -370,8 +383,12 @@ impl TuiApp {
                     "Save File",
                     "Enter filename (will be saved in current directory):",
                 )
-                .with_default(default_filename)
-                .with_validator(|s| {
+                .with_default(default_filename);
+                let dialog = match load_history("input_history") {
+                    Some(history) => dialog.with_history(history),
+                    None => dialog,
+                };
+                let dialog = dialog.with_validator(|s| {
                     if s.is_empty() {
                         Err("Filename cannot be empty".to_string())
                     } else if s.contains('/') || s.contains('\\') {
@@ -414,6 +431,21 @@ impl TuiApp {
                 self.ics_panel.toggle_highlighting();
                 tracing::debug!("ICS: Highlighting toggled");
             }
+            "ics:clear-analysis-cache" => {
+                use crate::ics::semantic_highlighter::{cache::clear_store_dir, SemanticCache};
+
+                let cleared = match SemanticCache::default_store_dir() {
+                    Some(dir) => clear_store_dir(&dir),
+                    None => Ok(()),
+                };
+                match cleared {
+                    Ok(()) => self.notify_success("Semantic analysis cache cleared"),
+                    Err(e) => {
+                        tracing::error!("ICS: Clearing analysis cache failed: {}", e);
+                        self.notify_error(&format!("Clearing analysis cache failed: {}", e));
+                    }
+                }
+            }
             "ics:focus-editor" => {
                 // Ensure ICS panel is visible and focused
                 if !self.ics_panel.is_visible() {
@@ -570,3 +602,16 @@ impl TuiApp {
         Ok(())
     }
 }
+
+/// Open a persistent history file, or `None` (logged) if unavailable
+fn load_history(name: &str) -> Option<super::widgets::CommandHistory> {
+    let path = super::widgets::CommandHistory::default_path(name)?;
+    match super::widgets::CommandHistory::open(&path, super::widgets::history::DEFAULT_MAX_HISTORY)
+    {
+        Ok(history) => Some(history),
+        Err(e) => {
+            tracing::warn!("Failed to load history {}: {}", path.display(), e);
+            None
+        }
+    }
+}
@@ -26,6 +26,6 @@ pub use notifications::{NotificationKind, NotificationManager};
 pub use terminal::{TerminalConfig, TerminalManager};
 pub use views::{ChatView, Dashboard, IcsPanel};
 pub use widgets::{
-    Command, CommandCategory, CommandPalette, ConfirmDialog, Dialog, DialogResult, HelpOverlay,
-    InputDialog, PreviewDialog, ScrollableList, StatusBar,
+    Command, CommandCategory, CommandHistory, CommandPalette, ConfirmDialog, Dialog, DialogResult,
+    HelpOverlay, InputDialog, PreviewDialog, ScrollableList, StatusBar,
 };
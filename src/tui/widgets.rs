@@ -1,8 +1,12 @@
 //! Shared widget components
 
 pub mod dialogs;
+pub mod fuzzy;
+pub mod history;
 
 pub use dialogs::{ConfirmDialog, Dialog, DialogResult, InputDialog, PreviewDialog};
+pub use fuzzy::{fuzzy_match, FuzzyMatch};
+pub use history::CommandHistory;
 
 use ratatui::{
     buffer::Buffer,
@@ -205,9 +209,12 @@ pub struct CommandPalette {
     /// All available commands
     commands: Vec<Command>,
 
-    /// Filtered commands (based on search)
+    /// Filtered commands (based on search), best match first
     filtered: Vec<usize>,
 
+    /// Matched name characters for each filtered command (for highlighting)
+    highlights: Vec<Vec<usize>>,
+
     /// Search query
     query: String,
 
@@ -222,6 +229,9 @@ pub struct CommandPalette {
 
     /// Maximum recent commands to track
     max_recent: usize,
+
+    /// Persistent history backing `recent`
+    history: Option<CommandHistory>,
 }
 
 impl CommandPalette {
@@ -230,14 +240,28 @@ impl CommandPalette {
         Self {
             commands: Vec::new(),
             filtered: Vec::new(),
+            highlights: Vec::new(),
             query: String::new(),
             selected: 0,
             visible: false,
             recent: Vec::new(),
             max_recent: 10,
+            history: None,
         }
     }
 
+    /// Persist executed commands to `history`, seeding recent commands from it
+    pub fn with_history(mut self, history: CommandHistory) -> Self {
+        self.recent = history
+            .entries()
+            .take(self.max_recent)
+            .map(str::to_string)
+            .collect();
+        self.history = Some(history);
+        self.update_filter();
+        self
+    }
+
     /// Set commands
     pub fn with_commands(mut self, commands: Vec<Command>) -> Self {
         self.commands = commands;
@@ -307,39 +331,50 @@ impl CommandPalette {
     }
 
     /// Update filtered commands based on query
+    ///
+    /// Each whitespace-separated term must fuzzy-match the command name (or,
+    /// failing that, its description at half score); terms may appear in any
+    /// order. Results are ranked by total score, then by recency of use, then
+    /// by registration order. An empty query lists every command, recently
+    /// used ones first.
     fn update_filter(&mut self) {
-        if self.query.is_empty() {
-            // Show all commands
-            self.filtered = (0..self.commands.len()).collect();
-        } else {
-            // Simple fuzzy matching - check if query chars appear in order
-            let query_lower = self.query.to_lowercase();
-            self.filtered = self
-                .commands
-                .iter()
-                .enumerate()
-                .filter(|(_, cmd)| {
-                    let name_lower = cmd.name.to_lowercase();
-                    let desc_lower = cmd.description.to_lowercase();
-
-                    // Check if all query characters appear in order
-                    let mut query_chars = query_lower.chars();
-                    let mut current_char = query_chars.next();
-
-                    for ch in name_lower.chars().chain(desc_lower.chars()) {
-                        if Some(ch) == current_char {
-                            current_char = query_chars.next();
-                            if current_char.is_none() {
-                                return true;
-                            }
-                        }
+        let terms: Vec<&str> = self.query.split_whitespace().collect();
+
+        let mut ranked: Vec<(usize, i64, Vec<usize>)> = self
+            .commands
+            .iter()
+            .enumerate()
+            .filter_map(|(i, cmd)| {
+                let mut score = 0;
+                let mut positions = Vec::new();
+                for term in &terms {
+                    if let Some(m) = fuzzy_match(term, &cmd.name) {
+                        score += m.score;
+                        positions.extend(m.positions);
+                    } else {
+                        score += fuzzy_match(term, &cmd.description)?.score / 2;
                     }
+                }
+                positions.sort_unstable();
+                positions.dedup();
+                Some((i, score, positions))
+            })
+            .collect();
 
-                    false
-                })
-                .map(|(i, _)| i)
-                .collect();
-        }
+        let recency = |idx: usize| {
+            let id = &self.commands[idx].id;
+            self.recent
+                .iter()
+                .position(|r| r == id)
+                .unwrap_or(usize::MAX)
+        };
+        // Stable sort keeps registration order for full ties
+        ranked.sort_by_key(|(idx, score, _)| (std::cmp::Reverse(*score), recency(*idx)));
+
+        (self.filtered, self.highlights) = ranked
+            .into_iter()
+            .map(|(idx, _, positions)| (idx, positions))
+            .unzip();
 
         // Clamp selected index
         if self.selected >= self.filtered.len() {
@@ -382,6 +417,12 @@ impl CommandPalette {
                 self.recent.truncate(self.max_recent);
             }
 
+            if let Some(history) = &mut self.history {
+                if let Err(e) = history.record(&cmd_id) {
+                    tracing::warn!("Failed to save command history: {}", e);
+                }
+            }
+
             self.hide();
             Some(cmd_id)
         } else {
@@ -471,11 +512,20 @@ impl Widget for &CommandPalette {
                     spans.push(Span::raw("  "));
                 }
 
-                // Command name
-                spans.push(Span::styled(
-                    &cmd.name,
-                    Style::default().add_modifier(Modifier::BOLD),
-                ));
+                // Command name, with fuzzy-matched characters highlighted
+                let name_style = Style::default().add_modifier(Modifier::BOLD);
+                let matched_style = name_style
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::UNDERLINED);
+                let matched = &self.highlights[i];
+                for (pos, ch) in cmd.name.chars().enumerate() {
+                    let style = if matched.binary_search(&pos).is_ok() {
+                        matched_style
+                    } else {
+                        name_style
+                    };
+                    spans.push(Span::styled(ch.to_string(), style));
+                }
 
                 // Description (separated by two spaces for visual clarity)
                 if !cmd.description.is_empty() {
@@ -839,6 +889,106 @@ mod tests {
         assert_eq!(palette.filtered.len(), 3);
     }
 
+    fn palette_with(names: &[&str]) -> CommandPalette {
+        let mut palette = CommandPalette::new();
+        for name in names {
+            palette.add_command(Command::new(
+                name.to_lowercase().replace(' ', "_"),
+                name.to_string(),
+                String::new(),
+                CommandCategory::Tools,
+            ));
+        }
+        palette.show();
+        palette
+    }
+
+    fn filtered_ids(palette: &CommandPalette) -> Vec<&str> {
+        palette
+            .filtered
+            .iter()
+            .map(|&i| palette.commands[i].id.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_palette_ranks_by_match_quality() {
+        let mut palette = palette_with(&["Show Config", "New Buffer File", "Unbuffered Find"]);
+
+        // Both match "nbf"; word starts outrank letters inside words
+        palette.set_query("nbf".to_string());
+        assert_eq!(
+            filtered_ids(&palette),
+            vec!["new_buffer_file", "unbuffered_find"]
+        );
+        assert_eq!(palette.highlights[0], vec![0, 4, 11]);
+
+        // Re-ranked on every keystroke
+        palette.clear_query();
+        palette.append_query('c');
+        assert_eq!(filtered_ids(&palette)[0], "show_config");
+    }
+
+    #[test]
+    fn test_palette_out_of_order_matches() {
+        let mut palette = palette_with(&["New Buffer File", "Close Window"]);
+
+        // Characters must appear in order within a term...
+        palette.set_query("fbn".to_string());
+        assert!(filtered_ids(&palette).is_empty());
+
+        // ...but separate terms may match in any order
+        palette.set_query("file new".to_string());
+        assert_eq!(filtered_ids(&palette), vec!["new_buffer_file"]);
+        assert_eq!(palette.highlights[0], vec![0, 1, 2, 11, 12, 13, 14]);
+    }
+
+    #[test]
+    fn test_palette_ties_broken_by_recency() {
+        let mut palette = palette_with(&["Open Log", "Open Logs"]);
+
+        palette.set_query("ol".to_string());
+        assert_eq!(filtered_ids(&palette), vec!["open_log", "open_logs"]);
+
+        // Use the second one; equal scores now favor it
+        palette.selected = 1;
+        assert_eq!(palette.execute_selected(), Some("open_logs".to_string()));
+        palette.show();
+        palette.set_query("ol".to_string());
+        assert_eq!(filtered_ids(&palette), vec!["open_logs", "open_log"]);
+    }
+
+    #[test]
+    fn test_palette_empty_query_returns_all_commands() {
+        let mut palette = palette_with(&["Alpha", "Beta", "Gamma"]);
+        assert_eq!(filtered_ids(&palette), vec!["alpha", "beta", "gamma"]);
+
+        palette.selected = 2;
+        palette.execute_selected();
+        palette.show();
+        assert_eq!(filtered_ids(&palette), vec!["gamma", "alpha", "beta"]);
+        assert!(palette.highlights.iter().all(|h| h.is_empty()));
+    }
+
+    #[test]
+    fn test_palette_history_survives_restart() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("palette_history");
+        let names = ["Alpha", "Beta", "Gamma"];
+
+        let mut palette =
+            palette_with(&names).with_history(CommandHistory::open(&path, 10).unwrap());
+        palette.selected = 2;
+        palette.execute_selected();
+
+        // A new session ranks the previously used command first
+        let mut restarted =
+            palette_with(&names).with_history(CommandHistory::open(&path, 10).unwrap());
+        restarted.show();
+        assert_eq!(restarted.recent_commands(), ["gamma".to_string()]);
+        assert_eq!(filtered_ids(&restarted), vec!["gamma", "alpha", "beta"]);
+    }
+
     #[test]
     fn test_palette_navigation() {
         let mut palette = CommandPalette::new();
@@ -1,5 +1,6 @@
 //! Dialog widgets for user interactions
 
+use super::history::CommandHistory;
 use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
@@ -245,6 +246,9 @@ pub struct InputDialog {
     result: DialogResult,
     validator: InputValidator,
     error: Option<String>,
+    history: Option<CommandHistory>,
+    /// Input typed before recalling history, restored past the newest entry
+    draft: Option<String>,
 }
 
 impl InputDialog {
@@ -259,9 +263,24 @@ impl InputDialog {
             result: DialogResult::Pending,
             validator: None,
             error: None,
+            history: None,
+            draft: None,
         }
     }
 
+    /// Recall earlier inputs with Up/Down; confirmed inputs are recorded
+    pub fn with_history(mut self, history: CommandHistory) -> Self {
+        self.history = Some(history);
+        self
+    }
+
+    /// Replace the input with a recalled entry (or the saved draft)
+    fn set_input(&mut self, input: String) {
+        self.input = input;
+        self.cursor_pos = self.input.len();
+        self.error = None;
+    }
+
     /// Set default input value
     pub fn with_default(mut self, default: impl Into<String>) -> Self {
         self.input = default.into();
@@ -400,7 +419,39 @@ impl Dialog for InputDialog {
     }
 
     fn handle_key(&mut self, key: KeyEvent) -> bool {
+        if !matches!(key.code, KeyCode::Up | KeyCode::Down) {
+            // Editing or moving on ends recall; the recalled text stays
+            if let Some(history) = &mut self.history {
+                history.reset_recall();
+            }
+            self.draft = None;
+        }
+
         match key.code {
+            KeyCode::Up => {
+                let Some(history) = &mut self.history else {
+                    return false;
+                };
+                if let Some(entry) = history.recall_older().map(str::to_string) {
+                    self.draft.get_or_insert_with(|| self.input.clone());
+                    self.set_input(entry);
+                }
+                false
+            }
+            KeyCode::Down => {
+                let Some(history) = &mut self.history else {
+                    return false;
+                };
+                match history.recall_newer().map(str::to_string) {
+                    Some(entry) => self.set_input(entry),
+                    None => {
+                        if let Some(draft) = self.draft.take() {
+                            self.set_input(draft);
+                        }
+                    }
+                }
+                false
+            }
             KeyCode::Char(c) => {
                 self.input.insert(self.cursor_pos, c);
                 self.cursor_pos += 1;
@@ -444,6 +495,11 @@ impl Dialog for InputDialog {
             }
             KeyCode::Enter => {
                 if self.validate() {
+                    if let Some(history) = &mut self.history {
+                        if let Err(e) = history.record(&self.input) {
+                            tracing::warn!("Failed to save input history: {}", e);
+                        }
+                    }
                     self.result = DialogResult::ConfirmedWithInput(self.input.clone());
                     self.visible = false;
                     true
@@ -710,6 +766,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_input_dialog_history_recall() {
+        let mut history = CommandHistory::new(10);
+        history.record("notes.md").unwrap();
+        history.record("plan.md").unwrap();
+
+        let mut dialog = InputDialog::new("Test", "Enter:").with_history(history);
+        dialog.handle_key(KeyEvent::from(KeyCode::Char('x')));
+
+        dialog.handle_key(KeyEvent::from(KeyCode::Up));
+        assert_eq!(dialog.input, "plan.md");
+        assert_eq!(dialog.cursor_pos, 7);
+        dialog.handle_key(KeyEvent::from(KeyCode::Up));
+        assert_eq!(dialog.input, "notes.md");
+
+        // Down past the newest entry restores what was typed
+        dialog.handle_key(KeyEvent::from(KeyCode::Down));
+        assert_eq!(dialog.input, "plan.md");
+        dialog.handle_key(KeyEvent::from(KeyCode::Down));
+        assert_eq!(dialog.input, "x");
+
+        // Confirmed input is recorded
+        dialog.handle_key(KeyEvent::from(KeyCode::Enter));
+        let recorded: Vec<&str> = dialog.history.as_ref().unwrap().entries().collect();
+        assert_eq!(recorded, vec!["x", "plan.md", "notes.md"]);
+    }
+
     #[test]
     fn test_preview_dialog_creation() {
         let dialog = PreviewDialog::new("Preview", "Line 1\nLine 2\nLine 3");
@@ -0,0 +1,177 @@
+//! Fuzzy subsequence matching for the command palette
+//!
+//! Scores in the spirit of fzf: every query character must appear in the
+//! candidate in order, and alignments earn bonuses for landing on word
+//! boundaries and for runs of consecutive characters, minus a penalty for
+//! gaps. "nbf" matches "New Buffer File" on the three word starts.
+
+/// Points for each matched character
+const SCORE_MATCH: i64 = 16;
+/// Bonus for matching at the start of a word
+const BONUS_BOUNDARY: i64 = 8;
+/// Bonus for matching right after the previous match
+const BONUS_CONSECUTIVE: i64 = 4;
+/// Penalty for opening a gap between matches
+const PENALTY_GAP_START: i64 = 3;
+/// Penalty for each further character in a gap
+const PENALTY_GAP_EXTENSION: i64 = 1;
+
+/// A successful match
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuzzyMatch {
+    /// Higher is better
+    pub score: i64,
+    /// Matched character indices (chars, not bytes), ascending
+    pub positions: Vec<usize>,
+}
+
+/// Match `query` as a case-insensitive subsequence of `candidate`
+///
+/// Finds the best-scoring alignment. An empty query matches with score 0.
+pub fn fuzzy_match(query: &str, candidate: &str) -> Option<FuzzyMatch> {
+    let query: Vec<char> = query.chars().map(fold_case).collect();
+    if query.is_empty() {
+        return Some(FuzzyMatch {
+            score: 0,
+            positions: Vec::new(),
+        });
+    }
+
+    let original: Vec<char> = candidate.chars().collect();
+    let text: Vec<char> = original.iter().copied().map(fold_case).collect();
+    if query.len() > text.len() {
+        return None;
+    }
+    let bonus: Vec<i64> = (0..text.len())
+        .map(|j| boundary_bonus(&original, j))
+        .collect();
+
+    // best[i][j]: best score with query[i] matched at text[j]; from[i][j] is
+    // where query[i - 1] matched on that path
+    let mut best = vec![vec![None; text.len()]; query.len()];
+    let mut from = vec![vec![0usize; text.len()]; query.len()];
+
+    for (j, &ch) in text.iter().enumerate() {
+        if ch == query[0] {
+            // The first query character counts its boundary bonus double
+            best[0][j] = Some(SCORE_MATCH + 2 * bonus[j]);
+        }
+    }
+
+    for i in 1..query.len() {
+        for j in i..text.len() {
+            if text[j] != query[i] {
+                continue;
+            }
+            let mut best_here: Option<(i64, usize)> = None;
+            for k in (i - 1)..j {
+                let Some(prev) = best[i - 1][k] else {
+                    continue;
+                };
+                let gap = (j - k - 1) as i64;
+                let transition = if gap == 0 {
+                    BONUS_CONSECUTIVE
+                } else {
+                    -(PENALTY_GAP_START + (gap - 1) * PENALTY_GAP_EXTENSION)
+                };
+                let score = prev + transition;
+                if best_here.is_none_or(|(s, _)| score > s) {
+                    best_here = Some((score, k));
+                }
+            }
+            if let Some((score, k)) = best_here {
+                best[i][j] = Some(score + SCORE_MATCH + bonus[j]);
+                from[i][j] = k;
+            }
+        }
+    }
+
+    // Best end position; ties prefer the earliest
+    let last = query.len() - 1;
+    let (mut j, score) = best[last]
+        .iter()
+        .enumerate()
+        .filter_map(|(j, s)| s.map(|s| (j, s)))
+        .fold(None, |acc: Option<(usize, i64)>, (j, s)| match acc {
+            Some((_, best_s)) if best_s >= s => acc,
+            _ => Some((j, s)),
+        })?;
+
+    let mut positions = vec![0; query.len()];
+    for i in (0..query.len()).rev() {
+        positions[i] = j;
+        if i > 0 {
+            j = from[i][j];
+        }
+    }
+
+    Some(FuzzyMatch { score, positions })
+}
+
+/// Lowercase a character without changing the character count
+fn fold_case(ch: char) -> char {
+    ch.to_lowercase().next().unwrap_or(ch)
+}
+
+/// Bonus for a match at `j`: start of text, after a separator, or a camelCase hump
+fn boundary_bonus(text: &[char], j: usize) -> i64 {
+    let Some(&prev) = j.checked_sub(1).and_then(|p| text.get(p)) else {
+        return BONUS_BOUNDARY;
+    };
+    let ch = text[j];
+    if !prev.is_alphanumeric() && ch.is_alphanumeric() {
+        BONUS_BOUNDARY
+    } else if prev.is_lowercase() && ch.is_uppercase() {
+        BONUS_BOUNDARY - 1
+    } else {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_subsequence_match_on_word_starts() {
+        let m = fuzzy_match("nbf", "New Buffer File").unwrap();
+        assert_eq!(m.positions, vec![0, 4, 11]);
+    }
+
+    #[test]
+    fn test_order_matters_within_query() {
+        assert!(fuzzy_match("fbn", "New Buffer File").is_none());
+        assert!(fuzzy_match("xyz", "New Buffer File").is_none());
+        assert!(fuzzy_match("toolong", "short").is_none());
+    }
+
+    #[test]
+    fn test_empty_query_matches_everything() {
+        let m = fuzzy_match("", "anything").unwrap();
+        assert_eq!(m.score, 0);
+        assert!(m.positions.is_empty());
+    }
+
+    #[test]
+    fn test_prefers_boundaries_and_runs() {
+        // Word starts beat letters buried mid-word
+        let starts = fuzzy_match("sf", "Save File").unwrap();
+        let buried = fuzzy_match("sf", "misfire").unwrap();
+        assert!(starts.score > buried.score);
+
+        // A contiguous run beats the same letters scattered
+        let run = fuzzy_match("file", "Open File").unwrap();
+        let scattered = fuzzy_match("file", "Find Invalid Lines Everywhere").unwrap();
+        assert!(run.score > scattered.score);
+
+        // The best alignment is chosen, not the first one found
+        let m = fuzzy_match("file", "fix the File").unwrap();
+        assert_eq!(m.positions, vec![8, 9, 10, 11]);
+    }
+
+    #[test]
+    fn test_case_insensitive_with_unicode_positions() {
+        let m = fuzzy_match("ÉC", "café écrire").unwrap();
+        assert_eq!(m.positions, vec![5, 6]);
+    }
+}
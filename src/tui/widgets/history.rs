@@ -0,0 +1,328 @@
+//! Persistent command history
+//!
+//! The history file is an append-only log, one entry per line. Each TUI
+//! instance appends what it executes and replays the whole log on load, so
+//! concurrent instances merge rather than overwrite each other. Replaying
+//! applies dedup-on-repeat and the size cap; once the log grows past twice
+//! the cap it is compacted back down under a lock file.
+
+use anyhow::Result;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Default number of entries kept
+pub const DEFAULT_MAX_HISTORY: usize = 100;
+
+/// Lock files older than this are assumed abandoned by a crashed instance
+const STALE_LOCK_AGE: Duration = Duration::from_secs(30);
+
+/// Capped, de-duplicated history of executed commands or inputs
+#[derive(Debug, Clone)]
+pub struct CommandHistory {
+    /// Entries, oldest first
+    entries: Vec<String>,
+
+    /// Maximum entries kept
+    max_entries: usize,
+
+    /// Backing file (None = in-memory only)
+    path: Option<PathBuf>,
+
+    /// Recall position, counted back from the newest entry
+    recall: Option<usize>,
+}
+
+impl CommandHistory {
+    /// Create an in-memory history
+    pub fn new(max_entries: usize) -> Self {
+        Self {
+            entries: Vec::new(),
+            max_entries: max_entries.max(1),
+            path: None,
+            recall: None,
+        }
+    }
+
+    /// Load a history file, creating it on first record
+    pub fn open(path: impl Into<PathBuf>, max_entries: usize) -> Result<Self> {
+        let mut history = Self::new(max_entries);
+        let path = path.into();
+        history.reload_from(&path)?;
+        history.path = Some(path);
+        Ok(history)
+    }
+
+    /// History file under the user's data directory (e.g. `palette_history`)
+    pub fn default_path(name: &str) -> Option<PathBuf> {
+        dirs::data_local_dir().map(|dir| dir.join("mnemosyne").join("tui").join(name))
+    }
+
+    /// Record an executed entry
+    ///
+    /// Moves a repeated entry to the newest position, evicts the oldest
+    /// entries beyond the cap, and appends to the backing file.
+    pub fn record(&mut self, entry: &str) -> Result<()> {
+        let entry = entry.trim();
+        self.recall = None;
+        if entry.is_empty() || entry.contains('\n') {
+            return Ok(());
+        }
+
+        self.push(entry);
+
+        if let Some(path) = self.path.clone() {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            // A single small O_APPEND write, so concurrent appends don't interleave
+            let mut file = OpenOptions::new().create(true).append(true).open(&path)?;
+            file.write_all(format!("{}\n", entry).as_bytes())?;
+            drop(file);
+
+            // Pick up entries other instances appended meanwhile
+            let lines = self.reload_from(&path)?;
+            if lines > self.max_entries * 2 {
+                self.compact(&path)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Entries, newest first
+    pub fn entries(&self) -> impl Iterator<Item = &str> {
+        self.entries.iter().rev().map(String::as_str)
+    }
+
+    /// Number of entries
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the history is empty
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Recall the next older entry (Up arrow)
+    ///
+    /// Stays on the oldest entry once reached.
+    pub fn recall_older(&mut self) -> Option<&str> {
+        if self.entries.is_empty() {
+            return None;
+        }
+        let back = match self.recall {
+            None => 0,
+            Some(back) => (back + 1).min(self.entries.len() - 1),
+        };
+        self.recall = Some(back);
+        self.entries
+            .get(self.entries.len() - 1 - back)
+            .map(String::as_str)
+    }
+
+    /// Recall the next newer entry (Down arrow)
+    ///
+    /// Returns `None` when moving past the newest entry, i.e. back to the
+    /// caller's own draft.
+    pub fn recall_newer(&mut self) -> Option<&str> {
+        match self.recall {
+            None | Some(0) => {
+                self.recall = None;
+                None
+            }
+            Some(back) => {
+                self.recall = Some(back - 1);
+                self.entries
+                    .get(self.entries.len() - back)
+                    .map(String::as_str)
+            }
+        }
+    }
+
+    /// Forget the recall position (e.g. when the user edits the input)
+    pub fn reset_recall(&mut self) {
+        self.recall = None;
+    }
+
+    fn push(&mut self, entry: &str) {
+        self.entries.retain(|e| e != entry);
+        self.entries.push(entry.to_string());
+        if self.entries.len() > self.max_entries {
+            let excess = self.entries.len() - self.max_entries;
+            self.entries.drain(..excess);
+        }
+    }
+
+    /// Replace entries by replaying the log; returns the number of log lines
+    fn reload_from(&mut self, path: &Path) -> Result<usize> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(0),
+            Err(e) => return Err(e.into()),
+        };
+
+        self.entries.clear();
+        let mut lines = 0;
+        for line in contents.lines().map(str::trim).filter(|l| !l.is_empty()) {
+            self.push(line);
+            lines += 1;
+        }
+        Ok(lines)
+    }
+
+    /// Rewrite the log as just the current entries
+    ///
+    /// Skipped if another instance holds the compaction lock; it will
+    /// compact instead.
+    fn compact(&mut self, path: &Path) -> Result<()> {
+        let lock_path = path.with_extension("lock");
+        if let Ok(meta) = fs::metadata(&lock_path) {
+            let stale = meta
+                .modified()
+                .ok()
+                .and_then(|m| m.elapsed().ok())
+                .is_some_and(|age| age > STALE_LOCK_AGE);
+            if stale {
+                let _ = fs::remove_file(&lock_path);
+            }
+        }
+        if OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&lock_path)
+            .is_err()
+        {
+            return Ok(());
+        }
+
+        let result = self.rewrite(path);
+        let _ = fs::remove_file(&lock_path);
+        result
+    }
+
+    fn rewrite(&mut self, path: &Path) -> Result<()> {
+        // Re-read under the lock so nothing appended since is dropped
+        self.reload_from(path)?;
+        let tmp_path = path.with_extension("tmp");
+        let mut contents = self.entries.join("\n");
+        contents.push('\n');
+        fs::write(&tmp_path, contents)?;
+        fs::rename(&tmp_path, path)?;
+        Ok(())
+    }
+}
+
+impl Default for CommandHistory {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_HISTORY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn newest_first(history: &CommandHistory) -> Vec<&str> {
+        history.entries().collect()
+    }
+
+    #[test]
+    fn test_dedup_moves_repeat_to_front() {
+        let mut history = CommandHistory::new(10);
+        for entry in ["open", "save", "quit", "save"] {
+            history.record(entry).unwrap();
+        }
+
+        assert_eq!(newest_first(&history), vec!["save", "quit", "open"]);
+
+        // Blank entries are ignored
+        history.record("   ").unwrap();
+        assert_eq!(history.len(), 3);
+    }
+
+    #[test]
+    fn test_cap_evicts_oldest() {
+        let mut history = CommandHistory::new(3);
+        for entry in ["a", "b", "c", "d", "e"] {
+            history.record(entry).unwrap();
+        }
+        assert_eq!(newest_first(&history), vec!["e", "d", "c"]);
+
+        // A repeat doesn't count twice toward the cap
+        history.record("c").unwrap();
+        history.record("f").unwrap();
+        assert_eq!(newest_first(&history), vec!["f", "c", "e"]);
+    }
+
+    #[test]
+    fn test_up_down_recall() {
+        let mut history = CommandHistory::new(10);
+        for entry in ["first", "second", "third"] {
+            history.record(entry).unwrap();
+        }
+
+        assert_eq!(history.recall_older(), Some("third"));
+        assert_eq!(history.recall_older(), Some("second"));
+        assert_eq!(history.recall_older(), Some("first"));
+        assert_eq!(history.recall_older(), Some("first"));
+        assert_eq!(history.recall_newer(), Some("second"));
+        assert_eq!(history.recall_newer(), Some("third"));
+        assert_eq!(history.recall_newer(), None);
+
+        history.recall_older();
+        history.reset_recall();
+        assert_eq!(history.recall_older(), Some("third"));
+    }
+
+    #[test]
+    fn test_persists_across_sessions() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("nested").join("history");
+
+        let mut history = CommandHistory::open(&path, 5).unwrap();
+        history.record("open").unwrap();
+        history.record("save").unwrap();
+        history.record("open").unwrap();
+
+        let reloaded = CommandHistory::open(&path, 5).unwrap();
+        assert_eq!(newest_first(&reloaded), vec!["open", "save"]);
+    }
+
+    #[test]
+    fn test_concurrent_instances_merge() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("history");
+
+        let mut first = CommandHistory::open(&path, 50).unwrap();
+        let mut second = CommandHistory::open(&path, 50).unwrap();
+
+        first.record("from-first").unwrap();
+        second.record("from-second").unwrap();
+        first.record("again-first").unwrap();
+
+        let merged = CommandHistory::open(&path, 50).unwrap();
+        assert_eq!(
+            newest_first(&merged),
+            vec!["again-first", "from-second", "from-first"]
+        );
+        assert_eq!(newest_first(&first), newest_first(&merged));
+    }
+
+    #[test]
+    fn test_log_is_compacted() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("history");
+
+        let mut history = CommandHistory::open(&path, 2).unwrap();
+        for entry in ["a", "b", "a", "b", "c"] {
+            history.record(entry).unwrap();
+        }
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "b\nc\n");
+        assert!(!path.with_extension("lock").exists());
+        assert_eq!(newest_first(&history), vec!["c", "b"]);
+    }
+}
@@ -87,6 +87,31 @@ impl Namespace {
         !matches!(self, Namespace::Global)
     }
 
+    /// Check that named parts are non-empty and don't contain `:`
+    /// (which would make the `project:`/`session:` forms ambiguous)
+    pub fn validate(&self) -> std::result::Result<(), String> {
+        let parts: &[(&str, &str)] = match self {
+            Namespace::Global => &[],
+            Namespace::Project { name } => &[("project name", name)],
+            Namespace::Session {
+                project,
+                session_id,
+            } => &[("project name", project), ("session id", session_id)],
+        };
+        for (what, value) in parts {
+            if value.trim().is_empty() {
+                return Err(format!("namespace {} must not be empty", what));
+            }
+            if value.contains(':') {
+                return Err(format!(
+                    "namespace {} must not contain ':', got '{}'",
+                    what, value
+                ));
+            }
+        }
+        Ok(())
+    }
+
     /// Get the project name if applicable
     pub fn project_name(&self) -> Option<&str> {
         match self {
@@ -112,9 +137,34 @@ impl std::fmt::Display for Namespace {
     }
 }
 
+impl std::str::FromStr for Namespace {
+    type Err = crate::error::MnemosyneError;
+
+    /// Parse the `Display` form: `global`, `project:<name>`, or
+    /// `session:<project>:<session_id>`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split(':').collect::<Vec<_>>().as_slice() {
+            ["global"] => Ok(Namespace::Global),
+            ["project", name] => Ok(Namespace::Project {
+                name: name.to_string(),
+            }),
+            ["session", project, session_id] => Ok(Namespace::Session {
+                project: project.to_string(),
+                session_id: session_id.to_string(),
+            }),
+            _ => Err(crate::error::MnemosyneError::InvalidNamespace(
+                s.to_string(),
+            )),
+        }
+    }
+}
+
 /// Memory type classification for organizational and filtering purposes
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
+///
+/// Serialized (and stored) as a snake_case name; names that are not built in
+/// become [`MemoryType::Custom`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
 pub enum MemoryType {
     /// Architectural decisions and system design choices
     ArchitectureDecision,
@@ -167,9 +217,89 @@ pub enum MemoryType {
 
     /// Clarification resolving ambiguities in specs or requirements
     Clarification,
+
+    /// Team-defined type (e.g. `security_finding`), declared under
+    /// `[memory_types.custom]` in config
+    Custom(String),
 }
 
 impl MemoryType {
+    /// Every built-in type, in declaration order
+    pub const BUILT_IN: [MemoryType; 17] = [
+        MemoryType::ArchitectureDecision,
+        MemoryType::CodePattern,
+        MemoryType::BugFix,
+        MemoryType::Configuration,
+        MemoryType::Constraint,
+        MemoryType::Entity,
+        MemoryType::Insight,
+        MemoryType::Reference,
+        MemoryType::Preference,
+        MemoryType::Task,
+        MemoryType::AgentEvent,
+        MemoryType::Constitution,
+        MemoryType::FeatureSpec,
+        MemoryType::ImplementationPlan,
+        MemoryType::TaskBreakdown,
+        MemoryType::QualityChecklist,
+        MemoryType::Clarification,
+    ];
+
+    /// Stored snake_case name (`architecture_decision`, `security_finding`, ...)
+    pub fn as_str(&self) -> &str {
+        match self {
+            MemoryType::ArchitectureDecision => "architecture_decision",
+            MemoryType::CodePattern => "code_pattern",
+            MemoryType::BugFix => "bug_fix",
+            MemoryType::Configuration => "configuration",
+            MemoryType::Constraint => "constraint",
+            MemoryType::Entity => "entity",
+            MemoryType::Insight => "insight",
+            MemoryType::Reference => "reference",
+            MemoryType::Preference => "preference",
+            MemoryType::Task => "task",
+            MemoryType::AgentEvent => "agent_event",
+            MemoryType::Constitution => "constitution",
+            MemoryType::FeatureSpec => "feature_spec",
+            MemoryType::ImplementationPlan => "implementation_plan",
+            MemoryType::TaskBreakdown => "task_breakdown",
+            MemoryType::QualityChecklist => "quality_checklist",
+            MemoryType::Clarification => "clarification",
+            MemoryType::Custom(name) => name,
+        }
+    }
+
+    /// Built-in type with this stored name, or a custom type otherwise
+    pub fn from_name(name: &str) -> Self {
+        Self::BUILT_IN
+            .into_iter()
+            .find(|t| t.as_str() == name)
+            .unwrap_or_else(|| MemoryType::Custom(name.to_string()))
+    }
+
+    /// Whether this is a team-defined type
+    pub fn is_custom(&self) -> bool {
+        matches!(self, MemoryType::Custom(_))
+    }
+
+    /// Check that a custom type name is non-empty snake_case
+    pub fn validate(&self) -> std::result::Result<(), String> {
+        match self {
+            MemoryType::Custom(name)
+                if name.is_empty()
+                    || !name
+                        .chars()
+                        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_') =>
+            {
+                Err(format!(
+                    "custom memory type must be non-empty snake_case, got '{}'",
+                    name
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+
     /// Get the type factor for importance calculations
     /// Different memory types have different base value
     pub fn type_factor(&self) -> f32 {
@@ -199,6 +329,30 @@ impl MemoryType {
     }
 }
 
+impl From<String> for MemoryType {
+    fn from(name: String) -> Self {
+        match Self::from_name(&name) {
+            MemoryType::Custom(_) => MemoryType::Custom(name),
+            built_in => built_in,
+        }
+    }
+}
+
+impl From<MemoryType> for String {
+    fn from(memory_type: MemoryType) -> Self {
+        match memory_type {
+            MemoryType::Custom(name) => name,
+            built_in => built_in.as_str().to_string(),
+        }
+    }
+}
+
+impl std::fmt::Display for MemoryType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// Relationship types between memories for knowledge graph construction
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -358,6 +512,39 @@ impl MemoryNote {
         let age_days = (Utc::now() - self.updated_at).num_days() as u32;
         age_days > threshold_days && self.decayed_importance() < min_importance
     }
+
+    /// Check the fields storage relies on, listing every problem found
+    pub fn validate(&self) -> std::result::Result<(), Vec<String>> {
+        let mut problems = Vec::new();
+
+        if !(1..=10).contains(&self.importance) {
+            problems.push(format!(
+                "importance must be between 1 and 10, got {}",
+                self.importance
+            ));
+        }
+        if !(0.0..=1.0).contains(&self.confidence) {
+            problems.push(format!(
+                "confidence must be between 0.0 and 1.0, got {}",
+                self.confidence
+            ));
+        }
+        if self.content.trim().is_empty() {
+            problems.push("content must not be empty".to_string());
+        }
+        if let Err(problem) = self.namespace.validate() {
+            problems.push(problem);
+        }
+        if let Err(problem) = self.memory_type.validate() {
+            problems.push(problem);
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(problems)
+        }
+    }
 }
 
 /// Search query with filters for memory retrieval
@@ -372,11 +559,16 @@ pub struct SearchQuery {
     /// Optional namespace filter
     pub namespace: Option<Namespace>,
 
-    /// Filter by memory types
+    /// Only memories of one of these types (empty: any type)
     pub memory_types: Vec<MemoryType>,
 
-    /// Filter by tags
-    pub tags: Vec<String>,
+    /// Only memories with at least one of these tags (empty: no restriction)
+    #[serde(default, alias = "tags")]
+    pub tags_any: Vec<String>,
+
+    /// Only memories with all of these tags (empty: no restriction)
+    #[serde(default)]
+    pub tags_all: Vec<String>,
 
     /// Minimum importance threshold
     pub min_importance: Option<u8>,
@@ -386,6 +578,60 @@ pub struct SearchQuery {
 
     /// Whether to include archived memories
     pub include_archived: bool,
+
+    /// Weight added as `importance_boost * (importance / 10)` to each fused
+    /// score before ranking; 0 ranks on the search signals alone
+    #[serde(default)]
+    pub importance_boost: f32,
+
+    /// Keys that order results with equal scores, applied in turn after the
+    /// score; remaining ties fall back to memory ID so output is reproducible
+    #[serde(default)]
+    pub tie_breaks: Vec<TieBreak>,
+}
+
+/// Result ordering key for `SearchQuery::tie_breaks`
+///
+/// Every key sorts descending: highest score, most important, most recently
+/// created, most accessed first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TieBreak {
+    /// Fused search score (always the primary key; listing it is a no-op)
+    Score,
+    /// Memory importance
+    Importance,
+    /// Creation time
+    Recency,
+    /// Number of recalls
+    AccessCount,
+}
+
+impl TieBreak {
+    /// Compare two results on this key, best first
+    pub fn compare(self, a: &SearchResult, b: &SearchResult) -> std::cmp::Ordering {
+        match self {
+            TieBreak::Score => b.score.total_cmp(&a.score),
+            TieBreak::Importance => b.memory.importance.cmp(&a.memory.importance),
+            TieBreak::Recency => b.memory.created_at.cmp(&a.memory.created_at),
+            TieBreak::AccessCount => b.memory.access_count.cmp(&a.memory.access_count),
+        }
+    }
+}
+
+impl SearchQuery {
+    /// Whether `memory` passes the type, tag, and importance filters
+    ///
+    /// Storage applies the same filters in SQL; this is for results gathered
+    /// elsewhere.
+    pub fn matches(&self, memory: &MemoryNote) -> bool {
+        (self.memory_types.is_empty() || self.memory_types.contains(&memory.memory_type))
+            && (self.tags_any.is_empty() || self.tags_any.iter().any(|t| memory.tags.contains(t)))
+            && self.tags_all.iter().all(|t| memory.tags.contains(t))
+            && self
+                .min_importance
+                .is_none_or(|min| memory.importance >= min)
+    }
 }
 
 impl Default for SearchQuery {
@@ -394,10 +640,13 @@ impl Default for SearchQuery {
             query: String::new(),
             namespace: None,
             memory_types: Vec::new(),
-            tags: Vec::new(),
+            tags_any: Vec::new(),
+            tags_all: Vec::new(),
             min_importance: None,
             max_results: 10,
             include_archived: false,
+            importance_boost: 0.0,
+            tie_breaks: Vec::new(),
         }
     }
 }
@@ -413,6 +662,41 @@ pub struct SearchResult {
 
     /// Explanation of why this memory matched
     pub match_reason: String,
+
+    /// Per-signal scores, set by hybrid search when
+    /// `SearchConfig::explain_scores` is on
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub score_breakdown: Option<ScoreBreakdown>,
+}
+
+/// Signals behind a hybrid search score
+///
+/// Each signal is its raw 0.0-1.0 value before weighting; `fused` is the
+/// weighted sum used as the result's score.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct ScoreBreakdown {
+    /// Keyword (FTS) match
+    pub keyword: f32,
+    /// Vector similarity to the query embedding
+    pub vector: f32,
+    /// Graph proximity to a seed result
+    pub graph: f32,
+    /// Memory importance
+    pub importance: f32,
+    /// Recency decay
+    pub recency: f32,
+    /// Weighted sum of the signals
+    pub fused: f32,
+}
+
+impl std::fmt::Display for ScoreBreakdown {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "keyword {:.2} · vector {:.2} · graph {:.2} · importance {:.2} · recency {:.2} → {:.3}",
+            self.keyword, self.vector, self.graph, self.importance, self.recency, self.fused
+        )
+    }
 }
 
 /// Updates to apply to an existing memory
@@ -484,6 +768,27 @@ mod tests {
         assert_eq!(session.priority(), 3);
     }
 
+    #[test]
+    fn test_namespace_parse_round_trip() {
+        for namespace in [
+            Namespace::Global,
+            Namespace::Project {
+                name: "app".to_string(),
+            },
+            Namespace::Session {
+                project: "app".to_string(),
+                session_id: "s1".to_string(),
+            },
+        ] {
+            assert_eq!(
+                namespace.to_string().parse::<Namespace>().unwrap(),
+                namespace
+            );
+        }
+        assert!("project".parse::<Namespace>().is_err());
+        assert!("session:app".parse::<Namespace>().is_err());
+    }
+
     #[test]
     fn test_memory_type_factors() {
         assert!(MemoryType::ArchitectureDecision.type_factor() > 1.0);
@@ -491,6 +796,32 @@ mod tests {
         assert_eq!(MemoryType::CodePattern.type_factor(), 1.0);
     }
 
+    #[test]
+    fn test_memory_type_serde_names() {
+        for memory_type in MemoryType::BUILT_IN {
+            let json = serde_json::to_string(&memory_type).unwrap();
+            assert_eq!(json, format!("\"{}\"", memory_type.as_str()));
+            assert_eq!(
+                serde_json::from_str::<MemoryType>(&json).unwrap(),
+                memory_type
+            );
+        }
+        assert_eq!(
+            serde_json::to_string(&MemoryType::ArchitectureDecision).unwrap(),
+            "\"architecture_decision\""
+        );
+
+        let custom = MemoryType::Custom("security_finding".to_string());
+        let json = serde_json::to_string(&custom).unwrap();
+        assert_eq!(json, "\"security_finding\"");
+        assert_eq!(serde_json::from_str::<MemoryType>(&json).unwrap(), custom);
+        assert!(custom.validate().is_ok());
+        assert!(MemoryType::Custom("Security Finding".to_string())
+            .validate()
+            .is_err());
+        assert!(MemoryType::Custom(String::new()).validate().is_err());
+    }
+
     #[test]
     fn test_decayed_importance() {
         let mut memory = MemoryNote {
@@ -527,4 +858,104 @@ mod tests {
         let accessed_importance = memory.decayed_importance();
         assert!(accessed_importance > fresh_importance);
     }
+
+    fn valid_memory() -> MemoryNote {
+        MemoryNote {
+            id: MemoryId::new(),
+            namespace: Namespace::Project {
+                name: "mnemosyne".to_string(),
+            },
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            content: "Use libSQL for storage".to_string(),
+            summary: "Storage decision".to_string(),
+            keywords: vec![],
+            tags: vec![],
+            context: String::new(),
+            memory_type: MemoryType::ArchitectureDecision,
+            importance: 8,
+            confidence: 0.9,
+            links: vec![],
+            related_files: vec![],
+            related_entities: vec![],
+            access_count: 0,
+            last_accessed_at: Utc::now(),
+            expires_at: None,
+            is_archived: false,
+            superseded_by: None,
+            embedding: None,
+            embedding_model: String::new(),
+        }
+    }
+
+    fn problems(memory: &MemoryNote) -> Vec<String> {
+        memory.validate().unwrap_err()
+    }
+
+    #[test]
+    fn test_valid_memory_passes() {
+        let memory = valid_memory();
+        assert!(memory.validate().is_ok());
+
+        for importance in [1, 10] {
+            let mut edge = memory.clone();
+            edge.importance = importance;
+            edge.confidence = if importance == 1 { 0.0 } else { 1.0 };
+            edge.namespace = Namespace::Global;
+            assert!(edge.validate().is_ok());
+        }
+    }
+
+    #[test]
+    fn test_invalid_importance() {
+        for importance in [0, 11, 50] {
+            let mut memory = valid_memory();
+            memory.importance = importance;
+            let found = problems(&memory);
+            assert_eq!(found.len(), 1);
+            assert!(found[0].contains("importance"), "{:?}", found);
+        }
+    }
+
+    #[test]
+    fn test_invalid_confidence() {
+        for confidence in [-0.1, 1.5, f32::NAN] {
+            let mut memory = valid_memory();
+            memory.confidence = confidence;
+            let found = problems(&memory);
+            assert_eq!(found.len(), 1);
+            assert!(found[0].contains("confidence"), "{:?}", found);
+        }
+    }
+
+    #[test]
+    fn test_empty_content() {
+        let mut memory = valid_memory();
+        memory.content = "  \n".to_string();
+        assert_eq!(problems(&memory), vec!["content must not be empty"]);
+    }
+
+    #[test]
+    fn test_invalid_namespace() {
+        let mut memory = valid_memory();
+        memory.namespace = Namespace::Project {
+            name: String::new(),
+        };
+        assert!(problems(&memory)[0].contains("project name"));
+
+        memory.namespace = Namespace::Session {
+            project: "app".to_string(),
+            session_id: "a:b".to_string(),
+        };
+        assert!(problems(&memory)[0].contains("session id"));
+    }
+
+    #[test]
+    fn test_all_problems_reported() {
+        let mut memory = valid_memory();
+        memory.importance = 0;
+        memory.confidence = 2.0;
+        memory.content = String::new();
+        assert_eq!(problems(&memory).len(), 3);
+    }
 }
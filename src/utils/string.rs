@@ -37,6 +37,68 @@ pub fn truncate_at_char_boundary(s: &str, max_chars: usize) -> String {
     }
 }
 
+/// Extract a window of text around the first occurrence of any query term.
+///
+/// Matching is case-insensitive and works on characters, so multi-byte text
+/// is never split. Falls back to the start of the text when no term matches.
+/// Ellipses mark elided text on either side.
+///
+/// # Examples
+/// ```
+/// use mnemosyne_core::utils::string::snippet_around;
+///
+/// assert_eq!(snippet_around("short text", "text", 50), "short text");
+/// assert_eq!(
+///     snippet_around("a long preamble before the database decision", "database", 20),
+///     "...e the database decis..."
+/// );
+/// ```
+pub fn snippet_around(text: &str, query: &str, max_chars: usize) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    if chars.len() <= max_chars {
+        return text.to_string();
+    }
+
+    let lower: Vec<char> = chars
+        .iter()
+        .map(|c| c.to_lowercase().next().unwrap_or(*c))
+        .collect();
+
+    let first_match = query
+        .split_whitespace()
+        .filter_map(|term| {
+            let term: Vec<char> = term.to_lowercase().chars().collect();
+            if term.is_empty() || term.len() > lower.len() {
+                return None;
+            }
+            lower
+                .windows(term.len())
+                .position(|w| w == term.as_slice())
+                .map(|pos| (pos, term.len()))
+        })
+        .min();
+
+    let Some((match_start, match_len)) = first_match else {
+        return truncate_at_char_boundary(text, max_chars);
+    };
+
+    // Center the window on the match, clamped to the text bounds
+    let start = (match_start + match_len / 2)
+        .saturating_sub(max_chars / 2)
+        .min(chars.len() - max_chars);
+    let end = start + max_chars;
+
+    let mut snippet = String::new();
+    if start > 0 {
+        snippet.push_str("...");
+    }
+    snippet.extend(&chars[start..end]);
+    if end < chars.len() {
+        snippet.push_str("...");
+    }
+    snippet
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -107,4 +169,37 @@ mod tests {
         assert!(result.len() <= text.len());
         assert!(result.ends_with("..."));
     }
+
+    #[test]
+    fn test_snippet_short_text_unchanged() {
+        assert_eq!(snippet_around("hello world", "world", 20), "hello world");
+    }
+
+    #[test]
+    fn test_snippet_centers_on_match() {
+        let text = "a long preamble before the database decision";
+        assert_eq!(
+            snippet_around(text, "database", 20),
+            "...e the database decis..."
+        );
+    }
+
+    #[test]
+    fn test_snippet_case_insensitive_earliest_term() {
+        let text = "Zebra alpha beta gamma delta epsilon Database";
+        let snippet = snippet_around(text, "database ALPHA", 10);
+        assert!(snippet.contains("alpha"));
+    }
+
+    #[test]
+    fn test_snippet_no_match_falls_back_to_prefix() {
+        assert_eq!(snippet_around("hello world", "xyz", 5), "hello...");
+    }
+
+    #[test]
+    fn test_snippet_multibyte() {
+        let text = "前置き→データベースの決定について";
+        let snippet = snippet_around(text, "データベース", 8);
+        assert!(snippet.contains("データベース"));
+    }
 }
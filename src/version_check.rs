@@ -3,17 +3,64 @@
 //! This module provides functionality to:
 //! - Check for updates to mnemosyne, Claude Code, and beads
 //! - Compare semantic versions
-//! - Cache check results (24-hour throttling)
+//! - Cache check results on disk (configurable TTL, 24 hours by default) so
+//!   repeated checks within the window stay offline
+//! - Follow the `stable` or `beta` release channel (`[updates]` in config)
+//! - Skip automatic checks entirely when `MNEMOSYNE_NO_UPDATE_CHECK` is set
 //! - Detect installed tool versions
+//! - Locate prebuilt release binaries and their published SHA256 checksums
 
 use crate::error::{MnemosyneError, Result};
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use tracing::{debug, warn};
 
+/// Environment variable that disables automatic update checks
+pub const NO_UPDATE_CHECK_ENV: &str = "MNEMOSYNE_NO_UPDATE_CHECK";
+
+/// Whether automatic update checks are disabled via [`NO_UPDATE_CHECK_ENV`]
+///
+/// Any value other than empty, `0` or `false` disables them.
+pub fn update_checks_disabled() -> bool {
+    std::env::var(NO_UPDATE_CHECK_ENV)
+        .map(|v| !matches!(v.trim().to_lowercase().as_str(), "" | "0" | "false"))
+        .unwrap_or(false)
+}
+
+/// Release stream to check for updates
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UpdateChannel {
+    /// Latest full release
+    #[default]
+    Stable,
+    /// Latest release including pre-releases (npm `beta` dist-tag)
+    Beta,
+}
+
+/// Update check settings (`[updates]`)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UpdateCheckSettings {
+    /// Release stream to follow
+    pub channel: UpdateChannel,
+
+    /// Hours a cached check result is reused before querying again
+    pub cache_ttl_hours: u64,
+}
+
+impl Default for UpdateCheckSettings {
+    fn default() -> Self {
+        Self {
+            channel: UpdateChannel::Stable,
+            cache_ttl_hours: 24,
+        }
+    }
+}
+
 /// Tool that can be version-checked
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -54,12 +101,32 @@ pub struct VersionInfo {
     pub update_available: bool,
     pub is_installed: bool,
     pub release_url: Option<String>,
+    /// Prebuilt binary for this platform, if the release publishes one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub artifact: Option<ReleaseArtifact>,
+}
+
+/// Prebuilt release binary and the metadata needed to verify it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReleaseArtifact {
+    /// Asset name, e.g. `mnemosyne-aarch64-macos`
+    pub name: String,
+    pub download_url: String,
+    /// Expected SHA256 (lowercase hex) from the release's checksum file
+    pub sha256: Option<String>,
+    /// Detached minisign signature (`<name>.minisig`), if published
+    pub signature_url: Option<String>,
 }
 
+/// Name of the checksum file attached to mnemosyne releases
+pub const CHECKSUMS_ASSET: &str = "SHA256SUMS";
+
 /// Cached version check result
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VersionCheckCache {
     pub tool: Tool,
+    #[serde(default)]
+    pub channel: UpdateChannel,
     pub latest_version: String,
     pub checked_at: u64,
     pub release_url: String,
@@ -71,7 +138,7 @@ impl VersionCheckCache {
             .duration_since(UNIX_EPOCH)
             .unwrap_or_default()
             .as_secs();
-        let age_hours = (now - self.checked_at) / 3600;
+        let age_hours = now.saturating_sub(self.checked_at) / 3600;
         age_hours >= max_age_hours
     }
 }
@@ -81,6 +148,14 @@ impl VersionCheckCache {
 struct GitHubRelease {
     tag_name: String,
     html_url: String,
+    #[serde(default)]
+    assets: Vec<GitHubAsset>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubAsset {
+    name: String,
+    browser_download_url: String,
 }
 
 /// npm registry response (simplified)
@@ -93,16 +168,26 @@ struct NpmPackage {
 #[derive(Debug, Deserialize)]
 struct NpmDistTags {
     latest: String,
+    #[serde(default)]
+    beta: Option<String>,
 }
 
+const GITHUB_API: &str = "https://api.github.com";
+const NPM_REGISTRY: &str = "https://registry.npmjs.org";
+
 /// Version checker service
 pub struct VersionChecker {
     client: Client,
-    _cache_max_age_hours: u64,
+    cache_max_age_hours: u64,
+    channel: UpdateChannel,
+    /// On-disk result cache (None = always query)
+    cache_path: Option<PathBuf>,
+    github_api: String,
+    npm_registry: String,
 }
 
 impl VersionChecker {
-    /// Create a new version checker
+    /// Create a version checker for the stable channel with a 24-hour cache
     pub fn new() -> Result<Self> {
         let client = Client::builder()
             .timeout(Duration::from_secs(5))
@@ -112,10 +197,46 @@ impl VersionChecker {
 
         Ok(Self {
             client,
-            _cache_max_age_hours: 24,
+            cache_max_age_hours: UpdateCheckSettings::default().cache_ttl_hours,
+            channel: UpdateChannel::default(),
+            cache_path: Self::default_cache_path(),
+            github_api: GITHUB_API.to_string(),
+            npm_registry: NPM_REGISTRY.to_string(),
         })
     }
 
+    /// Create a version checker using the `[updates]` settings from config
+    ///
+    /// Falls back to the defaults (with a warning) if the config can't be loaded.
+    pub fn from_config() -> Result<Self> {
+        let settings: UpdateCheckSettings =
+            crate::config::ConfigManager::load_section("update settings", |s| s.updates);
+        Ok(Self::new()?.with_settings(&settings))
+    }
+
+    /// Apply channel and cache TTL settings
+    pub fn with_settings(mut self, settings: &UpdateCheckSettings) -> Self {
+        self.channel = settings.channel;
+        self.cache_max_age_hours = settings.cache_ttl_hours;
+        self
+    }
+
+    /// Use a different cache file (None disables caching)
+    pub fn with_cache_path(mut self, path: Option<PathBuf>) -> Self {
+        self.cache_path = path;
+        self
+    }
+
+    /// Release channel being followed
+    pub fn channel(&self) -> UpdateChannel {
+        self.channel
+    }
+
+    /// Default cache file under the user's cache directory
+    pub fn default_cache_path() -> Option<PathBuf> {
+        dirs::cache_dir().map(|dir| dir.join("mnemosyne").join("version_check.json"))
+    }
+
     /// Check for updates to all tools
     pub async fn check_all_tools(&self) -> Result<Vec<VersionInfo>> {
         let mut results = Vec::new();
@@ -133,6 +254,7 @@ impl VersionChecker {
                         update_available: false,
                         is_installed: self.is_tool_installed(tool),
                         release_url: None,
+                        artifact: None,
                     });
                 }
             }
@@ -142,41 +264,116 @@ impl VersionChecker {
     }
 
     /// Check for updates to a specific tool
+    ///
+    /// Answers from the cache while its entry for this tool and channel is
+    /// younger than the TTL; cached answers carry no release artifact.
     pub async fn check_tool(&self, tool: Tool) -> Result<VersionInfo> {
-        let installed = self.detect_installed_version(tool);
-        let is_installed = installed.is_some();
+        if let Some(cached) = self.cached(tool) {
+            debug!(
+                "Using cached {} version {}",
+                tool.display_name(),
+                cached.latest_version
+            );
+            return Ok(self.version_info(tool, cached.latest_version, cached.release_url, None));
+        }
+        self.check_tool_fresh(tool).await
+    }
 
+    /// Check for updates to a specific tool, bypassing the cache
+    pub async fn check_tool_fresh(&self, tool: Tool) -> Result<VersionInfo> {
         // Fetch latest version from appropriate source
         // Note: Use npm for Beads since that's the primary distribution method,
         // even though GitHub releases may have newer versions not yet published to npm
-        let (latest, release_url) = match tool {
+        let (latest, release_url, artifact) = match tool {
             Tool::Mnemosyne => self.fetch_github_latest("rand", "mnemosyne").await?,
-            Tool::ClaudeCode => self.fetch_npm_latest("@anthropic-ai/claude-code").await?,
-            Tool::Beads => self.fetch_npm_latest("@beads/bd").await?,
+            Tool::ClaudeCode => {
+                let (latest, url) = self.fetch_npm_latest("@anthropic-ai/claude-code").await?;
+                (latest, url, None)
+            }
+            Tool::Beads => {
+                let (latest, url) = self.fetch_npm_latest("@beads/bd").await?;
+                (latest, url, None)
+            }
         };
 
+        self.store_cached(VersionCheckCache {
+            tool,
+            channel: self.channel,
+            latest_version: latest.clone(),
+            checked_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs(),
+            release_url: release_url.clone(),
+        });
+
+        Ok(self.version_info(tool, latest, release_url, artifact))
+    }
+
+    fn version_info(
+        &self,
+        tool: Tool,
+        latest: String,
+        release_url: String,
+        artifact: Option<ReleaseArtifact>,
+    ) -> VersionInfo {
+        let installed = self.detect_installed_version(tool);
+        let is_installed = installed.is_some();
+
         let update_available = if let Some(installed_ver) = &installed {
             Self::is_newer_version(&latest, installed_ver)
         } else {
             false
         };
 
-        Ok(VersionInfo {
+        VersionInfo {
             tool,
             installed,
             latest: Some(latest),
             update_available,
             is_installed,
             release_url: Some(release_url),
-        })
+            artifact,
+        }
     }
 
-    /// Fetch latest version from GitHub releases
-    async fn fetch_github_latest(&self, owner: &str, repo: &str) -> Result<(String, String)> {
-        let url = format!(
-            "https://api.github.com/repos/{}/{}/releases/latest",
-            owner, repo
-        );
+    /// Fresh cache entry for a tool on the current channel
+    fn cached(&self, tool: Tool) -> Option<VersionCheckCache> {
+        let path = self.cache_path.as_ref()?;
+        read_cache(path)
+            .into_iter()
+            .find(|entry| entry.tool == tool && entry.channel == self.channel)
+            .filter(|entry| !entry.is_stale(self.cache_max_age_hours))
+    }
+
+    /// Record a check result; cache write failures are only logged
+    fn store_cached(&self, entry: VersionCheckCache) {
+        let Some(path) = &self.cache_path else {
+            return;
+        };
+        let mut entries = read_cache(path);
+        entries.retain(|e| !(e.tool == entry.tool && e.channel == entry.channel));
+        entries.push(entry);
+
+        let result = path
+            .parent()
+            .map_or(Ok(()), std::fs::create_dir_all)
+            .and_then(|()| {
+                let json = serde_json::to_vec_pretty(&entries).map_err(std::io::Error::other)?;
+                std::fs::write(path, json)
+            });
+        if let Err(e) = result {
+            warn!("Failed to write version cache {}: {}", path.display(), e);
+        }
+    }
+
+    /// Fetch latest version from GitHub releases, with this platform's binary
+    async fn fetch_github_latest(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<(String, String, Option<ReleaseArtifact>)> {
+        let url = github_release_endpoint(&self.github_api, owner, repo, self.channel);
 
         debug!("Fetching latest release from: {}", url);
 
@@ -191,9 +388,21 @@ impl VersionChecker {
             )));
         }
 
-        let release: GitHubRelease = response.json().await.map_err(|e| {
-            MnemosyneError::NetworkError(format!("Failed to parse GitHub response: {}", e))
-        })?;
+        let parse_error =
+            |e| MnemosyneError::NetworkError(format!("Failed to parse GitHub response: {}", e));
+        let release: GitHubRelease = match self.channel {
+            UpdateChannel::Stable => response.json().await.map_err(parse_error)?,
+            // The release list is newest first and includes pre-releases
+            UpdateChannel::Beta => response
+                .json::<Vec<GitHubRelease>>()
+                .await
+                .map_err(parse_error)?
+                .into_iter()
+                .next()
+                .ok_or_else(|| {
+                    MnemosyneError::NetworkError(format!("No releases found for {}", repo))
+                })?,
+        };
 
         // Strip 'v' prefix if present
         let version = release
@@ -202,12 +411,64 @@ impl VersionChecker {
             .unwrap_or(&release.tag_name)
             .to_string();
 
-        Ok((version, release.html_url))
+        let artifact = self.release_artifact(&release).await;
+
+        Ok((version, release.html_url, artifact))
+    }
+
+    /// Find this platform's binary in a release and look up its checksum
+    async fn release_artifact(&self, release: &GitHubRelease) -> Option<ReleaseArtifact> {
+        let name = platform_asset_name();
+        let asset = release.assets.iter().find(|a| a.name == name)?;
+        let asset_url = |asset_name: &str| {
+            release
+                .assets
+                .iter()
+                .find(|a| a.name == asset_name)
+                .map(|a| a.browser_download_url.clone())
+        };
+
+        let sha256 = match asset_url(CHECKSUMS_ASSET) {
+            Some(url) => match self.fetch_text(&url).await {
+                Ok(checksums) => parse_checksum(&checksums, &name),
+                Err(e) => {
+                    warn!("Failed to fetch release checksums: {}", e);
+                    None
+                }
+            },
+            None => None,
+        };
+
+        Some(ReleaseArtifact {
+            signature_url: asset_url(&format!("{}.minisig", name)),
+            name,
+            download_url: asset.browser_download_url.clone(),
+            sha256,
+        })
+    }
+
+    async fn fetch_text(&self, url: &str) -> Result<String> {
+        let response = self.client.get(url).send().await.map_err(|e| {
+            MnemosyneError::NetworkError(format!("Request to {} failed: {}", url, e))
+        })?;
+
+        if !response.status().is_success() {
+            return Err(MnemosyneError::NetworkError(format!(
+                "{} returned status: {}",
+                url,
+                response.status()
+            )));
+        }
+
+        response
+            .text()
+            .await
+            .map_err(|e| MnemosyneError::NetworkError(e.to_string()))
     }
 
     /// Fetch latest version from npm registry
     async fn fetch_npm_latest(&self, package: &str) -> Result<(String, String)> {
-        let url = format!("https://registry.npmjs.org/{}", package);
+        let url = format!("{}/{}", self.npm_registry, package);
 
         debug!("Fetching npm package info from: {}", url);
 
@@ -226,7 +487,7 @@ impl VersionChecker {
             MnemosyneError::NetworkError(format!("Failed to parse npm response: {}", e))
         })?;
 
-        let version = package_info.dist_tags.latest;
+        let version = npm_dist_tag(package_info.dist_tags, self.channel);
         let release_url = format!("https://www.npmjs.com/package/{}", package);
 
         Ok((version, release_url))
@@ -360,6 +621,51 @@ impl VersionChecker {
     }
 }
 
+/// GitHub endpoint listing the newest release on a channel
+fn github_release_endpoint(api: &str, owner: &str, repo: &str, channel: UpdateChannel) -> String {
+    match channel {
+        UpdateChannel::Stable => format!("{}/repos/{}/{}/releases/latest", api, owner, repo),
+        UpdateChannel::Beta => format!("{}/repos/{}/{}/releases?per_page=1", api, owner, repo),
+    }
+}
+
+/// npm version for a channel; beta falls back to latest if no beta is tagged
+fn npm_dist_tag(tags: NpmDistTags, channel: UpdateChannel) -> String {
+    match channel {
+        UpdateChannel::Stable => tags.latest,
+        UpdateChannel::Beta => tags.beta.unwrap_or(tags.latest),
+    }
+}
+
+fn read_cache(path: &Path) -> Vec<VersionCheckCache> {
+    std::fs::read(path)
+        .ok()
+        .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+        .unwrap_or_default()
+}
+
+/// Release asset name of the mnemosyne binary for this platform
+pub fn platform_asset_name() -> String {
+    format!(
+        "mnemosyne-{}-{}",
+        std::env::consts::ARCH,
+        std::env::consts::OS
+    )
+}
+
+/// Look up a file's SHA256 in `sha256sum`-style output
+///
+/// Lines are `<hex>  <name>`, optionally with a `*` before the name for
+/// binary mode.
+pub fn parse_checksum(checksums: &str, name: &str) -> Option<String> {
+    checksums.lines().find_map(|line| {
+        let (hash, file) = line.trim().split_once(char::is_whitespace)?;
+        let file = file.trim_start().trim_start_matches('*');
+        let valid = hash.len() == 64 && hash.chars().all(|c| c.is_ascii_hexdigit());
+        (file == name && valid).then(|| hash.to_ascii_lowercase())
+    })
+}
+
 impl Default for VersionChecker {
     fn default() -> Self {
         Self::new().expect("Failed to create version checker")
@@ -399,6 +705,125 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_checksum() {
+        let hash = "a".repeat(64);
+        let checksums = format!(
+            "{}  mnemosyne-x86_64-linux\n{} *mnemosyne-aarch64-macos\nnot-a-hash  other\n",
+            "b".repeat(64),
+            hash.to_uppercase()
+        );
+
+        assert_eq!(
+            parse_checksum(&checksums, "mnemosyne-aarch64-macos"),
+            Some(hash)
+        );
+        assert_eq!(
+            parse_checksum(&checksums, "mnemosyne-x86_64-linux"),
+            Some("b".repeat(64))
+        );
+        assert_eq!(parse_checksum(&checksums, "other"), None);
+        assert_eq!(parse_checksum(&checksums, "missing"), None);
+    }
+
+    fn offline_checker(cache_path: PathBuf, settings: &UpdateCheckSettings) -> VersionChecker {
+        let mut checker = VersionChecker::new()
+            .unwrap()
+            .with_settings(settings)
+            .with_cache_path(Some(cache_path));
+        // Nothing listens here, so a cache miss fails instead of going online
+        checker.github_api = "http://127.0.0.1:9".to_string();
+        checker.npm_registry = "http://127.0.0.1:9".to_string();
+        checker
+    }
+
+    #[tokio::test]
+    async fn test_cache_hit_within_ttl() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("version_check.json");
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let entry = |channel, checked_at| VersionCheckCache {
+            tool: Tool::Mnemosyne,
+            channel,
+            latest_version: "99.0.0".to_string(),
+            checked_at,
+            release_url: "https://example.invalid/release".to_string(),
+        };
+        std::fs::write(
+            &path,
+            serde_json::to_vec(&vec![entry(UpdateChannel::Stable, now)]).unwrap(),
+        )
+        .unwrap();
+
+        let stable = offline_checker(path.clone(), &UpdateCheckSettings::default());
+        let info = stable.check_tool(Tool::Mnemosyne).await.unwrap();
+        assert_eq!(info.latest.as_deref(), Some("99.0.0"));
+
+        // Entries are per channel
+        let beta = offline_checker(
+            path.clone(),
+            &UpdateCheckSettings {
+                channel: UpdateChannel::Beta,
+                ..Default::default()
+            },
+        );
+        assert!(beta.check_tool(Tool::Mnemosyne).await.is_err());
+
+        // Entries older than the TTL are ignored
+        std::fs::write(
+            &path,
+            serde_json::to_vec(&vec![entry(UpdateChannel::Stable, now - 2 * 3600)]).unwrap(),
+        )
+        .unwrap();
+        let short_ttl = offline_checker(
+            path,
+            &UpdateCheckSettings {
+                cache_ttl_hours: 1,
+                ..Default::default()
+            },
+        );
+        assert!(short_ttl.check_tool(Tool::Mnemosyne).await.is_err());
+    }
+
+    #[test]
+    fn test_channel_selects_endpoint() {
+        assert_eq!(
+            github_release_endpoint(GITHUB_API, "rand", "mnemosyne", UpdateChannel::Stable),
+            "https://api.github.com/repos/rand/mnemosyne/releases/latest"
+        );
+        assert_eq!(
+            github_release_endpoint(GITHUB_API, "rand", "mnemosyne", UpdateChannel::Beta),
+            "https://api.github.com/repos/rand/mnemosyne/releases?per_page=1"
+        );
+
+        let tags = || NpmDistTags {
+            latest: "1.0.0".to_string(),
+            beta: Some("1.1.0-beta.1".to_string()),
+        };
+        assert_eq!(npm_dist_tag(tags(), UpdateChannel::Stable), "1.0.0");
+        assert_eq!(npm_dist_tag(tags(), UpdateChannel::Beta), "1.1.0-beta.1");
+        let no_beta = NpmDistTags {
+            beta: None,
+            ..tags()
+        };
+        assert_eq!(npm_dist_tag(no_beta, UpdateChannel::Beta), "1.0.0");
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_no_update_check_env() {
+        std::env::remove_var(NO_UPDATE_CHECK_ENV);
+        assert!(!update_checks_disabled());
+        std::env::set_var(NO_UPDATE_CHECK_ENV, "1");
+        assert!(update_checks_disabled());
+        std::env::set_var(NO_UPDATE_CHECK_ENV, "false");
+        assert!(!update_checks_disabled());
+        std::env::remove_var(NO_UPDATE_CHECK_ENV);
+    }
+
     #[test]
     fn test_tool_names() {
         assert_eq!(Tool::Mnemosyne.name(), "mnemosyne");
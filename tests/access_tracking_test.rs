@@ -0,0 +1,51 @@
+//! Tests for batched access-count tracking
+
+use mnemosyne_core::{MemoryType, StorageBackend};
+
+mod common;
+use common::{create_test_storage, sample_memory};
+
+#[tokio::test]
+async fn test_n_recalls_increment_by_n() {
+    let storage = create_test_storage().await;
+    let first = sample_memory("Recalled cache memory", MemoryType::Insight, 5);
+    let second = sample_memory("Another cache memory", MemoryType::Insight, 5);
+    let unrelated = sample_memory("Unrelated", MemoryType::Insight, 5);
+    for memory in [&first, &second, &unrelated] {
+        storage.store_memory(memory).await.unwrap();
+    }
+
+    const RECALLS: u32 = 4;
+    for _ in 0..RECALLS {
+        let results = storage
+            .hybrid_search("cache", None, 10, false)
+            .await
+            .unwrap();
+        let ids: Vec<_> = results.iter().map(|r| r.memory.id).collect();
+        assert_eq!(ids.len(), 2);
+        storage.increment_access_batch(&ids).await.unwrap();
+    }
+
+    for memory in [&first, &second] {
+        let stored = storage.get_memory(memory.id).await.unwrap();
+        assert_eq!(stored.access_count, RECALLS);
+    }
+    let untouched = storage.get_memory(unrelated.id).await.unwrap();
+    assert_eq!(untouched.access_count, 0);
+}
+
+#[tokio::test]
+async fn test_batch_counts_duplicates() {
+    let storage = create_test_storage().await;
+    let memory = sample_memory("Accessed twice", MemoryType::Insight, 5);
+    storage.store_memory(&memory).await.unwrap();
+
+    storage
+        .increment_access_batch(&[memory.id, memory.id])
+        .await
+        .unwrap();
+    storage.increment_access_batch(&[]).await.unwrap();
+    storage.increment_access(memory.id).await.unwrap();
+
+    assert_eq!(storage.get_memory(memory.id).await.unwrap().access_count, 3);
+}
@@ -7,12 +7,19 @@ use mnemosyne_core::artifacts::{
     Constitution, FeatureSpec, ImplementationPlan, QualityChecklist, Task, TaskBreakdown,
     TaskPhase, UserScenario,
 };
-use mnemosyne_core::types::Namespace;
-use mnemosyne_core::{ConnectionMode, LibsqlStorage};
+use mnemosyne_core::types::{LinkType, MemoryType, Namespace};
+use mnemosyne_core::{ConnectionMode, LibsqlStorage, StorageBackend};
 use std::sync::Arc;
 
 /// Helper to create temp directory and workflow
 async fn setup_workflow() -> (tempfile::TempDir, ArtifactWorkflow) {
+    let (temp_dir, workflow, _storage) = setup_workflow_with_storage().await;
+    (temp_dir, workflow)
+}
+
+/// Like `setup_workflow`, also returning the memory storage
+async fn setup_workflow_with_storage() -> (tempfile::TempDir, ArtifactWorkflow, Arc<LibsqlStorage>)
+{
     let temp_dir = tempfile::tempdir().unwrap();
     let artifacts_dir = temp_dir.path().join(".mnemosyne/artifacts");
     std::fs::create_dir_all(&artifacts_dir).unwrap();
@@ -40,9 +47,9 @@ async fn setup_workflow() -> (tempfile::TempDir, ArtifactWorkflow) {
         .unwrap(),
     );
 
-    let workflow = ArtifactWorkflow::new(artifacts_dir, storage).unwrap();
+    let workflow = ArtifactWorkflow::new(artifacts_dir, storage.clone()).unwrap();
 
-    (temp_dir, workflow)
+    (temp_dir, workflow, storage)
 }
 
 #[tokio::test]
@@ -376,3 +383,111 @@ async fn test_complete_workflow_chain() {
         .unwrap();
     let _loaded_clarification = workflow.load_clarification("chain-feature").await.unwrap();
 }
+
+fn offline_sync_spec(requirement: &str) -> FeatureSpec {
+    FeatureSpec::builder("offline-sync".to_string(), "Offline Sync".to_string())
+        .requirement(requirement)
+        .build()
+}
+
+#[tokio::test]
+async fn test_artifact_memory_is_searchable_reference() {
+    let (_temp_dir, workflow, storage) = setup_workflow_with_storage().await;
+    let namespace = Namespace::Project {
+        name: "test-project".to_string(),
+    };
+
+    let mut spec = offline_sync_spec("Queue writes while the device is offline");
+    let memory_id = workflow
+        .save_feature_spec(&mut spec, namespace.clone(), None)
+        .await
+        .unwrap();
+
+    let memory = storage.get_memory(memory_id).await.unwrap();
+    assert_eq!(memory.memory_type, MemoryType::Reference);
+    assert!(memory
+        .related_files
+        .contains(&".mnemosyne/artifacts/specs/offline-sync.md".to_string()));
+
+    // Recalling the artifact's topic surfaces it
+    let results = storage
+        .hybrid_search("offline", Some(namespace), 10, false)
+        .await
+        .unwrap();
+    assert!(results.iter().any(|r| r.memory.id == memory_id));
+}
+
+#[tokio::test]
+async fn test_resaving_artifact_updates_memory() {
+    let (_temp_dir, workflow, storage) = setup_workflow_with_storage().await;
+    let namespace = Namespace::Project {
+        name: "test-project".to_string(),
+    };
+
+    let mut spec = offline_sync_spec("Queue writes while offline");
+    let first_id = workflow
+        .save_feature_spec(&mut spec, namespace.clone(), None)
+        .await
+        .unwrap();
+
+    // Same instance
+    let second_id = workflow
+        .save_feature_spec(&mut spec, namespace.clone(), None)
+        .await
+        .unwrap();
+    assert_eq!(second_id, first_id);
+
+    // Fresh instance overwriting the same file
+    let mut rewritten = offline_sync_spec("Replay queued writes on reconnect");
+    let third_id = workflow
+        .save_feature_spec(&mut rewritten, namespace.clone(), None)
+        .await
+        .unwrap();
+    assert_eq!(third_id, first_id);
+
+    assert_eq!(storage.count_memories(Some(namespace)).await.unwrap(), 1);
+    let memory = storage.get_memory(first_id).await.unwrap();
+    assert!(memory.content.contains("Replay queued writes"));
+}
+
+#[tokio::test]
+async fn test_artifact_memories_link_to_references() {
+    let (_temp_dir, workflow, storage) = setup_workflow_with_storage().await;
+    let namespace = Namespace::Project {
+        name: "test-project".to_string(),
+    };
+
+    let mut constitution = Constitution::builder("LinkTest".to_string())
+        .principle("Offline first")
+        .build();
+    let constitution_id = workflow
+        .save_constitution(&mut constitution, namespace.clone())
+        .await
+        .unwrap();
+
+    let mut spec = offline_sync_spec("Queue writes while offline");
+    let spec_id = workflow
+        .save_feature_spec(
+            &mut spec,
+            namespace.clone(),
+            Some(constitution_id.to_string()),
+        )
+        .await
+        .unwrap();
+
+    // Re-saving doesn't duplicate the reference or the link
+    workflow
+        .save_feature_spec(
+            &mut spec,
+            namespace.clone(),
+            Some(constitution_id.to_string()),
+        )
+        .await
+        .unwrap();
+    assert_eq!(spec.metadata.references, vec![constitution_id.to_string()]);
+
+    let memory = storage.get_memory(spec_id).await.unwrap();
+    assert_eq!(memory.links.len(), 1);
+    assert_eq!(memory.links[0].target_id, constitution_id);
+    assert_eq!(memory.links[0].link_type, LinkType::References);
+}
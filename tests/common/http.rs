@@ -0,0 +1,67 @@
+//! Minimal HTTP server for tests against mock APIs (Messages, Voyage)
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+/// Read one request's headers, then its declared body, and return the body
+/// as JSON (null if it isn't JSON)
+pub async fn read_request(socket: &mut TcpStream) -> serde_json::Value {
+    let mut request = Vec::new();
+    let mut buf = [0u8; 4096];
+    let header_end = loop {
+        let n = socket.read(&mut buf).await.unwrap();
+        request.extend_from_slice(&buf[..n]);
+        if let Some(pos) = request.windows(4).position(|w| w == b"\r\n\r\n") {
+            break pos + 4;
+        }
+    };
+    let headers = String::from_utf8_lossy(&request[..header_end]).to_lowercase();
+    let content_length = headers
+        .lines()
+        .find_map(|line| line.strip_prefix("content-length:"))
+        .and_then(|len| len.trim().parse::<usize>().ok())
+        .unwrap_or(0);
+    while request.len() < header_end + content_length {
+        let n = socket.read(&mut buf).await.unwrap();
+        request.extend_from_slice(&buf[..n]);
+    }
+    serde_json::from_slice(&request[header_end..]).unwrap_or_default()
+}
+
+/// Answer with `200 OK` and a JSON body, then close
+pub async fn write_json(socket: &mut TcpStream, body: &serde_json::Value) {
+    let body = body.to_string();
+    let response = format!(
+        "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+        body.len(),
+        body
+    );
+    socket.write_all(response.as_bytes()).await.unwrap();
+}
+
+/// Answer every request with the JSON `respond` returns for its body;
+/// returns the base URL
+pub async fn mock_json<F>(mut respond: F) -> String
+where
+    F: FnMut(serde_json::Value) -> serde_json::Value + Send + 'static,
+{
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    tokio::spawn(async move {
+        while let Ok((mut socket, _)) = listener.accept().await {
+            let request = read_request(&mut socket).await;
+            write_json(&mut socket, &respond(request)).await;
+        }
+    });
+
+    format!("http://{}", addr)
+}
+
+/// Base URL with nothing listening
+pub async fn unreachable_url() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    drop(listener);
+    format!("http://{}", addr)
+}
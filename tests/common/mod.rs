@@ -1,5 +1,7 @@
 //! Common test utilities and helpers
 
+pub mod http;
+
 use mnemosyne_core::{
     ConfigManager, ConnectionMode, LibsqlStorage, LlmConfig, LlmService, MemoryId, MemoryNote,
     MemoryType, Namespace,
@@ -33,6 +35,7 @@ pub fn create_test_llm_service() -> Arc<LlmService> {
             model: "claude-haiku-4-5-20251001".to_string(),
             max_tokens: 1024,
             temperature: 0.7,
+            task_models: Default::default(),
         })
         .expect("Failed to create test LLM service"),
     )
@@ -49,6 +52,7 @@ pub fn create_real_llm_service() -> Option<Arc<LlmService>> {
                 model: "claude-haiku-4-5-20251001".to_string(),
                 max_tokens: 1024,
                 temperature: 0.7,
+                task_models: Default::default(),
             })
             .ok()
         })
@@ -0,0 +1,106 @@
+//! Custom (team-defined) memory types round-trip through storage
+
+use mnemosyne_core::{
+    storage::{MemoryFilter, MemorySortOrder},
+    LibsqlStorage, MemoryType, MemoryTypesConfig, SearchQuery, StorageBackend,
+};
+
+mod common;
+use common::{create_test_storage, sample_memory};
+
+fn security_finding() -> MemoryType {
+    MemoryType::Custom("security_finding".to_string())
+}
+
+/// Test storage with `security_finding` declared
+async fn storage_declaring_security_finding() -> LibsqlStorage {
+    let mut storage = create_test_storage().await;
+    let mut memory_types = MemoryTypesConfig::default();
+    memory_types.custom.insert(
+        "security_finding".to_string(),
+        "Vulnerabilities found in review".to_string(),
+    );
+    storage.set_memory_types(memory_types);
+    storage
+}
+
+#[tokio::test]
+async fn test_custom_type_round_trips() {
+    let storage = storage_declaring_security_finding().await;
+    let finding = sample_memory("SQL injection in login form", security_finding(), 8);
+    storage.store_memory(&finding).await.unwrap();
+
+    let stored = storage.get_memory(finding.id).await.unwrap();
+    assert_eq!(stored.memory_type, security_finding());
+
+    let results = storage
+        .hybrid_search("injection", None, 10, false)
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].memory.memory_type, security_finding());
+
+    // Built-in names still parse as built-ins
+    let mut updated = stored.clone();
+    updated.memory_type = MemoryType::from_name("bug_fix");
+    storage.update_memory(&updated).await.unwrap();
+    assert_eq!(
+        storage.get_memory(finding.id).await.unwrap().memory_type,
+        MemoryType::BugFix
+    );
+}
+
+#[tokio::test]
+async fn test_filter_by_custom_type() {
+    let storage = storage_declaring_security_finding().await;
+    let finding = sample_memory("Token leaked in logs", security_finding(), 8);
+    let insight = sample_memory("Token refresh is slow", MemoryType::Insight, 5);
+    storage.store_memory(&finding).await.unwrap();
+    storage.store_memory(&insight).await.unwrap();
+
+    let listed = storage
+        .list_memories_filtered(
+            &MemoryFilter {
+                memory_type: Some(security_finding()),
+                ..Default::default()
+            },
+            10,
+            MemorySortOrder::Recent,
+        )
+        .await
+        .unwrap();
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed[0].id, finding.id);
+
+    let searched = storage
+        .search(&SearchQuery {
+            query: "token".to_string(),
+            memory_types: vec![security_finding()],
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(searched.len(), 1);
+    assert_eq!(searched[0].memory.id, finding.id);
+
+    let stats = storage.memory_stats(None, 10).await.unwrap();
+    assert_eq!(stats.by_type.get("security_finding"), Some(&1));
+}
+
+#[tokio::test]
+async fn test_malformed_custom_type_rejected() {
+    let storage = create_test_storage().await;
+    let memory = sample_memory(
+        "Bad type name",
+        MemoryType::Custom("Security Finding".to_string()),
+        5,
+    );
+    assert!(storage.store_memory(&memory).await.is_err());
+}
+
+#[tokio::test]
+async fn test_undeclared_custom_type_rejected() {
+    let storage = create_test_storage().await;
+    let memory = sample_memory("Token leaked in logs", security_finding(), 8);
+    assert!(storage.store_memory(&memory).await.is_err());
+}
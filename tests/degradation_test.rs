@@ -0,0 +1,72 @@
+//! Degradation state follows embedding service failures and recoveries
+//!
+//! Single test: the degradation state is process-global.
+
+use mnemosyne_core::{
+    api::{EventBroadcaster, EventType},
+    global_degradation, DegradedService, EmbeddingService, RemoteEmbeddingService,
+    VOYAGE_EMBEDDING_DIM,
+};
+
+mod common;
+use common::http::{mock_json, unreachable_url};
+
+/// Answer embedding requests with a Voyage-shaped response; returns the
+/// base URL
+async fn mock_voyage() -> String {
+    mock_json(|_| {
+        serde_json::json!({
+            "data": [{ "embedding": vec![0.1_f32; VOYAGE_EMBEDDING_DIM], "index": 0 }],
+            "usage": { "total_tokens": 3 }
+        })
+    })
+    .await
+}
+
+#[tokio::test]
+async fn test_embedding_failure_flips_flag_and_success_clears_it() {
+    let state = global_degradation();
+    let broadcaster = EventBroadcaster::new(16);
+    let mut rx = broadcaster.subscribe();
+    state.set_broadcaster(broadcaster);
+    assert!(!state.is_degraded(DegradedService::Embeddings));
+
+    let failing =
+        RemoteEmbeddingService::new("key".to_string(), None, Some(unreachable_url().await))
+            .unwrap();
+    assert!(failing.embed("connection refused").await.is_err());
+
+    assert!(state.is_degraded(DegradedService::Embeddings));
+    let degraded = state.snapshot();
+    assert_eq!(degraded.len(), 1);
+    assert_eq!(degraded[0].service, DegradedService::Embeddings);
+    assert!(!degraded[0].reason.is_empty());
+
+    let working =
+        RemoteEmbeddingService::new("key".to_string(), None, Some(mock_voyage().await)).unwrap();
+    let embedding = working.embed("back online").await.unwrap();
+    assert_eq!(embedding.len(), VOYAGE_EMBEDDING_DIM);
+
+    assert!(!state.is_degraded(DegradedService::Embeddings));
+    assert!(state.snapshot().is_empty());
+
+    let mut changes = Vec::new();
+    while let Ok(event) = rx.try_recv() {
+        if let EventType::DegradationChanged {
+            service,
+            degraded,
+            reason,
+            ..
+        } = event.event_type
+        {
+            changes.push((service, degraded, reason.is_some()));
+        }
+    }
+    assert_eq!(
+        changes,
+        vec![
+            ("embeddings".to_string(), true, true),
+            ("embeddings".to_string(), false, false),
+        ]
+    );
+}
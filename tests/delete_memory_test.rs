@@ -0,0 +1,130 @@
+//! Permanent deletion of a memory and the rows that refer to it
+
+use mnemosyne_core::error::MnemosyneError;
+use mnemosyne_core::evaluation::feedback_collector::{
+    ContextType, FeedbackCollector, ProvidedContext,
+};
+use mnemosyne_core::{
+    ConnectionMode, LibsqlStorage, LinkType, MemoryLink, MemoryNote, MemoryType, StorageBackend,
+};
+use tempfile::TempDir;
+
+mod common;
+use common::sample_memory;
+
+fn link_to(memory: &MemoryNote) -> MemoryLink {
+    MemoryLink {
+        target_id: memory.id,
+        link_type: LinkType::References,
+        strength: 0.8,
+        reason: "Test link".to_string(),
+        created_at: chrono::Utc::now(),
+        last_traversed_at: None,
+        user_created: false,
+    }
+}
+
+fn provided(context_type: ContextType, context_id: String) -> ProvidedContext {
+    ProvidedContext {
+        session_id: "delete-session".to_string(),
+        agent_role: "optimizer".to_string(),
+        namespace: "global".to_string(),
+        context_type,
+        context_id,
+        task_hash: "0123456789abcdef".to_string(),
+        task_keywords: None,
+        task_type: None,
+        work_phase: None,
+        file_types: None,
+        error_context: None,
+        related_technologies: None,
+    }
+}
+
+async fn count(conn: &libsql::Connection, sql: &str, id: &str) -> i64 {
+    let mut rows = conn.query(sql, libsql::params![id]).await.unwrap();
+    rows.next().await.unwrap().unwrap().get(0).unwrap()
+}
+
+#[tokio::test]
+async fn test_delete_memory_removes_related_rows() {
+    let temp_dir = TempDir::new().unwrap();
+    let db_path = temp_dir
+        .path()
+        .join("delete.db")
+        .to_string_lossy()
+        .to_string();
+    let storage = LibsqlStorage::new_with_validation(ConnectionMode::Local(db_path.clone()), true)
+        .await
+        .unwrap();
+
+    let target = sample_memory("Deploys go through staging first", MemoryType::Insight, 5);
+    let mut doomed = sample_memory("API key sk-live-leaked-secret", MemoryType::Insight, 5);
+    doomed.links.push(link_to(&target));
+    let mut referrer = sample_memory("Rotate credentials quarterly", MemoryType::Insight, 5);
+    referrer.links.push(link_to(&doomed));
+    for memory in [&target, &doomed, &referrer] {
+        storage.store_memory(memory).await.unwrap();
+    }
+
+    let collector = FeedbackCollector::new(db_path.clone());
+    collector.init_schema().await.unwrap();
+    collector
+        .record_context_provided(provided(ContextType::Memory, doomed.id.to_string()))
+        .await
+        .unwrap();
+    let kept_eval = collector
+        .record_context_provided(provided(ContextType::Skill, "rust-async.md".to_string()))
+        .await
+        .unwrap();
+
+    storage.delete_memory(doomed.id).await.unwrap();
+
+    assert!(matches!(
+        storage.get_memory(doomed.id).await,
+        Err(MnemosyneError::MemoryNotFound(_))
+    ));
+    assert!(storage
+        .keyword_search("leaked", None)
+        .await
+        .unwrap()
+        .is_empty());
+    assert!(storage
+        .get_memory(referrer.id)
+        .await
+        .unwrap()
+        .links
+        .is_empty());
+    assert!(storage.get_memory(target.id).await.is_ok());
+
+    let db = libsql::Builder::new_local(&db_path).build().await.unwrap();
+    let conn = db.connect().unwrap();
+    let id = doomed.id.to_string();
+    for sql in [
+        "SELECT COUNT(*) FROM memory_links WHERE source_id = ?1 OR target_id = ?1",
+        "SELECT COUNT(*) FROM context_evaluations WHERE context_id = ?1",
+    ] {
+        assert_eq!(count(&conn, sql, &id).await, 0, "{}", sql);
+    }
+    assert!(collector.get_evaluation(&kept_eval).await.is_ok());
+
+    // Earlier audit history stays, and the deletion itself is recorded
+    // against the memory's ID, without the content
+    let audited = "SELECT COUNT(*) FROM audit_log WHERE memory_id = ?1 AND operation = 'create'";
+    assert_eq!(count(&conn, audited, &id).await, 1);
+    let mut rows = conn
+        .query(
+            "SELECT metadata FROM audit_log WHERE memory_id = ?1 AND operation = 'delete'",
+            libsql::params![id.clone()],
+        )
+        .await
+        .unwrap();
+    let metadata: String = rows.next().await.unwrap().unwrap().get(0).unwrap();
+    assert!(!metadata.contains("sk-live"));
+
+    // Deleting again reports the memory as missing
+    assert!(matches!(
+        storage.delete_memory(doomed.id).await,
+        Err(MnemosyneError::MemoryNotFound(_))
+    ));
+}
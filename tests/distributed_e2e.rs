@@ -1,7 +1,7 @@
 use async_trait::async_trait;
 use mnemosyne_core::launcher::agents::AgentRole;
 use mnemosyne_core::orchestration::messages::{AgentMessage, ExecutorMessage};
-use mnemosyne_core::orchestration::network::router::LocalAgent;
+use mnemosyne_core::orchestration::network::router::{AgentLocation, LocalAgent};
 use mnemosyne_core::orchestration::network::{MessageRouter, NetworkLayer};
 use mnemosyne_core::orchestration::state::{Phase, WorkItem};
 use ractor::{Actor, ActorProcessingErr, ActorRef};
@@ -69,7 +69,7 @@ async fn test_distributed_execution_flow() -> anyhow::Result<()> {
 
     // 3. Connect Node A to Node B
     // Get Node B's invite ticket
-    let ticket = node_b.create_invite().await?;
+    let ticket = node_b.create_invite(None).await?;
 
     // Node A joins Node B
     node_a.join_peer(&ticket).await?;
@@ -97,7 +97,10 @@ async fn test_distributed_execution_flow() -> anyhow::Result<()> {
     // Route message with retry
     let mut attempts = 0;
     loop {
-        match router_a.route(AgentRole::Executor, message.clone()).await {
+        match router_a
+            .route(AgentRole::Executor, message.clone(), None)
+            .await
+        {
             Ok(_) => {
                 println!("Message routed successfully");
                 break;
@@ -129,3 +132,76 @@ async fn test_distributed_execution_flow() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+#[ignore] // Flaky in restricted test environments due to P2P connection setup
+async fn test_remote_node_id_is_captured() -> anyhow::Result<()> {
+    std::env::set_var("MNEMOSYNE_TEST_BIND_ADDR", "127.0.0.1:0");
+
+    let (node_a, router_a) = spawn_test_node().await?;
+    let (node_b, router_b) = spawn_test_node().await?;
+    let node_a_id = node_a.node_id().await.unwrap();
+    let node_b_id = node_b.node_id().await.unwrap();
+
+    // Both nodes host an executor, so joining announces Node A's roles
+    let (tx_a, _rx_a) = mpsc::channel(1);
+    let (executor_a, _) = Actor::spawn(None, MockExecutorActor { tx: tx_a }, ()).await?;
+    router_a
+        .register_local(AgentRole::Executor, LocalAgent::Executor(executor_a))
+        .await;
+    let (tx_b, mut rx_b) = mpsc::channel(1);
+    let (executor_b, _) = Actor::spawn(None, MockExecutorActor { tx: tx_b }, ()).await?;
+    router_b
+        .register_local(
+            AgentRole::Executor,
+            LocalAgent::Executor(executor_b.clone()),
+        )
+        .await;
+
+    let ticket = node_b.create_invite(None).await?;
+    node_a.join_peer(&ticket).await?;
+
+    // Node B registers the announcement under Node A's authenticated ID
+    let announced = tokio::time::timeout(Duration::from_secs(5), async {
+        loop {
+            let registered =
+                router_b.list_agents().await.into_iter().any(
+                    |(_, loc)| matches!(loc, AgentLocation::Remote(ref id) if *id == node_a_id),
+                );
+            if registered {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+    })
+    .await;
+    assert!(announced.is_ok(), "Node A's roles were not registered");
+
+    // Work sent directly from Node A records Node A as the sender on Node B
+    let work_item = WorkItem::new(
+        "Remote sender test".to_string(),
+        AgentRole::Executor,
+        Phase::PlanToArtifacts,
+        1,
+    );
+    router_a
+        .register_remote(AgentRole::Executor, node_b_id.clone())
+        .await;
+    let message = AgentMessage::Executor(Box::new(ExecutorMessage::ExecuteWork(work_item)));
+    router_a
+        .route_to(AgentRole::Executor, &node_b_id, message)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?;
+
+    let received = tokio::time::timeout(Duration::from_secs(5), rx_b.recv()).await;
+    assert!(received.is_ok(), "Timed out waiting for message");
+    assert_eq!(
+        router_b.last_sender(&executor_b.get_id().to_string()).await,
+        Some(node_a_id)
+    );
+
+    node_a.stop().await?;
+    node_b.stop().await?;
+
+    Ok(())
+}
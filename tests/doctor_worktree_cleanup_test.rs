@@ -7,8 +7,9 @@
 //! 4. Verify cleanup succeeded
 
 use mnemosyne_core::health::{run_health_checks, CheckStatus};
-use mnemosyne_core::orchestration::{identity::AgentId, WorktreeManager};
+use mnemosyne_core::orchestration::{identity::AgentId, CrossProcessCoordinator, WorktreeManager};
 use mnemosyne_core::storage::libsql::{ConnectionMode, LibsqlStorage};
+use serial_test::serial;
 use std::fs;
 use std::process::Command;
 use tempfile::TempDir;
@@ -93,6 +94,7 @@ fn setup_test_repo() -> (TempDir, WorktreeManager, Vec<AgentId>) {
 }
 
 #[tokio::test]
+#[serial]
 async fn test_doctor_detects_worktrees() {
     let (temp_dir, manager, agent_ids) = setup_test_repo();
 
@@ -105,7 +107,7 @@ async fn test_doctor_detects_worktrees() {
         .expect("Failed to create storage");
 
     // Run doctor without fix (should detect worktrees)
-    let summary = run_health_checks(&storage, false, false)
+    let summary = run_health_checks(&storage, false, false, false)
         .await
         .expect("Health checks failed");
 
@@ -147,6 +149,7 @@ async fn test_doctor_detects_worktrees() {
 }
 
 #[tokio::test]
+#[serial]
 async fn test_doctor_fix_cleans_worktrees() {
     let (temp_dir, manager, agent_ids) = setup_test_repo();
 
@@ -164,7 +167,7 @@ async fn test_doctor_fix_cleans_worktrees() {
     assert!(manager.worktree_exists(&agent_ids[2]));
 
     // Run doctor with fix (should clean up all worktrees)
-    let summary = run_health_checks(&storage, false, true)
+    let summary = run_health_checks(&storage, false, true, false)
         .await
         .expect("Health checks failed");
 
@@ -213,6 +216,49 @@ async fn test_doctor_fix_cleans_worktrees() {
 }
 
 #[tokio::test]
+#[serial]
+async fn test_doctor_fix_keeps_live_session_worktree() {
+    let (temp_dir, manager, agent_ids) = setup_test_repo();
+
+    std::env::set_current_dir(temp_dir.path()).expect("Failed to change directory");
+
+    // agent 0 belongs to this (running) process, as the launcher registers it
+    let mut coordinator =
+        CrossProcessCoordinator::new(&temp_dir.path().join(".mnemosyne"), agent_ids[0].clone())
+            .expect("Failed to create coordinator");
+    coordinator
+        .set_worktree_path(manager.get_worktree_path(&agent_ids[0]))
+        .expect("Failed to register worktree");
+
+    let storage = LibsqlStorage::new(ConnectionMode::InMemory)
+        .await
+        .expect("Failed to create storage");
+
+    let summary = run_health_checks(&storage, false, true, false)
+        .await
+        .expect("Health checks failed");
+
+    let cleanup_check = summary
+        .checks
+        .iter()
+        .find(|c| c.name == "worktree_cleanup")
+        .expect("worktree_cleanup not found");
+
+    assert_eq!(cleanup_check.status, CheckStatus::Pass);
+    let details = cleanup_check.details.as_ref().expect("details missing");
+    assert_eq!(details["kept_count"].as_u64(), Some(1));
+    assert!(cleanup_check.message.contains("kept"));
+
+    assert!(
+        manager.worktree_exists(&agent_ids[0]),
+        "Live session's worktree must be kept"
+    );
+    assert!(!manager.worktree_exists(&agent_ids[1]));
+    assert!(!manager.worktree_exists(&agent_ids[2]));
+}
+
+#[tokio::test]
+#[serial]
 async fn test_doctor_no_worktrees() {
     let temp_dir = tempfile::tempdir().expect("Failed to create temp dir");
     let repo_path = temp_dir.path();
@@ -256,7 +302,7 @@ async fn test_doctor_no_worktrees() {
         .await
         .expect("Failed to create storage");
 
-    let summary = run_health_checks(&storage, false, false)
+    let summary = run_health_checks(&storage, false, false, false)
         .await
         .expect("Health checks failed");
 
@@ -293,6 +339,7 @@ async fn test_doctor_no_worktrees() {
 }
 
 #[tokio::test]
+#[serial]
 async fn test_doctor_outside_git_repo() {
     let temp_dir = tempfile::tempdir().expect("Failed to create temp dir");
 
@@ -303,7 +350,7 @@ async fn test_doctor_outside_git_repo() {
         .await
         .expect("Failed to create storage");
 
-    let summary = run_health_checks(&storage, false, false)
+    let summary = run_health_checks(&storage, false, false, false)
         .await
         .expect("Health checks failed");
 
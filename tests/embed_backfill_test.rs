@@ -0,0 +1,205 @@
+//! `mnemosyne embed` backfill: skip memories already embedded with the model
+
+use async_trait::async_trait;
+use mnemosyne_core::embeddings::{DeterministicEmbeddingService, DETERMINISTIC_EMBEDDING_DIM};
+use mnemosyne_core::error::Result;
+use mnemosyne_core::{
+    backfill_embeddings, BackfillOptions, EmbeddingService, MemoryType, Namespace, StorageBackend,
+};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+mod common;
+use common::{create_test_storage, sample_memory};
+
+#[tokio::test]
+async fn test_second_run_embeds_nothing_new() {
+    let storage = create_test_storage().await;
+    for i in 0..5 {
+        storage
+            .store_memory(&sample_memory(
+                &format!("Deployment note {}", i),
+                MemoryType::Insight,
+                5,
+            ))
+            .await
+            .unwrap();
+    }
+    let service = DeterministicEmbeddingService::new(DETERMINISTIC_EMBEDDING_DIM);
+    let options = BackfillOptions {
+        batch_size: 2,
+        ..BackfillOptions::default()
+    };
+
+    let mut batches = 0;
+    let first = backfill_embeddings(&storage, &service, &options, |_| batches += 1)
+        .await
+        .unwrap();
+    assert_eq!((first.total, first.embedded, first.skipped), (5, 5, 0));
+    assert_eq!(batches, 3);
+
+    let second = backfill_embeddings(&storage, &service, &options, |_| {})
+        .await
+        .unwrap();
+    assert_eq!((second.total, second.embedded, second.skipped), (5, 0, 5));
+
+    let forced = backfill_embeddings(
+        &storage,
+        &service,
+        &BackfillOptions {
+            force: true,
+            ..options.clone()
+        },
+        |_| {},
+    )
+    .await
+    .unwrap();
+    assert_eq!(forced.embedded, 5);
+}
+
+#[tokio::test]
+async fn test_resume_and_model_change() {
+    let storage = create_test_storage().await;
+    let mut ids = Vec::new();
+    for i in 0..4 {
+        let mut memory = sample_memory(&format!("Cache note {}", i), MemoryType::Insight, 5);
+        memory.namespace = Namespace::Project {
+            name: "myapp".to_string(),
+        };
+        storage.store_memory(&memory).await.unwrap();
+        ids.push(memory.id);
+    }
+    ids.sort_by_key(|id| id.to_string());
+    let service = DeterministicEmbeddingService::new(DETERMINISTIC_EMBEDDING_DIM);
+
+    // Embedded by an older model: stale, so picked up again
+    storage
+        .store_memory_embedding(&ids[0], &service.embed("x").await.unwrap(), "old-model")
+        .await
+        .unwrap();
+    assert_eq!(
+        storage
+            .count_memories_needing_embedding(service.model_name(), None, false, None)
+            .await
+            .unwrap(),
+        4
+    );
+
+    // A forced run resuming from a checkpoint starts after it
+    let resumed = backfill_embeddings(
+        &storage,
+        &service,
+        &BackfillOptions {
+            namespace: Some(Namespace::Project {
+                name: "myapp".to_string(),
+            }),
+            force: true,
+            resume_after: Some(ids[1]),
+            ..BackfillOptions::default()
+        },
+        |_| {},
+    )
+    .await
+    .unwrap();
+    assert_eq!((resumed.embedded, resumed.skipped), (2, 2));
+    assert_eq!(resumed.last_id, Some(ids[3]));
+
+    let remaining = storage
+        .memories_needing_embedding(service.model_name(), None, false, None, 10)
+        .await
+        .unwrap();
+    let remaining: Vec<_> = remaining.into_iter().map(|m| m.id).collect();
+    assert_eq!(remaining, vec![ids[0], ids[1]]);
+}
+
+/// Answers after a short delay, recording the most requests in flight at once
+#[derive(Default)]
+struct SlowEmbeddingService {
+    inner: DeterministicEmbeddingService,
+    in_flight: AtomicUsize,
+    peak: AtomicUsize,
+}
+
+#[async_trait]
+impl EmbeddingService for SlowEmbeddingService {
+    async fn embed(&self, text: &str) -> Result<Vec<f32>> {
+        let now = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+        self.peak.fetch_max(now, Ordering::SeqCst);
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        self.in_flight.fetch_sub(1, Ordering::SeqCst);
+        self.inner.embed(text).await
+    }
+
+    async fn embed_batch(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>> {
+        self.inner.embed_batch(texts).await
+    }
+
+    fn dimensions(&self) -> usize {
+        self.inner.dimensions()
+    }
+
+    fn model_name(&self) -> &str {
+        self.inner.model_name()
+    }
+}
+
+#[tokio::test]
+async fn test_concurrent_batches_overlap_and_store_everything() {
+    let storage = create_test_storage().await;
+    let mut ids = Vec::new();
+    for i in 0..8 {
+        let memory = sample_memory(&format!("Release note {}", i), MemoryType::Insight, 5);
+        storage.store_memory(&memory).await.unwrap();
+        ids.push(memory.id);
+    }
+    ids.sort_by_key(|id| id.to_string());
+
+    let service = SlowEmbeddingService::default();
+    let mut reported = Vec::new();
+    let summary = backfill_embeddings(
+        &storage,
+        &service,
+        &BackfillOptions {
+            batch_size: 2,
+            concurrency: 4,
+            ..BackfillOptions::default()
+        },
+        |progress| reported.push(progress.clone()),
+    )
+    .await
+    .unwrap();
+
+    assert!(service.peak.load(Ordering::SeqCst) > 1);
+    assert_eq!((summary.total, summary.embedded, summary.failed), (8, 8, 0));
+    assert_eq!(summary.last_id, Some(ids[7]));
+
+    // Counts only grow, one report per batch, ending at the total
+    assert_eq!(reported.len(), 4);
+    assert!(reported
+        .windows(2)
+        .all(|pair| pair[0].processed() < pair[1].processed()));
+    assert_eq!(reported.last().unwrap().processed(), 8);
+
+    let remaining = storage
+        .count_memories_needing_embedding(service.model_name(), None, false, None)
+        .await
+        .unwrap();
+    assert_eq!(remaining, 0);
+
+    // The default runs one batch at a time
+    let sequential = SlowEmbeddingService::default();
+    let forced = backfill_embeddings(
+        &storage,
+        &sequential,
+        &BackfillOptions {
+            batch_size: 2,
+            force: true,
+            ..BackfillOptions::default()
+        },
+        |_| {},
+    )
+    .await
+    .unwrap();
+    assert_eq!(forced.embedded, 8);
+    assert_eq!(sequential.peak.load(Ordering::SeqCst), 1);
+}
@@ -0,0 +1,133 @@
+//! Stale embedding models: detected, and kept out of vector search
+
+use mnemosyne_core::diagnostics::Operation;
+use mnemosyne_core::embeddings::{DeterministicEmbeddingService, DETERMINISTIC_EMBEDDING_DIM};
+use mnemosyne_core::{
+    global_latency, EmbeddingService, LibsqlStorage, MemoryId, MemoryManager, MemoryType,
+    SearchConfig, StaleEmbeddingPolicy, StorageBackend,
+};
+use std::sync::Arc;
+
+mod common;
+use common::{create_test_storage, sample_memory};
+
+/// Two memories with the same embedding, one from an older model
+async fn mixed_model_storage(
+    service: &DeterministicEmbeddingService,
+) -> (LibsqlStorage, MemoryId, MemoryId) {
+    let storage = create_test_storage().await;
+    let embedding = service.embed("retry with backoff").await.unwrap();
+
+    let current = sample_memory("Retry with backoff", MemoryType::CodePattern, 6);
+    let stale = sample_memory("Retry with exponential backoff", MemoryType::CodePattern, 6);
+    for memory in [&current, &stale] {
+        storage.store_memory(memory).await.unwrap();
+    }
+    storage
+        .store_memory_embedding(&current.id, &embedding, service.model_name())
+        .await
+        .unwrap();
+    storage
+        .store_memory_embedding(&stale.id, &embedding, "old-model")
+        .await
+        .unwrap();
+
+    (storage, current.id, stale.id)
+}
+
+#[tokio::test]
+async fn test_stale_model_excluded_from_vector_search() {
+    let service = DeterministicEmbeddingService::new(DETERMINISTIC_EMBEDDING_DIM);
+    let (mut storage, current, stale) = mixed_model_storage(&service).await;
+    let query = service.embed("retry with backoff").await.unwrap();
+
+    let status = storage
+        .embedding_model_status(service.model_name())
+        .await
+        .unwrap();
+    assert_eq!((status.embedded, status.stale), (2, 1));
+    assert!((status.stale_fraction() - 0.5).abs() < 1e-9);
+
+    // Without an active model nothing is known to be stale
+    assert_eq!(
+        storage.vector_search(&query, 10, None).await.unwrap().len(),
+        2
+    );
+
+    storage.set_active_embedding_model(Some(service.model_name().to_string()));
+    let hits = storage.vector_search(&query, 10, None).await.unwrap();
+    assert_eq!(
+        hits.iter().map(|(id, _)| *id).collect::<Vec<_>>(),
+        vec![current]
+    );
+    let results = StorageBackend::vector_search(&storage, &query, 10, None)
+        .await
+        .unwrap();
+    assert!(results.iter().all(|r| r.memory.id != stale));
+
+    storage.set_search_config(SearchConfig {
+        stale_embeddings: StaleEmbeddingPolicy::Include,
+        ..SearchConfig::default()
+    });
+    assert_eq!(
+        storage.vector_search(&query, 10, None).await.unwrap().len(),
+        2
+    );
+}
+
+#[tokio::test]
+async fn test_manager_reembeds_stale_memories() {
+    let service = Arc::new(DeterministicEmbeddingService::new(
+        DETERMINISTIC_EMBEDDING_DIM,
+    ));
+    let (mut storage, _, stale) = mixed_model_storage(&service).await;
+    storage.set_search_config(SearchConfig {
+        stale_embeddings: StaleEmbeddingPolicy::Reembed,
+        ..SearchConfig::default()
+    });
+
+    let manager = MemoryManager::new(Arc::new(storage)).with_embeddings(service.clone());
+    let progress = manager.reembed_stale().await.unwrap();
+    assert_eq!(progress.embedded, 1);
+
+    let status = manager
+        .storage()
+        .embedding_model_status(service.model_name())
+        .await
+        .unwrap();
+    assert_eq!(status.stale, 0);
+    let memory = manager.storage().get_memory(stale).await.unwrap();
+    assert_eq!(memory.embedding_model, service.model_name());
+}
+
+#[tokio::test]
+async fn test_updated_embedding_records_its_model() {
+    let service = DeterministicEmbeddingService::new(DETERMINISTIC_EMBEDDING_DIM);
+    let (storage, _, stale) = mixed_model_storage(&service).await;
+
+    let mut memory = storage.get_memory(stale).await.unwrap();
+    memory.embedding = Some(service.embed(&memory.content).await.unwrap());
+    memory.embedding_model = service.model_name().to_string();
+    storage.update_memory(&memory).await.unwrap();
+
+    let status = storage
+        .embedding_model_status(service.model_name())
+        .await
+        .unwrap();
+    assert_eq!((status.embedded, status.stale), (2, 0));
+}
+
+#[tokio::test]
+async fn test_vector_search_is_timed() {
+    let service = DeterministicEmbeddingService::new(DETERMINISTIC_EMBEDDING_DIM);
+    let (storage, _, _) = mixed_model_storage(&service).await;
+    let query = service.embed("retry with backoff").await.unwrap();
+
+    // The search MCP recall runs, not only the hybrid candidate helper
+    let timed = global_latency().histogram(Operation::VectorSearch);
+    let before = timed.count();
+    StorageBackend::vector_search(&storage, &query, 10, None)
+        .await
+        .unwrap();
+    assert!(timed.count() > before);
+}
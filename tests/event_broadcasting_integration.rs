@@ -289,6 +289,7 @@ async fn test_sse_subscriber_converts_api_event_to_agent_event() {
     let _api_event = ApiEvent {
         id: "test-1".to_string(),
         instance_id: None,
+        correlation_id: None,
         event_type: EventType::CliCommandStarted {
             command: "remember".to_string(),
             args: vec!["test".to_string()],
@@ -0,0 +1,260 @@
+//! Tests for memory export filtering and lossless export/import
+//!
+//! Exports are full `MemoryNote` records (as written by `mnemosyne export
+//! --format json|jsonl`); importing them into a fresh database must yield
+//! identical memories, links included.
+
+use mnemosyne_core::{
+    storage::{MemoryFilter, MemorySortOrder},
+    LibsqlStorage, LinkType, MemoryId, MemoryLink, MemoryNote, MemoryType, Namespace,
+    StorageBackend,
+};
+use std::collections::HashSet;
+
+mod common;
+use common::{create_test_storage, sample_memory};
+
+/// Store a small linked graph and return the full records as exported
+async fn seed_and_export(storage: &LibsqlStorage) -> Vec<MemoryNote> {
+    let target = sample_memory(
+        "Use libSQL for storage",
+        MemoryType::ArchitectureDecision,
+        9,
+    );
+
+    let mut source = sample_memory("Vector search via F32_BLOB", MemoryType::CodePattern, 7);
+    source.namespace = Namespace::Project {
+        name: "myapp".to_string(),
+    };
+    source.tags = vec!["storage".to_string(), "search".to_string()];
+    source.related_files = vec!["src/storage/libsql.rs".to_string()];
+    source.links = vec![MemoryLink {
+        target_id: target.id,
+        link_type: LinkType::Implements,
+        strength: 0.75,
+        reason: "Builds on the storage decision".to_string(),
+        created_at: chrono::Utc::now(),
+        last_traversed_at: None,
+        user_created: false,
+    }];
+
+    let mut expiring = sample_memory("Temporary workaround", MemoryType::BugFix, 4);
+    expiring.expires_at = Some(chrono::Utc::now() + chrono::Duration::days(30));
+
+    storage.store_memory(&target).await.unwrap();
+    storage.store_memory(&source).await.unwrap();
+    storage.store_memory(&expiring).await.unwrap();
+
+    // Same path as `mnemosyne export`: list, then load full records
+    let listed = storage
+        .list_memories(None, 10000, MemorySortOrder::Recent)
+        .await
+        .unwrap();
+    let mut exported = Vec::new();
+    for memory in listed {
+        exported.push(storage.get_memory(memory.id).await.unwrap());
+    }
+    exported
+}
+
+async fn assert_identical(original: &[MemoryNote], restored: &LibsqlStorage) {
+    assert_eq!(restored.count_memories(None).await.unwrap(), original.len());
+
+    for memory in original {
+        let imported = restored.get_memory(memory.id).await.unwrap();
+        assert_eq!(
+            serde_json::to_value(&imported).unwrap(),
+            serde_json::to_value(memory).unwrap(),
+            "memory {} changed on round trip",
+            memory.id
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_json_export_import_round_trip() {
+    let source = create_test_storage().await;
+    let exported = seed_and_export(&source).await;
+    assert!(exported.iter().any(|m| !m.links.is_empty()));
+
+    let json = serde_json::to_string_pretty(&exported).unwrap();
+    let parsed: Vec<MemoryNote> = serde_json::from_str(&json).unwrap();
+
+    let dest = create_test_storage().await;
+    dest.store_memories_atomic(&parsed).await.unwrap();
+
+    assert_identical(&exported, &dest).await;
+}
+
+#[tokio::test]
+async fn test_jsonl_export_import_round_trip() {
+    let source = create_test_storage().await;
+    let exported = seed_and_export(&source).await;
+
+    let jsonl: String = exported
+        .iter()
+        .map(|m| serde_json::to_string(m).unwrap() + "\n")
+        .collect();
+    let parsed: Vec<MemoryNote> = jsonl
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+
+    let dest = create_test_storage().await;
+    dest.store_memories_atomic(&parsed).await.unwrap();
+
+    assert_identical(&exported, &dest).await;
+}
+
+#[tokio::test]
+async fn test_import_is_atomic() {
+    let source = create_test_storage().await;
+    let exported = seed_and_export(&source).await;
+
+    let dest = create_test_storage().await;
+    let already_there = exported.iter().find(|m| m.links.is_empty()).unwrap();
+    dest.store_memory(already_there).await.unwrap();
+
+    // One duplicate ID aborts the whole batch
+    assert!(dest.store_memories_atomic(&exported).await.is_err());
+    assert_eq!(dest.count_memories(None).await.unwrap(), 1);
+}
+
+/// Seed memories that differ along every filterable dimension
+async fn seed_for_filters(storage: &LibsqlStorage) -> Vec<MemoryNote> {
+    let old = chrono::Utc::now() - chrono::Duration::days(60);
+
+    let mut decision_high = sample_memory("Adopt libSQL", MemoryType::ArchitectureDecision, 9);
+    decision_high.tags = vec!["storage".to_string(), "core".to_string()];
+
+    let mut decision_low = sample_memory("Prefer tabs", MemoryType::ArchitectureDecision, 5);
+    decision_low.tags = vec!["style".to_string()];
+
+    let mut decision_old = sample_memory("Use SQLite first", MemoryType::ArchitectureDecision, 8);
+    decision_old.tags = vec!["storage".to_string()];
+    decision_old.created_at = old;
+    decision_old.updated_at = old;
+
+    let mut bug = sample_memory("Fix FTS trigger", MemoryType::BugFix, 8);
+    bug.tags = vec!["storage".to_string(), "core".to_string()];
+
+    let memories = vec![decision_high, decision_low, decision_old, bug];
+    for memory in &memories {
+        storage.store_memory(memory).await.unwrap();
+    }
+    memories
+}
+
+async fn filtered_ids(storage: &LibsqlStorage, filter: MemoryFilter) -> HashSet<MemoryId> {
+    storage
+        .list_memories_filtered(&filter, 10000, MemorySortOrder::Recent)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|m| m.id)
+        .collect()
+}
+
+fn ids(memories: &[&MemoryNote]) -> HashSet<MemoryId> {
+    memories.iter().map(|m| m.id).collect()
+}
+
+#[tokio::test]
+async fn test_export_filters_narrow_individually() {
+    let storage = create_test_storage().await;
+    let seeded = seed_for_filters(&storage).await;
+    let [decision_high, decision_low, decision_old, bug] = [0, 1, 2, 3].map(|i| &seeded[i]);
+
+    let all = filtered_ids(&storage, MemoryFilter::default()).await;
+    assert_eq!(all, ids(&[decision_high, decision_low, decision_old, bug]));
+
+    let by_type = MemoryFilter {
+        memory_type: Some(MemoryType::ArchitectureDecision),
+        ..Default::default()
+    };
+    assert_eq!(
+        filtered_ids(&storage, by_type).await,
+        ids(&[decision_high, decision_low, decision_old])
+    );
+
+    let by_tag = MemoryFilter {
+        tags: vec!["storage".to_string()],
+        ..Default::default()
+    };
+    assert_eq!(
+        filtered_ids(&storage, by_tag).await,
+        ids(&[decision_high, decision_old, bug])
+    );
+
+    let by_importance = MemoryFilter {
+        min_importance: Some(8),
+        ..Default::default()
+    };
+    assert_eq!(
+        filtered_ids(&storage, by_importance).await,
+        ids(&[decision_high, decision_old, bug])
+    );
+
+    let by_since = MemoryFilter {
+        since: Some(chrono::Utc::now() - chrono::Duration::days(7)),
+        ..Default::default()
+    };
+    assert_eq!(
+        filtered_ids(&storage, by_since).await,
+        ids(&[decision_high, decision_low, bug])
+    );
+
+    let by_namespace = MemoryFilter {
+        namespace: Some(Namespace::Project {
+            name: "elsewhere".to_string(),
+        }),
+        ..Default::default()
+    };
+    assert!(filtered_ids(&storage, by_namespace).await.is_empty());
+}
+
+#[tokio::test]
+async fn test_export_filters_combine_with_and() {
+    let storage = create_test_storage().await;
+    let seeded = seed_for_filters(&storage).await;
+    let [decision_high, _, decision_old, bug] = [0, 1, 2, 3].map(|i| &seeded[i]);
+
+    // Architecture decisions above importance 7
+    let important_decisions = MemoryFilter {
+        memory_type: Some(MemoryType::ArchitectureDecision),
+        min_importance: Some(8),
+        ..Default::default()
+    };
+    assert_eq!(
+        filtered_ids(&storage, important_decisions.clone()).await,
+        ids(&[decision_high, decision_old])
+    );
+
+    // ...created recently
+    let recent_important_decisions = MemoryFilter {
+        since: Some(chrono::Utc::now() - chrono::Duration::days(7)),
+        ..important_decisions
+    };
+    assert_eq!(
+        filtered_ids(&storage, recent_important_decisions).await,
+        ids(&[decision_high])
+    );
+
+    // Multiple tags must all be present
+    let both_tags = MemoryFilter {
+        tags: vec!["storage".to_string(), "core".to_string()],
+        ..Default::default()
+    };
+    assert_eq!(
+        filtered_ids(&storage, both_tags).await,
+        ids(&[decision_high, bug])
+    );
+
+    // Tag and type together
+    let tagged_bugs = MemoryFilter {
+        memory_type: Some(MemoryType::BugFix),
+        tags: vec!["core".to_string()],
+        ..Default::default()
+    };
+    assert_eq!(filtered_ids(&storage, tagged_bugs).await, ids(&[bug]));
+}
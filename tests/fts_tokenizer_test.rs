@@ -0,0 +1,84 @@
+//! Configurable FTS5 tokenizer for keyword search
+
+use mnemosyne_core::{FtsTokenizer, LibsqlStorage, MemoryType, SearchConfig, StorageBackend};
+
+mod common;
+use common::{create_test_storage, sample_memory};
+
+fn use_tokenizer(storage: &mut LibsqlStorage, fts_tokenizer: FtsTokenizer) {
+    storage.set_search_config(SearchConfig {
+        fts_tokenizer,
+        ..SearchConfig::default()
+    });
+}
+
+async fn keyword_hits(storage: &LibsqlStorage, query: &str) -> usize {
+    storage.keyword_search(query, None).await.unwrap().len()
+}
+
+#[tokio::test]
+async fn test_stemming_tokenizer_matches_word_forms() {
+    let mut storage = create_test_storage().await;
+    storage
+        .store_memory(&sample_memory(
+            "Background jobs keep running after deploys",
+            MemoryType::Insight,
+            5,
+        ))
+        .await
+        .unwrap();
+
+    // Porter is the default: the index needs no rebuild
+    assert!(!storage.apply_fts_tokenizer().await.unwrap());
+    assert_eq!(keyword_hits(&storage, "run").await, 1);
+
+    use_tokenizer(
+        &mut storage,
+        FtsTokenizer::Unicode61 {
+            remove_diacritics: 2,
+            tokenchars: String::new(),
+            separators: String::new(),
+        },
+    );
+    assert!(storage.apply_fts_tokenizer().await.unwrap());
+    assert!(!storage.apply_fts_tokenizer().await.unwrap());
+
+    // Existing memories were re-indexed, without stemming
+    assert_eq!(keyword_hits(&storage, "run").await, 0);
+    assert_eq!(keyword_hits(&storage, "running").await, 1);
+}
+
+#[tokio::test]
+async fn test_trigram_tokenizer_matches_substrings() {
+    let mut storage = create_test_storage().await;
+    storage
+        .store_memory(&sample_memory(
+            "Authentication middleware validates tokens",
+            MemoryType::CodePattern,
+            6,
+        ))
+        .await
+        .unwrap();
+    assert_eq!(keyword_hits(&storage, "thentic").await, 0);
+
+    use_tokenizer(
+        &mut storage,
+        FtsTokenizer::Trigram {
+            case_sensitive: false,
+        },
+    );
+    assert!(storage.apply_fts_tokenizer().await.unwrap());
+    assert_eq!(keyword_hits(&storage, "thentic").await, 1);
+
+    // New writes are indexed with the same tokenizer
+    storage
+        .store_memory(&sample_memory(
+            "Use 認証トークン for service calls",
+            MemoryType::Insight,
+            5,
+        ))
+        .await
+        .unwrap();
+    assert_eq!(keyword_hits(&storage, "ddlewa").await, 1);
+    assert_eq!(keyword_hits(&storage, "認証ト").await, 1);
+}
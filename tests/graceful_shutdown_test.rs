@@ -0,0 +1,88 @@
+//! SIGTERM during active work drains the orchestration engine
+//!
+//! Single test: it signals its own process, which is only safe once the
+//! handler is installed and must not race other tests' handlers.
+
+use mnemosyne_core::{
+    launcher::{
+        agents::AgentRole,
+        shutdown::{ShutdownSignal, SHUTDOWN_GRACE_PERIOD},
+    },
+    orchestration::{
+        state::{Phase, WorkItem},
+        AgentEvent, EventReplay, OrchestrationEngine, OrchestratorMessage, SupervisionConfig,
+    },
+    types::Namespace,
+    ConnectionMode, LibsqlStorage,
+};
+use nix::sys::signal::{kill, Signal};
+use nix::unistd::Pid;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tempfile::TempDir;
+
+#[tokio::test]
+async fn test_sigterm_during_work_drains_supervision_tree() {
+    let temp_dir = TempDir::new().unwrap();
+    let db_path = temp_dir.path().join("shutdown.db");
+    let storage = Arc::new(
+        LibsqlStorage::new_with_validation(
+            ConnectionMode::Local(db_path.to_str().unwrap().to_string()),
+            true,
+        )
+        .await
+        .unwrap(),
+    );
+    let namespace = Namespace::Session {
+        project: "test".to_string(),
+        session_id: format!("shutdown-{}", uuid::Uuid::new_v4()),
+    };
+
+    let mut shutdown = ShutdownSignal::install().unwrap();
+    let mut engine = OrchestrationEngine::new_with_namespace(
+        storage.clone(),
+        SupervisionConfig::default(),
+        namespace.clone(),
+    )
+    .await
+    .unwrap();
+    engine.start().await.unwrap();
+
+    // Queue work without waiting for the orchestrator to pick it up
+    for i in 0..5 {
+        let item = WorkItem::new(
+            format!("Queued work {}", i),
+            AgentRole::Executor,
+            Phase::PromptToSpec,
+            5,
+        );
+        engine
+            .orchestrator()
+            .cast(OrchestratorMessage::SubmitWork(Box::new(item)))
+            .unwrap();
+    }
+
+    kill(Pid::this(), Signal::SIGTERM).unwrap();
+    tokio::time::timeout(Duration::from_secs(5), shutdown.recv())
+        .await
+        .expect("SIGTERM should reach the handler");
+
+    let started = Instant::now();
+    engine
+        .stop_with_timeout(SHUTDOWN_GRACE_PERIOD)
+        .await
+        .unwrap();
+    assert!(started.elapsed() < SHUTDOWN_GRACE_PERIOD);
+    assert!(!engine.has_running_actors());
+
+    // Every queued submission was processed (and persisted) before exit
+    let events = EventReplay::new(storage, namespace)
+        .load_events()
+        .await
+        .unwrap();
+    let assigned = events
+        .iter()
+        .filter(|event| matches!(event, AgentEvent::WorkItemAssigned { .. }))
+        .count();
+    assert_eq!(assigned, 5);
+}
@@ -0,0 +1,90 @@
+//! Graph traversal node budget over a densely linked namespace
+
+use mnemosyne_core::{
+    LibsqlStorage, LinkType, MemoryId, MemoryLink, MemoryNote, MemoryType, SearchConfig,
+    StorageBackend,
+};
+
+mod common;
+use common::{create_test_storage, sample_memory};
+
+/// Hub linked to one leaf per `(strength, importance)`; returns the hub and
+/// the leaves in the same order
+async fn star(storage: &LibsqlStorage, leaves: &[(f32, u8)]) -> (MemoryNote, Vec<MemoryNote>) {
+    let mut hub = sample_memory(
+        "Hub: service architecture",
+        MemoryType::ArchitectureDecision,
+        8,
+    );
+    let mut spokes = Vec::new();
+    for (i, (strength, importance)) in leaves.iter().enumerate() {
+        let leaf = sample_memory(
+            &format!("Leaf {} with strength {}", i, strength),
+            MemoryType::Insight,
+            *importance,
+        );
+        storage.store_memory(&leaf).await.unwrap();
+        hub.links.push(MemoryLink {
+            target_id: leaf.id,
+            link_type: LinkType::References,
+            strength: *strength,
+            reason: "Part of the architecture".to_string(),
+            created_at: chrono::Utc::now(),
+            last_traversed_at: None,
+            user_created: false,
+        });
+        spokes.push(leaf);
+    }
+    storage.store_memory(&hub).await.unwrap();
+    (hub, spokes)
+}
+
+fn ids(memories: &[MemoryNote]) -> Vec<MemoryId> {
+    memories.iter().map(|m| m.id).collect()
+}
+
+const LEAVES: [(f32, u8); 8] = [
+    (0.1, 5),
+    (0.7, 3),
+    (1.0, 5),
+    (0.3, 5),
+    (0.9, 5),
+    (0.7, 9),
+    (0.5, 5),
+    (0.2, 5),
+];
+
+#[tokio::test]
+async fn test_traversal_stops_at_node_budget_strongest_first() {
+    let mut storage = create_test_storage().await;
+    storage.set_search_config(SearchConfig {
+        max_graph_nodes: 4,
+        ..SearchConfig::default()
+    });
+    let (hub, leaves) = star(&storage, &LEAVES).await;
+
+    let reached = storage.graph_traverse(&[hub.id], 2, None).await.unwrap();
+
+    // The hub, then strengths 1.0 and 0.9, then the more important 0.7 leaf
+    assert_eq!(
+        ids(&reached),
+        vec![hub.id, leaves[2].id, leaves[4].id, leaves[5].id]
+    );
+}
+
+#[tokio::test]
+async fn test_traversal_within_budget_reaches_every_leaf() {
+    let storage = create_test_storage().await;
+    let (hub, leaves) = star(&storage, &LEAVES).await;
+
+    let reached = storage.graph_traverse(&[hub.id], 2, None).await.unwrap();
+    assert_eq!(reached.len(), leaves.len() + 1);
+    assert_eq!(reached[0].id, hub.id);
+
+    // Links are followed in both directions: one hop from a leaf is the hub
+    let reached = storage
+        .graph_traverse(&[leaves[0].id], 1, None)
+        .await
+        .unwrap();
+    assert_eq!(ids(&reached), vec![leaves[0].id, hub.id]);
+}
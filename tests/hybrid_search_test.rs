@@ -6,7 +6,9 @@
 //! - Weighted ranking (keyword, graph, importance, recency)
 //! - Recency decay
 
-use mnemosyne_core::{LinkType, MemoryLink, MemoryType, Namespace, StorageBackend};
+use mnemosyne_core::{
+    LinkType, MemoryLink, MemoryType, Namespace, SearchQuery, StorageBackend, TieBreak,
+};
 
 mod common;
 use common::{create_test_storage, sample_memory};
@@ -368,3 +370,164 @@ async fn test_hybrid_scoring_components() {
         result.match_reason
     );
 }
+
+#[tokio::test]
+async fn test_score_breakdown_explains_fused_score() {
+    let mut storage = create_test_storage().await;
+    let test_data = TestData::load();
+    for memory in test_data.all() {
+        storage.store_memory(&memory).await.unwrap();
+    }
+
+    // Off by default
+    let results = storage
+        .hybrid_search("database", None, 10, true)
+        .await
+        .unwrap();
+    assert!(!results.is_empty());
+    assert!(results.iter().all(|r| r.score_breakdown.is_none()));
+
+    storage.set_explain_scores(true);
+    let results = storage
+        .hybrid_search("database", None, 10, true)
+        .await
+        .unwrap();
+    for result in &results {
+        let breakdown = result
+            .score_breakdown
+            .expect("breakdown should be set when explaining");
+        assert_eq!(breakdown.fused, result.score);
+        assert!(breakdown.keyword > 0.0 || breakdown.graph > 0.0);
+        assert!((0.0..=1.0).contains(&breakdown.importance));
+        assert!((0.0..=1.0).contains(&breakdown.recency));
+    }
+    // Keyword hits are reported as such
+    assert!(results
+        .iter()
+        .any(|r| r.score_breakdown.unwrap().keyword == 1.0));
+}
+
+#[tokio::test]
+async fn test_importance_boost_breaks_ties() {
+    use chrono::Duration;
+
+    let storage = create_test_storage().await;
+
+    // Equal keyword similarity; the trivial memory is newer, so recency
+    // favors it when importance is not boosted
+    let mut decision = sample_memory(
+        "Deploy pipeline decision",
+        MemoryType::ArchitectureDecision,
+        6,
+    );
+    decision.keywords = vec!["deploy".to_string()];
+    decision.created_at = chrono::Utc::now() - Duration::days(60);
+
+    let mut trivia = sample_memory("Deploy pipeline trivia", MemoryType::Insight, 5);
+    trivia.keywords = vec!["deploy".to_string()];
+
+    storage.store_memory(&decision).await.unwrap();
+    storage.store_memory(&trivia).await.unwrap();
+
+    let query = SearchQuery {
+        query: "deploy".to_string(),
+        ..Default::default()
+    };
+    let unboosted = storage.search(&query).await.unwrap();
+    assert_eq!(unboosted.len(), 2);
+    assert_eq!(unboosted[0].memory.id, trivia.id);
+
+    let boosted = storage
+        .search(&SearchQuery {
+            importance_boost: 1.0,
+            ..query
+        })
+        .await
+        .unwrap();
+    assert_eq!(boosted.len(), 2);
+    assert_eq!(boosted[0].memory.id, decision.id);
+    let expected_gap = unboosted[1].score - unboosted[0].score + 1.0 * (6.0 - 5.0) / 10.0;
+    assert!((boosted[0].score - boosted[1].score - expected_gap).abs() < 1e-4);
+}
+
+#[tokio::test]
+async fn test_tied_results_order_deterministically() {
+    let storage = create_test_storage().await;
+
+    // Identical scores: same content, importance, and creation time
+    let created_at = chrono::Utc::now();
+    let mut tied = Vec::new();
+    for _ in 0..5 {
+        let mut memory = sample_memory("Tied cache entry", MemoryType::Insight, 5);
+        memory.created_at = created_at;
+        storage.store_memory(&memory).await.unwrap();
+        tied.push(memory.id);
+    }
+    // Accessed memory is last by ID
+    tied.sort_by_key(|id| id.0);
+    let accessed = *tied.last().unwrap();
+    storage.increment_access(accessed).await.unwrap();
+
+    let query = SearchQuery {
+        query: "cache".to_string(),
+        ..Default::default()
+    };
+    let ids = |results: Vec<mnemosyne_core::SearchResult>| {
+        results.into_iter().map(|r| r.memory.id).collect::<Vec<_>>()
+    };
+
+    // Remaining ties fall back to memory ID, on every run
+    for _ in 0..5 {
+        assert_eq!(ids(storage.search(&query).await.unwrap()), tied);
+        let plain = storage
+            .hybrid_search("cache", None, 10, true)
+            .await
+            .unwrap();
+        assert_eq!(ids(plain), tied);
+    }
+
+    let by_access = storage
+        .search(&SearchQuery {
+            tie_breaks: vec![TieBreak::AccessCount, TieBreak::Recency],
+            ..query
+        })
+        .await
+        .unwrap();
+    let by_access = ids(by_access);
+    assert_eq!(by_access[0], accessed);
+    assert_eq!(&by_access[1..], &tied[..4]);
+}
+
+#[tokio::test]
+async fn test_memories_found_by_several_signals_appear_once() {
+    let storage = create_test_storage().await;
+    let test_data = TestData::load();
+    let database = &test_data.database_memories;
+    for memory in database {
+        storage.store_memory(memory).await.unwrap();
+    }
+
+    // Link every keyword match to the next, so graph expansion reaches
+    // memories the keyword search already found
+    for pair in database.windows(2) {
+        let mut linked = pair[0].clone();
+        linked.links = vec![MemoryLink {
+            target_id: pair[1].id,
+            link_type: LinkType::References,
+            strength: 0.8,
+            reason: "related database note".to_string(),
+            created_at: chrono::Utc::now(),
+            last_traversed_at: None,
+            user_created: false,
+        }];
+        storage.update_memory(&linked).await.unwrap();
+    }
+
+    let results = storage
+        .hybrid_search("database", None, 20, true)
+        .await
+        .unwrap();
+    let ids: std::collections::HashSet<_> = results.iter().map(|r| r.memory.id).collect();
+    assert_eq!(ids.len(), results.len(), "duplicate memory in results");
+    assert!(results.len() >= database.len());
+}
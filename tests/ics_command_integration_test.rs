@@ -4,7 +4,8 @@
 //! for the ICS integration.
 
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output, Stdio};
 use tempfile::TempDir;
 
 /// Get path to the mnemosyne binary
@@ -16,6 +17,50 @@ fn mnemosyne_bin() -> PathBuf {
     path
 }
 
+/// Run `mnemosyne edit <file> --session-context <intent>` without a terminal
+fn edit_with_handoff(temp_dir: &Path, file: &Path, intent: serde_json::Value) -> Output {
+    let session_dir = temp_dir.join(".claude/sessions");
+    fs::create_dir_all(&session_dir).unwrap();
+    let intent_path = session_dir.join("edit-intent.json");
+    fs::write(&intent_path, intent.to_string()).unwrap();
+
+    Command::new(mnemosyne_bin())
+        .arg("--db-path")
+        .arg(temp_dir.join("test.db"))
+        .arg("edit")
+        .arg(file)
+        .arg("--session-context")
+        .arg(&intent_path)
+        .current_dir(temp_dir)
+        .stdin(Stdio::null())
+        .output()
+        .unwrap()
+}
+
+fn handoff_result(temp_dir: &Path) -> serde_json::Value {
+    let json = fs::read_to_string(temp_dir.join(".claude/sessions/edit-result.json"))
+        .expect("edit should write a handoff result");
+    serde_json::from_str(&json).unwrap()
+}
+
+fn intent_json(session_id: &str, file: &Path, timestamp: &str) -> serde_json::Value {
+    serde_json::json!({
+        "session_id": session_id,
+        "timestamp": timestamp,
+        "action": "edit",
+        "file_path": file,
+        "template": null,
+        "readonly": false,
+        "panel": null,
+        "context": {
+            "conversation_summary": "",
+            "relevant_memories": [],
+            "related_files": []
+        },
+        "writer_pid": std::process::id()
+    })
+}
+
 #[test]
 fn test_edit_creates_empty_file_with_default_content() {
     let temp_dir = TempDir::new().unwrap();
@@ -264,3 +309,43 @@ fn test_multiple_templates_distinct() {
     assert_ne!(api, feat);
     assert_ne!(api, refac);
 }
+
+#[test]
+fn test_edit_answers_handoff_when_editor_fails() {
+    let temp_dir = TempDir::new().unwrap();
+    let file = temp_dir.path().join("context.md");
+    let intent = intent_json("handoff-fresh", &file, &chrono::Utc::now().to_rfc3339());
+
+    // Without a TTY the editor can't start; the waiting session still hears back
+    let output = edit_with_handoff(temp_dir.path(), &file, intent);
+    assert!(!output.status.success());
+
+    let result = handoff_result(temp_dir.path());
+    assert_eq!(result["session_id"], "handoff-fresh");
+    assert_eq!(result["status"], "error");
+    assert_eq!(result["exit_reason"], "error");
+    assert_eq!(result["changes_made"], false);
+
+    // Written through a temp file that is renamed into place
+    let mut leftovers: Vec<_> = fs::read_dir(temp_dir.path().join(".claude/sessions"))
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+        .collect();
+    leftovers.sort();
+    assert_eq!(leftovers, ["edit-intent.json", "edit-result.json"]);
+}
+
+#[test]
+fn test_edit_answers_stale_handoff_without_opening() {
+    let temp_dir = TempDir::new().unwrap();
+    let file = temp_dir.path().join("context.md");
+    let intent = intent_json("handoff-stale", &file, "2025-11-04T20:00:00Z");
+
+    let output = edit_with_handoff(temp_dir.path(), &file, intent);
+    assert!(output.status.success());
+    assert!(!file.exists(), "stale intent must not open the editor");
+
+    let result = handoff_result(temp_dir.path());
+    assert_eq!(result["session_id"], "handoff-stale");
+    assert_eq!(result["exit_reason"], "timed_out");
+}
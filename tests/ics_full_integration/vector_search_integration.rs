@@ -4,7 +4,10 @@
 
 use crate::ics_full_integration::*;
 use mnemosyne_core::{
-    embeddings::{cosine_similarity, EmbeddingService},
+    embeddings::{
+        cosine_similarity, DeterministicEmbeddingService, EmbeddingService,
+        DETERMINISTIC_EMBEDDING_DIM,
+    },
     storage::StorageBackend,
     types::{MemoryType, Namespace},
 };
@@ -14,8 +17,8 @@ use mnemosyne_core::{
 async fn v1_embedding_generation() {
     let storage = StorageFixture::new().await.expect("Storage setup failed");
 
-    // Create mock embedding service (no model download required)
-    let embedding_service = MockEmbeddingService::new_standard();
+    // Deterministic embedding service (no model download required)
+    let embedding_service = DeterministicEmbeddingService::new(DETERMINISTIC_EMBEDDING_DIM);
 
     // Create memory
     let content = "Authentication system uses JWT tokens with 1-hour expiration";
@@ -63,8 +66,8 @@ async fn v1_embedding_generation() {
 async fn v2_semantic_search() {
     let storage = StorageFixture::new().await.expect("Storage setup failed");
 
-    // Create mock embedding service
-    let embedding_service = MockEmbeddingService::new_standard();
+    // Create deterministic embedding service
+    let embedding_service = DeterministicEmbeddingService::new(DETERMINISTIC_EMBEDDING_DIM);
 
     // Create semantically related memories
     let memories_content = vec![
@@ -172,8 +175,8 @@ async fn v3_hybrid_search() {
 async fn v4_semantic_relevance_ranking() {
     let storage = StorageFixture::new().await.expect("Storage setup failed");
 
-    // Create mock embedding service
-    let embedding_service = MockEmbeddingService::new_standard();
+    // Create deterministic embedding service
+    let embedding_service = DeterministicEmbeddingService::new(DETERMINISTIC_EMBEDDING_DIM);
 
     // Create memories with varying semantic similarity to query
     let memories = vec![
@@ -247,8 +250,8 @@ async fn v4_semantic_relevance_ranking() {
 /// V5: Embedding model consistency
 #[tokio::test]
 async fn v5_embedding_model_consistency() {
-    // Create mock embedding service
-    let embedding_service = MockEmbeddingService::new_standard();
+    // Create deterministic embedding service
+    let embedding_service = DeterministicEmbeddingService::new(DETERMINISTIC_EMBEDDING_DIM);
 
     let text = "Authentication system implementation";
 
@@ -291,8 +294,8 @@ async fn v6_large_scale_vector_search() {
 
     let storage = StorageFixture::new().await.expect("Storage setup failed");
 
-    // Create mock embedding service (fast, no model download)
-    let embedding_service = MockEmbeddingService::new_standard();
+    // Create deterministic embedding service (fast, no model download)
+    let embedding_service = DeterministicEmbeddingService::new(DETERMINISTIC_EMBEDDING_DIM);
 
     // Create 100 memories with embeddings (reduced from 1000 for test speed)
     let start = Instant::now();
@@ -353,8 +356,8 @@ async fn v6_large_scale_vector_search() {
 async fn v7_namespace_filtering() {
     let storage = StorageFixture::new().await.expect("Storage setup failed");
 
-    // Create mock embedding service
-    let embedding_service = MockEmbeddingService::new_standard();
+    // Create deterministic embedding service
+    let embedding_service = DeterministicEmbeddingService::new(DETERMINISTIC_EMBEDDING_DIM);
 
     // Create memories in different namespaces
     let namespaces = vec![
@@ -443,8 +446,8 @@ async fn v7_namespace_filtering() {
 async fn v8_incremental_embedding_updates() {
     let storage = StorageFixture::new().await.expect("Storage setup failed");
 
-    // Create mock embedding service
-    let embedding_service = MockEmbeddingService::new_standard();
+    // Create deterministic embedding service
+    let embedding_service = DeterministicEmbeddingService::new(DETERMINISTIC_EMBEDDING_DIM);
 
     // Create memory without embedding
     let mut memory = create_test_memory(
@@ -473,6 +473,60 @@ fn test_editor_multiple_buffers() {
         .contains("Buffer 3"));
 }
 
+/// Test closing the active buffer activates its neighbour
+#[test]
+fn test_editor_close_active_buffer() {
+    let mut editor = IcsEditor::new();
+    let first = editor.new_buffer(None);
+    let second = editor.new_buffer(None);
+
+    editor.set_active_buffer(second);
+    editor
+        .close_buffer(second, false)
+        .expect("Should close clean buffer");
+    assert!(editor.buffer(second).is_none());
+    assert_eq!(editor.active_buffer().id, first);
+
+    // Closing an inactive buffer leaves the active one alone
+    let third = editor.new_buffer(None);
+    editor.close_buffer(third, false).expect("Should close");
+    assert_eq!(editor.active_buffer().id, first);
+
+    assert!(editor.close_buffer(third, false).is_err());
+}
+
+/// Test the last buffer can't be closed
+#[test]
+fn test_editor_close_last_buffer_refused() {
+    let mut editor = IcsEditor::new();
+    assert!(editor.close_buffer(0, true).is_err());
+    assert_eq!(editor.active_buffer().id, 0);
+
+    // Buffer 0 stays even once other buffers exist
+    let other = editor.new_buffer(None);
+    assert!(editor.close_buffer(0, true).is_err());
+    editor.close_buffer(other, false).expect("Should close");
+    assert_eq!(editor.active_buffer().id, 0);
+}
+
+/// Test dirty buffers need force to close
+#[test]
+fn test_editor_close_dirty_buffer() {
+    let mut editor = IcsEditor::new();
+    let id = editor.new_buffer(None);
+    editor
+        .buffer_mut(id)
+        .unwrap()
+        .insert(0, "unsaved")
+        .expect("Should insert");
+
+    assert!(editor.close_buffer(id, false).is_err());
+    assert!(editor.buffer(id).is_some());
+
+    editor.close_buffer(id, true).expect("Force should close");
+    assert!(editor.buffer(id).is_none());
+}
+
 /// Test end-to-end workflow: edit → analyze → proposals → diagnostics
 #[tokio::test]
 async fn test_full_ics_workflow() {
@@ -234,6 +234,57 @@ fn test_highlight_cached_full_text() {
     );
 }
 
+#[test]
+fn test_highlight_cached_reanalyzes_only_edited_paragraph() {
+    let cache = Arc::new(SemanticCache::new(100, 60));
+    let mut analyzer = RelationalAnalyzer::new(RelationalSettings::default(), cache);
+
+    let paragraphs: Vec<String> = (0..20)
+        .map(|i| {
+            format!(
+                "Dr. Alice reviewed section {:02}.\nShe approved the change.",
+                i
+            )
+        })
+        .collect();
+    let text = paragraphs.join("\n\n");
+
+    let spans = analyzer.highlight_cached(&text).expect("Should succeed");
+    assert_eq!(analyzer.regions_analyzed(), 20);
+
+    // Spans are in document offsets, not paragraph offsets
+    let last_start = text.rfind("Dr. Alice").unwrap();
+    assert!(spans
+        .iter()
+        .any(|s| s.range.start >= last_start && text[s.range.clone()].contains("Alice")));
+
+    // Edit one line in the middle of the document
+    let line_start = text.find("section 07").unwrap();
+    let edited = text.replacen("section 07", "section 7b", 1);
+    analyzer.schedule_analysis(&edited, line_start..line_start + 10);
+
+    let edited_spans = analyzer.highlight_cached(&edited).expect("Should succeed");
+    assert_eq!(analyzer.regions_analyzed(), 21, "Only the edited paragraph");
+    assert_eq!(edited_spans.len(), spans.len());
+
+    // Nothing dirty or changed: everything comes from the cache
+    analyzer.highlight_cached(&edited).expect("Should succeed");
+    assert_eq!(analyzer.regions_analyzed(), 21);
+
+    // Lengthening the first paragraph shifts every later one, which still
+    // reuses its result
+    let lengthened = edited.replacen("section 00", "section 00 and its appendix", 1);
+    analyzer.schedule_analysis(&lengthened, 0..30);
+    let lengthened_spans = analyzer
+        .highlight_cached(&lengthened)
+        .expect("Should succeed");
+    assert_eq!(analyzer.regions_analyzed(), 22, "Only the first paragraph");
+    let last_start = lengthened.rfind("Dr. Alice").unwrap();
+    assert!(lengthened_spans
+        .iter()
+        .any(|s| s.range.start >= last_start && lengthened[s.range.clone()].contains("Alice")));
+}
+
 #[test]
 fn test_entity_type_all_variants_serializable() {
     let variants = vec![
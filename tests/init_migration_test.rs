@@ -0,0 +1,120 @@
+//! Idempotent `init`: schema version tracking and pending migrations
+
+use mnemosyne_core::storage::migrations::MigrationRegistry;
+use mnemosyne_core::{ConnectionMode, LibsqlStorage};
+use tempfile::TempDir;
+
+async fn open(path: &str) -> LibsqlStorage {
+    LibsqlStorage::new_with_validation(ConnectionMode::Local(path.to_string()), true)
+        .await
+        .unwrap()
+}
+
+/// Open without running migrations, as `init --check` does
+async fn open_unmigrated(path: &str) -> LibsqlStorage {
+    LibsqlStorage::new(ConnectionMode::LocalReadOnly(path.to_string()))
+        .await
+        .unwrap()
+}
+
+async fn raw_connection(path: &str) -> libsql::Connection {
+    libsql::Builder::new_local(path)
+        .build()
+        .await
+        .unwrap()
+        .connect()
+        .unwrap()
+}
+
+async fn schema_updated_at(path: &str) -> i64 {
+    let conn = raw_connection(path).await;
+    let mut rows = conn
+        .query("SELECT updated_at FROM _schema_version WHERE id = 1", ())
+        .await
+        .unwrap();
+    rows.next().await.unwrap().unwrap().get(0).unwrap()
+}
+
+#[tokio::test]
+async fn test_fresh_init_records_schema_version() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("fresh.db");
+    let path = path.to_str().unwrap();
+
+    let storage = open(path).await;
+    let status = storage.migration_status().await.unwrap();
+
+    assert!(status.pending.is_empty());
+    assert_eq!(
+        status.target_version,
+        MigrationRegistry::libsql().latest_version()
+    );
+    assert_eq!(status.schema_version, Some(status.target_version));
+    assert!(status.is_current());
+}
+
+#[tokio::test]
+async fn test_reinit_is_noop() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("reinit.db");
+    let path = path.to_str().unwrap();
+
+    let storage = open(path).await;
+    let applied = storage.get_applied_migrations().await.unwrap();
+    let updated_at = schema_updated_at(path).await;
+    drop(storage);
+
+    // Updates within the same second would be indistinguishable
+    tokio::time::sleep(std::time::Duration::from_millis(1100)).await;
+
+    let storage = open(path).await;
+    assert_eq!(storage.get_applied_migrations().await.unwrap(), applied);
+    assert!(storage.migration_status().await.unwrap().is_current());
+    assert_eq!(schema_updated_at(path).await, updated_at);
+}
+
+#[tokio::test]
+async fn test_init_migrates_older_schema() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("older.db");
+    let path = path.to_str().unwrap();
+    drop(open(path).await);
+
+    // Roll the fixture back to a database migrated before the version-check
+    // cache and before schema versions were recorded
+    let conn = raw_connection(path).await;
+    conn.execute_batch(
+        "DROP TABLE version_check_cache;
+         DELETE FROM migrations WHERE version = 15;
+         DROP TABLE _schema_version;",
+    )
+    .await
+    .unwrap();
+    drop(conn);
+
+    // --check reports without applying
+    let status = open_unmigrated(path)
+        .await
+        .migration_status()
+        .await
+        .unwrap();
+    assert_eq!(status.schema_version, None);
+    assert_eq!(
+        status.pending,
+        vec!["015_version_check_cache.sql".to_string()]
+    );
+    assert!(!status.is_current());
+
+    let storage = open(path).await;
+    let status = storage.migration_status().await.unwrap();
+    assert!(status.is_current());
+    assert_eq!(
+        status.schema_version,
+        Some(MigrationRegistry::libsql().latest_version())
+    );
+    assert!(storage
+        .get_applied_migrations()
+        .await
+        .unwrap()
+        .contains(&"015_version_check_cache.sql".to_string()));
+}
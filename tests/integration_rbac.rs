@@ -4,6 +4,7 @@
 
 use mnemosyne_core::agents::{
     AgentMemoryView, AgentRole, MemoryAccessControl, MemoryMetadata, MemoryUpdates,
+    ModificationType, WriteAction,
 };
 use mnemosyne_core::storage::libsql::LibsqlStorage;
 use mnemosyne_core::types::{MemoryType, Namespace};
@@ -227,6 +228,7 @@ async fn test_custom_visibility_override() {
 }
 
 #[tokio::test]
+#[serial_test::serial]
 async fn test_admin_mode_with_env_var() {
     // Save original state
     let original = std::env::var("MNEMOSYNE_ADMIN_MODE").ok();
@@ -247,6 +249,7 @@ async fn test_admin_mode_with_env_var() {
 }
 
 #[tokio::test]
+#[serial_test::serial]
 async fn test_admin_mode_with_human_user() {
     // Save original state
     let original = std::env::var("MNEMOSYNE_USER").ok();
@@ -554,3 +557,87 @@ async fn test_different_namespaces() {
     // Both should be created successfully
     assert_ne!(global_id, project_id);
 }
+
+#[tokio::test]
+#[serial_test::serial] // Admin mode tests toggle env vars that bypass the checks
+async fn test_reviewer_can_annotate_but_not_delete_executor_memory() {
+    let fixture = create_test_storage().await;
+    let executor = AgentMemoryView::new(AgentRole::Executor, Arc::clone(&fixture.storage));
+    let reviewer = AgentMemoryView::new(AgentRole::Reviewer, Arc::clone(&fixture.storage));
+
+    let memory_id = MemoryAccessControl::new(AgentRole::Executor, Arc::clone(&fixture.storage))
+        .create_memory("Executor-owned pattern", create_test_metadata())
+        .await
+        .expect("Failed to create memory");
+
+    // Annotating is allowed
+    reviewer
+        .update(
+            memory_id,
+            MemoryUpdates {
+                tags: Some(vec!["reviewed".to_string()]),
+                ..Default::default()
+            },
+        )
+        .await
+        .expect("Reviewer should be able to annotate");
+
+    // Editing and deleting are not
+    let edit = reviewer
+        .update(
+            memory_id,
+            MemoryUpdates {
+                content: Some("Rewritten by reviewer".to_string()),
+                ..Default::default()
+            },
+        )
+        .await;
+    assert!(edit.is_err(), "Reviewer should not edit executor content");
+    let delete = reviewer.delete(memory_id).await;
+    assert!(
+        delete.is_err(),
+        "Reviewer should not delete executor memory"
+    );
+    assert!(!reviewer
+        .can_perform(memory_id, WriteAction::Archive)
+        .await
+        .unwrap());
+
+    let memory = fixture.storage.get_memory(memory_id).await.unwrap();
+    assert_eq!(memory.tags, vec!["reviewed".to_string()]);
+    assert_eq!(memory.content, "Executor-owned pattern");
+    assert!(!memory.is_archived);
+
+    // The owner keeps full control
+    executor
+        .delete(memory_id)
+        .await
+        .expect("Owner should be able to delete");
+
+    // Every attempt is audited: create, annotate, denied edit, denied delete, delete
+    let trail = MemoryAccessControl::new(AgentRole::Executor, Arc::clone(&fixture.storage))
+        .get_audit_trail(&memory_id)
+        .await
+        .unwrap();
+    assert_eq!(trail.len(), 5);
+    let denied: Vec<_> = trail
+        .iter()
+        .filter(|log| log.modification_type == ModificationType::Denied)
+        .collect();
+    assert_eq!(denied.len(), 2);
+    assert!(denied
+        .iter()
+        .all(|log| log.agent_role == AgentRole::Reviewer));
+    assert!(denied.iter().any(|log| log
+        .changes
+        .as_deref()
+        .is_some_and(|c| c.contains("\"action\":\"delete\""))));
+
+    // Denials never read as real modifications
+    let deletes: Vec<_> = trail
+        .iter()
+        .filter(|log| log.modification_type == ModificationType::Delete)
+        .collect();
+    assert_eq!(deletes.len(), 1);
+    assert_eq!(deletes[0].agent_role, AgentRole::Executor);
+}
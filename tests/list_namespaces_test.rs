@@ -0,0 +1,86 @@
+//! Namespace listing through storage and the `mnemosyne.list_namespaces` MCP tool
+
+use mnemosyne_core::mcp::ToolHandler;
+use mnemosyne_core::services::embeddings::EmbeddingService;
+use mnemosyne_core::storage::NamespaceCount;
+use mnemosyne_core::{LlmConfig, LlmService, MemoryType, Namespace, StorageBackend};
+use std::sync::Arc;
+
+mod common;
+use common::{create_test_storage, sample_memory};
+
+fn project(name: &str) -> Namespace {
+    Namespace::Project {
+        name: name.to_string(),
+    }
+}
+
+async fn seeded_storage() -> Arc<dyn StorageBackend> {
+    let storage = create_test_storage().await;
+    let seeds = [
+        (Namespace::Global, 1),
+        (project("alpha"), 3),
+        (project("beta"), 2),
+    ];
+    for (namespace, count) in seeds {
+        for i in 0..count {
+            let mut memory =
+                sample_memory(&format!("{} note {}", namespace, i), MemoryType::Insight, 5);
+            memory.namespace = namespace.clone();
+            storage.store_memory(&memory).await.unwrap();
+        }
+    }
+
+    // Archived memories are not counted
+    let mut archived = sample_memory("Retired note", MemoryType::Insight, 5);
+    archived.namespace = project("gamma");
+    storage.store_memory(&archived).await.unwrap();
+    storage.archive_memory(archived.id).await.unwrap();
+
+    Arc::new(storage)
+}
+
+#[tokio::test]
+async fn test_list_namespaces_counts_active_memories() {
+    let storage = seeded_storage().await;
+
+    let namespaces = storage.list_namespaces().await.unwrap();
+    assert_eq!(
+        namespaces,
+        vec![
+            NamespaceCount {
+                namespace: project("alpha"),
+                count: 3
+            },
+            NamespaceCount {
+                namespace: project("beta"),
+                count: 2
+            },
+            NamespaceCount {
+                namespace: Namespace::Global,
+                count: 1
+            },
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_list_namespaces_tool() {
+    let storage = seeded_storage().await;
+    let llm_config = LlmConfig::default();
+    let llm = Arc::new(LlmService::new(llm_config.clone()).unwrap());
+    let embeddings = Arc::new(EmbeddingService::new("test-key".to_string(), llm_config));
+    let handler = ToolHandler::new(storage, llm, embeddings);
+
+    let tools = handler.list_tools();
+    assert!(tools.iter().any(|t| t.name == "mnemosyne.list_namespaces"));
+
+    let result = handler
+        .execute("mnemosyne.list_namespaces", serde_json::json!({}))
+        .await
+        .unwrap();
+    assert_eq!(result["count"], 3);
+    assert_eq!(result["namespaces"][0]["namespace"], "project:alpha");
+    assert_eq!(result["namespaces"][0]["count"], 3);
+    assert_eq!(result["namespaces"][2]["namespace"], "global");
+}
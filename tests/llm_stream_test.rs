@@ -0,0 +1,129 @@
+//! Streaming LLM completions against a mock Messages API
+
+use mnemosyne_core::{LlmConfig, LlmService, MnemosyneError};
+use std::time::Duration;
+use tokio::io::AsyncWriteExt;
+use tokio::net::TcpListener;
+use tokio_stream::StreamExt;
+
+mod common;
+use common::http::read_request;
+
+fn event(data: serde_json::Value) -> String {
+    format!(
+        "event: {}\ndata: {}\n\n",
+        data["type"].as_str().unwrap(),
+        data
+    )
+}
+
+fn text_delta(text: &str) -> String {
+    event(serde_json::json!({
+        "type": "content_block_delta",
+        "index": 0,
+        "delta": { "type": "text_delta", "text": text }
+    }))
+}
+
+/// Serve one event-stream response written in `writes`, then hold the
+/// connection open; returns the base URL
+async fn mock_stream(writes: Vec<String>) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    tokio::spawn(async move {
+        let (mut socket, _) = listener.accept().await.unwrap();
+
+        let body = read_request(&mut socket).await;
+        assert_eq!(body["stream"], true);
+
+        socket
+            .write_all(
+                b"HTTP/1.1 200 OK\r\ncontent-type: text/event-stream\r\nconnection: close\r\n\r\n",
+            )
+            .await
+            .unwrap();
+        for write in writes {
+            socket.write_all(write.as_bytes()).await.unwrap();
+            socket.flush().await.unwrap();
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+
+        // The client must stop at message_stop, not at connection close
+        tokio::time::sleep(Duration::from_secs(30)).await;
+    });
+
+    format!("http://{}", addr)
+}
+
+fn llm(base_url: &str) -> LlmService {
+    let config = LlmConfig {
+        api_key: "test-key".to_string(),
+        model: "claude-haiku-4-5-20251001".to_string(),
+        max_tokens: 256,
+        temperature: 0.0,
+        task_models: Default::default(),
+    };
+    LlmService::new(config).unwrap().with_base_url(base_url)
+}
+
+#[tokio::test]
+async fn test_stream_yields_chunks_in_order() {
+    let delta = text_delta(" consider");
+    let (first_half, second_half) = delta.split_at(delta.len() / 2);
+    let base_url = mock_stream(vec![
+        event(serde_json::json!({ "type": "message_start", "message": { "id": "msg_1" } })),
+        event(serde_json::json!({
+            "type": "content_block_start",
+            "index": 0,
+            "content_block": { "type": "text", "text": "" }
+        })),
+        event(serde_json::json!({ "type": "ping" })),
+        text_delta("You might"),
+        // An event split across writes is reassembled
+        first_half.to_string(),
+        second_half.to_string(),
+        text_delta(" a trait."),
+        event(serde_json::json!({ "type": "content_block_stop", "index": 0 })),
+        event(serde_json::json!({
+            "type": "message_delta",
+            "delta": { "stop_reason": "end_turn" }
+        })),
+        event(serde_json::json!({ "type": "message_stop" })),
+    ])
+    .await;
+
+    let chunks: Vec<String> = tokio::time::timeout(
+        Duration::from_secs(5),
+        llm(&base_url)
+            .complete_stream("Suggest a refactor")
+            .collect::<Vec<_>>(),
+    )
+    .await
+    .expect("stream should end on message_stop")
+    .into_iter()
+    .collect::<Result<_, _>>()
+    .unwrap();
+
+    assert_eq!(chunks, vec!["You might", " consider", " a trait."]);
+}
+
+#[tokio::test]
+async fn test_stream_error_event_ends_stream() {
+    let base_url = mock_stream(vec![
+        text_delta("Partial"),
+        event(serde_json::json!({
+            "type": "error",
+            "error": { "type": "overloaded_error", "message": "Overloaded" }
+        })),
+    ])
+    .await;
+
+    let mut stream = llm(&base_url).complete_stream("Suggest a refactor");
+    assert_eq!(stream.next().await.unwrap().unwrap(), "Partial");
+    match stream.next().await {
+        Some(Err(MnemosyneError::LlmApi(message))) => assert!(message.contains("Overloaded")),
+        other => panic!("expected stream error, got {:?}", other),
+    }
+    assert!(stream.next().await.is_none());
+}
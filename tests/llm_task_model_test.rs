@@ -0,0 +1,92 @@
+//! Per-task model selection against a mock Messages API
+
+use mnemosyne_core::{LlmConfig, LlmService, LlmSettings, LlmTask};
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+
+mod common;
+use common::http::mock_json;
+
+const DEFAULT_MODEL: &str = "claude-haiku-4-5-20251001";
+const STRONG_MODEL: &str = "claude-sonnet-4-5";
+
+/// Answer every request with a short completion; returns the base URL and
+/// the `model` each request asked for
+async fn mock_anthropic() -> (String, Arc<Mutex<Vec<String>>>) {
+    let models = Arc::new(Mutex::new(Vec::new()));
+    let seen = models.clone();
+    let base_url = mock_json(move |request| {
+        seen.lock()
+            .unwrap()
+            .push(request["model"].as_str().unwrap_or_default().to_string());
+        serde_json::json!({
+            "content": [{ "type": "text", "text": "ok" }],
+            "usage": { "input_tokens": 10, "output_tokens": 2 }
+        })
+    })
+    .await;
+
+    (base_url, models)
+}
+
+#[tokio::test]
+async fn test_overridden_task_uses_its_model_and_others_the_default() {
+    let (base_url, models) = mock_anthropic().await;
+    let llm = LlmService::new(LlmConfig {
+        api_key: "test-key".to_string(),
+        model: DEFAULT_MODEL.to_string(),
+        max_tokens: 256,
+        temperature: 0.0,
+        task_models: Default::default(),
+    })
+    .unwrap()
+    .with_base_url(base_url)
+    .with_task_models(BTreeMap::from([(
+        LlmTask::Contradiction,
+        STRONG_MODEL.to_string(),
+    )]));
+
+    llm.call_task(LlmTask::Contradiction, "Do these conflict?")
+        .await
+        .unwrap();
+    llm.call_task(LlmTask::Enrichment, "Summarize this")
+        .await
+        .unwrap();
+    llm.call_api("Anything else").await.unwrap();
+
+    assert_eq!(
+        *models.lock().unwrap(),
+        vec![STRONG_MODEL, DEFAULT_MODEL, DEFAULT_MODEL]
+    );
+}
+
+#[tokio::test]
+async fn test_configured_settings_apply_models_and_budget() {
+    let (base_url, models) = mock_anthropic().await;
+    let settings = LlmSettings {
+        token_budget: 1000,
+        models: BTreeMap::from([(LlmTask::Enrichment, STRONG_MODEL.to_string())]),
+        ..LlmSettings::default()
+    };
+    let llm = LlmService::new(LlmConfig {
+        api_key: "test-key".to_string(),
+        model: DEFAULT_MODEL.to_string(),
+        max_tokens: 256,
+        temperature: 0.0,
+        task_models: Default::default(),
+    })
+    .unwrap()
+    .with_base_url(base_url)
+    .with_settings(&settings);
+
+    llm.call_task(LlmTask::Enrichment, "Summarize this")
+        .await
+        .unwrap();
+
+    assert_eq!(*models.lock().unwrap(), vec![STRONG_MODEL]);
+    assert_eq!(
+        llm.usage_tracker().budget().map(|b| b.max_tokens),
+        Some(1000)
+    );
+    assert_eq!(llm.usage_tracker().window_tokens(), 12);
+}
@@ -0,0 +1,109 @@
+//! Per-request LLM timeouts and cancellation
+
+use mnemosyne_core::ics::semantic_highlighter::tier3_analytical::{
+    CoherenceAnalyzer, RequestControl,
+};
+use mnemosyne_core::ics::semantic_highlighter::SemanticError;
+use mnemosyne_core::{LlmConfig, LlmService, MnemosyneError};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::net::TcpListener;
+use tokio_util::sync::CancellationToken;
+
+/// Accept connections and never respond; returns the base URL
+async fn mock_unresponsive() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    tokio::spawn(async move {
+        let mut held = Vec::new();
+        while let Ok((socket, _)) = listener.accept().await {
+            held.push(socket);
+        }
+    });
+
+    format!("http://{}", addr)
+}
+
+fn llm(base_url: &str) -> LlmService {
+    let config = LlmConfig {
+        api_key: "test-key".to_string(),
+        model: "claude-haiku-4-5-20251001".to_string(),
+        max_tokens: 256,
+        temperature: 0.0,
+        task_models: Default::default(),
+    };
+    LlmService::new(config).unwrap().with_base_url(base_url)
+}
+
+#[tokio::test]
+async fn test_request_exceeding_timeout_fails_with_timeout() {
+    let service = llm(&mock_unresponsive().await);
+    let timeout = Duration::from_millis(200);
+
+    let started = Instant::now();
+    let result = service
+        .call_api_with("Analyze this", timeout, &CancellationToken::new())
+        .await;
+
+    match result {
+        Err(MnemosyneError::LlmTimeout(after)) => assert_eq!(after, timeout),
+        other => panic!("expected LlmTimeout, got {:?}", other),
+    }
+    assert!(result_is_prompt(started, timeout));
+}
+
+#[tokio::test]
+async fn test_cancellation_stops_in_flight_request() {
+    let service = llm(&mock_unresponsive().await);
+    let cancel = CancellationToken::new();
+
+    let canceller = cancel.clone();
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        canceller.cancel();
+    });
+
+    let started = Instant::now();
+    let result = service
+        .call_api_with("Analyze this", Duration::from_secs(30), &cancel)
+        .await;
+
+    assert!(matches!(result, Err(MnemosyneError::LlmCancelled)));
+    assert!(result_is_prompt(started, Duration::from_millis(100)));
+}
+
+#[tokio::test]
+async fn test_analyzer_applies_its_request_control() {
+    let service = Arc::new(llm(&mock_unresponsive().await));
+    let text = "The cache is fast. Bananas are yellow.";
+
+    let timeout = Duration::from_millis(200);
+    let analyzer =
+        CoherenceAnalyzer::new(Arc::clone(&service)).with_request_control(RequestControl {
+            timeout,
+            cancel: CancellationToken::new(),
+        });
+    let started = Instant::now();
+    assert!(matches!(
+        analyzer.analyze(text).await,
+        Err(SemanticError::LlmTimeout(_))
+    ));
+    assert!(result_is_prompt(started, timeout));
+
+    let cancel = CancellationToken::new();
+    cancel.cancel();
+    let analyzer = CoherenceAnalyzer::new(service).with_request_control(RequestControl {
+        timeout: Duration::from_secs(30),
+        cancel,
+    });
+    assert!(matches!(
+        analyzer.analyze(text).await,
+        Err(SemanticError::Cancelled)
+    ));
+}
+
+/// Returned within a second of `expected`
+fn result_is_prompt(started: Instant, expected: Duration) -> bool {
+    started.elapsed() < expected + Duration::from_secs(1)
+}
@@ -0,0 +1,79 @@
+//! Token usage accounting and budget enforcement against a mock Messages API
+
+use mnemosyne_core::{LlmConfig, LlmService, MnemosyneError, TokenBudget, TokenUsage};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+mod common;
+use common::http::mock_json;
+
+/// Answer every request with a completion reporting the given token counts;
+/// returns the base URL and the number of requests served
+async fn mock_anthropic(input_tokens: u64, output_tokens: u64) -> (String, Arc<AtomicUsize>) {
+    let requests = Arc::new(AtomicUsize::new(0));
+    let served = requests.clone();
+    let base_url = mock_json(move |_| {
+        served.fetch_add(1, Ordering::SeqCst);
+        serde_json::json!({
+            "content": [{ "type": "text", "text": "ok" }],
+            "usage": { "input_tokens": input_tokens, "output_tokens": output_tokens }
+        })
+    })
+    .await;
+
+    (base_url, requests)
+}
+
+fn llm(base_url: &str) -> LlmService {
+    LlmService::new(LlmConfig {
+        api_key: "test-key".to_string(),
+        model: "claude-haiku-4-5-20251001".to_string(),
+        max_tokens: 256,
+        temperature: 0.0,
+        task_models: Default::default(),
+    })
+    .unwrap()
+    .with_base_url(base_url)
+}
+
+#[tokio::test]
+async fn test_usage_accumulates_across_calls() {
+    let (base_url, _) = mock_anthropic(120, 30).await;
+    let llm = llm(&base_url);
+    assert_eq!(llm.usage(), TokenUsage::default());
+
+    llm.call_api("first").await.unwrap();
+    llm.call_api("second").await.unwrap();
+
+    assert_eq!(llm.usage(), TokenUsage::new(240, 60));
+    // Clones share the running total
+    llm.clone().call_api("third").await.unwrap();
+    assert_eq!(llm.usage().total_tokens, 450);
+    assert!(llm.usage_tracker().total_cost_usd() > 0.0);
+}
+
+#[tokio::test]
+async fn test_budget_fails_calls_fast_once_spent() {
+    let (base_url, requests) = mock_anthropic(120, 30).await;
+    let llm = llm(&base_url).with_token_budget(TokenBudget {
+        max_tokens: 200,
+        window: Duration::from_secs(3600),
+    });
+
+    // 150 of 200 tokens used: still under budget
+    llm.call_api("first").await.unwrap();
+    // Starts under budget, finishes over it
+    llm.call_api("second").await.unwrap();
+    assert_eq!(requests.load(Ordering::SeqCst), 2);
+
+    match llm.call_api("third").await {
+        Err(MnemosyneError::LlmBudgetExceeded(message)) => {
+            assert!(message.contains("300 of 200"), "{}", message)
+        }
+        other => panic!("Expected LlmBudgetExceeded, got {:?}", other),
+    }
+    // Rejected without contacting the API
+    assert_eq!(requests.load(Ordering::SeqCst), 2);
+    assert_eq!(llm.usage().total_tokens, 300);
+}
@@ -0,0 +1,231 @@
+//! The same MCP tool call over the stdio and HTTP+SSE transports
+
+use mnemosyne_core::mcp::{http, McpServer, ToolHandler};
+use mnemosyne_core::services::embeddings::EmbeddingService;
+use mnemosyne_core::{LlmConfig, LlmService, MemoryType, Namespace, StorageBackend};
+use serde_json::Value;
+use std::sync::Arc;
+use tokio_stream::StreamExt;
+
+mod common;
+use common::{create_test_storage, sample_memory};
+
+const REQUEST: &str = r#"{"jsonrpc":"2.0","id":7,"method":"tools/call","params":{"name":"mnemosyne.list_namespaces","arguments":{}}}"#;
+
+async fn server() -> McpServer {
+    let storage = create_test_storage().await;
+    for name in ["alpha", "beta"] {
+        let mut memory = sample_memory(&format!("{} note", name), MemoryType::Insight, 5);
+        memory.namespace = Namespace::Project {
+            name: name.to_string(),
+        };
+        storage.store_memory(&memory).await.unwrap();
+    }
+
+    let llm_config = LlmConfig {
+        api_key: String::new(),
+        model: "claude-haiku-4-5-20251001".to_string(),
+        max_tokens: 1024,
+        temperature: 0.7,
+        task_models: Default::default(),
+    };
+    let llm = Arc::new(LlmService::new(llm_config.clone()).unwrap());
+    let embeddings = Arc::new(EmbeddingService::new(String::new(), llm_config));
+    let storage: Arc<dyn StorageBackend> = Arc::new(storage);
+    McpServer::new(ToolHandler::new(storage, llm, embeddings))
+}
+
+async fn call_over_stdio(server: &McpServer) -> Value {
+    let input = format!("{}\n", REQUEST);
+    let mut output = Vec::new();
+    server
+        .serve_stdio(input.as_bytes(), &mut output)
+        .await
+        .unwrap();
+
+    let output = String::from_utf8(output).unwrap();
+    let mut lines = output.lines();
+    let response = serde_json::from_str(lines.next().unwrap()).unwrap();
+    assert!(lines.next().is_none(), "one response per request");
+    response
+}
+
+/// Reads `(event, data)` pairs from a server-sent event stream
+struct SseReader<S> {
+    body: S,
+    buffer: String,
+}
+
+impl<S, B> SseReader<S>
+where
+    S: tokio_stream::Stream<Item = reqwest::Result<B>> + Unpin,
+    B: AsRef<[u8]>,
+{
+    async fn next_event(&mut self) -> (String, String) {
+        loop {
+            if let Some(end) = self.buffer.find("\n\n") {
+                let block: String = self.buffer.drain(..end + 2).collect();
+                let (mut event, mut data) = (String::from("message"), String::new());
+                for line in block.lines() {
+                    if let Some(value) = line.strip_prefix("event:") {
+                        event = value.trim().to_string();
+                    } else if let Some(value) = line.strip_prefix("data:") {
+                        data.push_str(value.strip_prefix(' ').unwrap_or(value));
+                    }
+                }
+                if data.is_empty() {
+                    // Keep-alive comment
+                    continue;
+                }
+                return (event, data);
+            }
+            let chunk = self.body.next().await.expect("stream ended").unwrap();
+            self.buffer
+                .push_str(&String::from_utf8_lossy(chunk.as_ref()).replace("\r\n", "\n"));
+        }
+    }
+}
+
+/// Serve `server` over HTTP on an ephemeral loopback port; returns its base URL
+async fn spawn_http(server: McpServer, token: Option<&str>) -> String {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    tokio::spawn(http::serve(
+        Arc::new(server),
+        listener,
+        token.map(String::from),
+    ));
+    base_url
+}
+
+/// Open an event stream and read its message endpoint
+async fn open_session(
+    client: &reqwest::Client,
+    base_url: &str,
+) -> (
+    SseReader<impl tokio_stream::Stream<Item = reqwest::Result<impl AsRef<[u8]>>> + Unpin>,
+    String,
+) {
+    let stream = client
+        .get(format!("{}{}", base_url, http::SSE_PATH))
+        .send()
+        .await
+        .unwrap();
+    assert!(stream.status().is_success());
+    let mut events = SseReader {
+        body: stream.bytes_stream(),
+        buffer: String::new(),
+    };
+
+    let (event, endpoint) = events.next_event().await;
+    assert_eq!(event, "endpoint");
+    assert!(endpoint.starts_with(http::MESSAGE_PATH));
+    (events, endpoint)
+}
+
+async fn call_over_http(server: McpServer) -> Value {
+    let base_url = spawn_http(server, None).await;
+    let client = reqwest::Client::new();
+    let (mut events, endpoint) = open_session(&client, &base_url).await;
+
+    let posted = client
+        .post(format!("{}{}", base_url, endpoint))
+        .header("content-type", "application/json")
+        .body(REQUEST)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(posted.status(), reqwest::StatusCode::ACCEPTED);
+
+    let (event, data) = events.next_event().await;
+    assert_eq!(event, "message");
+    serde_json::from_str(&data).unwrap()
+}
+
+#[tokio::test]
+async fn test_tool_call_is_identical_over_stdio_and_http() {
+    let server = server().await;
+
+    let over_stdio = call_over_stdio(&server).await;
+    assert_eq!(over_stdio["id"], 7);
+    assert!(over_stdio["error"].is_null(), "{}", over_stdio);
+    let text = over_stdio["result"]["content"][0]["text"].as_str().unwrap();
+    let result: Value = serde_json::from_str(text).unwrap();
+    assert_eq!(result["count"], 2);
+
+    let over_http = call_over_http(server).await;
+    assert_eq!(over_http, over_stdio);
+}
+
+#[tokio::test]
+async fn test_http_message_for_unknown_session_is_rejected() {
+    let base_url = spawn_http(server().await, None).await;
+
+    let response = reqwest::Client::new()
+        .post(format!(
+            "{}{}?sessionId=missing",
+            base_url,
+            http::MESSAGE_PATH
+        ))
+        .body(REQUEST)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_http_rejects_foreign_origins() {
+    let base_url = spawn_http(server().await, None).await;
+    let client = reqwest::Client::new();
+    let sse_url = format!("{}{}", base_url, http::SSE_PATH);
+
+    let foreign = client
+        .get(&sse_url)
+        .header("origin", "https://attacker.example")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(foreign.status(), reqwest::StatusCode::FORBIDDEN);
+
+    let local = client
+        .get(&sse_url)
+        .header("origin", "http://localhost:5173")
+        .send()
+        .await
+        .unwrap();
+    assert!(local.status().is_success());
+}
+
+#[tokio::test]
+async fn test_http_requires_configured_token() {
+    let base_url = spawn_http(server().await, Some("s3cret-token")).await;
+    let client = reqwest::Client::new();
+    let sse_url = format!("{}{}", base_url, http::SSE_PATH);
+
+    for authorization in [None, Some("Bearer wrong-token")] {
+        let mut request = client.get(&sse_url);
+        if let Some(value) = authorization {
+            request = request.header("authorization", value);
+        }
+        assert_eq!(
+            request.send().await.unwrap().status(),
+            reqwest::StatusCode::UNAUTHORIZED
+        );
+    }
+
+    let authorized = client
+        .get(&sse_url)
+        .bearer_auth("s3cret-token")
+        .send()
+        .await
+        .unwrap();
+    assert!(authorized.status().is_success());
+}
+
+#[tokio::test]
+async fn test_http_refuses_non_loopback_address_without_token() {
+    let listener = tokio::net::TcpListener::bind("0.0.0.0:0").await.unwrap();
+    let result = http::serve(Arc::new(server().await), listener, None).await;
+    assert!(result.is_err());
+}
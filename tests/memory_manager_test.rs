@@ -0,0 +1,175 @@
+//! MemoryManager remember/recall, with and without LLM enrichment
+
+use mnemosyne_core::embeddings::{DeterministicEmbeddingService, DETERMINISTIC_EMBEDDING_DIM};
+use mnemosyne_core::{
+    ConnectionMode, LibsqlStorage, LlmConfig, LlmService, MemoryManager, MemoryType,
+    MnemosyneError, Namespace, SearchQuery, StorageBackend,
+};
+use std::sync::Arc;
+
+mod common;
+use common::http::{mock_json, unreachable_url};
+
+/// Answer with Anthropic-shaped responses whose text is `enrichment`,
+/// returning the base URL
+async fn mock_anthropic(enrichment: serde_json::Value) -> String {
+    mock_json(move |_| {
+        serde_json::json!({
+            "content": [{ "type": "text", "text": enrichment.to_string() }]
+        })
+    })
+    .await
+}
+
+fn llm(api_key: &str, base_url: &str) -> Arc<LlmService> {
+    let config = LlmConfig {
+        api_key: api_key.to_string(),
+        model: "claude-haiku-4-5-20251001".to_string(),
+        max_tokens: 1024,
+        temperature: 0.7,
+        task_models: Default::default(),
+    };
+    Arc::new(LlmService::new(config).unwrap().with_base_url(base_url))
+}
+
+async fn in_memory_storage() -> Arc<LibsqlStorage> {
+    Arc::new(
+        LibsqlStorage::new_with_validation(ConnectionMode::InMemory, true)
+            .await
+            .unwrap(),
+    )
+}
+
+fn project() -> Namespace {
+    Namespace::Project {
+        name: "myapp".to_string(),
+    }
+}
+
+#[tokio::test]
+async fn test_remember_enriches_and_embeds() {
+    let base_url = mock_anthropic(serde_json::json!({
+        "summary": "PostgreSQL chosen for user data",
+        "keywords": ["PostgreSQL", "database"],
+        "tags": ["architecture"],
+        "type": "ArchitectureDecision",
+        "importance": 7
+    }))
+    .await;
+    let storage = in_memory_storage().await;
+    let manager = MemoryManager::new(storage.clone())
+        .with_llm(llm("test-key", &base_url))
+        .with_embeddings(Arc::new(DeterministicEmbeddingService::new(
+            DETERMINISTIC_EMBEDDING_DIM,
+        )));
+
+    let id = manager
+        .remember(
+            "Decided to use PostgreSQL for user data".to_string(),
+            project(),
+            Some(9),
+        )
+        .await
+        .unwrap();
+
+    let memory = storage.get_memory(id).await.unwrap();
+    assert_eq!(memory.summary, "PostgreSQL chosen for user data");
+    assert_eq!(memory.keywords, vec!["PostgreSQL", "database"]);
+    assert_eq!(memory.tags, vec!["architecture"]);
+    assert_eq!(memory.memory_type, MemoryType::ArchitectureDecision);
+    // The caller's importance wins over the extracted one
+    assert_eq!(memory.importance, 9);
+    assert_eq!(memory.namespace, project());
+    assert_eq!(
+        memory.embedding.map(|e| e.len()),
+        Some(DETERMINISTIC_EMBEDDING_DIM)
+    );
+
+    let results = manager
+        .recall(SearchQuery {
+            query: "PostgreSQL".to_string(),
+            namespace: Some(project()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].memory.id, id);
+}
+
+#[tokio::test]
+async fn test_remember_degrades_to_basic_metadata() {
+    let storage = in_memory_storage().await;
+    let content = "Retry failed uploads with exponential backoff".to_string();
+
+    // No API key: enrichment is not attempted
+    let without_key = MemoryManager::new(storage.clone()).with_llm(llm("", "http://127.0.0.1:9"));
+    let id = without_key
+        .remember(content.clone(), project(), None)
+        .await
+        .unwrap();
+    let memory = storage.get_memory(id).await.unwrap();
+    assert_eq!(memory.summary, content);
+    assert!(memory.keywords.is_empty() && memory.tags.is_empty());
+    assert_eq!(memory.memory_type, MemoryType::Insight);
+    assert_eq!(memory.importance, 5);
+    assert_eq!(memory.confidence, 0.5);
+    assert!(memory.embedding.is_none());
+
+    // Unreachable API: enrichment fails and the memory is still stored
+    let unreachable = unreachable_url().await;
+    let unreachable_llm =
+        MemoryManager::new(storage.clone()).with_llm(llm("test-key", &unreachable));
+    let id = unreachable_llm
+        .remember(
+            "Uploads retry at most five times".to_string(),
+            project(),
+            Some(3),
+        )
+        .await
+        .unwrap();
+    let memory = storage.get_memory(id).await.unwrap();
+    assert_eq!(memory.confidence, 0.5);
+    assert_eq!(memory.importance, 3);
+
+    let results = without_key
+        .recall(SearchQuery {
+            query: "uploads".to_string(),
+            namespace: Some(project()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 2);
+}
+
+#[tokio::test]
+async fn test_remember_rejects_out_of_range_importance() {
+    let storage = in_memory_storage().await;
+    let manager = MemoryManager::new(storage.clone());
+
+    for importance in [0, 11] {
+        let result = manager
+            .remember(
+                "Importance outside the scale".to_string(),
+                project(),
+                Some(importance),
+            )
+            .await;
+        assert!(
+            matches!(result, Err(MnemosyneError::ValidationError(_))),
+            "importance {} should be rejected",
+            importance
+        );
+    }
+
+    let stored = manager
+        .recall(SearchQuery {
+            query: "importance".to_string(),
+            namespace: Some(project()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert!(stored.is_empty());
+}
@@ -0,0 +1,105 @@
+//! Tests for the aggregate memory overview (`mnemosyne stats`)
+
+use mnemosyne_core::{LibsqlStorage, LinkType, MemoryLink, MemoryType, Namespace, StorageBackend};
+
+mod common;
+use common::{create_test_storage, sample_memory};
+
+/// Three active project memories (one embedded, one link) plus one archived
+/// and one in another namespace
+async fn seed(storage: &LibsqlStorage) -> Namespace {
+    let project = Namespace::Project {
+        name: "myapp".to_string(),
+    };
+
+    let mut decision = sample_memory("Use libSQL", MemoryType::ArchitectureDecision, 9);
+    decision.namespace = project.clone();
+    decision.tags = vec!["storage".to_string(), "db".to_string()];
+    decision.embedding = Some(vec![0.1; 384]);
+
+    let mut pattern = sample_memory("Retry with backoff", MemoryType::CodePattern, 6);
+    pattern.namespace = project.clone();
+    pattern.tags = vec!["db".to_string()];
+    pattern.links = vec![MemoryLink {
+        target_id: decision.id,
+        link_type: LinkType::Implements,
+        strength: 0.7,
+        reason: "test".to_string(),
+        created_at: chrono::Utc::now(),
+        last_traversed_at: None,
+        user_created: false,
+    }];
+
+    let mut fix = sample_memory("Fix pool leak", MemoryType::BugFix, 3);
+    fix.namespace = project.clone();
+    fix.tags = vec![];
+
+    let mut archived = sample_memory("Old approach", MemoryType::CodePattern, 2);
+    archived.namespace = project.clone();
+
+    let elsewhere = sample_memory("Global preference", MemoryType::Preference, 5);
+
+    for memory in [&decision, &pattern, &fix, &archived, &elsewhere] {
+        storage.store_memory(memory).await.unwrap();
+    }
+    storage.archive_memory(archived.id).await.unwrap();
+    project
+}
+
+#[tokio::test]
+async fn test_stats_match_fixture() {
+    let storage = create_test_storage().await;
+    let project = seed(&storage).await;
+
+    let stats = storage.memory_stats(Some(&project), 10).await.unwrap();
+    assert_eq!(stats.total, 4);
+    assert_eq!(stats.active, 3);
+    assert_eq!(stats.archived, 1);
+    assert_eq!(stats.by_type.get("architecture_decision"), Some(&1));
+    assert_eq!(stats.by_type.get("code_pattern"), Some(&1));
+    assert_eq!(stats.by_type.get("bug_fix"), Some(&1));
+    assert_eq!(stats.by_type.len(), 3);
+    assert!((stats.average_importance - 6.0).abs() < 1e-9);
+    assert_eq!(stats.embedded, 1);
+    assert!((stats.embedding_coverage - 1.0 / 3.0).abs() < 1e-9);
+    assert_eq!(stats.links, 1);
+    assert!((stats.link_density - 1.0 / 3.0).abs() < 1e-9);
+    assert_eq!(stats.top_tags[0].tag, "db");
+    assert_eq!(stats.top_tags[0].count, 2);
+
+    let all = storage.memory_stats(None, 1).await.unwrap();
+    assert_eq!(all.total, 5);
+    assert_eq!(all.active, 4);
+    assert_eq!(all.top_tags.len(), 1);
+}
+
+#[tokio::test]
+async fn test_stats_json_shape() {
+    let storage = create_test_storage().await;
+    let project = seed(&storage).await;
+
+    let stats = storage.memory_stats(Some(&project), 10).await.unwrap();
+    let json = serde_json::to_value(&stats).unwrap();
+
+    for key in ["total", "active", "archived", "embedded", "links"] {
+        assert!(json[key].is_u64(), "{} should be a count", key);
+    }
+    for key in ["average_importance", "embedding_coverage", "link_density"] {
+        assert!(json[key].is_f64(), "{} should be a number", key);
+    }
+    assert_eq!(json["by_type"]["code_pattern"], 1);
+    assert_eq!(json["top_tags"][0]["tag"], "db");
+    assert_eq!(json["top_tags"][0]["count"], 2);
+}
+
+#[tokio::test]
+async fn test_stats_empty_store() {
+    let storage = create_test_storage().await;
+    let stats = storage.memory_stats(None, 10).await.unwrap();
+
+    assert_eq!(stats.total, 0);
+    assert_eq!(stats.average_importance, 0.0);
+    assert_eq!(stats.embedding_coverage, 0.0);
+    assert_eq!(stats.link_density, 0.0);
+    assert!(stats.top_tags.is_empty());
+}
@@ -0,0 +1,47 @@
+//! Storage rejects memories that fail `MemoryNote::validate`
+
+use mnemosyne_core::{error::MnemosyneError, MemoryType, StorageBackend};
+
+mod common;
+use common::{create_test_storage, sample_memory};
+
+#[tokio::test]
+async fn test_store_rejects_invalid_memory() {
+    let storage = create_test_storage().await;
+
+    let mut invalid = sample_memory("Out of range", MemoryType::Insight, 5);
+    invalid.importance = 50;
+    invalid.content = String::new();
+
+    match storage.store_memory(&invalid).await {
+        Err(MnemosyneError::ValidationError(msg)) => {
+            assert!(msg.contains("importance"), "{}", msg);
+            assert!(msg.contains("content"), "{}", msg);
+        }
+        other => panic!("expected a validation error, got {:?}", other),
+    }
+    assert_eq!(storage.count_memories(None).await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn test_update_rejects_invalid_memory() {
+    let storage = create_test_storage().await;
+    let memory = sample_memory("Valid memory", MemoryType::Insight, 5);
+    storage.store_memory(&memory).await.unwrap();
+
+    // Stored unchanged
+    let stored = storage.get_memory(memory.id).await.unwrap();
+    assert_eq!(stored.content, memory.content);
+    assert_eq!(stored.importance, memory.importance);
+
+    let mut updated = stored.clone();
+    updated.confidence = 1.5;
+    assert!(matches!(
+        storage.update_memory(&updated).await,
+        Err(MnemosyneError::ValidationError(_))
+    ));
+    assert_eq!(
+        storage.get_memory(memory.id).await.unwrap().confidence,
+        stored.confidence
+    );
+}
@@ -0,0 +1,225 @@
+//! Versioned, reversible migrations: ordering, idempotence, and rollback
+
+use libsql::Connection;
+use mnemosyne_core::storage::migrations::{Migration, MigrationFuture, MigrationRegistry};
+use mnemosyne_core::{ConnectionMode, LibsqlStorage};
+use tempfile::TempDir;
+
+async fn connect(dir: &TempDir) -> Connection {
+    libsql::Builder::new_local(dir.path().join("migrations.db"))
+        .build()
+        .await
+        .unwrap()
+        .connect()
+        .unwrap()
+}
+
+fn insert_three(conn: &Connection) -> MigrationFuture<'_> {
+    Box::pin(async move {
+        conn.execute("INSERT INTO log VALUES (3)", ()).await?;
+        Ok(())
+    })
+}
+
+/// Registered out of order; the registry sorts by version
+fn registry() -> MigrationRegistry {
+    MigrationRegistry::new(vec![
+        Migration::rust(3, "003_rust_step", insert_three),
+        Migration::sql(
+            1,
+            "001_log",
+            "CREATE TABLE log (step INTEGER NOT NULL);
+             INSERT INTO log VALUES (1);",
+        ),
+        Migration::sql(4, "004_widgets", "CREATE TABLE widgets (id INTEGER);")
+            .with_down_sql("DROP TABLE widgets;"),
+        Migration::sql(2, "002_log", "INSERT INTO log VALUES (2);"),
+    ])
+    .unwrap()
+}
+
+async fn log(conn: &Connection) -> Vec<i64> {
+    let mut rows = conn
+        .query("SELECT step FROM log ORDER BY rowid", ())
+        .await
+        .unwrap();
+    let mut steps = Vec::new();
+    while let Some(row) = rows.next().await.unwrap() {
+        steps.push(row.get(0).unwrap());
+    }
+    steps
+}
+
+async fn has_table(conn: &Connection, table: &str) -> bool {
+    conn.query(
+        "SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = ?",
+        libsql::params![table],
+    )
+    .await
+    .unwrap()
+    .next()
+    .await
+    .unwrap()
+    .is_some()
+}
+
+#[tokio::test]
+async fn test_migrations_apply_in_order_once() {
+    let dir = TempDir::new().unwrap();
+    let conn = connect(&dir).await;
+    let registry = registry();
+
+    assert_eq!(registry.pending(&conn).await.unwrap().len(), 4);
+    assert_eq!(
+        registry.apply_pending(&conn).await.unwrap(),
+        vec![1, 2, 3, 4]
+    );
+    assert_eq!(log(&conn).await, vec![1, 2, 3]);
+
+    // Idempotent: nothing pending, nothing re-run
+    assert!(registry.pending(&conn).await.unwrap().is_empty());
+    assert!(registry.apply_pending(&conn).await.unwrap().is_empty());
+    assert_eq!(log(&conn).await, vec![1, 2, 3]);
+    assert_eq!(
+        registry.applied_versions(&conn).await.unwrap(),
+        vec![1, 2, 3, 4]
+    );
+}
+
+#[tokio::test]
+async fn test_down_reverts_schema() {
+    let dir = TempDir::new().unwrap();
+    let conn = connect(&dir).await;
+    let registry = registry();
+    registry.apply_pending(&conn).await.unwrap();
+    assert!(has_table(&conn, "widgets").await);
+
+    assert_eq!(registry.rollback_to(&conn, 3).await.unwrap(), vec![4]);
+    assert!(!has_table(&conn, "widgets").await);
+    assert_eq!(
+        registry.applied_versions(&conn).await.unwrap(),
+        vec![1, 2, 3]
+    );
+
+    // Rolling back past an irreversible migration changes nothing
+    assert!(registry.rollback_to(&conn, 0).await.is_err());
+    assert_eq!(
+        registry.applied_versions(&conn).await.unwrap(),
+        vec![1, 2, 3]
+    );
+
+    // The reverted migration is pending again
+    assert_eq!(registry.apply_pending(&conn).await.unwrap(), vec![4]);
+    assert!(has_table(&conn, "widgets").await);
+}
+
+#[tokio::test]
+async fn test_failed_migration_leaves_no_trace() {
+    let dir = TempDir::new().unwrap();
+    let conn = connect(&dir).await;
+    let registry = MigrationRegistry::new(vec![
+        Migration::sql(1, "001_ok", "CREATE TABLE kept (id INTEGER);"),
+        Migration::sql(
+            2,
+            "002_broken",
+            "CREATE TABLE partial (id INTEGER);
+             INSERT INTO missing_table VALUES (1);",
+        ),
+    ])
+    .unwrap();
+
+    assert!(registry.apply_pending(&conn).await.is_err());
+    assert!(has_table(&conn, "kept").await);
+    assert!(!has_table(&conn, "partial").await);
+    assert_eq!(registry.applied_versions(&conn).await.unwrap(), vec![1]);
+}
+
+#[test]
+fn test_duplicate_versions_rejected() {
+    let result = MigrationRegistry::new(vec![
+        Migration::sql(1, "001_a", "SELECT 1;"),
+        Migration::sql(1, "001_b", "SELECT 1;"),
+    ]);
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_storage_rollback_stops_at_irreversible_migration() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("storage.db");
+    let storage = LibsqlStorage::new_with_validation(
+        ConnectionMode::Local(path.to_str().unwrap().to_string()),
+        true,
+    )
+    .await
+    .unwrap();
+    let latest = storage.target_schema_version();
+
+    // 018 rebuilds the audit log and cannot be reverted
+    assert!(storage.rollback_migrations(12).await.is_err());
+    assert_eq!(storage.schema_version().await.unwrap(), Some(latest));
+    assert!(storage.migration_status().await.unwrap().is_current());
+}
+
+#[tokio::test]
+async fn test_custom_types_migration_keeps_legacy_columns() {
+    let dir = TempDir::new().unwrap();
+    let conn = connect(&dir).await;
+    let full = MigrationRegistry::libsql();
+    let before_017 = MigrationRegistry::new(
+        full.migrations()
+            .iter()
+            .filter(|m| m.version < 17)
+            .cloned()
+            .collect(),
+    )
+    .unwrap();
+    before_017.apply_pending(&conn).await.unwrap();
+
+    // What a database upgraded through 007 and 008 carries
+    conn.execute_batch(
+        "ALTER TABLE memories ADD COLUMN archived_at INTEGER;
+         CREATE INDEX idx_memories_archived ON memories(archived_at);
+         ALTER TABLE memories ADD COLUMN created_by TEXT;
+         ALTER TABLE memories ADD COLUMN visible_to TEXT NOT NULL DEFAULT '[]';
+         CREATE VIEW orchestrator_memories AS SELECT * FROM memories WHERE created_by = 'orchestrator';
+         INSERT INTO memories (id, namespace, content, summary, keywords, tags, context,
+                               memory_type, importance, confidence, embedding_model,
+                               archived_at, created_by, visible_to)
+         VALUES ('m1', 'global', 'content', 'summary', '[]', '[]', 'ctx', 'insight', 5, 0.9,
+                 'test', 1700000000, 'orchestrator', '[\"reviewer\"]');",
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(full.apply_pending(&conn).await.unwrap(), vec![17, 18]);
+
+    let mut rows = conn
+        .query(
+            "SELECT archived_at, created_by, visible_to FROM orchestrator_memories WHERE id = 'm1'",
+            (),
+        )
+        .await
+        .unwrap();
+    let row = rows.next().await.unwrap().unwrap();
+    assert_eq!(row.get::<i64>(0).unwrap(), 1700000000);
+    assert_eq!(row.get::<String>(1).unwrap(), "orchestrator");
+    assert_eq!(row.get::<String>(2).unwrap(), "[\"reviewer\"]");
+
+    let mut rows = conn
+        .query(
+            "SELECT 1 FROM sqlite_master WHERE type = 'index' AND name = 'idx_memories_archived'",
+            (),
+        )
+        .await
+        .unwrap();
+    assert!(rows.next().await.unwrap().is_some());
+
+    // Custom types now pass the constraint
+    conn.execute(
+        "UPDATE memories SET memory_type = 'security_finding' WHERE id = 'm1'",
+        (),
+    )
+    .await
+    .unwrap();
+}
@@ -3,7 +3,11 @@
 //! Tests that memories are properly isolated by namespace and that
 //! the namespace hierarchy (Global -> Project -> Session) works correctly.
 
-use mnemosyne_core::{MemoryType, Namespace, StorageBackend};
+use mnemosyne_core::{
+    LibsqlStorage, LinkType, MemoryId, MemoryLink, MemoryType, Namespace, NamespaceBoundary,
+    SearchConfig, StorageBackend,
+};
+use std::collections::HashSet;
 
 mod common;
 use common::{create_test_storage, sample_memory};
@@ -394,3 +398,66 @@ async fn test_archived_memories_excluded_from_search() {
 
     assert_eq!(count, 0, "Archived memories should not be counted");
 }
+
+#[tokio::test]
+async fn test_graph_traverse_honors_namespace_boundary() {
+    let mut storage = create_test_storage().await;
+    let project = |name: &str| Namespace::Project {
+        name: name.to_string(),
+    };
+
+    let mut sibling = sample_memory("Same project note", MemoryType::Insight, 5);
+    sibling.namespace = project("app1");
+    let mut global = sample_memory("Global convention", MemoryType::CodePattern, 5);
+    let mut foreign = sample_memory("Another project's note", MemoryType::Insight, 5);
+    foreign.namespace = project("app2");
+    // Only reachable through the global memory
+    let mut beyond_global = sample_memory("Linked from the global note", MemoryType::Insight, 5);
+    beyond_global.namespace = project("app2");
+    for memory in [&sibling, &foreign, &beyond_global] {
+        storage.store_memory(memory).await.unwrap();
+    }
+
+    let link = |target_id| MemoryLink {
+        target_id,
+        link_type: LinkType::References,
+        strength: 0.8,
+        reason: "related".to_string(),
+        created_at: chrono::Utc::now(),
+        last_traversed_at: None,
+        user_created: false,
+    };
+    global.links = vec![link(beyond_global.id)];
+    storage.store_memory(&global).await.unwrap();
+    let mut seed = sample_memory("Project seed", MemoryType::ArchitectureDecision, 8);
+    seed.namespace = project("app1");
+    seed.links = vec![link(sibling.id), link(global.id), link(foreign.id)];
+    storage.store_memory(&seed).await.unwrap();
+
+    assert_eq!(
+        reached(&mut storage, seed.id, NamespaceBoundary::Strict).await,
+        HashSet::from([seed.id, sibling.id])
+    );
+    assert_eq!(
+        reached(&mut storage, seed.id, NamespaceBoundary::AllowGlobal).await,
+        HashSet::from([seed.id, sibling.id, global.id])
+    );
+    assert_eq!(
+        reached(&mut storage, seed.id, NamespaceBoundary::Any).await,
+        HashSet::from([seed.id, sibling.id, global.id, foreign.id, beyond_global.id])
+    );
+}
+
+/// Memories `graph_traverse` reaches from `seed` under `boundary`
+async fn reached(
+    storage: &mut LibsqlStorage,
+    seed: MemoryId,
+    boundary: NamespaceBoundary,
+) -> HashSet<MemoryId> {
+    storage.set_search_config(SearchConfig {
+        namespace_boundary: boundary,
+        ..SearchConfig::default()
+    });
+    let memories = storage.graph_traverse(&[seed], 3, None).await.unwrap();
+    memories.into_iter().map(|m| m.id).collect()
+}
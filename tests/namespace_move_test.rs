@@ -0,0 +1,153 @@
+//! Bulk namespace rename and merge
+
+use mnemosyne_core::error::MnemosyneError;
+use mnemosyne_core::evaluation::feedback_collector::{
+    ContextType, FeedbackCollector, ProvidedContext,
+};
+use mnemosyne_core::{
+    ConnectionMode, LibsqlStorage, LinkType, MemoryLink, MemoryNote, MemoryType, Namespace,
+    StorageBackend,
+};
+use tempfile::TempDir;
+
+mod common;
+use common::sample_memory;
+
+fn project(name: &str) -> Namespace {
+    Namespace::Project {
+        name: name.to_string(),
+    }
+}
+
+fn memory_in(namespace: &Namespace, content: &str) -> MemoryNote {
+    let mut memory = sample_memory(content, MemoryType::Insight, 5);
+    memory.namespace = namespace.clone();
+    memory
+}
+
+async fn open_storage(temp_dir: &TempDir) -> (LibsqlStorage, String) {
+    let db_path = temp_dir
+        .path()
+        .join("namespaces.db")
+        .to_string_lossy()
+        .to_string();
+    let storage = LibsqlStorage::new_with_validation(ConnectionMode::Local(db_path.clone()), true)
+        .await
+        .unwrap();
+    (storage, db_path)
+}
+
+#[tokio::test]
+async fn test_rename_namespace_moves_all_memories() {
+    let temp_dir = TempDir::new().unwrap();
+    let (storage, db_path) = open_storage(&temp_dir).await;
+    let old = project("old");
+    let new = project("new");
+    let session = Namespace::Session {
+        project: "old".to_string(),
+        session_id: "s1".to_string(),
+    };
+
+    let first = memory_in(&old, "Services talk over gRPC");
+    let archived = memory_in(&old, "Legacy REST gateway");
+    let noted = memory_in(&session, "Debugging the gateway today");
+    let unrelated = memory_in(&project("other"), "Unrelated project memory");
+    for memory in [&first, &archived, &noted, &unrelated] {
+        storage.store_memory(memory).await.unwrap();
+    }
+    storage.archive_memory(archived.id).await.unwrap();
+
+    let collector = FeedbackCollector::new(db_path);
+    collector.init_schema().await.unwrap();
+    let eval_id = collector
+        .record_context_provided(ProvidedContext {
+            session_id: "eval-session".to_string(),
+            agent_role: "optimizer".to_string(),
+            namespace: old.to_string(),
+            context_type: ContextType::Memory,
+            context_id: first.id.to_string(),
+            task_hash: "0123456789abcdef".to_string(),
+            task_keywords: None,
+            task_type: None,
+            work_phase: None,
+            file_types: None,
+            error_context: None,
+            related_technologies: None,
+        })
+        .await
+        .unwrap();
+
+    let moved = storage.rename_namespace(&old, &new).await.unwrap();
+    assert_eq!(moved, 3);
+
+    assert_eq!(storage.count_memories(Some(old.clone())).await.unwrap(), 0);
+    assert_eq!(storage.get_memory(first.id).await.unwrap().namespace, new);
+    assert_eq!(
+        storage.get_memory(archived.id).await.unwrap().namespace,
+        new
+    );
+    assert_eq!(
+        storage.get_memory(noted.id).await.unwrap().namespace,
+        Namespace::Session {
+            project: "new".to_string(),
+            session_id: "s1".to_string(),
+        }
+    );
+    assert_eq!(
+        storage.get_memory(unrelated.id).await.unwrap().namespace,
+        project("other")
+    );
+    assert_eq!(
+        collector.get_evaluation(&eval_id).await.unwrap().namespace,
+        "project:new"
+    );
+
+    // Renaming onto a namespace that holds memories changes nothing
+    let result = storage.rename_namespace(&project("other"), &new).await;
+    assert!(matches!(result, Err(MnemosyneError::ValidationError(_))));
+    assert_eq!(
+        storage.get_memory(unrelated.id).await.unwrap().namespace,
+        project("other")
+    );
+}
+
+#[tokio::test]
+async fn test_merge_namespace_keeps_links() {
+    let temp_dir = TempDir::new().unwrap();
+    let (storage, _db_path) = open_storage(&temp_dir).await;
+    let source = project("frontend");
+    let target = project("web");
+
+    let kept = memory_in(&target, "Web app uses server-side rendering");
+    let mut moving = memory_in(&source, "Hydration runs after server rendering");
+    moving.links.push(MemoryLink {
+        target_id: kept.id,
+        link_type: LinkType::Extends,
+        strength: 0.9,
+        reason: "Builds on the rendering decision".to_string(),
+        created_at: chrono::Utc::now(),
+        last_traversed_at: None,
+        user_created: false,
+    });
+    storage.store_memory(&kept).await.unwrap();
+    storage.store_memory(&moving).await.unwrap();
+
+    let moved = storage.merge_namespace(&source, &target).await.unwrap();
+    assert_eq!(moved, 1);
+    assert_eq!(
+        storage.count_memories(Some(target.clone())).await.unwrap(),
+        2
+    );
+    assert_eq!(storage.count_memories(Some(source)).await.unwrap(), 0);
+
+    let merged = storage.get_memory(moving.id).await.unwrap();
+    assert_eq!(merged.namespace, target);
+    assert_eq!(merged.links.len(), 1);
+    assert_eq!(merged.links[0].target_id, kept.id);
+
+    let reached = storage
+        .graph_traverse(&[moving.id], 1, Some(target))
+        .await
+        .unwrap();
+    assert!(reached.iter().any(|m| m.id == kept.id));
+}
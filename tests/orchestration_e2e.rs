@@ -782,6 +782,7 @@ async fn test_event_persistence_and_replay() {
             item_id: WorkItemId::new(),
             description: "Test task".to_string(),
             phase: Phase::PromptToSpec,
+            item: None,
         },
         AgentEvent::WorkItemStarted {
             agent: AgentRole::Executor,
@@ -842,6 +843,7 @@ async fn test_state_reconstruction_from_events() {
             item_id: item_id_1.clone(),
             description: "Task 1".to_string(),
             phase: Phase::PromptToSpec,
+            item: None,
         },
         AgentEvent::WorkItemStarted {
             agent: AgentRole::Executor,
@@ -859,6 +861,7 @@ async fn test_state_reconstruction_from_events() {
             item_id: item_id_2.clone(),
             description: "Task 2".to_string(),
             phase: Phase::SpecToFullSpec,
+            item: None,
         },
         AgentEvent::WorkItemFailed {
             agent: AgentRole::Executor,
@@ -980,6 +983,7 @@ async fn test_cross_session_event_persistence() {
                 item_id: WorkItemId::new(),
                 description: format!("Task {}", i),
                 phase: Phase::PromptToSpec,
+                item: None,
             };
             persistence.persist(event).await.expect("Failed to persist");
         }
@@ -1005,6 +1009,7 @@ async fn test_cross_session_event_persistence() {
                 item_id: WorkItemId::new(),
                 description: format!("Task {}", i),
                 phase: Phase::SpecToFullSpec,
+                item: None,
             };
             persistence.persist(event).await.expect("Failed to persist");
         }
@@ -1043,6 +1048,7 @@ async fn test_event_ordering_preservation() {
             item_id,
             description: format!("Task {}", i),
             phase: Phase::PromptToSpec,
+            item: None,
         };
 
         persistence.persist(event).await.expect("Failed to persist");
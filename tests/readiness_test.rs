@@ -0,0 +1,60 @@
+//! Liveness and readiness probes
+
+use mnemosyne_core::health::{liveness, readiness, Readiness};
+use mnemosyne_core::storage::migrations::MigrationRegistry;
+use mnemosyne_core::{ConnectionMode, LibsqlStorage};
+use tempfile::TempDir;
+
+async fn open(path: &str) -> LibsqlStorage {
+    LibsqlStorage::new_with_validation(ConnectionMode::Local(path.to_string()), true)
+        .await
+        .unwrap()
+}
+
+/// Open without running migrations
+async fn open_unmigrated(path: &str) -> LibsqlStorage {
+    LibsqlStorage::new(ConnectionMode::LocalReadOnly(path.to_string()))
+        .await
+        .unwrap()
+}
+
+#[test]
+fn test_liveness_reports_this_process() {
+    let report = liveness();
+    assert!(report.alive);
+    assert_eq!(report.pid, std::process::id());
+}
+
+#[tokio::test]
+async fn test_readiness_waits_for_migrations() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("ready.db");
+    let path = path.to_str().unwrap();
+    drop(open(path).await);
+
+    // Mark the most recent migration as not yet applied
+    let latest = MigrationRegistry::libsql().latest_version();
+    let conn = libsql::Builder::new_local(path)
+        .build()
+        .await
+        .unwrap()
+        .connect()
+        .unwrap();
+    conn.execute(
+        "DELETE FROM migrations WHERE version = ?1",
+        [i64::from(latest)],
+    )
+    .await
+    .unwrap();
+    drop(conn);
+
+    let report = readiness(&open_unmigrated(path).await).await;
+    assert_eq!(report.status, Readiness::NotReady);
+    assert!(!report.dependency("migrations").unwrap().ready);
+    assert!(report.dependency("storage").unwrap().ready);
+
+    let report = readiness(&open(path).await).await;
+    assert_eq!(report.status, Readiness::Ready, "{:?}", report);
+    assert!(report.dependencies.iter().all(|d| d.ready));
+    assert_eq!(serde_json::to_value(&report).unwrap()["status"], "READY");
+}
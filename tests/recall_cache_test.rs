@@ -0,0 +1,134 @@
+//! Recall result cache: writes invalidate it, hits skip the database
+
+use mnemosyne_core::{
+    ConnectionMode, LibsqlStorage, MemoryType, Namespace, SearchConfig, StorageBackend,
+};
+
+mod common;
+use common::sample_memory;
+
+fn project() -> Namespace {
+    Namespace::Project {
+        name: "myapp".to_string(),
+    }
+}
+
+/// Storage on `path` with a 16-entry recall cache
+async fn cached_storage(path: &str) -> LibsqlStorage {
+    let mut storage =
+        LibsqlStorage::new_with_validation(ConnectionMode::Local(path.to_string()), true)
+            .await
+            .unwrap();
+    storage.set_search_config(SearchConfig {
+        recall_cache_size: 16,
+        recall_cache_ttl_secs: 300,
+        ..SearchConfig::default()
+    });
+    storage
+}
+
+async fn recall(storage: &LibsqlStorage) -> usize {
+    storage
+        .hybrid_search("backoff", Some(project()), 10, false)
+        .await
+        .unwrap()
+        .len()
+}
+
+#[tokio::test]
+async fn test_write_invalidates_cached_recall() {
+    let dir = tempfile::tempdir().unwrap();
+    let storage = cached_storage(dir.path().join("cache.db").to_str().unwrap()).await;
+
+    let mut first = sample_memory("Retry with backoff", MemoryType::CodePattern, 6);
+    first.namespace = project();
+    storage.store_memory(&first).await.unwrap();
+
+    assert_eq!(recall(&storage).await, 1);
+    assert_eq!(recall(&storage).await, 1);
+    let stats = storage.recall_cache_stats().unwrap();
+    assert_eq!((stats.hits, stats.misses), (1, 1));
+
+    // A write elsewhere leaves the cached recall alone
+    let mut elsewhere = sample_memory("Global backoff policy", MemoryType::Preference, 5);
+    elsewhere.namespace = Namespace::Project {
+        name: "other".to_string(),
+    };
+    storage.store_memory(&elsewhere).await.unwrap();
+    assert_eq!(recall(&storage).await, 1);
+    assert_eq!(storage.recall_cache_stats().unwrap().hits, 2);
+
+    let mut second = sample_memory(
+        "Exponential backoff with jitter",
+        MemoryType::CodePattern,
+        7,
+    );
+    second.namespace = project();
+    storage.store_memory(&second).await.unwrap();
+    assert_eq!(recall(&storage).await, 2);
+
+    storage.archive_memory(first.id).await.unwrap();
+    assert_eq!(recall(&storage).await, 1);
+
+    let stats = storage.recall_cache_stats().unwrap();
+    assert_eq!((stats.hits, stats.misses), (2, 3));
+    assert!((stats.hit_rate() - 0.4).abs() < 1e-9);
+}
+
+#[tokio::test]
+async fn test_cached_hit_skips_database() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("shared.db");
+    let path = path.to_str().unwrap();
+    let reader = cached_storage(path).await;
+    // A second handle writes without going through the reader's cache
+    let writer = LibsqlStorage::new(ConnectionMode::Local(path.to_string()))
+        .await
+        .unwrap();
+
+    let mut memory = sample_memory("Retry with backoff", MemoryType::CodePattern, 6);
+    memory.namespace = project();
+    writer.store_memory(&memory).await.unwrap();
+    assert_eq!(recall(&reader).await, 1);
+
+    writer.archive_memory(memory.id).await.unwrap();
+
+    // Served from the cache: the archive is not visible yet
+    assert_eq!(recall(&reader).await, 1);
+    assert_eq!(reader.recall_cache_stats().unwrap().hits, 1);
+
+    // An uncached handle sees the database as it is
+    assert_eq!(recall(&writer).await, 0);
+    assert!(writer.recall_cache_stats().is_none());
+}
+
+#[tokio::test]
+async fn test_supersede_importance_and_tag_changes_invalidate() {
+    let dir = tempfile::tempdir().unwrap();
+    let storage = cached_storage(dir.path().join("cache.db").to_str().unwrap()).await;
+
+    let mut old = sample_memory("Retry with backoff", MemoryType::CodePattern, 6);
+    old.namespace = project();
+    let mut new = sample_memory("Retry with capped backoff", MemoryType::CodePattern, 6);
+    new.namespace = project();
+    for memory in [&old, &new] {
+        storage.store_memory(memory).await.unwrap();
+    }
+    let misses = || storage.recall_cache_stats().unwrap().misses;
+
+    assert_eq!(recall(&storage).await, 2);
+    storage.update_importance(&new.id, 9.0).await.unwrap();
+    assert_eq!(recall(&storage).await, 2);
+    assert_eq!(misses(), 2);
+
+    storage
+        .rename_tags(&["test".to_string()], "resilience", None)
+        .await
+        .unwrap();
+    assert_eq!(recall(&storage).await, 2);
+    assert_eq!(misses(), 3);
+
+    storage.mark_superseded(&old.id, &new.id).await.unwrap();
+    assert_eq!(recall(&storage).await, 1);
+    assert_eq!(misses(), 4);
+}
@@ -0,0 +1,126 @@
+//! Smoke tests for gRPC server reflection and standard health checking
+//!
+//! Run with: cargo test --test rpc_reflection_test --features rpc-reflection,rpc-health
+
+#![cfg(all(feature = "rpc-reflection", feature = "rpc-health"))]
+
+use mnemosyne_core::{
+    rpc::RpcServer,
+    storage::{libsql::LibsqlStorage, StorageBackend},
+};
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tokio::sync::oneshot;
+use tonic_health::pb::{
+    health_check_response::ServingStatus, health_client::HealthClient, HealthCheckRequest,
+};
+use tonic_reflection::pb::v1::{
+    server_reflection_client::ServerReflectionClient, server_reflection_request::MessageRequest,
+    server_reflection_response::MessageResponse, ServerReflectionRequest,
+};
+
+/// Start a server on an ephemeral port; send on the returned channel to stop it
+async fn start_server() -> (
+    SocketAddr,
+    oneshot::Sender<()>,
+    tokio::task::JoinHandle<anyhow::Result<()>>,
+) {
+    use mnemosyne_core::storage::libsql::ConnectionMode;
+
+    let temp_file = format!("/tmp/mnemosyne_rpc_reflection_{}.db", uuid::Uuid::new_v4());
+    let storage: Arc<dyn StorageBackend> = Arc::new(
+        LibsqlStorage::new_with_validation(ConnectionMode::Local(temp_file), true)
+            .await
+            .expect("Failed to create test storage"),
+    );
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let (stop_tx, stop_rx) = oneshot::channel::<()>();
+
+    let handle = tokio::spawn(
+        RpcServer::new(storage, None).serve_listener(listener, async {
+            let _ = stop_rx.await;
+        }),
+    );
+
+    (addr, stop_tx, handle)
+}
+
+#[tokio::test]
+async fn test_reflection_lists_services() {
+    let (addr, stop, handle) = start_server().await;
+
+    let mut client = ServerReflectionClient::connect(format!("http://{}", addr))
+        .await
+        .expect("Failed to connect");
+
+    let request = ServerReflectionRequest {
+        host: String::new(),
+        message_request: Some(MessageRequest::ListServices(String::new())),
+    };
+    let mut responses = client
+        .server_reflection_info(tokio_stream::once(request))
+        .await
+        .expect("Reflection request failed")
+        .into_inner();
+
+    let response = responses
+        .message()
+        .await
+        .expect("Reflection stream failed")
+        .expect("No reflection response");
+
+    let services: Vec<String> = match response.message_response {
+        Some(MessageResponse::ListServicesResponse(list)) => {
+            list.service.into_iter().map(|s| s.name).collect()
+        }
+        other => panic!("Unexpected reflection response: {:?}", other),
+    };
+
+    for expected in [
+        "mnemosyne.v1.MemoryService",
+        "mnemosyne.v1.HealthService",
+        "mnemosyne.v1.EventService",
+        "grpc.health.v1.Health",
+    ] {
+        assert!(
+            services.iter().any(|s| s == expected),
+            "{} missing from {:?}",
+            expected,
+            services
+        );
+    }
+
+    stop.send(()).unwrap();
+    handle.await.unwrap().unwrap();
+}
+
+#[tokio::test]
+async fn test_grpc_health_reports_serving() {
+    let (addr, stop, handle) = start_server().await;
+
+    let mut client = HealthClient::connect(format!("http://{}", addr))
+        .await
+        .expect("Failed to connect");
+
+    for service in ["", "mnemosyne.v1.MemoryService"] {
+        let status = client
+            .check(HealthCheckRequest {
+                service: service.to_string(),
+            })
+            .await
+            .expect("Health check failed")
+            .into_inner()
+            .status;
+        assert_eq!(
+            status,
+            ServingStatus::Serving as i32,
+            "service {:?}",
+            service
+        );
+    }
+
+    stop.send(()).unwrap();
+    handle.await.unwrap().unwrap();
+}
@@ -5,17 +5,20 @@
 #![cfg(feature = "rpc")]
 
 use mnemosyne_core::{
+    api::{Event, EventBroadcaster},
     rpc::{
         generated::{
-            memory_service_server::MemoryService, GetContextRequest, GetMemoryRequest,
-            GraphTraverseRequest, ListMemoriesRequest, RecallRequest, SemanticSearchRequest,
-            StoreMemoryRequest,
+            event_service_server::EventService, memory_service_server::MemoryService,
+            GetContextRequest, GetMemoryRequest, GraphTraverseRequest, ListMemoriesRequest,
+            RecallRequest, SemanticSearchRequest, StoreMemoryRequest, StreamEventsRequest,
         },
-        services::MemoryServiceImpl,
+        services::{EventServiceImpl, MemoryServiceImpl},
     },
     storage::{libsql::LibsqlStorage, StorageBackend},
 };
 use std::sync::Arc;
+use std::time::Duration;
+use tokio_stream::StreamExt;
 use tonic::Request;
 
 /// Helper to create a test storage backend with temporary file database
@@ -243,6 +246,154 @@ async fn test_recall_search() {
     assert_eq!(response.query, "Rust programming");
 }
 
+#[tokio::test]
+async fn test_recall_stream() {
+    let storage = create_test_storage().await;
+    let service = MemoryServiceImpl::new(storage.clone(), None);
+
+    for content in [
+        "Rust ownership prevents data races",
+        "Rust lifetimes track borrows",
+        "Go uses garbage collection",
+    ] {
+        service
+            .store_memory(Request::new(StoreMemoryRequest {
+                content: content.to_string(),
+                namespace: Some(test_namespace()),
+                importance: Some(7),
+                context: None,
+                tags: vec![],
+                memory_type: None,
+                skip_llm_enrichment: true,
+            }))
+            .await
+            .expect("Failed to store memory");
+    }
+
+    let recall_request = || RecallRequest {
+        query: "Rust".to_string(),
+        namespace: Some(test_namespace()),
+        max_results: 5,
+        min_importance: None,
+        memory_types: vec![],
+        tags: vec![],
+        include_archived: false,
+        semantic_weight: None,
+        fts_weight: None,
+        graph_weight: None,
+    };
+
+    let mut stream = service
+        .recall_stream(Request::new(recall_request()))
+        .await
+        .expect("Failed to open recall stream")
+        .into_inner();
+
+    let mut streamed = Vec::new();
+    while let Some(item) = stream.next().await {
+        streamed.push(item.expect("Stream item should not be an error"));
+    }
+
+    let unary = service
+        .recall(Request::new(recall_request()))
+        .await
+        .expect("Failed to recall memories")
+        .into_inner();
+
+    assert!(!streamed.is_empty(), "Should stream at least one result");
+    let ids = |results: &[mnemosyne_core::rpc::generated::SearchResult]| {
+        results
+            .iter()
+            .map(|r| r.memory.as_ref().unwrap().id.clone())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(ids(&streamed), ids(&unary.results));
+}
+
+#[tokio::test]
+async fn test_stream_events() {
+    let broadcaster = EventBroadcaster::new(16);
+    let service = EventServiceImpl::new(broadcaster.clone());
+
+    let mut stream = service
+        .stream_events(Request::new(StreamEventsRequest {
+            event_types: vec!["agent_completed".to_string()],
+        }))
+        .await
+        .expect("Failed to open event stream")
+        .into_inner();
+
+    // Filtered out
+    broadcaster
+        .broadcast(Event::agent_started("executor".to_string()))
+        .unwrap();
+    let completed = Event::agent_completed("executor".to_string(), "done".to_string());
+    broadcaster.broadcast(completed.clone()).unwrap();
+
+    let message = tokio::time::timeout(Duration::from_secs(5), stream.next())
+        .await
+        .expect("Timed out waiting for event")
+        .expect("Stream ended early")
+        .expect("Stream item should not be an error");
+
+    assert_eq!(message.id, completed.id);
+    assert_eq!(message.event_type, "agent_completed");
+    let payload: serde_json::Value = serde_json::from_str(&message.payload_json).unwrap();
+    assert_eq!(payload["agent_id"], "executor");
+
+    // Dropping the stream unsubscribes the forwarding task
+    drop(stream);
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while broadcaster.subscriber_count() > 0 {
+            broadcaster
+                .broadcast(Event::agent_started("executor".to_string()))
+                .ok();
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("Forwarding task should stop after the client disconnects");
+}
+
+#[tokio::test]
+async fn test_memory_events_reach_event_stream() {
+    let broadcaster = EventBroadcaster::new(16);
+    let events = EventServiceImpl::new(broadcaster.clone());
+    let service = MemoryServiceImpl::new(create_test_storage().await, None)
+        .with_event_broadcaster(broadcaster);
+
+    let mut stream = events
+        .stream_events(Request::new(StreamEventsRequest {
+            event_types: vec!["memory_stored".to_string()],
+        }))
+        .await
+        .expect("Failed to open event stream")
+        .into_inner();
+
+    let stored = service
+        .store_memory(Request::new(StoreMemoryRequest {
+            content: "Published memory".to_string(),
+            namespace: Some(test_namespace()),
+            importance: Some(5),
+            context: None,
+            tags: vec![],
+            memory_type: None,
+            skip_llm_enrichment: true,
+        }))
+        .await
+        .expect("Failed to store memory")
+        .into_inner();
+
+    let message = tokio::time::timeout(Duration::from_secs(5), stream.next())
+        .await
+        .expect("Timed out waiting for event")
+        .expect("Stream ended early")
+        .expect("Stream item should not be an error");
+    assert_eq!(message.event_type, "memory_stored");
+    let payload: serde_json::Value = serde_json::from_str(&message.payload_json).unwrap();
+    assert_eq!(payload["memory_id"], stored.memory_id);
+}
+
 #[tokio::test]
 async fn test_semantic_search() {
     let storage = create_test_storage().await;
@@ -0,0 +1,131 @@
+//! SearchQuery memory-type and tag filters
+
+use mnemosyne_core::{LibsqlStorage, MemoryType, SearchQuery, StorageBackend};
+
+mod common;
+use common::{create_test_storage, sample_memory};
+
+async fn store(storage: &LibsqlStorage, content: &str, memory_type: MemoryType, tags: &[&str]) {
+    let mut memory = sample_memory(content, memory_type, 6);
+    memory.tags = tags.iter().map(|t| t.to_string()).collect();
+    storage.store_memory(&memory).await.unwrap();
+}
+
+/// Storage with four "token" memories of mixed types and tags
+async fn tagged_storage() -> LibsqlStorage {
+    let storage = create_test_storage().await;
+    store(
+        &storage,
+        "Token refresh race fixed",
+        MemoryType::BugFix,
+        &["security", "auth"],
+    )
+    .await;
+    store(
+        &storage,
+        "Token cache eviction bug",
+        MemoryType::BugFix,
+        &["performance"],
+    )
+    .await;
+    store(
+        &storage,
+        "Token signing uses Ed25519",
+        MemoryType::ArchitectureDecision,
+        &["security"],
+    )
+    .await;
+    store(
+        &storage,
+        "Token logging redacts secrets",
+        MemoryType::CodePattern,
+        &["auth", "logging"],
+    )
+    .await;
+    storage
+}
+
+async fn matching(storage: &LibsqlStorage, query: SearchQuery) -> Vec<String> {
+    let mut contents: Vec<String> = storage
+        .search(&SearchQuery {
+            query: "token".to_string(),
+            ..query
+        })
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|r| r.memory.content)
+        .collect();
+    contents.sort();
+    contents
+}
+
+#[tokio::test]
+async fn test_memory_type_filter() {
+    let storage = tagged_storage().await;
+    assert_eq!(matching(&storage, SearchQuery::default()).await.len(), 4);
+
+    let bug_fixes = matching(
+        &storage,
+        SearchQuery {
+            memory_types: vec![MemoryType::BugFix],
+            ..SearchQuery::default()
+        },
+    )
+    .await;
+    assert_eq!(
+        bug_fixes,
+        vec!["Token cache eviction bug", "Token refresh race fixed"]
+    );
+}
+
+#[tokio::test]
+async fn test_tags_all_requires_every_tag() {
+    let storage = tagged_storage().await;
+    let results = matching(
+        &storage,
+        SearchQuery {
+            tags_all: vec!["security".to_string(), "auth".to_string()],
+            ..SearchQuery::default()
+        },
+    )
+    .await;
+    assert_eq!(results, vec!["Token refresh race fixed"]);
+}
+
+#[tokio::test]
+async fn test_tags_any_matches_either_tag() {
+    let storage = tagged_storage().await;
+    let results = matching(
+        &storage,
+        SearchQuery {
+            tags_any: vec!["performance".to_string(), "logging".to_string()],
+            ..SearchQuery::default()
+        },
+    )
+    .await;
+    assert_eq!(
+        results,
+        vec!["Token cache eviction bug", "Token logging redacts secrets"]
+    );
+}
+
+#[tokio::test]
+async fn test_type_and_tag_filters_combine() {
+    let storage = tagged_storage().await;
+    let query = SearchQuery {
+        memory_types: vec![MemoryType::BugFix, MemoryType::CodePattern],
+        tags_any: vec!["security".to_string(), "logging".to_string()],
+        tags_all: vec!["auth".to_string()],
+        ..SearchQuery::default()
+    };
+    let results = matching(&storage, query.clone()).await;
+    assert_eq!(
+        results,
+        vec!["Token logging redacts secrets", "Token refresh race fixed"]
+    );
+
+    // The in-memory check agrees with the SQL filters
+    let signing = sample_memory("Token signing", MemoryType::ArchitectureDecision, 6);
+    assert!(!query.matches(&signing));
+}
@@ -3,8 +3,14 @@
 //! Tests the complete three-tier system working together.
 
 use mnemosyne_core::ics::semantic_highlighter::{
-    tier2_relational::*, HighlightSettings, SemanticHighlightEngine,
+    tier2_relational::*, tier3_analytical::ContradictionDetector, HighlightSettings,
+    SemanticHighlightEngine,
 };
+use mnemosyne_core::{MemoryType, StorageBackend};
+use std::sync::Arc;
+
+mod common;
+use common::{create_test_llm_service, create_test_storage, sample_memory};
 
 #[tokio::test]
 async fn test_tier1_xml_tag_highlighting() {
@@ -245,3 +251,40 @@ async fn test_clear_caches() {
     assert_eq!(relational_stats.size, 0);
     assert_eq!(analytical_stats.size, 0);
 }
+
+#[tokio::test]
+async fn test_memory_contradictions_check_related_important_memories() {
+    let storage = Arc::new(create_test_storage().await);
+
+    let decision = sample_memory(
+        "Decided to use PostgreSQL as the primary database",
+        MemoryType::ArchitectureDecision,
+        9,
+    );
+    let minor = sample_memory(
+        "Someone mentioned a database benchmark",
+        MemoryType::Insight,
+        3,
+    );
+    let unrelated = sample_memory("Prefer tabs for indentation", MemoryType::Preference, 9);
+    for memory in [&decision, &minor, &unrelated] {
+        storage.store_memory(memory).await.unwrap();
+    }
+
+    let text = "We will store everything in a MySQL database.";
+
+    // Off by default: no retrieval, no extra API call
+    let detector = ContradictionDetector::new(create_test_llm_service());
+    assert!(detector.related_memories(text).await.unwrap().is_empty());
+    assert!(detector
+        .detect_against_memories(text)
+        .await
+        .unwrap()
+        .is_empty());
+
+    // Opted in: only related memories above the importance floor are checked
+    let detector = detector.with_memory_search(storage, 7);
+    let related = detector.related_memories(text).await.unwrap();
+    let ids: Vec<_> = related.iter().map(|m| m.id).collect();
+    assert_eq!(ids, vec![decision.id]);
+}
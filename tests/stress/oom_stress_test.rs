@@ -6,7 +6,9 @@
 
 #![cfg(not(debug_assertions))] // Only run in release mode
 
-use mnemosyne_core::diagnostics::{global_memory_tracker, start_memory_monitoring, MemoryStatus};
+use mnemosyne_core::diagnostics::{
+    global_memory_tracker, start_memory_monitoring, LeakThresholds, MemoryStatus,
+};
 use mnemosyne_core::{LibsqlStorage, Namespace, StorageBackend};
 use std::sync::Arc;
 use std::time::Duration;
@@ -17,7 +19,7 @@ use tokio::time::sleep;
 #[ignore] // Run manually with: cargo test --release oom_stress_test -- --ignored
 async fn test_memory_growth_embeddings() {
     // Start memory monitoring
-    let _monitor_task = start_memory_monitoring();
+    let _monitor_task = start_memory_monitoring(LeakThresholds::default(), None);
 
     // Create temporary storage
     let temp_dir = tempdir().unwrap();
@@ -78,7 +80,7 @@ async fn test_work_queue_unbounded_growth() {
     use mnemosyne_core::orchestration::{WorkItem, WorkQueue};
     use mnemosyne_core::types::MemoryId;
 
-    let _monitor_task = start_memory_monitoring();
+    let _monitor_task = start_memory_monitoring(LeakThresholds::default(), None);
     let work_queue = Arc::new(tokio::sync::RwLock::new(WorkQueue::new()));
 
     let snapshot_before = global_memory_tracker().snapshot();
@@ -125,7 +127,7 @@ async fn test_work_queue_unbounded_growth() {
 #[tokio::test]
 #[ignore]
 async fn test_spawned_task_cleanup() {
-    let _monitor_task = start_memory_monitoring();
+    let _monitor_task = start_memory_monitoring(LeakThresholds::default(), None);
 
     global_memory_tracker().set_spawned_tasks(0);
 
@@ -159,7 +161,7 @@ async fn test_spawned_task_cleanup() {
 #[tokio::test]
 #[ignore]
 async fn test_database_connection_leaks() {
-    let _monitor_task = start_memory_monitoring();
+    let _monitor_task = start_memory_monitoring(LeakThresholds::default(), None);
 
     let temp_dir = tempdir().unwrap();
     let db_path = temp_dir.path().join("test.db");
@@ -0,0 +1,139 @@
+//! Tests for tag listing, renaming, and merging (`mnemosyne tags`)
+
+use mnemosyne_core::{LibsqlStorage, MemoryNote, MemoryType, Namespace, StorageBackend};
+
+mod common;
+use common::{create_test_storage, sample_memory};
+
+fn tagged(content: &str, tags: &[&str]) -> MemoryNote {
+    let mut memory = sample_memory(content, MemoryType::Insight, 5);
+    memory.tags = tags.iter().map(|t| t.to_string()).collect();
+    memory
+}
+
+async fn tags_of(storage: &LibsqlStorage, memory: &MemoryNote) -> Vec<String> {
+    storage.get_memory(memory.id).await.unwrap().tags
+}
+
+#[tokio::test]
+async fn test_tag_counts() {
+    let storage = create_test_storage().await;
+    let project = Namespace::Project {
+        name: "myapp".to_string(),
+    };
+
+    let a = tagged("a", &["db", "perf"]);
+    let b = tagged("b", &["db"]);
+    let mut c = tagged("c", &["db", "api"]);
+    c.namespace = project.clone();
+    let archived = tagged("archived", &["db", "legacy"]);
+    for memory in [&a, &b, &c, &archived] {
+        storage.store_memory(memory).await.unwrap();
+    }
+    storage.archive_memory(archived.id).await.unwrap();
+
+    let all = storage.tag_counts(None).await.unwrap();
+    assert_eq!(
+        all,
+        vec![
+            ("db".to_string(), 3),
+            ("api".to_string(), 1),
+            ("perf".to_string(), 1),
+        ]
+    );
+
+    let scoped = storage.tag_counts(Some(&project)).await.unwrap();
+    assert_eq!(scoped, vec![("api".to_string(), 1), ("db".to_string(), 1)]);
+}
+
+#[tokio::test]
+async fn test_rename_tag() {
+    let storage = create_test_storage().await;
+    let plain = tagged("plain", &["perf", "db", "api"]);
+    let untouched = tagged("untouched", &["api"]);
+    storage.store_memory(&plain).await.unwrap();
+    storage.store_memory(&untouched).await.unwrap();
+
+    let changed = storage
+        .rename_tags(&["db".to_string()], "database", None)
+        .await
+        .unwrap();
+
+    assert_eq!(changed, 1);
+    // Position is kept
+    assert_eq!(
+        tags_of(&storage, &plain).await,
+        vec!["perf", "database", "api"]
+    );
+    assert_eq!(tags_of(&storage, &untouched).await, vec!["api"]);
+    // Renamed tag is searchable under its new name only
+    let counts = storage.tag_counts(None).await.unwrap();
+    assert!(counts.contains(&("database".to_string(), 1)));
+    assert!(!counts.iter().any(|(t, _)| t == "db"));
+}
+
+#[tokio::test]
+async fn test_rename_collision_merges() {
+    let storage = create_test_storage().await;
+    let both = tagged("both", &["db", "x", "database"]);
+    let old_only = tagged("old only", &["db"]);
+    storage.store_memory(&both).await.unwrap();
+    storage.store_memory(&old_only).await.unwrap();
+
+    let changed = storage
+        .rename_tags(&["db".to_string()], "database", None)
+        .await
+        .unwrap();
+
+    assert_eq!(changed, 2);
+    assert_eq!(tags_of(&storage, &both).await, vec!["database", "x"]);
+    assert_eq!(tags_of(&storage, &old_only).await, vec!["database"]);
+    assert_eq!(
+        storage.tag_counts(None).await.unwrap(),
+        vec![("database".to_string(), 2), ("x".to_string(), 1)]
+    );
+
+    // Renaming to itself is a no-op
+    let changed = storage
+        .rename_tags(&["database".to_string()], "database", None)
+        .await
+        .unwrap();
+    assert_eq!(changed, 0);
+}
+
+#[tokio::test]
+async fn test_merge_tags_in_namespace() {
+    let storage = create_test_storage().await;
+    let project = Namespace::Project {
+        name: "myapp".to_string(),
+    };
+
+    let mut in_project = tagged("in project", &["db", "databases"]);
+    in_project.namespace = project.clone();
+    let mut other = tagged("other", &["databases"]);
+    other.namespace = project.clone();
+    let global = tagged("global", &["db"]);
+    for memory in [&in_project, &other, &global] {
+        storage.store_memory(memory).await.unwrap();
+    }
+
+    let changed = storage
+        .rename_tags(
+            &["db".to_string(), "databases".to_string()],
+            "database",
+            Some(&project),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(changed, 2);
+    assert_eq!(tags_of(&storage, &in_project).await, vec!["database"]);
+    assert_eq!(tags_of(&storage, &other).await, vec!["database"]);
+    // Outside the namespace
+    assert_eq!(tags_of(&storage, &global).await, vec!["db"]);
+
+    assert!(storage
+        .rename_tags(&["db".to_string()], "  ", None)
+        .await
+        .is_err());
+}